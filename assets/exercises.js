@@ -52,7 +52,7 @@
             return container.dataset.exerciseId;
         }
         // Try looking for article child
-        const article = container.querySelector('.exercise, .usecase-exercise');
+        const article = container.querySelector('.exercise, .usecase-exercise, .quiz-exercise');
         if (article && article.dataset && article.dataset.exerciseId) {
             return article.dataset.exerciseId;
         }
@@ -142,6 +142,7 @@
                         const temp = document.createElement('textarea');
                         temp.innerHTML = textarea.dataset.original;
                         textarea.value = temp.value;
+                        textarea.dispatchEvent(new CustomEvent('exercise:reset'));
                         showNotification('Code reset to original', 'success');
                     }
                 } else {
@@ -191,13 +192,13 @@
     function initProgressTracking() {
         const progress = getProgress();
 
-        document.querySelectorAll('.exercise-container, .exercise, .usecase-exercise').forEach(container => {
+        document.querySelectorAll('.exercise-container, .exercise, .usecase-exercise, .quiz-exercise').forEach(container => {
             const exerciseId = getExerciseId(container);
             if (!exerciseId) return;
 
-            const article = container.classList.contains('exercise') || container.classList.contains('usecase-exercise') 
+            const article = ['exercise', 'usecase-exercise', 'quiz-exercise'].some(c => container.classList.contains(c))
                 ? container 
-                : container.querySelector('.exercise, .usecase-exercise');
+                : container.querySelector('.exercise, .usecase-exercise, .quiz-exercise');
                 
             const completeBtn = container.querySelector('.btn-complete');
             const checkbox = container.querySelector('.progress-indicator input[type="checkbox"]');
@@ -253,7 +254,7 @@
     }
 
     // ============================================ 
-    // Run Tests (Rust Playground Integration)
+    // Run Tests (Execution Backends)
     // ============================================ 
 
     function initRunTests() {
@@ -263,11 +264,19 @@
                 const starterContainer = container.querySelector('.exercise-starter');
                 const testsContainer = container.querySelector('.exercise-tests');
 
-                // Get the user's code
+                // Get the user's code, with the starter's hidden lines added back
+                const withHidden = t => (t.dataset.hiddenPrefix || '') + t.value + (t.dataset.hiddenSuffix || '');
                 let userCode = '';
-                const textarea = starterContainer?.querySelector('textarea');
+                const textarea = starterContainer?.querySelector('textarea:not([data-path])');
                 if (textarea) {
-                    userCode = textarea.value;
+                    userCode = withHidden(textarea);
+                }
+                const otherFiles = Array.from(starterContainer?.querySelectorAll('textarea[data-path]') || [])
+                    .map(t => ({ path: t.dataset.path, content: withHidden(t) }));
+                // A Parsons problem's code is the lines the student put in order
+                const parsonsEl = container.querySelector('.exercise-parsons');
+                if (!textarea && parsonsEl) {
+                    userCode = parsonsCode(parsonsEl);
                 }
 
                 // Get the test code
@@ -279,8 +288,31 @@
                     return;
                 }
 
-                // Combine user code and test code
-                const fullCode = combineCodeForTests(userCode, testCode);
+                // Code that breaks the exercise's requirements isn't run
+                const requirementsEl = container.querySelector('.exercise-requirements');
+                const violations = requirementsEl && textarea
+                    ? requirementViolations(textarea.value, requirementsEl.dataset.requirements, requirementsEl.dataset.comment)
+                    : [];
+                if (violations.length > 0) {
+                    const resultsEl = testsContainer?.querySelector('.test-results');
+                    if (resultsEl) {
+                        resultsEl.hidden = false;
+                        resultsEl.classList.remove('success', 'pending');
+                        resultsEl.classList.add('error', 'show');
+                        renderTestResults(resultsEl, {
+                            status: 'failed',
+                            tests: violations.map(name => ({ name: name, status: 'failed', message: null })),
+                            stdout: '',
+                            stderr: ''
+                        });
+                    }
+                    showNotification('Your code doesn\'t meet the requirements yet', 'error');
+                    return;
+                }
+
+                const locked = textarea ? lockedRegions(textarea) : [];
+                const request = buildExecutionRequest(this, userCode, testCode, locked, otherFiles);
+                const adapter = EXECUTION_ADAPTERS[this.dataset.backend] || EXECUTION_ADAPTERS.playground;
 
                 // Show loading state
                 this.classList.add('loading');
@@ -289,27 +321,24 @@
 
                 const resultsEl = testsContainer?.querySelector('.test-results');
                 if (resultsEl) {
+                    resultsEl.hidden = false;
                     resultsEl.classList.remove('success', 'error', 'pending', 'show');
                     resultsEl.classList.add('pending', 'show');
                     resultsEl.textContent = 'Compiling and running tests...';
                 }
 
                 try {
-                    const result = await runOnPlayground(fullCode);
+                    const result = await adapter(request, this.dataset.backendUrl, this.dataset.combine);
+                    const passed = result.status === 'passed';
 
                     if (resultsEl) {
                         resultsEl.classList.remove('pending');
-
-                        if (result.success) {
-                            resultsEl.classList.add('success');
-                            resultsEl.textContent = result.output || 'All tests passed!';
-                        } else {
-                            resultsEl.classList.add('error');
-                            resultsEl.textContent = result.output || result.error || 'Tests failed';
-                        }
+                        resultsEl.classList.add(passed ? 'success' : 'error');
+                        const errorCodes = (this.dataset.errorCodes || '').split(',').filter(Boolean);
+                        renderTestResults(resultsEl, result, errorCodes);
                     }
 
-                    if (result.success) {
+                    if (passed) {
                         showNotification('Tests passed!', 'success');
                     } else {
                         showNotification('Tests failed', 'error');
@@ -320,7 +349,7 @@
                     if (resultsEl) {
                         resultsEl.classList.remove('pending');
                         resultsEl.classList.add('error');
-                        resultsEl.textContent = 'Failed to connect to Rust Playground: ' + e.message;
+                        resultsEl.textContent = 'Failed to connect to the test runner: ' + e.message;
                     }
                     showNotification('Failed to run tests', 'error');
                 } finally {
@@ -332,17 +361,354 @@
         });
     }
 
+    /**
+     * The requirements (`data-requirements` JSON) that `code` breaks, as
+     * messages. Mirrors `Requirements::violations`: constructs match as whole
+     * words at word-character ends, and comment-only lines are ignored.
+     */
+    function requirementViolations(code, requirementsJson, comment) {
+        let requirements = {};
+        try {
+            requirements = JSON.parse(requirementsJson || '{}');
+        } catch (e) {
+            console.warn('Invalid data-requirements:', e);
+            return [];
+        }
+        comment = comment || '//';
+        const lines = code.split('\n').filter(line => line.trim() && !line.trim().startsWith(comment));
+        const text = lines.join('\n');
+        const uses = construct => {
+            const escaped = construct.replace(/[.*+?^${}()|[\]\\]/g, '\\$&');
+            const edge = c => /\w/.test(c || '') ? '\\b' : '';
+            const pattern = edge(construct[0]) + escaped + edge(construct[construct.length - 1]);
+            return new RegExp(pattern, 'u').test(text);
+        };
+        const violations = [];
+        (requirements.must_use || []).filter(c => !uses(c)).forEach(c => violations.push(`must use \`${c}\``));
+        (requirements.must_not_use || []).filter(uses).forEach(c => violations.push(`must not use \`${c}\``));
+        if (requirements.max_lines != null && lines.length > requirements.max_lines) {
+            violations.push(`has ${lines.length} lines of code, more than ${requirements.max_lines}`);
+        }
+        return violations;
+    }
+
+    /**
+     * Build an execution protocol request (see docs/EXECUTION_PROTOCOL.md).
+     */
+    function buildExecutionRequest(button, userCode, testCode, locked, otherFiles) {
+        let dependencies = [];
+        try {
+            dependencies = JSON.parse(button.dataset.dependencies || '[]');
+        } catch (e) {
+            console.warn('Invalid data-dependencies:', e);
+        }
+        let ioTests = [];
+        try {
+            ioTests = JSON.parse(button.dataset.ioTests || '[]');
+        } catch (e) {
+            console.warn('Invalid data-io-tests:', e);
+        }
+        return {
+            version: 1,
+            exercise_id: button.dataset.exerciseId || null,
+            language: button.dataset.language || 'rust',
+            files: [{ path: button.dataset.filename || '', content: userCode }].concat(otherFiles || []),
+            tests: testCode,
+            io_tests: ioTests,
+            limits: { timeout_ms: 30000, max_output_bytes: 65536 },
+            toolchain: {
+                edition: button.dataset.edition || '2021',
+                channel: button.dataset.channel || 'stable',
+                build_mode: button.dataset.buildMode || 'debug',
+                crate_type: button.dataset.crateType || 'lib',
+                backtrace: button.dataset.backtrace === 'true',
+                lint: button.dataset.lint || null,
+                lint_level: button.dataset.lintLevel || 'warn',
+                format: button.dataset.format || null
+            },
+            dependencies: dependencies,
+            locked: locked || []
+        };
+    }
+
+    /**
+     * Execution backend adapters. Each takes a protocol request, the backend
+     * URL and the language's combine strategy, and resolves to a protocol
+     * response: { status, tests, stdout, stderr, duration_ms }.
+     */
+    const EXECUTION_ADAPTERS = {
+        /**
+         * A service that speaks the execution protocol directly.
+         */
+        protocol: async function(request, url) {
+            const response = await fetch(url, {
+                method: 'POST',
+                headers: {
+                    'Content-Type': 'application/json',
+                },
+                body: JSON.stringify(request)
+            });
+
+            if (!response.ok) {
+                throw new Error(`Test runner returned ${response.status}`);
+            }
+
+            return await response.json();
+        },
+
+        /**
+         * The Rust Playground: combines the code in the browser and maps the
+         * playground's { success, stdout, stderr } onto a protocol response.
+         * Input/output cases run the code once as a program; cases that need
+         * stdin or arguments can't run there and are reported as ignored.
+         * Lint and format checks use the Playground's `/clippy` and `/format`.
+         */
+        playground: async function(request, url, combine) {
+            // The Playground runs a single file, so other files are inlined as modules
+            const others = request.files.slice(1);
+            const used = new Set();
+            const main = inlineModules(request.files[0].content, others, '', used);
+            const code = combineCodeForTests(main, request.tests, combine);
+            const warnings = await missingPlaygroundCrates(request.dependencies, url)
+                .then(missing => missing.map(name => `The Rust Playground doesn't provide the \`${name}\` crate, so code using it won't compile there.`))
+                .catch(() => []);
+            others.filter(f => !used.has(f.path)).forEach(f => {
+                warnings.push(`\`${f.path}\` isn't used on the Rust Playground, which runs a single file.`);
+            });
+            const started = Date.now();
+            const ioTests = request.io_tests || [];
+            let response = null;
+            if (request.tests || ioTests.length === 0) {
+                const result = await runOnPlayground(code, url, request.toolchain);
+                let status = 'passed';
+                if (!result.success) {
+                    status = /could not compile/.test(result.stderr) ? 'compile_error' : 'failed';
+                }
+                response = {
+                    status: status,
+                    tests: status === 'compile_error' ? [] : parseLibtestOutput(result.stdout),
+                    stdout: result.stdout,
+                    stderr: result.stderr
+                };
+            }
+            if (ioTests.length > 0 && (!response || response.status !== 'compile_error')) {
+                const program = await runOnPlayground(main, url, request.toolchain, true);
+                const io = ioTestsResponse(ioTests, program);
+                response = response ? mergeResponses(response, io, 'io tests') : io;
+            }
+            const toolchain = request.toolchain || {};
+            if ((toolchain.lint || toolchain.format) && !['compile_error', 'timed_out'].includes(response.status)) {
+                const checks = await playgroundChecks(main, url, toolchain).catch(e => {
+                    warnings.push(`The code couldn't be checked with ${toolchain.lint || toolchain.format}: ${e.message}`);
+                    return { lints: [], format_diff: null };
+                });
+                response.lints = checks.lints;
+                response.format_diff = checks.format_diff;
+                const hasFindings = checks.lints.length > 0 || checks.format_diff;
+                if (toolchain.lint_level === 'deny' && hasFindings && response.status === 'passed') {
+                    response.status = 'failed';
+                }
+            }
+            response.duration_ms = Date.now() - started;
+            response.warnings = warnings;
+            return response;
+        }
+    };
+
+    /**
+     * Lint code with the Playground's `/clippy` and format it with `/format`,
+     * as the toolchain's `lint` and `format` settings ask. Resolves to
+     * { lints, format_diff } in the protocol's shape.
+     */
+    async function playgroundChecks(code, playgroundUrl, toolchain) {
+        const post = async (path, body) => {
+            const response = await fetch((playgroundUrl || PLAYGROUND_URL) + path, {
+                method: 'POST',
+                headers: {
+                    'Content-Type': 'application/json',
+                },
+                body: JSON.stringify(body)
+            });
+            if (!response.ok) {
+                throw new Error(`Playground returned ${response.status}`);
+            }
+            return response.json();
+        };
+        const channel = toolchain.channel || 'stable';
+        const edition = toolchain.edition || '2021';
+        const checks = { lints: [], format_diff: null };
+        if (toolchain.lint === 'clippy') {
+            const result = await post('/clippy', {
+                channel: channel,
+                edition: edition,
+                crateType: toolchain.crate_type || 'lib',
+                code: code
+            });
+            checks.lints = parseClippyWarnings(result.stderr || '');
+        }
+        if (toolchain.format === 'rustfmt') {
+            const result = await post('/format', { channel: channel, edition: edition, code: code });
+            const original = code.trimEnd() + '\n';
+            if (result.success && result.code && result.code !== original) {
+                checks.format_diff = lineDiff(original, result.code);
+            }
+        }
+        return checks;
+    }
+
+    /**
+     * Parse clippy's human-readable warnings into protocol lint records:
+     * { lint, message, line, rendered }. Warnings without a location (the
+     * "generated N warnings" summary) and rustc's own warnings, such as
+     * `dead_code` for helpers only the tests use, are skipped.
+     */
+    function parseClippyWarnings(stderr) {
+        // Deny-by-default lints (e.g. clippy::eq_op) are reported as errors
+        return stderr.split(/^(?=warning|error)/m)
+            .map(block => block.split('\n\n')[0].trimEnd())
+            .map(block => {
+                const location = /^\s*--> src\/(?:lib|main)\.rs:(\d+)/m.exec(block);
+                if (!location) return null;
+                // The `#[warn(...)]` note is only on a lint's first warning; the help link is on each
+                const name = /#\[(?:warn|deny)\((clippy::\w+)\)\]/.exec(block) || /rust-clippy\/[^\s#]*#(\w+)/.exec(block);
+                if (!name) return null;
+                return {
+                    lint: name[1].startsWith('clippy::') ? name[1] : 'clippy::' + name[1],
+                    message: block.split('\n')[0].replace(/^(?:warning|error)(\[\w+\])?: /, ''),
+                    line: parseInt(location[1], 10),
+                    rendered: block
+                };
+            })
+            .filter(Boolean);
+    }
+
+    /**
+     * A line diff of two texts, with `-`/`+`/` ` prefixes like rustfmt's
+     * `--check` output.
+     */
+    function lineDiff(before, after) {
+        const a = before.split('\n');
+        const b = after.split('\n');
+        // Longest common subsequence lengths of the suffixes
+        const lcs = a.map(() => new Array(b.length + 1).fill(0));
+        lcs.push(new Array(b.length + 1).fill(0));
+        for (let i = a.length - 1; i >= 0; i--) {
+            for (let j = b.length - 1; j >= 0; j--) {
+                lcs[i][j] = a[i] === b[j] ? lcs[i + 1][j + 1] + 1 : Math.max(lcs[i + 1][j], lcs[i][j + 1]);
+            }
+        }
+        const lines = [];
+        let i = 0;
+        let j = 0;
+        while (i < a.length || j < b.length) {
+            if (i < a.length && j < b.length && a[i] === b[j]) {
+                lines.push(' ' + a[i++]);
+                j++;
+            } else if (i < a.length && (j === b.length || lcs[i + 1][j] >= lcs[i][j + 1])) {
+                lines.push('-' + a[i++]);
+            } else {
+                lines.push('+' + b[j++]);
+            }
+        }
+        return lines.join('\n').trimEnd();
+    }
+
+    /**
+     * Check one Playground run of a program against input/output cases.
+     */
+    function ioTestsResponse(cases, program) {
+        if (!program.success && /could not compile/.test(program.stderr)) {
+            return { status: 'compile_error', tests: [], stdout: program.stdout, stderr: program.stderr };
+        }
+        const tests = cases.map(testCase => {
+            const test = { name: testCase.name, status: 'passed', message: null, assertion: null };
+            if (testCase.stdin || (testCase.args || []).length > 0) {
+                test.status = 'ignored';
+                test.message = 'Needs input, which the Rust Playground can\'t provide';
+            } else if (!program.success) {
+                test.status = 'failed';
+                test.message = compilerErrors(program.stderr) || 'The program exited with an error';
+            } else if (!ioCaseMatches(testCase, program.stdout)) {
+                test.status = 'failed';
+                test.message = testCase.match === 'regex'
+                    ? `output doesn't match /${testCase.expected_stdout}/`
+                    : 'output differs from the expected output';
+                test.assertion = { left: program.stdout, right: testCase.expected_stdout };
+            }
+            return test;
+        });
+        return {
+            status: tests.some(t => t.status === 'failed') ? 'failed' : 'passed',
+            tests: tests,
+            stdout: program.stdout,
+            stderr: program.stderr
+        };
+    }
+
+    /**
+     * Whether a program's output satisfies an input/output case, compared as
+     * its `match` says: `exact`, `trim` (the default) or `regex`.
+     */
+    function ioCaseMatches(testCase, stdout) {
+        const trim = text => text.trim().split(/\r?\n/).map(line => line.trimEnd()).join('\n');
+        if (testCase.match === 'exact') return stdout === testCase.expected_stdout;
+        if (testCase.match === 'regex') {
+            try {
+                return new RegExp(testCase.expected_stdout).test(stdout.trim());
+            } catch (e) {
+                return false;
+            }
+        }
+        return trim(stdout) === trim(testCase.expected_stdout);
+    }
+
+    /**
+     * Combine two protocol responses: the worse status, all tests, and the
+     * second response's output after a `--- label ---` line.
+     */
+    function mergeResponses(first, second, label) {
+        const rank = ['passed', 'failed', 'timed_out', 'compile_error'];
+        const join = (a, b) => b ? (a ? `${a}\n--- ${label} ---\n${b}` : b) : a;
+        return {
+            status: rank.indexOf(second.status) > rank.indexOf(first.status) ? second.status : first.status,
+            tests: first.tests.concat(second.tests),
+            stdout: join(first.stdout, second.stdout),
+            stderr: join(first.stderr, second.stderr)
+        };
+    }
+
+    /**
+     * Replace `mod name;` declarations with the contents of `src/<dir>name.rs`
+     * (or `src/<dir>name/mod.rs`) from `files`, recording inlined paths in `used`.
+     */
+    function inlineModules(code, files, dir, used) {
+        const modDecl = /^([ \t]*)((?:pub(?:\([^)]*\))?\s+)?mod\s+([A-Za-z_][A-Za-z0-9_]*))\s*;/gm;
+        return code.replace(modDecl, (match, indent, decl, name) => {
+            const base = 'src/' + dir + name;
+            const file = files.find(f => f.path === base + '.rs' || f.path === base + '/mod.rs');
+            if (!file || used.has(file.path)) return match;
+            used.add(file.path);
+            const inner = inlineModules(file.content, files, dir + name + '/', used);
+            return `${indent}${decl} {\n${inner}\n${indent}}`;
+        });
+    }
+
     /**
      * Combine user code and test code for Playground execution.
+     *
+     * `strategy` comes from the language's `combine` setting: "rust" drops
+     * the test code's `fn main` when both define one, "append" just appends.
      */
-    function combineCodeForTests(userCode, testCode) {
+    function combineCodeForTests(userCode, testCode, strategy) {
         // If the test code includes #[cfg(test)], we need to handle it specially
         // Otherwise, just combine them
 
         // Remove any existing main function from user code if tests have their own
         let combined = userCode;
 
-        if (testCode) {
+        if (testCode && strategy === 'append') {
+            combined = userCode + '\n\n' + testCode;
+        } else if (testCode) {
             // Check if user code has a main function
             const hasMain = /fn\s+main\s*\(/.test(userCode);
             const testHasMain = /fn\s+main\s*\(/.test(testCode);
@@ -359,22 +725,25 @@
     }
 
     /**
-     * Run code on the Rust Playground.
+     * Run code on the Rust Playground (or the configured `playground_url`)
+     * with the exercise's toolchain settings: its tests, or with `asProgram`
+     * its `main` as a binary.
      */
-    async function runOnPlayground(code) {
-        const response = await fetch(PLAYGROUND_URL + '/execute', {
+    async function runOnPlayground(code, playgroundUrl, toolchain, asProgram) {
+        toolchain = toolchain || {};
+        const response = await fetch((playgroundUrl || PLAYGROUND_URL) + '/execute', {
             method: 'POST',
             headers: {
                 'Content-Type': 'application/json',
             },
             body: JSON.stringify({
-                channel: 'stable',
-                mode: 'debug',
-                edition: '2021',
-                crateType: 'lib',
-                tests: true,
+                channel: toolchain.channel || 'stable',
+                mode: toolchain.build_mode || 'debug',
+                edition: toolchain.edition || '2021',
+                crateType: asProgram ? 'bin' : (toolchain.crate_type || 'lib'),
+                tests: !asProgram,
                 code: code,
-                backtrace: false
+                backtrace: !!toolchain.backtrace
             })
         });
 
@@ -386,11 +755,226 @@
 
         return {
             success: result.success,
-            output: result.stdout + result.stderr,
-            error: result.stderr
+            stdout: result.stdout || '',
+            stderr: result.stderr || ''
         };
     }
 
+    /**
+     * Names of declared dependencies missing from the Playground's crate list.
+     */
+    const playgroundCrates = {};
+    async function missingPlaygroundCrates(dependencies, playgroundUrl) {
+        if (!dependencies || dependencies.length === 0) return [];
+        const base = playgroundUrl || PLAYGROUND_URL;
+        if (!playgroundCrates[base]) {
+            playgroundCrates[base] = fetch(base + '/meta/crates')
+                .then(response => response.ok ? response.json() : { crates: [] })
+                .then(meta => new Set((meta.crates || []).map(c => (c.id || c.name).replace(/-/g, '_'))));
+        }
+        const available = await playgroundCrates[base];
+        return dependencies
+            .map(dep => dep.name)
+            .filter(name => available.size > 0 && !available.has(name.replace(/-/g, '_')));
+    }
+
+    /**
+     * Parse libtest's human-readable output into protocol test records:
+     * { name, status, message, assertion: { left, right } | null }.
+     */
+    function parseLibtestOutput(stdout) {
+        const tests = [];
+        const byName = {};
+        (stdout || '').split('\n').forEach(line => {
+            const match = /^test (.+) \.\.\. (ok|FAILED|ignored.*)$/.exec(line);
+            if (!match) return;
+            const status = match[2] === 'ok' ? 'passed' : match[2] === 'FAILED' ? 'failed' : 'ignored';
+            const test = { name: match[1].trim(), status: status, message: null, assertion: null };
+            byName[test.name] = test;
+            tests.push(test);
+        });
+
+        // Failure sections: "---- name stdout ----" followed by captured output
+        let current = null;
+        let lines = [];
+        const flush = () => {
+            const test = current && byName[current];
+            const message = lines.join('\n').trim();
+            if (test && message) {
+                test.message = message;
+                test.assertion = parseAssertion(message);
+            }
+            current = null;
+            lines = [];
+        };
+        (stdout || '').split('\n').forEach(line => {
+            const section = /^---- (.+) stdout ----$/.exec(line);
+            if (section) {
+                flush();
+                current = section[1];
+            } else if (line === 'failures:' || line.startsWith('test result:')) {
+                flush();
+            } else if (current) {
+                lines.push(line);
+            }
+        });
+        flush();
+        return tests;
+    }
+
+    /**
+     * Extract the left/right values of a failed assert_eq!/assert_ne!.
+     */
+    function parseAssertion(message) {
+        const left = /^\s*left:\s?(.*)$/m.exec(message || '');
+        const right = /^\s*right:\s?(.*)$/m.exec(message || '');
+        return left && right ? { left: left[1], right: right[1] } : null;
+    }
+
+    /**
+     * The panic message of a failed test, without the "thread ... panicked at"
+     * location line, the assertion values and the backtrace note.
+     */
+    function panicMessage(message) {
+        return (message || '')
+            .split('\n')
+            .filter(line => !/^thread '.*' (\(\d+\) )?panicked at/.test(line))
+            .filter(line => !/^\s*(left|right):/.test(line))
+            .filter(line => !/^note: run with `RUST_BACKTRACE/.test(line))
+            .join('\n')
+            .trim();
+    }
+
+    /**
+     * Compiler diagnostics from cargo's stderr, without progress lines.
+     */
+    function compilerErrors(stderr) {
+        return (stderr || '')
+            .split('\n')
+            .filter(line => !/^\s*(Compiling|Finished|Running|Updating|Downloaded?|Blocking)\b/.test(line))
+            .join('\n')
+            .trim();
+    }
+
+    /**
+     * Show a protocol response as a summary, a per-test checklist, compiler
+     * errors (kept apart from test failures) and the raw output. For a
+     * compile-fail exercise, `errorCodes` are the rustc errors the starter is
+     * expected to fail with.
+     */
+    function renderTestResults(resultsEl, result, errorCodes = []) {
+        resultsEl.textContent = '';
+        const el = (tag, className, text) => {
+            const node = document.createElement(tag);
+            if (className) node.className = className;
+            if (text !== undefined) node.textContent = text;
+            return node;
+        };
+        const tests = result.tests || [];
+        const count = status => tests.filter(t => t.status === status).length;
+
+        const expected = errorCodes.filter(code => (result.stderr || '').includes(`error[${code}]`));
+
+        let summary;
+        if (result.status === 'compile_error' && expected.length > 0) {
+            summary = `Still failing with ${expected.map(code => `error[${code}]`).join(', ')}, ` +
+                'the error this exercise is about. Fix it, then run again.';
+        } else if (result.status === 'compile_error') {
+            summary = 'Your code does not compile yet. Fix the errors below, then run the tests again.';
+        } else if (result.status === 'timed_out') {
+            summary = 'The tests took too long and were stopped. Check for infinite loops.';
+        } else if (tests.length > 0) {
+            summary = `${count('passed')} passed, ${count('failed')} failed` +
+                (count('ignored') ? `, ${count('ignored')} ignored` : '');
+        } else {
+            summary = result.status !== 'passed' ? 'Tests failed' :
+                errorCodes.length > 0 ? 'Your code compiles now!' : 'All tests passed!';
+        }
+        resultsEl.appendChild(el('p', 'test-summary', summary));
+
+        const lints = result.lints || [];
+        const hasFindings = lints.length > 0 || !!result.format_diff;
+        if (hasFindings && result.status === 'failed' && count('failed') === 0) {
+            resultsEl.appendChild(el('p', 'test-warning', 'The tests pass, but this exercise also needs code without lint warnings that is formatted with rustfmt.'));
+        }
+
+        (result.warnings || []).forEach(warning => {
+            resultsEl.appendChild(el('p', 'test-warning', '⚠ ' + warning));
+        });
+
+        if (result.status === 'compile_error') {
+            const errors = el('div', 'compile-errors');
+            errors.appendChild(el('h4', null, 'Compiler errors'));
+            errors.appendChild(el('pre', null, compilerErrors(result.stderr)));
+            expected.forEach(code => {
+                errors.appendChild(el('p', 'expected-error', `error[${code}] is the expected error for this exercise.`));
+            });
+            resultsEl.appendChild(errors);
+        }
+
+        if (tests.length > 0) {
+            const icons = { passed: '✓', failed: '✗', ignored: '○' };
+            const list = el('ul', 'test-checklist');
+            tests.forEach(test => {
+                const item = el('li', `test-case ${test.status}` + (test.hidden ? ' hidden-test' : ''));
+                item.appendChild(el('span', 'test-icon', icons[test.status] || '?'));
+                item.appendChild(el('span', 'test-name', test.name));
+                if (test.hidden) item.appendChild(el('span', 'test-hidden-badge', '🔒 hidden'));
+                if (test.status === 'failed') {
+                    const failure = el('div', 'test-failure');
+                    const message = panicMessage(test.message);
+                    if (message) failure.appendChild(el('pre', 'test-message', message));
+                    if (test.assertion) {
+                        const values = el('dl', 'assertion-values');
+                        values.appendChild(el('dt', null, 'left'));
+                        values.appendChild(el('dd', null, test.assertion.left));
+                        values.appendChild(el('dt', null, 'right'));
+                        values.appendChild(el('dd', null, test.assertion.right));
+                        failure.appendChild(values);
+                    }
+                    if (failure.childNodes.length) item.appendChild(failure);
+                }
+                list.appendChild(item);
+            });
+            resultsEl.appendChild(list);
+        }
+
+        if (hasFindings) {
+            const checks = el('div', 'code-checks');
+            if (lints.length > 0) {
+                checks.appendChild(el('h4', null, `Lint warnings (${lints.length})`));
+                const list = el('ul', 'lint-warnings');
+                lints.forEach(lint => {
+                    const item = el('li', 'lint-warning');
+                    item.appendChild(el('span', 'lint-message', (lint.line ? `Line ${lint.line}: ` : '') + lint.message));
+                    if (lint.lint) item.appendChild(el('code', 'lint-name', lint.lint));
+                    if (lint.rendered) {
+                        const details = el('details');
+                        details.appendChild(el('summary', null, 'Details'));
+                        details.appendChild(el('pre', null, lint.rendered));
+                        item.appendChild(details);
+                    }
+                    list.appendChild(item);
+                });
+                checks.appendChild(list);
+            }
+            if (result.format_diff) {
+                checks.appendChild(el('h4', null, 'Formatting'));
+                checks.appendChild(el('p', null, 'rustfmt would change your code:'));
+                checks.appendChild(el('pre', 'format-diff', result.format_diff));
+            }
+            resultsEl.appendChild(checks);
+        }
+
+        const output = [result.stdout, result.stderr].filter(Boolean).join('\n').trim();
+        if (output) {
+            const raw = el('details', 'raw-output');
+            raw.appendChild(el('summary', null, 'Full output'));
+            raw.appendChild(el('pre', null, output));
+            resultsEl.appendChild(raw);
+        }
+    }
+
     // ============================================ 
     // UseCase Exercise Word Count
     // ============================================ 
@@ -469,6 +1053,299 @@
         });
     }
 
+    // ============================================ 
+    // Parsons Problems
+    // ============================================ 
+
+    /**
+     * The lines of a Parsons problem's answer, as `{ code, indent }`.
+     */
+    function parsonsAnswer(section) {
+        return Array.from(section.querySelectorAll('.parsons-answer .parsons-line'))
+            .map(li => ({ code: li.dataset.code, indent: parseInt(li.dataset.indent || '0', 10) }));
+    }
+
+    /**
+     * The code the student has put together. Mirrors `ParsonsProblem::assemble`.
+     */
+    function parsonsCode(section) {
+        const unit = section.dataset.indentUnit || '    ';
+        return parsonsAnswer(section).map(line => unit.repeat(line.indent) + line.code).join('\n');
+    }
+
+    function setParsonsIndent(li, indent) {
+        const level = Math.max(0, Math.min(indent, 12));
+        li.dataset.indent = String(level);
+        li.style.setProperty('--indent', level);
+    }
+
+    function initParsons() {
+        document.querySelectorAll('.exercise-parsons').forEach(section => {
+            const source = section.querySelector('.parsons-source');
+            const answer = section.querySelector('.parsons-answer');
+            const feedback = section.querySelector('.parsons-feedback');
+            const settable = section.dataset.indent === 'true';
+            const lines = Array.from(section.querySelectorAll('.parsons-line'));
+            let dragged = null;
+            let grabOffset = 0;
+
+            const clearMarks = () => {
+                lines.forEach(li => li.classList.remove('correct', 'incorrect'));
+                if (feedback) feedback.hidden = true;
+            };
+
+            // The line a line dropped at height `y` goes before
+            const lineBelow = (list, y) => Array.from(list.querySelectorAll('.parsons-line:not(.dragging)'))
+                .find(li => {
+                    const box = li.getBoundingClientRect();
+                    return y < box.top + box.height / 2;
+                }) || null;
+
+            lines.forEach(li => {
+                li.addEventListener('dragstart', e => {
+                    dragged = li;
+                    grabOffset = e.clientX - li.getBoundingClientRect().left;
+                    li.classList.add('dragging');
+                    e.dataTransfer.effectAllowed = 'move';
+                    e.dataTransfer.setData('text/plain', li.dataset.code);
+                });
+                li.addEventListener('dragend', () => {
+                    li.classList.remove('dragging');
+                    dragged = null;
+                });
+                li.addEventListener('keydown', e => {
+                    const list = li.parentElement;
+                    if (e.key === 'Enter' || e.key === ' ') {
+                        e.preventDefault();
+                        const target = list === answer ? source : answer;
+                        target.appendChild(li);
+                        if (settable && target === source) setParsonsIndent(li, 0);
+                    } else if (e.altKey && e.key === 'ArrowUp' && li.previousElementSibling) {
+                        e.preventDefault();
+                        list.insertBefore(li, li.previousElementSibling);
+                    } else if (e.altKey && e.key === 'ArrowDown' && li.nextElementSibling) {
+                        e.preventDefault();
+                        list.insertBefore(li.nextElementSibling, li);
+                    } else if (e.key === 'ArrowUp' || e.key === 'ArrowDown') {
+                        e.preventDefault();
+                        (e.key === 'ArrowUp' ? li.previousElementSibling : li.nextElementSibling)?.focus();
+                        return;
+                    } else if (settable && list === answer && (e.key === 'ArrowLeft' || e.key === 'ArrowRight')) {
+                        e.preventDefault();
+                        setParsonsIndent(li, parseInt(li.dataset.indent || '0', 10) + (e.key === 'ArrowRight' ? 1 : -1));
+                    } else {
+                        return;
+                    }
+                    li.focus();
+                    clearMarks();
+                });
+            });
+
+            [source, answer].forEach(list => {
+                list.addEventListener('dragover', e => {
+                    if (dragged) e.preventDefault();
+                });
+                list.addEventListener('drop', e => {
+                    if (!dragged) return;
+                    e.preventDefault();
+                    list.insertBefore(dragged, lineBelow(list, e.clientY));
+                    if (settable) {
+                        // Where the line's left edge is dropped sets its indentation, one level per 2em
+                        const em = parseFloat(getComputedStyle(list).fontSize) || 16;
+                        const left = e.clientX - grabOffset - list.getBoundingClientRect().left;
+                        setParsonsIndent(dragged, list === answer ? Math.round(left / (2 * em)) : 0);
+                    }
+                    clearMarks();
+                });
+            });
+
+            section.querySelector('.btn-check-parsons')?.addEventListener('click', function() {
+                let solution = [];
+                try {
+                    solution = JSON.parse(section.dataset.solution || '[]');
+                } catch (e) {
+                    console.warn('Invalid data-solution:', e);
+                }
+                const given = Array.from(answer.querySelectorAll('.parsons-line'));
+                given.forEach((li, i) => {
+                    const expected = solution[i];
+                    const right = !!expected && li.dataset.code === expected.code &&
+                        (!settable || parseInt(li.dataset.indent || '0', 10) === expected.indent);
+                    li.classList.toggle('correct', right);
+                    li.classList.toggle('incorrect', !right);
+                });
+                const passed = given.length === solution.length && !given.some(li => li.classList.contains('incorrect'));
+
+                let message;
+                if (passed) {
+                    const runBtn = section.closest('.exercise')?.querySelector('.btn-run-tests');
+                    message = '✓ Correct!' + (runBtn ? ' Run the tests to see it work.' : '');
+                } else if (given.some(li => !solution.some(line => line.code === li.dataset.code))) {
+                    message = 'Some of your lines don\'t belong in the solution.';
+                } else if (given.length < solution.length) {
+                    message = `Your solution has ${given.length} of ${solution.length} lines.`;
+                } else {
+                    message = 'The highlighted lines are in the wrong place' + (settable ? ' or wrongly indented.' : '.');
+                }
+                if (feedback) {
+                    feedback.hidden = false;
+                    feedback.textContent = message;
+                    feedback.classList.toggle('success', passed);
+                    feedback.classList.toggle('error', !passed);
+                }
+                showNotification(passed ? 'Correct order!' : 'Not quite yet', passed ? 'success' : 'error');
+            });
+
+            section.querySelector('.btn-reset-parsons')?.addEventListener('click', function() {
+                lines.forEach(li => {
+                    source.appendChild(li);
+                    if (settable) setParsonsIndent(li, 0);
+                });
+                clearMarks();
+            });
+        });
+    }
+
+    // ============================================ 
+    // Quiz Exercises
+    // ============================================ 
+
+    /**
+     * Shuffle the children of `parent` matching `selector` in place.
+     */
+    function shuffleChildren(parent, selector) {
+        const items = Array.from(parent.querySelectorAll(':scope > ' + selector));
+        for (let i = items.length - 1; i > 0; i--) {
+            const j = Math.floor(Math.random() * (i + 1));
+            [items[i], items[j]] = [items[j], items[i]];
+        }
+        items.forEach(item => parent.appendChild(item));
+    }
+
+    /**
+     * Whether a question is answered correctly. Mirrors
+     * `QuizQuestion::check_choices` and `QuizQuestion::check_answer`.
+     */
+    function quizQuestionCorrect(question) {
+        if (question.dataset.type === 'short') {
+            const input = question.querySelector('.quiz-answer');
+            const answer = (input ? input.value : '').trim();
+            const caseSensitive = question.dataset.caseSensitive === 'true';
+            let answers = [];
+            try {
+                answers = JSON.parse(question.dataset.answers || '[]');
+            } catch (e) {
+                console.warn('Invalid data-answers:', e);
+            }
+            return answers.some(expected => {
+                if (question.dataset.match === 'regex') {
+                    try {
+                        return new RegExp(`^(?:${expected})$`, caseSensitive ? '' : 'i').test(answer);
+                    } catch (e) {
+                        return false;
+                    }
+                }
+                return caseSensitive
+                    ? answer === expected.trim()
+                    : answer.toLowerCase() === expected.trim().toLowerCase();
+            });
+        }
+        return Array.from(question.querySelectorAll('.quiz-option')).every(option => {
+            const chosen = option.querySelector('input').checked;
+            return chosen === (option.dataset.correct === 'true');
+        });
+    }
+
+    function initQuizzes() {
+        document.querySelectorAll('.quiz-exercise').forEach(article => {
+            const questionsEl = article.querySelector('.quiz-questions');
+            const questions = Array.from(article.querySelectorAll('.quiz-question'));
+            const checkBtn = article.querySelector('.btn-check-quiz');
+            const retryBtn = article.querySelector('.btn-retry-quiz');
+            const resultEl = article.querySelector('.quiz-result');
+            const passMark = parseFloat(article.dataset.passMark || '1');
+
+            if (article.dataset.shuffle === 'true' && questionsEl) {
+                shuffleChildren(questionsEl, '.quiz-question');
+                // Number the questions in their new order
+                questionsEl.querySelectorAll(':scope > .quiz-question').forEach((question, i) => {
+                    const legend = question.querySelector('.question-number');
+                    if (legend) legend.textContent = `Question ${i + 1}`;
+                });
+            }
+            questions.forEach(question => {
+                const options = question.querySelector('.quiz-options');
+                if (options && question.dataset.shuffle === 'true') {
+                    shuffleChildren(options, '.quiz-option');
+                }
+            });
+
+            const setLocked = locked => {
+                questions.forEach(q => q.querySelectorAll('input').forEach(input => { input.disabled = locked; }));
+                if (checkBtn) checkBtn.hidden = locked;
+                if (retryBtn) retryBtn.hidden = !locked;
+            };
+
+            checkBtn?.addEventListener('click', function() {
+                let correct = 0;
+                questions.forEach(question => {
+                    const right = quizQuestionCorrect(question);
+                    if (right) correct++;
+                    question.classList.toggle('correct', right);
+                    question.classList.toggle('incorrect', !right);
+                    const feedback = question.querySelector('.question-feedback');
+                    if (feedback) {
+                        feedback.hidden = false;
+                        feedback.textContent = right ? '✓ Correct' : '✗ Not quite';
+                    }
+                    // Explain the options the student chose, without revealing the others
+                    question.querySelectorAll('.quiz-option').forEach(option => {
+                        const chosen = option.querySelector('input').checked;
+                        option.classList.toggle('chosen-correct', chosen && option.dataset.correct === 'true');
+                        option.classList.toggle('chosen-incorrect', chosen && option.dataset.correct !== 'true');
+                        const explanation = option.querySelector('.option-explanation');
+                        if (explanation) explanation.hidden = !chosen;
+                    });
+                    const explanation = question.querySelector('.question-explanation');
+                    if (explanation) explanation.hidden = !right;
+                });
+
+                const score = questions.length ? correct / questions.length : 1;
+                const passed = score + 1e-9 >= passMark;
+                if (resultEl) {
+                    resultEl.hidden = false;
+                    resultEl.classList.toggle('success', passed);
+                    resultEl.classList.toggle('error', !passed);
+                    resultEl.textContent = `${correct} of ${questions.length} correct (${Math.round(score * 100)}%). ` +
+                        (passed ? 'Passed!' : `You need ${Math.round(passMark * 100)}% to pass.`);
+                }
+                setLocked(true);
+
+                const exerciseId = article.dataset.exerciseId;
+                if (passed && exerciseId) {
+                    const current = getProgress();
+                    current[exerciseId] = { completed: true, timestamp: new Date().toISOString(), score: score };
+                    saveProgress(current);
+                    article.classList.add('completed');
+                    showNotification('Quiz passed!', 'success');
+                }
+            });
+
+            retryBtn?.addEventListener('click', function() {
+                questions.forEach(question => {
+                    question.classList.remove('correct', 'incorrect');
+                    question.querySelectorAll('input[type="radio"], input[type="checkbox"]').forEach(input => { input.checked = false; });
+                    const answer = question.querySelector('.quiz-answer');
+                    if (answer) answer.value = '';
+                    question.querySelectorAll('.quiz-option').forEach(option => option.classList.remove('chosen-correct', 'chosen-incorrect'));
+                    question.querySelectorAll('.question-feedback, .question-explanation, .option-explanation').forEach(el => { el.hidden = true; });
+                });
+                if (resultEl) resultEl.hidden = true;
+                setLocked(false);
+            });
+        });
+    }
+
     // ============================================ 
     // Editable Code (make code blocks editable)
     // ============================================ 
@@ -577,6 +1454,111 @@
         });
     }
 
+    // ============================================ 
+    // Tabs (starter files, alternative solutions, solution files)
+    // ============================================ 
+
+    function initTabs() {
+        document.querySelectorAll('.starter-tabs, .solution-tabs, .solution-file-tabs').forEach(tabList => {
+            const section = tabList.closest('section');
+            const tabs = Array.from(tabList.querySelectorAll('[role="tab"]'));
+            tabs.forEach(tab => {
+                tab.addEventListener('click', function() {
+                    tabs.forEach(other => {
+                        const selected = other === this;
+                        other.classList.toggle('active', selected);
+                        other.setAttribute('aria-selected', selected ? 'true' : 'false');
+                        const panel = document.getElementById(other.dataset.target + '-panel');
+                        if (panel) panel.hidden = !selected;
+                    });
+                    // Copy and Reset act on the visible starter file
+                    section.querySelectorAll('.btn-copy, .btn-reset').forEach(button => {
+                        button.dataset.target = this.dataset.target;
+                    });
+                });
+            });
+        });
+    }
+
+    // ============================================ 
+    // Locked Lines
+    // ============================================ 
+
+    function lockedLineNumbers(textarea) {
+        return (textarea.dataset.lockedLines || '')
+            .split(',')
+            .filter(n => n !== '')
+            .map(Number);
+    }
+
+    function lineAt(text, offset) {
+        return text.slice(0, offset).split('\n').length - 1;
+    }
+
+    /**
+     * Text of each run of consecutive locked lines in the original code.
+     */
+    function lockedRegions(textarea) {
+        const lines = (textarea.defaultLockedText || '').split('\n');
+        const regions = [];
+        let previous = -2;
+        lockedLineNumbers(textarea).filter(n => n < lines.length).forEach(n => {
+            if (n === previous + 1) {
+                regions[regions.length - 1] += '\n' + lines[n];
+            } else {
+                regions.push(lines[n]);
+            }
+            previous = n;
+        });
+        return regions;
+    }
+
+    /**
+     * Stop edits that touch locked lines, and keep track of where the locked
+     * lines move as the student adds or removes lines around them.
+     */
+    function initLockedLines() {
+        document.querySelectorAll('textarea.code-editor[data-locked-lines]').forEach(textarea => {
+            textarea.defaultLockedText = textarea.value;
+            let locked = lockedLineNumbers(textarea);
+            let pending = null;
+
+            textarea.addEventListener('beforeinput', function(e) {
+                let start = this.selectionStart;
+                let end = this.selectionEnd;
+                if (start === end && e.inputType.startsWith('delete')) {
+                    if (e.inputType.includes('Backward')) {
+                        start = Math.max(0, start - 1);
+                    } else {
+                        end = Math.min(this.value.length, end + 1);
+                    }
+                }
+                const first = lineAt(this.value, start);
+                const last = lineAt(this.value, end);
+                // A new line may still be started at the end of a locked line
+                const atLineEnd = start === end && (end === this.value.length || this.value[end] === '\n');
+                const newLine = e.inputType === 'insertLineBreak' && atLineEnd;
+                if (!newLine && locked.some(n => n >= first && n <= last)) {
+                    e.preventDefault();
+                    showNotification('This line is locked', 'info');
+                    return;
+                }
+                pending = { line: first, count: this.value.split('\n').length };
+            });
+
+            textarea.addEventListener('input', function() {
+                if (!pending) return;
+                const delta = this.value.split('\n').length - pending.count;
+                locked = locked.map(n => (n > pending.line ? n + delta : n));
+                pending = null;
+            });
+
+            textarea.addEventListener('exercise:reset', function() {
+                locked = lockedLineNumbers(this);
+            });
+        });
+    }
+
     // ============================================ 
     // Section Navigation Highlighting
     // ============================================ 
@@ -637,6 +1619,8 @@
     function init() {
         addAnimationStyles();
         initTextareaContent();
+        initLockedLines();
+        initTabs();
         initCopyButtons();
         initResetButtons();
         initSolutionToggles();
@@ -647,6 +1631,8 @@
         initNavHighlighting();
         initWordCount(); // New
         initUseCaseSubmission(); // New
+        initParsons();
+        initQuizzes();
         applySyntaxHighlighting();
 
         console.log('mdbook-exercises initialized');
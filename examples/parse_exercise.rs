@@ -29,6 +29,9 @@ fn main() {}
         Ok(ParsedExercise::UseCase(exercise)) => {
             println!("Parsed usecase exercise: {}", exercise.metadata.id);
         }
+        Ok(ParsedExercise::Quiz(exercise)) => {
+            println!("Parsed quiz: {} ({} questions)", exercise.metadata.id, exercise.questions.len());
+        }
         Err(e) => eprintln!("Error: {}", e),
     }
 }
@@ -30,6 +30,7 @@ fn main() {
     let (id, title_opt) = match &parsed {
         ParsedExercise::Code(ex) => (ex.metadata.id.clone(), ex.title.clone()),
         ParsedExercise::UseCase(ex) => (ex.metadata.id.clone(), ex.title.clone()),
+        ParsedExercise::Quiz(ex) => (ex.metadata.id.clone(), ex.title.clone()),
     };
 
     println!("Exercise ID: {}", id);
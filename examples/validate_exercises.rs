@@ -1,4 +1,31 @@
+use mdbook_exercises::collect::{collect_markdown_files, load_exercise_files};
+use mdbook_exercises::validate::validate_files;
+use std::path::PathBuf;
+
 fn main() {
-    // Placeholder for validation logic
-    println!("Validation example");
+    let paths: Vec<PathBuf> = std::env::args().skip(1).map(PathBuf::from).collect();
+    let paths = if paths.is_empty() { vec![PathBuf::from("examples")] } else { paths };
+
+    let files = collect_markdown_files(&paths, None)
+        .and_then(|md| load_exercise_files(&md, &Default::default()))
+        .unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            std::process::exit(2);
+        });
+
+    let report = validate_files(&files);
+    for diagnostic in &report.diagnostics {
+        println!("{}", diagnostic);
+    }
+    println!(
+        "Checked {} files ({} exercises): {} errors, {} warnings",
+        report.files_checked,
+        report.exercises,
+        report.error_count(),
+        report.warning_count()
+    );
+
+    if report.error_count() > 0 {
+        std::process::exit(1);
+    }
 }
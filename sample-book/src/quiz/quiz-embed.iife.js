@@ -1,226 +0,0 @@
-var pN=Object.defineProperty;var hN=(we,Dn,ei)=>Dn in we?pN(we,Dn,{enumerable:!0,configurable:!0,writable:!0,value:ei}):we[Dn]=ei;var dd=(we,Dn,ei)=>(hN(we,typeof Dn!="symbol"?Dn+"":Dn,ei),ei);(function(){"use strict";var we=typeof globalThis<"u"?globalThis:typeof window<"u"?window:typeof global<"u"?global:typeof self<"u"?self:{};function Dn(e){return e&&e.__esModule&&Object.prototype.hasOwnProperty.call(e,"default")?e.default:e}var ei={exports:{}},ma={},zg={exports:{}},Ie={};/**
- * @license React
- * react.production.min.js
- *
- * Copyright (c) Facebook, Inc. and its affiliates.
- *
- * This source code is licensed under the MIT license found in the
- * LICENSE file in the root directory of this source tree.
- */var Is=Symbol.for("react.element"),j3=Symbol.for("react.portal"),F3=Symbol.for("react.fragment"),V3=Symbol.for("react.strict_mode"),H3=Symbol.for("react.profiler"),G3=Symbol.for("react.provider"),W3=Symbol.for("react.context"),K3=Symbol.for("react.forward_ref"),Y3=Symbol.for("react.suspense"),Q3=Symbol.for("react.memo"),X3=Symbol.for("react.lazy"),$g=Symbol.iterator;function Z3(e){return e===null||typeof e!="object"?null:(e=$g&&e[$g]||e["@@iterator"],typeof e=="function"?e:null)}var Ug={isMounted:function(){return!1},enqueueForceUpdate:function(){},enqueueReplaceState:function(){},enqueueSetState:function(){}},jg=Object.assign,Fg={};function Co(e,t,n){this.props=e,this.context=t,this.refs=Fg,this.updater=n||Ug}Co.prototype.isReactComponent={},Co.prototype.setState=function(e,t){if(typeof e!="object"&&typeof e!="function"&&e!=null)throw Error("setState(...): takes an object of state variables to update or a function which returns an object of state variables.");this.updater.enqueueSetState(this,e,t,"setState")},Co.prototype.forceUpdate=function(e){this.updater.enqueueForceUpdate(this,e,"forceUpdate")};function Vg(){}Vg.prototype=Co.prototype;function fd(e,t,n){this.props=e,this.context=t,this.refs=Fg,this.updater=n||Ug}var pd=fd.prototype=new Vg;pd.constructor=fd,jg(pd,Co.prototype),pd.isPureReactComponent=!0;var Hg=Array.isArray,Gg=Object.prototype.hasOwnProperty,hd={current:null},Wg={key:!0,ref:!0,__self:!0,__source:!0};function Kg(e,t,n){var r,o={},u=null,c=null;if(t!=null)for(r in t.ref!==void 0&&(c=t.ref),t.key!==void 0&&(u=""+t.key),t)Gg.call(t,r)&&!Wg.hasOwnProperty(r)&&(o[r]=t[r]);var p=arguments.length-2;if(p===1)o.children=n;else if(1<p){for(var g=Array(p),b=0;b<p;b++)g[b]=arguments[b+2];o.children=g}if(e&&e.defaultProps)for(r in p=e.defaultProps,p)o[r]===void 0&&(o[r]=p[r]);return{$$typeof:Is,type:e,key:u,ref:c,props:o,_owner:hd.current}}function J3(e,t){return{$$typeof:Is,type:e.type,key:t,ref:e.ref,props:e.props,_owner:e._owner}}function gd(e){return typeof e=="object"&&e!==null&&e.$$typeof===Is}function e8(e){var t={"=":"=0",":":"=2"};return"$"+e.replace(/[=:]/g,function(n){return t[n]})}var Yg=/\/+/g;function md(e,t){return typeof e=="object"&&e!==null&&e.key!=null?e8(""+e.key):t.toString(36)}function va(e,t,n,r,o){var u=typeof e;(u==="undefined"||u==="boolean")&&(e=null);var c=!1;if(e===null)c=!0;else switch(u){case"string":case"number":c=!0;break;case"object":switch(e.$$typeof){case Is:case j3:c=!0}}if(c)return c=e,o=o(c),e=r===""?"."+md(c,0):r,Hg(o)?(n="",e!=null&&(n=e.replace(Yg,"$&/")+"/"),va(o,t,n,"",function(b){return b})):o!=null&&(gd(o)&&(o=J3(o,n+(!o.key||c&&c.key===o.key?"":(""+o.key).replace(Yg,"$&/")+"/")+e)),t.push(o)),1;if(c=0,r=r===""?".":r+":",Hg(e))for(var p=0;p<e.length;p++){u=e[p];var g=r+md(u,p);c+=va(u,t,n,g,o)}else if(g=Z3(e),typeof g=="function")for(e=g.call(e),p=0;!(u=e.next()).done;)u=u.value,g=r+md(u,p++),c+=va(u,t,n,g,o);else if(u==="object")throw t=String(e),Error("Objects are not valid as a React child (found: "+(t==="[object Object]"?"object with keys {"+Object.keys(e).join(", ")+"}":t)+"). If you meant to render a collection of children, use an array instead.");return c}function _a(e,t,n){if(e==null)return e;var r=[],o=0;return va(e,r,"","",function(u){return t.call(n,u,o++)}),r}function t8(e){if(e._status===-1){var t=e._result;t=t(),t.then(function(n){(e._status===0||e._status===-1)&&(e._status=1,e._result=n)},function(n){(e._status===0||e._status===-1)&&(e._status=2,e._result=n)}),e._status===-1&&(e._status=0,e._result=t)}if(e._status===1)return e._result.default;throw e._result}var Ht={current:null},ya={transition:null},n8={ReactCurrentDispatcher:Ht,ReactCurrentBatchConfig:ya,ReactCurrentOwner:hd};Ie.Children={map:_a,forEach:function(e,t,n){_a(e,function(){t.apply(this,arguments)},n)},count:function(e){var t=0;return _a(e,function(){t++}),t},toArray:function(e){return _a(e,function(t){return t})||[]},only:function(e){if(!gd(e))throw Error("React.Children.only expected to receive a single React element child.");return e}},Ie.Component=Co,Ie.Fragment=F3,Ie.Profiler=H3,Ie.PureComponent=fd,Ie.StrictMode=V3,Ie.Suspense=Y3,Ie.__SECRET_INTERNALS_DO_NOT_USE_OR_YOU_WILL_BE_FIRED=n8,Ie.cloneElement=function(e,t,n){if(e==null)throw Error("React.cloneElement(...): The argument must be a React element, but you passed "+e+".");var r=jg({},e.props),o=e.key,u=e.ref,c=e._owner;if(t!=null){if(t.ref!==void 0&&(u=t.ref,c=hd.current),t.key!==void 0&&(o=""+t.key),e.type&&e.type.defaultProps)var p=e.type.defaultProps;for(g in t)Gg.call(t,g)&&!Wg.hasOwnProperty(g)&&(r[g]=t[g]===void 0&&p!==void 0?p[g]:t[g])}var g=arguments.length-2;if(g===1)r.children=n;else if(1<g){p=Array(g);for(var b=0;b<g;b++)p[b]=arguments[b+2];r.children=p}return{$$typeof:Is,type:e.type,key:o,ref:u,props:r,_owner:c}},Ie.createContext=function(e){return e={$$typeof:W3,_currentValue:e,_currentValue2:e,_threadCount:0,Provider:null,Consumer:null,_defaultValue:null,_globalName:null},e.Provider={$$typeof:G3,_context:e},e.Consumer=e},Ie.createElement=Kg,Ie.createFactory=function(e){var t=Kg.bind(null,e);return t.type=e,t},Ie.createRef=function(){return{current:null}},Ie.forwardRef=function(e){return{$$typeof:K3,render:e}},Ie.isValidElement=gd,Ie.lazy=function(e){return{$$typeof:X3,_payload:{_status:-1,_result:e},_init:t8}},Ie.memo=function(e,t){return{$$typeof:Q3,type:e,compare:t===void 0?null:t}},Ie.startTransition=function(e){var t=ya.transition;ya.transition={};try{e()}finally{ya.transition=t}},Ie.unstable_act=function(){throw Error("act(...) is not supported in production builds of React.")},Ie.useCallback=function(e,t){return Ht.current.useCallback(e,t)},Ie.useContext=function(e){return Ht.current.useContext(e)},Ie.useDebugValue=function(){},Ie.useDeferredValue=function(e){return Ht.current.useDeferredValue(e)},Ie.useEffect=function(e,t){return Ht.current.useEffect(e,t)},Ie.useId=function(){return Ht.current.useId()},Ie.useImperativeHandle=function(e,t,n){return Ht.current.useImperativeHandle(e,t,n)},Ie.useInsertionEffect=function(e,t){return Ht.current.useInsertionEffect(e,t)},Ie.useLayoutEffect=function(e,t){return Ht.current.useLayoutEffect(e,t)},Ie.useMemo=function(e,t){return Ht.current.useMemo(e,t)},Ie.useReducer=function(e,t,n){return Ht.current.useReducer(e,t,n)},Ie.useRef=function(e){return Ht.current.useRef(e)},Ie.useState=function(e){return Ht.current.useState(e)},Ie.useSyncExternalStore=function(e,t,n){return Ht.current.useSyncExternalStore(e,t,n)},Ie.useTransition=function(){return Ht.current.useTransition()},Ie.version="18.2.0",zg.exports=Ie;var Ee=zg.exports;const K=Dn(Ee);/**
- * @license React
- * react-jsx-runtime.production.min.js
- *
- * Copyright (c) Facebook, Inc. and its affiliates.
- *
- * This source code is licensed under the MIT license found in the
- * LICENSE file in the root directory of this source tree.
- */var r8=Ee,i8=Symbol.for("react.element"),o8=Symbol.for("react.fragment"),s8=Object.prototype.hasOwnProperty,u8=r8.__SECRET_INTERNALS_DO_NOT_USE_OR_YOU_WILL_BE_FIRED.ReactCurrentOwner,a8={key:!0,ref:!0,__self:!0,__source:!0};function Qg(e,t,n){var r,o={},u=null,c=null;n!==void 0&&(u=""+n),t.key!==void 0&&(u=""+t.key),t.ref!==void 0&&(c=t.ref);for(r in t)s8.call(t,r)&&!a8.hasOwnProperty(r)&&(o[r]=t[r]);if(e&&e.defaultProps)for(r in t=e.defaultProps,t)o[r]===void 0&&(o[r]=t[r]);return{$$typeof:i8,type:e,key:u,ref:c,props:o,_owner:u8.current}}ma.Fragment=o8,ma.jsx=Qg,ma.jsxs=Qg,ei.exports=ma;var wa=ei.exports;const gN="";var Xg={exports:{}};/*!
-	Copyright (c) 2018 Jed Watson.
-	Licensed under the MIT License (MIT), see
-	http://jedwatson.github.io/classnames
-*/(function(e){(function(){var t={}.hasOwnProperty;function n(){for(var r=[],o=0;o<arguments.length;o++){var u=arguments[o];if(u){var c=typeof u;if(c==="string"||c==="number")r.push(u);else if(Array.isArray(u)){if(u.length){var p=n.apply(null,u);p&&r.push(p)}}else if(c==="object"){if(u.toString!==Object.prototype.toString&&!u.toString.toString().includes("[native code]")){r.push(u.toString());continue}for(var g in u)t.call(u,g)&&u[g]&&r.push(g)}}}return r.join(" ")}e.exports?(n.default=n,e.exports=n):window.classNames=n})()})(Xg);var l8=Xg.exports;const Ui=Dn(l8);var ba={exports:{}};/**
- * @license
- * Lodash <https://lodash.com/>
- * Copyright OpenJS Foundation and other contributors <https://openjsf.org/>
- * Released under MIT license <https://lodash.com/license>
- * Based on Underscore.js 1.8.3 <http://underscorejs.org/LICENSE>
- * Copyright Jeremy Ashkenas, DocumentCloud and Investigative Reporters & Editors
- */ba.exports,function(e,t){(function(){var n,r="4.17.21",o=200,u="Unsupported core-js use. Try https://npms.io/search?q=ponyfill.",c="Expected a function",p="Invalid `variable` option passed into `_.template`",g="__lodash_hash_undefined__",b=500,O="__lodash_placeholder__",P=1,q=2,Z=4,M=1,H=2,z=1,L=2,k=4,x=8,l=16,h=32,d=64,_=128,v=256,y=512,m=30,E="...",D=800,j=16,V=1,W=2,T=3,Q=1/0,G=9007199254740991,ee=17976931348623157e292,ie=0/0,le=4294967295,Oe=le-1,Xe=le>>>1,tt=[["ary",_],["bind",z],["bindKey",L],["curry",x],["curryRight",l],["flip",y],["partial",h],["partialRight",d],["rearg",v]],je="[object Arguments]",Me="[object Array]",Ze="[object AsyncFunction]",ce="[object Boolean]",A="[object Date]",R="[object DOMException]",$="[object Error]",se="[object Function]",ae="[object GeneratorFunction]",fe="[object Map]",I="[object Number]",Y="[object Null]",J="[object Object]",ve="[object Promise]",pe="[object Proxy]",oe="[object RegExp]",ge="[object Set]",Te="[object String]",Ve="[object Symbol]",lr="[object Undefined]",mo="[object WeakMap]",Ju="[object WeakSet]",Vr="[object ArrayBuffer]",Pi="[object DataView]",ws="[object Float32Array]",ea="[object Float64Array]",bs="[object Int8Array]",bh="[object Int16Array]",Sh="[object Int32Array]",Eh="[object Uint8Array]",kh="[object Uint8ClampedArray]",Ah="[object Uint16Array]",Ch="[object Uint32Array]",RT=/\b__p \+= '';/g,qT=/\b(__p \+=) '' \+/g,IT=/(__e\(.*?\)|\b__t\)) \+\n'';/g,p1=/&(?:amp|lt|gt|quot|#39);/g,h1=/[&<>"']/g,MT=RegExp(p1.source),BT=RegExp(h1.source),zT=/<%-([\s\S]+?)%>/g,$T=/<%([\s\S]+?)%>/g,g1=/<%=([\s\S]+?)%>/g,UT=/\.|\[(?:[^[\]]*|(["'])(?:(?!\1)[^\\]|\\.)*?\1)\]/,jT=/^\w*$/,FT=/[^.[\]]+|\[(?:(-?\d+(?:\.\d+)?)|(["'])((?:(?!\2)[^\\]|\\.)*?)\2)\]|(?=(?:\.|\[\])(?:\.|\[\]|$))/g,Th=/[\\^$.*+?()[\]{}|]/g,VT=RegExp(Th.source),xh=/^\s+/,HT=/\s/,GT=/\{(?:\n\/\* \[wrapped with .+\] \*\/)?\n?/,WT=/\{\n\/\* \[wrapped with (.+)\] \*/,KT=/,? & /,YT=/[^\x00-\x2f\x3a-\x40\x5b-\x60\x7b-\x7f]+/g,QT=/[()=,{}\[\]\/\s]/,XT=/\\(\\)?/g,ZT=/\$\{([^\\}]*(?:\\.[^\\}]*)*)\}/g,m1=/\w*$/,JT=/^[-+]0x[0-9a-f]+$/i,ex=/^0b[01]+$/i,tx=/^\[object .+?Constructor\]$/,nx=/^0o[0-7]+$/i,rx=/^(?:0|[1-9]\d*)$/,ix=/[\xc0-\xd6\xd8-\xf6\xf8-\xff\u0100-\u017f]/g,Ec=/($^)/,ox=/['\n\r\u2028\u2029\\]/g,kc="\\ud800-\\udfff",sx="\\u0300-\\u036f",ux="\\ufe20-\\ufe2f",ax="\\u20d0-\\u20ff",v1=sx+ux+ax,_1="\\u2700-\\u27bf",y1="a-z\\xdf-\\xf6\\xf8-\\xff",lx="\\xac\\xb1\\xd7\\xf7",cx="\\x00-\\x2f\\x3a-\\x40\\x5b-\\x60\\x7b-\\xbf",dx="\\u2000-\\u206f",fx=" \\t\\x0b\\f\\xa0\\ufeff\\n\\r\\u2028\\u2029\\u1680\\u180e\\u2000\\u2001\\u2002\\u2003\\u2004\\u2005\\u2006\\u2007\\u2008\\u2009\\u200a\\u202f\\u205f\\u3000",w1="A-Z\\xc0-\\xd6\\xd8-\\xde",b1="\\ufe0e\\ufe0f",S1=lx+cx+dx+fx,Lh="['’]",px="["+kc+"]",E1="["+S1+"]",Ac="["+v1+"]",k1="\\d+",hx="["+_1+"]",A1="["+y1+"]",C1="[^"+kc+S1+k1+_1+y1+w1+"]",Oh="\\ud83c[\\udffb-\\udfff]",gx="(?:"+Ac+"|"+Oh+")",T1="[^"+kc+"]",Ph="(?:\\ud83c[\\udde6-\\uddff]){2}",Dh="[\\ud800-\\udbff][\\udc00-\\udfff]",Ss="["+w1+"]",x1="\\u200d",L1="(?:"+A1+"|"+C1+")",mx="(?:"+Ss+"|"+C1+")",O1="(?:"+Lh+"(?:d|ll|m|re|s|t|ve))?",P1="(?:"+Lh+"(?:D|LL|M|RE|S|T|VE))?",D1=gx+"?",N1="["+b1+"]?",vx="(?:"+x1+"(?:"+[T1,Ph,Dh].join("|")+")"+N1+D1+")*",_x="\\d*(?:1st|2nd|3rd|(?![123])\\dth)(?=\\b|[A-Z_])",yx="\\d*(?:1ST|2ND|3RD|(?![123])\\dTH)(?=\\b|[a-z_])",R1=N1+D1+vx,wx="(?:"+[hx,Ph,Dh].join("|")+")"+R1,bx="(?:"+[T1+Ac+"?",Ac,Ph,Dh,px].join("|")+")",Sx=RegExp(Lh,"g"),Ex=RegExp(Ac,"g"),Nh=RegExp(Oh+"(?="+Oh+")|"+bx+R1,"g"),kx=RegExp([Ss+"?"+A1+"+"+O1+"(?="+[E1,Ss,"$"].join("|")+")",mx+"+"+P1+"(?="+[E1,Ss+L1,"$"].join("|")+")",Ss+"?"+L1+"+"+O1,Ss+"+"+P1,yx,_x,k1,wx].join("|"),"g"),Ax=RegExp("["+x1+kc+v1+b1+"]"),Cx=/[a-z][A-Z]|[A-Z]{2}[a-z]|[0-9][a-zA-Z]|[a-zA-Z][0-9]|[^a-zA-Z0-9 ]/,Tx=["Array","Buffer","DataView","Date","Error","Float32Array","Float64Array","Function","Int8Array","Int16Array","Int32Array","Map","Math","Object","Promise","RegExp","Set","String","Symbol","TypeError","Uint8Array","Uint8ClampedArray","Uint16Array","Uint32Array","WeakMap","_","clearTimeout","isFinite","parseInt","setTimeout"],xx=-1,ot={};ot[ws]=ot[ea]=ot[bs]=ot[bh]=ot[Sh]=ot[Eh]=ot[kh]=ot[Ah]=ot[Ch]=!0,ot[je]=ot[Me]=ot[Vr]=ot[ce]=ot[Pi]=ot[A]=ot[$]=ot[se]=ot[fe]=ot[I]=ot[J]=ot[oe]=ot[ge]=ot[Te]=ot[mo]=!1;var nt={};nt[je]=nt[Me]=nt[Vr]=nt[Pi]=nt[ce]=nt[A]=nt[ws]=nt[ea]=nt[bs]=nt[bh]=nt[Sh]=nt[fe]=nt[I]=nt[J]=nt[oe]=nt[ge]=nt[Te]=nt[Ve]=nt[Eh]=nt[kh]=nt[Ah]=nt[Ch]=!0,nt[$]=nt[se]=nt[mo]=!1;var Lx={À:"A",Á:"A",Â:"A",Ã:"A",Ä:"A",Å:"A",à:"a",á:"a",â:"a",ã:"a",ä:"a",å:"a",Ç:"C",ç:"c",Ð:"D",ð:"d",È:"E",É:"E",Ê:"E",Ë:"E",è:"e",é:"e",ê:"e",ë:"e",Ì:"I",Í:"I",Î:"I",Ï:"I",ì:"i",í:"i",î:"i",ï:"i",Ñ:"N",ñ:"n",Ò:"O",Ó:"O",Ô:"O",Õ:"O",Ö:"O",Ø:"O",ò:"o",ó:"o",ô:"o",õ:"o",ö:"o",ø:"o",Ù:"U",Ú:"U",Û:"U",Ü:"U",ù:"u",ú:"u",û:"u",ü:"u",Ý:"Y",ý:"y",ÿ:"y",Æ:"Ae",æ:"ae",Þ:"Th",þ:"th",ß:"ss",Ā:"A",Ă:"A",Ą:"A",ā:"a",ă:"a",ą:"a",Ć:"C",Ĉ:"C",Ċ:"C",Č:"C",ć:"c",ĉ:"c",ċ:"c",č:"c",Ď:"D",Đ:"D",ď:"d",đ:"d",Ē:"E",Ĕ:"E",Ė:"E",Ę:"E",Ě:"E",ē:"e",ĕ:"e",ė:"e",ę:"e",ě:"e",Ĝ:"G",Ğ:"G",Ġ:"G",Ģ:"G",ĝ:"g",ğ:"g",ġ:"g",ģ:"g",Ĥ:"H",Ħ:"H",ĥ:"h",ħ:"h",Ĩ:"I",Ī:"I",Ĭ:"I",Į:"I",İ:"I",ĩ:"i",ī:"i",ĭ:"i",į:"i",ı:"i",Ĵ:"J",ĵ:"j",Ķ:"K",ķ:"k",ĸ:"k",Ĺ:"L",Ļ:"L",Ľ:"L",Ŀ:"L",Ł:"L",ĺ:"l",ļ:"l",ľ:"l",ŀ:"l",ł:"l",Ń:"N",Ņ:"N",Ň:"N",Ŋ:"N",ń:"n",ņ:"n",ň:"n",ŋ:"n",Ō:"O",Ŏ:"O",Ő:"O",ō:"o",ŏ:"o",ő:"o",Ŕ:"R",Ŗ:"R",Ř:"R",ŕ:"r",ŗ:"r",ř:"r",Ś:"S",Ŝ:"S",Ş:"S",Š:"S",ś:"s",ŝ:"s",ş:"s",š:"s",Ţ:"T",Ť:"T",Ŧ:"T",ţ:"t",ť:"t",ŧ:"t",Ũ:"U",Ū:"U",Ŭ:"U",Ů:"U",Ű:"U",Ų:"U",ũ:"u",ū:"u",ŭ:"u",ů:"u",ű:"u",ų:"u",Ŵ:"W",ŵ:"w",Ŷ:"Y",ŷ:"y",Ÿ:"Y",Ź:"Z",Ż:"Z",Ž:"Z",ź:"z",ż:"z",ž:"z",Ĳ:"IJ",ĳ:"ij",Œ:"Oe",œ:"oe",ŉ:"'n",ſ:"s"},Ox={"&":"&amp;","<":"&lt;",">":"&gt;",'"':"&quot;","'":"&#39;"},Px={"&amp;":"&","&lt;":"<","&gt;":">","&quot;":'"',"&#39;":"'"},Dx={"\\":"\\","'":"'","\n":"n","\r":"r","\u2028":"u2028","\u2029":"u2029"},Nx=parseFloat,Rx=parseInt,q1=typeof we=="object"&&we&&we.Object===Object&&we,qx=typeof self=="object"&&self&&self.Object===Object&&self,Mt=q1||qx||Function("return this")(),Rh=t&&!t.nodeType&&t,vo=Rh&&!0&&e&&!e.nodeType&&e,I1=vo&&vo.exports===Rh,qh=I1&&q1.process,jn=function(){try{var U=vo&&vo.require&&vo.require("util").types;return U||qh&&qh.binding&&qh.binding("util")}catch{}}(),M1=jn&&jn.isArrayBuffer,B1=jn&&jn.isDate,z1=jn&&jn.isMap,$1=jn&&jn.isRegExp,U1=jn&&jn.isSet,j1=jn&&jn.isTypedArray;function Cn(U,te,X){switch(X.length){case 0:return U.call(te);case 1:return U.call(te,X[0]);case 2:return U.call(te,X[0],X[1]);case 3:return U.call(te,X[0],X[1],X[2])}return U.apply(te,X)}function Ix(U,te,X,_e){for(var xe=-1,Ge=U==null?0:U.length;++xe<Ge;){var Ct=U[xe];te(_e,Ct,X(Ct),U)}return _e}function Fn(U,te){for(var X=-1,_e=U==null?0:U.length;++X<_e&&te(U[X],X,U)!==!1;);return U}function Mx(U,te){for(var X=U==null?0:U.length;X--&&te(U[X],X,U)!==!1;);return U}function F1(U,te){for(var X=-1,_e=U==null?0:U.length;++X<_e;)if(!te(U[X],X,U))return!1;return!0}function Di(U,te){for(var X=-1,_e=U==null?0:U.length,xe=0,Ge=[];++X<_e;){var Ct=U[X];te(Ct,X,U)&&(Ge[xe++]=Ct)}return Ge}function Cc(U,te){var X=U==null?0:U.length;return!!X&&Es(U,te,0)>-1}function Ih(U,te,X){for(var _e=-1,xe=U==null?0:U.length;++_e<xe;)if(X(te,U[_e]))return!0;return!1}function at(U,te){for(var X=-1,_e=U==null?0:U.length,xe=Array(_e);++X<_e;)xe[X]=te(U[X],X,U);return xe}function Ni(U,te){for(var X=-1,_e=te.length,xe=U.length;++X<_e;)U[xe+X]=te[X];return U}function Mh(U,te,X,_e){var xe=-1,Ge=U==null?0:U.length;for(_e&&Ge&&(X=U[++xe]);++xe<Ge;)X=te(X,U[xe],xe,U);return X}function Bx(U,te,X,_e){var xe=U==null?0:U.length;for(_e&&xe&&(X=U[--xe]);xe--;)X=te(X,U[xe],xe,U);return X}function Bh(U,te){for(var X=-1,_e=U==null?0:U.length;++X<_e;)if(te(U[X],X,U))return!0;return!1}var zx=zh("length");function $x(U){return U.split("")}function Ux(U){return U.match(YT)||[]}function V1(U,te,X){var _e;return X(U,function(xe,Ge,Ct){if(te(xe,Ge,Ct))return _e=Ge,!1}),_e}function Tc(U,te,X,_e){for(var xe=U.length,Ge=X+(_e?1:-1);_e?Ge--:++Ge<xe;)if(te(U[Ge],Ge,U))return Ge;return-1}function Es(U,te,X){return te===te?Jx(U,te,X):Tc(U,H1,X)}function jx(U,te,X,_e){for(var xe=X-1,Ge=U.length;++xe<Ge;)if(_e(U[xe],te))return xe;return-1}function H1(U){return U!==U}function G1(U,te){var X=U==null?0:U.length;return X?Uh(U,te)/X:ie}function zh(U){return function(te){return te==null?n:te[U]}}function $h(U){return function(te){return U==null?n:U[te]}}function W1(U,te,X,_e,xe){return xe(U,function(Ge,Ct,Je){X=_e?(_e=!1,Ge):te(X,Ge,Ct,Je)}),X}function Fx(U,te){var X=U.length;for(U.sort(te);X--;)U[X]=U[X].value;return U}function Uh(U,te){for(var X,_e=-1,xe=U.length;++_e<xe;){var Ge=te(U[_e]);Ge!==n&&(X=X===n?Ge:X+Ge)}return X}function jh(U,te){for(var X=-1,_e=Array(U);++X<U;)_e[X]=te(X);return _e}function Vx(U,te){return at(te,function(X){return[X,U[X]]})}function K1(U){return U&&U.slice(0,Z1(U)+1).replace(xh,"")}function Tn(U){return function(te){return U(te)}}function Fh(U,te){return at(te,function(X){return U[X]})}function ta(U,te){return U.has(te)}function Y1(U,te){for(var X=-1,_e=U.length;++X<_e&&Es(te,U[X],0)>-1;);return X}function Q1(U,te){for(var X=U.length;X--&&Es(te,U[X],0)>-1;);return X}function Hx(U,te){for(var X=U.length,_e=0;X--;)U[X]===te&&++_e;return _e}var Gx=$h(Lx),Wx=$h(Ox);function Kx(U){return"\\"+Dx[U]}function Yx(U,te){return U==null?n:U[te]}function ks(U){return Ax.test(U)}function Qx(U){return Cx.test(U)}function Xx(U){for(var te,X=[];!(te=U.next()).done;)X.push(te.value);return X}function Vh(U){var te=-1,X=Array(U.size);return U.forEach(function(_e,xe){X[++te]=[xe,_e]}),X}function X1(U,te){return function(X){return U(te(X))}}function Ri(U,te){for(var X=-1,_e=U.length,xe=0,Ge=[];++X<_e;){var Ct=U[X];(Ct===te||Ct===O)&&(U[X]=O,Ge[xe++]=X)}return Ge}function xc(U){var te=-1,X=Array(U.size);return U.forEach(function(_e){X[++te]=_e}),X}function Zx(U){var te=-1,X=Array(U.size);return U.forEach(function(_e){X[++te]=[_e,_e]}),X}function Jx(U,te,X){for(var _e=X-1,xe=U.length;++_e<xe;)if(U[_e]===te)return _e;return-1}function eL(U,te,X){for(var _e=X+1;_e--;)if(U[_e]===te)return _e;return _e}function As(U){return ks(U)?nL(U):zx(U)}function cr(U){return ks(U)?rL(U):$x(U)}function Z1(U){for(var te=U.length;te--&&HT.test(U.charAt(te)););return te}var tL=$h(Px);function nL(U){for(var te=Nh.lastIndex=0;Nh.test(U);)++te;return te}function rL(U){return U.match(Nh)||[]}function iL(U){return U.match(kx)||[]}var oL=function U(te){te=te==null?Mt:Cs.defaults(Mt.Object(),te,Cs.pick(Mt,Tx));var X=te.Array,_e=te.Date,xe=te.Error,Ge=te.Function,Ct=te.Math,Je=te.Object,Hh=te.RegExp,sL=te.String,Vn=te.TypeError,Lc=X.prototype,uL=Ge.prototype,Ts=Je.prototype,Oc=te["__core-js_shared__"],Pc=uL.toString,Ye=Ts.hasOwnProperty,aL=0,J1=function(){var i=/[^.]+$/.exec(Oc&&Oc.keys&&Oc.keys.IE_PROTO||"");return i?"Symbol(src)_1."+i:""}(),Dc=Ts.toString,lL=Pc.call(Je),cL=Mt._,dL=Hh("^"+Pc.call(Ye).replace(Th,"\\$&").replace(/hasOwnProperty|(function).*?(?=\\\()| for .+?(?=\\\])/g,"$1.*?")+"$"),Nc=I1?te.Buffer:n,qi=te.Symbol,Rc=te.Uint8Array,eb=Nc?Nc.allocUnsafe:n,qc=X1(Je.getPrototypeOf,Je),tb=Je.create,nb=Ts.propertyIsEnumerable,Ic=Lc.splice,rb=qi?qi.isConcatSpreadable:n,na=qi?qi.iterator:n,_o=qi?qi.toStringTag:n,Mc=function(){try{var i=Eo(Je,"defineProperty");return i({},"",{}),i}catch{}}(),fL=te.clearTimeout!==Mt.clearTimeout&&te.clearTimeout,pL=_e&&_e.now!==Mt.Date.now&&_e.now,hL=te.setTimeout!==Mt.setTimeout&&te.setTimeout,Bc=Ct.ceil,zc=Ct.floor,Gh=Je.getOwnPropertySymbols,gL=Nc?Nc.isBuffer:n,ib=te.isFinite,mL=Lc.join,vL=X1(Je.keys,Je),Tt=Ct.max,Ft=Ct.min,_L=_e.now,yL=te.parseInt,ob=Ct.random,wL=Lc.reverse,Wh=Eo(te,"DataView"),ra=Eo(te,"Map"),Kh=Eo(te,"Promise"),xs=Eo(te,"Set"),ia=Eo(te,"WeakMap"),oa=Eo(Je,"create"),$c=ia&&new ia,Ls={},bL=ko(Wh),SL=ko(ra),EL=ko(Kh),kL=ko(xs),AL=ko(ia),Uc=qi?qi.prototype:n,sa=Uc?Uc.valueOf:n,sb=Uc?Uc.toString:n;function S(i){if(gt(i)&&!Pe(i)&&!(i instanceof Be)){if(i instanceof Hn)return i;if(Ye.call(i,"__wrapped__"))return u3(i)}return new Hn(i)}var Os=function(){function i(){}return function(s){if(!ft(s))return{};if(tb)return tb(s);i.prototype=s;var a=new i;return i.prototype=n,a}}();function jc(){}function Hn(i,s){this.__wrapped__=i,this.__actions__=[],this.__chain__=!!s,this.__index__=0,this.__values__=n}S.templateSettings={escape:zT,evaluate:$T,interpolate:g1,variable:"",imports:{_:S}},S.prototype=jc.prototype,S.prototype.constructor=S,Hn.prototype=Os(jc.prototype),Hn.prototype.constructor=Hn;function Be(i){this.__wrapped__=i,this.__actions__=[],this.__dir__=1,this.__filtered__=!1,this.__iteratees__=[],this.__takeCount__=le,this.__views__=[]}function CL(){var i=new Be(this.__wrapped__);return i.__actions__=ln(this.__actions__),i.__dir__=this.__dir__,i.__filtered__=this.__filtered__,i.__iteratees__=ln(this.__iteratees__),i.__takeCount__=this.__takeCount__,i.__views__=ln(this.__views__),i}function TL(){if(this.__filtered__){var i=new Be(this);i.__dir__=-1,i.__filtered__=!0}else i=this.clone(),i.__dir__*=-1;return i}function xL(){var i=this.__wrapped__.value(),s=this.__dir__,a=Pe(i),f=s<0,w=a?i.length:0,C=$O(0,w,this.__views__),N=C.start,B=C.end,F=B-N,ne=f?B:N-1,re=this.__iteratees__,ue=re.length,me=0,be=Ft(F,this.__takeCount__);if(!a||!f&&w==F&&be==F)return Ob(i,this.__actions__);var Ae=[];e:for(;F--&&me<be;){ne+=s;for(var Ne=-1,Ce=i[ne];++Ne<ue;){var qe=re[Ne],$e=qe.iteratee,On=qe.type,Jt=$e(Ce);if(On==W)Ce=Jt;else if(!Jt){if(On==V)continue e;break e}}Ae[me++]=Ce}return Ae}Be.prototype=Os(jc.prototype),Be.prototype.constructor=Be;function yo(i){var s=-1,a=i==null?0:i.length;for(this.clear();++s<a;){var f=i[s];this.set(f[0],f[1])}}function LL(){this.__data__=oa?oa(null):{},this.size=0}function OL(i){var s=this.has(i)&&delete this.__data__[i];return this.size-=s?1:0,s}function PL(i){var s=this.__data__;if(oa){var a=s[i];return a===g?n:a}return Ye.call(s,i)?s[i]:n}function DL(i){var s=this.__data__;return oa?s[i]!==n:Ye.call(s,i)}function NL(i,s){var a=this.__data__;return this.size+=this.has(i)?0:1,a[i]=oa&&s===n?g:s,this}yo.prototype.clear=LL,yo.prototype.delete=OL,yo.prototype.get=PL,yo.prototype.has=DL,yo.prototype.set=NL;function Hr(i){var s=-1,a=i==null?0:i.length;for(this.clear();++s<a;){var f=i[s];this.set(f[0],f[1])}}function RL(){this.__data__=[],this.size=0}function qL(i){var s=this.__data__,a=Fc(s,i);if(a<0)return!1;var f=s.length-1;return a==f?s.pop():Ic.call(s,a,1),--this.size,!0}function IL(i){var s=this.__data__,a=Fc(s,i);return a<0?n:s[a][1]}function ML(i){return Fc(this.__data__,i)>-1}function BL(i,s){var a=this.__data__,f=Fc(a,i);return f<0?(++this.size,a.push([i,s])):a[f][1]=s,this}Hr.prototype.clear=RL,Hr.prototype.delete=qL,Hr.prototype.get=IL,Hr.prototype.has=ML,Hr.prototype.set=BL;function Gr(i){var s=-1,a=i==null?0:i.length;for(this.clear();++s<a;){var f=i[s];this.set(f[0],f[1])}}function zL(){this.size=0,this.__data__={hash:new yo,map:new(ra||Hr),string:new yo}}function $L(i){var s=td(this,i).delete(i);return this.size-=s?1:0,s}function UL(i){return td(this,i).get(i)}function jL(i){return td(this,i).has(i)}function FL(i,s){var a=td(this,i),f=a.size;return a.set(i,s),this.size+=a.size==f?0:1,this}Gr.prototype.clear=zL,Gr.prototype.delete=$L,Gr.prototype.get=UL,Gr.prototype.has=jL,Gr.prototype.set=FL;function wo(i){var s=-1,a=i==null?0:i.length;for(this.__data__=new Gr;++s<a;)this.add(i[s])}function VL(i){return this.__data__.set(i,g),this}function HL(i){return this.__data__.has(i)}wo.prototype.add=wo.prototype.push=VL,wo.prototype.has=HL;function dr(i){var s=this.__data__=new Hr(i);this.size=s.size}function GL(){this.__data__=new Hr,this.size=0}function WL(i){var s=this.__data__,a=s.delete(i);return this.size=s.size,a}function KL(i){return this.__data__.get(i)}function YL(i){return this.__data__.has(i)}function QL(i,s){var a=this.__data__;if(a instanceof Hr){var f=a.__data__;if(!ra||f.length<o-1)return f.push([i,s]),this.size=++a.size,this;a=this.__data__=new Gr(f)}return a.set(i,s),this.size=a.size,this}dr.prototype.clear=GL,dr.prototype.delete=WL,dr.prototype.get=KL,dr.prototype.has=YL,dr.prototype.set=QL;function ub(i,s){var a=Pe(i),f=!a&&Ao(i),w=!a&&!f&&$i(i),C=!a&&!f&&!w&&Rs(i),N=a||f||w||C,B=N?jh(i.length,sL):[],F=B.length;for(var ne in i)(s||Ye.call(i,ne))&&!(N&&(ne=="length"||w&&(ne=="offset"||ne=="parent")||C&&(ne=="buffer"||ne=="byteLength"||ne=="byteOffset")||Qr(ne,F)))&&B.push(ne);return B}function ab(i){var s=i.length;return s?i[og(0,s-1)]:n}function XL(i,s){return nd(ln(i),bo(s,0,i.length))}function ZL(i){return nd(ln(i))}function Yh(i,s,a){(a!==n&&!fr(i[s],a)||a===n&&!(s in i))&&Wr(i,s,a)}function ua(i,s,a){var f=i[s];(!(Ye.call(i,s)&&fr(f,a))||a===n&&!(s in i))&&Wr(i,s,a)}function Fc(i,s){for(var a=i.length;a--;)if(fr(i[a][0],s))return a;return-1}function JL(i,s,a,f){return Ii(i,function(w,C,N){s(f,w,a(w),N)}),f}function lb(i,s){return i&&Cr(s,Dt(s),i)}function eO(i,s){return i&&Cr(s,dn(s),i)}function Wr(i,s,a){s=="__proto__"&&Mc?Mc(i,s,{configurable:!0,enumerable:!0,value:a,writable:!0}):i[s]=a}function Qh(i,s){for(var a=-1,f=s.length,w=X(f),C=i==null;++a<f;)w[a]=C?n:Og(i,s[a]);return w}function bo(i,s,a){return i===i&&(a!==n&&(i=i<=a?i:a),s!==n&&(i=i>=s?i:s)),i}function Gn(i,s,a,f,w,C){var N,B=s&P,F=s&q,ne=s&Z;if(a&&(N=w?a(i,f,w,C):a(i)),N!==n)return N;if(!ft(i))return i;var re=Pe(i);if(re){if(N=jO(i),!B)return ln(i,N)}else{var ue=Vt(i),me=ue==se||ue==ae;if($i(i))return Nb(i,B);if(ue==J||ue==je||me&&!w){if(N=F||me?{}:Zb(i),!B)return F?PO(i,eO(N,i)):OO(i,lb(N,i))}else{if(!nt[ue])return w?i:{};N=FO(i,ue,B)}}C||(C=new dr);var be=C.get(i);if(be)return be;C.set(i,N),T3(i)?i.forEach(function(Ce){N.add(Gn(Ce,s,a,Ce,i,C))}):A3(i)&&i.forEach(function(Ce,qe){N.set(qe,Gn(Ce,s,a,qe,i,C))});var Ae=ne?F?mg:gg:F?dn:Dt,Ne=re?n:Ae(i);return Fn(Ne||i,function(Ce,qe){Ne&&(qe=Ce,Ce=i[qe]),ua(N,qe,Gn(Ce,s,a,qe,i,C))}),N}function tO(i){var s=Dt(i);return function(a){return cb(a,i,s)}}function cb(i,s,a){var f=a.length;if(i==null)return!f;for(i=Je(i);f--;){var w=a[f],C=s[w],N=i[w];if(N===n&&!(w in i)||!C(N))return!1}return!0}function db(i,s,a){if(typeof i!="function")throw new Vn(c);return ha(function(){i.apply(n,a)},s)}function aa(i,s,a,f){var w=-1,C=Cc,N=!0,B=i.length,F=[],ne=s.length;if(!B)return F;a&&(s=at(s,Tn(a))),f?(C=Ih,N=!1):s.length>=o&&(C=ta,N=!1,s=new wo(s));e:for(;++w<B;){var re=i[w],ue=a==null?re:a(re);if(re=f||re!==0?re:0,N&&ue===ue){for(var me=ne;me--;)if(s[me]===ue)continue e;F.push(re)}else C(s,ue,f)||F.push(re)}return F}var Ii=Bb(Ar),fb=Bb(Zh,!0);function nO(i,s){var a=!0;return Ii(i,function(f,w,C){return a=!!s(f,w,C),a}),a}function Vc(i,s,a){for(var f=-1,w=i.length;++f<w;){var C=i[f],N=s(C);if(N!=null&&(B===n?N===N&&!Ln(N):a(N,B)))var B=N,F=C}return F}function rO(i,s,a,f){var w=i.length;for(a=De(a),a<0&&(a=-a>w?0:w+a),f=f===n||f>w?w:De(f),f<0&&(f+=w),f=a>f?0:L3(f);a<f;)i[a++]=s;return i}function pb(i,s){var a=[];return Ii(i,function(f,w,C){s(f,w,C)&&a.push(f)}),a}function Bt(i,s,a,f,w){var C=-1,N=i.length;for(a||(a=HO),w||(w=[]);++C<N;){var B=i[C];s>0&&a(B)?s>1?Bt(B,s-1,a,f,w):Ni(w,B):f||(w[w.length]=B)}return w}var Xh=zb(),hb=zb(!0);function Ar(i,s){return i&&Xh(i,s,Dt)}function Zh(i,s){return i&&hb(i,s,Dt)}function Hc(i,s){return Di(s,function(a){return Xr(i[a])})}function So(i,s){s=Bi(s,i);for(var a=0,f=s.length;i!=null&&a<f;)i=i[Tr(s[a++])];return a&&a==f?i:n}function gb(i,s,a){var f=s(i);return Pe(i)?f:Ni(f,a(i))}function Xt(i){return i==null?i===n?lr:Y:_o&&_o in Je(i)?zO(i):ZO(i)}function Jh(i,s){return i>s}function iO(i,s){return i!=null&&Ye.call(i,s)}function oO(i,s){return i!=null&&s in Je(i)}function sO(i,s,a){return i>=Ft(s,a)&&i<Tt(s,a)}function eg(i,s,a){for(var f=a?Ih:Cc,w=i[0].length,C=i.length,N=C,B=X(C),F=1/0,ne=[];N--;){var re=i[N];N&&s&&(re=at(re,Tn(s))),F=Ft(re.length,F),B[N]=!a&&(s||w>=120&&re.length>=120)?new wo(N&&re):n}re=i[0];var ue=-1,me=B[0];e:for(;++ue<w&&ne.length<F;){var be=re[ue],Ae=s?s(be):be;if(be=a||be!==0?be:0,!(me?ta(me,Ae):f(ne,Ae,a))){for(N=C;--N;){var Ne=B[N];if(!(Ne?ta(Ne,Ae):f(i[N],Ae,a)))continue e}me&&me.push(Ae),ne.push(be)}}return ne}function uO(i,s,a,f){return Ar(i,function(w,C,N){s(f,a(w),C,N)}),f}function la(i,s,a){s=Bi(s,i),i=n3(i,s);var f=i==null?i:i[Tr(Kn(s))];return f==null?n:Cn(f,i,a)}function mb(i){return gt(i)&&Xt(i)==je}function aO(i){return gt(i)&&Xt(i)==Vr}function lO(i){return gt(i)&&Xt(i)==A}function ca(i,s,a,f,w){return i===s?!0:i==null||s==null||!gt(i)&&!gt(s)?i!==i&&s!==s:cO(i,s,a,f,ca,w)}function cO(i,s,a,f,w,C){var N=Pe(i),B=Pe(s),F=N?Me:Vt(i),ne=B?Me:Vt(s);F=F==je?J:F,ne=ne==je?J:ne;var re=F==J,ue=ne==J,me=F==ne;if(me&&$i(i)){if(!$i(s))return!1;N=!0,re=!1}if(me&&!re)return C||(C=new dr),N||Rs(i)?Yb(i,s,a,f,w,C):MO(i,s,F,a,f,w,C);if(!(a&M)){var be=re&&Ye.call(i,"__wrapped__"),Ae=ue&&Ye.call(s,"__wrapped__");if(be||Ae){var Ne=be?i.value():i,Ce=Ae?s.value():s;return C||(C=new dr),w(Ne,Ce,a,f,C)}}return me?(C||(C=new dr),BO(i,s,a,f,w,C)):!1}function dO(i){return gt(i)&&Vt(i)==fe}function tg(i,s,a,f){var w=a.length,C=w,N=!f;if(i==null)return!C;for(i=Je(i);w--;){var B=a[w];if(N&&B[2]?B[1]!==i[B[0]]:!(B[0]in i))return!1}for(;++w<C;){B=a[w];var F=B[0],ne=i[F],re=B[1];if(N&&B[2]){if(ne===n&&!(F in i))return!1}else{var ue=new dr;if(f)var me=f(ne,re,F,i,s,ue);if(!(me===n?ca(re,ne,M|H,f,ue):me))return!1}}return!0}function vb(i){if(!ft(i)||WO(i))return!1;var s=Xr(i)?dL:tx;return s.test(ko(i))}function fO(i){return gt(i)&&Xt(i)==oe}function pO(i){return gt(i)&&Vt(i)==ge}function hO(i){return gt(i)&&ad(i.length)&&!!ot[Xt(i)]}function _b(i){return typeof i=="function"?i:i==null?fn:typeof i=="object"?Pe(i)?bb(i[0],i[1]):wb(i):$3(i)}function ng(i){if(!pa(i))return vL(i);var s=[];for(var a in Je(i))Ye.call(i,a)&&a!="constructor"&&s.push(a);return s}function gO(i){if(!ft(i))return XO(i);var s=pa(i),a=[];for(var f in i)f=="constructor"&&(s||!Ye.call(i,f))||a.push(f);return a}function rg(i,s){return i<s}function yb(i,s){var a=-1,f=cn(i)?X(i.length):[];return Ii(i,function(w,C,N){f[++a]=s(w,C,N)}),f}function wb(i){var s=_g(i);return s.length==1&&s[0][2]?e3(s[0][0],s[0][1]):function(a){return a===i||tg(a,i,s)}}function bb(i,s){return wg(i)&&Jb(s)?e3(Tr(i),s):function(a){var f=Og(a,i);return f===n&&f===s?Pg(a,i):ca(s,f,M|H)}}function Gc(i,s,a,f,w){i!==s&&Xh(s,function(C,N){if(w||(w=new dr),ft(C))mO(i,s,N,a,Gc,f,w);else{var B=f?f(Sg(i,N),C,N+"",i,s,w):n;B===n&&(B=C),Yh(i,N,B)}},dn)}function mO(i,s,a,f,w,C,N){var B=Sg(i,a),F=Sg(s,a),ne=N.get(F);if(ne){Yh(i,a,ne);return}var re=C?C(B,F,a+"",i,s,N):n,ue=re===n;if(ue){var me=Pe(F),be=!me&&$i(F),Ae=!me&&!be&&Rs(F);re=F,me||be||Ae?Pe(B)?re=B:vt(B)?re=ln(B):be?(ue=!1,re=Nb(F,!0)):Ae?(ue=!1,re=Rb(F,!0)):re=[]:ga(F)||Ao(F)?(re=B,Ao(B)?re=O3(B):(!ft(B)||Xr(B))&&(re=Zb(F))):ue=!1}ue&&(N.set(F,re),w(re,F,f,C,N),N.delete(F)),Yh(i,a,re)}function Sb(i,s){var a=i.length;if(a)return s+=s<0?a:0,Qr(s,a)?i[s]:n}function Eb(i,s,a){s.length?s=at(s,function(C){return Pe(C)?function(N){return So(N,C.length===1?C[0]:C)}:C}):s=[fn];var f=-1;s=at(s,Tn(ke()));var w=yb(i,function(C,N,B){var F=at(s,function(ne){return ne(C)});return{criteria:F,index:++f,value:C}});return Fx(w,function(C,N){return LO(C,N,a)})}function vO(i,s){return kb(i,s,function(a,f){return Pg(i,f)})}function kb(i,s,a){for(var f=-1,w=s.length,C={};++f<w;){var N=s[f],B=So(i,N);a(B,N)&&da(C,Bi(N,i),B)}return C}function _O(i){return function(s){return So(s,i)}}function ig(i,s,a,f){var w=f?jx:Es,C=-1,N=s.length,B=i;for(i===s&&(s=ln(s)),a&&(B=at(i,Tn(a)));++C<N;)for(var F=0,ne=s[C],re=a?a(ne):ne;(F=w(B,re,F,f))>-1;)B!==i&&Ic.call(B,F,1),Ic.call(i,F,1);return i}function Ab(i,s){for(var a=i?s.length:0,f=a-1;a--;){var w=s[a];if(a==f||w!==C){var C=w;Qr(w)?Ic.call(i,w,1):ag(i,w)}}return i}function og(i,s){return i+zc(ob()*(s-i+1))}function yO(i,s,a,f){for(var w=-1,C=Tt(Bc((s-i)/(a||1)),0),N=X(C);C--;)N[f?C:++w]=i,i+=a;return N}function sg(i,s){var a="";if(!i||s<1||s>G)return a;do s%2&&(a+=i),s=zc(s/2),s&&(i+=i);while(s);return a}function Re(i,s){return Eg(t3(i,s,fn),i+"")}function wO(i){return ab(qs(i))}function bO(i,s){var a=qs(i);return nd(a,bo(s,0,a.length))}function da(i,s,a,f){if(!ft(i))return i;s=Bi(s,i);for(var w=-1,C=s.length,N=C-1,B=i;B!=null&&++w<C;){var F=Tr(s[w]),ne=a;if(F==="__proto__"||F==="constructor"||F==="prototype")return i;if(w!=N){var re=B[F];ne=f?f(re,F,B):n,ne===n&&(ne=ft(re)?re:Qr(s[w+1])?[]:{})}ua(B,F,ne),B=B[F]}return i}var Cb=$c?function(i,s){return $c.set(i,s),i}:fn,SO=Mc?function(i,s){return Mc(i,"toString",{configurable:!0,enumerable:!1,value:Ng(s),writable:!0})}:fn;function EO(i){return nd(qs(i))}function Wn(i,s,a){var f=-1,w=i.length;s<0&&(s=-s>w?0:w+s),a=a>w?w:a,a<0&&(a+=w),w=s>a?0:a-s>>>0,s>>>=0;for(var C=X(w);++f<w;)C[f]=i[f+s];return C}function kO(i,s){var a;return Ii(i,function(f,w,C){return a=s(f,w,C),!a}),!!a}function Wc(i,s,a){var f=0,w=i==null?f:i.length;if(typeof s=="number"&&s===s&&w<=Xe){for(;f<w;){var C=f+w>>>1,N=i[C];N!==null&&!Ln(N)&&(a?N<=s:N<s)?f=C+1:w=C}return w}return ug(i,s,fn,a)}function ug(i,s,a,f){var w=0,C=i==null?0:i.length;if(C===0)return 0;s=a(s);for(var N=s!==s,B=s===null,F=Ln(s),ne=s===n;w<C;){var re=zc((w+C)/2),ue=a(i[re]),me=ue!==n,be=ue===null,Ae=ue===ue,Ne=Ln(ue);if(N)var Ce=f||Ae;else ne?Ce=Ae&&(f||me):B?Ce=Ae&&me&&(f||!be):F?Ce=Ae&&me&&!be&&(f||!Ne):be||Ne?Ce=!1:Ce=f?ue<=s:ue<s;Ce?w=re+1:C=re}return Ft(C,Oe)}function Tb(i,s){for(var a=-1,f=i.length,w=0,C=[];++a<f;){var N=i[a],B=s?s(N):N;if(!a||!fr(B,F)){var F=B;C[w++]=N===0?0:N}}return C}function xb(i){return typeof i=="number"?i:Ln(i)?ie:+i}function xn(i){if(typeof i=="string")return i;if(Pe(i))return at(i,xn)+"";if(Ln(i))return sb?sb.call(i):"";var s=i+"";return s=="0"&&1/i==-Q?"-0":s}function Mi(i,s,a){var f=-1,w=Cc,C=i.length,N=!0,B=[],F=B;if(a)N=!1,w=Ih;else if(C>=o){var ne=s?null:qO(i);if(ne)return xc(ne);N=!1,w=ta,F=new wo}else F=s?[]:B;e:for(;++f<C;){var re=i[f],ue=s?s(re):re;if(re=a||re!==0?re:0,N&&ue===ue){for(var me=F.length;me--;)if(F[me]===ue)continue e;s&&F.push(ue),B.push(re)}else w(F,ue,a)||(F!==B&&F.push(ue),B.push(re))}return B}function ag(i,s){return s=Bi(s,i),i=n3(i,s),i==null||delete i[Tr(Kn(s))]}function Lb(i,s,a,f){return da(i,s,a(So(i,s)),f)}function Kc(i,s,a,f){for(var w=i.length,C=f?w:-1;(f?C--:++C<w)&&s(i[C],C,i););return a?Wn(i,f?0:C,f?C+1:w):Wn(i,f?C+1:0,f?w:C)}function Ob(i,s){var a=i;return a instanceof Be&&(a=a.value()),Mh(s,function(f,w){return w.func.apply(w.thisArg,Ni([f],w.args))},a)}function lg(i,s,a){var f=i.length;if(f<2)return f?Mi(i[0]):[];for(var w=-1,C=X(f);++w<f;)for(var N=i[w],B=-1;++B<f;)B!=w&&(C[w]=aa(C[w]||N,i[B],s,a));return Mi(Bt(C,1),s,a)}function Pb(i,s,a){for(var f=-1,w=i.length,C=s.length,N={};++f<w;){var B=f<C?s[f]:n;a(N,i[f],B)}return N}function cg(i){return vt(i)?i:[]}function dg(i){return typeof i=="function"?i:fn}function Bi(i,s){return Pe(i)?i:wg(i,s)?[i]:s3(We(i))}var AO=Re;function zi(i,s,a){var f=i.length;return a=a===n?f:a,!s&&a>=f?i:Wn(i,s,a)}var Db=fL||function(i){return Mt.clearTimeout(i)};function Nb(i,s){if(s)return i.slice();var a=i.length,f=eb?eb(a):new i.constructor(a);return i.copy(f),f}function fg(i){var s=new i.constructor(i.byteLength);return new Rc(s).set(new Rc(i)),s}function CO(i,s){var a=s?fg(i.buffer):i.buffer;return new i.constructor(a,i.byteOffset,i.byteLength)}function TO(i){var s=new i.constructor(i.source,m1.exec(i));return s.lastIndex=i.lastIndex,s}function xO(i){return sa?Je(sa.call(i)):{}}function Rb(i,s){var a=s?fg(i.buffer):i.buffer;return new i.constructor(a,i.byteOffset,i.length)}function qb(i,s){if(i!==s){var a=i!==n,f=i===null,w=i===i,C=Ln(i),N=s!==n,B=s===null,F=s===s,ne=Ln(s);if(!B&&!ne&&!C&&i>s||C&&N&&F&&!B&&!ne||f&&N&&F||!a&&F||!w)return 1;if(!f&&!C&&!ne&&i<s||ne&&a&&w&&!f&&!C||B&&a&&w||!N&&w||!F)return-1}return 0}function LO(i,s,a){for(var f=-1,w=i.criteria,C=s.criteria,N=w.length,B=a.length;++f<N;){var F=qb(w[f],C[f]);if(F){if(f>=B)return F;var ne=a[f];return F*(ne=="desc"?-1:1)}}return i.index-s.index}function Ib(i,s,a,f){for(var w=-1,C=i.length,N=a.length,B=-1,F=s.length,ne=Tt(C-N,0),re=X(F+ne),ue=!f;++B<F;)re[B]=s[B];for(;++w<N;)(ue||w<C)&&(re[a[w]]=i[w]);for(;ne--;)re[B++]=i[w++];return re}function Mb(i,s,a,f){for(var w=-1,C=i.length,N=-1,B=a.length,F=-1,ne=s.length,re=Tt(C-B,0),ue=X(re+ne),me=!f;++w<re;)ue[w]=i[w];for(var be=w;++F<ne;)ue[be+F]=s[F];for(;++N<B;)(me||w<C)&&(ue[be+a[N]]=i[w++]);return ue}function ln(i,s){var a=-1,f=i.length;for(s||(s=X(f));++a<f;)s[a]=i[a];return s}function Cr(i,s,a,f){var w=!a;a||(a={});for(var C=-1,N=s.length;++C<N;){var B=s[C],F=f?f(a[B],i[B],B,a,i):n;F===n&&(F=i[B]),w?Wr(a,B,F):ua(a,B,F)}return a}function OO(i,s){return Cr(i,yg(i),s)}function PO(i,s){return Cr(i,Qb(i),s)}function Yc(i,s){return function(a,f){var w=Pe(a)?Ix:JL,C=s?s():{};return w(a,i,ke(f,2),C)}}function Ps(i){return Re(function(s,a){var f=-1,w=a.length,C=w>1?a[w-1]:n,N=w>2?a[2]:n;for(C=i.length>3&&typeof C=="function"?(w--,C):n,N&&Zt(a[0],a[1],N)&&(C=w<3?n:C,w=1),s=Je(s);++f<w;){var B=a[f];B&&i(s,B,f,C)}return s})}function Bb(i,s){return function(a,f){if(a==null)return a;if(!cn(a))return i(a,f);for(var w=a.length,C=s?w:-1,N=Je(a);(s?C--:++C<w)&&f(N[C],C,N)!==!1;);return a}}function zb(i){return function(s,a,f){for(var w=-1,C=Je(s),N=f(s),B=N.length;B--;){var F=N[i?B:++w];if(a(C[F],F,C)===!1)break}return s}}function DO(i,s,a){var f=s&z,w=fa(i);function C(){var N=this&&this!==Mt&&this instanceof C?w:i;return N.apply(f?a:this,arguments)}return C}function $b(i){return function(s){s=We(s);var a=ks(s)?cr(s):n,f=a?a[0]:s.charAt(0),w=a?zi(a,1).join(""):s.slice(1);return f[i]()+w}}function Ds(i){return function(s){return Mh(B3(M3(s).replace(Sx,"")),i,"")}}function fa(i){return function(){var s=arguments;switch(s.length){case 0:return new i;case 1:return new i(s[0]);case 2:return new i(s[0],s[1]);case 3:return new i(s[0],s[1],s[2]);case 4:return new i(s[0],s[1],s[2],s[3]);case 5:return new i(s[0],s[1],s[2],s[3],s[4]);case 6:return new i(s[0],s[1],s[2],s[3],s[4],s[5]);case 7:return new i(s[0],s[1],s[2],s[3],s[4],s[5],s[6])}var a=Os(i.prototype),f=i.apply(a,s);return ft(f)?f:a}}function NO(i,s,a){var f=fa(i);function w(){for(var C=arguments.length,N=X(C),B=C,F=Ns(w);B--;)N[B]=arguments[B];var ne=C<3&&N[0]!==F&&N[C-1]!==F?[]:Ri(N,F);if(C-=ne.length,C<a)return Hb(i,s,Qc,w.placeholder,n,N,ne,n,n,a-C);var re=this&&this!==Mt&&this instanceof w?f:i;return Cn(re,this,N)}return w}function Ub(i){return function(s,a,f){var w=Je(s);if(!cn(s)){var C=ke(a,3);s=Dt(s),a=function(B){return C(w[B],B,w)}}var N=i(s,a,f);return N>-1?w[C?s[N]:N]:n}}function jb(i){return Yr(function(s){var a=s.length,f=a,w=Hn.prototype.thru;for(i&&s.reverse();f--;){var C=s[f];if(typeof C!="function")throw new Vn(c);if(w&&!N&&ed(C)=="wrapper")var N=new Hn([],!0)}for(f=N?f:a;++f<a;){C=s[f];var B=ed(C),F=B=="wrapper"?vg(C):n;F&&bg(F[0])&&F[1]==(_|x|h|v)&&!F[4].length&&F[9]==1?N=N[ed(F[0])].apply(N,F[3]):N=C.length==1&&bg(C)?N[B]():N.thru(C)}return function(){var ne=arguments,re=ne[0];if(N&&ne.length==1&&Pe(re))return N.plant(re).value();for(var ue=0,me=a?s[ue].apply(this,ne):re;++ue<a;)me=s[ue].call(this,me);return me}})}function Qc(i,s,a,f,w,C,N,B,F,ne){var re=s&_,ue=s&z,me=s&L,be=s&(x|l),Ae=s&y,Ne=me?n:fa(i);function Ce(){for(var qe=arguments.length,$e=X(qe),On=qe;On--;)$e[On]=arguments[On];if(be)var Jt=Ns(Ce),Pn=Hx($e,Jt);if(f&&($e=Ib($e,f,w,be)),C&&($e=Mb($e,C,N,be)),qe-=Pn,be&&qe<ne){var _t=Ri($e,Jt);return Hb(i,s,Qc,Ce.placeholder,a,$e,_t,B,F,ne-qe)}var pr=ue?a:this,Jr=me?pr[i]:i;return qe=$e.length,B?$e=JO($e,B):Ae&&qe>1&&$e.reverse(),re&&F<qe&&($e.length=F),this&&this!==Mt&&this instanceof Ce&&(Jr=Ne||fa(Jr)),Jr.apply(pr,$e)}return Ce}function Fb(i,s){return function(a,f){return uO(a,i,s(f),{})}}function Xc(i,s){return function(a,f){var w;if(a===n&&f===n)return s;if(a!==n&&(w=a),f!==n){if(w===n)return f;typeof a=="string"||typeof f=="string"?(a=xn(a),f=xn(f)):(a=xb(a),f=xb(f)),w=i(a,f)}return w}}function pg(i){return Yr(function(s){return s=at(s,Tn(ke())),Re(function(a){var f=this;return i(s,function(w){return Cn(w,f,a)})})})}function Zc(i,s){s=s===n?" ":xn(s);var a=s.length;if(a<2)return a?sg(s,i):s;var f=sg(s,Bc(i/As(s)));return ks(s)?zi(cr(f),0,i).join(""):f.slice(0,i)}function RO(i,s,a,f){var w=s&z,C=fa(i);function N(){for(var B=-1,F=arguments.length,ne=-1,re=f.length,ue=X(re+F),me=this&&this!==Mt&&this instanceof N?C:i;++ne<re;)ue[ne]=f[ne];for(;F--;)ue[ne++]=arguments[++B];return Cn(me,w?a:this,ue)}return N}function Vb(i){return function(s,a,f){return f&&typeof f!="number"&&Zt(s,a,f)&&(a=f=n),s=Zr(s),a===n?(a=s,s=0):a=Zr(a),f=f===n?s<a?1:-1:Zr(f),yO(s,a,f,i)}}function Jc(i){return function(s,a){return typeof s=="string"&&typeof a=="string"||(s=Yn(s),a=Yn(a)),i(s,a)}}function Hb(i,s,a,f,w,C,N,B,F,ne){var re=s&x,ue=re?N:n,me=re?n:N,be=re?C:n,Ae=re?n:C;s|=re?h:d,s&=~(re?d:h),s&k||(s&=~(z|L));var Ne=[i,s,w,be,ue,Ae,me,B,F,ne],Ce=a.apply(n,Ne);return bg(i)&&r3(Ce,Ne),Ce.placeholder=f,i3(Ce,i,s)}function hg(i){var s=Ct[i];return function(a,f){if(a=Yn(a),f=f==null?0:Ft(De(f),292),f&&ib(a)){var w=(We(a)+"e").split("e"),C=s(w[0]+"e"+(+w[1]+f));return w=(We(C)+"e").split("e"),+(w[0]+"e"+(+w[1]-f))}return s(a)}}var qO=xs&&1/xc(new xs([,-0]))[1]==Q?function(i){return new xs(i)}:Ig;function Gb(i){return function(s){var a=Vt(s);return a==fe?Vh(s):a==ge?Zx(s):Vx(s,i(s))}}function Kr(i,s,a,f,w,C,N,B){var F=s&L;if(!F&&typeof i!="function")throw new Vn(c);var ne=f?f.length:0;if(ne||(s&=~(h|d),f=w=n),N=N===n?N:Tt(De(N),0),B=B===n?B:De(B),ne-=w?w.length:0,s&d){var re=f,ue=w;f=w=n}var me=F?n:vg(i),be=[i,s,a,f,w,re,ue,C,N,B];if(me&&QO(be,me),i=be[0],s=be[1],a=be[2],f=be[3],w=be[4],B=be[9]=be[9]===n?F?0:i.length:Tt(be[9]-ne,0),!B&&s&(x|l)&&(s&=~(x|l)),!s||s==z)var Ae=DO(i,s,a);else s==x||s==l?Ae=NO(i,s,B):(s==h||s==(z|h))&&!w.length?Ae=RO(i,s,a,f):Ae=Qc.apply(n,be);var Ne=me?Cb:r3;return i3(Ne(Ae,be),i,s)}function Wb(i,s,a,f){return i===n||fr(i,Ts[a])&&!Ye.call(f,a)?s:i}function Kb(i,s,a,f,w,C){return ft(i)&&ft(s)&&(C.set(s,i),Gc(i,s,n,Kb,C),C.delete(s)),i}function IO(i){return ga(i)?n:i}function Yb(i,s,a,f,w,C){var N=a&M,B=i.length,F=s.length;if(B!=F&&!(N&&F>B))return!1;var ne=C.get(i),re=C.get(s);if(ne&&re)return ne==s&&re==i;var ue=-1,me=!0,be=a&H?new wo:n;for(C.set(i,s),C.set(s,i);++ue<B;){var Ae=i[ue],Ne=s[ue];if(f)var Ce=N?f(Ne,Ae,ue,s,i,C):f(Ae,Ne,ue,i,s,C);if(Ce!==n){if(Ce)continue;me=!1;break}if(be){if(!Bh(s,function(qe,$e){if(!ta(be,$e)&&(Ae===qe||w(Ae,qe,a,f,C)))return be.push($e)})){me=!1;break}}else if(!(Ae===Ne||w(Ae,Ne,a,f,C))){me=!1;break}}return C.delete(i),C.delete(s),me}function MO(i,s,a,f,w,C,N){switch(a){case Pi:if(i.byteLength!=s.byteLength||i.byteOffset!=s.byteOffset)return!1;i=i.buffer,s=s.buffer;case Vr:return!(i.byteLength!=s.byteLength||!C(new Rc(i),new Rc(s)));case ce:case A:case I:return fr(+i,+s);case $:return i.name==s.name&&i.message==s.message;case oe:case Te:return i==s+"";case fe:var B=Vh;case ge:var F=f&M;if(B||(B=xc),i.size!=s.size&&!F)return!1;var ne=N.get(i);if(ne)return ne==s;f|=H,N.set(i,s);var re=Yb(B(i),B(s),f,w,C,N);return N.delete(i),re;case Ve:if(sa)return sa.call(i)==sa.call(s)}return!1}function BO(i,s,a,f,w,C){var N=a&M,B=gg(i),F=B.length,ne=gg(s),re=ne.length;if(F!=re&&!N)return!1;for(var ue=F;ue--;){var me=B[ue];if(!(N?me in s:Ye.call(s,me)))return!1}var be=C.get(i),Ae=C.get(s);if(be&&Ae)return be==s&&Ae==i;var Ne=!0;C.set(i,s),C.set(s,i);for(var Ce=N;++ue<F;){me=B[ue];var qe=i[me],$e=s[me];if(f)var On=N?f($e,qe,me,s,i,C):f(qe,$e,me,i,s,C);if(!(On===n?qe===$e||w(qe,$e,a,f,C):On)){Ne=!1;break}Ce||(Ce=me=="constructor")}if(Ne&&!Ce){var Jt=i.constructor,Pn=s.constructor;Jt!=Pn&&"constructor"in i&&"constructor"in s&&!(typeof Jt=="function"&&Jt instanceof Jt&&typeof Pn=="function"&&Pn instanceof Pn)&&(Ne=!1)}return C.delete(i),C.delete(s),Ne}function Yr(i){return Eg(t3(i,n,c3),i+"")}function gg(i){return gb(i,Dt,yg)}function mg(i){return gb(i,dn,Qb)}var vg=$c?function(i){return $c.get(i)}:Ig;function ed(i){for(var s=i.name+"",a=Ls[s],f=Ye.call(Ls,s)?a.length:0;f--;){var w=a[f],C=w.func;if(C==null||C==i)return w.name}return s}function Ns(i){var s=Ye.call(S,"placeholder")?S:i;return s.placeholder}function ke(){var i=S.iteratee||Rg;return i=i===Rg?_b:i,arguments.length?i(arguments[0],arguments[1]):i}function td(i,s){var a=i.__data__;return GO(s)?a[typeof s=="string"?"string":"hash"]:a.map}function _g(i){for(var s=Dt(i),a=s.length;a--;){var f=s[a],w=i[f];s[a]=[f,w,Jb(w)]}return s}function Eo(i,s){var a=Yx(i,s);return vb(a)?a:n}function zO(i){var s=Ye.call(i,_o),a=i[_o];try{i[_o]=n;var f=!0}catch{}var w=Dc.call(i);return f&&(s?i[_o]=a:delete i[_o]),w}var yg=Gh?function(i){return i==null?[]:(i=Je(i),Di(Gh(i),function(s){return nb.call(i,s)}))}:Mg,Qb=Gh?function(i){for(var s=[];i;)Ni(s,yg(i)),i=qc(i);return s}:Mg,Vt=Xt;(Wh&&Vt(new Wh(new ArrayBuffer(1)))!=Pi||ra&&Vt(new ra)!=fe||Kh&&Vt(Kh.resolve())!=ve||xs&&Vt(new xs)!=ge||ia&&Vt(new ia)!=mo)&&(Vt=function(i){var s=Xt(i),a=s==J?i.constructor:n,f=a?ko(a):"";if(f)switch(f){case bL:return Pi;case SL:return fe;case EL:return ve;case kL:return ge;case AL:return mo}return s});function $O(i,s,a){for(var f=-1,w=a.length;++f<w;){var C=a[f],N=C.size;switch(C.type){case"drop":i+=N;break;case"dropRight":s-=N;break;case"take":s=Ft(s,i+N);break;case"takeRight":i=Tt(i,s-N);break}}return{start:i,end:s}}function UO(i){var s=i.match(WT);return s?s[1].split(KT):[]}function Xb(i,s,a){s=Bi(s,i);for(var f=-1,w=s.length,C=!1;++f<w;){var N=Tr(s[f]);if(!(C=i!=null&&a(i,N)))break;i=i[N]}return C||++f!=w?C:(w=i==null?0:i.length,!!w&&ad(w)&&Qr(N,w)&&(Pe(i)||Ao(i)))}function jO(i){var s=i.length,a=new i.constructor(s);return s&&typeof i[0]=="string"&&Ye.call(i,"index")&&(a.index=i.index,a.input=i.input),a}function Zb(i){return typeof i.constructor=="function"&&!pa(i)?Os(qc(i)):{}}function FO(i,s,a){var f=i.constructor;switch(s){case Vr:return fg(i);case ce:case A:return new f(+i);case Pi:return CO(i,a);case ws:case ea:case bs:case bh:case Sh:case Eh:case kh:case Ah:case Ch:return Rb(i,a);case fe:return new f;case I:case Te:return new f(i);case oe:return TO(i);case ge:return new f;case Ve:return xO(i)}}function VO(i,s){var a=s.length;if(!a)return i;var f=a-1;return s[f]=(a>1?"& ":"")+s[f],s=s.join(a>2?", ":" "),i.replace(GT,`{
-/* [wrapped with `+s+`] */
-`)}function HO(i){return Pe(i)||Ao(i)||!!(rb&&i&&i[rb])}function Qr(i,s){var a=typeof i;return s=s??G,!!s&&(a=="number"||a!="symbol"&&rx.test(i))&&i>-1&&i%1==0&&i<s}function Zt(i,s,a){if(!ft(a))return!1;var f=typeof s;return(f=="number"?cn(a)&&Qr(s,a.length):f=="string"&&s in a)?fr(a[s],i):!1}function wg(i,s){if(Pe(i))return!1;var a=typeof i;return a=="number"||a=="symbol"||a=="boolean"||i==null||Ln(i)?!0:jT.test(i)||!UT.test(i)||s!=null&&i in Je(s)}function GO(i){var s=typeof i;return s=="string"||s=="number"||s=="symbol"||s=="boolean"?i!=="__proto__":i===null}function bg(i){var s=ed(i),a=S[s];if(typeof a!="function"||!(s in Be.prototype))return!1;if(i===a)return!0;var f=vg(a);return!!f&&i===f[0]}function WO(i){return!!J1&&J1 in i}var KO=Oc?Xr:Bg;function pa(i){var s=i&&i.constructor,a=typeof s=="function"&&s.prototype||Ts;return i===a}function Jb(i){return i===i&&!ft(i)}function e3(i,s){return function(a){return a==null?!1:a[i]===s&&(s!==n||i in Je(a))}}function YO(i){var s=sd(i,function(f){return a.size===b&&a.clear(),f}),a=s.cache;return s}function QO(i,s){var a=i[1],f=s[1],w=a|f,C=w<(z|L|_),N=f==_&&a==x||f==_&&a==v&&i[7].length<=s[8]||f==(_|v)&&s[7].length<=s[8]&&a==x;if(!(C||N))return i;f&z&&(i[2]=s[2],w|=a&z?0:k);var B=s[3];if(B){var F=i[3];i[3]=F?Ib(F,B,s[4]):B,i[4]=F?Ri(i[3],O):s[4]}return B=s[5],B&&(F=i[5],i[5]=F?Mb(F,B,s[6]):B,i[6]=F?Ri(i[5],O):s[6]),B=s[7],B&&(i[7]=B),f&_&&(i[8]=i[8]==null?s[8]:Ft(i[8],s[8])),i[9]==null&&(i[9]=s[9]),i[0]=s[0],i[1]=w,i}function XO(i){var s=[];if(i!=null)for(var a in Je(i))s.push(a);return s}function ZO(i){return Dc.call(i)}function t3(i,s,a){return s=Tt(s===n?i.length-1:s,0),function(){for(var f=arguments,w=-1,C=Tt(f.length-s,0),N=X(C);++w<C;)N[w]=f[s+w];w=-1;for(var B=X(s+1);++w<s;)B[w]=f[w];return B[s]=a(N),Cn(i,this,B)}}function n3(i,s){return s.length<2?i:So(i,Wn(s,0,-1))}function JO(i,s){for(var a=i.length,f=Ft(s.length,a),w=ln(i);f--;){var C=s[f];i[f]=Qr(C,a)?w[C]:n}return i}function Sg(i,s){if(!(s==="constructor"&&typeof i[s]=="function")&&s!="__proto__")return i[s]}var r3=o3(Cb),ha=hL||function(i,s){return Mt.setTimeout(i,s)},Eg=o3(SO);function i3(i,s,a){var f=s+"";return Eg(i,VO(f,e6(UO(f),a)))}function o3(i){var s=0,a=0;return function(){var f=_L(),w=j-(f-a);if(a=f,w>0){if(++s>=D)return arguments[0]}else s=0;return i.apply(n,arguments)}}function nd(i,s){var a=-1,f=i.length,w=f-1;for(s=s===n?f:s;++a<s;){var C=og(a,w),N=i[C];i[C]=i[a],i[a]=N}return i.length=s,i}var s3=YO(function(i){var s=[];return i.charCodeAt(0)===46&&s.push(""),i.replace(FT,function(a,f,w,C){s.push(w?C.replace(XT,"$1"):f||a)}),s});function Tr(i){if(typeof i=="string"||Ln(i))return i;var s=i+"";return s=="0"&&1/i==-Q?"-0":s}function ko(i){if(i!=null){try{return Pc.call(i)}catch{}try{return i+""}catch{}}return""}function e6(i,s){return Fn(tt,function(a){var f="_."+a[0];s&a[1]&&!Cc(i,f)&&i.push(f)}),i.sort()}function u3(i){if(i instanceof Be)return i.clone();var s=new Hn(i.__wrapped__,i.__chain__);return s.__actions__=ln(i.__actions__),s.__index__=i.__index__,s.__values__=i.__values__,s}function t6(i,s,a){(a?Zt(i,s,a):s===n)?s=1:s=Tt(De(s),0);var f=i==null?0:i.length;if(!f||s<1)return[];for(var w=0,C=0,N=X(Bc(f/s));w<f;)N[C++]=Wn(i,w,w+=s);return N}function n6(i){for(var s=-1,a=i==null?0:i.length,f=0,w=[];++s<a;){var C=i[s];C&&(w[f++]=C)}return w}function r6(){var i=arguments.length;if(!i)return[];for(var s=X(i-1),a=arguments[0],f=i;f--;)s[f-1]=arguments[f];return Ni(Pe(a)?ln(a):[a],Bt(s,1))}var i6=Re(function(i,s){return vt(i)?aa(i,Bt(s,1,vt,!0)):[]}),o6=Re(function(i,s){var a=Kn(s);return vt(a)&&(a=n),vt(i)?aa(i,Bt(s,1,vt,!0),ke(a,2)):[]}),s6=Re(function(i,s){var a=Kn(s);return vt(a)&&(a=n),vt(i)?aa(i,Bt(s,1,vt,!0),n,a):[]});function u6(i,s,a){var f=i==null?0:i.length;return f?(s=a||s===n?1:De(s),Wn(i,s<0?0:s,f)):[]}function a6(i,s,a){var f=i==null?0:i.length;return f?(s=a||s===n?1:De(s),s=f-s,Wn(i,0,s<0?0:s)):[]}function l6(i,s){return i&&i.length?Kc(i,ke(s,3),!0,!0):[]}function c6(i,s){return i&&i.length?Kc(i,ke(s,3),!0):[]}function d6(i,s,a,f){var w=i==null?0:i.length;return w?(a&&typeof a!="number"&&Zt(i,s,a)&&(a=0,f=w),rO(i,s,a,f)):[]}function a3(i,s,a){var f=i==null?0:i.length;if(!f)return-1;var w=a==null?0:De(a);return w<0&&(w=Tt(f+w,0)),Tc(i,ke(s,3),w)}function l3(i,s,a){var f=i==null?0:i.length;if(!f)return-1;var w=f-1;return a!==n&&(w=De(a),w=a<0?Tt(f+w,0):Ft(w,f-1)),Tc(i,ke(s,3),w,!0)}function c3(i){var s=i==null?0:i.length;return s?Bt(i,1):[]}function f6(i){var s=i==null?0:i.length;return s?Bt(i,Q):[]}function p6(i,s){var a=i==null?0:i.length;return a?(s=s===n?1:De(s),Bt(i,s)):[]}function h6(i){for(var s=-1,a=i==null?0:i.length,f={};++s<a;){var w=i[s];f[w[0]]=w[1]}return f}function d3(i){return i&&i.length?i[0]:n}function g6(i,s,a){var f=i==null?0:i.length;if(!f)return-1;var w=a==null?0:De(a);return w<0&&(w=Tt(f+w,0)),Es(i,s,w)}function m6(i){var s=i==null?0:i.length;return s?Wn(i,0,-1):[]}var v6=Re(function(i){var s=at(i,cg);return s.length&&s[0]===i[0]?eg(s):[]}),_6=Re(function(i){var s=Kn(i),a=at(i,cg);return s===Kn(a)?s=n:a.pop(),a.length&&a[0]===i[0]?eg(a,ke(s,2)):[]}),y6=Re(function(i){var s=Kn(i),a=at(i,cg);return s=typeof s=="function"?s:n,s&&a.pop(),a.length&&a[0]===i[0]?eg(a,n,s):[]});function w6(i,s){return i==null?"":mL.call(i,s)}function Kn(i){var s=i==null?0:i.length;return s?i[s-1]:n}function b6(i,s,a){var f=i==null?0:i.length;if(!f)return-1;var w=f;return a!==n&&(w=De(a),w=w<0?Tt(f+w,0):Ft(w,f-1)),s===s?eL(i,s,w):Tc(i,H1,w,!0)}function S6(i,s){return i&&i.length?Sb(i,De(s)):n}var E6=Re(f3);function f3(i,s){return i&&i.length&&s&&s.length?ig(i,s):i}function k6(i,s,a){return i&&i.length&&s&&s.length?ig(i,s,ke(a,2)):i}function A6(i,s,a){return i&&i.length&&s&&s.length?ig(i,s,n,a):i}var C6=Yr(function(i,s){var a=i==null?0:i.length,f=Qh(i,s);return Ab(i,at(s,function(w){return Qr(w,a)?+w:w}).sort(qb)),f});function T6(i,s){var a=[];if(!(i&&i.length))return a;var f=-1,w=[],C=i.length;for(s=ke(s,3);++f<C;){var N=i[f];s(N,f,i)&&(a.push(N),w.push(f))}return Ab(i,w),a}function kg(i){return i==null?i:wL.call(i)}function x6(i,s,a){var f=i==null?0:i.length;return f?(a&&typeof a!="number"&&Zt(i,s,a)?(s=0,a=f):(s=s==null?0:De(s),a=a===n?f:De(a)),Wn(i,s,a)):[]}function L6(i,s){return Wc(i,s)}function O6(i,s,a){return ug(i,s,ke(a,2))}function P6(i,s){var a=i==null?0:i.length;if(a){var f=Wc(i,s);if(f<a&&fr(i[f],s))return f}return-1}function D6(i,s){return Wc(i,s,!0)}function N6(i,s,a){return ug(i,s,ke(a,2),!0)}function R6(i,s){var a=i==null?0:i.length;if(a){var f=Wc(i,s,!0)-1;if(fr(i[f],s))return f}return-1}function q6(i){return i&&i.length?Tb(i):[]}function I6(i,s){return i&&i.length?Tb(i,ke(s,2)):[]}function M6(i){var s=i==null?0:i.length;return s?Wn(i,1,s):[]}function B6(i,s,a){return i&&i.length?(s=a||s===n?1:De(s),Wn(i,0,s<0?0:s)):[]}function z6(i,s,a){var f=i==null?0:i.length;return f?(s=a||s===n?1:De(s),s=f-s,Wn(i,s<0?0:s,f)):[]}function $6(i,s){return i&&i.length?Kc(i,ke(s,3),!1,!0):[]}function U6(i,s){return i&&i.length?Kc(i,ke(s,3)):[]}var j6=Re(function(i){return Mi(Bt(i,1,vt,!0))}),F6=Re(function(i){var s=Kn(i);return vt(s)&&(s=n),Mi(Bt(i,1,vt,!0),ke(s,2))}),V6=Re(function(i){var s=Kn(i);return s=typeof s=="function"?s:n,Mi(Bt(i,1,vt,!0),n,s)});function H6(i){return i&&i.length?Mi(i):[]}function G6(i,s){return i&&i.length?Mi(i,ke(s,2)):[]}function W6(i,s){return s=typeof s=="function"?s:n,i&&i.length?Mi(i,n,s):[]}function Ag(i){if(!(i&&i.length))return[];var s=0;return i=Di(i,function(a){if(vt(a))return s=Tt(a.length,s),!0}),jh(s,function(a){return at(i,zh(a))})}function p3(i,s){if(!(i&&i.length))return[];var a=Ag(i);return s==null?a:at(a,function(f){return Cn(s,n,f)})}var K6=Re(function(i,s){return vt(i)?aa(i,s):[]}),Y6=Re(function(i){return lg(Di(i,vt))}),Q6=Re(function(i){var s=Kn(i);return vt(s)&&(s=n),lg(Di(i,vt),ke(s,2))}),X6=Re(function(i){var s=Kn(i);return s=typeof s=="function"?s:n,lg(Di(i,vt),n,s)}),Z6=Re(Ag);function J6(i,s){return Pb(i||[],s||[],ua)}function eP(i,s){return Pb(i||[],s||[],da)}var tP=Re(function(i){var s=i.length,a=s>1?i[s-1]:n;return a=typeof a=="function"?(i.pop(),a):n,p3(i,a)});function h3(i){var s=S(i);return s.__chain__=!0,s}function nP(i,s){return s(i),i}function rd(i,s){return s(i)}var rP=Yr(function(i){var s=i.length,a=s?i[0]:0,f=this.__wrapped__,w=function(C){return Qh(C,i)};return s>1||this.__actions__.length||!(f instanceof Be)||!Qr(a)?this.thru(w):(f=f.slice(a,+a+(s?1:0)),f.__actions__.push({func:rd,args:[w],thisArg:n}),new Hn(f,this.__chain__).thru(function(C){return s&&!C.length&&C.push(n),C}))});function iP(){return h3(this)}function oP(){return new Hn(this.value(),this.__chain__)}function sP(){this.__values__===n&&(this.__values__=x3(this.value()));var i=this.__index__>=this.__values__.length,s=i?n:this.__values__[this.__index__++];return{done:i,value:s}}function uP(){return this}function aP(i){for(var s,a=this;a instanceof jc;){var f=u3(a);f.__index__=0,f.__values__=n,s?w.__wrapped__=f:s=f;var w=f;a=a.__wrapped__}return w.__wrapped__=i,s}function lP(){var i=this.__wrapped__;if(i instanceof Be){var s=i;return this.__actions__.length&&(s=new Be(this)),s=s.reverse(),s.__actions__.push({func:rd,args:[kg],thisArg:n}),new Hn(s,this.__chain__)}return this.thru(kg)}function cP(){return Ob(this.__wrapped__,this.__actions__)}var dP=Yc(function(i,s,a){Ye.call(i,a)?++i[a]:Wr(i,a,1)});function fP(i,s,a){var f=Pe(i)?F1:nO;return a&&Zt(i,s,a)&&(s=n),f(i,ke(s,3))}function pP(i,s){var a=Pe(i)?Di:pb;return a(i,ke(s,3))}var hP=Ub(a3),gP=Ub(l3);function mP(i,s){return Bt(id(i,s),1)}function vP(i,s){return Bt(id(i,s),Q)}function _P(i,s,a){return a=a===n?1:De(a),Bt(id(i,s),a)}function g3(i,s){var a=Pe(i)?Fn:Ii;return a(i,ke(s,3))}function m3(i,s){var a=Pe(i)?Mx:fb;return a(i,ke(s,3))}var yP=Yc(function(i,s,a){Ye.call(i,a)?i[a].push(s):Wr(i,a,[s])});function wP(i,s,a,f){i=cn(i)?i:qs(i),a=a&&!f?De(a):0;var w=i.length;return a<0&&(a=Tt(w+a,0)),ld(i)?a<=w&&i.indexOf(s,a)>-1:!!w&&Es(i,s,a)>-1}var bP=Re(function(i,s,a){var f=-1,w=typeof s=="function",C=cn(i)?X(i.length):[];return Ii(i,function(N){C[++f]=w?Cn(s,N,a):la(N,s,a)}),C}),SP=Yc(function(i,s,a){Wr(i,a,s)});function id(i,s){var a=Pe(i)?at:yb;return a(i,ke(s,3))}function EP(i,s,a,f){return i==null?[]:(Pe(s)||(s=s==null?[]:[s]),a=f?n:a,Pe(a)||(a=a==null?[]:[a]),Eb(i,s,a))}var kP=Yc(function(i,s,a){i[a?0:1].push(s)},function(){return[[],[]]});function AP(i,s,a){var f=Pe(i)?Mh:W1,w=arguments.length<3;return f(i,ke(s,4),a,w,Ii)}function CP(i,s,a){var f=Pe(i)?Bx:W1,w=arguments.length<3;return f(i,ke(s,4),a,w,fb)}function TP(i,s){var a=Pe(i)?Di:pb;return a(i,ud(ke(s,3)))}function xP(i){var s=Pe(i)?ab:wO;return s(i)}function LP(i,s,a){(a?Zt(i,s,a):s===n)?s=1:s=De(s);var f=Pe(i)?XL:bO;return f(i,s)}function OP(i){var s=Pe(i)?ZL:EO;return s(i)}function PP(i){if(i==null)return 0;if(cn(i))return ld(i)?As(i):i.length;var s=Vt(i);return s==fe||s==ge?i.size:ng(i).length}function DP(i,s,a){var f=Pe(i)?Bh:kO;return a&&Zt(i,s,a)&&(s=n),f(i,ke(s,3))}var NP=Re(function(i,s){if(i==null)return[];var a=s.length;return a>1&&Zt(i,s[0],s[1])?s=[]:a>2&&Zt(s[0],s[1],s[2])&&(s=[s[0]]),Eb(i,Bt(s,1),[])}),od=pL||function(){return Mt.Date.now()};function RP(i,s){if(typeof s!="function")throw new Vn(c);return i=De(i),function(){if(--i<1)return s.apply(this,arguments)}}function v3(i,s,a){return s=a?n:s,s=i&&s==null?i.length:s,Kr(i,_,n,n,n,n,s)}function _3(i,s){var a;if(typeof s!="function")throw new Vn(c);return i=De(i),function(){return--i>0&&(a=s.apply(this,arguments)),i<=1&&(s=n),a}}var Cg=Re(function(i,s,a){var f=z;if(a.length){var w=Ri(a,Ns(Cg));f|=h}return Kr(i,f,s,a,w)}),y3=Re(function(i,s,a){var f=z|L;if(a.length){var w=Ri(a,Ns(y3));f|=h}return Kr(s,f,i,a,w)});function w3(i,s,a){s=a?n:s;var f=Kr(i,x,n,n,n,n,n,s);return f.placeholder=w3.placeholder,f}function b3(i,s,a){s=a?n:s;var f=Kr(i,l,n,n,n,n,n,s);return f.placeholder=b3.placeholder,f}function S3(i,s,a){var f,w,C,N,B,F,ne=0,re=!1,ue=!1,me=!0;if(typeof i!="function")throw new Vn(c);s=Yn(s)||0,ft(a)&&(re=!!a.leading,ue="maxWait"in a,C=ue?Tt(Yn(a.maxWait)||0,s):C,me="trailing"in a?!!a.trailing:me);function be(_t){var pr=f,Jr=w;return f=w=n,ne=_t,N=i.apply(Jr,pr),N}function Ae(_t){return ne=_t,B=ha(qe,s),re?be(_t):N}function Ne(_t){var pr=_t-F,Jr=_t-ne,U3=s-pr;return ue?Ft(U3,C-Jr):U3}function Ce(_t){var pr=_t-F,Jr=_t-ne;return F===n||pr>=s||pr<0||ue&&Jr>=C}function qe(){var _t=od();if(Ce(_t))return $e(_t);B=ha(qe,Ne(_t))}function $e(_t){return B=n,me&&f?be(_t):(f=w=n,N)}function On(){B!==n&&Db(B),ne=0,f=F=w=B=n}function Jt(){return B===n?N:$e(od())}function Pn(){var _t=od(),pr=Ce(_t);if(f=arguments,w=this,F=_t,pr){if(B===n)return Ae(F);if(ue)return Db(B),B=ha(qe,s),be(F)}return B===n&&(B=ha(qe,s)),N}return Pn.cancel=On,Pn.flush=Jt,Pn}var qP=Re(function(i,s){return db(i,1,s)}),IP=Re(function(i,s,a){return db(i,Yn(s)||0,a)});function MP(i){return Kr(i,y)}function sd(i,s){if(typeof i!="function"||s!=null&&typeof s!="function")throw new Vn(c);var a=function(){var f=arguments,w=s?s.apply(this,f):f[0],C=a.cache;if(C.has(w))return C.get(w);var N=i.apply(this,f);return a.cache=C.set(w,N)||C,N};return a.cache=new(sd.Cache||Gr),a}sd.Cache=Gr;function ud(i){if(typeof i!="function")throw new Vn(c);return function(){var s=arguments;switch(s.length){case 0:return!i.call(this);case 1:return!i.call(this,s[0]);case 2:return!i.call(this,s[0],s[1]);case 3:return!i.call(this,s[0],s[1],s[2])}return!i.apply(this,s)}}function BP(i){return _3(2,i)}var zP=AO(function(i,s){s=s.length==1&&Pe(s[0])?at(s[0],Tn(ke())):at(Bt(s,1),Tn(ke()));var a=s.length;return Re(function(f){for(var w=-1,C=Ft(f.length,a);++w<C;)f[w]=s[w].call(this,f[w]);return Cn(i,this,f)})}),Tg=Re(function(i,s){var a=Ri(s,Ns(Tg));return Kr(i,h,n,s,a)}),E3=Re(function(i,s){var a=Ri(s,Ns(E3));return Kr(i,d,n,s,a)}),$P=Yr(function(i,s){return Kr(i,v,n,n,n,s)});function UP(i,s){if(typeof i!="function")throw new Vn(c);return s=s===n?s:De(s),Re(i,s)}function jP(i,s){if(typeof i!="function")throw new Vn(c);return s=s==null?0:Tt(De(s),0),Re(function(a){var f=a[s],w=zi(a,0,s);return f&&Ni(w,f),Cn(i,this,w)})}function FP(i,s,a){var f=!0,w=!0;if(typeof i!="function")throw new Vn(c);return ft(a)&&(f="leading"in a?!!a.leading:f,w="trailing"in a?!!a.trailing:w),S3(i,s,{leading:f,maxWait:s,trailing:w})}function VP(i){return v3(i,1)}function HP(i,s){return Tg(dg(s),i)}function GP(){if(!arguments.length)return[];var i=arguments[0];return Pe(i)?i:[i]}function WP(i){return Gn(i,Z)}function KP(i,s){return s=typeof s=="function"?s:n,Gn(i,Z,s)}function YP(i){return Gn(i,P|Z)}function QP(i,s){return s=typeof s=="function"?s:n,Gn(i,P|Z,s)}function XP(i,s){return s==null||cb(i,s,Dt(s))}function fr(i,s){return i===s||i!==i&&s!==s}var ZP=Jc(Jh),JP=Jc(function(i,s){return i>=s}),Ao=mb(function(){return arguments}())?mb:function(i){return gt(i)&&Ye.call(i,"callee")&&!nb.call(i,"callee")},Pe=X.isArray,eD=M1?Tn(M1):aO;function cn(i){return i!=null&&ad(i.length)&&!Xr(i)}function vt(i){return gt(i)&&cn(i)}function tD(i){return i===!0||i===!1||gt(i)&&Xt(i)==ce}var $i=gL||Bg,nD=B1?Tn(B1):lO;function rD(i){return gt(i)&&i.nodeType===1&&!ga(i)}function iD(i){if(i==null)return!0;if(cn(i)&&(Pe(i)||typeof i=="string"||typeof i.splice=="function"||$i(i)||Rs(i)||Ao(i)))return!i.length;var s=Vt(i);if(s==fe||s==ge)return!i.size;if(pa(i))return!ng(i).length;for(var a in i)if(Ye.call(i,a))return!1;return!0}function oD(i,s){return ca(i,s)}function sD(i,s,a){a=typeof a=="function"?a:n;var f=a?a(i,s):n;return f===n?ca(i,s,n,a):!!f}function xg(i){if(!gt(i))return!1;var s=Xt(i);return s==$||s==R||typeof i.message=="string"&&typeof i.name=="string"&&!ga(i)}function uD(i){return typeof i=="number"&&ib(i)}function Xr(i){if(!ft(i))return!1;var s=Xt(i);return s==se||s==ae||s==Ze||s==pe}function k3(i){return typeof i=="number"&&i==De(i)}function ad(i){return typeof i=="number"&&i>-1&&i%1==0&&i<=G}function ft(i){var s=typeof i;return i!=null&&(s=="object"||s=="function")}function gt(i){return i!=null&&typeof i=="object"}var A3=z1?Tn(z1):dO;function aD(i,s){return i===s||tg(i,s,_g(s))}function lD(i,s,a){return a=typeof a=="function"?a:n,tg(i,s,_g(s),a)}function cD(i){return C3(i)&&i!=+i}function dD(i){if(KO(i))throw new xe(u);return vb(i)}function fD(i){return i===null}function pD(i){return i==null}function C3(i){return typeof i=="number"||gt(i)&&Xt(i)==I}function ga(i){if(!gt(i)||Xt(i)!=J)return!1;var s=qc(i);if(s===null)return!0;var a=Ye.call(s,"constructor")&&s.constructor;return typeof a=="function"&&a instanceof a&&Pc.call(a)==lL}var Lg=$1?Tn($1):fO;function hD(i){return k3(i)&&i>=-G&&i<=G}var T3=U1?Tn(U1):pO;function ld(i){return typeof i=="string"||!Pe(i)&&gt(i)&&Xt(i)==Te}function Ln(i){return typeof i=="symbol"||gt(i)&&Xt(i)==Ve}var Rs=j1?Tn(j1):hO;function gD(i){return i===n}function mD(i){return gt(i)&&Vt(i)==mo}function vD(i){return gt(i)&&Xt(i)==Ju}var _D=Jc(rg),yD=Jc(function(i,s){return i<=s});function x3(i){if(!i)return[];if(cn(i))return ld(i)?cr(i):ln(i);if(na&&i[na])return Xx(i[na]());var s=Vt(i),a=s==fe?Vh:s==ge?xc:qs;return a(i)}function Zr(i){if(!i)return i===0?i:0;if(i=Yn(i),i===Q||i===-Q){var s=i<0?-1:1;return s*ee}return i===i?i:0}function De(i){var s=Zr(i),a=s%1;return s===s?a?s-a:s:0}function L3(i){return i?bo(De(i),0,le):0}function Yn(i){if(typeof i=="number")return i;if(Ln(i))return ie;if(ft(i)){var s=typeof i.valueOf=="function"?i.valueOf():i;i=ft(s)?s+"":s}if(typeof i!="string")return i===0?i:+i;i=K1(i);var a=ex.test(i);return a||nx.test(i)?Rx(i.slice(2),a?2:8):JT.test(i)?ie:+i}function O3(i){return Cr(i,dn(i))}function wD(i){return i?bo(De(i),-G,G):i===0?i:0}function We(i){return i==null?"":xn(i)}var bD=Ps(function(i,s){if(pa(s)||cn(s)){Cr(s,Dt(s),i);return}for(var a in s)Ye.call(s,a)&&ua(i,a,s[a])}),P3=Ps(function(i,s){Cr(s,dn(s),i)}),cd=Ps(function(i,s,a,f){Cr(s,dn(s),i,f)}),SD=Ps(function(i,s,a,f){Cr(s,Dt(s),i,f)}),ED=Yr(Qh);function kD(i,s){var a=Os(i);return s==null?a:lb(a,s)}var AD=Re(function(i,s){i=Je(i);var a=-1,f=s.length,w=f>2?s[2]:n;for(w&&Zt(s[0],s[1],w)&&(f=1);++a<f;)for(var C=s[a],N=dn(C),B=-1,F=N.length;++B<F;){var ne=N[B],re=i[ne];(re===n||fr(re,Ts[ne])&&!Ye.call(i,ne))&&(i[ne]=C[ne])}return i}),CD=Re(function(i){return i.push(n,Kb),Cn(D3,n,i)});function TD(i,s){return V1(i,ke(s,3),Ar)}function xD(i,s){return V1(i,ke(s,3),Zh)}function LD(i,s){return i==null?i:Xh(i,ke(s,3),dn)}function OD(i,s){return i==null?i:hb(i,ke(s,3),dn)}function PD(i,s){return i&&Ar(i,ke(s,3))}function DD(i,s){return i&&Zh(i,ke(s,3))}function ND(i){return i==null?[]:Hc(i,Dt(i))}function RD(i){return i==null?[]:Hc(i,dn(i))}function Og(i,s,a){var f=i==null?n:So(i,s);return f===n?a:f}function qD(i,s){return i!=null&&Xb(i,s,iO)}function Pg(i,s){return i!=null&&Xb(i,s,oO)}var ID=Fb(function(i,s,a){s!=null&&typeof s.toString!="function"&&(s=Dc.call(s)),i[s]=a},Ng(fn)),MD=Fb(function(i,s,a){s!=null&&typeof s.toString!="function"&&(s=Dc.call(s)),Ye.call(i,s)?i[s].push(a):i[s]=[a]},ke),BD=Re(la);function Dt(i){return cn(i)?ub(i):ng(i)}function dn(i){return cn(i)?ub(i,!0):gO(i)}function zD(i,s){var a={};return s=ke(s,3),Ar(i,function(f,w,C){Wr(a,s(f,w,C),f)}),a}function $D(i,s){var a={};return s=ke(s,3),Ar(i,function(f,w,C){Wr(a,w,s(f,w,C))}),a}var UD=Ps(function(i,s,a){Gc(i,s,a)}),D3=Ps(function(i,s,a,f){Gc(i,s,a,f)}),jD=Yr(function(i,s){var a={};if(i==null)return a;var f=!1;s=at(s,function(C){return C=Bi(C,i),f||(f=C.length>1),C}),Cr(i,mg(i),a),f&&(a=Gn(a,P|q|Z,IO));for(var w=s.length;w--;)ag(a,s[w]);return a});function FD(i,s){return N3(i,ud(ke(s)))}var VD=Yr(function(i,s){return i==null?{}:vO(i,s)});function N3(i,s){if(i==null)return{};var a=at(mg(i),function(f){return[f]});return s=ke(s),kb(i,a,function(f,w){return s(f,w[0])})}function HD(i,s,a){s=Bi(s,i);var f=-1,w=s.length;for(w||(w=1,i=n);++f<w;){var C=i==null?n:i[Tr(s[f])];C===n&&(f=w,C=a),i=Xr(C)?C.call(i):C}return i}function GD(i,s,a){return i==null?i:da(i,s,a)}function WD(i,s,a,f){return f=typeof f=="function"?f:n,i==null?i:da(i,s,a,f)}var R3=Gb(Dt),q3=Gb(dn);function KD(i,s,a){var f=Pe(i),w=f||$i(i)||Rs(i);if(s=ke(s,4),a==null){var C=i&&i.constructor;w?a=f?new C:[]:ft(i)?a=Xr(C)?Os(qc(i)):{}:a={}}return(w?Fn:Ar)(i,function(N,B,F){return s(a,N,B,F)}),a}function YD(i,s){return i==null?!0:ag(i,s)}function QD(i,s,a){return i==null?i:Lb(i,s,dg(a))}function XD(i,s,a,f){return f=typeof f=="function"?f:n,i==null?i:Lb(i,s,dg(a),f)}function qs(i){return i==null?[]:Fh(i,Dt(i))}function ZD(i){return i==null?[]:Fh(i,dn(i))}function JD(i,s,a){return a===n&&(a=s,s=n),a!==n&&(a=Yn(a),a=a===a?a:0),s!==n&&(s=Yn(s),s=s===s?s:0),bo(Yn(i),s,a)}function e4(i,s,a){return s=Zr(s),a===n?(a=s,s=0):a=Zr(a),i=Yn(i),sO(i,s,a)}function t4(i,s,a){if(a&&typeof a!="boolean"&&Zt(i,s,a)&&(s=a=n),a===n&&(typeof s=="boolean"?(a=s,s=n):typeof i=="boolean"&&(a=i,i=n)),i===n&&s===n?(i=0,s=1):(i=Zr(i),s===n?(s=i,i=0):s=Zr(s)),i>s){var f=i;i=s,s=f}if(a||i%1||s%1){var w=ob();return Ft(i+w*(s-i+Nx("1e-"+((w+"").length-1))),s)}return og(i,s)}var n4=Ds(function(i,s,a){return s=s.toLowerCase(),i+(a?I3(s):s)});function I3(i){return Dg(We(i).toLowerCase())}function M3(i){return i=We(i),i&&i.replace(ix,Gx).replace(Ex,"")}function r4(i,s,a){i=We(i),s=xn(s);var f=i.length;a=a===n?f:bo(De(a),0,f);var w=a;return a-=s.length,a>=0&&i.slice(a,w)==s}function i4(i){return i=We(i),i&&BT.test(i)?i.replace(h1,Wx):i}function o4(i){return i=We(i),i&&VT.test(i)?i.replace(Th,"\\$&"):i}var s4=Ds(function(i,s,a){return i+(a?"-":"")+s.toLowerCase()}),u4=Ds(function(i,s,a){return i+(a?" ":"")+s.toLowerCase()}),a4=$b("toLowerCase");function l4(i,s,a){i=We(i),s=De(s);var f=s?As(i):0;if(!s||f>=s)return i;var w=(s-f)/2;return Zc(zc(w),a)+i+Zc(Bc(w),a)}function c4(i,s,a){i=We(i),s=De(s);var f=s?As(i):0;return s&&f<s?i+Zc(s-f,a):i}function d4(i,s,a){i=We(i),s=De(s);var f=s?As(i):0;return s&&f<s?Zc(s-f,a)+i:i}function f4(i,s,a){return a||s==null?s=0:s&&(s=+s),yL(We(i).replace(xh,""),s||0)}function p4(i,s,a){return(a?Zt(i,s,a):s===n)?s=1:s=De(s),sg(We(i),s)}function h4(){var i=arguments,s=We(i[0]);return i.length<3?s:s.replace(i[1],i[2])}var g4=Ds(function(i,s,a){return i+(a?"_":"")+s.toLowerCase()});function m4(i,s,a){return a&&typeof a!="number"&&Zt(i,s,a)&&(s=a=n),a=a===n?le:a>>>0,a?(i=We(i),i&&(typeof s=="string"||s!=null&&!Lg(s))&&(s=xn(s),!s&&ks(i))?zi(cr(i),0,a):i.split(s,a)):[]}var v4=Ds(function(i,s,a){return i+(a?" ":"")+Dg(s)});function _4(i,s,a){return i=We(i),a=a==null?0:bo(De(a),0,i.length),s=xn(s),i.slice(a,a+s.length)==s}function y4(i,s,a){var f=S.templateSettings;a&&Zt(i,s,a)&&(s=n),i=We(i),s=cd({},s,f,Wb);var w=cd({},s.imports,f.imports,Wb),C=Dt(w),N=Fh(w,C),B,F,ne=0,re=s.interpolate||Ec,ue="__p += '",me=Hh((s.escape||Ec).source+"|"+re.source+"|"+(re===g1?ZT:Ec).source+"|"+(s.evaluate||Ec).source+"|$","g"),be="//# sourceURL="+(Ye.call(s,"sourceURL")?(s.sourceURL+"").replace(/\s/g," "):"lodash.templateSources["+ ++xx+"]")+`
-`;i.replace(me,function(Ce,qe,$e,On,Jt,Pn){return $e||($e=On),ue+=i.slice(ne,Pn).replace(ox,Kx),qe&&(B=!0,ue+=`' +
-__e(`+qe+`) +
-'`),Jt&&(F=!0,ue+=`';
-`+Jt+`;
-__p += '`),$e&&(ue+=`' +
-((__t = (`+$e+`)) == null ? '' : __t) +
-'`),ne=Pn+Ce.length,Ce}),ue+=`';
-`;var Ae=Ye.call(s,"variable")&&s.variable;if(!Ae)ue=`with (obj) {
-`+ue+`
-}
-`;else if(QT.test(Ae))throw new xe(p);ue=(F?ue.replace(RT,""):ue).replace(qT,"$1").replace(IT,"$1;"),ue="function("+(Ae||"obj")+`) {
-`+(Ae?"":`obj || (obj = {});
-`)+"var __t, __p = ''"+(B?", __e = _.escape":"")+(F?`, __j = Array.prototype.join;
-function print() { __p += __j.call(arguments, '') }
-`:`;
-`)+ue+`return __p
-}`;var Ne=z3(function(){return Ge(C,be+"return "+ue).apply(n,N)});if(Ne.source=ue,xg(Ne))throw Ne;return Ne}function w4(i){return We(i).toLowerCase()}function b4(i){return We(i).toUpperCase()}function S4(i,s,a){if(i=We(i),i&&(a||s===n))return K1(i);if(!i||!(s=xn(s)))return i;var f=cr(i),w=cr(s),C=Y1(f,w),N=Q1(f,w)+1;return zi(f,C,N).join("")}function E4(i,s,a){if(i=We(i),i&&(a||s===n))return i.slice(0,Z1(i)+1);if(!i||!(s=xn(s)))return i;var f=cr(i),w=Q1(f,cr(s))+1;return zi(f,0,w).join("")}function k4(i,s,a){if(i=We(i),i&&(a||s===n))return i.replace(xh,"");if(!i||!(s=xn(s)))return i;var f=cr(i),w=Y1(f,cr(s));return zi(f,w).join("")}function A4(i,s){var a=m,f=E;if(ft(s)){var w="separator"in s?s.separator:w;a="length"in s?De(s.length):a,f="omission"in s?xn(s.omission):f}i=We(i);var C=i.length;if(ks(i)){var N=cr(i);C=N.length}if(a>=C)return i;var B=a-As(f);if(B<1)return f;var F=N?zi(N,0,B).join(""):i.slice(0,B);if(w===n)return F+f;if(N&&(B+=F.length-B),Lg(w)){if(i.slice(B).search(w)){var ne,re=F;for(w.global||(w=Hh(w.source,We(m1.exec(w))+"g")),w.lastIndex=0;ne=w.exec(re);)var ue=ne.index;F=F.slice(0,ue===n?B:ue)}}else if(i.indexOf(xn(w),B)!=B){var me=F.lastIndexOf(w);me>-1&&(F=F.slice(0,me))}return F+f}function C4(i){return i=We(i),i&&MT.test(i)?i.replace(p1,tL):i}var T4=Ds(function(i,s,a){return i+(a?" ":"")+s.toUpperCase()}),Dg=$b("toUpperCase");function B3(i,s,a){return i=We(i),s=a?n:s,s===n?Qx(i)?iL(i):Ux(i):i.match(s)||[]}var z3=Re(function(i,s){try{return Cn(i,n,s)}catch(a){return xg(a)?a:new xe(a)}}),x4=Yr(function(i,s){return Fn(s,function(a){a=Tr(a),Wr(i,a,Cg(i[a],i))}),i});function L4(i){var s=i==null?0:i.length,a=ke();return i=s?at(i,function(f){if(typeof f[1]!="function")throw new Vn(c);return[a(f[0]),f[1]]}):[],Re(function(f){for(var w=-1;++w<s;){var C=i[w];if(Cn(C[0],this,f))return Cn(C[1],this,f)}})}function O4(i){return tO(Gn(i,P))}function Ng(i){return function(){return i}}function P4(i,s){return i==null||i!==i?s:i}var D4=jb(),N4=jb(!0);function fn(i){return i}function Rg(i){return _b(typeof i=="function"?i:Gn(i,P))}function R4(i){return wb(Gn(i,P))}function q4(i,s){return bb(i,Gn(s,P))}var I4=Re(function(i,s){return function(a){return la(a,i,s)}}),M4=Re(function(i,s){return function(a){return la(i,a,s)}});function qg(i,s,a){var f=Dt(s),w=Hc(s,f);a==null&&!(ft(s)&&(w.length||!f.length))&&(a=s,s=i,i=this,w=Hc(s,Dt(s)));var C=!(ft(a)&&"chain"in a)||!!a.chain,N=Xr(i);return Fn(w,function(B){var F=s[B];i[B]=F,N&&(i.prototype[B]=function(){var ne=this.__chain__;if(C||ne){var re=i(this.__wrapped__),ue=re.__actions__=ln(this.__actions__);return ue.push({func:F,args:arguments,thisArg:i}),re.__chain__=ne,re}return F.apply(i,Ni([this.value()],arguments))})}),i}function B4(){return Mt._===this&&(Mt._=cL),this}function Ig(){}function z4(i){return i=De(i),Re(function(s){return Sb(s,i)})}var $4=pg(at),U4=pg(F1),j4=pg(Bh);function $3(i){return wg(i)?zh(Tr(i)):_O(i)}function F4(i){return function(s){return i==null?n:So(i,s)}}var V4=Vb(),H4=Vb(!0);function Mg(){return[]}function Bg(){return!1}function G4(){return{}}function W4(){return""}function K4(){return!0}function Y4(i,s){if(i=De(i),i<1||i>G)return[];var a=le,f=Ft(i,le);s=ke(s),i-=le;for(var w=jh(f,s);++a<i;)s(a);return w}function Q4(i){return Pe(i)?at(i,Tr):Ln(i)?[i]:ln(s3(We(i)))}function X4(i){var s=++aL;return We(i)+s}var Z4=Xc(function(i,s){return i+s},0),J4=hg("ceil"),eN=Xc(function(i,s){return i/s},1),tN=hg("floor");function nN(i){return i&&i.length?Vc(i,fn,Jh):n}function rN(i,s){return i&&i.length?Vc(i,ke(s,2),Jh):n}function iN(i){return G1(i,fn)}function oN(i,s){return G1(i,ke(s,2))}function sN(i){return i&&i.length?Vc(i,fn,rg):n}function uN(i,s){return i&&i.length?Vc(i,ke(s,2),rg):n}var aN=Xc(function(i,s){return i*s},1),lN=hg("round"),cN=Xc(function(i,s){return i-s},0);function dN(i){return i&&i.length?Uh(i,fn):0}function fN(i,s){return i&&i.length?Uh(i,ke(s,2)):0}return S.after=RP,S.ary=v3,S.assign=bD,S.assignIn=P3,S.assignInWith=cd,S.assignWith=SD,S.at=ED,S.before=_3,S.bind=Cg,S.bindAll=x4,S.bindKey=y3,S.castArray=GP,S.chain=h3,S.chunk=t6,S.compact=n6,S.concat=r6,S.cond=L4,S.conforms=O4,S.constant=Ng,S.countBy=dP,S.create=kD,S.curry=w3,S.curryRight=b3,S.debounce=S3,S.defaults=AD,S.defaultsDeep=CD,S.defer=qP,S.delay=IP,S.difference=i6,S.differenceBy=o6,S.differenceWith=s6,S.drop=u6,S.dropRight=a6,S.dropRightWhile=l6,S.dropWhile=c6,S.fill=d6,S.filter=pP,S.flatMap=mP,S.flatMapDeep=vP,S.flatMapDepth=_P,S.flatten=c3,S.flattenDeep=f6,S.flattenDepth=p6,S.flip=MP,S.flow=D4,S.flowRight=N4,S.fromPairs=h6,S.functions=ND,S.functionsIn=RD,S.groupBy=yP,S.initial=m6,S.intersection=v6,S.intersectionBy=_6,S.intersectionWith=y6,S.invert=ID,S.invertBy=MD,S.invokeMap=bP,S.iteratee=Rg,S.keyBy=SP,S.keys=Dt,S.keysIn=dn,S.map=id,S.mapKeys=zD,S.mapValues=$D,S.matches=R4,S.matchesProperty=q4,S.memoize=sd,S.merge=UD,S.mergeWith=D3,S.method=I4,S.methodOf=M4,S.mixin=qg,S.negate=ud,S.nthArg=z4,S.omit=jD,S.omitBy=FD,S.once=BP,S.orderBy=EP,S.over=$4,S.overArgs=zP,S.overEvery=U4,S.overSome=j4,S.partial=Tg,S.partialRight=E3,S.partition=kP,S.pick=VD,S.pickBy=N3,S.property=$3,S.propertyOf=F4,S.pull=E6,S.pullAll=f3,S.pullAllBy=k6,S.pullAllWith=A6,S.pullAt=C6,S.range=V4,S.rangeRight=H4,S.rearg=$P,S.reject=TP,S.remove=T6,S.rest=UP,S.reverse=kg,S.sampleSize=LP,S.set=GD,S.setWith=WD,S.shuffle=OP,S.slice=x6,S.sortBy=NP,S.sortedUniq=q6,S.sortedUniqBy=I6,S.split=m4,S.spread=jP,S.tail=M6,S.take=B6,S.takeRight=z6,S.takeRightWhile=$6,S.takeWhile=U6,S.tap=nP,S.throttle=FP,S.thru=rd,S.toArray=x3,S.toPairs=R3,S.toPairsIn=q3,S.toPath=Q4,S.toPlainObject=O3,S.transform=KD,S.unary=VP,S.union=j6,S.unionBy=F6,S.unionWith=V6,S.uniq=H6,S.uniqBy=G6,S.uniqWith=W6,S.unset=YD,S.unzip=Ag,S.unzipWith=p3,S.update=QD,S.updateWith=XD,S.values=qs,S.valuesIn=ZD,S.without=K6,S.words=B3,S.wrap=HP,S.xor=Y6,S.xorBy=Q6,S.xorWith=X6,S.zip=Z6,S.zipObject=J6,S.zipObjectDeep=eP,S.zipWith=tP,S.entries=R3,S.entriesIn=q3,S.extend=P3,S.extendWith=cd,qg(S,S),S.add=Z4,S.attempt=z3,S.camelCase=n4,S.capitalize=I3,S.ceil=J4,S.clamp=JD,S.clone=WP,S.cloneDeep=YP,S.cloneDeepWith=QP,S.cloneWith=KP,S.conformsTo=XP,S.deburr=M3,S.defaultTo=P4,S.divide=eN,S.endsWith=r4,S.eq=fr,S.escape=i4,S.escapeRegExp=o4,S.every=fP,S.find=hP,S.findIndex=a3,S.findKey=TD,S.findLast=gP,S.findLastIndex=l3,S.findLastKey=xD,S.floor=tN,S.forEach=g3,S.forEachRight=m3,S.forIn=LD,S.forInRight=OD,S.forOwn=PD,S.forOwnRight=DD,S.get=Og,S.gt=ZP,S.gte=JP,S.has=qD,S.hasIn=Pg,S.head=d3,S.identity=fn,S.includes=wP,S.indexOf=g6,S.inRange=e4,S.invoke=BD,S.isArguments=Ao,S.isArray=Pe,S.isArrayBuffer=eD,S.isArrayLike=cn,S.isArrayLikeObject=vt,S.isBoolean=tD,S.isBuffer=$i,S.isDate=nD,S.isElement=rD,S.isEmpty=iD,S.isEqual=oD,S.isEqualWith=sD,S.isError=xg,S.isFinite=uD,S.isFunction=Xr,S.isInteger=k3,S.isLength=ad,S.isMap=A3,S.isMatch=aD,S.isMatchWith=lD,S.isNaN=cD,S.isNative=dD,S.isNil=pD,S.isNull=fD,S.isNumber=C3,S.isObject=ft,S.isObjectLike=gt,S.isPlainObject=ga,S.isRegExp=Lg,S.isSafeInteger=hD,S.isSet=T3,S.isString=ld,S.isSymbol=Ln,S.isTypedArray=Rs,S.isUndefined=gD,S.isWeakMap=mD,S.isWeakSet=vD,S.join=w6,S.kebabCase=s4,S.last=Kn,S.lastIndexOf=b6,S.lowerCase=u4,S.lowerFirst=a4,S.lt=_D,S.lte=yD,S.max=nN,S.maxBy=rN,S.mean=iN,S.meanBy=oN,S.min=sN,S.minBy=uN,S.stubArray=Mg,S.stubFalse=Bg,S.stubObject=G4,S.stubString=W4,S.stubTrue=K4,S.multiply=aN,S.nth=S6,S.noConflict=B4,S.noop=Ig,S.now=od,S.pad=l4,S.padEnd=c4,S.padStart=d4,S.parseInt=f4,S.random=t4,S.reduce=AP,S.reduceRight=CP,S.repeat=p4,S.replace=h4,S.result=HD,S.round=lN,S.runInContext=U,S.sample=xP,S.size=PP,S.snakeCase=g4,S.some=DP,S.sortedIndex=L6,S.sortedIndexBy=O6,S.sortedIndexOf=P6,S.sortedLastIndex=D6,S.sortedLastIndexBy=N6,S.sortedLastIndexOf=R6,S.startCase=v4,S.startsWith=_4,S.subtract=cN,S.sum=dN,S.sumBy=fN,S.template=y4,S.times=Y4,S.toFinite=Zr,S.toInteger=De,S.toLength=L3,S.toLower=w4,S.toNumber=Yn,S.toSafeInteger=wD,S.toString=We,S.toUpper=b4,S.trim=S4,S.trimEnd=E4,S.trimStart=k4,S.truncate=A4,S.unescape=C4,S.uniqueId=X4,S.upperCase=T4,S.upperFirst=Dg,S.each=g3,S.eachRight=m3,S.first=d3,qg(S,function(){var i={};return Ar(S,function(s,a){Ye.call(S.prototype,a)||(i[a]=s)}),i}(),{chain:!1}),S.VERSION=r,Fn(["bind","bindKey","curry","curryRight","partial","partialRight"],function(i){S[i].placeholder=S}),Fn(["drop","take"],function(i,s){Be.prototype[i]=function(a){a=a===n?1:Tt(De(a),0);var f=this.__filtered__&&!s?new Be(this):this.clone();return f.__filtered__?f.__takeCount__=Ft(a,f.__takeCount__):f.__views__.push({size:Ft(a,le),type:i+(f.__dir__<0?"Right":"")}),f},Be.prototype[i+"Right"]=function(a){return this.reverse()[i](a).reverse()}}),Fn(["filter","map","takeWhile"],function(i,s){var a=s+1,f=a==V||a==T;Be.prototype[i]=function(w){var C=this.clone();return C.__iteratees__.push({iteratee:ke(w,3),type:a}),C.__filtered__=C.__filtered__||f,C}}),Fn(["head","last"],function(i,s){var a="take"+(s?"Right":"");Be.prototype[i]=function(){return this[a](1).value()[0]}}),Fn(["initial","tail"],function(i,s){var a="drop"+(s?"":"Right");Be.prototype[i]=function(){return this.__filtered__?new Be(this):this[a](1)}}),Be.prototype.compact=function(){return this.filter(fn)},Be.prototype.find=function(i){return this.filter(i).head()},Be.prototype.findLast=function(i){return this.reverse().find(i)},Be.prototype.invokeMap=Re(function(i,s){return typeof i=="function"?new Be(this):this.map(function(a){return la(a,i,s)})}),Be.prototype.reject=function(i){return this.filter(ud(ke(i)))},Be.prototype.slice=function(i,s){i=De(i);var a=this;return a.__filtered__&&(i>0||s<0)?new Be(a):(i<0?a=a.takeRight(-i):i&&(a=a.drop(i)),s!==n&&(s=De(s),a=s<0?a.dropRight(-s):a.take(s-i)),a)},Be.prototype.takeRightWhile=function(i){return this.reverse().takeWhile(i).reverse()},Be.prototype.toArray=function(){return this.take(le)},Ar(Be.prototype,function(i,s){var a=/^(?:filter|find|map|reject)|While$/.test(s),f=/^(?:head|last)$/.test(s),w=S[f?"take"+(s=="last"?"Right":""):s],C=f||/^find/.test(s);w&&(S.prototype[s]=function(){var N=this.__wrapped__,B=f?[1]:arguments,F=N instanceof Be,ne=B[0],re=F||Pe(N),ue=function(qe){var $e=w.apply(S,Ni([qe],B));return f&&me?$e[0]:$e};re&&a&&typeof ne=="function"&&ne.length!=1&&(F=re=!1);var me=this.__chain__,be=!!this.__actions__.length,Ae=C&&!me,Ne=F&&!be;if(!C&&re){N=Ne?N:new Be(this);var Ce=i.apply(N,B);return Ce.__actions__.push({func:rd,args:[ue],thisArg:n}),new Hn(Ce,me)}return Ae&&Ne?i.apply(this,B):(Ce=this.thru(ue),Ae?f?Ce.value()[0]:Ce.value():Ce)})}),Fn(["pop","push","shift","sort","splice","unshift"],function(i){var s=Lc[i],a=/^(?:push|sort|unshift)$/.test(i)?"tap":"thru",f=/^(?:pop|shift)$/.test(i);S.prototype[i]=function(){var w=arguments;if(f&&!this.__chain__){var C=this.value();return s.apply(Pe(C)?C:[],w)}return this[a](function(N){return s.apply(Pe(N)?N:[],w)})}}),Ar(Be.prototype,function(i,s){var a=S[s];if(a){var f=a.name+"";Ye.call(Ls,f)||(Ls[f]=[]),Ls[f].push({name:s,func:a})}}),Ls[Qc(n,L).name]=[{name:"wrapper",func:n}],Be.prototype.clone=CL,Be.prototype.reverse=TL,Be.prototype.value=xL,S.prototype.at=rP,S.prototype.chain=iP,S.prototype.commit=oP,S.prototype.next=sP,S.prototype.plant=aP,S.prototype.reverse=lP,S.prototype.toJSON=S.prototype.valueOf=S.prototype.value=cP,S.prototype.first=S.prototype.head,na&&(S.prototype[na]=uP),S},Cs=oL();vo?((vo.exports=Cs)._=Cs,Rh._=Cs):Mt._=Cs}).call(we)}(ba,ba.exports);var c8=ba.exports;const ji=Dn(c8);var Ms=e=>e.type==="checkbox",To=e=>e instanceof Date,Gt=e=>e==null;const Zg=e=>typeof e=="object";var bt=e=>!Gt(e)&&!Array.isArray(e)&&Zg(e)&&!To(e),d8=e=>bt(e)&&e.target?Ms(e.target)?e.target.checked:e.target.value:e,f8=e=>e.substring(0,e.search(/\.\d+(\.|$)/))||e,p8=(e,t)=>e.has(f8(t)),h8=e=>{const t=e.constructor&&e.constructor.prototype;return bt(t)&&t.hasOwnProperty("isPrototypeOf")},vd=typeof window<"u"&&typeof window.HTMLElement<"u"&&typeof document<"u";function Fi(e){let t;const n=Array.isArray(e);if(e instanceof Date)t=new Date(e);else if(e instanceof Set)t=new Set(e);else if(!(vd&&(e instanceof Blob||e instanceof FileList))&&(n||bt(e)))if(t=n?[]:{},!Array.isArray(e)&&!h8(e))t=e;else for(const r in e)t[r]=Fi(e[r]);else return e;return t}var Bs=e=>Array.isArray(e)?e.filter(Boolean):[],yt=e=>e===void 0,Se=(e,t,n)=>{if(!t||!bt(e))return n;const r=Bs(t.split(/[,[\].]+?/)).reduce((o,u)=>Gt(o)?o:o[u],e);return yt(r)||r===e?yt(e[t])?n:e[t]:r};const Jg={BLUR:"blur",FOCUS_OUT:"focusout",CHANGE:"change"},Qn={onBlur:"onBlur",onChange:"onChange",onSubmit:"onSubmit",onTouched:"onTouched",all:"all"},xr={max:"max",min:"min",maxLength:"maxLength",minLength:"minLength",pattern:"pattern",required:"required",validate:"validate"};K.createContext(null);var g8=(e,t,n,r=!0)=>{const o={defaultValues:t._defaultValues};for(const u in e)Object.defineProperty(o,u,{get:()=>{const c=u;return t._proxyFormState[c]!==Qn.all&&(t._proxyFormState[c]=!r||Qn.all),n&&(n[c]=!0),e[c]}});return o},Nn=e=>bt(e)&&!Object.keys(e).length,m8=(e,t,n,r)=>{n(e);const{name:o,...u}=e;return Nn(u)||Object.keys(u).length>=Object.keys(t).length||Object.keys(u).find(c=>t[c]===(!r||Qn.all))},_d=e=>Array.isArray(e)?e:[e];function v8(e){const t=K.useRef(e);t.current=e,K.useEffect(()=>{const n=!e.disabled&&t.current.subject&&t.current.subject.subscribe({next:t.current.next});return()=>{n&&n.unsubscribe()}},[e.disabled])}var hr=e=>typeof e=="string",_8=(e,t,n,r,o)=>hr(e)?(r&&t.watch.add(e),Se(n,e,o)):Array.isArray(e)?e.map(u=>(r&&t.watch.add(u),Se(n,u))):(r&&(t.watchAll=!0),n),yd=e=>/^\w*$/.test(e),em=e=>Bs(e.replace(/["|']|\]/g,"").split(/\.|\[/));function st(e,t,n){let r=-1;const o=yd(t)?[t]:em(t),u=o.length,c=u-1;for(;++r<u;){const p=o[r];let g=n;if(r!==c){const b=e[p];g=bt(b)||Array.isArray(b)?b:isNaN(+o[r+1])?{}:[]}e[p]=g,e=e[p]}return e}var y8=(e,t,n,r,o)=>t?{...n[e],types:{...n[e]&&n[e].types?n[e].types:{},[r]:o||!0}}:{};const wd=(e,t,n)=>{for(const r of n||Object.keys(e)){const o=Se(e,r);if(o){const{_f:u,...c}=o;if(u&&t(u.name)){if(u.ref.focus){u.ref.focus();break}else if(u.refs&&u.refs[0].focus){u.refs[0].focus();break}}else bt(c)&&wd(c,t)}}};var tm=e=>({isOnSubmit:!e||e===Qn.onSubmit,isOnBlur:e===Qn.onBlur,isOnChange:e===Qn.onChange,isOnAll:e===Qn.all,isOnTouch:e===Qn.onTouched}),nm=(e,t,n)=>!n&&(t.watchAll||t.watch.has(e)||[...t.watch].some(r=>e.startsWith(r)&&/^\.\w+/.test(e.slice(r.length)))),w8=(e,t,n)=>{const r=Bs(Se(e,n));return st(r,"root",t[n]),st(e,n,r),e},xo=e=>typeof e=="boolean",bd=e=>e.type==="file",ti=e=>typeof e=="function",Sa=e=>{if(!vd)return!1;const t=e?e.ownerDocument:0;return e instanceof(t&&t.defaultView?t.defaultView.HTMLElement:HTMLElement)},Ea=e=>hr(e),Sd=e=>e.type==="radio",ka=e=>e instanceof RegExp;const rm={value:!1,isValid:!1},im={value:!0,isValid:!0};var om=e=>{if(Array.isArray(e)){if(e.length>1){const t=e.filter(n=>n&&n.checked&&!n.disabled).map(n=>n.value);return{value:t,isValid:!!t.length}}return e[0].checked&&!e[0].disabled?e[0].attributes&&!yt(e[0].attributes.value)?yt(e[0].value)||e[0].value===""?im:{value:e[0].value,isValid:!0}:im:rm}return rm};const sm={isValid:!1,value:null};var um=e=>Array.isArray(e)?e.reduce((t,n)=>n&&n.checked&&!n.disabled?{isValid:!0,value:n.value}:t,sm):sm;function am(e,t,n="validate"){if(Ea(e)||Array.isArray(e)&&e.every(Ea)||xo(e)&&!e)return{type:n,message:Ea(e)?e:"",ref:t}}var Lo=e=>bt(e)&&!ka(e)?e:{value:e,message:""},lm=async(e,t,n,r,o)=>{const{ref:u,refs:c,required:p,maxLength:g,minLength:b,min:O,max:P,pattern:q,validate:Z,name:M,valueAsNumber:H,mount:z,disabled:L}=e._f,k=Se(t,M);if(!z||L)return{};const x=c?c[0]:u,l=D=>{r&&x.reportValidity&&(x.setCustomValidity(xo(D)?"":D||""),x.reportValidity())},h={},d=Sd(u),_=Ms(u),v=d||_,y=(H||bd(u))&&yt(u.value)&&yt(k)||Sa(u)&&u.value===""||k===""||Array.isArray(k)&&!k.length,m=y8.bind(null,M,n,h),E=(D,j,V,W=xr.maxLength,T=xr.minLength)=>{const Q=D?j:V;h[M]={type:D?W:T,message:Q,ref:u,...m(D?W:T,Q)}};if(o?!Array.isArray(k)||!k.length:p&&(!v&&(y||Gt(k))||xo(k)&&!k||_&&!om(c).isValid||d&&!um(c).isValid)){const{value:D,message:j}=Ea(p)?{value:!!p,message:p}:Lo(p);if(D&&(h[M]={type:xr.required,message:j,ref:x,...m(xr.required,j)},!n))return l(j),h}if(!y&&(!Gt(O)||!Gt(P))){let D,j;const V=Lo(P),W=Lo(O);if(!Gt(k)&&!isNaN(k)){const T=u.valueAsNumber||k&&+k;Gt(V.value)||(D=T>V.value),Gt(W.value)||(j=T<W.value)}else{const T=u.valueAsDate||new Date(k),Q=ie=>new Date(new Date().toDateString()+" "+ie),G=u.type=="time",ee=u.type=="week";hr(V.value)&&k&&(D=G?Q(k)>Q(V.value):ee?k>V.value:T>new Date(V.value)),hr(W.value)&&k&&(j=G?Q(k)<Q(W.value):ee?k<W.value:T<new Date(W.value))}if((D||j)&&(E(!!D,V.message,W.message,xr.max,xr.min),!n))return l(h[M].message),h}if((g||b)&&!y&&(hr(k)||o&&Array.isArray(k))){const D=Lo(g),j=Lo(b),V=!Gt(D.value)&&k.length>+D.value,W=!Gt(j.value)&&k.length<+j.value;if((V||W)&&(E(V,D.message,j.message),!n))return l(h[M].message),h}if(q&&!y&&hr(k)){const{value:D,message:j}=Lo(q);if(ka(D)&&!k.match(D)&&(h[M]={type:xr.pattern,message:j,ref:u,...m(xr.pattern,j)},!n))return l(j),h}if(Z){if(ti(Z)){const D=await Z(k,t),j=am(D,x);if(j&&(h[M]={...j,...m(xr.validate,j.message)},!n))return l(j.message),h}else if(bt(Z)){let D={};for(const j in Z){if(!Nn(D)&&!n)break;const V=am(await Z[j](k,t),x,j);V&&(D={...V,...m(j,V.message)},l(V.message),n&&(h[M]=D))}if(!Nn(D)&&(h[M]={ref:x,...D},!n))return h}}return l(!0),h};function b8(e,t){const n=t.slice(0,-1).length;let r=0;for(;r<n;)e=yt(e)?r++:e[t[r++]];return e}function S8(e){for(const t in e)if(!yt(e[t]))return!1;return!0}function Nt(e,t){const n=Array.isArray(t)?t:yd(t)?[t]:em(t),r=n.length===1?e:b8(e,n),o=n.length-1,u=n[o];return r&&delete r[u],o!==0&&(bt(r)&&Nn(r)||Array.isArray(r)&&S8(r))&&Nt(e,n.slice(0,-1)),e}function Ed(){let e=[];return{get observers(){return e},next:o=>{for(const u of e)u.next&&u.next(o)},subscribe:o=>(e.push(o),{unsubscribe:()=>{e=e.filter(u=>u!==o)}}),unsubscribe:()=>{e=[]}}}var Aa=e=>Gt(e)||!Zg(e);function Vi(e,t){if(Aa(e)||Aa(t))return e===t;if(To(e)&&To(t))return e.getTime()===t.getTime();const n=Object.keys(e),r=Object.keys(t);if(n.length!==r.length)return!1;for(const o of n){const u=e[o];if(!r.includes(o))return!1;if(o!=="ref"){const c=t[o];if(To(u)&&To(c)||bt(u)&&bt(c)||Array.isArray(u)&&Array.isArray(c)?!Vi(u,c):u!==c)return!1}}return!0}var cm=e=>e.type==="select-multiple",E8=e=>Sd(e)||Ms(e),kd=e=>Sa(e)&&e.isConnected,dm=e=>{for(const t in e)if(ti(e[t]))return!0;return!1};function Ca(e,t={}){const n=Array.isArray(e);if(bt(e)||n)for(const r in e)Array.isArray(e[r])||bt(e[r])&&!dm(e[r])?(t[r]=Array.isArray(e[r])?[]:{},Ca(e[r],t[r])):Gt(e[r])||(t[r]=!0);return t}function fm(e,t,n){const r=Array.isArray(e);if(bt(e)||r)for(const o in e)Array.isArray(e[o])||bt(e[o])&&!dm(e[o])?yt(t)||Aa(n[o])?n[o]=Array.isArray(e[o])?Ca(e[o],[]):{...Ca(e[o])}:fm(e[o],Gt(t)?{}:t[o],n[o]):n[o]=!Vi(e[o],t[o]);return n}var Ad=(e,t)=>fm(e,t,Ca(t)),pm=(e,{valueAsNumber:t,valueAsDate:n,setValueAs:r})=>yt(e)?e:t?e===""?NaN:e&&+e:n&&hr(e)?new Date(e):r?r(e):e;function Cd(e){const t=e.ref;if(!(e.refs?e.refs.every(n=>n.disabled):t.disabled))return bd(t)?t.files:Sd(t)?um(e.refs).value:cm(t)?[...t.selectedOptions].map(({value:n})=>n):Ms(t)?om(e.refs).value:pm(yt(t.value)?e.ref.value:t.value,e)}var k8=(e,t,n,r)=>{const o={};for(const u of e){const c=Se(t,u);c&&st(o,u,c._f)}return{criteriaMode:n,names:[...e],fields:o,shouldUseNativeValidation:r}},zs=e=>yt(e)?e:ka(e)?e.source:bt(e)?ka(e.value)?e.value.source:e.value:e,A8=e=>e.mount&&(e.required||e.min||e.max||e.maxLength||e.minLength||e.pattern||e.validate);function hm(e,t,n){const r=Se(e,n);if(r||yd(n))return{error:r,name:n};const o=n.split(".");for(;o.length;){const u=o.join("."),c=Se(t,u),p=Se(e,u);if(c&&!Array.isArray(c)&&n!==u)return{name:n};if(p&&p.type)return{name:u,error:p};o.pop()}return{name:n}}var C8=(e,t,n,r,o)=>o.isOnAll?!1:!n&&o.isOnTouch?!(t||e):(n?r.isOnBlur:o.isOnBlur)?!e:(n?r.isOnChange:o.isOnChange)?e:!0,T8=(e,t)=>!Bs(Se(e,t)).length&&Nt(e,t);const x8={mode:Qn.onSubmit,reValidateMode:Qn.onChange,shouldFocusError:!0};function L8(e={},t){let n={...x8,...e},r={submitCount:0,isDirty:!1,isLoading:ti(n.defaultValues),isValidating:!1,isSubmitted:!1,isSubmitting:!1,isSubmitSuccessful:!1,isValid:!1,touchedFields:{},dirtyFields:{},errors:{}},o={},u=bt(n.defaultValues)||bt(n.values)?Fi(n.defaultValues||n.values)||{}:{},c=n.shouldUnregister?{}:Fi(u),p={action:!1,mount:!1,watch:!1},g={mount:new Set,unMount:new Set,array:new Set,watch:new Set},b,O=0;const P={isDirty:!1,dirtyFields:!1,touchedFields:!1,isValidating:!1,isValid:!1,errors:!1},q={values:Ed(),array:Ed(),state:Ed()},Z=e.resetOptions&&e.resetOptions.keepDirtyValues,M=tm(n.mode),H=tm(n.reValidateMode),z=n.criteriaMode===Qn.all,L=I=>Y=>{clearTimeout(O),O=setTimeout(I,Y)},k=async I=>{if(P.isValid||I){const Y=n.resolver?Nn((await y()).errors):await E(o,!0);Y!==r.isValid&&q.state.next({isValid:Y})}},x=I=>P.isValidating&&q.state.next({isValidating:I}),l=(I,Y=[],J,ve,pe=!0,oe=!0)=>{if(ve&&J){if(p.action=!0,oe&&Array.isArray(Se(o,I))){const ge=J(Se(o,I),ve.argA,ve.argB);pe&&st(o,I,ge)}if(oe&&Array.isArray(Se(r.errors,I))){const ge=J(Se(r.errors,I),ve.argA,ve.argB);pe&&st(r.errors,I,ge),T8(r.errors,I)}if(P.touchedFields&&oe&&Array.isArray(Se(r.touchedFields,I))){const ge=J(Se(r.touchedFields,I),ve.argA,ve.argB);pe&&st(r.touchedFields,I,ge)}P.dirtyFields&&(r.dirtyFields=Ad(u,c)),q.state.next({name:I,isDirty:j(I,Y),dirtyFields:r.dirtyFields,errors:r.errors,isValid:r.isValid})}else st(c,I,Y)},h=(I,Y)=>{st(r.errors,I,Y),q.state.next({errors:r.errors})},d=(I,Y,J,ve)=>{const pe=Se(o,I);if(pe){const oe=Se(c,I,yt(J)?Se(u,I):J);yt(oe)||ve&&ve.defaultChecked||Y?st(c,I,Y?oe:Cd(pe._f)):T(I,oe),p.mount&&k()}},_=(I,Y,J,ve,pe)=>{let oe=!1,ge=!1;const Te={name:I};if(!J||ve){P.isDirty&&(ge=r.isDirty,r.isDirty=Te.isDirty=j(),oe=ge!==Te.isDirty);const Ve=Vi(Se(u,I),Y);ge=Se(r.dirtyFields,I),Ve?Nt(r.dirtyFields,I):st(r.dirtyFields,I,!0),Te.dirtyFields=r.dirtyFields,oe=oe||P.dirtyFields&&ge!==!Ve}if(J){const Ve=Se(r.touchedFields,I);Ve||(st(r.touchedFields,I,J),Te.touchedFields=r.touchedFields,oe=oe||P.touchedFields&&Ve!==J)}return oe&&pe&&q.state.next(Te),oe?Te:{}},v=(I,Y,J,ve)=>{const pe=Se(r.errors,I),oe=P.isValid&&xo(Y)&&r.isValid!==Y;if(e.delayError&&J?(b=L(()=>h(I,J)),b(e.delayError)):(clearTimeout(O),b=null,J?st(r.errors,I,J):Nt(r.errors,I)),(J?!Vi(pe,J):pe)||!Nn(ve)||oe){const ge={...ve,...oe&&xo(Y)?{isValid:Y}:{},errors:r.errors,name:I};r={...r,...ge},q.state.next(ge)}x(!1)},y=async I=>n.resolver(c,n.context,k8(I||g.mount,o,n.criteriaMode,n.shouldUseNativeValidation)),m=async I=>{const{errors:Y}=await y();if(I)for(const J of I){const ve=Se(Y,J);ve?st(r.errors,J,ve):Nt(r.errors,J)}else r.errors=Y;return Y},E=async(I,Y,J={valid:!0})=>{for(const ve in I){const pe=I[ve];if(pe){const{_f:oe,...ge}=pe;if(oe){const Te=g.array.has(oe.name),Ve=await lm(pe,c,z,n.shouldUseNativeValidation&&!Y,Te);if(Ve[oe.name]&&(J.valid=!1,Y))break;!Y&&(Se(Ve,oe.name)?Te?w8(r.errors,Ve,oe.name):st(r.errors,oe.name,Ve[oe.name]):Nt(r.errors,oe.name))}ge&&await E(ge,Y,J)}}return J.valid},D=()=>{for(const I of g.unMount){const Y=Se(o,I);Y&&(Y._f.refs?Y._f.refs.every(J=>!kd(J)):!kd(Y._f.ref))&&Me(I)}g.unMount=new Set},j=(I,Y)=>(I&&Y&&st(c,I,Y),!Vi(le(),u)),V=(I,Y,J)=>_8(I,g,{...p.mount?c:yt(Y)?u:hr(I)?{[I]:Y}:Y},J,Y),W=I=>Bs(Se(p.mount?c:u,I,e.shouldUnregister?Se(u,I,[]):[])),T=(I,Y,J={})=>{const ve=Se(o,I);let pe=Y;if(ve){const oe=ve._f;oe&&(!oe.disabled&&st(c,I,pm(Y,oe)),pe=Sa(oe.ref)&&Gt(Y)?"":Y,cm(oe.ref)?[...oe.ref.options].forEach(ge=>ge.selected=pe.includes(ge.value)):oe.refs?Ms(oe.ref)?oe.refs.length>1?oe.refs.forEach(ge=>(!ge.defaultChecked||!ge.disabled)&&(ge.checked=Array.isArray(pe)?!!pe.find(Te=>Te===ge.value):pe===ge.value)):oe.refs[0]&&(oe.refs[0].checked=!!pe):oe.refs.forEach(ge=>ge.checked=ge.value===pe):bd(oe.ref)?oe.ref.value="":(oe.ref.value=pe,oe.ref.type||q.values.next({name:I,values:{...c}})))}(J.shouldDirty||J.shouldTouch)&&_(I,pe,J.shouldTouch,J.shouldDirty,!0),J.shouldValidate&&ie(I)},Q=(I,Y,J)=>{for(const ve in Y){const pe=Y[ve],oe=`${I}.${ve}`,ge=Se(o,oe);(g.array.has(I)||!Aa(pe)||ge&&!ge._f)&&!To(pe)?Q(oe,pe,J):T(oe,pe,J)}},G=(I,Y,J={})=>{const ve=Se(o,I),pe=g.array.has(I),oe=Fi(Y);st(c,I,oe),pe?(q.array.next({name:I,values:{...c}}),(P.isDirty||P.dirtyFields)&&J.shouldDirty&&q.state.next({name:I,dirtyFields:Ad(u,c),isDirty:j(I,oe)})):ve&&!ve._f&&!Gt(oe)?Q(I,oe,J):T(I,oe,J),nm(I,g)&&q.state.next({...r}),q.values.next({name:I,values:{...c}}),!p.mount&&t()},ee=async I=>{const Y=I.target;let J=Y.name,ve=!0;const pe=Se(o,J),oe=()=>Y.type?Cd(pe._f):d8(I);if(pe){let ge,Te;const Ve=oe(),lr=I.type===Jg.BLUR||I.type===Jg.FOCUS_OUT,mo=!A8(pe._f)&&!n.resolver&&!Se(r.errors,J)&&!pe._f.deps||C8(lr,Se(r.touchedFields,J),r.isSubmitted,H,M),Ju=nm(J,g,lr);st(c,J,Ve),lr?(pe._f.onBlur&&pe._f.onBlur(I),b&&b(0)):pe._f.onChange&&pe._f.onChange(I);const Vr=_(J,Ve,lr,!1),Pi=!Nn(Vr)||Ju;if(!lr&&q.values.next({name:J,type:I.type,values:{...c}}),mo)return P.isValid&&k(),Pi&&q.state.next({name:J,...Ju?{}:Vr});if(!lr&&Ju&&q.state.next({...r}),x(!0),n.resolver){const{errors:ws}=await y([J]),ea=hm(r.errors,o,J),bs=hm(ws,o,ea.name||J);ge=bs.error,J=bs.name,Te=Nn(ws)}else ge=(await lm(pe,c,z,n.shouldUseNativeValidation))[J],ve=isNaN(Ve)||Ve===Se(c,J,Ve),ve&&(ge?Te=!1:P.isValid&&(Te=await E(o,!0)));ve&&(pe._f.deps&&ie(pe._f.deps),v(J,Te,ge,Vr))}},ie=async(I,Y={})=>{let J,ve;const pe=_d(I);if(x(!0),n.resolver){const oe=await m(yt(I)?I:pe);J=Nn(oe),ve=I?!pe.some(ge=>Se(oe,ge)):J}else I?(ve=(await Promise.all(pe.map(async oe=>{const ge=Se(o,oe);return await E(ge&&ge._f?{[oe]:ge}:ge)}))).every(Boolean),!(!ve&&!r.isValid)&&k()):ve=J=await E(o);return q.state.next({...!hr(I)||P.isValid&&J!==r.isValid?{}:{name:I},...n.resolver||!I?{isValid:J}:{},errors:r.errors,isValidating:!1}),Y.shouldFocus&&!ve&&wd(o,oe=>oe&&Se(r.errors,oe),I?pe:g.mount),ve},le=I=>{const Y={...u,...p.mount?c:{}};return yt(I)?Y:hr(I)?Se(Y,I):I.map(J=>Se(Y,J))},Oe=(I,Y)=>({invalid:!!Se((Y||r).errors,I),isDirty:!!Se((Y||r).dirtyFields,I),isTouched:!!Se((Y||r).touchedFields,I),error:Se((Y||r).errors,I)}),Xe=I=>{I&&_d(I).forEach(Y=>Nt(r.errors,Y)),q.state.next({errors:I?r.errors:{}})},tt=(I,Y,J)=>{const ve=(Se(o,I,{_f:{}})._f||{}).ref;st(r.errors,I,{...Y,ref:ve}),q.state.next({name:I,errors:r.errors,isValid:!1}),J&&J.shouldFocus&&ve&&ve.focus&&ve.focus()},je=(I,Y)=>ti(I)?q.values.subscribe({next:J=>I(V(void 0,Y),J)}):V(I,Y,!0),Me=(I,Y={})=>{for(const J of I?_d(I):g.mount)g.mount.delete(J),g.array.delete(J),Y.keepValue||(Nt(o,J),Nt(c,J)),!Y.keepError&&Nt(r.errors,J),!Y.keepDirty&&Nt(r.dirtyFields,J),!Y.keepTouched&&Nt(r.touchedFields,J),!n.shouldUnregister&&!Y.keepDefaultValue&&Nt(u,J);q.values.next({values:{...c}}),q.state.next({...r,...Y.keepDirty?{isDirty:j()}:{}}),!Y.keepIsValid&&k()},Ze=(I,Y={})=>{let J=Se(o,I);const ve=xo(Y.disabled);return st(o,I,{...J||{},_f:{...J&&J._f?J._f:{ref:{name:I}},name:I,mount:!0,...Y}}),g.mount.add(I),J?ve&&st(c,I,Y.disabled?void 0:Se(c,I,Cd(J._f))):d(I,!0,Y.value),{...ve?{disabled:Y.disabled}:{},...n.shouldUseNativeValidation?{required:!!Y.required,min:zs(Y.min),max:zs(Y.max),minLength:zs(Y.minLength),maxLength:zs(Y.maxLength),pattern:zs(Y.pattern)}:{},name:I,onChange:ee,onBlur:ee,ref:pe=>{if(pe){Ze(I,Y),J=Se(o,I);const oe=yt(pe.value)&&pe.querySelectorAll&&pe.querySelectorAll("input,select,textarea")[0]||pe,ge=E8(oe),Te=J._f.refs||[];if(ge?Te.find(Ve=>Ve===oe):oe===J._f.ref)return;st(o,I,{_f:{...J._f,...ge?{refs:[...Te.filter(kd),oe,...Array.isArray(Se(u,I))?[{}]:[]],ref:{type:oe.type,name:I}}:{ref:oe}}}),d(I,!1,void 0,oe)}else J=Se(o,I,{}),J._f&&(J._f.mount=!1),(n.shouldUnregister||Y.shouldUnregister)&&!(p8(g.array,I)&&p.action)&&g.unMount.add(I)}}},ce=()=>n.shouldFocusError&&wd(o,I=>I&&Se(r.errors,I),g.mount),A=(I,Y)=>async J=>{J&&(J.preventDefault&&J.preventDefault(),J.persist&&J.persist());let ve=Fi(c);if(q.state.next({isSubmitting:!0}),n.resolver){const{errors:pe,values:oe}=await y();r.errors=pe,ve=oe}else await E(o);Nt(r.errors,"root"),Nn(r.errors)?(q.state.next({errors:{}}),await I(ve,J)):(Y&&await Y({...r.errors},J),ce(),setTimeout(ce)),q.state.next({isSubmitted:!0,isSubmitting:!1,isSubmitSuccessful:Nn(r.errors),submitCount:r.submitCount+1,errors:r.errors})},R=(I,Y={})=>{Se(o,I)&&(yt(Y.defaultValue)?G(I,Se(u,I)):(G(I,Y.defaultValue),st(u,I,Y.defaultValue)),Y.keepTouched||Nt(r.touchedFields,I),Y.keepDirty||(Nt(r.dirtyFields,I),r.isDirty=Y.defaultValue?j(I,Se(u,I)):j()),Y.keepError||(Nt(r.errors,I),P.isValid&&k()),q.state.next({...r}))},$=(I,Y={})=>{const J=I||u,ve=Fi(J),pe=I&&!Nn(I)?ve:u;if(Y.keepDefaultValues||(u=J),!Y.keepValues){if(Y.keepDirtyValues||Z)for(const oe of g.mount)Se(r.dirtyFields,oe)?st(pe,oe,Se(c,oe)):G(oe,Se(pe,oe));else{if(vd&&yt(I))for(const oe of g.mount){const ge=Se(o,oe);if(ge&&ge._f){const Te=Array.isArray(ge._f.refs)?ge._f.refs[0]:ge._f.ref;if(Sa(Te)){const Ve=Te.closest("form");if(Ve){Ve.reset();break}}}}o={}}c=e.shouldUnregister?Y.keepDefaultValues?Fi(u):{}:ve,q.array.next({values:{...pe}}),q.values.next({values:{...pe}})}g={mount:new Set,unMount:new Set,array:new Set,watch:new Set,watchAll:!1,focus:""},!p.mount&&t(),p.mount=!P.isValid||!!Y.keepIsValid,p.watch=!!e.shouldUnregister,q.state.next({submitCount:Y.keepSubmitCount?r.submitCount:0,isDirty:Y.keepDirty?r.isDirty:!!(Y.keepDefaultValues&&!Vi(I,u)),isSubmitted:Y.keepIsSubmitted?r.isSubmitted:!1,dirtyFields:Y.keepDirtyValues?r.dirtyFields:Y.keepDefaultValues&&I?Ad(u,I):{},touchedFields:Y.keepTouched?r.touchedFields:{},errors:Y.keepErrors?r.errors:{},isSubmitting:!1,isSubmitSuccessful:!1})},se=(I,Y)=>$(ti(I)?I(c):I,Y),ae=(I,Y={})=>{const J=Se(o,I),ve=J&&J._f;if(ve){const pe=ve.refs?ve.refs[0]:ve.ref;pe.focus&&(pe.focus(),Y.shouldSelect&&pe.select())}},fe=I=>{r={...r,...I}};return ti(n.defaultValues)&&n.defaultValues().then(I=>{se(I,n.resetOptions),q.state.next({isLoading:!1})}),{control:{register:Ze,unregister:Me,getFieldState:Oe,_executeSchema:y,_getWatch:V,_getDirty:j,_updateValid:k,_removeUnmounted:D,_updateFieldArray:l,_getFieldArray:W,_reset:$,_updateFormState:fe,_subjects:q,_proxyFormState:P,get _fields(){return o},get _formValues(){return c},get _state(){return p},set _state(I){p=I},get _defaultValues(){return u},get _names(){return g},set _names(I){g=I},get _formState(){return r},set _formState(I){r=I},get _options(){return n},set _options(I){n={...n,...I}}},trigger:ie,register:Ze,handleSubmit:A,watch:je,setValue:G,getValues:le,reset:se,resetField:R,clearErrors:Xe,unregister:Me,setError:tt,setFocus:ae,getFieldState:Oe}}function O8(e={}){const t=K.useRef(),[n,r]=K.useState({isDirty:!1,isValidating:!1,isLoading:ti(e.defaultValues),isSubmitted:!1,isSubmitting:!1,isSubmitSuccessful:!1,isValid:!1,submitCount:0,dirtyFields:{},touchedFields:{},errors:{},defaultValues:ti(e.defaultValues)?void 0:e.defaultValues});t.current||(t.current={...L8(e,()=>r(u=>({...u}))),formState:n});const o=t.current.control;return o._options=e,v8({subject:o._subjects.state,next:u=>{m8(u,o._proxyFormState,o._updateFormState,!0)&&r({...o._formState})}}),K.useEffect(()=>{e.values&&!Vi(e.values,o._defaultValues)&&o._reset(e.values,o._options.resetOptions)},[e.values,o]),K.useEffect(()=>{o._state.mount||(o._updateValid(),o._state.mount=!0),o._state.watch&&(o._state.watch=!1,o._subjects.state.next({...o._formState})),o._removeUnmounted()}),t.current.formState=g8(n,o),t.current}var gm={exports:{}};(function(e){(function(){function t(l){var h={omitExtraWLInCodeBlocks:{defaultValue:!1,describe:"Omit the default extra whiteline added to code blocks",type:"boolean"},noHeaderId:{defaultValue:!1,describe:"Turn on/off generated header id",type:"boolean"},prefixHeaderId:{defaultValue:!1,describe:"Add a prefix to the generated header ids. Passing a string will prefix that string to the header id. Setting to true will add a generic 'section-' prefix",type:"string"},rawPrefixHeaderId:{defaultValue:!1,describe:'Setting this option to true will prevent showdown from modifying the prefix. This might result in malformed IDs (if, for instance, the " char is used in the prefix)',type:"boolean"},ghCompatibleHeaderId:{defaultValue:!1,describe:"Generate header ids compatible with github style (spaces are replaced with dashes, a bunch of non alphanumeric chars are removed)",type:"boolean"},rawHeaderId:{defaultValue:!1,describe:`Remove only spaces, ' and " from generated header ids (including prefixes), replacing them with dashes (-). WARNING: This might result in malformed ids`,type:"boolean"},headerLevelStart:{defaultValue:!1,describe:"The header blocks level start",type:"integer"},parseImgDimensions:{defaultValue:!1,describe:"Turn on/off image dimension parsing",type:"boolean"},simplifiedAutoLink:{defaultValue:!1,describe:"Turn on/off GFM autolink style",type:"boolean"},excludeTrailingPunctuationFromURLs:{defaultValue:!1,describe:"Excludes trailing punctuation from links generated with autoLinking",type:"boolean"},literalMidWordUnderscores:{defaultValue:!1,describe:"Parse midword underscores as literal underscores",type:"boolean"},literalMidWordAsterisks:{defaultValue:!1,describe:"Parse midword asterisks as literal asterisks",type:"boolean"},strikethrough:{defaultValue:!1,describe:"Turn on/off strikethrough support",type:"boolean"},tables:{defaultValue:!1,describe:"Turn on/off tables support",type:"boolean"},tablesHeaderId:{defaultValue:!1,describe:"Add an id to table headers",type:"boolean"},ghCodeBlocks:{defaultValue:!0,describe:"Turn on/off GFM fenced code blocks support",type:"boolean"},tasklists:{defaultValue:!1,describe:"Turn on/off GFM tasklist support",type:"boolean"},smoothLivePreview:{defaultValue:!1,describe:"Prevents weird effects in live previews due to incomplete input",type:"boolean"},smartIndentationFix:{defaultValue:!1,description:"Tries to smartly fix indentation in es6 strings",type:"boolean"},disableForced4SpacesIndentedSublists:{defaultValue:!1,description:"Disables the requirement of indenting nested sublists by 4 spaces",type:"boolean"},simpleLineBreaks:{defaultValue:!1,description:"Parses simple line breaks as <br> (GFM Style)",type:"boolean"},requireSpaceBeforeHeadingText:{defaultValue:!1,description:"Makes adding a space between `#` and the header text mandatory (GFM Style)",type:"boolean"},ghMentions:{defaultValue:!1,description:"Enables github @mentions",type:"boolean"},ghMentionsLink:{defaultValue:"https://github.com/{u}",description:"Changes the link generated by @mentions. Only applies if ghMentions option is enabled.",type:"string"},encodeEmails:{defaultValue:!0,description:"Encode e-mail addresses through the use of Character Entities, transforming ASCII e-mail addresses into its equivalent decimal entities",type:"boolean"},openLinksInNewWindow:{defaultValue:!1,description:"Open all links in new windows",type:"boolean"},backslashEscapesHTMLTags:{defaultValue:!1,description:"Support for HTML Tag escaping. ex: <div>foo</div>",type:"boolean"},emoji:{defaultValue:!1,description:"Enable emoji support. Ex: `this is a :smile: emoji`",type:"boolean"},underline:{defaultValue:!1,description:"Enable support for underline. Syntax is double or triple underscores: `__underline word__`. With this option enabled, underscores no longer parses into `<em>` and `<strong>`",type:"boolean"},completeHTMLDocument:{defaultValue:!1,description:"Outputs a complete html document, including `<html>`, `<head>` and `<body>` tags",type:"boolean"},metadata:{defaultValue:!1,description:"Enable support for document metadata (defined at the top of the document between `«««` and `»»»` or between `---` and `---`).",type:"boolean"},splitAdjacentBlockquotes:{defaultValue:!1,description:"Split adjacent blockquote blocks",type:"boolean"}};if(l===!1)return JSON.parse(JSON.stringify(h));var d={};for(var _ in h)h.hasOwnProperty(_)&&(d[_]=h[_].defaultValue);return d}function n(){var l=t(!0),h={};for(var d in l)l.hasOwnProperty(d)&&(h[d]=!0);return h}var r={},o={},u={},c=t(!0),p="vanilla",g={github:{omitExtraWLInCodeBlocks:!0,simplifiedAutoLink:!0,excludeTrailingPunctuationFromURLs:!0,literalMidWordUnderscores:!0,strikethrough:!0,tables:!0,tablesHeaderId:!0,ghCodeBlocks:!0,tasklists:!0,disableForced4SpacesIndentedSublists:!0,simpleLineBreaks:!0,requireSpaceBeforeHeadingText:!0,ghCompatibleHeaderId:!0,ghMentions:!0,backslashEscapesHTMLTags:!0,emoji:!0,splitAdjacentBlockquotes:!0},original:{noHeaderId:!0,ghCodeBlocks:!1},ghost:{omitExtraWLInCodeBlocks:!0,parseImgDimensions:!0,simplifiedAutoLink:!0,excludeTrailingPunctuationFromURLs:!0,literalMidWordUnderscores:!0,strikethrough:!0,tables:!0,tablesHeaderId:!0,ghCodeBlocks:!0,tasklists:!0,smoothLivePreview:!0,simpleLineBreaks:!0,requireSpaceBeforeHeadingText:!0,ghMentions:!1,encodeEmails:!0},vanilla:t(!0),allOn:n()};r.helper={},r.extensions={},r.setOption=function(l,h){return c[l]=h,this},r.getOption=function(l){return c[l]},r.getOptions=function(){return c},r.resetOptions=function(){c=t(!0)},r.setFlavor=function(l){if(!g.hasOwnProperty(l))throw Error(l+" flavor was not found");r.resetOptions();var h=g[l];p=l;for(var d in h)h.hasOwnProperty(d)&&(c[d]=h[d])},r.getFlavor=function(){return p},r.getFlavorOptions=function(l){if(g.hasOwnProperty(l))return g[l]},r.getDefaultOptions=function(l){return t(l)},r.subParser=function(l,h){if(r.helper.isString(l))if(typeof h<"u")o[l]=h;else{if(o.hasOwnProperty(l))return o[l];throw Error("SubParser named "+l+" not registered!")}},r.extension=function(l,h){if(!r.helper.isString(l))throw Error("Extension 'name' must be a string");if(l=r.helper.stdExtName(l),r.helper.isUndefined(h)){if(!u.hasOwnProperty(l))throw Error("Extension named "+l+" is not registered!");return u[l]}else{typeof h=="function"&&(h=h()),r.helper.isArray(h)||(h=[h]);var d=b(h,l);if(d.valid)u[l]=h;else throw Error(d.error)}},r.getAllExtensions=function(){return u},r.removeExtension=function(l){delete u[l]},r.resetExtensions=function(){u={}};function b(l,h){var d=h?"Error in "+h+" extension->":"Error in unnamed extension",_={valid:!0,error:""};r.helper.isArray(l)||(l=[l]);for(var v=0;v<l.length;++v){var y=d+" sub-extension "+v+": ",m=l[v];if(typeof m!="object")return _.valid=!1,_.error=y+"must be an object, but "+typeof m+" given",_;if(!r.helper.isString(m.type))return _.valid=!1,_.error=y+'property "type" must be a string, but '+typeof m.type+" given",_;var E=m.type=m.type.toLowerCase();if(E==="language"&&(E=m.type="lang"),E==="html"&&(E=m.type="output"),E!=="lang"&&E!=="output"&&E!=="listener")return _.valid=!1,_.error=y+"type "+E+' is not recognized. Valid values: "lang/language", "output/html" or "listener"',_;if(E==="listener"){if(r.helper.isUndefined(m.listeners))return _.valid=!1,_.error=y+'. Extensions of type "listener" must have a property called "listeners"',_}else if(r.helper.isUndefined(m.filter)&&r.helper.isUndefined(m.regex))return _.valid=!1,_.error=y+E+' extensions must define either a "regex" property or a "filter" method',_;if(m.listeners){if(typeof m.listeners!="object")return _.valid=!1,_.error=y+'"listeners" property must be an object but '+typeof m.listeners+" given",_;for(var D in m.listeners)if(m.listeners.hasOwnProperty(D)&&typeof m.listeners[D]!="function")return _.valid=!1,_.error=y+'"listeners" property must be an hash of [event name]: [callback]. listeners.'+D+" must be a function but "+typeof m.listeners[D]+" given",_}if(m.filter){if(typeof m.filter!="function")return _.valid=!1,_.error=y+'"filter" must be a function, but '+typeof m.filter+" given",_}else if(m.regex){if(r.helper.isString(m.regex)&&(m.regex=new RegExp(m.regex,"g")),!(m.regex instanceof RegExp))return _.valid=!1,_.error=y+'"regex" property must either be a string or a RegExp object, but '+typeof m.regex+" given",_;if(r.helper.isUndefined(m.replace))return _.valid=!1,_.error=y+'"regex" extensions must implement a replace string or function',_}}return _}r.validateExtension=function(l){var h=b(l,null);return h.valid?!0:(console.warn(h.error),!1)},r.hasOwnProperty("helper")||(r.helper={}),r.helper.isString=function(l){return typeof l=="string"||l instanceof String},r.helper.isFunction=function(l){var h={};return l&&h.toString.call(l)==="[object Function]"},r.helper.isArray=function(l){return Array.isArray(l)},r.helper.isUndefined=function(l){return typeof l>"u"},r.helper.forEach=function(l,h){if(r.helper.isUndefined(l))throw new Error("obj param is required");if(r.helper.isUndefined(h))throw new Error("callback param is required");if(!r.helper.isFunction(h))throw new Error("callback param must be a function/closure");if(typeof l.forEach=="function")l.forEach(h);else if(r.helper.isArray(l))for(var d=0;d<l.length;d++)h(l[d],d,l);else if(typeof l=="object")for(var _ in l)l.hasOwnProperty(_)&&h(l[_],_,l);else throw new Error("obj does not seem to be an array or an iterable object")},r.helper.stdExtName=function(l){return l.replace(/[_?*+\/\\.^-]/g,"").replace(/\s/g,"").toLowerCase()};function O(l,h){var d=h.charCodeAt(0);return"¨E"+d+"E"}r.helper.escapeCharactersCallback=O,r.helper.escapeCharacters=function(l,h,d){var _="(["+h.replace(/([\[\]\\])/g,"\\$1")+"])";d&&(_="\\\\"+_);var v=new RegExp(_,"g");return l=l.replace(v,O),l},r.helper.unescapeHTMLEntities=function(l){return l.replace(/&quot;/g,'"').replace(/&lt;/g,"<").replace(/&gt;/g,">").replace(/&amp;/g,"&")};var P=function(l,h,d,_){var v=_||"",y=v.indexOf("g")>-1,m=new RegExp(h+"|"+d,"g"+v.replace(/g/g,"")),E=new RegExp(h,v.replace(/g/g,"")),D=[],j,V,W,T,Q;do for(j=0;W=m.exec(l);)if(E.test(W[0]))j++||(V=m.lastIndex,T=V-W[0].length);else if(j&&!--j){Q=W.index+W[0].length;var G={left:{start:T,end:V},match:{start:V,end:W.index},right:{start:W.index,end:Q},wholeMatch:{start:T,end:Q}};if(D.push(G),!y)return D}while(j&&(m.lastIndex=V));return D};r.helper.matchRecursiveRegExp=function(l,h,d,_){for(var v=P(l,h,d,_),y=[],m=0;m<v.length;++m)y.push([l.slice(v[m].wholeMatch.start,v[m].wholeMatch.end),l.slice(v[m].match.start,v[m].match.end),l.slice(v[m].left.start,v[m].left.end),l.slice(v[m].right.start,v[m].right.end)]);return y},r.helper.replaceRecursiveRegExp=function(l,h,d,_,v){if(!r.helper.isFunction(h)){var y=h;h=function(){return y}}var m=P(l,d,_,v),E=l,D=m.length;if(D>0){var j=[];m[0].wholeMatch.start!==0&&j.push(l.slice(0,m[0].wholeMatch.start));for(var V=0;V<D;++V)j.push(h(l.slice(m[V].wholeMatch.start,m[V].wholeMatch.end),l.slice(m[V].match.start,m[V].match.end),l.slice(m[V].left.start,m[V].left.end),l.slice(m[V].right.start,m[V].right.end))),V<D-1&&j.push(l.slice(m[V].wholeMatch.end,m[V+1].wholeMatch.start));m[D-1].wholeMatch.end<l.length&&j.push(l.slice(m[D-1].wholeMatch.end)),E=j.join("")}return E},r.helper.regexIndexOf=function(l,h,d){if(!r.helper.isString(l))throw"InvalidArgumentError: first parameter of showdown.helper.regexIndexOf function must be a string";if(!(h instanceof RegExp))throw"InvalidArgumentError: second parameter of showdown.helper.regexIndexOf function must be an instance of RegExp";var _=l.substring(d||0).search(h);return _>=0?_+(d||0):_},r.helper.splitAtIndex=function(l,h){if(!r.helper.isString(l))throw"InvalidArgumentError: first parameter of showdown.helper.regexIndexOf function must be a string";return[l.substring(0,h),l.substring(h)]},r.helper.encodeEmailAddress=function(l){var h=[function(d){return"&#"+d.charCodeAt(0)+";"},function(d){return"&#x"+d.charCodeAt(0).toString(16)+";"},function(d){return d}];return l=l.replace(/./g,function(d){if(d==="@")d=h[Math.floor(Math.random()*2)](d);else{var _=Math.random();d=_>.9?h[2](d):_>.45?h[1](d):h[0](d)}return d}),l},r.helper.padEnd=function(h,d,_){return d=d>>0,_=String(_||" "),h.length>d?String(h):(d=d-h.length,d>_.length&&(_+=_.repeat(d/_.length)),String(h)+_.slice(0,d))},typeof console>"u"&&(console={warn:function(l){alert(l)},log:function(l){alert(l)},error:function(l){throw l}}),r.helper.regexes={asteriskDashAndColon:/([*_:~])/g},r.helper.emojis={"+1":"👍","-1":"👎",100:"💯",1234:"🔢","1st_place_medal":"🥇","2nd_place_medal":"🥈","3rd_place_medal":"🥉","8ball":"🎱",a:"🅰️",ab:"🆎",abc:"🔤",abcd:"🔡",accept:"🉑",aerial_tramway:"🚡",airplane:"✈️",alarm_clock:"⏰",alembic:"⚗️",alien:"👽",ambulance:"🚑",amphora:"🏺",anchor:"⚓️",angel:"👼",anger:"💢",angry:"😠",anguished:"😧",ant:"🐜",apple:"🍎",aquarius:"♒️",aries:"♈️",arrow_backward:"◀️",arrow_double_down:"⏬",arrow_double_up:"⏫",arrow_down:"⬇️",arrow_down_small:"🔽",arrow_forward:"▶️",arrow_heading_down:"⤵️",arrow_heading_up:"⤴️",arrow_left:"⬅️",arrow_lower_left:"↙️",arrow_lower_right:"↘️",arrow_right:"➡️",arrow_right_hook:"↪️",arrow_up:"⬆️",arrow_up_down:"↕️",arrow_up_small:"🔼",arrow_upper_left:"↖️",arrow_upper_right:"↗️",arrows_clockwise:"🔃",arrows_counterclockwise:"🔄",art:"🎨",articulated_lorry:"🚛",artificial_satellite:"🛰",astonished:"😲",athletic_shoe:"👟",atm:"🏧",atom_symbol:"⚛️",avocado:"🥑",b:"🅱️",baby:"👶",baby_bottle:"🍼",baby_chick:"🐤",baby_symbol:"🚼",back:"🔙",bacon:"🥓",badminton:"🏸",baggage_claim:"🛄",baguette_bread:"🥖",balance_scale:"⚖️",balloon:"🎈",ballot_box:"🗳",ballot_box_with_check:"☑️",bamboo:"🎍",banana:"🍌",bangbang:"‼️",bank:"🏦",bar_chart:"📊",barber:"💈",baseball:"⚾️",basketball:"🏀",basketball_man:"⛹️",basketball_woman:"⛹️&zwj;♀️",bat:"🦇",bath:"🛀",bathtub:"🛁",battery:"🔋",beach_umbrella:"🏖",bear:"🐻",bed:"🛏",bee:"🐝",beer:"🍺",beers:"🍻",beetle:"🐞",beginner:"🔰",bell:"🔔",bellhop_bell:"🛎",bento:"🍱",biking_man:"🚴",bike:"🚲",biking_woman:"🚴&zwj;♀️",bikini:"👙",biohazard:"☣️",bird:"🐦",birthday:"🎂",black_circle:"⚫️",black_flag:"🏴",black_heart:"🖤",black_joker:"🃏",black_large_square:"⬛️",black_medium_small_square:"◾️",black_medium_square:"◼️",black_nib:"✒️",black_small_square:"▪️",black_square_button:"🔲",blonde_man:"👱",blonde_woman:"👱&zwj;♀️",blossom:"🌼",blowfish:"🐡",blue_book:"📘",blue_car:"🚙",blue_heart:"💙",blush:"😊",boar:"🐗",boat:"⛵️",bomb:"💣",book:"📖",bookmark:"🔖",bookmark_tabs:"📑",books:"📚",boom:"💥",boot:"👢",bouquet:"💐",bowing_man:"🙇",bow_and_arrow:"🏹",bowing_woman:"🙇&zwj;♀️",bowling:"🎳",boxing_glove:"🥊",boy:"👦",bread:"🍞",bride_with_veil:"👰",bridge_at_night:"🌉",briefcase:"💼",broken_heart:"💔",bug:"🐛",building_construction:"🏗",bulb:"💡",bullettrain_front:"🚅",bullettrain_side:"🚄",burrito:"🌯",bus:"🚌",business_suit_levitating:"🕴",busstop:"🚏",bust_in_silhouette:"👤",busts_in_silhouette:"👥",butterfly:"🦋",cactus:"🌵",cake:"🍰",calendar:"📆",call_me_hand:"🤙",calling:"📲",camel:"🐫",camera:"📷",camera_flash:"📸",camping:"🏕",cancer:"♋️",candle:"🕯",candy:"🍬",canoe:"🛶",capital_abcd:"🔠",capricorn:"♑️",car:"🚗",card_file_box:"🗃",card_index:"📇",card_index_dividers:"🗂",carousel_horse:"🎠",carrot:"🥕",cat:"🐱",cat2:"🐈",cd:"💿",chains:"⛓",champagne:"🍾",chart:"💹",chart_with_downwards_trend:"📉",chart_with_upwards_trend:"📈",checkered_flag:"🏁",cheese:"🧀",cherries:"🍒",cherry_blossom:"🌸",chestnut:"🌰",chicken:"🐔",children_crossing:"🚸",chipmunk:"🐿",chocolate_bar:"🍫",christmas_tree:"🎄",church:"⛪️",cinema:"🎦",circus_tent:"🎪",city_sunrise:"🌇",city_sunset:"🌆",cityscape:"🏙",cl:"🆑",clamp:"🗜",clap:"👏",clapper:"🎬",classical_building:"🏛",clinking_glasses:"🥂",clipboard:"📋",clock1:"🕐",clock10:"🕙",clock1030:"🕥",clock11:"🕚",clock1130:"🕦",clock12:"🕛",clock1230:"🕧",clock130:"🕜",clock2:"🕑",clock230:"🕝",clock3:"🕒",clock330:"🕞",clock4:"🕓",clock430:"🕟",clock5:"🕔",clock530:"🕠",clock6:"🕕",clock630:"🕡",clock7:"🕖",clock730:"🕢",clock8:"🕗",clock830:"🕣",clock9:"🕘",clock930:"🕤",closed_book:"📕",closed_lock_with_key:"🔐",closed_umbrella:"🌂",cloud:"☁️",cloud_with_lightning:"🌩",cloud_with_lightning_and_rain:"⛈",cloud_with_rain:"🌧",cloud_with_snow:"🌨",clown_face:"🤡",clubs:"♣️",cocktail:"🍸",coffee:"☕️",coffin:"⚰️",cold_sweat:"😰",comet:"☄️",computer:"💻",computer_mouse:"🖱",confetti_ball:"🎊",confounded:"😖",confused:"😕",congratulations:"㊗️",construction:"🚧",construction_worker_man:"👷",construction_worker_woman:"👷&zwj;♀️",control_knobs:"🎛",convenience_store:"🏪",cookie:"🍪",cool:"🆒",policeman:"👮",copyright:"©️",corn:"🌽",couch_and_lamp:"🛋",couple:"👫",couple_with_heart_woman_man:"💑",couple_with_heart_man_man:"👨&zwj;❤️&zwj;👨",couple_with_heart_woman_woman:"👩&zwj;❤️&zwj;👩",couplekiss_man_man:"👨&zwj;❤️&zwj;💋&zwj;👨",couplekiss_man_woman:"💏",couplekiss_woman_woman:"👩&zwj;❤️&zwj;💋&zwj;👩",cow:"🐮",cow2:"🐄",cowboy_hat_face:"🤠",crab:"🦀",crayon:"🖍",credit_card:"💳",crescent_moon:"🌙",cricket:"🏏",crocodile:"🐊",croissant:"🥐",crossed_fingers:"🤞",crossed_flags:"🎌",crossed_swords:"⚔️",crown:"👑",cry:"😢",crying_cat_face:"😿",crystal_ball:"🔮",cucumber:"🥒",cupid:"💘",curly_loop:"➰",currency_exchange:"💱",curry:"🍛",custard:"🍮",customs:"🛃",cyclone:"🌀",dagger:"🗡",dancer:"💃",dancing_women:"👯",dancing_men:"👯&zwj;♂️",dango:"🍡",dark_sunglasses:"🕶",dart:"🎯",dash:"💨",date:"📅",deciduous_tree:"🌳",deer:"🦌",department_store:"🏬",derelict_house:"🏚",desert:"🏜",desert_island:"🏝",desktop_computer:"🖥",male_detective:"🕵️",diamond_shape_with_a_dot_inside:"💠",diamonds:"♦️",disappointed:"😞",disappointed_relieved:"😥",dizzy:"💫",dizzy_face:"😵",do_not_litter:"🚯",dog:"🐶",dog2:"🐕",dollar:"💵",dolls:"🎎",dolphin:"🐬",door:"🚪",doughnut:"🍩",dove:"🕊",dragon:"🐉",dragon_face:"🐲",dress:"👗",dromedary_camel:"🐪",drooling_face:"🤤",droplet:"💧",drum:"🥁",duck:"🦆",dvd:"📀","e-mail":"📧",eagle:"🦅",ear:"👂",ear_of_rice:"🌾",earth_africa:"🌍",earth_americas:"🌎",earth_asia:"🌏",egg:"🥚",eggplant:"🍆",eight_pointed_black_star:"✴️",eight_spoked_asterisk:"✳️",electric_plug:"🔌",elephant:"🐘",email:"✉️",end:"🔚",envelope_with_arrow:"📩",euro:"💶",european_castle:"🏰",european_post_office:"🏤",evergreen_tree:"🌲",exclamation:"❗️",expressionless:"😑",eye:"👁",eye_speech_bubble:"👁&zwj;🗨",eyeglasses:"👓",eyes:"👀",face_with_head_bandage:"🤕",face_with_thermometer:"🤒",fist_oncoming:"👊",factory:"🏭",fallen_leaf:"🍂",family_man_woman_boy:"👪",family_man_boy:"👨&zwj;👦",family_man_boy_boy:"👨&zwj;👦&zwj;👦",family_man_girl:"👨&zwj;👧",family_man_girl_boy:"👨&zwj;👧&zwj;👦",family_man_girl_girl:"👨&zwj;👧&zwj;👧",family_man_man_boy:"👨&zwj;👨&zwj;👦",family_man_man_boy_boy:"👨&zwj;👨&zwj;👦&zwj;👦",family_man_man_girl:"👨&zwj;👨&zwj;👧",family_man_man_girl_boy:"👨&zwj;👨&zwj;👧&zwj;👦",family_man_man_girl_girl:"👨&zwj;👨&zwj;👧&zwj;👧",family_man_woman_boy_boy:"👨&zwj;👩&zwj;👦&zwj;👦",family_man_woman_girl:"👨&zwj;👩&zwj;👧",family_man_woman_girl_boy:"👨&zwj;👩&zwj;👧&zwj;👦",family_man_woman_girl_girl:"👨&zwj;👩&zwj;👧&zwj;👧",family_woman_boy:"👩&zwj;👦",family_woman_boy_boy:"👩&zwj;👦&zwj;👦",family_woman_girl:"👩&zwj;👧",family_woman_girl_boy:"👩&zwj;👧&zwj;👦",family_woman_girl_girl:"👩&zwj;👧&zwj;👧",family_woman_woman_boy:"👩&zwj;👩&zwj;👦",family_woman_woman_boy_boy:"👩&zwj;👩&zwj;👦&zwj;👦",family_woman_woman_girl:"👩&zwj;👩&zwj;👧",family_woman_woman_girl_boy:"👩&zwj;👩&zwj;👧&zwj;👦",family_woman_woman_girl_girl:"👩&zwj;👩&zwj;👧&zwj;👧",fast_forward:"⏩",fax:"📠",fearful:"😨",feet:"🐾",female_detective:"🕵️&zwj;♀️",ferris_wheel:"🎡",ferry:"⛴",field_hockey:"🏑",file_cabinet:"🗄",file_folder:"📁",film_projector:"📽",film_strip:"🎞",fire:"🔥",fire_engine:"🚒",fireworks:"🎆",first_quarter_moon:"🌓",first_quarter_moon_with_face:"🌛",fish:"🐟",fish_cake:"🍥",fishing_pole_and_fish:"🎣",fist_raised:"✊",fist_left:"🤛",fist_right:"🤜",flags:"🎏",flashlight:"🔦",fleur_de_lis:"⚜️",flight_arrival:"🛬",flight_departure:"🛫",floppy_disk:"💾",flower_playing_cards:"🎴",flushed:"😳",fog:"🌫",foggy:"🌁",football:"🏈",footprints:"👣",fork_and_knife:"🍴",fountain:"⛲️",fountain_pen:"🖋",four_leaf_clover:"🍀",fox_face:"🦊",framed_picture:"🖼",free:"🆓",fried_egg:"🍳",fried_shrimp:"🍤",fries:"🍟",frog:"🐸",frowning:"😦",frowning_face:"☹️",frowning_man:"🙍&zwj;♂️",frowning_woman:"🙍",middle_finger:"🖕",fuelpump:"⛽️",full_moon:"🌕",full_moon_with_face:"🌝",funeral_urn:"⚱️",game_die:"🎲",gear:"⚙️",gem:"💎",gemini:"♊️",ghost:"👻",gift:"🎁",gift_heart:"💝",girl:"👧",globe_with_meridians:"🌐",goal_net:"🥅",goat:"🐐",golf:"⛳️",golfing_man:"🏌️",golfing_woman:"🏌️&zwj;♀️",gorilla:"🦍",grapes:"🍇",green_apple:"🍏",green_book:"📗",green_heart:"💚",green_salad:"🥗",grey_exclamation:"❕",grey_question:"❔",grimacing:"😬",grin:"😁",grinning:"😀",guardsman:"💂",guardswoman:"💂&zwj;♀️",guitar:"🎸",gun:"🔫",haircut_woman:"💇",haircut_man:"💇&zwj;♂️",hamburger:"🍔",hammer:"🔨",hammer_and_pick:"⚒",hammer_and_wrench:"🛠",hamster:"🐹",hand:"✋",handbag:"👜",handshake:"🤝",hankey:"💩",hatched_chick:"🐥",hatching_chick:"🐣",headphones:"🎧",hear_no_evil:"🙉",heart:"❤️",heart_decoration:"💟",heart_eyes:"😍",heart_eyes_cat:"😻",heartbeat:"💓",heartpulse:"💗",hearts:"♥️",heavy_check_mark:"✔️",heavy_division_sign:"➗",heavy_dollar_sign:"💲",heavy_heart_exclamation:"❣️",heavy_minus_sign:"➖",heavy_multiplication_x:"✖️",heavy_plus_sign:"➕",helicopter:"🚁",herb:"🌿",hibiscus:"🌺",high_brightness:"🔆",high_heel:"👠",hocho:"🔪",hole:"🕳",honey_pot:"🍯",horse:"🐴",horse_racing:"🏇",hospital:"🏥",hot_pepper:"🌶",hotdog:"🌭",hotel:"🏨",hotsprings:"♨️",hourglass:"⌛️",hourglass_flowing_sand:"⏳",house:"🏠",house_with_garden:"🏡",houses:"🏘",hugs:"🤗",hushed:"😯",ice_cream:"🍨",ice_hockey:"🏒",ice_skate:"⛸",icecream:"🍦",id:"🆔",ideograph_advantage:"🉐",imp:"👿",inbox_tray:"📥",incoming_envelope:"📨",tipping_hand_woman:"💁",information_source:"ℹ️",innocent:"😇",interrobang:"⁉️",iphone:"📱",izakaya_lantern:"🏮",jack_o_lantern:"🎃",japan:"🗾",japanese_castle:"🏯",japanese_goblin:"👺",japanese_ogre:"👹",jeans:"👖",joy:"😂",joy_cat:"😹",joystick:"🕹",kaaba:"🕋",key:"🔑",keyboard:"⌨️",keycap_ten:"🔟",kick_scooter:"🛴",kimono:"👘",kiss:"💋",kissing:"😗",kissing_cat:"😽",kissing_closed_eyes:"😚",kissing_heart:"😘",kissing_smiling_eyes:"😙",kiwi_fruit:"🥝",koala:"🐨",koko:"🈁",label:"🏷",large_blue_circle:"🔵",large_blue_diamond:"🔷",large_orange_diamond:"🔶",last_quarter_moon:"🌗",last_quarter_moon_with_face:"🌜",latin_cross:"✝️",laughing:"😆",leaves:"🍃",ledger:"📒",left_luggage:"🛅",left_right_arrow:"↔️",leftwards_arrow_with_hook:"↩️",lemon:"🍋",leo:"♌️",leopard:"🐆",level_slider:"🎚",libra:"♎️",light_rail:"🚈",link:"🔗",lion:"🦁",lips:"👄",lipstick:"💄",lizard:"🦎",lock:"🔒",lock_with_ink_pen:"🔏",lollipop:"🍭",loop:"➿",loud_sound:"🔊",loudspeaker:"📢",love_hotel:"🏩",love_letter:"💌",low_brightness:"🔅",lying_face:"🤥",m:"Ⓜ️",mag:"🔍",mag_right:"🔎",mahjong:"🀄️",mailbox:"📫",mailbox_closed:"📪",mailbox_with_mail:"📬",mailbox_with_no_mail:"📭",man:"👨",man_artist:"👨&zwj;🎨",man_astronaut:"👨&zwj;🚀",man_cartwheeling:"🤸&zwj;♂️",man_cook:"👨&zwj;🍳",man_dancing:"🕺",man_facepalming:"🤦&zwj;♂️",man_factory_worker:"👨&zwj;🏭",man_farmer:"👨&zwj;🌾",man_firefighter:"👨&zwj;🚒",man_health_worker:"👨&zwj;⚕️",man_in_tuxedo:"🤵",man_judge:"👨&zwj;⚖️",man_juggling:"🤹&zwj;♂️",man_mechanic:"👨&zwj;🔧",man_office_worker:"👨&zwj;💼",man_pilot:"👨&zwj;✈️",man_playing_handball:"🤾&zwj;♂️",man_playing_water_polo:"🤽&zwj;♂️",man_scientist:"👨&zwj;🔬",man_shrugging:"🤷&zwj;♂️",man_singer:"👨&zwj;🎤",man_student:"👨&zwj;🎓",man_teacher:"👨&zwj;🏫",man_technologist:"👨&zwj;💻",man_with_gua_pi_mao:"👲",man_with_turban:"👳",tangerine:"🍊",mans_shoe:"👞",mantelpiece_clock:"🕰",maple_leaf:"🍁",martial_arts_uniform:"🥋",mask:"😷",massage_woman:"💆",massage_man:"💆&zwj;♂️",meat_on_bone:"🍖",medal_military:"🎖",medal_sports:"🏅",mega:"📣",melon:"🍈",memo:"📝",men_wrestling:"🤼&zwj;♂️",menorah:"🕎",mens:"🚹",metal:"🤘",metro:"🚇",microphone:"🎤",microscope:"🔬",milk_glass:"🥛",milky_way:"🌌",minibus:"🚐",minidisc:"💽",mobile_phone_off:"📴",money_mouth_face:"🤑",money_with_wings:"💸",moneybag:"💰",monkey:"🐒",monkey_face:"🐵",monorail:"🚝",moon:"🌔",mortar_board:"🎓",mosque:"🕌",motor_boat:"🛥",motor_scooter:"🛵",motorcycle:"🏍",motorway:"🛣",mount_fuji:"🗻",mountain:"⛰",mountain_biking_man:"🚵",mountain_biking_woman:"🚵&zwj;♀️",mountain_cableway:"🚠",mountain_railway:"🚞",mountain_snow:"🏔",mouse:"🐭",mouse2:"🐁",movie_camera:"🎥",moyai:"🗿",mrs_claus:"🤶",muscle:"💪",mushroom:"🍄",musical_keyboard:"🎹",musical_note:"🎵",musical_score:"🎼",mute:"🔇",nail_care:"💅",name_badge:"📛",national_park:"🏞",nauseated_face:"🤢",necktie:"👔",negative_squared_cross_mark:"❎",nerd_face:"🤓",neutral_face:"😐",new:"🆕",new_moon:"🌑",new_moon_with_face:"🌚",newspaper:"📰",newspaper_roll:"🗞",next_track_button:"⏭",ng:"🆖",no_good_man:"🙅&zwj;♂️",no_good_woman:"🙅",night_with_stars:"🌃",no_bell:"🔕",no_bicycles:"🚳",no_entry:"⛔️",no_entry_sign:"🚫",no_mobile_phones:"📵",no_mouth:"😶",no_pedestrians:"🚷",no_smoking:"🚭","non-potable_water":"🚱",nose:"👃",notebook:"📓",notebook_with_decorative_cover:"📔",notes:"🎶",nut_and_bolt:"🔩",o:"⭕️",o2:"🅾️",ocean:"🌊",octopus:"🐙",oden:"🍢",office:"🏢",oil_drum:"🛢",ok:"🆗",ok_hand:"👌",ok_man:"🙆&zwj;♂️",ok_woman:"🙆",old_key:"🗝",older_man:"👴",older_woman:"👵",om:"🕉",on:"🔛",oncoming_automobile:"🚘",oncoming_bus:"🚍",oncoming_police_car:"🚔",oncoming_taxi:"🚖",open_file_folder:"📂",open_hands:"👐",open_mouth:"😮",open_umbrella:"☂️",ophiuchus:"⛎",orange_book:"📙",orthodox_cross:"☦️",outbox_tray:"📤",owl:"🦉",ox:"🐂",package:"📦",page_facing_up:"📄",page_with_curl:"📃",pager:"📟",paintbrush:"🖌",palm_tree:"🌴",pancakes:"🥞",panda_face:"🐼",paperclip:"📎",paperclips:"🖇",parasol_on_ground:"⛱",parking:"🅿️",part_alternation_mark:"〽️",partly_sunny:"⛅️",passenger_ship:"🛳",passport_control:"🛂",pause_button:"⏸",peace_symbol:"☮️",peach:"🍑",peanuts:"🥜",pear:"🍐",pen:"🖊",pencil2:"✏️",penguin:"🐧",pensive:"😔",performing_arts:"🎭",persevere:"😣",person_fencing:"🤺",pouting_woman:"🙎",phone:"☎️",pick:"⛏",pig:"🐷",pig2:"🐖",pig_nose:"🐽",pill:"💊",pineapple:"🍍",ping_pong:"🏓",pisces:"♓️",pizza:"🍕",place_of_worship:"🛐",plate_with_cutlery:"🍽",play_or_pause_button:"⏯",point_down:"👇",point_left:"👈",point_right:"👉",point_up:"☝️",point_up_2:"👆",police_car:"🚓",policewoman:"👮&zwj;♀️",poodle:"🐩",popcorn:"🍿",post_office:"🏣",postal_horn:"📯",postbox:"📮",potable_water:"🚰",potato:"🥔",pouch:"👝",poultry_leg:"🍗",pound:"💷",rage:"😡",pouting_cat:"😾",pouting_man:"🙎&zwj;♂️",pray:"🙏",prayer_beads:"📿",pregnant_woman:"🤰",previous_track_button:"⏮",prince:"🤴",princess:"👸",printer:"🖨",purple_heart:"💜",purse:"👛",pushpin:"📌",put_litter_in_its_place:"🚮",question:"❓",rabbit:"🐰",rabbit2:"🐇",racehorse:"🐎",racing_car:"🏎",radio:"📻",radio_button:"🔘",radioactive:"☢️",railway_car:"🚃",railway_track:"🛤",rainbow:"🌈",rainbow_flag:"🏳️&zwj;🌈",raised_back_of_hand:"🤚",raised_hand_with_fingers_splayed:"🖐",raised_hands:"🙌",raising_hand_woman:"🙋",raising_hand_man:"🙋&zwj;♂️",ram:"🐏",ramen:"🍜",rat:"🐀",record_button:"⏺",recycle:"♻️",red_circle:"🔴",registered:"®️",relaxed:"☺️",relieved:"😌",reminder_ribbon:"🎗",repeat:"🔁",repeat_one:"🔂",rescue_worker_helmet:"⛑",restroom:"🚻",revolving_hearts:"💞",rewind:"⏪",rhinoceros:"🦏",ribbon:"🎀",rice:"🍚",rice_ball:"🍙",rice_cracker:"🍘",rice_scene:"🎑",right_anger_bubble:"🗯",ring:"💍",robot:"🤖",rocket:"🚀",rofl:"🤣",roll_eyes:"🙄",roller_coaster:"🎢",rooster:"🐓",rose:"🌹",rosette:"🏵",rotating_light:"🚨",round_pushpin:"📍",rowing_man:"🚣",rowing_woman:"🚣&zwj;♀️",rugby_football:"🏉",running_man:"🏃",running_shirt_with_sash:"🎽",running_woman:"🏃&zwj;♀️",sa:"🈂️",sagittarius:"♐️",sake:"🍶",sandal:"👡",santa:"🎅",satellite:"📡",saxophone:"🎷",school:"🏫",school_satchel:"🎒",scissors:"✂️",scorpion:"🦂",scorpius:"♏️",scream:"😱",scream_cat:"🙀",scroll:"📜",seat:"💺",secret:"㊙️",see_no_evil:"🙈",seedling:"🌱",selfie:"🤳",shallow_pan_of_food:"🥘",shamrock:"☘️",shark:"🦈",shaved_ice:"🍧",sheep:"🐑",shell:"🐚",shield:"🛡",shinto_shrine:"⛩",ship:"🚢",shirt:"👕",shopping:"🛍",shopping_cart:"🛒",shower:"🚿",shrimp:"🦐",signal_strength:"📶",six_pointed_star:"🔯",ski:"🎿",skier:"⛷",skull:"💀",skull_and_crossbones:"☠️",sleeping:"😴",sleeping_bed:"🛌",sleepy:"😪",slightly_frowning_face:"🙁",slightly_smiling_face:"🙂",slot_machine:"🎰",small_airplane:"🛩",small_blue_diamond:"🔹",small_orange_diamond:"🔸",small_red_triangle:"🔺",small_red_triangle_down:"🔻",smile:"😄",smile_cat:"😸",smiley:"😃",smiley_cat:"😺",smiling_imp:"😈",smirk:"😏",smirk_cat:"😼",smoking:"🚬",snail:"🐌",snake:"🐍",sneezing_face:"🤧",snowboarder:"🏂",snowflake:"❄️",snowman:"⛄️",snowman_with_snow:"☃️",sob:"😭",soccer:"⚽️",soon:"🔜",sos:"🆘",sound:"🔉",space_invader:"👾",spades:"♠️",spaghetti:"🍝",sparkle:"❇️",sparkler:"🎇",sparkles:"✨",sparkling_heart:"💖",speak_no_evil:"🙊",speaker:"🔈",speaking_head:"🗣",speech_balloon:"💬",speedboat:"🚤",spider:"🕷",spider_web:"🕸",spiral_calendar:"🗓",spiral_notepad:"🗒",spoon:"🥄",squid:"🦑",stadium:"🏟",star:"⭐️",star2:"🌟",star_and_crescent:"☪️",star_of_david:"✡️",stars:"🌠",station:"🚉",statue_of_liberty:"🗽",steam_locomotive:"🚂",stew:"🍲",stop_button:"⏹",stop_sign:"🛑",stopwatch:"⏱",straight_ruler:"📏",strawberry:"🍓",stuck_out_tongue:"😛",stuck_out_tongue_closed_eyes:"😝",stuck_out_tongue_winking_eye:"😜",studio_microphone:"🎙",stuffed_flatbread:"🥙",sun_behind_large_cloud:"🌥",sun_behind_rain_cloud:"🌦",sun_behind_small_cloud:"🌤",sun_with_face:"🌞",sunflower:"🌻",sunglasses:"😎",sunny:"☀️",sunrise:"🌅",sunrise_over_mountains:"🌄",surfing_man:"🏄",surfing_woman:"🏄&zwj;♀️",sushi:"🍣",suspension_railway:"🚟",sweat:"😓",sweat_drops:"💦",sweat_smile:"😅",sweet_potato:"🍠",swimming_man:"🏊",swimming_woman:"🏊&zwj;♀️",symbols:"🔣",synagogue:"🕍",syringe:"💉",taco:"🌮",tada:"🎉",tanabata_tree:"🎋",taurus:"♉️",taxi:"🚕",tea:"🍵",telephone_receiver:"📞",telescope:"🔭",tennis:"🎾",tent:"⛺️",thermometer:"🌡",thinking:"🤔",thought_balloon:"💭",ticket:"🎫",tickets:"🎟",tiger:"🐯",tiger2:"🐅",timer_clock:"⏲",tipping_hand_man:"💁&zwj;♂️",tired_face:"😫",tm:"™️",toilet:"🚽",tokyo_tower:"🗼",tomato:"🍅",tongue:"👅",top:"🔝",tophat:"🎩",tornado:"🌪",trackball:"🖲",tractor:"🚜",traffic_light:"🚥",train:"🚋",train2:"🚆",tram:"🚊",triangular_flag_on_post:"🚩",triangular_ruler:"📐",trident:"🔱",triumph:"😤",trolleybus:"🚎",trophy:"🏆",tropical_drink:"🍹",tropical_fish:"🐠",truck:"🚚",trumpet:"🎺",tulip:"🌷",tumbler_glass:"🥃",turkey:"🦃",turtle:"🐢",tv:"📺",twisted_rightwards_arrows:"🔀",two_hearts:"💕",two_men_holding_hands:"👬",two_women_holding_hands:"👭",u5272:"🈹",u5408:"🈴",u55b6:"🈺",u6307:"🈯️",u6708:"🈷️",u6709:"🈶",u6e80:"🈵",u7121:"🈚️",u7533:"🈸",u7981:"🈲",u7a7a:"🈳",umbrella:"☔️",unamused:"😒",underage:"🔞",unicorn:"🦄",unlock:"🔓",up:"🆙",upside_down_face:"🙃",v:"✌️",vertical_traffic_light:"🚦",vhs:"📼",vibration_mode:"📳",video_camera:"📹",video_game:"🎮",violin:"🎻",virgo:"♍️",volcano:"🌋",volleyball:"🏐",vs:"🆚",vulcan_salute:"🖖",walking_man:"🚶",walking_woman:"🚶&zwj;♀️",waning_crescent_moon:"🌘",waning_gibbous_moon:"🌖",warning:"⚠️",wastebasket:"🗑",watch:"⌚️",water_buffalo:"🐃",watermelon:"🍉",wave:"👋",wavy_dash:"〰️",waxing_crescent_moon:"🌒",wc:"🚾",weary:"😩",wedding:"💒",weight_lifting_man:"🏋️",weight_lifting_woman:"🏋️&zwj;♀️",whale:"🐳",whale2:"🐋",wheel_of_dharma:"☸️",wheelchair:"♿️",white_check_mark:"✅",white_circle:"⚪️",white_flag:"🏳️",white_flower:"💮",white_large_square:"⬜️",white_medium_small_square:"◽️",white_medium_square:"◻️",white_small_square:"▫️",white_square_button:"🔳",wilted_flower:"🥀",wind_chime:"🎐",wind_face:"🌬",wine_glass:"🍷",wink:"😉",wolf:"🐺",woman:"👩",woman_artist:"👩&zwj;🎨",woman_astronaut:"👩&zwj;🚀",woman_cartwheeling:"🤸&zwj;♀️",woman_cook:"👩&zwj;🍳",woman_facepalming:"🤦&zwj;♀️",woman_factory_worker:"👩&zwj;🏭",woman_farmer:"👩&zwj;🌾",woman_firefighter:"👩&zwj;🚒",woman_health_worker:"👩&zwj;⚕️",woman_judge:"👩&zwj;⚖️",woman_juggling:"🤹&zwj;♀️",woman_mechanic:"👩&zwj;🔧",woman_office_worker:"👩&zwj;💼",woman_pilot:"👩&zwj;✈️",woman_playing_handball:"🤾&zwj;♀️",woman_playing_water_polo:"🤽&zwj;♀️",woman_scientist:"👩&zwj;🔬",woman_shrugging:"🤷&zwj;♀️",woman_singer:"👩&zwj;🎤",woman_student:"👩&zwj;🎓",woman_teacher:"👩&zwj;🏫",woman_technologist:"👩&zwj;💻",woman_with_turban:"👳&zwj;♀️",womans_clothes:"👚",womans_hat:"👒",women_wrestling:"🤼&zwj;♀️",womens:"🚺",world_map:"🗺",worried:"😟",wrench:"🔧",writing_hand:"✍️",x:"❌",yellow_heart:"💛",yen:"💴",yin_yang:"☯️",yum:"😋",zap:"⚡️",zipper_mouth_face:"🤐",zzz:"💤",octocat:'<img alt=":octocat:" height="20" width="20" align="absmiddle" src="https://assets-cdn.github.com/images/icons/emoji/octocat.png">',showdown:`<span style="font-family: 'Anonymous Pro', monospace; text-decoration: underline; text-decoration-style: dashed; text-decoration-color: #3e8b8a;text-underline-position: under;">S</span>`},r.Converter=function(l){var h={},d=[],_=[],v={},y=p,m={parsed:{},raw:"",format:""};E();function E(){l=l||{};for(var T in c)c.hasOwnProperty(T)&&(h[T]=c[T]);if(typeof l=="object")for(var Q in l)l.hasOwnProperty(Q)&&(h[Q]=l[Q]);else throw Error("Converter expects the passed parameter to be an object, but "+typeof l+" was passed instead.");h.extensions&&r.helper.forEach(h.extensions,D)}function D(T,Q){if(Q=Q||null,r.helper.isString(T))if(T=r.helper.stdExtName(T),Q=T,r.extensions[T]){console.warn("DEPRECATION WARNING: "+T+" is an old extension that uses a deprecated loading method.Please inform the developer that the extension should be updated!"),j(r.extensions[T],T);return}else if(!r.helper.isUndefined(u[T]))T=u[T];else throw Error('Extension "'+T+'" could not be loaded. It was either not found or is not a valid extension.');typeof T=="function"&&(T=T()),r.helper.isArray(T)||(T=[T]);var G=b(T,Q);if(!G.valid)throw Error(G.error);for(var ee=0;ee<T.length;++ee){switch(T[ee].type){case"lang":d.push(T[ee]);break;case"output":_.push(T[ee]);break}if(T[ee].hasOwnProperty("listeners"))for(var ie in T[ee].listeners)T[ee].listeners.hasOwnProperty(ie)&&V(ie,T[ee].listeners[ie])}}function j(T,Q){typeof T=="function"&&(T=T(new r.Converter)),r.helper.isArray(T)||(T=[T]);var G=b(T,Q);if(!G.valid)throw Error(G.error);for(var ee=0;ee<T.length;++ee)switch(T[ee].type){case"lang":d.push(T[ee]);break;case"output":_.push(T[ee]);break;default:throw Error("Extension loader error: Type unrecognized!!!")}}function V(T,Q){if(!r.helper.isString(T))throw Error("Invalid argument in converter.listen() method: name must be a string, but "+typeof T+" given");if(typeof Q!="function")throw Error("Invalid argument in converter.listen() method: callback must be a function, but "+typeof Q+" given");v.hasOwnProperty(T)||(v[T]=[]),v[T].push(Q)}function W(T){var Q=T.match(/^\s*/)[0].length,G=new RegExp("^\\s{0,"+Q+"}","gm");return T.replace(G,"")}this._dispatch=function(Q,G,ee,ie){if(v.hasOwnProperty(Q))for(var le=0;le<v[Q].length;++le){var Oe=v[Q][le](Q,G,this,ee,ie);Oe&&typeof Oe<"u"&&(G=Oe)}return G},this.listen=function(T,Q){return V(T,Q),this},this.makeHtml=function(T){if(!T)return T;var Q={gHtmlBlocks:[],gHtmlMdBlocks:[],gHtmlSpans:[],gUrls:{},gTitles:{},gDimensions:{},gListLevel:0,hashLinkCounts:{},langExtensions:d,outputModifiers:_,converter:this,ghCodeBlocks:[],metadata:{parsed:{},raw:"",format:""}};return T=T.replace(/¨/g,"¨T"),T=T.replace(/\$/g,"¨D"),T=T.replace(/\r\n/g,`
-`),T=T.replace(/\r/g,`
-`),T=T.replace(/\u00A0/g,"&nbsp;"),h.smartIndentationFix&&(T=W(T)),T=`
-
-`+T+`
-
-`,T=r.subParser("detab")(T,h,Q),T=T.replace(/^[ \t]+$/mg,""),r.helper.forEach(d,function(G){T=r.subParser("runExtension")(G,T,h,Q)}),T=r.subParser("metadata")(T,h,Q),T=r.subParser("hashPreCodeTags")(T,h,Q),T=r.subParser("githubCodeBlocks")(T,h,Q),T=r.subParser("hashHTMLBlocks")(T,h,Q),T=r.subParser("hashCodeTags")(T,h,Q),T=r.subParser("stripLinkDefinitions")(T,h,Q),T=r.subParser("blockGamut")(T,h,Q),T=r.subParser("unhashHTMLSpans")(T,h,Q),T=r.subParser("unescapeSpecialChars")(T,h,Q),T=T.replace(/¨D/g,"$$"),T=T.replace(/¨T/g,"¨"),T=r.subParser("completeHTMLDocument")(T,h,Q),r.helper.forEach(_,function(G){T=r.subParser("runExtension")(G,T,h,Q)}),m=Q.metadata,T},this.makeMarkdown=this.makeMd=function(T,Q){if(T=T.replace(/\r\n/g,`
-`),T=T.replace(/\r/g,`
-`),T=T.replace(/>[ \t]+</,">¨NBSP;<"),!Q)if(window&&window.document)Q=window.document;else throw new Error("HTMLParser is undefined. If in a webworker or nodejs environment, you need to provide a WHATWG DOM and HTML such as JSDOM");var G=Q.createElement("div");G.innerHTML=T;var ee={preList:tt(G)};Xe(G);for(var ie=G.childNodes,le="",Oe=0;Oe<ie.length;Oe++)le+=r.subParser("makeMarkdown.node")(ie[Oe],ee);function Xe(je){for(var Me=0;Me<je.childNodes.length;++Me){var Ze=je.childNodes[Me];Ze.nodeType===3?/\S/.test(Ze.nodeValue)?(Ze.nodeValue=Ze.nodeValue.split(`
-`).join(" "),Ze.nodeValue=Ze.nodeValue.replace(/(\s)+/g,"$1")):(je.removeChild(Ze),--Me):Ze.nodeType===1&&Xe(Ze)}}function tt(je){for(var Me=je.querySelectorAll("pre"),Ze=[],ce=0;ce<Me.length;++ce)if(Me[ce].childElementCount===1&&Me[ce].firstChild.tagName.toLowerCase()==="code"){var A=Me[ce].firstChild.innerHTML.trim(),R=Me[ce].firstChild.getAttribute("data-language")||"";if(R==="")for(var $=Me[ce].firstChild.className.split(" "),se=0;se<$.length;++se){var ae=$[se].match(/^language-(.+)$/);if(ae!==null){R=ae[1];break}}A=r.helper.unescapeHTMLEntities(A),Ze.push(A),Me[ce].outerHTML='<precode language="'+R+'" precodenum="'+ce.toString()+'"></precode>'}else Ze.push(Me[ce].innerHTML),Me[ce].innerHTML="",Me[ce].setAttribute("prenum",ce.toString());return Ze}return le},this.setOption=function(T,Q){h[T]=Q},this.getOption=function(T){return h[T]},this.getOptions=function(){return h},this.addExtension=function(T,Q){Q=Q||null,D(T,Q)},this.useExtension=function(T){D(T)},this.setFlavor=function(T){if(!g.hasOwnProperty(T))throw Error(T+" flavor was not found");var Q=g[T];y=T;for(var G in Q)Q.hasOwnProperty(G)&&(h[G]=Q[G])},this.getFlavor=function(){return y},this.removeExtension=function(T){r.helper.isArray(T)||(T=[T]);for(var Q=0;Q<T.length;++Q){for(var G=T[Q],ee=0;ee<d.length;++ee)d[ee]===G&&d[ee].splice(ee,1);for(var ie=0;ie<_.length;++ee)_[ie]===G&&_[ie].splice(ee,1)}},this.getAllExtensions=function(){return{language:d,output:_}},this.getMetadata=function(T){return T?m.raw:m.parsed},this.getMetadataFormat=function(){return m.format},this._setMetadataPair=function(T,Q){m.parsed[T]=Q},this._setMetadataFormat=function(T){m.format=T},this._setMetadataRaw=function(T){m.raw=T}},r.subParser("anchors",function(l,h,d){l=d.converter._dispatch("anchors.before",l,h,d);var _=function(v,y,m,E,D,j,V){if(r.helper.isUndefined(V)&&(V=""),m=m.toLowerCase(),v.search(/\(<?\s*>? ?(['"].*['"])?\)$/m)>-1)E="";else if(!E)if(m||(m=y.toLowerCase().replace(/ ?\n/g," ")),E="#"+m,!r.helper.isUndefined(d.gUrls[m]))E=d.gUrls[m],r.helper.isUndefined(d.gTitles[m])||(V=d.gTitles[m]);else return v;E=E.replace(r.helper.regexes.asteriskDashAndColon,r.helper.escapeCharactersCallback);var W='<a href="'+E+'"';return V!==""&&V!==null&&(V=V.replace(/"/g,"&quot;"),V=V.replace(r.helper.regexes.asteriskDashAndColon,r.helper.escapeCharactersCallback),W+=' title="'+V+'"'),h.openLinksInNewWindow&&!/^#/.test(E)&&(W+=' rel="noopener noreferrer" target="¨E95Eblank"'),W+=">"+y+"</a>",W};return l=l.replace(/\[((?:\[[^\]]*]|[^\[\]])*)] ?(?:\n *)?\[(.*?)]()()()()/g,_),l=l.replace(/\[((?:\[[^\]]*]|[^\[\]])*)]()[ \t]*\([ \t]?<([^>]*)>(?:[ \t]*((["'])([^"]*?)\5))?[ \t]?\)/g,_),l=l.replace(/\[((?:\[[^\]]*]|[^\[\]])*)]()[ \t]*\([ \t]?<?([\S]+?(?:\([\S]*?\)[\S]*?)?)>?(?:[ \t]*((["'])([^"]*?)\5))?[ \t]?\)/g,_),l=l.replace(/\[([^\[\]]+)]()()()()()/g,_),h.ghMentions&&(l=l.replace(/(^|\s)(\\)?(@([a-z\d]+(?:[a-z\d.-]+?[a-z\d]+)*))/gmi,function(v,y,m,E,D){if(m==="\\")return y+E;if(!r.helper.isString(h.ghMentionsLink))throw new Error("ghMentionsLink option must be a string");var j=h.ghMentionsLink.replace(/\{u}/g,D),V="";return h.openLinksInNewWindow&&(V=' rel="noopener noreferrer" target="¨E95Eblank"'),y+'<a href="'+j+'"'+V+">"+E+"</a>"})),l=d.converter._dispatch("anchors.after",l,h,d),l});var q=/([*~_]+|\b)(((https?|ftp|dict):\/\/|www\.)[^'">\s]+?\.[^'">\s]+?)()(\1)?(?=\s|$)(?!["<>])/gi,Z=/([*~_]+|\b)(((https?|ftp|dict):\/\/|www\.)[^'">\s]+\.[^'">\s]+?)([.!?,()\[\]])?(\1)?(?=\s|$)(?!["<>])/gi,M=/()<(((https?|ftp|dict):\/\/|www\.)[^'">\s]+)()>()/gi,H=/(^|\s)(?:mailto:)?([A-Za-z0-9!#$%&'*+-/=?^_`{|}~.]+@[-a-z0-9]+(\.[-a-z0-9]+)*\.[a-z]+)(?=$|\s)/gmi,z=/<()(?:mailto:)?([-.\w]+@[-a-z0-9]+(\.[-a-z0-9]+)*\.[a-z]+)>/gi,L=function(l){return function(h,d,_,v,y,m,E){_=_.replace(r.helper.regexes.asteriskDashAndColon,r.helper.escapeCharactersCallback);var D=_,j="",V="",W=d||"",T=E||"";return/^www\./i.test(_)&&(_=_.replace(/^www\./i,"http://www.")),l.excludeTrailingPunctuationFromURLs&&m&&(j=m),l.openLinksInNewWindow&&(V=' rel="noopener noreferrer" target="¨E95Eblank"'),W+'<a href="'+_+'"'+V+">"+D+"</a>"+j+T}},k=function(l,h){return function(d,_,v){var y="mailto:";return _=_||"",v=r.subParser("unescapeSpecialChars")(v,l,h),l.encodeEmails?(y=r.helper.encodeEmailAddress(y+v),v=r.helper.encodeEmailAddress(v)):y=y+v,_+'<a href="'+y+'">'+v+"</a>"}};r.subParser("autoLinks",function(l,h,d){return l=d.converter._dispatch("autoLinks.before",l,h,d),l=l.replace(M,L(h)),l=l.replace(z,k(h,d)),l=d.converter._dispatch("autoLinks.after",l,h,d),l}),r.subParser("simplifiedAutoLinks",function(l,h,d){return h.simplifiedAutoLink&&(l=d.converter._dispatch("simplifiedAutoLinks.before",l,h,d),h.excludeTrailingPunctuationFromURLs?l=l.replace(Z,L(h)):l=l.replace(q,L(h)),l=l.replace(H,k(h,d)),l=d.converter._dispatch("simplifiedAutoLinks.after",l,h,d)),l}),r.subParser("blockGamut",function(l,h,d){return l=d.converter._dispatch("blockGamut.before",l,h,d),l=r.subParser("blockQuotes")(l,h,d),l=r.subParser("headers")(l,h,d),l=r.subParser("horizontalRule")(l,h,d),l=r.subParser("lists")(l,h,d),l=r.subParser("codeBlocks")(l,h,d),l=r.subParser("tables")(l,h,d),l=r.subParser("hashHTMLBlocks")(l,h,d),l=r.subParser("paragraphs")(l,h,d),l=d.converter._dispatch("blockGamut.after",l,h,d),l}),r.subParser("blockQuotes",function(l,h,d){l=d.converter._dispatch("blockQuotes.before",l,h,d),l=l+`
-
-`;var _=/(^ {0,3}>[ \t]?.+\n(.+\n)*\n*)+/gm;return h.splitAdjacentBlockquotes&&(_=/^ {0,3}>[\s\S]*?(?:\n\n)/gm),l=l.replace(_,function(v){return v=v.replace(/^[ \t]*>[ \t]?/gm,""),v=v.replace(/¨0/g,""),v=v.replace(/^[ \t]+$/gm,""),v=r.subParser("githubCodeBlocks")(v,h,d),v=r.subParser("blockGamut")(v,h,d),v=v.replace(/(^|\n)/g,"$1  "),v=v.replace(/(\s*<pre>[^\r]+?<\/pre>)/gm,function(y,m){var E=m;return E=E.replace(/^  /mg,"¨0"),E=E.replace(/¨0/g,""),E}),r.subParser("hashBlock")(`<blockquote>
-`+v+`
-</blockquote>`,h,d)}),l=d.converter._dispatch("blockQuotes.after",l,h,d),l}),r.subParser("codeBlocks",function(l,h,d){l=d.converter._dispatch("codeBlocks.before",l,h,d),l+="¨0";var _=/(?:\n\n|^)((?:(?:[ ]{4}|\t).*\n+)+)(\n*[ ]{0,3}[^ \t\n]|(?=¨0))/g;return l=l.replace(_,function(v,y,m){var E=y,D=m,j=`
-`;return E=r.subParser("outdent")(E,h,d),E=r.subParser("encodeCode")(E,h,d),E=r.subParser("detab")(E,h,d),E=E.replace(/^\n+/g,""),E=E.replace(/\n+$/g,""),h.omitExtraWLInCodeBlocks&&(j=""),E="<pre><code>"+E+j+"</code></pre>",r.subParser("hashBlock")(E,h,d)+D}),l=l.replace(/¨0/,""),l=d.converter._dispatch("codeBlocks.after",l,h,d),l}),r.subParser("codeSpans",function(l,h,d){return l=d.converter._dispatch("codeSpans.before",l,h,d),typeof l>"u"&&(l=""),l=l.replace(/(^|[^\\])(`+)([^\r]*?[^`])\2(?!`)/gm,function(_,v,y,m){var E=m;return E=E.replace(/^([ \t]*)/g,""),E=E.replace(/[ \t]*$/g,""),E=r.subParser("encodeCode")(E,h,d),E=v+"<code>"+E+"</code>",E=r.subParser("hashHTMLSpans")(E,h,d),E}),l=d.converter._dispatch("codeSpans.after",l,h,d),l}),r.subParser("completeHTMLDocument",function(l,h,d){if(!h.completeHTMLDocument)return l;l=d.converter._dispatch("completeHTMLDocument.before",l,h,d);var _="html",v=`<!DOCTYPE HTML>
-`,y="",m=`<meta charset="utf-8">
-`,E="",D="";typeof d.metadata.parsed.doctype<"u"&&(v="<!DOCTYPE "+d.metadata.parsed.doctype+`>
-`,_=d.metadata.parsed.doctype.toString().toLowerCase(),(_==="html"||_==="html5")&&(m='<meta charset="utf-8">'));for(var j in d.metadata.parsed)if(d.metadata.parsed.hasOwnProperty(j))switch(j.toLowerCase()){case"doctype":break;case"title":y="<title>"+d.metadata.parsed.title+`</title>
-`;break;case"charset":_==="html"||_==="html5"?m='<meta charset="'+d.metadata.parsed.charset+`">
-`:m='<meta name="charset" content="'+d.metadata.parsed.charset+`">
-`;break;case"language":case"lang":E=' lang="'+d.metadata.parsed[j]+'"',D+='<meta name="'+j+'" content="'+d.metadata.parsed[j]+`">
-`;break;default:D+='<meta name="'+j+'" content="'+d.metadata.parsed[j]+`">
-`}return l=v+"<html"+E+`>
-<head>
-`+y+m+D+`</head>
-<body>
-`+l.trim()+`
-</body>
-</html>`,l=d.converter._dispatch("completeHTMLDocument.after",l,h,d),l}),r.subParser("detab",function(l,h,d){return l=d.converter._dispatch("detab.before",l,h,d),l=l.replace(/\t(?=\t)/g,"    "),l=l.replace(/\t/g,"¨A¨B"),l=l.replace(/¨B(.+?)¨A/g,function(_,v){for(var y=v,m=4-y.length%4,E=0;E<m;E++)y+=" ";return y}),l=l.replace(/¨A/g,"    "),l=l.replace(/¨B/g,""),l=d.converter._dispatch("detab.after",l,h,d),l}),r.subParser("ellipsis",function(l,h,d){return l=d.converter._dispatch("ellipsis.before",l,h,d),l=l.replace(/\.\.\./g,"…"),l=d.converter._dispatch("ellipsis.after",l,h,d),l}),r.subParser("emoji",function(l,h,d){if(!h.emoji)return l;l=d.converter._dispatch("emoji.before",l,h,d);var _=/:([\S]+?):/g;return l=l.replace(_,function(v,y){return r.helper.emojis.hasOwnProperty(y)?r.helper.emojis[y]:v}),l=d.converter._dispatch("emoji.after",l,h,d),l}),r.subParser("encodeAmpsAndAngles",function(l,h,d){return l=d.converter._dispatch("encodeAmpsAndAngles.before",l,h,d),l=l.replace(/&(?!#?[xX]?(?:[0-9a-fA-F]+|\w+);)/g,"&amp;"),l=l.replace(/<(?![a-z\/?$!])/gi,"&lt;"),l=l.replace(/</g,"&lt;"),l=l.replace(/>/g,"&gt;"),l=d.converter._dispatch("encodeAmpsAndAngles.after",l,h,d),l}),r.subParser("encodeBackslashEscapes",function(l,h,d){return l=d.converter._dispatch("encodeBackslashEscapes.before",l,h,d),l=l.replace(/\\(\\)/g,r.helper.escapeCharactersCallback),l=l.replace(/\\([`*_{}\[\]()>#+.!~=|-])/g,r.helper.escapeCharactersCallback),l=d.converter._dispatch("encodeBackslashEscapes.after",l,h,d),l}),r.subParser("encodeCode",function(l,h,d){return l=d.converter._dispatch("encodeCode.before",l,h,d),l=l.replace(/&/g,"&amp;").replace(/</g,"&lt;").replace(/>/g,"&gt;").replace(/([*_{}\[\]\\=~-])/g,r.helper.escapeCharactersCallback),l=d.converter._dispatch("encodeCode.after",l,h,d),l}),r.subParser("escapeSpecialCharsWithinTagAttributes",function(l,h,d){l=d.converter._dispatch("escapeSpecialCharsWithinTagAttributes.before",l,h,d);var _=/<\/?[a-z\d_:-]+(?:[\s]+[\s\S]+?)?>/gi,v=/<!(--(?:(?:[^>-]|-[^>])(?:[^-]|-[^-])*)--)>/gi;return l=l.replace(_,function(y){return y.replace(/(.)<\/?code>(?=.)/g,"$1`").replace(/([\\`*_~=|])/g,r.helper.escapeCharactersCallback)}),l=l.replace(v,function(y){return y.replace(/([\\`*_~=|])/g,r.helper.escapeCharactersCallback)}),l=d.converter._dispatch("escapeSpecialCharsWithinTagAttributes.after",l,h,d),l}),r.subParser("githubCodeBlocks",function(l,h,d){return h.ghCodeBlocks?(l=d.converter._dispatch("githubCodeBlocks.before",l,h,d),l+="¨0",l=l.replace(/(?:^|\n)(?: {0,3})(```+|~~~+)(?: *)([^\s`~]*)\n([\s\S]*?)\n(?: {0,3})\1/g,function(_,v,y,m){var E=h.omitExtraWLInCodeBlocks?"":`
-`;return m=r.subParser("encodeCode")(m,h,d),m=r.subParser("detab")(m,h,d),m=m.replace(/^\n+/g,""),m=m.replace(/\n+$/g,""),m="<pre><code"+(y?' class="'+y+" language-"+y+'"':"")+">"+m+E+"</code></pre>",m=r.subParser("hashBlock")(m,h,d),`
-
-¨G`+(d.ghCodeBlocks.push({text:_,codeblock:m})-1)+`G
-
-`}),l=l.replace(/¨0/,""),d.converter._dispatch("githubCodeBlocks.after",l,h,d)):l}),r.subParser("hashBlock",function(l,h,d){return l=d.converter._dispatch("hashBlock.before",l,h,d),l=l.replace(/(^\n+|\n+$)/g,""),l=`
-
-¨K`+(d.gHtmlBlocks.push(l)-1)+`K
-
-`,l=d.converter._dispatch("hashBlock.after",l,h,d),l}),r.subParser("hashCodeTags",function(l,h,d){l=d.converter._dispatch("hashCodeTags.before",l,h,d);var _=function(v,y,m,E){var D=m+r.subParser("encodeCode")(y,h,d)+E;return"¨C"+(d.gHtmlSpans.push(D)-1)+"C"};return l=r.helper.replaceRecursiveRegExp(l,_,"<code\\b[^>]*>","</code>","gim"),l=d.converter._dispatch("hashCodeTags.after",l,h,d),l}),r.subParser("hashElement",function(l,h,d){return function(_,v){var y=v;return y=y.replace(/\n\n/g,`
-`),y=y.replace(/^\n/,""),y=y.replace(/\n+$/g,""),y=`
-
-¨K`+(d.gHtmlBlocks.push(y)-1)+`K
-
-`,y}}),r.subParser("hashHTMLBlocks",function(l,h,d){l=d.converter._dispatch("hashHTMLBlocks.before",l,h,d);var _=["pre","div","h1","h2","h3","h4","h5","h6","blockquote","table","dl","ol","ul","script","noscript","form","fieldset","iframe","math","style","section","header","footer","nav","article","aside","address","audio","canvas","figure","hgroup","output","video","p"],v=function(T,Q,G,ee){var ie=T;return G.search(/\bmarkdown\b/)!==-1&&(ie=G+d.converter.makeHtml(Q)+ee),`
-
-¨K`+(d.gHtmlBlocks.push(ie)-1)+`K
-
-`};h.backslashEscapesHTMLTags&&(l=l.replace(/\\<(\/?[^>]+?)>/g,function(T,Q){return"&lt;"+Q+"&gt;"}));for(var y=0;y<_.length;++y)for(var m,E=new RegExp("^ {0,3}(<"+_[y]+"\\b[^>]*>)","im"),D="<"+_[y]+"\\b[^>]*>",j="</"+_[y]+">";(m=r.helper.regexIndexOf(l,E))!==-1;){var V=r.helper.splitAtIndex(l,m),W=r.helper.replaceRecursiveRegExp(V[1],v,D,j,"im");if(W===V[1])break;l=V[0].concat(W)}return l=l.replace(/(\n {0,3}(<(hr)\b([^<>])*?\/?>)[ \t]*(?=\n{2,}))/g,r.subParser("hashElement")(l,h,d)),l=r.helper.replaceRecursiveRegExp(l,function(T){return`
-
-¨K`+(d.gHtmlBlocks.push(T)-1)+`K
-
-`},"^ {0,3}<!--","-->","gm"),l=l.replace(/(?:\n\n)( {0,3}(?:<([?%])[^\r]*?\2>)[ \t]*(?=\n{2,}))/g,r.subParser("hashElement")(l,h,d)),l=d.converter._dispatch("hashHTMLBlocks.after",l,h,d),l}),r.subParser("hashHTMLSpans",function(l,h,d){l=d.converter._dispatch("hashHTMLSpans.before",l,h,d);function _(v){return"¨C"+(d.gHtmlSpans.push(v)-1)+"C"}return l=l.replace(/<[^>]+?\/>/gi,function(v){return _(v)}),l=l.replace(/<([^>]+?)>[\s\S]*?<\/\1>/g,function(v){return _(v)}),l=l.replace(/<([^>]+?)\s[^>]+?>[\s\S]*?<\/\1>/g,function(v){return _(v)}),l=l.replace(/<[^>]+?>/gi,function(v){return _(v)}),l=d.converter._dispatch("hashHTMLSpans.after",l,h,d),l}),r.subParser("unhashHTMLSpans",function(l,h,d){l=d.converter._dispatch("unhashHTMLSpans.before",l,h,d);for(var _=0;_<d.gHtmlSpans.length;++_){for(var v=d.gHtmlSpans[_],y=0;/¨C(\d+)C/.test(v);){var m=RegExp.$1;if(v=v.replace("¨C"+m+"C",d.gHtmlSpans[m]),y===10){console.error("maximum nesting of 10 spans reached!!!");break}++y}l=l.replace("¨C"+_+"C",v)}return l=d.converter._dispatch("unhashHTMLSpans.after",l,h,d),l}),r.subParser("hashPreCodeTags",function(l,h,d){l=d.converter._dispatch("hashPreCodeTags.before",l,h,d);var _=function(v,y,m,E){var D=m+r.subParser("encodeCode")(y,h,d)+E;return`
-
-¨G`+(d.ghCodeBlocks.push({text:v,codeblock:D})-1)+`G
-
-`};return l=r.helper.replaceRecursiveRegExp(l,_,"^ {0,3}<pre\\b[^>]*>\\s*<code\\b[^>]*>","^ {0,3}</code>\\s*</pre>","gim"),l=d.converter._dispatch("hashPreCodeTags.after",l,h,d),l}),r.subParser("headers",function(l,h,d){l=d.converter._dispatch("headers.before",l,h,d);var _=isNaN(parseInt(h.headerLevelStart))?1:parseInt(h.headerLevelStart),v=h.smoothLivePreview?/^(.+)[ \t]*\n={2,}[ \t]*\n+/gm:/^(.+)[ \t]*\n=+[ \t]*\n+/gm,y=h.smoothLivePreview?/^(.+)[ \t]*\n-{2,}[ \t]*\n+/gm:/^(.+)[ \t]*\n-+[ \t]*\n+/gm;l=l.replace(v,function(D,j){var V=r.subParser("spanGamut")(j,h,d),W=h.noHeaderId?"":' id="'+E(j)+'"',T=_,Q="<h"+T+W+">"+V+"</h"+T+">";return r.subParser("hashBlock")(Q,h,d)}),l=l.replace(y,function(D,j){var V=r.subParser("spanGamut")(j,h,d),W=h.noHeaderId?"":' id="'+E(j)+'"',T=_+1,Q="<h"+T+W+">"+V+"</h"+T+">";return r.subParser("hashBlock")(Q,h,d)});var m=h.requireSpaceBeforeHeadingText?/^(#{1,6})[ \t]+(.+?)[ \t]*#*\n+/gm:/^(#{1,6})[ \t]*(.+?)[ \t]*#*\n+/gm;l=l.replace(m,function(D,j,V){var W=V;h.customizedHeaderId&&(W=V.replace(/\s?\{([^{]+?)}\s*$/,""));var T=r.subParser("spanGamut")(W,h,d),Q=h.noHeaderId?"":' id="'+E(V)+'"',G=_-1+j.length,ee="<h"+G+Q+">"+T+"</h"+G+">";return r.subParser("hashBlock")(ee,h,d)});function E(D){var j,V;if(h.customizedHeaderId){var W=D.match(/\{([^{]+?)}\s*$/);W&&W[1]&&(D=W[1])}return j=D,r.helper.isString(h.prefixHeaderId)?V=h.prefixHeaderId:h.prefixHeaderId===!0?V="section-":V="",h.rawPrefixHeaderId||(j=V+j),h.ghCompatibleHeaderId?j=j.replace(/ /g,"-").replace(/&amp;/g,"").replace(/¨T/g,"").replace(/¨D/g,"").replace(/[&+$,\/:;=?@"#{}|^¨~\[\]`\\*)(%.!'<>]/g,"").toLowerCase():h.rawHeaderId?j=j.replace(/ /g,"-").replace(/&amp;/g,"&").replace(/¨T/g,"¨").replace(/¨D/g,"$").replace(/["']/g,"-").toLowerCase():j=j.replace(/[^\w]/g,"").toLowerCase(),h.rawPrefixHeaderId&&(j=V+j),d.hashLinkCounts[j]?j=j+"-"+d.hashLinkCounts[j]++:d.hashLinkCounts[j]=1,j}return l=d.converter._dispatch("headers.after",l,h,d),l}),r.subParser("horizontalRule",function(l,h,d){l=d.converter._dispatch("horizontalRule.before",l,h,d);var _=r.subParser("hashBlock")("<hr />",h,d);return l=l.replace(/^ {0,2}( ?-){3,}[ \t]*$/gm,_),l=l.replace(/^ {0,2}( ?\*){3,}[ \t]*$/gm,_),l=l.replace(/^ {0,2}( ?_){3,}[ \t]*$/gm,_),l=d.converter._dispatch("horizontalRule.after",l,h,d),l}),r.subParser("images",function(l,h,d){l=d.converter._dispatch("images.before",l,h,d);var _=/!\[([^\]]*?)][ \t]*()\([ \t]?<?([\S]+?(?:\([\S]*?\)[\S]*?)?)>?(?: =([*\d]+[A-Za-z%]{0,4})x([*\d]+[A-Za-z%]{0,4}))?[ \t]*(?:(["'])([^"]*?)\6)?[ \t]?\)/g,v=/!\[([^\]]*?)][ \t]*()\([ \t]?<([^>]*)>(?: =([*\d]+[A-Za-z%]{0,4})x([*\d]+[A-Za-z%]{0,4}))?[ \t]*(?:(?:(["'])([^"]*?)\6))?[ \t]?\)/g,y=/!\[([^\]]*?)][ \t]*()\([ \t]?<?(data:.+?\/.+?;base64,[A-Za-z0-9+/=\n]+?)>?(?: =([*\d]+[A-Za-z%]{0,4})x([*\d]+[A-Za-z%]{0,4}))?[ \t]*(?:(["'])([^"]*?)\6)?[ \t]?\)/g,m=/!\[([^\]]*?)] ?(?:\n *)?\[([\s\S]*?)]()()()()()/g,E=/!\[([^\[\]]+)]()()()()()/g;function D(V,W,T,Q,G,ee,ie,le){return Q=Q.replace(/\s/g,""),j(V,W,T,Q,G,ee,ie,le)}function j(V,W,T,Q,G,ee,ie,le){var Oe=d.gUrls,Xe=d.gTitles,tt=d.gDimensions;if(T=T.toLowerCase(),le||(le=""),V.search(/\(<?\s*>? ?(['"].*['"])?\)$/m)>-1)Q="";else if(Q===""||Q===null)if((T===""||T===null)&&(T=W.toLowerCase().replace(/ ?\n/g," ")),Q="#"+T,!r.helper.isUndefined(Oe[T]))Q=Oe[T],r.helper.isUndefined(Xe[T])||(le=Xe[T]),r.helper.isUndefined(tt[T])||(G=tt[T].width,ee=tt[T].height);else return V;W=W.replace(/"/g,"&quot;").replace(r.helper.regexes.asteriskDashAndColon,r.helper.escapeCharactersCallback),Q=Q.replace(r.helper.regexes.asteriskDashAndColon,r.helper.escapeCharactersCallback);var je='<img src="'+Q+'" alt="'+W+'"';return le&&r.helper.isString(le)&&(le=le.replace(/"/g,"&quot;").replace(r.helper.regexes.asteriskDashAndColon,r.helper.escapeCharactersCallback),je+=' title="'+le+'"'),G&&ee&&(G=G==="*"?"auto":G,ee=ee==="*"?"auto":ee,je+=' width="'+G+'"',je+=' height="'+ee+'"'),je+=" />",je}return l=l.replace(m,j),l=l.replace(y,D),l=l.replace(v,j),l=l.replace(_,j),l=l.replace(E,j),l=d.converter._dispatch("images.after",l,h,d),l}),r.subParser("italicsAndBold",function(l,h,d){l=d.converter._dispatch("italicsAndBold.before",l,h,d);function _(v,y,m){return y+v+m}return h.literalMidWordUnderscores?(l=l.replace(/\b___(\S[\s\S]*?)___\b/g,function(v,y){return _(y,"<strong><em>","</em></strong>")}),l=l.replace(/\b__(\S[\s\S]*?)__\b/g,function(v,y){return _(y,"<strong>","</strong>")}),l=l.replace(/\b_(\S[\s\S]*?)_\b/g,function(v,y){return _(y,"<em>","</em>")})):(l=l.replace(/___(\S[\s\S]*?)___/g,function(v,y){return/\S$/.test(y)?_(y,"<strong><em>","</em></strong>"):v}),l=l.replace(/__(\S[\s\S]*?)__/g,function(v,y){return/\S$/.test(y)?_(y,"<strong>","</strong>"):v}),l=l.replace(/_([^\s_][\s\S]*?)_/g,function(v,y){return/\S$/.test(y)?_(y,"<em>","</em>"):v})),h.literalMidWordAsterisks?(l=l.replace(/([^*]|^)\B\*\*\*(\S[\s\S]*?)\*\*\*\B(?!\*)/g,function(v,y,m){return _(m,y+"<strong><em>","</em></strong>")}),l=l.replace(/([^*]|^)\B\*\*(\S[\s\S]*?)\*\*\B(?!\*)/g,function(v,y,m){return _(m,y+"<strong>","</strong>")}),l=l.replace(/([^*]|^)\B\*(\S[\s\S]*?)\*\B(?!\*)/g,function(v,y,m){return _(m,y+"<em>","</em>")})):(l=l.replace(/\*\*\*(\S[\s\S]*?)\*\*\*/g,function(v,y){return/\S$/.test(y)?_(y,"<strong><em>","</em></strong>"):v}),l=l.replace(/\*\*(\S[\s\S]*?)\*\*/g,function(v,y){return/\S$/.test(y)?_(y,"<strong>","</strong>"):v}),l=l.replace(/\*([^\s*][\s\S]*?)\*/g,function(v,y){return/\S$/.test(y)?_(y,"<em>","</em>"):v})),l=d.converter._dispatch("italicsAndBold.after",l,h,d),l}),r.subParser("lists",function(l,h,d){function _(m,E){d.gListLevel++,m=m.replace(/\n{2,}$/,`
-`),m+="¨0";var D=/(\n)?(^ {0,3})([*+-]|\d+[.])[ \t]+((\[(x|X| )?])?[ \t]*[^\r]+?(\n{1,2}))(?=\n*(¨0| {0,3}([*+-]|\d+[.])[ \t]+))/gm,j=/\n[ \t]*\n(?!¨0)/.test(m);return h.disableForced4SpacesIndentedSublists&&(D=/(\n)?(^ {0,3})([*+-]|\d+[.])[ \t]+((\[(x|X| )?])?[ \t]*[^\r]+?(\n{1,2}))(?=\n*(¨0|\2([*+-]|\d+[.])[ \t]+))/gm),m=m.replace(D,function(V,W,T,Q,G,ee,ie){ie=ie&&ie.trim()!=="";var le=r.subParser("outdent")(G,h,d),Oe="";return ee&&h.tasklists&&(Oe=' class="task-list-item" style="list-style-type: none;"',le=le.replace(/^[ \t]*\[(x|X| )?]/m,function(){var Xe='<input type="checkbox" disabled style="margin: 0px 0.35em 0.25em -1.6em; vertical-align: middle;"';return ie&&(Xe+=" checked"),Xe+=">",Xe})),le=le.replace(/^([-*+]|\d\.)[ \t]+[\S\n ]*/g,function(Xe){return"¨A"+Xe}),W||le.search(/\n{2,}/)>-1?(le=r.subParser("githubCodeBlocks")(le,h,d),le=r.subParser("blockGamut")(le,h,d)):(le=r.subParser("lists")(le,h,d),le=le.replace(/\n$/,""),le=r.subParser("hashHTMLBlocks")(le,h,d),le=le.replace(/\n\n+/g,`
-
-`),j?le=r.subParser("paragraphs")(le,h,d):le=r.subParser("spanGamut")(le,h,d)),le=le.replace("¨A",""),le="<li"+Oe+">"+le+`</li>
-`,le}),m=m.replace(/¨0/g,""),d.gListLevel--,E&&(m=m.replace(/\s+$/,"")),m}function v(m,E){if(E==="ol"){var D=m.match(/^ *(\d+)\./);if(D&&D[1]!=="1")return' start="'+D[1]+'"'}return""}function y(m,E,D){var j=h.disableForced4SpacesIndentedSublists?/^ ?\d+\.[ \t]/gm:/^ {0,3}\d+\.[ \t]/gm,V=h.disableForced4SpacesIndentedSublists?/^ ?[*+-][ \t]/gm:/^ {0,3}[*+-][ \t]/gm,W=E==="ul"?j:V,T="";if(m.search(W)!==-1)(function G(ee){var ie=ee.search(W),le=v(m,E);ie!==-1?(T+=`
-
-<`+E+le+`>
-`+_(ee.slice(0,ie),!!D)+"</"+E+`>
-`,E=E==="ul"?"ol":"ul",W=E==="ul"?j:V,G(ee.slice(ie))):T+=`
-
-<`+E+le+`>
-`+_(ee,!!D)+"</"+E+`>
-`})(m);else{var Q=v(m,E);T=`
-
-<`+E+Q+`>
-`+_(m,!!D)+"</"+E+`>
-`}return T}return l=d.converter._dispatch("lists.before",l,h,d),l+="¨0",d.gListLevel?l=l.replace(/^(( {0,3}([*+-]|\d+[.])[ \t]+)[^\r]+?(¨0|\n{2,}(?=\S)(?![ \t]*(?:[*+-]|\d+[.])[ \t]+)))/gm,function(m,E,D){var j=D.search(/[*+-]/g)>-1?"ul":"ol";return y(E,j,!0)}):l=l.replace(/(\n\n|^\n?)(( {0,3}([*+-]|\d+[.])[ \t]+)[^\r]+?(¨0|\n{2,}(?=\S)(?![ \t]*(?:[*+-]|\d+[.])[ \t]+)))/gm,function(m,E,D,j){var V=j.search(/[*+-]/g)>-1?"ul":"ol";return y(D,V,!1)}),l=l.replace(/¨0/,""),l=d.converter._dispatch("lists.after",l,h,d),l}),r.subParser("metadata",function(l,h,d){if(!h.metadata)return l;l=d.converter._dispatch("metadata.before",l,h,d);function _(v){d.metadata.raw=v,v=v.replace(/&/g,"&amp;").replace(/"/g,"&quot;"),v=v.replace(/\n {4}/g," "),v.replace(/^([\S ]+): +([\s\S]+?)$/gm,function(y,m,E){return d.metadata.parsed[m]=E,""})}return l=l.replace(/^\s*«««+(\S*?)\n([\s\S]+?)\n»»»+\n/,function(v,y,m){return _(m),"¨M"}),l=l.replace(/^\s*---+(\S*?)\n([\s\S]+?)\n---+\n/,function(v,y,m){return y&&(d.metadata.format=y),_(m),"¨M"}),l=l.replace(/¨M/g,""),l=d.converter._dispatch("metadata.after",l,h,d),l}),r.subParser("outdent",function(l,h,d){return l=d.converter._dispatch("outdent.before",l,h,d),l=l.replace(/^(\t|[ ]{1,4})/gm,"¨0"),l=l.replace(/¨0/g,""),l=d.converter._dispatch("outdent.after",l,h,d),l}),r.subParser("paragraphs",function(l,h,d){l=d.converter._dispatch("paragraphs.before",l,h,d),l=l.replace(/^\n+/g,""),l=l.replace(/\n+$/g,"");for(var _=l.split(/\n{2,}/g),v=[],y=_.length,m=0;m<y;m++){var E=_[m];E.search(/¨(K|G)(\d+)\1/g)>=0?v.push(E):E.search(/\S/)>=0&&(E=r.subParser("spanGamut")(E,h,d),E=E.replace(/^([ \t]*)/g,"<p>"),E+="</p>",v.push(E))}for(y=v.length,m=0;m<y;m++){for(var D="",j=v[m],V=!1;/¨(K|G)(\d+)\1/.test(j);){var W=RegExp.$1,T=RegExp.$2;W==="K"?D=d.gHtmlBlocks[T]:V?D=r.subParser("encodeCode")(d.ghCodeBlocks[T].text,h,d):D=d.ghCodeBlocks[T].codeblock,D=D.replace(/\$/g,"$$$$"),j=j.replace(/(\n\n)?¨(K|G)\d+\2(\n\n)?/,D),/^<pre\b[^>]*>\s*<code\b[^>]*>/.test(j)&&(V=!0)}v[m]=j}return l=v.join(`
-`),l=l.replace(/^\n+/g,""),l=l.replace(/\n+$/g,""),d.converter._dispatch("paragraphs.after",l,h,d)}),r.subParser("runExtension",function(l,h,d,_){if(l.filter)h=l.filter(h,_.converter,d);else if(l.regex){var v=l.regex;v instanceof RegExp||(v=new RegExp(v,"g")),h=h.replace(v,l.replace)}return h}),r.subParser("spanGamut",function(l,h,d){return l=d.converter._dispatch("spanGamut.before",l,h,d),l=r.subParser("codeSpans")(l,h,d),l=r.subParser("escapeSpecialCharsWithinTagAttributes")(l,h,d),l=r.subParser("encodeBackslashEscapes")(l,h,d),l=r.subParser("images")(l,h,d),l=r.subParser("anchors")(l,h,d),l=r.subParser("autoLinks")(l,h,d),l=r.subParser("simplifiedAutoLinks")(l,h,d),l=r.subParser("emoji")(l,h,d),l=r.subParser("underline")(l,h,d),l=r.subParser("italicsAndBold")(l,h,d),l=r.subParser("strikethrough")(l,h,d),l=r.subParser("ellipsis")(l,h,d),l=r.subParser("hashHTMLSpans")(l,h,d),l=r.subParser("encodeAmpsAndAngles")(l,h,d),h.simpleLineBreaks?/\n\n¨K/.test(l)||(l=l.replace(/\n+/g,`<br />
-`)):l=l.replace(/  +\n/g,`<br />
-`),l=d.converter._dispatch("spanGamut.after",l,h,d),l}),r.subParser("strikethrough",function(l,h,d){function _(v){return h.simplifiedAutoLink&&(v=r.subParser("simplifiedAutoLinks")(v,h,d)),"<del>"+v+"</del>"}return h.strikethrough&&(l=d.converter._dispatch("strikethrough.before",l,h,d),l=l.replace(/(?:~){2}([\s\S]+?)(?:~){2}/g,function(v,y){return _(y)}),l=d.converter._dispatch("strikethrough.after",l,h,d)),l}),r.subParser("stripLinkDefinitions",function(l,h,d){var _=/^ {0,3}\[(.+)]:[ \t]*\n?[ \t]*<?([^>\s]+)>?(?: =([*\d]+[A-Za-z%]{0,4})x([*\d]+[A-Za-z%]{0,4}))?[ \t]*\n?[ \t]*(?:(\n*)["|'(](.+?)["|')][ \t]*)?(?:\n+|(?=¨0))/gm,v=/^ {0,3}\[(.+)]:[ \t]*\n?[ \t]*<?(data:.+?\/.+?;base64,[A-Za-z0-9+/=\n]+?)>?(?: =([*\d]+[A-Za-z%]{0,4})x([*\d]+[A-Za-z%]{0,4}))?[ \t]*\n?[ \t]*(?:(\n*)["|'(](.+?)["|')][ \t]*)?(?:\n\n|(?=¨0)|(?=\n\[))/gm;l+="¨0";var y=function(m,E,D,j,V,W,T){return E=E.toLowerCase(),D.match(/^data:.+?\/.+?;base64,/)?d.gUrls[E]=D.replace(/\s/g,""):d.gUrls[E]=r.subParser("encodeAmpsAndAngles")(D,h,d),W?W+T:(T&&(d.gTitles[E]=T.replace(/"|'/g,"&quot;")),h.parseImgDimensions&&j&&V&&(d.gDimensions[E]={width:j,height:V}),"")};return l=l.replace(v,y),l=l.replace(_,y),l=l.replace(/¨0/,""),l}),r.subParser("tables",function(l,h,d){if(!h.tables)return l;var _=/^ {0,3}\|?.+\|.+\n {0,3}\|?[ \t]*:?[ \t]*(?:[-=]){2,}[ \t]*:?[ \t]*\|[ \t]*:?[ \t]*(?:[-=]){2,}[\s\S]+?(?:\n\n|¨0)/gm,v=/^ {0,3}\|.+\|[ \t]*\n {0,3}\|[ \t]*:?[ \t]*(?:[-=]){2,}[ \t]*:?[ \t]*\|[ \t]*\n( {0,3}\|.+\|[ \t]*\n)*(?:\n|¨0)/gm;function y(V){return/^:[ \t]*--*$/.test(V)?' style="text-align:left;"':/^--*[ \t]*:[ \t]*$/.test(V)?' style="text-align:right;"':/^:[ \t]*--*[ \t]*:$/.test(V)?' style="text-align:center;"':""}function m(V,W){var T="";return V=V.trim(),(h.tablesHeaderId||h.tableHeaderId)&&(T=' id="'+V.replace(/ /g,"_").toLowerCase()+'"'),V=r.subParser("spanGamut")(V,h,d),"<th"+T+W+">"+V+`</th>
-`}function E(V,W){var T=r.subParser("spanGamut")(V,h,d);return"<td"+W+">"+T+`</td>
-`}function D(V,W){for(var T=`<table>
-<thead>
-<tr>
-`,Q=V.length,G=0;G<Q;++G)T+=V[G];for(T+=`</tr>
-</thead>
-<tbody>
-`,G=0;G<W.length;++G){T+=`<tr>
-`;for(var ee=0;ee<Q;++ee)T+=W[G][ee];T+=`</tr>
-`}return T+=`</tbody>
-</table>
-`,T}function j(V){var W,T=V.split(`
-`);for(W=0;W<T.length;++W)/^ {0,3}\|/.test(T[W])&&(T[W]=T[W].replace(/^ {0,3}\|/,"")),/\|[ \t]*$/.test(T[W])&&(T[W]=T[W].replace(/\|[ \t]*$/,"")),T[W]=r.subParser("codeSpans")(T[W],h,d);var Q=T[0].split("|").map(function(je){return je.trim()}),G=T[1].split("|").map(function(je){return je.trim()}),ee=[],ie=[],le=[],Oe=[];for(T.shift(),T.shift(),W=0;W<T.length;++W)T[W].trim()!==""&&ee.push(T[W].split("|").map(function(je){return je.trim()}));if(Q.length<G.length)return V;for(W=0;W<G.length;++W)le.push(y(G[W]));for(W=0;W<Q.length;++W)r.helper.isUndefined(le[W])&&(le[W]=""),ie.push(m(Q[W],le[W]));for(W=0;W<ee.length;++W){for(var Xe=[],tt=0;tt<ie.length;++tt)r.helper.isUndefined(ee[W][tt]),Xe.push(E(ee[W][tt],le[tt]));Oe.push(Xe)}return D(ie,Oe)}return l=d.converter._dispatch("tables.before",l,h,d),l=l.replace(/\\(\|)/g,r.helper.escapeCharactersCallback),l=l.replace(_,j),l=l.replace(v,j),l=d.converter._dispatch("tables.after",l,h,d),l}),r.subParser("underline",function(l,h,d){return h.underline&&(l=d.converter._dispatch("underline.before",l,h,d),h.literalMidWordUnderscores?(l=l.replace(/\b___(\S[\s\S]*?)___\b/g,function(_,v){return"<u>"+v+"</u>"}),l=l.replace(/\b__(\S[\s\S]*?)__\b/g,function(_,v){return"<u>"+v+"</u>"})):(l=l.replace(/___(\S[\s\S]*?)___/g,function(_,v){return/\S$/.test(v)?"<u>"+v+"</u>":_}),l=l.replace(/__(\S[\s\S]*?)__/g,function(_,v){return/\S$/.test(v)?"<u>"+v+"</u>":_})),l=l.replace(/(_)/g,r.helper.escapeCharactersCallback),l=d.converter._dispatch("underline.after",l,h,d)),l}),r.subParser("unescapeSpecialChars",function(l,h,d){return l=d.converter._dispatch("unescapeSpecialChars.before",l,h,d),l=l.replace(/¨E(\d+)E/g,function(_,v){var y=parseInt(v);return String.fromCharCode(y)}),l=d.converter._dispatch("unescapeSpecialChars.after",l,h,d),l}),r.subParser("makeMarkdown.blockquote",function(l,h){var d="";if(l.hasChildNodes())for(var _=l.childNodes,v=_.length,y=0;y<v;++y){var m=r.subParser("makeMarkdown.node")(_[y],h);m!==""&&(d+=m)}return d=d.trim(),d="> "+d.split(`
-`).join(`
-> `),d}),r.subParser("makeMarkdown.codeBlock",function(l,h){var d=l.getAttribute("language"),_=l.getAttribute("precodenum");return"```"+d+`
-`+h.preList[_]+"\n```"}),r.subParser("makeMarkdown.codeSpan",function(l){return"`"+l.innerHTML+"`"}),r.subParser("makeMarkdown.emphasis",function(l,h){var d="";if(l.hasChildNodes()){d+="*";for(var _=l.childNodes,v=_.length,y=0;y<v;++y)d+=r.subParser("makeMarkdown.node")(_[y],h);d+="*"}return d}),r.subParser("makeMarkdown.header",function(l,h,d){var _=new Array(d+1).join("#"),v="";if(l.hasChildNodes()){v=_+" ";for(var y=l.childNodes,m=y.length,E=0;E<m;++E)v+=r.subParser("makeMarkdown.node")(y[E],h)}return v}),r.subParser("makeMarkdown.hr",function(){return"---"}),r.subParser("makeMarkdown.image",function(l){var h="";return l.hasAttribute("src")&&(h+="!["+l.getAttribute("alt")+"](",h+="<"+l.getAttribute("src")+">",l.hasAttribute("width")&&l.hasAttribute("height")&&(h+=" ="+l.getAttribute("width")+"x"+l.getAttribute("height")),l.hasAttribute("title")&&(h+=' "'+l.getAttribute("title")+'"'),h+=")"),h}),r.subParser("makeMarkdown.links",function(l,h){var d="";if(l.hasChildNodes()&&l.hasAttribute("href")){var _=l.childNodes,v=_.length;d="[";for(var y=0;y<v;++y)d+=r.subParser("makeMarkdown.node")(_[y],h);d+="](",d+="<"+l.getAttribute("href")+">",l.hasAttribute("title")&&(d+=' "'+l.getAttribute("title")+'"'),d+=")"}return d}),r.subParser("makeMarkdown.list",function(l,h,d){var _="";if(!l.hasChildNodes())return"";for(var v=l.childNodes,y=v.length,m=l.getAttribute("start")||1,E=0;E<y;++E)if(!(typeof v[E].tagName>"u"||v[E].tagName.toLowerCase()!=="li")){var D="";d==="ol"?D=m.toString()+". ":D="- ",_+=D+r.subParser("makeMarkdown.listItem")(v[E],h),++m}return _+=`
-<!-- -->
-`,_.trim()}),r.subParser("makeMarkdown.listItem",function(l,h){for(var d="",_=l.childNodes,v=_.length,y=0;y<v;++y)d+=r.subParser("makeMarkdown.node")(_[y],h);return/\n$/.test(d)?d=d.split(`
-`).join(`
-    `).replace(/^ {4}$/gm,"").replace(/\n\n+/g,`
-
-`):d+=`
-`,d}),r.subParser("makeMarkdown.node",function(l,h,d){d=d||!1;var _="";if(l.nodeType===3)return r.subParser("makeMarkdown.txt")(l,h);if(l.nodeType===8)return"<!--"+l.data+`-->
-
-`;if(l.nodeType!==1)return"";var v=l.tagName.toLowerCase();switch(v){case"h1":d||(_=r.subParser("makeMarkdown.header")(l,h,1)+`
-
-`);break;case"h2":d||(_=r.subParser("makeMarkdown.header")(l,h,2)+`
-
-`);break;case"h3":d||(_=r.subParser("makeMarkdown.header")(l,h,3)+`
-
-`);break;case"h4":d||(_=r.subParser("makeMarkdown.header")(l,h,4)+`
-
-`);break;case"h5":d||(_=r.subParser("makeMarkdown.header")(l,h,5)+`
-
-`);break;case"h6":d||(_=r.subParser("makeMarkdown.header")(l,h,6)+`
-
-`);break;case"p":d||(_=r.subParser("makeMarkdown.paragraph")(l,h)+`
-
-`);break;case"blockquote":d||(_=r.subParser("makeMarkdown.blockquote")(l,h)+`
-
-`);break;case"hr":d||(_=r.subParser("makeMarkdown.hr")(l,h)+`
-
-`);break;case"ol":d||(_=r.subParser("makeMarkdown.list")(l,h,"ol")+`
-
-`);break;case"ul":d||(_=r.subParser("makeMarkdown.list")(l,h,"ul")+`
-
-`);break;case"precode":d||(_=r.subParser("makeMarkdown.codeBlock")(l,h)+`
-
-`);break;case"pre":d||(_=r.subParser("makeMarkdown.pre")(l,h)+`
-
-`);break;case"table":d||(_=r.subParser("makeMarkdown.table")(l,h)+`
-
-`);break;case"code":_=r.subParser("makeMarkdown.codeSpan")(l,h);break;case"em":case"i":_=r.subParser("makeMarkdown.emphasis")(l,h);break;case"strong":case"b":_=r.subParser("makeMarkdown.strong")(l,h);break;case"del":_=r.subParser("makeMarkdown.strikethrough")(l,h);break;case"a":_=r.subParser("makeMarkdown.links")(l,h);break;case"img":_=r.subParser("makeMarkdown.image")(l,h);break;default:_=l.outerHTML+`
-
-`}return _}),r.subParser("makeMarkdown.paragraph",function(l,h){var d="";if(l.hasChildNodes())for(var _=l.childNodes,v=_.length,y=0;y<v;++y)d+=r.subParser("makeMarkdown.node")(_[y],h);return d=d.trim(),d}),r.subParser("makeMarkdown.pre",function(l,h){var d=l.getAttribute("prenum");return"<pre>"+h.preList[d]+"</pre>"}),r.subParser("makeMarkdown.strikethrough",function(l,h){var d="";if(l.hasChildNodes()){d+="~~";for(var _=l.childNodes,v=_.length,y=0;y<v;++y)d+=r.subParser("makeMarkdown.node")(_[y],h);d+="~~"}return d}),r.subParser("makeMarkdown.strong",function(l,h){var d="";if(l.hasChildNodes()){d+="**";for(var _=l.childNodes,v=_.length,y=0;y<v;++y)d+=r.subParser("makeMarkdown.node")(_[y],h);d+="**"}return d}),r.subParser("makeMarkdown.table",function(l,h){var d="",_=[[],[]],v=l.querySelectorAll("thead>tr>th"),y=l.querySelectorAll("tbody>tr"),m,E;for(m=0;m<v.length;++m){var D=r.subParser("makeMarkdown.tableCell")(v[m],h),j="---";if(v[m].hasAttribute("style")){var V=v[m].getAttribute("style").toLowerCase().replace(/\s/g,"");switch(V){case"text-align:left;":j=":---";break;case"text-align:right;":j="---:";break;case"text-align:center;":j=":---:";break}}_[0][m]=D.trim(),_[1][m]=j}for(m=0;m<y.length;++m){var W=_.push([])-1,T=y[m].getElementsByTagName("td");for(E=0;E<v.length;++E){var Q=" ";typeof T[E]<"u"&&(Q=r.subParser("makeMarkdown.tableCell")(T[E],h)),_[W].push(Q)}}var G=3;for(m=0;m<_.length;++m)for(E=0;E<_[m].length;++E){var ee=_[m][E].length;ee>G&&(G=ee)}for(m=0;m<_.length;++m){for(E=0;E<_[m].length;++E)m===1?_[m][E].slice(-1)===":"?_[m][E]=r.helper.padEnd(_[m][E].slice(-1),G-1,"-")+":":_[m][E]=r.helper.padEnd(_[m][E],G,"-"):_[m][E]=r.helper.padEnd(_[m][E],G);d+="| "+_[m].join(" | ")+` |
-`}return d.trim()}),r.subParser("makeMarkdown.tableCell",function(l,h){var d="";if(!l.hasChildNodes())return"";for(var _=l.childNodes,v=_.length,y=0;y<v;++y)d+=r.subParser("makeMarkdown.node")(_[y],h,!0);return d.trim()}),r.subParser("makeMarkdown.txt",function(l){var h=l.nodeValue;return h=h.replace(/ +/g," "),h=h.replace(/¨NBSP;/g," "),h=r.helper.unescapeHTMLEntities(h),h=h.replace(/([*_~|`])/g,"\\$1"),h=h.replace(/^(\s*)>/g,"\\$1>"),h=h.replace(/^#/gm,"\\#"),h=h.replace(/^(\s*)([-=]{3,})(\s*)$/,"$1\\$2$3"),h=h.replace(/^( {0,3}\d+)\./gm,"$1\\."),h=h.replace(/^( {0,3})([+-])/gm,"$1\\$2"),h=h.replace(/]([\s]*)\(/g,"\\]$1\\("),h=h.replace(/^ {0,3}\[([\S \t]*?)]:/gm,"\\[$1]:"),h});var x=this;e.exports?e.exports=r:x.showdown=r}).call(we)})(gm);var P8=gm.exports,mm={},$s={},Ta={},xa={};const D8={0:65533,128:8364,130:8218,131:402,132:8222,133:8230,134:8224,135:8225,136:710,137:8240,138:352,139:8249,140:338,142:381,145:8216,146:8217,147:8220,148:8221,149:8226,150:8211,151:8212,152:732,153:8482,154:353,155:8250,156:339,158:382,159:376};var N8=we&&we.__importDefault||function(e){return e&&e.__esModule?e:{default:e}};Object.defineProperty(xa,"__esModule",{value:!0});var vm=N8(D8),R8=String.fromCodePoint||function(e){var t="";return e>65535&&(e-=65536,t+=String.fromCharCode(e>>>10&1023|55296),e=56320|e&1023),t+=String.fromCharCode(e),t};function q8(e){return e>=55296&&e<=57343||e>1114111?"�":(e in vm.default&&(e=vm.default[e]),R8(e))}xa.default=q8;const Td={Aacute:"Á",aacute:"á",Abreve:"Ă",abreve:"ă",ac:"∾",acd:"∿",acE:"∾̳",Acirc:"Â",acirc:"â",acute:"´",Acy:"А",acy:"а",AElig:"Æ",aelig:"æ",af:"⁡",Afr:"𝔄",afr:"𝔞",Agrave:"À",agrave:"à",alefsym:"ℵ",aleph:"ℵ",Alpha:"Α",alpha:"α",Amacr:"Ā",amacr:"ā",amalg:"⨿",amp:"&",AMP:"&",andand:"⩕",And:"⩓",and:"∧",andd:"⩜",andslope:"⩘",andv:"⩚",ang:"∠",ange:"⦤",angle:"∠",angmsdaa:"⦨",angmsdab:"⦩",angmsdac:"⦪",angmsdad:"⦫",angmsdae:"⦬",angmsdaf:"⦭",angmsdag:"⦮",angmsdah:"⦯",angmsd:"∡",angrt:"∟",angrtvb:"⊾",angrtvbd:"⦝",angsph:"∢",angst:"Å",angzarr:"⍼",Aogon:"Ą",aogon:"ą",Aopf:"𝔸",aopf:"𝕒",apacir:"⩯",ap:"≈",apE:"⩰",ape:"≊",apid:"≋",apos:"'",ApplyFunction:"⁡",approx:"≈",approxeq:"≊",Aring:"Å",aring:"å",Ascr:"𝒜",ascr:"𝒶",Assign:"≔",ast:"*",asymp:"≈",asympeq:"≍",Atilde:"Ã",atilde:"ã",Auml:"Ä",auml:"ä",awconint:"∳",awint:"⨑",backcong:"≌",backepsilon:"϶",backprime:"‵",backsim:"∽",backsimeq:"⋍",Backslash:"∖",Barv:"⫧",barvee:"⊽",barwed:"⌅",Barwed:"⌆",barwedge:"⌅",bbrk:"⎵",bbrktbrk:"⎶",bcong:"≌",Bcy:"Б",bcy:"б",bdquo:"„",becaus:"∵",because:"∵",Because:"∵",bemptyv:"⦰",bepsi:"϶",bernou:"ℬ",Bernoullis:"ℬ",Beta:"Β",beta:"β",beth:"ℶ",between:"≬",Bfr:"𝔅",bfr:"𝔟",bigcap:"⋂",bigcirc:"◯",bigcup:"⋃",bigodot:"⨀",bigoplus:"⨁",bigotimes:"⨂",bigsqcup:"⨆",bigstar:"★",bigtriangledown:"▽",bigtriangleup:"△",biguplus:"⨄",bigvee:"⋁",bigwedge:"⋀",bkarow:"⤍",blacklozenge:"⧫",blacksquare:"▪",blacktriangle:"▴",blacktriangledown:"▾",blacktriangleleft:"◂",blacktriangleright:"▸",blank:"␣",blk12:"▒",blk14:"░",blk34:"▓",block:"█",bne:"=⃥",bnequiv:"≡⃥",bNot:"⫭",bnot:"⌐",Bopf:"𝔹",bopf:"𝕓",bot:"⊥",bottom:"⊥",bowtie:"⋈",boxbox:"⧉",boxdl:"┐",boxdL:"╕",boxDl:"╖",boxDL:"╗",boxdr:"┌",boxdR:"╒",boxDr:"╓",boxDR:"╔",boxh:"─",boxH:"═",boxhd:"┬",boxHd:"╤",boxhD:"╥",boxHD:"╦",boxhu:"┴",boxHu:"╧",boxhU:"╨",boxHU:"╩",boxminus:"⊟",boxplus:"⊞",boxtimes:"⊠",boxul:"┘",boxuL:"╛",boxUl:"╜",boxUL:"╝",boxur:"└",boxuR:"╘",boxUr:"╙",boxUR:"╚",boxv:"│",boxV:"║",boxvh:"┼",boxvH:"╪",boxVh:"╫",boxVH:"╬",boxvl:"┤",boxvL:"╡",boxVl:"╢",boxVL:"╣",boxvr:"├",boxvR:"╞",boxVr:"╟",boxVR:"╠",bprime:"‵",breve:"˘",Breve:"˘",brvbar:"¦",bscr:"𝒷",Bscr:"ℬ",bsemi:"⁏",bsim:"∽",bsime:"⋍",bsolb:"⧅",bsol:"\\",bsolhsub:"⟈",bull:"•",bullet:"•",bump:"≎",bumpE:"⪮",bumpe:"≏",Bumpeq:"≎",bumpeq:"≏",Cacute:"Ć",cacute:"ć",capand:"⩄",capbrcup:"⩉",capcap:"⩋",cap:"∩",Cap:"⋒",capcup:"⩇",capdot:"⩀",CapitalDifferentialD:"ⅅ",caps:"∩︀",caret:"⁁",caron:"ˇ",Cayleys:"ℭ",ccaps:"⩍",Ccaron:"Č",ccaron:"č",Ccedil:"Ç",ccedil:"ç",Ccirc:"Ĉ",ccirc:"ĉ",Cconint:"∰",ccups:"⩌",ccupssm:"⩐",Cdot:"Ċ",cdot:"ċ",cedil:"¸",Cedilla:"¸",cemptyv:"⦲",cent:"¢",centerdot:"·",CenterDot:"·",cfr:"𝔠",Cfr:"ℭ",CHcy:"Ч",chcy:"ч",check:"✓",checkmark:"✓",Chi:"Χ",chi:"χ",circ:"ˆ",circeq:"≗",circlearrowleft:"↺",circlearrowright:"↻",circledast:"⊛",circledcirc:"⊚",circleddash:"⊝",CircleDot:"⊙",circledR:"®",circledS:"Ⓢ",CircleMinus:"⊖",CirclePlus:"⊕",CircleTimes:"⊗",cir:"○",cirE:"⧃",cire:"≗",cirfnint:"⨐",cirmid:"⫯",cirscir:"⧂",ClockwiseContourIntegral:"∲",CloseCurlyDoubleQuote:"”",CloseCurlyQuote:"’",clubs:"♣",clubsuit:"♣",colon:":",Colon:"∷",Colone:"⩴",colone:"≔",coloneq:"≔",comma:",",commat:"@",comp:"∁",compfn:"∘",complement:"∁",complexes:"ℂ",cong:"≅",congdot:"⩭",Congruent:"≡",conint:"∮",Conint:"∯",ContourIntegral:"∮",copf:"𝕔",Copf:"ℂ",coprod:"∐",Coproduct:"∐",copy:"©",COPY:"©",copysr:"℗",CounterClockwiseContourIntegral:"∳",crarr:"↵",cross:"✗",Cross:"⨯",Cscr:"𝒞",cscr:"𝒸",csub:"⫏",csube:"⫑",csup:"⫐",csupe:"⫒",ctdot:"⋯",cudarrl:"⤸",cudarrr:"⤵",cuepr:"⋞",cuesc:"⋟",cularr:"↶",cularrp:"⤽",cupbrcap:"⩈",cupcap:"⩆",CupCap:"≍",cup:"∪",Cup:"⋓",cupcup:"⩊",cupdot:"⊍",cupor:"⩅",cups:"∪︀",curarr:"↷",curarrm:"⤼",curlyeqprec:"⋞",curlyeqsucc:"⋟",curlyvee:"⋎",curlywedge:"⋏",curren:"¤",curvearrowleft:"↶",curvearrowright:"↷",cuvee:"⋎",cuwed:"⋏",cwconint:"∲",cwint:"∱",cylcty:"⌭",dagger:"†",Dagger:"‡",daleth:"ℸ",darr:"↓",Darr:"↡",dArr:"⇓",dash:"‐",Dashv:"⫤",dashv:"⊣",dbkarow:"⤏",dblac:"˝",Dcaron:"Ď",dcaron:"ď",Dcy:"Д",dcy:"д",ddagger:"‡",ddarr:"⇊",DD:"ⅅ",dd:"ⅆ",DDotrahd:"⤑",ddotseq:"⩷",deg:"°",Del:"∇",Delta:"Δ",delta:"δ",demptyv:"⦱",dfisht:"⥿",Dfr:"𝔇",dfr:"𝔡",dHar:"⥥",dharl:"⇃",dharr:"⇂",DiacriticalAcute:"´",DiacriticalDot:"˙",DiacriticalDoubleAcute:"˝",DiacriticalGrave:"`",DiacriticalTilde:"˜",diam:"⋄",diamond:"⋄",Diamond:"⋄",diamondsuit:"♦",diams:"♦",die:"¨",DifferentialD:"ⅆ",digamma:"ϝ",disin:"⋲",div:"÷",divide:"÷",divideontimes:"⋇",divonx:"⋇",DJcy:"Ђ",djcy:"ђ",dlcorn:"⌞",dlcrop:"⌍",dollar:"$",Dopf:"𝔻",dopf:"𝕕",Dot:"¨",dot:"˙",DotDot:"⃜",doteq:"≐",doteqdot:"≑",DotEqual:"≐",dotminus:"∸",dotplus:"∔",dotsquare:"⊡",doublebarwedge:"⌆",DoubleContourIntegral:"∯",DoubleDot:"¨",DoubleDownArrow:"⇓",DoubleLeftArrow:"⇐",DoubleLeftRightArrow:"⇔",DoubleLeftTee:"⫤",DoubleLongLeftArrow:"⟸",DoubleLongLeftRightArrow:"⟺",DoubleLongRightArrow:"⟹",DoubleRightArrow:"⇒",DoubleRightTee:"⊨",DoubleUpArrow:"⇑",DoubleUpDownArrow:"⇕",DoubleVerticalBar:"∥",DownArrowBar:"⤓",downarrow:"↓",DownArrow:"↓",Downarrow:"⇓",DownArrowUpArrow:"⇵",DownBreve:"̑",downdownarrows:"⇊",downharpoonleft:"⇃",downharpoonright:"⇂",DownLeftRightVector:"⥐",DownLeftTeeVector:"⥞",DownLeftVectorBar:"⥖",DownLeftVector:"↽",DownRightTeeVector:"⥟",DownRightVectorBar:"⥗",DownRightVector:"⇁",DownTeeArrow:"↧",DownTee:"⊤",drbkarow:"⤐",drcorn:"⌟",drcrop:"⌌",Dscr:"𝒟",dscr:"𝒹",DScy:"Ѕ",dscy:"ѕ",dsol:"⧶",Dstrok:"Đ",dstrok:"đ",dtdot:"⋱",dtri:"▿",dtrif:"▾",duarr:"⇵",duhar:"⥯",dwangle:"⦦",DZcy:"Џ",dzcy:"џ",dzigrarr:"⟿",Eacute:"É",eacute:"é",easter:"⩮",Ecaron:"Ě",ecaron:"ě",Ecirc:"Ê",ecirc:"ê",ecir:"≖",ecolon:"≕",Ecy:"Э",ecy:"э",eDDot:"⩷",Edot:"Ė",edot:"ė",eDot:"≑",ee:"ⅇ",efDot:"≒",Efr:"𝔈",efr:"𝔢",eg:"⪚",Egrave:"È",egrave:"è",egs:"⪖",egsdot:"⪘",el:"⪙",Element:"∈",elinters:"⏧",ell:"ℓ",els:"⪕",elsdot:"⪗",Emacr:"Ē",emacr:"ē",empty:"∅",emptyset:"∅",EmptySmallSquare:"◻",emptyv:"∅",EmptyVerySmallSquare:"▫",emsp13:" ",emsp14:" ",emsp:" ",ENG:"Ŋ",eng:"ŋ",ensp:" ",Eogon:"Ę",eogon:"ę",Eopf:"𝔼",eopf:"𝕖",epar:"⋕",eparsl:"⧣",eplus:"⩱",epsi:"ε",Epsilon:"Ε",epsilon:"ε",epsiv:"ϵ",eqcirc:"≖",eqcolon:"≕",eqsim:"≂",eqslantgtr:"⪖",eqslantless:"⪕",Equal:"⩵",equals:"=",EqualTilde:"≂",equest:"≟",Equilibrium:"⇌",equiv:"≡",equivDD:"⩸",eqvparsl:"⧥",erarr:"⥱",erDot:"≓",escr:"ℯ",Escr:"ℰ",esdot:"≐",Esim:"⩳",esim:"≂",Eta:"Η",eta:"η",ETH:"Ð",eth:"ð",Euml:"Ë",euml:"ë",euro:"€",excl:"!",exist:"∃",Exists:"∃",expectation:"ℰ",exponentiale:"ⅇ",ExponentialE:"ⅇ",fallingdotseq:"≒",Fcy:"Ф",fcy:"ф",female:"♀",ffilig:"ﬃ",fflig:"ﬀ",ffllig:"ﬄ",Ffr:"𝔉",ffr:"𝔣",filig:"ﬁ",FilledSmallSquare:"◼",FilledVerySmallSquare:"▪",fjlig:"fj",flat:"♭",fllig:"ﬂ",fltns:"▱",fnof:"ƒ",Fopf:"𝔽",fopf:"𝕗",forall:"∀",ForAll:"∀",fork:"⋔",forkv:"⫙",Fouriertrf:"ℱ",fpartint:"⨍",frac12:"½",frac13:"⅓",frac14:"¼",frac15:"⅕",frac16:"⅙",frac18:"⅛",frac23:"⅔",frac25:"⅖",frac34:"¾",frac35:"⅗",frac38:"⅜",frac45:"⅘",frac56:"⅚",frac58:"⅝",frac78:"⅞",frasl:"⁄",frown:"⌢",fscr:"𝒻",Fscr:"ℱ",gacute:"ǵ",Gamma:"Γ",gamma:"γ",Gammad:"Ϝ",gammad:"ϝ",gap:"⪆",Gbreve:"Ğ",gbreve:"ğ",Gcedil:"Ģ",Gcirc:"Ĝ",gcirc:"ĝ",Gcy:"Г",gcy:"г",Gdot:"Ġ",gdot:"ġ",ge:"≥",gE:"≧",gEl:"⪌",gel:"⋛",geq:"≥",geqq:"≧",geqslant:"⩾",gescc:"⪩",ges:"⩾",gesdot:"⪀",gesdoto:"⪂",gesdotol:"⪄",gesl:"⋛︀",gesles:"⪔",Gfr:"𝔊",gfr:"𝔤",gg:"≫",Gg:"⋙",ggg:"⋙",gimel:"ℷ",GJcy:"Ѓ",gjcy:"ѓ",gla:"⪥",gl:"≷",glE:"⪒",glj:"⪤",gnap:"⪊",gnapprox:"⪊",gne:"⪈",gnE:"≩",gneq:"⪈",gneqq:"≩",gnsim:"⋧",Gopf:"𝔾",gopf:"𝕘",grave:"`",GreaterEqual:"≥",GreaterEqualLess:"⋛",GreaterFullEqual:"≧",GreaterGreater:"⪢",GreaterLess:"≷",GreaterSlantEqual:"⩾",GreaterTilde:"≳",Gscr:"𝒢",gscr:"ℊ",gsim:"≳",gsime:"⪎",gsiml:"⪐",gtcc:"⪧",gtcir:"⩺",gt:">",GT:">",Gt:"≫",gtdot:"⋗",gtlPar:"⦕",gtquest:"⩼",gtrapprox:"⪆",gtrarr:"⥸",gtrdot:"⋗",gtreqless:"⋛",gtreqqless:"⪌",gtrless:"≷",gtrsim:"≳",gvertneqq:"≩︀",gvnE:"≩︀",Hacek:"ˇ",hairsp:" ",half:"½",hamilt:"ℋ",HARDcy:"Ъ",hardcy:"ъ",harrcir:"⥈",harr:"↔",hArr:"⇔",harrw:"↭",Hat:"^",hbar:"ℏ",Hcirc:"Ĥ",hcirc:"ĥ",hearts:"♥",heartsuit:"♥",hellip:"…",hercon:"⊹",hfr:"𝔥",Hfr:"ℌ",HilbertSpace:"ℋ",hksearow:"⤥",hkswarow:"⤦",hoarr:"⇿",homtht:"∻",hookleftarrow:"↩",hookrightarrow:"↪",hopf:"𝕙",Hopf:"ℍ",horbar:"―",HorizontalLine:"─",hscr:"𝒽",Hscr:"ℋ",hslash:"ℏ",Hstrok:"Ħ",hstrok:"ħ",HumpDownHump:"≎",HumpEqual:"≏",hybull:"⁃",hyphen:"‐",Iacute:"Í",iacute:"í",ic:"⁣",Icirc:"Î",icirc:"î",Icy:"И",icy:"и",Idot:"İ",IEcy:"Е",iecy:"е",iexcl:"¡",iff:"⇔",ifr:"𝔦",Ifr:"ℑ",Igrave:"Ì",igrave:"ì",ii:"ⅈ",iiiint:"⨌",iiint:"∭",iinfin:"⧜",iiota:"℩",IJlig:"Ĳ",ijlig:"ĳ",Imacr:"Ī",imacr:"ī",image:"ℑ",ImaginaryI:"ⅈ",imagline:"ℐ",imagpart:"ℑ",imath:"ı",Im:"ℑ",imof:"⊷",imped:"Ƶ",Implies:"⇒",incare:"℅",in:"∈",infin:"∞",infintie:"⧝",inodot:"ı",intcal:"⊺",int:"∫",Int:"∬",integers:"ℤ",Integral:"∫",intercal:"⊺",Intersection:"⋂",intlarhk:"⨗",intprod:"⨼",InvisibleComma:"⁣",InvisibleTimes:"⁢",IOcy:"Ё",iocy:"ё",Iogon:"Į",iogon:"į",Iopf:"𝕀",iopf:"𝕚",Iota:"Ι",iota:"ι",iprod:"⨼",iquest:"¿",iscr:"𝒾",Iscr:"ℐ",isin:"∈",isindot:"⋵",isinE:"⋹",isins:"⋴",isinsv:"⋳",isinv:"∈",it:"⁢",Itilde:"Ĩ",itilde:"ĩ",Iukcy:"І",iukcy:"і",Iuml:"Ï",iuml:"ï",Jcirc:"Ĵ",jcirc:"ĵ",Jcy:"Й",jcy:"й",Jfr:"𝔍",jfr:"𝔧",jmath:"ȷ",Jopf:"𝕁",jopf:"𝕛",Jscr:"𝒥",jscr:"𝒿",Jsercy:"Ј",jsercy:"ј",Jukcy:"Є",jukcy:"є",Kappa:"Κ",kappa:"κ",kappav:"ϰ",Kcedil:"Ķ",kcedil:"ķ",Kcy:"К",kcy:"к",Kfr:"𝔎",kfr:"𝔨",kgreen:"ĸ",KHcy:"Х",khcy:"х",KJcy:"Ќ",kjcy:"ќ",Kopf:"𝕂",kopf:"𝕜",Kscr:"𝒦",kscr:"𝓀",lAarr:"⇚",Lacute:"Ĺ",lacute:"ĺ",laemptyv:"⦴",lagran:"ℒ",Lambda:"Λ",lambda:"λ",lang:"⟨",Lang:"⟪",langd:"⦑",langle:"⟨",lap:"⪅",Laplacetrf:"ℒ",laquo:"«",larrb:"⇤",larrbfs:"⤟",larr:"←",Larr:"↞",lArr:"⇐",larrfs:"⤝",larrhk:"↩",larrlp:"↫",larrpl:"⤹",larrsim:"⥳",larrtl:"↢",latail:"⤙",lAtail:"⤛",lat:"⪫",late:"⪭",lates:"⪭︀",lbarr:"⤌",lBarr:"⤎",lbbrk:"❲",lbrace:"{",lbrack:"[",lbrke:"⦋",lbrksld:"⦏",lbrkslu:"⦍",Lcaron:"Ľ",lcaron:"ľ",Lcedil:"Ļ",lcedil:"ļ",lceil:"⌈",lcub:"{",Lcy:"Л",lcy:"л",ldca:"⤶",ldquo:"“",ldquor:"„",ldrdhar:"⥧",ldrushar:"⥋",ldsh:"↲",le:"≤",lE:"≦",LeftAngleBracket:"⟨",LeftArrowBar:"⇤",leftarrow:"←",LeftArrow:"←",Leftarrow:"⇐",LeftArrowRightArrow:"⇆",leftarrowtail:"↢",LeftCeiling:"⌈",LeftDoubleBracket:"⟦",LeftDownTeeVector:"⥡",LeftDownVectorBar:"⥙",LeftDownVector:"⇃",LeftFloor:"⌊",leftharpoondown:"↽",leftharpoonup:"↼",leftleftarrows:"⇇",leftrightarrow:"↔",LeftRightArrow:"↔",Leftrightarrow:"⇔",leftrightarrows:"⇆",leftrightharpoons:"⇋",leftrightsquigarrow:"↭",LeftRightVector:"⥎",LeftTeeArrow:"↤",LeftTee:"⊣",LeftTeeVector:"⥚",leftthreetimes:"⋋",LeftTriangleBar:"⧏",LeftTriangle:"⊲",LeftTriangleEqual:"⊴",LeftUpDownVector:"⥑",LeftUpTeeVector:"⥠",LeftUpVectorBar:"⥘",LeftUpVector:"↿",LeftVectorBar:"⥒",LeftVector:"↼",lEg:"⪋",leg:"⋚",leq:"≤",leqq:"≦",leqslant:"⩽",lescc:"⪨",les:"⩽",lesdot:"⩿",lesdoto:"⪁",lesdotor:"⪃",lesg:"⋚︀",lesges:"⪓",lessapprox:"⪅",lessdot:"⋖",lesseqgtr:"⋚",lesseqqgtr:"⪋",LessEqualGreater:"⋚",LessFullEqual:"≦",LessGreater:"≶",lessgtr:"≶",LessLess:"⪡",lesssim:"≲",LessSlantEqual:"⩽",LessTilde:"≲",lfisht:"⥼",lfloor:"⌊",Lfr:"𝔏",lfr:"𝔩",lg:"≶",lgE:"⪑",lHar:"⥢",lhard:"↽",lharu:"↼",lharul:"⥪",lhblk:"▄",LJcy:"Љ",ljcy:"љ",llarr:"⇇",ll:"≪",Ll:"⋘",llcorner:"⌞",Lleftarrow:"⇚",llhard:"⥫",lltri:"◺",Lmidot:"Ŀ",lmidot:"ŀ",lmoustache:"⎰",lmoust:"⎰",lnap:"⪉",lnapprox:"⪉",lne:"⪇",lnE:"≨",lneq:"⪇",lneqq:"≨",lnsim:"⋦",loang:"⟬",loarr:"⇽",lobrk:"⟦",longleftarrow:"⟵",LongLeftArrow:"⟵",Longleftarrow:"⟸",longleftrightarrow:"⟷",LongLeftRightArrow:"⟷",Longleftrightarrow:"⟺",longmapsto:"⟼",longrightarrow:"⟶",LongRightArrow:"⟶",Longrightarrow:"⟹",looparrowleft:"↫",looparrowright:"↬",lopar:"⦅",Lopf:"𝕃",lopf:"𝕝",loplus:"⨭",lotimes:"⨴",lowast:"∗",lowbar:"_",LowerLeftArrow:"↙",LowerRightArrow:"↘",loz:"◊",lozenge:"◊",lozf:"⧫",lpar:"(",lparlt:"⦓",lrarr:"⇆",lrcorner:"⌟",lrhar:"⇋",lrhard:"⥭",lrm:"‎",lrtri:"⊿",lsaquo:"‹",lscr:"𝓁",Lscr:"ℒ",lsh:"↰",Lsh:"↰",lsim:"≲",lsime:"⪍",lsimg:"⪏",lsqb:"[",lsquo:"‘",lsquor:"‚",Lstrok:"Ł",lstrok:"ł",ltcc:"⪦",ltcir:"⩹",lt:"<",LT:"<",Lt:"≪",ltdot:"⋖",lthree:"⋋",ltimes:"⋉",ltlarr:"⥶",ltquest:"⩻",ltri:"◃",ltrie:"⊴",ltrif:"◂",ltrPar:"⦖",lurdshar:"⥊",luruhar:"⥦",lvertneqq:"≨︀",lvnE:"≨︀",macr:"¯",male:"♂",malt:"✠",maltese:"✠",Map:"⤅",map:"↦",mapsto:"↦",mapstodown:"↧",mapstoleft:"↤",mapstoup:"↥",marker:"▮",mcomma:"⨩",Mcy:"М",mcy:"м",mdash:"—",mDDot:"∺",measuredangle:"∡",MediumSpace:" ",Mellintrf:"ℳ",Mfr:"𝔐",mfr:"𝔪",mho:"℧",micro:"µ",midast:"*",midcir:"⫰",mid:"∣",middot:"·",minusb:"⊟",minus:"−",minusd:"∸",minusdu:"⨪",MinusPlus:"∓",mlcp:"⫛",mldr:"…",mnplus:"∓",models:"⊧",Mopf:"𝕄",mopf:"𝕞",mp:"∓",mscr:"𝓂",Mscr:"ℳ",mstpos:"∾",Mu:"Μ",mu:"μ",multimap:"⊸",mumap:"⊸",nabla:"∇",Nacute:"Ń",nacute:"ń",nang:"∠⃒",nap:"≉",napE:"⩰̸",napid:"≋̸",napos:"ŉ",napprox:"≉",natural:"♮",naturals:"ℕ",natur:"♮",nbsp:" ",nbump:"≎̸",nbumpe:"≏̸",ncap:"⩃",Ncaron:"Ň",ncaron:"ň",Ncedil:"Ņ",ncedil:"ņ",ncong:"≇",ncongdot:"⩭̸",ncup:"⩂",Ncy:"Н",ncy:"н",ndash:"–",nearhk:"⤤",nearr:"↗",neArr:"⇗",nearrow:"↗",ne:"≠",nedot:"≐̸",NegativeMediumSpace:"​",NegativeThickSpace:"​",NegativeThinSpace:"​",NegativeVeryThinSpace:"​",nequiv:"≢",nesear:"⤨",nesim:"≂̸",NestedGreaterGreater:"≫",NestedLessLess:"≪",NewLine:`
-`,nexist:"∄",nexists:"∄",Nfr:"𝔑",nfr:"𝔫",ngE:"≧̸",nge:"≱",ngeq:"≱",ngeqq:"≧̸",ngeqslant:"⩾̸",nges:"⩾̸",nGg:"⋙̸",ngsim:"≵",nGt:"≫⃒",ngt:"≯",ngtr:"≯",nGtv:"≫̸",nharr:"↮",nhArr:"⇎",nhpar:"⫲",ni:"∋",nis:"⋼",nisd:"⋺",niv:"∋",NJcy:"Њ",njcy:"њ",nlarr:"↚",nlArr:"⇍",nldr:"‥",nlE:"≦̸",nle:"≰",nleftarrow:"↚",nLeftarrow:"⇍",nleftrightarrow:"↮",nLeftrightarrow:"⇎",nleq:"≰",nleqq:"≦̸",nleqslant:"⩽̸",nles:"⩽̸",nless:"≮",nLl:"⋘̸",nlsim:"≴",nLt:"≪⃒",nlt:"≮",nltri:"⋪",nltrie:"⋬",nLtv:"≪̸",nmid:"∤",NoBreak:"⁠",NonBreakingSpace:" ",nopf:"𝕟",Nopf:"ℕ",Not:"⫬",not:"¬",NotCongruent:"≢",NotCupCap:"≭",NotDoubleVerticalBar:"∦",NotElement:"∉",NotEqual:"≠",NotEqualTilde:"≂̸",NotExists:"∄",NotGreater:"≯",NotGreaterEqual:"≱",NotGreaterFullEqual:"≧̸",NotGreaterGreater:"≫̸",NotGreaterLess:"≹",NotGreaterSlantEqual:"⩾̸",NotGreaterTilde:"≵",NotHumpDownHump:"≎̸",NotHumpEqual:"≏̸",notin:"∉",notindot:"⋵̸",notinE:"⋹̸",notinva:"∉",notinvb:"⋷",notinvc:"⋶",NotLeftTriangleBar:"⧏̸",NotLeftTriangle:"⋪",NotLeftTriangleEqual:"⋬",NotLess:"≮",NotLessEqual:"≰",NotLessGreater:"≸",NotLessLess:"≪̸",NotLessSlantEqual:"⩽̸",NotLessTilde:"≴",NotNestedGreaterGreater:"⪢̸",NotNestedLessLess:"⪡̸",notni:"∌",notniva:"∌",notnivb:"⋾",notnivc:"⋽",NotPrecedes:"⊀",NotPrecedesEqual:"⪯̸",NotPrecedesSlantEqual:"⋠",NotReverseElement:"∌",NotRightTriangleBar:"⧐̸",NotRightTriangle:"⋫",NotRightTriangleEqual:"⋭",NotSquareSubset:"⊏̸",NotSquareSubsetEqual:"⋢",NotSquareSuperset:"⊐̸",NotSquareSupersetEqual:"⋣",NotSubset:"⊂⃒",NotSubsetEqual:"⊈",NotSucceeds:"⊁",NotSucceedsEqual:"⪰̸",NotSucceedsSlantEqual:"⋡",NotSucceedsTilde:"≿̸",NotSuperset:"⊃⃒",NotSupersetEqual:"⊉",NotTilde:"≁",NotTildeEqual:"≄",NotTildeFullEqual:"≇",NotTildeTilde:"≉",NotVerticalBar:"∤",nparallel:"∦",npar:"∦",nparsl:"⫽⃥",npart:"∂̸",npolint:"⨔",npr:"⊀",nprcue:"⋠",nprec:"⊀",npreceq:"⪯̸",npre:"⪯̸",nrarrc:"⤳̸",nrarr:"↛",nrArr:"⇏",nrarrw:"↝̸",nrightarrow:"↛",nRightarrow:"⇏",nrtri:"⋫",nrtrie:"⋭",nsc:"⊁",nsccue:"⋡",nsce:"⪰̸",Nscr:"𝒩",nscr:"𝓃",nshortmid:"∤",nshortparallel:"∦",nsim:"≁",nsime:"≄",nsimeq:"≄",nsmid:"∤",nspar:"∦",nsqsube:"⋢",nsqsupe:"⋣",nsub:"⊄",nsubE:"⫅̸",nsube:"⊈",nsubset:"⊂⃒",nsubseteq:"⊈",nsubseteqq:"⫅̸",nsucc:"⊁",nsucceq:"⪰̸",nsup:"⊅",nsupE:"⫆̸",nsupe:"⊉",nsupset:"⊃⃒",nsupseteq:"⊉",nsupseteqq:"⫆̸",ntgl:"≹",Ntilde:"Ñ",ntilde:"ñ",ntlg:"≸",ntriangleleft:"⋪",ntrianglelefteq:"⋬",ntriangleright:"⋫",ntrianglerighteq:"⋭",Nu:"Ν",nu:"ν",num:"#",numero:"№",numsp:" ",nvap:"≍⃒",nvdash:"⊬",nvDash:"⊭",nVdash:"⊮",nVDash:"⊯",nvge:"≥⃒",nvgt:">⃒",nvHarr:"⤄",nvinfin:"⧞",nvlArr:"⤂",nvle:"≤⃒",nvlt:"<⃒",nvltrie:"⊴⃒",nvrArr:"⤃",nvrtrie:"⊵⃒",nvsim:"∼⃒",nwarhk:"⤣",nwarr:"↖",nwArr:"⇖",nwarrow:"↖",nwnear:"⤧",Oacute:"Ó",oacute:"ó",oast:"⊛",Ocirc:"Ô",ocirc:"ô",ocir:"⊚",Ocy:"О",ocy:"о",odash:"⊝",Odblac:"Ő",odblac:"ő",odiv:"⨸",odot:"⊙",odsold:"⦼",OElig:"Œ",oelig:"œ",ofcir:"⦿",Ofr:"𝔒",ofr:"𝔬",ogon:"˛",Ograve:"Ò",ograve:"ò",ogt:"⧁",ohbar:"⦵",ohm:"Ω",oint:"∮",olarr:"↺",olcir:"⦾",olcross:"⦻",oline:"‾",olt:"⧀",Omacr:"Ō",omacr:"ō",Omega:"Ω",omega:"ω",Omicron:"Ο",omicron:"ο",omid:"⦶",ominus:"⊖",Oopf:"𝕆",oopf:"𝕠",opar:"⦷",OpenCurlyDoubleQuote:"“",OpenCurlyQuote:"‘",operp:"⦹",oplus:"⊕",orarr:"↻",Or:"⩔",or:"∨",ord:"⩝",order:"ℴ",orderof:"ℴ",ordf:"ª",ordm:"º",origof:"⊶",oror:"⩖",orslope:"⩗",orv:"⩛",oS:"Ⓢ",Oscr:"𝒪",oscr:"ℴ",Oslash:"Ø",oslash:"ø",osol:"⊘",Otilde:"Õ",otilde:"õ",otimesas:"⨶",Otimes:"⨷",otimes:"⊗",Ouml:"Ö",ouml:"ö",ovbar:"⌽",OverBar:"‾",OverBrace:"⏞",OverBracket:"⎴",OverParenthesis:"⏜",para:"¶",parallel:"∥",par:"∥",parsim:"⫳",parsl:"⫽",part:"∂",PartialD:"∂",Pcy:"П",pcy:"п",percnt:"%",period:".",permil:"‰",perp:"⊥",pertenk:"‱",Pfr:"𝔓",pfr:"𝔭",Phi:"Φ",phi:"φ",phiv:"ϕ",phmmat:"ℳ",phone:"☎",Pi:"Π",pi:"π",pitchfork:"⋔",piv:"ϖ",planck:"ℏ",planckh:"ℎ",plankv:"ℏ",plusacir:"⨣",plusb:"⊞",pluscir:"⨢",plus:"+",plusdo:"∔",plusdu:"⨥",pluse:"⩲",PlusMinus:"±",plusmn:"±",plussim:"⨦",plustwo:"⨧",pm:"±",Poincareplane:"ℌ",pointint:"⨕",popf:"𝕡",Popf:"ℙ",pound:"£",prap:"⪷",Pr:"⪻",pr:"≺",prcue:"≼",precapprox:"⪷",prec:"≺",preccurlyeq:"≼",Precedes:"≺",PrecedesEqual:"⪯",PrecedesSlantEqual:"≼",PrecedesTilde:"≾",preceq:"⪯",precnapprox:"⪹",precneqq:"⪵",precnsim:"⋨",pre:"⪯",prE:"⪳",precsim:"≾",prime:"′",Prime:"″",primes:"ℙ",prnap:"⪹",prnE:"⪵",prnsim:"⋨",prod:"∏",Product:"∏",profalar:"⌮",profline:"⌒",profsurf:"⌓",prop:"∝",Proportional:"∝",Proportion:"∷",propto:"∝",prsim:"≾",prurel:"⊰",Pscr:"𝒫",pscr:"𝓅",Psi:"Ψ",psi:"ψ",puncsp:" ",Qfr:"𝔔",qfr:"𝔮",qint:"⨌",qopf:"𝕢",Qopf:"ℚ",qprime:"⁗",Qscr:"𝒬",qscr:"𝓆",quaternions:"ℍ",quatint:"⨖",quest:"?",questeq:"≟",quot:'"',QUOT:'"',rAarr:"⇛",race:"∽̱",Racute:"Ŕ",racute:"ŕ",radic:"√",raemptyv:"⦳",rang:"⟩",Rang:"⟫",rangd:"⦒",range:"⦥",rangle:"⟩",raquo:"»",rarrap:"⥵",rarrb:"⇥",rarrbfs:"⤠",rarrc:"⤳",rarr:"→",Rarr:"↠",rArr:"⇒",rarrfs:"⤞",rarrhk:"↪",rarrlp:"↬",rarrpl:"⥅",rarrsim:"⥴",Rarrtl:"⤖",rarrtl:"↣",rarrw:"↝",ratail:"⤚",rAtail:"⤜",ratio:"∶",rationals:"ℚ",rbarr:"⤍",rBarr:"⤏",RBarr:"⤐",rbbrk:"❳",rbrace:"}",rbrack:"]",rbrke:"⦌",rbrksld:"⦎",rbrkslu:"⦐",Rcaron:"Ř",rcaron:"ř",Rcedil:"Ŗ",rcedil:"ŗ",rceil:"⌉",rcub:"}",Rcy:"Р",rcy:"р",rdca:"⤷",rdldhar:"⥩",rdquo:"”",rdquor:"”",rdsh:"↳",real:"ℜ",realine:"ℛ",realpart:"ℜ",reals:"ℝ",Re:"ℜ",rect:"▭",reg:"®",REG:"®",ReverseElement:"∋",ReverseEquilibrium:"⇋",ReverseUpEquilibrium:"⥯",rfisht:"⥽",rfloor:"⌋",rfr:"𝔯",Rfr:"ℜ",rHar:"⥤",rhard:"⇁",rharu:"⇀",rharul:"⥬",Rho:"Ρ",rho:"ρ",rhov:"ϱ",RightAngleBracket:"⟩",RightArrowBar:"⇥",rightarrow:"→",RightArrow:"→",Rightarrow:"⇒",RightArrowLeftArrow:"⇄",rightarrowtail:"↣",RightCeiling:"⌉",RightDoubleBracket:"⟧",RightDownTeeVector:"⥝",RightDownVectorBar:"⥕",RightDownVector:"⇂",RightFloor:"⌋",rightharpoondown:"⇁",rightharpoonup:"⇀",rightleftarrows:"⇄",rightleftharpoons:"⇌",rightrightarrows:"⇉",rightsquigarrow:"↝",RightTeeArrow:"↦",RightTee:"⊢",RightTeeVector:"⥛",rightthreetimes:"⋌",RightTriangleBar:"⧐",RightTriangle:"⊳",RightTriangleEqual:"⊵",RightUpDownVector:"⥏",RightUpTeeVector:"⥜",RightUpVectorBar:"⥔",RightUpVector:"↾",RightVectorBar:"⥓",RightVector:"⇀",ring:"˚",risingdotseq:"≓",rlarr:"⇄",rlhar:"⇌",rlm:"‏",rmoustache:"⎱",rmoust:"⎱",rnmid:"⫮",roang:"⟭",roarr:"⇾",robrk:"⟧",ropar:"⦆",ropf:"𝕣",Ropf:"ℝ",roplus:"⨮",rotimes:"⨵",RoundImplies:"⥰",rpar:")",rpargt:"⦔",rppolint:"⨒",rrarr:"⇉",Rrightarrow:"⇛",rsaquo:"›",rscr:"𝓇",Rscr:"ℛ",rsh:"↱",Rsh:"↱",rsqb:"]",rsquo:"’",rsquor:"’",rthree:"⋌",rtimes:"⋊",rtri:"▹",rtrie:"⊵",rtrif:"▸",rtriltri:"⧎",RuleDelayed:"⧴",ruluhar:"⥨",rx:"℞",Sacute:"Ś",sacute:"ś",sbquo:"‚",scap:"⪸",Scaron:"Š",scaron:"š",Sc:"⪼",sc:"≻",sccue:"≽",sce:"⪰",scE:"⪴",Scedil:"Ş",scedil:"ş",Scirc:"Ŝ",scirc:"ŝ",scnap:"⪺",scnE:"⪶",scnsim:"⋩",scpolint:"⨓",scsim:"≿",Scy:"С",scy:"с",sdotb:"⊡",sdot:"⋅",sdote:"⩦",searhk:"⤥",searr:"↘",seArr:"⇘",searrow:"↘",sect:"§",semi:";",seswar:"⤩",setminus:"∖",setmn:"∖",sext:"✶",Sfr:"𝔖",sfr:"𝔰",sfrown:"⌢",sharp:"♯",SHCHcy:"Щ",shchcy:"щ",SHcy:"Ш",shcy:"ш",ShortDownArrow:"↓",ShortLeftArrow:"←",shortmid:"∣",shortparallel:"∥",ShortRightArrow:"→",ShortUpArrow:"↑",shy:"­",Sigma:"Σ",sigma:"σ",sigmaf:"ς",sigmav:"ς",sim:"∼",simdot:"⩪",sime:"≃",simeq:"≃",simg:"⪞",simgE:"⪠",siml:"⪝",simlE:"⪟",simne:"≆",simplus:"⨤",simrarr:"⥲",slarr:"←",SmallCircle:"∘",smallsetminus:"∖",smashp:"⨳",smeparsl:"⧤",smid:"∣",smile:"⌣",smt:"⪪",smte:"⪬",smtes:"⪬︀",SOFTcy:"Ь",softcy:"ь",solbar:"⌿",solb:"⧄",sol:"/",Sopf:"𝕊",sopf:"𝕤",spades:"♠",spadesuit:"♠",spar:"∥",sqcap:"⊓",sqcaps:"⊓︀",sqcup:"⊔",sqcups:"⊔︀",Sqrt:"√",sqsub:"⊏",sqsube:"⊑",sqsubset:"⊏",sqsubseteq:"⊑",sqsup:"⊐",sqsupe:"⊒",sqsupset:"⊐",sqsupseteq:"⊒",square:"□",Square:"□",SquareIntersection:"⊓",SquareSubset:"⊏",SquareSubsetEqual:"⊑",SquareSuperset:"⊐",SquareSupersetEqual:"⊒",SquareUnion:"⊔",squarf:"▪",squ:"□",squf:"▪",srarr:"→",Sscr:"𝒮",sscr:"𝓈",ssetmn:"∖",ssmile:"⌣",sstarf:"⋆",Star:"⋆",star:"☆",starf:"★",straightepsilon:"ϵ",straightphi:"ϕ",strns:"¯",sub:"⊂",Sub:"⋐",subdot:"⪽",subE:"⫅",sube:"⊆",subedot:"⫃",submult:"⫁",subnE:"⫋",subne:"⊊",subplus:"⪿",subrarr:"⥹",subset:"⊂",Subset:"⋐",subseteq:"⊆",subseteqq:"⫅",SubsetEqual:"⊆",subsetneq:"⊊",subsetneqq:"⫋",subsim:"⫇",subsub:"⫕",subsup:"⫓",succapprox:"⪸",succ:"≻",succcurlyeq:"≽",Succeeds:"≻",SucceedsEqual:"⪰",SucceedsSlantEqual:"≽",SucceedsTilde:"≿",succeq:"⪰",succnapprox:"⪺",succneqq:"⪶",succnsim:"⋩",succsim:"≿",SuchThat:"∋",sum:"∑",Sum:"∑",sung:"♪",sup1:"¹",sup2:"²",sup3:"³",sup:"⊃",Sup:"⋑",supdot:"⪾",supdsub:"⫘",supE:"⫆",supe:"⊇",supedot:"⫄",Superset:"⊃",SupersetEqual:"⊇",suphsol:"⟉",suphsub:"⫗",suplarr:"⥻",supmult:"⫂",supnE:"⫌",supne:"⊋",supplus:"⫀",supset:"⊃",Supset:"⋑",supseteq:"⊇",supseteqq:"⫆",supsetneq:"⊋",supsetneqq:"⫌",supsim:"⫈",supsub:"⫔",supsup:"⫖",swarhk:"⤦",swarr:"↙",swArr:"⇙",swarrow:"↙",swnwar:"⤪",szlig:"ß",Tab:"	",target:"⌖",Tau:"Τ",tau:"τ",tbrk:"⎴",Tcaron:"Ť",tcaron:"ť",Tcedil:"Ţ",tcedil:"ţ",Tcy:"Т",tcy:"т",tdot:"⃛",telrec:"⌕",Tfr:"𝔗",tfr:"𝔱",there4:"∴",therefore:"∴",Therefore:"∴",Theta:"Θ",theta:"θ",thetasym:"ϑ",thetav:"ϑ",thickapprox:"≈",thicksim:"∼",ThickSpace:"  ",ThinSpace:" ",thinsp:" ",thkap:"≈",thksim:"∼",THORN:"Þ",thorn:"þ",tilde:"˜",Tilde:"∼",TildeEqual:"≃",TildeFullEqual:"≅",TildeTilde:"≈",timesbar:"⨱",timesb:"⊠",times:"×",timesd:"⨰",tint:"∭",toea:"⤨",topbot:"⌶",topcir:"⫱",top:"⊤",Topf:"𝕋",topf:"𝕥",topfork:"⫚",tosa:"⤩",tprime:"‴",trade:"™",TRADE:"™",triangle:"▵",triangledown:"▿",triangleleft:"◃",trianglelefteq:"⊴",triangleq:"≜",triangleright:"▹",trianglerighteq:"⊵",tridot:"◬",trie:"≜",triminus:"⨺",TripleDot:"⃛",triplus:"⨹",trisb:"⧍",tritime:"⨻",trpezium:"⏢",Tscr:"𝒯",tscr:"𝓉",TScy:"Ц",tscy:"ц",TSHcy:"Ћ",tshcy:"ћ",Tstrok:"Ŧ",tstrok:"ŧ",twixt:"≬",twoheadleftarrow:"↞",twoheadrightarrow:"↠",Uacute:"Ú",uacute:"ú",uarr:"↑",Uarr:"↟",uArr:"⇑",Uarrocir:"⥉",Ubrcy:"Ў",ubrcy:"ў",Ubreve:"Ŭ",ubreve:"ŭ",Ucirc:"Û",ucirc:"û",Ucy:"У",ucy:"у",udarr:"⇅",Udblac:"Ű",udblac:"ű",udhar:"⥮",ufisht:"⥾",Ufr:"𝔘",ufr:"𝔲",Ugrave:"Ù",ugrave:"ù",uHar:"⥣",uharl:"↿",uharr:"↾",uhblk:"▀",ulcorn:"⌜",ulcorner:"⌜",ulcrop:"⌏",ultri:"◸",Umacr:"Ū",umacr:"ū",uml:"¨",UnderBar:"_",UnderBrace:"⏟",UnderBracket:"⎵",UnderParenthesis:"⏝",Union:"⋃",UnionPlus:"⊎",Uogon:"Ų",uogon:"ų",Uopf:"𝕌",uopf:"𝕦",UpArrowBar:"⤒",uparrow:"↑",UpArrow:"↑",Uparrow:"⇑",UpArrowDownArrow:"⇅",updownarrow:"↕",UpDownArrow:"↕",Updownarrow:"⇕",UpEquilibrium:"⥮",upharpoonleft:"↿",upharpoonright:"↾",uplus:"⊎",UpperLeftArrow:"↖",UpperRightArrow:"↗",upsi:"υ",Upsi:"ϒ",upsih:"ϒ",Upsilon:"Υ",upsilon:"υ",UpTeeArrow:"↥",UpTee:"⊥",upuparrows:"⇈",urcorn:"⌝",urcorner:"⌝",urcrop:"⌎",Uring:"Ů",uring:"ů",urtri:"◹",Uscr:"𝒰",uscr:"𝓊",utdot:"⋰",Utilde:"Ũ",utilde:"ũ",utri:"▵",utrif:"▴",uuarr:"⇈",Uuml:"Ü",uuml:"ü",uwangle:"⦧",vangrt:"⦜",varepsilon:"ϵ",varkappa:"ϰ",varnothing:"∅",varphi:"ϕ",varpi:"ϖ",varpropto:"∝",varr:"↕",vArr:"⇕",varrho:"ϱ",varsigma:"ς",varsubsetneq:"⊊︀",varsubsetneqq:"⫋︀",varsupsetneq:"⊋︀",varsupsetneqq:"⫌︀",vartheta:"ϑ",vartriangleleft:"⊲",vartriangleright:"⊳",vBar:"⫨",Vbar:"⫫",vBarv:"⫩",Vcy:"В",vcy:"в",vdash:"⊢",vDash:"⊨",Vdash:"⊩",VDash:"⊫",Vdashl:"⫦",veebar:"⊻",vee:"∨",Vee:"⋁",veeeq:"≚",vellip:"⋮",verbar:"|",Verbar:"‖",vert:"|",Vert:"‖",VerticalBar:"∣",VerticalLine:"|",VerticalSeparator:"❘",VerticalTilde:"≀",VeryThinSpace:" ",Vfr:"𝔙",vfr:"𝔳",vltri:"⊲",vnsub:"⊂⃒",vnsup:"⊃⃒",Vopf:"𝕍",vopf:"𝕧",vprop:"∝",vrtri:"⊳",Vscr:"𝒱",vscr:"𝓋",vsubnE:"⫋︀",vsubne:"⊊︀",vsupnE:"⫌︀",vsupne:"⊋︀",Vvdash:"⊪",vzigzag:"⦚",Wcirc:"Ŵ",wcirc:"ŵ",wedbar:"⩟",wedge:"∧",Wedge:"⋀",wedgeq:"≙",weierp:"℘",Wfr:"𝔚",wfr:"𝔴",Wopf:"𝕎",wopf:"𝕨",wp:"℘",wr:"≀",wreath:"≀",Wscr:"𝒲",wscr:"𝓌",xcap:"⋂",xcirc:"◯",xcup:"⋃",xdtri:"▽",Xfr:"𝔛",xfr:"𝔵",xharr:"⟷",xhArr:"⟺",Xi:"Ξ",xi:"ξ",xlarr:"⟵",xlArr:"⟸",xmap:"⟼",xnis:"⋻",xodot:"⨀",Xopf:"𝕏",xopf:"𝕩",xoplus:"⨁",xotime:"⨂",xrarr:"⟶",xrArr:"⟹",Xscr:"𝒳",xscr:"𝓍",xsqcup:"⨆",xuplus:"⨄",xutri:"△",xvee:"⋁",xwedge:"⋀",Yacute:"Ý",yacute:"ý",YAcy:"Я",yacy:"я",Ycirc:"Ŷ",ycirc:"ŷ",Ycy:"Ы",ycy:"ы",yen:"¥",Yfr:"𝔜",yfr:"𝔶",YIcy:"Ї",yicy:"ї",Yopf:"𝕐",yopf:"𝕪",Yscr:"𝒴",yscr:"𝓎",YUcy:"Ю",yucy:"ю",yuml:"ÿ",Yuml:"Ÿ",Zacute:"Ź",zacute:"ź",Zcaron:"Ž",zcaron:"ž",Zcy:"З",zcy:"з",Zdot:"Ż",zdot:"ż",zeetrf:"ℨ",ZeroWidthSpace:"​",Zeta:"Ζ",zeta:"ζ",zfr:"𝔷",Zfr:"ℨ",ZHcy:"Ж",zhcy:"ж",zigrarr:"⇝",zopf:"𝕫",Zopf:"ℤ",Zscr:"𝒵",zscr:"𝓏",zwj:"‍",zwnj:"‌"},_m={Aacute:"Á",aacute:"á",Acirc:"Â",acirc:"â",acute:"´",AElig:"Æ",aelig:"æ",Agrave:"À",agrave:"à",amp:"&",AMP:"&",Aring:"Å",aring:"å",Atilde:"Ã",atilde:"ã",Auml:"Ä",auml:"ä",brvbar:"¦",Ccedil:"Ç",ccedil:"ç",cedil:"¸",cent:"¢",copy:"©",COPY:"©",curren:"¤",deg:"°",divide:"÷",Eacute:"É",eacute:"é",Ecirc:"Ê",ecirc:"ê",Egrave:"È",egrave:"è",ETH:"Ð",eth:"ð",Euml:"Ë",euml:"ë",frac12:"½",frac14:"¼",frac34:"¾",gt:">",GT:">",Iacute:"Í",iacute:"í",Icirc:"Î",icirc:"î",iexcl:"¡",Igrave:"Ì",igrave:"ì",iquest:"¿",Iuml:"Ï",iuml:"ï",laquo:"«",lt:"<",LT:"<",macr:"¯",micro:"µ",middot:"·",nbsp:" ",not:"¬",Ntilde:"Ñ",ntilde:"ñ",Oacute:"Ó",oacute:"ó",Ocirc:"Ô",ocirc:"ô",Ograve:"Ò",ograve:"ò",ordf:"ª",ordm:"º",Oslash:"Ø",oslash:"ø",Otilde:"Õ",otilde:"õ",Ouml:"Ö",ouml:"ö",para:"¶",plusmn:"±",pound:"£",quot:'"',QUOT:'"',raquo:"»",reg:"®",REG:"®",sect:"§",shy:"­",sup1:"¹",sup2:"²",sup3:"³",szlig:"ß",THORN:"Þ",thorn:"þ",times:"×",Uacute:"Ú",uacute:"ú",Ucirc:"Û",ucirc:"û",Ugrave:"Ù",ugrave:"ù",uml:"¨",Uuml:"Ü",uuml:"ü",Yacute:"Ý",yacute:"ý",yen:"¥",yuml:"ÿ"},xd={amp:"&",apos:"'",gt:">",lt:"<",quot:'"'};var La=we&&we.__importDefault||function(e){return e&&e.__esModule?e:{default:e}};Object.defineProperty(Ta,"__esModule",{value:!0});var I8=La(xa),M8=La(Td),Ld=La(_m),B8=La(xd);function pn(e){return e===" "||e===`
-`||e==="	"||e==="\f"||e==="\r"}function ym(e){return e>="a"&&e<="z"||e>="A"&&e<="Z"}function xt(e,t,n){var r=e.toLowerCase();return e===r?function(o,u){u===r?o._state=t:(o._state=n,o._index--)}:function(o,u){u===r||u===e?o._state=t:(o._state=n,o._index--)}}function Xn(e,t){var n=e.toLowerCase();return function(r,o){o===n||o===e?r._state=t:(r._state=3,r._index--)}}var z8=xt("C",24,16),$8=xt("D",25,16),U8=xt("A",26,16),j8=xt("T",27,16),F8=xt("A",28,16),V8=Xn("R",35),H8=Xn("I",36),G8=Xn("P",37),W8=Xn("T",38),K8=xt("R",40,1),Y8=xt("I",41,1),Q8=xt("P",42,1),X8=xt("T",43,1),Z8=Xn("Y",45),J8=Xn("L",46),eS=Xn("E",47),tS=xt("Y",49,1),nS=xt("L",50,1),rS=xt("E",51,1),iS=Xn("I",54),oS=Xn("T",55),sS=Xn("L",56),uS=Xn("E",57),aS=xt("I",58,1),lS=xt("T",59,1),cS=xt("L",60,1),dS=xt("E",61,1),fS=xt("#",63,64),pS=xt("X",66,65),hS=function(){function e(t,n){var r;this._state=1,this.buffer="",this.sectionStart=0,this._index=0,this.bufferOffset=0,this.baseState=1,this.special=1,this.running=!0,this.ended=!1,this.cbs=n,this.xmlMode=!!(t!=null&&t.xmlMode),this.decodeEntities=(r=t==null?void 0:t.decodeEntities)!==null&&r!==void 0?r:!0}return e.prototype.reset=function(){this._state=1,this.buffer="",this.sectionStart=0,this._index=0,this.bufferOffset=0,this.baseState=1,this.special=1,this.running=!0,this.ended=!1},e.prototype.write=function(t){this.ended&&this.cbs.onerror(Error(".write() after done!")),this.buffer+=t,this.parse()},e.prototype.end=function(t){this.ended&&this.cbs.onerror(Error(".end() after done!")),t&&this.write(t),this.ended=!0,this.running&&this.finish()},e.prototype.pause=function(){this.running=!1},e.prototype.resume=function(){this.running=!0,this._index<this.buffer.length&&this.parse(),this.ended&&this.finish()},e.prototype.getAbsoluteIndex=function(){return this.bufferOffset+this._index},e.prototype.stateText=function(t){t==="<"?(this._index>this.sectionStart&&this.cbs.ontext(this.getSection()),this._state=2,this.sectionStart=this._index):this.decodeEntities&&t==="&"&&(this.special===1||this.special===4)&&(this._index>this.sectionStart&&this.cbs.ontext(this.getSection()),this.baseState=1,this._state=62,this.sectionStart=this._index)},e.prototype.isTagStartChar=function(t){return ym(t)||this.xmlMode&&!pn(t)&&t!=="/"&&t!==">"},e.prototype.stateBeforeTagName=function(t){t==="/"?this._state=5:t==="<"?(this.cbs.ontext(this.getSection()),this.sectionStart=this._index):t===">"||this.special!==1||pn(t)?this._state=1:t==="!"?(this._state=15,this.sectionStart=this._index+1):t==="?"?(this._state=17,this.sectionStart=this._index+1):this.isTagStartChar(t)?(this._state=!this.xmlMode&&(t==="s"||t==="S")?32:!this.xmlMode&&(t==="t"||t==="T")?52:3,this.sectionStart=this._index):this._state=1},e.prototype.stateInTagName=function(t){(t==="/"||t===">"||pn(t))&&(this.emitToken("onopentagname"),this._state=8,this._index--)},e.prototype.stateBeforeClosingTagName=function(t){pn(t)||(t===">"?this._state=1:this.special!==1?this.special!==4&&(t==="s"||t==="S")?this._state=33:this.special===4&&(t==="t"||t==="T")?this._state=53:(this._state=1,this._index--):this.isTagStartChar(t)?(this._state=6,this.sectionStart=this._index):(this._state=20,this.sectionStart=this._index))},e.prototype.stateInClosingTagName=function(t){(t===">"||pn(t))&&(this.emitToken("onclosetag"),this._state=7,this._index--)},e.prototype.stateAfterClosingTagName=function(t){t===">"&&(this._state=1,this.sectionStart=this._index+1)},e.prototype.stateBeforeAttributeName=function(t){t===">"?(this.cbs.onopentagend(),this._state=1,this.sectionStart=this._index+1):t==="/"?this._state=4:pn(t)||(this._state=9,this.sectionStart=this._index)},e.prototype.stateInSelfClosingTag=function(t){t===">"?(this.cbs.onselfclosingtag(),this._state=1,this.sectionStart=this._index+1,this.special=1):pn(t)||(this._state=8,this._index--)},e.prototype.stateInAttributeName=function(t){(t==="="||t==="/"||t===">"||pn(t))&&(this.cbs.onattribname(this.getSection()),this.sectionStart=-1,this._state=10,this._index--)},e.prototype.stateAfterAttributeName=function(t){t==="="?this._state=11:t==="/"||t===">"?(this.cbs.onattribend(void 0),this._state=8,this._index--):pn(t)||(this.cbs.onattribend(void 0),this._state=9,this.sectionStart=this._index)},e.prototype.stateBeforeAttributeValue=function(t){t==='"'?(this._state=12,this.sectionStart=this._index+1):t==="'"?(this._state=13,this.sectionStart=this._index+1):pn(t)||(this._state=14,this.sectionStart=this._index,this._index--)},e.prototype.handleInAttributeValue=function(t,n){t===n?(this.emitToken("onattribdata"),this.cbs.onattribend(n),this._state=8):this.decodeEntities&&t==="&"&&(this.emitToken("onattribdata"),this.baseState=this._state,this._state=62,this.sectionStart=this._index)},e.prototype.stateInAttributeValueDoubleQuotes=function(t){this.handleInAttributeValue(t,'"')},e.prototype.stateInAttributeValueSingleQuotes=function(t){this.handleInAttributeValue(t,"'")},e.prototype.stateInAttributeValueNoQuotes=function(t){pn(t)||t===">"?(this.emitToken("onattribdata"),this.cbs.onattribend(null),this._state=8,this._index--):this.decodeEntities&&t==="&"&&(this.emitToken("onattribdata"),this.baseState=this._state,this._state=62,this.sectionStart=this._index)},e.prototype.stateBeforeDeclaration=function(t){this._state=t==="["?23:t==="-"?18:16},e.prototype.stateInDeclaration=function(t){t===">"&&(this.cbs.ondeclaration(this.getSection()),this._state=1,this.sectionStart=this._index+1)},e.prototype.stateInProcessingInstruction=function(t){t===">"&&(this.cbs.onprocessinginstruction(this.getSection()),this._state=1,this.sectionStart=this._index+1)},e.prototype.stateBeforeComment=function(t){t==="-"?(this._state=19,this.sectionStart=this._index+1):this._state=16},e.prototype.stateInComment=function(t){t==="-"&&(this._state=21)},e.prototype.stateInSpecialComment=function(t){t===">"&&(this.cbs.oncomment(this.buffer.substring(this.sectionStart,this._index)),this._state=1,this.sectionStart=this._index+1)},e.prototype.stateAfterComment1=function(t){t==="-"?this._state=22:this._state=19},e.prototype.stateAfterComment2=function(t){t===">"?(this.cbs.oncomment(this.buffer.substring(this.sectionStart,this._index-2)),this._state=1,this.sectionStart=this._index+1):t!=="-"&&(this._state=19)},e.prototype.stateBeforeCdata6=function(t){t==="["?(this._state=29,this.sectionStart=this._index+1):(this._state=16,this._index--)},e.prototype.stateInCdata=function(t){t==="]"&&(this._state=30)},e.prototype.stateAfterCdata1=function(t){t==="]"?this._state=31:this._state=29},e.prototype.stateAfterCdata2=function(t){t===">"?(this.cbs.oncdata(this.buffer.substring(this.sectionStart,this._index-2)),this._state=1,this.sectionStart=this._index+1):t!=="]"&&(this._state=29)},e.prototype.stateBeforeSpecialS=function(t){t==="c"||t==="C"?this._state=34:t==="t"||t==="T"?this._state=44:(this._state=3,this._index--)},e.prototype.stateBeforeSpecialSEnd=function(t){this.special===2&&(t==="c"||t==="C")?this._state=39:this.special===3&&(t==="t"||t==="T")?this._state=48:this._state=1},e.prototype.stateBeforeSpecialLast=function(t,n){(t==="/"||t===">"||pn(t))&&(this.special=n),this._state=3,this._index--},e.prototype.stateAfterSpecialLast=function(t,n){t===">"||pn(t)?(this.special=1,this._state=6,this.sectionStart=this._index-n,this._index--):this._state=1},e.prototype.parseFixedEntity=function(t){if(t===void 0&&(t=this.xmlMode?B8.default:M8.default),this.sectionStart+1<this._index){var n=this.buffer.substring(this.sectionStart+1,this._index);Object.prototype.hasOwnProperty.call(t,n)&&(this.emitPartial(t[n]),this.sectionStart=this._index+1)}},e.prototype.parseLegacyEntity=function(){for(var t=this.sectionStart+1,n=Math.min(this._index-t,6);n>=2;){var r=this.buffer.substr(t,n);if(Object.prototype.hasOwnProperty.call(Ld.default,r)){this.emitPartial(Ld.default[r]),this.sectionStart+=n+1;return}n--}},e.prototype.stateInNamedEntity=function(t){t===";"?(this.parseFixedEntity(),this.baseState===1&&this.sectionStart+1<this._index&&!this.xmlMode&&this.parseLegacyEntity(),this._state=this.baseState):(t<"0"||t>"9")&&!ym(t)&&(this.xmlMode||this.sectionStart+1===this._index||(this.baseState!==1?t!=="="&&this.parseFixedEntity(Ld.default):this.parseLegacyEntity()),this._state=this.baseState,this._index--)},e.prototype.decodeNumericEntity=function(t,n,r){var o=this.sectionStart+t;if(o!==this._index){var u=this.buffer.substring(o,this._index),c=parseInt(u,n);this.emitPartial(I8.default(c)),this.sectionStart=r?this._index+1:this._index}this._state=this.baseState},e.prototype.stateInNumericEntity=function(t){t===";"?this.decodeNumericEntity(2,10,!0):(t<"0"||t>"9")&&(this.xmlMode?this._state=this.baseState:this.decodeNumericEntity(2,10,!1),this._index--)},e.prototype.stateInHexEntity=function(t){t===";"?this.decodeNumericEntity(3,16,!0):(t<"a"||t>"f")&&(t<"A"||t>"F")&&(t<"0"||t>"9")&&(this.xmlMode?this._state=this.baseState:this.decodeNumericEntity(3,16,!1),this._index--)},e.prototype.cleanup=function(){this.sectionStart<0?(this.buffer="",this.bufferOffset+=this._index,this._index=0):this.running&&(this._state===1?(this.sectionStart!==this._index&&this.cbs.ontext(this.buffer.substr(this.sectionStart)),this.buffer="",this.bufferOffset+=this._index,this._index=0):this.sectionStart===this._index?(this.buffer="",this.bufferOffset+=this._index,this._index=0):(this.buffer=this.buffer.substr(this.sectionStart),this._index-=this.sectionStart,this.bufferOffset+=this.sectionStart),this.sectionStart=0)},e.prototype.parse=function(){for(;this._index<this.buffer.length&&this.running;){var t=this.buffer.charAt(this._index);this._state===1?this.stateText(t):this._state===12?this.stateInAttributeValueDoubleQuotes(t):this._state===9?this.stateInAttributeName(t):this._state===19?this.stateInComment(t):this._state===20?this.stateInSpecialComment(t):this._state===8?this.stateBeforeAttributeName(t):this._state===3?this.stateInTagName(t):this._state===6?this.stateInClosingTagName(t):this._state===2?this.stateBeforeTagName(t):this._state===10?this.stateAfterAttributeName(t):this._state===13?this.stateInAttributeValueSingleQuotes(t):this._state===11?this.stateBeforeAttributeValue(t):this._state===5?this.stateBeforeClosingTagName(t):this._state===7?this.stateAfterClosingTagName(t):this._state===32?this.stateBeforeSpecialS(t):this._state===21?this.stateAfterComment1(t):this._state===14?this.stateInAttributeValueNoQuotes(t):this._state===4?this.stateInSelfClosingTag(t):this._state===16?this.stateInDeclaration(t):this._state===15?this.stateBeforeDeclaration(t):this._state===22?this.stateAfterComment2(t):this._state===18?this.stateBeforeComment(t):this._state===33?this.stateBeforeSpecialSEnd(t):this._state===53?aS(this,t):this._state===39?K8(this,t):this._state===40?Y8(this,t):this._state===41?Q8(this,t):this._state===34?V8(this,t):this._state===35?H8(this,t):this._state===36?G8(this,t):this._state===37?W8(this,t):this._state===38?this.stateBeforeSpecialLast(t,2):this._state===42?X8(this,t):this._state===43?this.stateAfterSpecialLast(t,6):this._state===44?Z8(this,t):this._state===29?this.stateInCdata(t):this._state===45?J8(this,t):this._state===46?eS(this,t):this._state===47?this.stateBeforeSpecialLast(t,3):this._state===48?tS(this,t):this._state===49?nS(this,t):this._state===50?rS(this,t):this._state===51?this.stateAfterSpecialLast(t,5):this._state===52?iS(this,t):this._state===54?oS(this,t):this._state===55?sS(this,t):this._state===56?uS(this,t):this._state===57?this.stateBeforeSpecialLast(t,4):this._state===58?lS(this,t):this._state===59?cS(this,t):this._state===60?dS(this,t):this._state===61?this.stateAfterSpecialLast(t,5):this._state===17?this.stateInProcessingInstruction(t):this._state===64?this.stateInNamedEntity(t):this._state===23?z8(this,t):this._state===62?fS(this,t):this._state===24?$8(this,t):this._state===25?U8(this,t):this._state===30?this.stateAfterCdata1(t):this._state===31?this.stateAfterCdata2(t):this._state===26?j8(this,t):this._state===27?F8(this,t):this._state===28?this.stateBeforeCdata6(t):this._state===66?this.stateInHexEntity(t):this._state===65?this.stateInNumericEntity(t):this._state===63?pS(this,t):this.cbs.onerror(Error("unknown _state"),this._state),this._index++}this.cleanup()},e.prototype.finish=function(){this.sectionStart<this._index&&this.handleTrailingData(),this.cbs.onend()},e.prototype.handleTrailingData=function(){var t=this.buffer.substr(this.sectionStart);this._state===29||this._state===30||this._state===31?this.cbs.oncdata(t):this._state===19||this._state===21||this._state===22?this.cbs.oncomment(t):this._state===64&&!this.xmlMode?(this.parseLegacyEntity(),this.sectionStart<this._index&&(this._state=this.baseState,this.handleTrailingData())):this._state===65&&!this.xmlMode?(this.decodeNumericEntity(2,10,!1),this.sectionStart<this._index&&(this._state=this.baseState,this.handleTrailingData())):this._state===66&&!this.xmlMode?(this.decodeNumericEntity(3,16,!1),this.sectionStart<this._index&&(this._state=this.baseState,this.handleTrailingData())):this._state!==3&&this._state!==8&&this._state!==11&&this._state!==10&&this._state!==9&&this._state!==13&&this._state!==12&&this._state!==14&&this._state!==6&&this.cbs.ontext(t)},e.prototype.getSection=function(){return this.buffer.substring(this.sectionStart,this._index)},e.prototype.emitToken=function(t){this.cbs[t](this.getSection()),this.sectionStart=-1},e.prototype.emitPartial=function(t){this.baseState!==1?this.cbs.onattribdata(t):this.cbs.ontext(t)},e}();Ta.default=hS;var gS=we&&we.__importDefault||function(e){return e&&e.__esModule?e:{default:e}};Object.defineProperty($s,"__esModule",{value:!0}),$s.Parser=void 0;var mS=gS(Ta),Oo=new Set(["input","option","optgroup","select","button","datalist","textarea"]),Qe=new Set(["p"]),wm={tr:new Set(["tr","th","td"]),th:new Set(["th"]),td:new Set(["thead","th","td"]),body:new Set(["head","link","script"]),li:new Set(["li"]),p:Qe,h1:Qe,h2:Qe,h3:Qe,h4:Qe,h5:Qe,h6:Qe,select:Oo,input:Oo,output:Oo,button:Oo,datalist:Oo,textarea:Oo,option:new Set(["option"]),optgroup:new Set(["optgroup","option"]),dd:new Set(["dt","dd"]),dt:new Set(["dt","dd"]),address:Qe,article:Qe,aside:Qe,blockquote:Qe,details:Qe,div:Qe,dl:Qe,fieldset:Qe,figcaption:Qe,figure:Qe,footer:Qe,form:Qe,header:Qe,hr:Qe,main:Qe,nav:Qe,ol:Qe,pre:Qe,section:Qe,table:Qe,ul:Qe,rt:new Set(["rt","rp"]),rp:new Set(["rt","rp"]),tbody:new Set(["thead","tbody"]),tfoot:new Set(["thead","tbody"])},Od=new Set(["area","base","basefont","br","col","command","embed","frame","hr","img","input","isindex","keygen","link","meta","param","source","track","wbr"]),bm=new Set(["math","svg"]),Sm=new Set(["mi","mo","mn","ms","mtext","annotation-xml","foreignObject","desc","title"]),vS=/\s|\//,_S=function(){function e(t,n){n===void 0&&(n={});var r,o,u,c,p;this.startIndex=0,this.endIndex=null,this.tagname="",this.attribname="",this.attribvalue="",this.attribs=null,this.stack=[],this.foreignContext=[],this.options=n,this.cbs=t??{},this.lowerCaseTagNames=(r=n.lowerCaseTags)!==null&&r!==void 0?r:!n.xmlMode,this.lowerCaseAttributeNames=(o=n.lowerCaseAttributeNames)!==null&&o!==void 0?o:!n.xmlMode,this.tokenizer=new((u=n.Tokenizer)!==null&&u!==void 0?u:mS.default)(this.options,this),(p=(c=this.cbs).onparserinit)===null||p===void 0||p.call(c,this)}return e.prototype.updatePosition=function(t){this.endIndex===null?this.tokenizer.sectionStart<=t?this.startIndex=0:this.startIndex=this.tokenizer.sectionStart-t:this.startIndex=this.endIndex+1,this.endIndex=this.tokenizer.getAbsoluteIndex()},e.prototype.ontext=function(t){var n,r;this.updatePosition(1),this.endIndex--,(r=(n=this.cbs).ontext)===null||r===void 0||r.call(n,t)},e.prototype.onopentagname=function(t){var n,r;if(this.lowerCaseTagNames&&(t=t.toLowerCase()),this.tagname=t,!this.options.xmlMode&&Object.prototype.hasOwnProperty.call(wm,t))for(var o=void 0;this.stack.length>0&&wm[t].has(o=this.stack[this.stack.length-1]);)this.onclosetag(o);(this.options.xmlMode||!Od.has(t))&&(this.stack.push(t),bm.has(t)?this.foreignContext.push(!0):Sm.has(t)&&this.foreignContext.push(!1)),(r=(n=this.cbs).onopentagname)===null||r===void 0||r.call(n,t),this.cbs.onopentag&&(this.attribs={})},e.prototype.onopentagend=function(){var t,n;this.updatePosition(1),this.attribs&&((n=(t=this.cbs).onopentag)===null||n===void 0||n.call(t,this.tagname,this.attribs),this.attribs=null),!this.options.xmlMode&&this.cbs.onclosetag&&Od.has(this.tagname)&&this.cbs.onclosetag(this.tagname),this.tagname=""},e.prototype.onclosetag=function(t){if(this.updatePosition(1),this.lowerCaseTagNames&&(t=t.toLowerCase()),(bm.has(t)||Sm.has(t))&&this.foreignContext.pop(),this.stack.length&&(this.options.xmlMode||!Od.has(t))){var n=this.stack.lastIndexOf(t);if(n!==-1)if(this.cbs.onclosetag)for(n=this.stack.length-n;n--;)this.cbs.onclosetag(this.stack.pop());else this.stack.length=n;else t==="p"&&!this.options.xmlMode&&(this.onopentagname(t),this.closeCurrentTag())}else!this.options.xmlMode&&(t==="br"||t==="p")&&(this.onopentagname(t),this.closeCurrentTag())},e.prototype.onselfclosingtag=function(){this.options.xmlMode||this.options.recognizeSelfClosing||this.foreignContext[this.foreignContext.length-1]?this.closeCurrentTag():this.onopentagend()},e.prototype.closeCurrentTag=function(){var t,n,r=this.tagname;this.onopentagend(),this.stack[this.stack.length-1]===r&&((n=(t=this.cbs).onclosetag)===null||n===void 0||n.call(t,r),this.stack.pop())},e.prototype.onattribname=function(t){this.lowerCaseAttributeNames&&(t=t.toLowerCase()),this.attribname=t},e.prototype.onattribdata=function(t){this.attribvalue+=t},e.prototype.onattribend=function(t){var n,r;(r=(n=this.cbs).onattribute)===null||r===void 0||r.call(n,this.attribname,this.attribvalue,t),this.attribs&&!Object.prototype.hasOwnProperty.call(this.attribs,this.attribname)&&(this.attribs[this.attribname]=this.attribvalue),this.attribname="",this.attribvalue=""},e.prototype.getInstructionName=function(t){var n=t.search(vS),r=n<0?t:t.substr(0,n);return this.lowerCaseTagNames&&(r=r.toLowerCase()),r},e.prototype.ondeclaration=function(t){if(this.cbs.onprocessinginstruction){var n=this.getInstructionName(t);this.cbs.onprocessinginstruction("!"+n,"!"+t)}},e.prototype.onprocessinginstruction=function(t){if(this.cbs.onprocessinginstruction){var n=this.getInstructionName(t);this.cbs.onprocessinginstruction("?"+n,"?"+t)}},e.prototype.oncomment=function(t){var n,r,o,u;this.updatePosition(4),(r=(n=this.cbs).oncomment)===null||r===void 0||r.call(n,t),(u=(o=this.cbs).oncommentend)===null||u===void 0||u.call(o)},e.prototype.oncdata=function(t){var n,r,o,u,c,p;this.updatePosition(1),this.options.xmlMode||this.options.recognizeCDATA?((r=(n=this.cbs).oncdatastart)===null||r===void 0||r.call(n),(u=(o=this.cbs).ontext)===null||u===void 0||u.call(o,t),(p=(c=this.cbs).oncdataend)===null||p===void 0||p.call(c)):this.oncomment("[CDATA["+t+"]]")},e.prototype.onerror=function(t){var n,r;(r=(n=this.cbs).onerror)===null||r===void 0||r.call(n,t)},e.prototype.onend=function(){var t,n;if(this.cbs.onclosetag)for(var r=this.stack.length;r>0;this.cbs.onclosetag(this.stack[--r]));(n=(t=this.cbs).onend)===null||n===void 0||n.call(t)},e.prototype.reset=function(){var t,n,r,o;(n=(t=this.cbs).onreset)===null||n===void 0||n.call(t),this.tokenizer.reset(),this.tagname="",this.attribname="",this.attribs=null,this.stack=[],(o=(r=this.cbs).onparserinit)===null||o===void 0||o.call(r,this)},e.prototype.parseComplete=function(t){this.reset(),this.end(t)},e.prototype.write=function(t){this.tokenizer.write(t)},e.prototype.end=function(t){this.tokenizer.end(t)},e.prototype.pause=function(){this.tokenizer.pause()},e.prototype.resume=function(){this.tokenizer.resume()},e.prototype.parseChunk=function(t){this.write(t)},e.prototype.done=function(t){this.end(t)},e}();$s.Parser=_S;var hn={},Po={};(function(e){Object.defineProperty(e,"__esModule",{value:!0}),e.Doctype=e.CDATA=e.Tag=e.Style=e.Script=e.Comment=e.Directive=e.Text=e.Root=e.isTag=e.ElementType=void 0;var t;(function(r){r.Root="root",r.Text="text",r.Directive="directive",r.Comment="comment",r.Script="script",r.Style="style",r.Tag="tag",r.CDATA="cdata",r.Doctype="doctype"})(t=e.ElementType||(e.ElementType={}));function n(r){return r.type===t.Tag||r.type===t.Script||r.type===t.Style}e.isTag=n,e.Root=t.Root,e.Text=t.Text,e.Directive=t.Directive,e.Comment=t.Comment,e.Script=t.Script,e.Style=t.Style,e.Tag=t.Tag,e.CDATA=t.CDATA,e.Doctype=t.Doctype})(Po);var ze={},Hi=we&&we.__extends||function(){var e=function(t,n){return e=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(r,o){r.__proto__=o}||function(r,o){for(var u in o)Object.prototype.hasOwnProperty.call(o,u)&&(r[u]=o[u])},e(t,n)};return function(t,n){if(typeof n!="function"&&n!==null)throw new TypeError("Class extends value "+String(n)+" is not a constructor or null");e(t,n);function r(){this.constructor=t}t.prototype=n===null?Object.create(n):(r.prototype=n.prototype,new r)}}(),Us=we&&we.__assign||function(){return Us=Object.assign||function(e){for(var t,n=1,r=arguments.length;n<r;n++){t=arguments[n];for(var o in t)Object.prototype.hasOwnProperty.call(t,o)&&(e[o]=t[o])}return e},Us.apply(this,arguments)};Object.defineProperty(ze,"__esModule",{value:!0}),ze.cloneNode=ze.hasChildren=ze.isDocument=ze.isDirective=ze.isComment=ze.isText=ze.isCDATA=ze.isTag=ze.Element=ze.Document=ze.NodeWithChildren=ze.ProcessingInstruction=ze.Comment=ze.Text=ze.DataNode=ze.Node=void 0;var pt=Po,yS=new Map([[pt.ElementType.Tag,1],[pt.ElementType.Script,1],[pt.ElementType.Style,1],[pt.ElementType.Directive,1],[pt.ElementType.Text,3],[pt.ElementType.CDATA,4],[pt.ElementType.Comment,8],[pt.ElementType.Root,9]]),Pd=function(){function e(t){this.type=t,this.parent=null,this.prev=null,this.next=null,this.startIndex=null,this.endIndex=null}return Object.defineProperty(e.prototype,"nodeType",{get:function(){var t;return(t=yS.get(this.type))!==null&&t!==void 0?t:1},enumerable:!1,configurable:!0}),Object.defineProperty(e.prototype,"parentNode",{get:function(){return this.parent},set:function(t){this.parent=t},enumerable:!1,configurable:!0}),Object.defineProperty(e.prototype,"previousSibling",{get:function(){return this.prev},set:function(t){this.prev=t},enumerable:!1,configurable:!0}),Object.defineProperty(e.prototype,"nextSibling",{get:function(){return this.next},set:function(t){this.next=t},enumerable:!1,configurable:!0}),e.prototype.cloneNode=function(t){return t===void 0&&(t=!1),Dd(this,t)},e}();ze.Node=Pd;var Oa=function(e){Hi(t,e);function t(n,r){var o=e.call(this,n)||this;return o.data=r,o}return Object.defineProperty(t.prototype,"nodeValue",{get:function(){return this.data},set:function(n){this.data=n},enumerable:!1,configurable:!0}),t}(Pd);ze.DataNode=Oa;var Em=function(e){Hi(t,e);function t(n){return e.call(this,pt.ElementType.Text,n)||this}return t}(Oa);ze.Text=Em;var km=function(e){Hi(t,e);function t(n){return e.call(this,pt.ElementType.Comment,n)||this}return t}(Oa);ze.Comment=km;var Am=function(e){Hi(t,e);function t(n,r){var o=e.call(this,pt.ElementType.Directive,r)||this;return o.name=n,o}return t}(Oa);ze.ProcessingInstruction=Am;var Pa=function(e){Hi(t,e);function t(n,r){var o=e.call(this,n)||this;return o.children=r,o}return Object.defineProperty(t.prototype,"firstChild",{get:function(){var n;return(n=this.children[0])!==null&&n!==void 0?n:null},enumerable:!1,configurable:!0}),Object.defineProperty(t.prototype,"lastChild",{get:function(){return this.children.length>0?this.children[this.children.length-1]:null},enumerable:!1,configurable:!0}),Object.defineProperty(t.prototype,"childNodes",{get:function(){return this.children},set:function(n){this.children=n},enumerable:!1,configurable:!0}),t}(Pd);ze.NodeWithChildren=Pa;var Cm=function(e){Hi(t,e);function t(n){return e.call(this,pt.ElementType.Root,n)||this}return t}(Pa);ze.Document=Cm;var Tm=function(e){Hi(t,e);function t(n,r,o,u){o===void 0&&(o=[]),u===void 0&&(u=n==="script"?pt.ElementType.Script:n==="style"?pt.ElementType.Style:pt.ElementType.Tag);var c=e.call(this,u,o)||this;return c.name=n,c.attribs=r,c}return Object.defineProperty(t.prototype,"tagName",{get:function(){return this.name},set:function(n){this.name=n},enumerable:!1,configurable:!0}),Object.defineProperty(t.prototype,"attributes",{get:function(){var n=this;return Object.keys(this.attribs).map(function(r){var o,u;return{name:r,value:n.attribs[r],namespace:(o=n["x-attribsNamespace"])===null||o===void 0?void 0:o[r],prefix:(u=n["x-attribsPrefix"])===null||u===void 0?void 0:u[r]}})},enumerable:!1,configurable:!0}),t}(Pa);ze.Element=Tm;function xm(e){return(0,pt.isTag)(e)}ze.isTag=xm;function Lm(e){return e.type===pt.ElementType.CDATA}ze.isCDATA=Lm;function Om(e){return e.type===pt.ElementType.Text}ze.isText=Om;function Pm(e){return e.type===pt.ElementType.Comment}ze.isComment=Pm;function Dm(e){return e.type===pt.ElementType.Directive}ze.isDirective=Dm;function Nm(e){return e.type===pt.ElementType.Root}ze.isDocument=Nm;function wS(e){return Object.prototype.hasOwnProperty.call(e,"children")}ze.hasChildren=wS;function Dd(e,t){t===void 0&&(t=!1);var n;if(Om(e))n=new Em(e.data);else if(Pm(e))n=new km(e.data);else if(xm(e)){var r=t?Nd(e.children):[],o=new Tm(e.name,Us({},e.attribs),r);r.forEach(function(g){return g.parent=o}),e.namespace!=null&&(o.namespace=e.namespace),e["x-attribsNamespace"]&&(o["x-attribsNamespace"]=Us({},e["x-attribsNamespace"])),e["x-attribsPrefix"]&&(o["x-attribsPrefix"]=Us({},e["x-attribsPrefix"])),n=o}else if(Lm(e)){var r=t?Nd(e.children):[],u=new Pa(pt.ElementType.CDATA,r);r.forEach(function(b){return b.parent=u}),n=u}else if(Nm(e)){var r=t?Nd(e.children):[],c=new Cm(r);r.forEach(function(b){return b.parent=c}),e["x-mode"]&&(c["x-mode"]=e["x-mode"]),n=c}else if(Dm(e)){var p=new Am(e.name,e.data);e["x-name"]!=null&&(p["x-name"]=e["x-name"],p["x-publicId"]=e["x-publicId"],p["x-systemId"]=e["x-systemId"]),n=p}else throw new Error("Not implemented yet: ".concat(e.type));return n.startIndex=e.startIndex,n.endIndex=e.endIndex,e.sourceCodeLocation!=null&&(n.sourceCodeLocation=e.sourceCodeLocation),n}ze.cloneNode=Dd;function Nd(e){for(var t=e.map(function(r){return Dd(r,!0)}),n=1;n<t.length;n++)t[n].prev=t[n-1],t[n-1].next=t[n];return t}(function(e){var t=we&&we.__createBinding||(Object.create?function(g,b,O,P){P===void 0&&(P=O);var q=Object.getOwnPropertyDescriptor(b,O);(!q||("get"in q?!b.__esModule:q.writable||q.configurable))&&(q={enumerable:!0,get:function(){return b[O]}}),Object.defineProperty(g,P,q)}:function(g,b,O,P){P===void 0&&(P=O),g[P]=b[O]}),n=we&&we.__exportStar||function(g,b){for(var O in g)O!=="default"&&!Object.prototype.hasOwnProperty.call(b,O)&&t(b,g,O)};Object.defineProperty(e,"__esModule",{value:!0}),e.DomHandler=void 0;var r=Po,o=ze;n(ze,e);var u=/\s+/g,c={normalizeWhitespace:!1,withStartIndices:!1,withEndIndices:!1,xmlMode:!1},p=function(){function g(b,O,P){this.dom=[],this.root=new o.Document(this.dom),this.done=!1,this.tagStack=[this.root],this.lastNode=null,this.parser=null,typeof O=="function"&&(P=O,O=c),typeof b=="object"&&(O=b,b=void 0),this.callback=b??null,this.options=O??c,this.elementCB=P??null}return g.prototype.onparserinit=function(b){this.parser=b},g.prototype.onreset=function(){this.dom=[],this.root=new o.Document(this.dom),this.done=!1,this.tagStack=[this.root],this.lastNode=null,this.parser=null},g.prototype.onend=function(){this.done||(this.done=!0,this.parser=null,this.handleCallback(null))},g.prototype.onerror=function(b){this.handleCallback(b)},g.prototype.onclosetag=function(){this.lastNode=null;var b=this.tagStack.pop();this.options.withEndIndices&&(b.endIndex=this.parser.endIndex),this.elementCB&&this.elementCB(b)},g.prototype.onopentag=function(b,O){var P=this.options.xmlMode?r.ElementType.Tag:void 0,q=new o.Element(b,O,void 0,P);this.addNode(q),this.tagStack.push(q)},g.prototype.ontext=function(b){var O=this.options.normalizeWhitespace,P=this.lastNode;if(P&&P.type===r.ElementType.Text)O?P.data=(P.data+b).replace(u," "):P.data+=b,this.options.withEndIndices&&(P.endIndex=this.parser.endIndex);else{O&&(b=b.replace(u," "));var q=new o.Text(b);this.addNode(q),this.lastNode=q}},g.prototype.oncomment=function(b){if(this.lastNode&&this.lastNode.type===r.ElementType.Comment){this.lastNode.data+=b;return}var O=new o.Comment(b);this.addNode(O),this.lastNode=O},g.prototype.oncommentend=function(){this.lastNode=null},g.prototype.oncdatastart=function(){var b=new o.Text(""),O=new o.NodeWithChildren(r.ElementType.CDATA,[b]);this.addNode(O),b.parent=O,this.lastNode=b},g.prototype.oncdataend=function(){this.lastNode=null},g.prototype.onprocessinginstruction=function(b,O){var P=new o.ProcessingInstruction(b,O);this.addNode(P)},g.prototype.handleCallback=function(b){if(typeof this.callback=="function")this.callback(b,this.dom);else if(b)throw b},g.prototype.addNode=function(b){var O=this.tagStack[this.tagStack.length-1],P=O.children[O.children.length-1];this.options.withStartIndices&&(b.startIndex=this.parser.startIndex),this.options.withEndIndices&&(b.endIndex=this.parser.endIndex),O.children.push(b),P&&(b.prev=P,P.next=b),b.parent=O,this.lastNode=null},g}();e.DomHandler=p,e.default=p})(hn);var Gi={},Rd={},gn={},qd={},Rm={},Lr={},Da=we&&we.__importDefault||function(e){return e&&e.__esModule?e:{default:e}};Object.defineProperty(Lr,"__esModule",{value:!0}),Lr.decodeHTML=Lr.decodeHTMLStrict=Lr.decodeXML=void 0;var Id=Da(Td),bS=Da(_m),SS=Da(xd),qm=Da(xa),ES=/&(?:[a-zA-Z0-9]+|#[xX][\da-fA-F]+|#\d+);/g;Lr.decodeXML=Im(SS.default),Lr.decodeHTMLStrict=Im(Id.default);function Im(e){var t=Bm(e);return function(n){return String(n).replace(ES,t)}}var Mm=function(e,t){return e<t?1:-1};Lr.decodeHTML=function(){for(var e=Object.keys(bS.default).sort(Mm),t=Object.keys(Id.default).sort(Mm),n=0,r=0;n<t.length;n++)e[r]===t[n]?(t[n]+=";?",r++):t[n]+=";";var o=new RegExp("&(?:"+t.join("|")+"|#[xX][\\da-fA-F]+;?|#\\d+;?)","g"),u=Bm(Id.default);function c(p){return p.substr(-1)!==";"&&(p+=";"),u(p)}return function(p){return String(p).replace(o,c)}}();function Bm(e){return function(n){if(n.charAt(1)==="#"){var r=n.charAt(2);return r==="X"||r==="x"?qm.default(parseInt(n.substr(3),16)):qm.default(parseInt(n.substr(2),10))}return e[n.slice(1,-1)]||n}}var mn={},zm=we&&we.__importDefault||function(e){return e&&e.__esModule?e:{default:e}};Object.defineProperty(mn,"__esModule",{value:!0}),mn.escapeUTF8=mn.escape=mn.encodeNonAsciiHTML=mn.encodeHTML=mn.encodeXML=void 0;var kS=zm(xd),$m=jm(kS.default),Um=Fm($m);mn.encodeXML=Gm($m);var AS=zm(Td),Md=jm(AS.default),CS=Fm(Md);mn.encodeHTML=xS(Md,CS),mn.encodeNonAsciiHTML=Gm(Md);function jm(e){return Object.keys(e).sort().reduce(function(t,n){return t[e[n]]="&"+n+";",t},{})}function Fm(e){for(var t=[],n=[],r=0,o=Object.keys(e);r<o.length;r++){var u=o[r];u.length===1?t.push("\\"+u):n.push(u)}t.sort();for(var c=0;c<t.length-1;c++){for(var p=c;p<t.length-1&&t[p].charCodeAt(1)+1===t[p+1].charCodeAt(1);)p+=1;var g=1+p-c;g<3||t.splice(c,g,t[c]+"-"+t[p])}return n.unshift("["+t.join("")+"]"),new RegExp(n.join("|"),"g")}var Vm=/(?:[\x80-\uD7FF\uE000-\uFFFF]|[\uD800-\uDBFF][\uDC00-\uDFFF]|[\uD800-\uDBFF](?![\uDC00-\uDFFF])|(?:[^\uD800-\uDBFF]|^)[\uDC00-\uDFFF])/g,TS=String.prototype.codePointAt!=null?function(e){return e.codePointAt(0)}:function(e){return(e.charCodeAt(0)-55296)*1024+e.charCodeAt(1)-56320+65536};function Na(e){return"&#x"+(e.length>1?TS(e):e.charCodeAt(0)).toString(16).toUpperCase()+";"}function xS(e,t){return function(n){return n.replace(t,function(r){return e[r]}).replace(Vm,Na)}}var Hm=new RegExp(Um.source+"|"+Vm.source,"g");function LS(e){return e.replace(Hm,Na)}mn.escape=LS;function OS(e){return e.replace(Um,Na)}mn.escapeUTF8=OS;function Gm(e){return function(t){return t.replace(Hm,function(n){return e[n]||Na(n)})}}(function(e){Object.defineProperty(e,"__esModule",{value:!0}),e.decodeXMLStrict=e.decodeHTML5Strict=e.decodeHTML4Strict=e.decodeHTML5=e.decodeHTML4=e.decodeHTMLStrict=e.decodeHTML=e.decodeXML=e.encodeHTML5=e.encodeHTML4=e.escapeUTF8=e.escape=e.encodeNonAsciiHTML=e.encodeHTML=e.encodeXML=e.encode=e.decodeStrict=e.decode=void 0;var t=Lr,n=mn;function r(g,b){return(!b||b<=0?t.decodeXML:t.decodeHTML)(g)}e.decode=r;function o(g,b){return(!b||b<=0?t.decodeXML:t.decodeHTMLStrict)(g)}e.decodeStrict=o;function u(g,b){return(!b||b<=0?n.encodeXML:n.encodeHTML)(g)}e.encode=u;var c=mn;Object.defineProperty(e,"encodeXML",{enumerable:!0,get:function(){return c.encodeXML}}),Object.defineProperty(e,"encodeHTML",{enumerable:!0,get:function(){return c.encodeHTML}}),Object.defineProperty(e,"encodeNonAsciiHTML",{enumerable:!0,get:function(){return c.encodeNonAsciiHTML}}),Object.defineProperty(e,"escape",{enumerable:!0,get:function(){return c.escape}}),Object.defineProperty(e,"escapeUTF8",{enumerable:!0,get:function(){return c.escapeUTF8}}),Object.defineProperty(e,"encodeHTML4",{enumerable:!0,get:function(){return c.encodeHTML}}),Object.defineProperty(e,"encodeHTML5",{enumerable:!0,get:function(){return c.encodeHTML}});var p=Lr;Object.defineProperty(e,"decodeXML",{enumerable:!0,get:function(){return p.decodeXML}}),Object.defineProperty(e,"decodeHTML",{enumerable:!0,get:function(){return p.decodeHTML}}),Object.defineProperty(e,"decodeHTMLStrict",{enumerable:!0,get:function(){return p.decodeHTMLStrict}}),Object.defineProperty(e,"decodeHTML4",{enumerable:!0,get:function(){return p.decodeHTML}}),Object.defineProperty(e,"decodeHTML5",{enumerable:!0,get:function(){return p.decodeHTML}}),Object.defineProperty(e,"decodeHTML4Strict",{enumerable:!0,get:function(){return p.decodeHTMLStrict}}),Object.defineProperty(e,"decodeHTML5Strict",{enumerable:!0,get:function(){return p.decodeHTMLStrict}}),Object.defineProperty(e,"decodeXMLStrict",{enumerable:!0,get:function(){return p.decodeXML}})})(Rm);var Do={};Object.defineProperty(Do,"__esModule",{value:!0}),Do.attributeNames=Do.elementNames=void 0,Do.elementNames=new Map([["altglyph","altGlyph"],["altglyphdef","altGlyphDef"],["altglyphitem","altGlyphItem"],["animatecolor","animateColor"],["animatemotion","animateMotion"],["animatetransform","animateTransform"],["clippath","clipPath"],["feblend","feBlend"],["fecolormatrix","feColorMatrix"],["fecomponenttransfer","feComponentTransfer"],["fecomposite","feComposite"],["feconvolvematrix","feConvolveMatrix"],["fediffuselighting","feDiffuseLighting"],["fedisplacementmap","feDisplacementMap"],["fedistantlight","feDistantLight"],["fedropshadow","feDropShadow"],["feflood","feFlood"],["fefunca","feFuncA"],["fefuncb","feFuncB"],["fefuncg","feFuncG"],["fefuncr","feFuncR"],["fegaussianblur","feGaussianBlur"],["feimage","feImage"],["femerge","feMerge"],["femergenode","feMergeNode"],["femorphology","feMorphology"],["feoffset","feOffset"],["fepointlight","fePointLight"],["fespecularlighting","feSpecularLighting"],["fespotlight","feSpotLight"],["fetile","feTile"],["feturbulence","feTurbulence"],["foreignobject","foreignObject"],["glyphref","glyphRef"],["lineargradient","linearGradient"],["radialgradient","radialGradient"],["textpath","textPath"]]),Do.attributeNames=new Map([["definitionurl","definitionURL"],["attributename","attributeName"],["attributetype","attributeType"],["basefrequency","baseFrequency"],["baseprofile","baseProfile"],["calcmode","calcMode"],["clippathunits","clipPathUnits"],["diffuseconstant","diffuseConstant"],["edgemode","edgeMode"],["filterunits","filterUnits"],["glyphref","glyphRef"],["gradienttransform","gradientTransform"],["gradientunits","gradientUnits"],["kernelmatrix","kernelMatrix"],["kernelunitlength","kernelUnitLength"],["keypoints","keyPoints"],["keysplines","keySplines"],["keytimes","keyTimes"],["lengthadjust","lengthAdjust"],["limitingconeangle","limitingConeAngle"],["markerheight","markerHeight"],["markerunits","markerUnits"],["markerwidth","markerWidth"],["maskcontentunits","maskContentUnits"],["maskunits","maskUnits"],["numoctaves","numOctaves"],["pathlength","pathLength"],["patterncontentunits","patternContentUnits"],["patterntransform","patternTransform"],["patternunits","patternUnits"],["pointsatx","pointsAtX"],["pointsaty","pointsAtY"],["pointsatz","pointsAtZ"],["preservealpha","preserveAlpha"],["preserveaspectratio","preserveAspectRatio"],["primitiveunits","primitiveUnits"],["refx","refX"],["refy","refY"],["repeatcount","repeatCount"],["repeatdur","repeatDur"],["requiredextensions","requiredExtensions"],["requiredfeatures","requiredFeatures"],["specularconstant","specularConstant"],["specularexponent","specularExponent"],["spreadmethod","spreadMethod"],["startoffset","startOffset"],["stddeviation","stdDeviation"],["stitchtiles","stitchTiles"],["surfacescale","surfaceScale"],["systemlanguage","systemLanguage"],["tablevalues","tableValues"],["targetx","targetX"],["targety","targetY"],["textlength","textLength"],["viewbox","viewBox"],["viewtarget","viewTarget"],["xchannelselector","xChannelSelector"],["ychannelselector","yChannelSelector"],["zoomandpan","zoomAndPan"]]);var No=we&&we.__assign||function(){return No=Object.assign||function(e){for(var t,n=1,r=arguments.length;n<r;n++){t=arguments[n];for(var o in t)Object.prototype.hasOwnProperty.call(t,o)&&(e[o]=t[o])}return e},No.apply(this,arguments)},PS=we&&we.__createBinding||(Object.create?function(e,t,n,r){r===void 0&&(r=n),Object.defineProperty(e,r,{enumerable:!0,get:function(){return t[n]}})}:function(e,t,n,r){r===void 0&&(r=n),e[r]=t[n]}),DS=we&&we.__setModuleDefault||(Object.create?function(e,t){Object.defineProperty(e,"default",{enumerable:!0,value:t})}:function(e,t){e.default=t}),NS=we&&we.__importStar||function(e){if(e&&e.__esModule)return e;var t={};if(e!=null)for(var n in e)n!=="default"&&Object.prototype.hasOwnProperty.call(e,n)&&PS(t,e,n);return DS(t,e),t};Object.defineProperty(qd,"__esModule",{value:!0});var Or=NS(Po),Wm=Rm,Km=Do,RS=new Set(["style","script","xmp","iframe","noembed","noframes","plaintext","noscript"]);function qS(e,t){if(e)return Object.keys(e).map(function(n){var r,o,u=(r=e[n])!==null&&r!==void 0?r:"";return t.xmlMode==="foreign"&&(n=(o=Km.attributeNames.get(n))!==null&&o!==void 0?o:n),!t.emptyAttrs&&!t.xmlMode&&u===""?n:n+'="'+(t.decodeEntities!==!1?Wm.encodeXML(u):u.replace(/"/g,"&quot;"))+'"'}).join(" ")}var Ym=new Set(["area","base","basefont","br","col","command","embed","frame","hr","img","input","isindex","keygen","link","meta","param","source","track","wbr"]);function Bd(e,t){t===void 0&&(t={});for(var n=("length"in e)?e:[e],r="",o=0;o<n.length;o++)r+=IS(n[o],t);return r}qd.default=Bd;function IS(e,t){switch(e.type){case Or.Root:return Bd(e.children,t);case Or.Directive:case Or.Doctype:return $S(e);case Or.Comment:return FS(e);case Or.CDATA:return jS(e);case Or.Script:case Or.Style:case Or.Tag:return zS(e,t);case Or.Text:return US(e,t)}}var MS=new Set(["mi","mo","mn","ms","mtext","annotation-xml","foreignObject","desc","title"]),BS=new Set(["svg","math"]);function zS(e,t){var n;t.xmlMode==="foreign"&&(e.name=(n=Km.elementNames.get(e.name))!==null&&n!==void 0?n:e.name,e.parent&&MS.has(e.parent.name)&&(t=No(No({},t),{xmlMode:!1}))),!t.xmlMode&&BS.has(e.name)&&(t=No(No({},t),{xmlMode:"foreign"}));var r="<"+e.name,o=qS(e.attribs,t);return o&&(r+=" "+o),e.children.length===0&&(t.xmlMode?t.selfClosingTags!==!1:t.selfClosingTags&&Ym.has(e.name))?(t.xmlMode||(r+=" "),r+="/>"):(r+=">",e.children.length>0&&(r+=Bd(e.children,t)),(t.xmlMode||!Ym.has(e.name))&&(r+="</"+e.name+">")),r}function $S(e){return"<"+e.data+">"}function US(e,t){var n=e.data||"";return t.decodeEntities!==!1&&!(!t.xmlMode&&e.parent&&RS.has(e.parent.name))&&(n=Wm.encodeXML(n)),n}function jS(e){return"<![CDATA["+e.children[0].data+"]]>"}function FS(e){return"<!--"+e.data+"-->"}var VS=we&&we.__importDefault||function(e){return e&&e.__esModule?e:{default:e}};Object.defineProperty(gn,"__esModule",{value:!0}),gn.innerText=gn.textContent=gn.getText=gn.getInnerHTML=gn.getOuterHTML=void 0;var gr=hn,HS=VS(qd),GS=Po;function Qm(e,t){return(0,HS.default)(e,t)}gn.getOuterHTML=Qm;function WS(e,t){return(0,gr.hasChildren)(e)?e.children.map(function(n){return Qm(n,t)}).join(""):""}gn.getInnerHTML=WS;function Ra(e){return Array.isArray(e)?e.map(Ra).join(""):(0,gr.isTag)(e)?e.name==="br"?`
-`:Ra(e.children):(0,gr.isCDATA)(e)?Ra(e.children):(0,gr.isText)(e)?e.data:""}gn.getText=Ra;function zd(e){return Array.isArray(e)?e.map(zd).join(""):(0,gr.hasChildren)(e)&&!(0,gr.isComment)(e)?zd(e.children):(0,gr.isText)(e)?e.data:""}gn.textContent=zd;function $d(e){return Array.isArray(e)?e.map($d).join(""):(0,gr.hasChildren)(e)&&(e.type===GS.ElementType.Tag||(0,gr.isCDATA)(e))?$d(e.children):(0,gr.isText)(e)?e.data:""}gn.innerText=$d;var Lt={};Object.defineProperty(Lt,"__esModule",{value:!0}),Lt.prevElementSibling=Lt.nextElementSibling=Lt.getName=Lt.hasAttrib=Lt.getAttributeValue=Lt.getSiblings=Lt.getParent=Lt.getChildren=void 0;var Xm=hn,KS=[];function Zm(e){var t;return(t=e.children)!==null&&t!==void 0?t:KS}Lt.getChildren=Zm;function Jm(e){return e.parent||null}Lt.getParent=Jm;function YS(e){var t,n,r=Jm(e);if(r!=null)return Zm(r);for(var o=[e],u=e.prev,c=e.next;u!=null;)o.unshift(u),t=u,u=t.prev;for(;c!=null;)o.push(c),n=c,c=n.next;return o}Lt.getSiblings=YS;function QS(e,t){var n;return(n=e.attribs)===null||n===void 0?void 0:n[t]}Lt.getAttributeValue=QS;function XS(e,t){return e.attribs!=null&&Object.prototype.hasOwnProperty.call(e.attribs,t)&&e.attribs[t]!=null}Lt.hasAttrib=XS;function ZS(e){return e.name}Lt.getName=ZS;function JS(e){for(var t,n=e.next;n!==null&&!(0,Xm.isTag)(n);)t=n,n=t.next;return n}Lt.nextElementSibling=JS;function eE(e){for(var t,n=e.prev;n!==null&&!(0,Xm.isTag)(n);)t=n,n=t.prev;return n}Lt.prevElementSibling=eE;var en={};Object.defineProperty(en,"__esModule",{value:!0}),en.prepend=en.prependChild=en.append=en.appendChild=en.replaceElement=en.removeElement=void 0;function js(e){if(e.prev&&(e.prev.next=e.next),e.next&&(e.next.prev=e.prev),e.parent){var t=e.parent.children;t.splice(t.lastIndexOf(e),1)}}en.removeElement=js;function tE(e,t){var n=t.prev=e.prev;n&&(n.next=t);var r=t.next=e.next;r&&(r.prev=t);var o=t.parent=e.parent;if(o){var u=o.children;u[u.lastIndexOf(e)]=t}}en.replaceElement=tE;function nE(e,t){if(js(t),t.next=null,t.parent=e,e.children.push(t)>1){var n=e.children[e.children.length-2];n.next=t,t.prev=n}else t.prev=null}en.appendChild=nE;function rE(e,t){js(t);var n=e.parent,r=e.next;if(t.next=r,t.prev=e,e.next=t,t.parent=n,r){if(r.prev=t,n){var o=n.children;o.splice(o.lastIndexOf(r),0,t)}}else n&&n.children.push(t)}en.append=rE;function iE(e,t){if(js(t),t.parent=e,t.prev=null,e.children.unshift(t)!==1){var n=e.children[1];n.prev=t,t.next=n}else t.next=null}en.prependChild=iE;function oE(e,t){js(t);var n=e.parent;if(n){var r=n.children;r.splice(r.indexOf(e),0,t)}e.prev&&(e.prev.next=t),t.parent=n,t.prev=e.prev,t.next=e,e.prev=t}en.prepend=oE;var Wt={};Object.defineProperty(Wt,"__esModule",{value:!0}),Wt.findAll=Wt.existsOne=Wt.findOne=Wt.findOneChild=Wt.find=Wt.filter=void 0;var Fs=hn;function sE(e,t,n,r){return n===void 0&&(n=!0),r===void 0&&(r=1/0),Array.isArray(t)||(t=[t]),Ud(e,t,n,r)}Wt.filter=sE;function Ud(e,t,n,r){for(var o=[],u=0,c=t;u<c.length;u++){var p=c[u];if(e(p)&&(o.push(p),--r<=0))break;if(n&&(0,Fs.hasChildren)(p)&&p.children.length>0){var g=Ud(e,p.children,n,r);if(o.push.apply(o,g),r-=g.length,r<=0)break}}return o}Wt.find=Ud;function uE(e,t){return t.find(e)}Wt.findOneChild=uE;function ev(e,t,n){n===void 0&&(n=!0);for(var r=null,o=0;o<t.length&&!r;o++){var u=t[o];if((0,Fs.isTag)(u))e(u)?r=u:n&&u.children.length>0&&(r=ev(e,u.children));else continue}return r}Wt.findOne=ev;function tv(e,t){return t.some(function(n){return(0,Fs.isTag)(n)&&(e(n)||n.children.length>0&&tv(e,n.children))})}Wt.existsOne=tv;function aE(e,t){for(var n,r=[],o=t.filter(Fs.isTag),u;u=o.shift();){var c=(n=u.children)===null||n===void 0?void 0:n.filter(Fs.isTag);c&&c.length>0&&o.unshift.apply(o,c),e(u)&&r.push(u)}return r}Wt.findAll=aE;var vn={};Object.defineProperty(vn,"__esModule",{value:!0}),vn.getElementsByTagType=vn.getElementsByTagName=vn.getElementById=vn.getElements=vn.testElement=void 0;var Wi=hn,qa=Wt,Ia={tag_name:function(e){return typeof e=="function"?function(t){return(0,Wi.isTag)(t)&&e(t.name)}:e==="*"?Wi.isTag:function(t){return(0,Wi.isTag)(t)&&t.name===e}},tag_type:function(e){return typeof e=="function"?function(t){return e(t.type)}:function(t){return t.type===e}},tag_contains:function(e){return typeof e=="function"?function(t){return(0,Wi.isText)(t)&&e(t.data)}:function(t){return(0,Wi.isText)(t)&&t.data===e}}};function nv(e,t){return typeof t=="function"?function(n){return(0,Wi.isTag)(n)&&t(n.attribs[e])}:function(n){return(0,Wi.isTag)(n)&&n.attribs[e]===t}}function lE(e,t){return function(n){return e(n)||t(n)}}function rv(e){var t=Object.keys(e).map(function(n){var r=e[n];return Object.prototype.hasOwnProperty.call(Ia,n)?Ia[n](r):nv(n,r)});return t.length===0?null:t.reduce(lE)}function cE(e,t){var n=rv(e);return n?n(t):!0}vn.testElement=cE;function dE(e,t,n,r){r===void 0&&(r=1/0);var o=rv(e);return o?(0,qa.filter)(o,t,n,r):[]}vn.getElements=dE;function fE(e,t,n){return n===void 0&&(n=!0),Array.isArray(t)||(t=[t]),(0,qa.findOne)(nv("id",e),t,n)}vn.getElementById=fE;function pE(e,t,n,r){return n===void 0&&(n=!0),r===void 0&&(r=1/0),(0,qa.filter)(Ia.tag_name(e),t,n,r)}vn.getElementsByTagName=pE;function hE(e,t,n,r){return n===void 0&&(n=!0),r===void 0&&(r=1/0),(0,qa.filter)(Ia.tag_type(e),t,n,r)}vn.getElementsByTagType=hE;var ni={};Object.defineProperty(ni,"__esModule",{value:!0}),ni.uniqueSort=ni.compareDocumentPosition=ni.removeSubsets=void 0;var iv=hn;function gE(e){for(var t=e.length;--t>=0;){var n=e[t];if(t>0&&e.lastIndexOf(n,t-1)>=0){e.splice(t,1);continue}for(var r=n.parent;r;r=r.parent)if(e.includes(r)){e.splice(t,1);break}}return e}ni.removeSubsets=gE;function ov(e,t){var n=[],r=[];if(e===t)return 0;for(var o=(0,iv.hasChildren)(e)?e:e.parent;o;)n.unshift(o),o=o.parent;for(o=(0,iv.hasChildren)(t)?t:t.parent;o;)r.unshift(o),o=o.parent;for(var u=Math.min(n.length,r.length),c=0;c<u&&n[c]===r[c];)c++;if(c===0)return 1;var p=n[c-1],g=p.children,b=n[c],O=r[c];return g.indexOf(b)>g.indexOf(O)?p===t?20:4:p===e?10:2}ni.compareDocumentPosition=ov;function mE(e){return e=e.filter(function(t,n,r){return!r.includes(t,n+1)}),e.sort(function(t,n){var r=ov(t,n);return r&2?-1:r&4?1:0}),e}ni.uniqueSort=mE;var Ma={};Object.defineProperty(Ma,"__esModule",{value:!0}),Ma.getFeed=void 0;var vE=gn,Vs=vn;function _E(e){var t=Ba(EE,e);return t?t.name==="feed"?yE(t):wE(t):null}Ma.getFeed=_E;function yE(e){var t,n=e.children,r={type:"atom",items:(0,Vs.getElementsByTagName)("entry",n).map(function(c){var p,g=c.children,b={media:sv(g)};tn(b,"id","id",g),tn(b,"title","title",g);var O=(p=Ba("link",g))===null||p===void 0?void 0:p.attribs.href;O&&(b.link=O);var P=Ki("summary",g)||Ki("content",g);P&&(b.description=P);var q=Ki("updated",g);return q&&(b.pubDate=new Date(q)),b})};tn(r,"id","id",n),tn(r,"title","title",n);var o=(t=Ba("link",n))===null||t===void 0?void 0:t.attribs.href;o&&(r.link=o),tn(r,"description","subtitle",n);var u=Ki("updated",n);return u&&(r.updated=new Date(u)),tn(r,"author","email",n,!0),r}function wE(e){var t,n,r=(n=(t=Ba("channel",e.children))===null||t===void 0?void 0:t.children)!==null&&n!==void 0?n:[],o={type:e.name.substr(0,3),id:"",items:(0,Vs.getElementsByTagName)("item",e.children).map(function(c){var p=c.children,g={media:sv(p)};tn(g,"id","guid",p),tn(g,"title","title",p),tn(g,"link","link",p),tn(g,"description","description",p);var b=Ki("pubDate",p);return b&&(g.pubDate=new Date(b)),g})};tn(o,"title","title",r),tn(o,"link","link",r),tn(o,"description","description",r);var u=Ki("lastBuildDate",r);return u&&(o.updated=new Date(u)),tn(o,"author","managingEditor",r,!0),o}var bE=["url","type","lang"],SE=["fileSize","bitrate","framerate","samplingrate","channels","duration","height","width"];function sv(e){return(0,Vs.getElementsByTagName)("media:content",e).map(function(t){for(var n=t.attribs,r={medium:n.medium,isDefault:!!n.isDefault},o=0,u=bE;o<u.length;o++){var c=u[o];n[c]&&(r[c]=n[c])}for(var p=0,g=SE;p<g.length;p++){var c=g[p];n[c]&&(r[c]=parseInt(n[c],10))}return n.expression&&(r.expression=n.expression),r})}function Ba(e,t){return(0,Vs.getElementsByTagName)(e,t,!0,1)[0]}function Ki(e,t,n){return n===void 0&&(n=!1),(0,vE.textContent)((0,Vs.getElementsByTagName)(e,t,n,1)).trim()}function tn(e,t,n,r,o){o===void 0&&(o=!1);var u=Ki(n,r,o);u&&(e[t]=u)}function EE(e){return e==="rss"||e==="feed"||e==="rdf:RDF"}(function(e){var t=we&&we.__createBinding||(Object.create?function(o,u,c,p){p===void 0&&(p=c),Object.defineProperty(o,p,{enumerable:!0,get:function(){return u[c]}})}:function(o,u,c,p){p===void 0&&(p=c),o[p]=u[c]}),n=we&&we.__exportStar||function(o,u){for(var c in o)c!=="default"&&!Object.prototype.hasOwnProperty.call(u,c)&&t(u,o,c)};Object.defineProperty(e,"__esModule",{value:!0}),e.hasChildren=e.isDocument=e.isComment=e.isText=e.isCDATA=e.isTag=void 0,n(gn,e),n(Lt,e),n(en,e),n(Wt,e),n(vn,e),n(ni,e),n(Ma,e);var r=hn;Object.defineProperty(e,"isTag",{enumerable:!0,get:function(){return r.isTag}}),Object.defineProperty(e,"isCDATA",{enumerable:!0,get:function(){return r.isCDATA}}),Object.defineProperty(e,"isText",{enumerable:!0,get:function(){return r.isText}}),Object.defineProperty(e,"isComment",{enumerable:!0,get:function(){return r.isComment}}),Object.defineProperty(e,"isDocument",{enumerable:!0,get:function(){return r.isDocument}}),Object.defineProperty(e,"hasChildren",{enumerable:!0,get:function(){return r.hasChildren}})})(Rd);var kE=we&&we.__extends||function(){var e=function(t,n){return e=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(r,o){r.__proto__=o}||function(r,o){for(var u in o)Object.prototype.hasOwnProperty.call(o,u)&&(r[u]=o[u])},e(t,n)};return function(t,n){if(typeof n!="function"&&n!==null)throw new TypeError("Class extends value "+String(n)+" is not a constructor or null");e(t,n);function r(){this.constructor=t}t.prototype=n===null?Object.create(n):(r.prototype=n.prototype,new r)}}(),AE=we&&we.__createBinding||(Object.create?function(e,t,n,r){r===void 0&&(r=n),Object.defineProperty(e,r,{enumerable:!0,get:function(){return t[n]}})}:function(e,t,n,r){r===void 0&&(r=n),e[r]=t[n]}),CE=we&&we.__setModuleDefault||(Object.create?function(e,t){Object.defineProperty(e,"default",{enumerable:!0,value:t})}:function(e,t){e.default=t}),TE=we&&we.__importStar||function(e){if(e&&e.__esModule)return e;var t={};if(e!=null)for(var n in e)n!=="default"&&Object.prototype.hasOwnProperty.call(e,n)&&AE(t,e,n);return CE(t,e),t},xE=we&&we.__importDefault||function(e){return e&&e.__esModule?e:{default:e}};Object.defineProperty(Gi,"__esModule",{value:!0}),Gi.parseFeed=Gi.FeedHandler=void 0;var LE=xE(hn),za=TE(Rd),OE=$s,uv;(function(e){e[e.image=0]="image",e[e.audio=1]="audio",e[e.video=2]="video",e[e.document=3]="document",e[e.executable=4]="executable"})(uv||(uv={}));var av;(function(e){e[e.sample=0]="sample",e[e.full=1]="full",e[e.nonstop=2]="nonstop"})(av||(av={}));var lv=function(e){kE(t,e);function t(n,r){var o=this;return typeof n=="object"&&(n=void 0,r=n),o=e.call(this,n,r)||this,o}return t.prototype.onend=function(){var n,r,o=$a(PE,this.dom);if(!o){this.handleCallback(new Error("couldn't find root of feed"));return}var u={};if(o.name==="feed"){var c=o.children;u.type="atom",nn(u,"id","id",c),nn(u,"title","title",c);var p=dv("href",$a("link",c));p&&(u.link=p),nn(u,"description","subtitle",c);var g=Yi("updated",c);g&&(u.updated=new Date(g)),nn(u,"author","email",c,!0),u.items=jd("entry",c).map(function(b){var O={},P=b.children;nn(O,"id","id",P),nn(O,"title","title",P);var q=dv("href",$a("link",P));q&&(O.link=q);var Z=Yi("summary",P)||Yi("content",P);Z&&(O.description=Z);var M=Yi("updated",P);return M&&(O.pubDate=new Date(M)),O.media=cv(P),O})}else{var c=(r=(n=$a("channel",o.children))===null||n===void 0?void 0:n.children)!==null&&r!==void 0?r:[];u.type=o.name.substr(0,3),u.id="",nn(u,"title","title",c),nn(u,"link","link",c),nn(u,"description","description",c);var g=Yi("lastBuildDate",c);g&&(u.updated=new Date(g)),nn(u,"author","managingEditor",c,!0),u.items=jd("item",o.children).map(function(P){var q={},Z=P.children;nn(q,"id","guid",Z),nn(q,"title","title",Z),nn(q,"link","link",Z),nn(q,"description","description",Z);var M=Yi("pubDate",Z);return M&&(q.pubDate=new Date(M)),q.media=cv(Z),q})}this.feed=u,this.handleCallback(null)},t}(LE.default);Gi.FeedHandler=lv;function cv(e){return jd("media:content",e).map(function(t){var n={medium:t.attribs.medium,isDefault:!!t.attribs.isDefault};return t.attribs.url&&(n.url=t.attribs.url),t.attribs.fileSize&&(n.fileSize=parseInt(t.attribs.fileSize,10)),t.attribs.type&&(n.type=t.attribs.type),t.attribs.expression&&(n.expression=t.attribs.expression),t.attribs.bitrate&&(n.bitrate=parseInt(t.attribs.bitrate,10)),t.attribs.framerate&&(n.framerate=parseInt(t.attribs.framerate,10)),t.attribs.samplingrate&&(n.samplingrate=parseInt(t.attribs.samplingrate,10)),t.attribs.channels&&(n.channels=parseInt(t.attribs.channels,10)),t.attribs.duration&&(n.duration=parseInt(t.attribs.duration,10)),t.attribs.height&&(n.height=parseInt(t.attribs.height,10)),t.attribs.width&&(n.width=parseInt(t.attribs.width,10)),t.attribs.lang&&(n.lang=t.attribs.lang),n})}function jd(e,t){return za.getElementsByTagName(e,t,!0)}function $a(e,t){return za.getElementsByTagName(e,t,!0,1)[0]}function Yi(e,t,n){return n===void 0&&(n=!1),za.getText(za.getElementsByTagName(e,t,n,1)).trim()}function dv(e,t){if(!t)return null;var n=t.attribs;return n[e]}function nn(e,t,n,r,o){o===void 0&&(o=!1);var u=Yi(n,r,o);u&&(e[t]=u)}function PE(e){return e==="rss"||e==="feed"||e==="rdf:RDF"}function DE(e,t){t===void 0&&(t={xmlMode:!0});var n=new lv(t);return new OE.Parser(n,t).end(e),n.feed}Gi.parseFeed=DE,function(e){var t=we&&we.__createBinding||(Object.create?function(M,H,z,L){L===void 0&&(L=z),Object.defineProperty(M,L,{enumerable:!0,get:function(){return H[z]}})}:function(M,H,z,L){L===void 0&&(L=z),M[L]=H[z]}),n=we&&we.__setModuleDefault||(Object.create?function(M,H){Object.defineProperty(M,"default",{enumerable:!0,value:H})}:function(M,H){M.default=H}),r=we&&we.__importStar||function(M){if(M&&M.__esModule)return M;var H={};if(M!=null)for(var z in M)z!=="default"&&Object.prototype.hasOwnProperty.call(M,z)&&t(H,M,z);return n(H,M),H},o=we&&we.__exportStar||function(M,H){for(var z in M)z!=="default"&&!Object.prototype.hasOwnProperty.call(H,z)&&t(H,M,z)},u=we&&we.__importDefault||function(M){return M&&M.__esModule?M:{default:M}};Object.defineProperty(e,"__esModule",{value:!0}),e.RssHandler=e.DefaultHandler=e.DomUtils=e.ElementType=e.Tokenizer=e.createDomStream=e.parseDOM=e.parseDocument=e.DomHandler=e.Parser=void 0;var c=$s;Object.defineProperty(e,"Parser",{enumerable:!0,get:function(){return c.Parser}});var p=hn;Object.defineProperty(e,"DomHandler",{enumerable:!0,get:function(){return p.DomHandler}}),Object.defineProperty(e,"DefaultHandler",{enumerable:!0,get:function(){return p.DomHandler}});function g(M,H){var z=new p.DomHandler(void 0,H);return new c.Parser(z,H).end(M),z.root}e.parseDocument=g;function b(M,H){return g(M,H).children}e.parseDOM=b;function O(M,H,z){var L=new p.DomHandler(M,H,z);return new c.Parser(L,H)}e.createDomStream=O;var P=Ta;Object.defineProperty(e,"Tokenizer",{enumerable:!0,get:function(){return u(P).default}});var q=r(Po);e.ElementType=q,o(Gi,e),e.DomUtils=r(Rd);var Z=Gi;Object.defineProperty(e,"RssHandler",{enumerable:!0,get:function(){return Z.FeedHandler}})}(mm);function Ua(){return Ua=Object.assign||function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e},Ua.apply(this,arguments)}function NE(e,t){if(e==null)return{};var n={},r=Object.keys(e),o,u;for(u=0;u<r.length;u++)o=r[u],!(t.indexOf(o)>=0)&&(n[o]=e[o]);return n}function RE(e,t){if(e){if(typeof e=="string")return fv(e,t);var n=Object.prototype.toString.call(e).slice(8,-1);if(n==="Object"&&e.constructor&&(n=e.constructor.name),n==="Map"||n==="Set")return Array.from(e);if(n==="Arguments"||/^(?:Ui|I)nt(?:8|16|32)(?:Clamped)?Array$/.test(n))return fv(e,t)}}function fv(e,t){(t==null||t>e.length)&&(t=e.length);for(var n=0,r=new Array(t);n<t;n++)r[n]=e[n];return r}function qE(e,t){var n;if(typeof Symbol>"u"||e[Symbol.iterator]==null){if(Array.isArray(e)||(n=RE(e))||t&&e&&typeof e.length=="number"){n&&(e=n);var r=0;return function(){return r>=e.length?{done:!0}:{done:!1,value:e[r++]}}}throw new TypeError(`Invalid attempt to iterate non-iterable instance.
-In order to be iterable, non-array objects must have a [Symbol.iterator]() method.`)}return n=e[Symbol.iterator](),n.next.bind(n)}function IE(e){var t=e.dangerouslySetInnerHTML,n=e.flavor,r=e.markdown,o=e.markup,u=e.options,c=e.extensions,p=e.components,g=e.sanitizeHtml,b=NE(e,["dangerouslySetInnerHTML","flavor","markdown","markup","options","extensions","components","sanitizeHtml"]),O=Ee.useMemo(function(){return function k(x,l){if(x.type==="tag"&&x instanceof hn.Element){var h=(p==null?void 0:p[x.name])||x.name,d=Ua({key:l},x.attribs);if(d.class&&!d.className&&(d.className=d.class,delete d.class),typeof d.style=="string"){var _={};d.style.split(";").forEach(function(E){if(E.indexOf(":")!==-1){var D=E.split(":"),j=D[0],V=D[1];j=j.trim().replace(/-([a-z])/g,function(W){return W[1].toUpperCase()}),V=V.trim(),_[j]=V}}),d.style=_}var v=ME.includes(x.name)?null:BE.includes(x.name)?x.children.filter(zE).map(k):x.children.map(k);return Ee.createElement(h,d,v)}else{if(x.type==="text"&&x instanceof hn.DataNode)return x.data;if(x.type==="comment")return null;if(x.type==="style"&&x instanceof hn.Element){var y=Ua({key:l},x.attribs),m=x.children.map(k);return Ee.createElement("style",y,m)}else return console.warn('Warning: Could not map element with type "'+x.type+'".',x),null}}},[p]);t&&p&&console.warn("MarkdownView could not render custom components when dangerouslySetInnerHTML is enabled.");var P=new P8.Converter;if(n&&P.setFlavor(n),u)for(var q in u){if(q==="extensions"&&u.extensions)for(var Z=qE(u.extensions),M;!(M=Z()).done;){var H=M.value;typeof H=="string"?P.useExtension(H):P.addExtension(H)}P.setOption(q,u[q])}c&&P.addExtension(c);var z=P.makeHtml(r??o);if(g&&(z=g(z)),t)return K.createElement("div",{dangerouslySetInnerHTML:{__html:z}});var L=mm.parseDOM(z,{lowerCaseTags:!1,lowerCaseAttributeNames:!1,decodeEntities:!0,recognizeSelfClosing:!0});return Ee.createElement("div",b,L.map(O))}var ME=["area","br","col","embed","hr","img","input","keygen","param","source","track","wbr"],BE=["table","thead","tbody","tr"];function zE(e){return e.type==="text"&&e instanceof hn.DataNode?e.data.trim().length>0:!0}var $E=IE;function Ue(e){for(var t=arguments.length,n=new Array(t>1?t-1:0),r=1;r<t;r++)n[r-1]=arguments[r];throw new Error(typeof e=="number"?"[MobX] minified error nr: "+e+(n.length?" "+n.map(String).join(","):"")+". Find the full error at: https://github.com/mobxjs/mobx/blob/main/packages/mobx/src/errors.ts":"[MobX] "+e)}var UE={};function Fd(){return typeof globalThis<"u"?globalThis:typeof window<"u"?window:typeof global<"u"?global:typeof self<"u"?self:UE}var pv=Object.assign,ja=Object.getOwnPropertyDescriptor,mr=Object.defineProperty,Hs=Object.prototype,Vd=[];Object.freeze(Vd);var hv={};Object.freeze(hv);var jE=typeof Proxy<"u",FE=Object.toString();function gv(){jE||Ue("Proxy not available")}function mv(e){var t=!1;return function(){if(!t)return t=!0,e.apply(this,arguments)}}var Ro=function(){};function Zn(e){return typeof e=="function"}function Qi(e){var t=typeof e;switch(t){case"string":case"symbol":case"number":return!0}return!1}function Fa(e){return e!==null&&typeof e=="object"}function ri(e){if(!Fa(e))return!1;var t=Object.getPrototypeOf(e);if(t==null)return!0;var n=Object.hasOwnProperty.call(t,"constructor")&&t.constructor;return typeof n=="function"&&n.toString()===FE}function vv(e){var t=e==null?void 0:e.constructor;return t?t.name==="GeneratorFunction"||t.displayName==="GeneratorFunction":!1}function Va(e,t,n){mr(e,t,{enumerable:!1,writable:!0,configurable:!0,value:n})}function _v(e,t,n){mr(e,t,{enumerable:!1,writable:!1,configurable:!0,value:n})}function ii(e,t){var n="isMobX"+e;return t.prototype[n]=!0,function(r){return Fa(r)&&r[n]===!0}}function qo(e){return e instanceof Map}function Gs(e){return e instanceof Set}var yv=typeof Object.getOwnPropertySymbols<"u";function VE(e){var t=Object.keys(e);if(!yv)return t;var n=Object.getOwnPropertySymbols(e);return n.length?[].concat(t,n.filter(function(r){return Hs.propertyIsEnumerable.call(e,r)})):t}var Ha=typeof Reflect<"u"&&Reflect.ownKeys?Reflect.ownKeys:yv?function(e){return Object.getOwnPropertyNames(e).concat(Object.getOwnPropertySymbols(e))}:Object.getOwnPropertyNames;function wv(e){return e===null?null:typeof e=="object"?""+e:e}function Pr(e,t){return Hs.hasOwnProperty.call(e,t)}var HE=Object.getOwnPropertyDescriptors||function(t){var n={};return Ha(t).forEach(function(r){n[r]=ja(t,r)}),n};function bv(e,t){for(var n=0;n<t.length;n++){var r=t[n];r.enumerable=r.enumerable||!1,r.configurable=!0,"value"in r&&(r.writable=!0),Object.defineProperty(e,KE(r.key),r)}}function Hd(e,t,n){return t&&bv(e.prototype,t),n&&bv(e,n),Object.defineProperty(e,"prototype",{writable:!1}),e}function Io(){return Io=Object.assign?Object.assign.bind():function(e){for(var t=1;t<arguments.length;t++){var n=arguments[t];for(var r in n)Object.prototype.hasOwnProperty.call(n,r)&&(e[r]=n[r])}return e},Io.apply(this,arguments)}function Sv(e,t){e.prototype=Object.create(t.prototype),e.prototype.constructor=e,Gd(e,t)}function Gd(e,t){return Gd=Object.setPrototypeOf?Object.setPrototypeOf.bind():function(r,o){return r.__proto__=o,r},Gd(e,t)}function Wd(e){if(e===void 0)throw new ReferenceError("this hasn't been initialised - super() hasn't been called");return e}function GE(e,t){if(e){if(typeof e=="string")return Ev(e,t);var n=Object.prototype.toString.call(e).slice(8,-1);if(n==="Object"&&e.constructor&&(n=e.constructor.name),n==="Map"||n==="Set")return Array.from(e);if(n==="Arguments"||/^(?:Ui|I)nt(?:8|16|32)(?:Clamped)?Array$/.test(n))return Ev(e,t)}}function Ev(e,t){(t==null||t>e.length)&&(t=e.length);for(var n=0,r=new Array(t);n<t;n++)r[n]=e[n];return r}function Mo(e,t){var n=typeof Symbol<"u"&&e[Symbol.iterator]||e["@@iterator"];if(n)return(n=n.call(e)).next.bind(n);if(Array.isArray(e)||(n=GE(e))||t&&e&&typeof e.length=="number"){n&&(e=n);var r=0;return function(){return r>=e.length?{done:!0}:{done:!1,value:e[r++]}}}throw new TypeError(`Invalid attempt to iterate non-iterable instance.
-In order to be iterable, non-array objects must have a [Symbol.iterator]() method.`)}function WE(e,t){if(typeof e!="object"||e===null)return e;var n=e[Symbol.toPrimitive];if(n!==void 0){var r=n.call(e,t||"default");if(typeof r!="object")return r;throw new TypeError("@@toPrimitive must return a primitive value.")}return(t==="string"?String:Number)(e)}function KE(e){var t=WE(e,"string");return typeof t=="symbol"?t:String(t)}var vr=Symbol("mobx-stored-annotations");function _r(e){function t(n,r){Ws(n,r,e)}return Object.assign(t,e)}function Ws(e,t,n){Pr(e,vr)||Va(e,vr,Io({},e[vr])),r2(n)||(e[vr][t]=n)}function YE(e){return Pr(e,vr)||Va(e,vr,Io({},e[vr])),e[vr]}var Le=Symbol("mobx administration"),Ks=function(){function e(n){n===void 0&&(n="Atom"),this.name_=void 0,this.isPendingUnobservation_=!1,this.isBeingObserved_=!1,this.observers_=new Set,this.diffValue_=0,this.lastAccessedBy_=0,this.lowestObserverState_=He.NOT_TRACKING_,this.onBOL=void 0,this.onBUOL=void 0,this.name_=n}var t=e.prototype;return t.onBO=function(){this.onBOL&&this.onBOL.forEach(function(r){return r()})},t.onBUO=function(){this.onBUOL&&this.onBUOL.forEach(function(r){return r()})},t.reportObserved=function(){return Gv(this)},t.reportChanged=function(){_n(),Wv(this),he.stateVersion=he.stateVersion<Number.MAX_SAFE_INTEGER?he.stateVersion+1:Number.MIN_SAFE_INTEGER,yn()},t.toString=function(){return this.name_},e}(),Kd=ii("Atom",Ks);function Ys(e,t,n){t===void 0&&(t=Ro),n===void 0&&(n=Ro);var r=new Ks(e);return t!==Ro&&ik(r,t),n!==Ro&&e_(r,n),r}function QE(e,t){return e===t}function XE(e,t){return sf(e,t)}function ZE(e,t){return sf(e,t,1)}function JE(e,t){return Object.is?Object.is(e,t):e===t?e!==0||1/e===1/t:e!==e&&t!==t}var Ga={identity:QE,structural:XE,default:JE,shallow:ZE};function Bo(e,t,n){return ef(e)?e:Array.isArray(e)?St.array(e,{name:n}):ri(e)?St.object(e,void 0,{name:n}):qo(e)?St.map(e,{name:n}):Gs(e)?St.set(e,{name:n}):typeof e=="function"&&!Jd(e)&&!sl(e)?vv(e)?iu(e):ru(n,e):e}function e2(e,t,n){if(e==null||Vo(e)||uu(e)||ai(e)||Ji(e))return e;if(Array.isArray(e))return St.array(e,{name:n,deep:!1});if(ri(e))return St.object(e,void 0,{name:n,deep:!1});if(qo(e))return St.map(e,{name:n,deep:!1});if(Gs(e))return St.set(e,{name:n,deep:!1})}function Wa(e){return e}function t2(e,t){return sf(e,t)?t:e}var n2="override";function r2(e){return e.annotationType_===n2}function Qs(e,t){return{annotationType_:e,options_:t,make_:i2,extend_:o2}}function i2(e,t,n,r){var o;if((o=this.options_)!=null&&o.bound)return this.extend_(e,t,n,!1)===null?0:1;if(r===e.target_)return this.extend_(e,t,n,!1)===null?0:2;if(Jd(n.value))return 1;var u=kv(e,this,t,n,!1);return mr(r,t,u),2}function o2(e,t,n,r){var o=kv(e,this,t,n);return e.defineProperty_(t,o,r)}function s2(e,t,n,r){t.annotationType_,r.value}function kv(e,t,n,r,o){var u,c,p,g,b,O,P;o===void 0&&(o=he.safeDescriptors),s2(e,t,n,r);var q=r.value;if((u=t.options_)!=null&&u.bound){var Z;q=q.bind((Z=e.proxy_)!=null?Z:e.target_)}return{value:zo((c=(p=t.options_)==null?void 0:p.name)!=null?c:n.toString(),q,(g=(b=t.options_)==null?void 0:b.autoAction)!=null?g:!1,(O=t.options_)!=null&&O.bound?(P=e.proxy_)!=null?P:e.target_:void 0),configurable:o?e.isPlainObject_:!0,enumerable:!1,writable:!o}}function Av(e,t){return{annotationType_:e,options_:t,make_:u2,extend_:a2}}function u2(e,t,n,r){var o;if(r===e.target_)return this.extend_(e,t,n,!1)===null?0:2;if((o=this.options_)!=null&&o.bound&&(!Pr(e.target_,t)||!sl(e.target_[t]))&&this.extend_(e,t,n,!1)===null)return 0;if(sl(n.value))return 1;var u=Cv(e,this,t,n,!1,!1);return mr(r,t,u),2}function a2(e,t,n,r){var o,u=Cv(e,this,t,n,(o=this.options_)==null?void 0:o.bound);return e.defineProperty_(t,u,r)}function l2(e,t,n,r){t.annotationType_,r.value}function Cv(e,t,n,r,o,u){u===void 0&&(u=he.safeDescriptors),l2(e,t,n,r);var c=r.value;if(sl(c)||(c=iu(c)),o){var p;c=c.bind((p=e.proxy_)!=null?p:e.target_),c.isMobXFlow=!0}return{value:c,configurable:u?e.isPlainObject_:!0,enumerable:!1,writable:!u}}function Yd(e,t){return{annotationType_:e,options_:t,make_:c2,extend_:d2}}function c2(e,t,n){return this.extend_(e,t,n,!1)===null?0:1}function d2(e,t,n,r){return f2(e,this,t,n),e.defineComputedProperty_(t,Io({},this.options_,{get:n.get,set:n.set}),r)}function f2(e,t,n,r){t.annotationType_,r.get}function Ka(e,t){return{annotationType_:e,options_:t,make_:p2,extend_:h2}}function p2(e,t,n){return this.extend_(e,t,n,!1)===null?0:1}function h2(e,t,n,r){var o,u;return g2(e,this),e.defineObservableProperty_(t,n.value,(o=(u=this.options_)==null?void 0:u.enhancer)!=null?o:Bo,r)}function g2(e,t,n,r){t.annotationType_}var m2="true",v2=Tv();function Tv(e){return{annotationType_:m2,options_:e,make_:_2,extend_:y2}}function _2(e,t,n,r){var o,u;if(n.get)return Xa.make_(e,t,n,r);if(n.set){var c=zo(t.toString(),n.set);return r===e.target_?e.defineProperty_(t,{configurable:he.safeDescriptors?e.isPlainObject_:!0,set:c})===null?0:2:(mr(r,t,{configurable:!0,set:c}),2)}if(r!==e.target_&&typeof n.value=="function"){var p;if(vv(n.value)){var g,b=(g=this.options_)!=null&&g.autoBind?iu.bound:iu;return b.make_(e,t,n,r)}var O=(p=this.options_)!=null&&p.autoBind?ru.bound:ru;return O.make_(e,t,n,r)}var P=((o=this.options_)==null?void 0:o.deep)===!1?St.ref:St;if(typeof n.value=="function"&&(u=this.options_)!=null&&u.autoBind){var q;n.value=n.value.bind((q=e.proxy_)!=null?q:e.target_)}return P.make_(e,t,n,r)}function y2(e,t,n,r){var o,u;if(n.get)return Xa.extend_(e,t,n,r);if(n.set)return e.defineProperty_(t,{configurable:he.safeDescriptors?e.isPlainObject_:!0,set:zo(t.toString(),n.set)},r);if(typeof n.value=="function"&&(o=this.options_)!=null&&o.autoBind){var c;n.value=n.value.bind((c=e.proxy_)!=null?c:e.target_)}var p=((u=this.options_)==null?void 0:u.deep)===!1?St.ref:St;return p.extend_(e,t,n,r)}var w2="observable",b2="observable.ref",S2="observable.shallow",E2="observable.struct",xv={deep:!0,name:void 0,defaultDecorator:void 0,proxy:!0};Object.freeze(xv);function Ya(e){return e||xv}var Lv=Ka(w2),k2=Ka(b2,{enhancer:Wa}),A2=Ka(S2,{enhancer:e2}),C2=Ka(E2,{enhancer:t2}),Ov=_r(Lv);function Qa(e){return e.deep===!0?Bo:e.deep===!1?Wa:x2(e.defaultDecorator)}function T2(e){var t;return e?(t=e.defaultDecorator)!=null?t:Tv(e):void 0}function x2(e){var t,n;return e&&(t=(n=e.options_)==null?void 0:n.enhancer)!=null?t:Bo}function Pv(e,t,n){if(Qi(t)){Ws(e,t,Lv);return}return ef(e)?e:ri(e)?St.object(e,t,n):Array.isArray(e)?St.array(e,t):qo(e)?St.map(e,t):Gs(e)?St.set(e,t):typeof e=="object"&&e!==null?e:St.box(e,t)}pv(Pv,Ov);var L2={box:function(t,n){var r=Ya(n);return new Xi(t,Qa(r),r.name,!0,r.equals)},array:function(t,n){var r=Ya(n);return(he.useProxies===!1||r.proxy===!1?Dk:Ek)(t,Qa(r),r.name)},map:function(t,n){var r=Ya(n);return new c_(t,Qa(r),r.name)},set:function(t,n){var r=Ya(n);return new p_(t,Qa(r),r.name)},object:function(t,n,r){return ak(he.useProxies===!1||(r==null?void 0:r.proxy)===!1?cl({},r):yk({},r),t,n)},ref:_r(k2),shallow:_r(A2),deep:Ov,struct:_r(C2)},St=pv(Pv,L2),Dv="computed",O2="computed.struct",Nv=Yd(Dv),P2=Yd(O2,{equals:Ga.structural}),Xa=function(t,n){if(Qi(n))return Ws(t,n,Nv);if(ri(t))return _r(Yd(Dv,t));var r=ri(n)?n:{};return r.get=t,r.name||(r.name=t.name||""),new Js(r)};Object.assign(Xa,Nv),Xa.struct=_r(P2);var Rv,qv,Za=0,D2=1,N2=(Rv=(qv=ja(function(){},"name"))==null?void 0:qv.configurable)!=null?Rv:!1,Iv={value:"action",configurable:!0,writable:!1,enumerable:!1};function zo(e,t,n,r){n===void 0&&(n=!1);function o(){return R2(e,n,t,r||this,arguments)}return o.isMobxAction=!0,N2&&(Iv.value=e,mr(o,"name",Iv)),o}function R2(e,t,n,r,o){var u=q2(e,t);try{return n.apply(r,o)}catch(c){throw u.error_=c,c}finally{I2(u)}}function q2(e,t,n,r){var o=!1,u=0,c=he.trackingDerivation,p=!t||!c;_n();var g=he.allowStateChanges;p&&($o(),g=Xs(!0));var b=tl(!0),O={runAsAction_:p,prevDerivation_:c,prevAllowStateChanges_:g,prevAllowStateReads_:b,notifySpy_:o,startTime_:u,actionId_:D2++,parentActionId_:Za};return Za=O.actionId_,O}function I2(e){Za!==e.actionId_&&Ue(30),Za=e.parentActionId_,e.error_!==void 0&&(he.suppressReactionErrors=!0),Zs(e.prevAllowStateChanges_),Uo(e.prevAllowStateReads_),yn(),e.runAsAction_&&oi(e.prevDerivation_),he.suppressReactionErrors=!1}function Mv(e,t){var n=Xs(e);try{return t()}finally{Zs(n)}}function Xs(e){var t=he.allowStateChanges;return he.allowStateChanges=e,t}function Zs(e){he.allowStateChanges=e}var Bv;Bv=Symbol.toPrimitive;var Xi=function(e){Sv(t,e);function t(r,o,u,c,p){var g;return u===void 0&&(u="ObservableValue"),p===void 0&&(p=Ga.default),g=e.call(this,u)||this,g.enhancer=void 0,g.name_=void 0,g.equals=void 0,g.hasUnreportedChange_=!1,g.interceptors_=void 0,g.changeListeners_=void 0,g.value_=void 0,g.dehancer=void 0,g.enhancer=o,g.name_=u,g.equals=p,g.value_=o(r,void 0,u),g}var n=t.prototype;return n.dehanceValue=function(o){return this.dehancer!==void 0?this.dehancer(o):o},n.set=function(o){this.value_,o=this.prepareNewValue_(o),o!==he.UNCHANGED&&this.setNewValue_(o)},n.prepareNewValue_=function(o){if(Rn(this)){var u=qn(this,{object:this,type:yr,newValue:o});if(!u)return he.UNCHANGED;o=u.newValue}return o=this.enhancer(o,this.value_,this.name_),this.equals(this.value_,o)?he.UNCHANGED:o},n.setNewValue_=function(o){var u=this.value_;this.value_=o,this.reportChanged(),er(this)&&tr(this,{type:yr,object:this,newValue:o,oldValue:u})},n.get=function(){return this.reportObserved(),this.dehanceValue(this.value_)},n.intercept_=function(o){return ou(this,o)},n.observe_=function(o,u){return u&&o({observableKind:"value",debugObjectName:this.name_,object:this,type:yr,newValue:this.value_,oldValue:void 0}),su(this,o)},n.raw=function(){return this.value_},n.toJSON=function(){return this.get()},n.toString=function(){return this.name_+"["+this.value_+"]"},n.valueOf=function(){return wv(this.get())},n[Bv]=function(){return this.valueOf()},t}(Ks),M2=ii("ObservableValue",Xi),zv;zv=Symbol.toPrimitive;var Js=function(){function e(n){this.dependenciesState_=He.NOT_TRACKING_,this.observing_=[],this.newObserving_=null,this.isBeingObserved_=!1,this.isPendingUnobservation_=!1,this.observers_=new Set,this.diffValue_=0,this.runId_=0,this.lastAccessedBy_=0,this.lowestObserverState_=He.UP_TO_DATE_,this.unboundDepsCount_=0,this.value_=new Ja(null),this.name_=void 0,this.triggeredBy_=void 0,this.isComputing_=!1,this.isRunningSetter_=!1,this.derivation=void 0,this.setter_=void 0,this.isTracing_=Zi.NONE,this.scope_=void 0,this.equals_=void 0,this.requiresReaction_=void 0,this.keepAlive_=void 0,this.onBOL=void 0,this.onBUOL=void 0,n.get||Ue(31),this.derivation=n.get,this.name_=n.name||"ComputedValue",n.set&&(this.setter_=zo("ComputedValue-setter",n.set)),this.equals_=n.equals||(n.compareStructural||n.struct?Ga.structural:Ga.default),this.scope_=n.context,this.requiresReaction_=n.requiresReaction,this.keepAlive_=!!n.keepAlive}var t=e.prototype;return t.onBecomeStale_=function(){F2(this)},t.onBO=function(){this.onBOL&&this.onBOL.forEach(function(r){return r()})},t.onBUO=function(){this.onBUOL&&this.onBUOL.forEach(function(r){return r()})},t.get=function(){if(this.isComputing_&&Ue(32,this.name_,this.derivation),he.inBatch===0&&this.observers_.size===0&&!this.keepAlive_)Qd(this)&&(this.warnAboutUntrackedRead_(),_n(),this.value_=this.computeValue_(!1),yn());else if(Gv(this),Qd(this)){var r=he.trackingContext;this.keepAlive_&&!r&&(he.trackingContext=this),this.trackAndCompute()&&j2(this),he.trackingContext=r}var o=this.value_;if(el(o))throw o.cause;return o},t.set=function(r){if(this.setter_){this.isRunningSetter_&&Ue(33,this.name_),this.isRunningSetter_=!0;try{this.setter_.call(this.scope_,r)}finally{this.isRunningSetter_=!1}}else Ue(34,this.name_)},t.trackAndCompute=function(){var r=this.value_,o=this.dependenciesState_===He.NOT_TRACKING_,u=this.computeValue_(!0),c=o||el(r)||el(u)||!this.equals_(r,u);return c&&(this.value_=u),c},t.computeValue_=function(r){this.isComputing_=!0;var o=Xs(!1),u;if(r)u=$v(this,this.derivation,this.scope_);else if(he.disableErrorBoundaries===!0)u=this.derivation.call(this.scope_);else try{u=this.derivation.call(this.scope_)}catch(c){u=new Ja(c)}return Zs(o),this.isComputing_=!1,u},t.suspend_=function(){this.keepAlive_||(Xd(this),this.value_=void 0)},t.observe_=function(r,o){var u=this,c=!0,p=void 0;return J2(function(){var g=u.get();if(!c||o){var b=$o();r({observableKind:"computed",debugObjectName:u.name_,type:yr,object:u,newValue:g,oldValue:p}),oi(b)}c=!1,p=g})},t.warnAboutUntrackedRead_=function(){},t.toString=function(){return this.name_+"["+this.derivation.toString()+"]"},t.valueOf=function(){return wv(this.get())},t[zv]=function(){return this.valueOf()},e}(),eu=ii("ComputedValue",Js),He;(function(e){e[e.NOT_TRACKING_=-1]="NOT_TRACKING_",e[e.UP_TO_DATE_=0]="UP_TO_DATE_",e[e.POSSIBLY_STALE_=1]="POSSIBLY_STALE_",e[e.STALE_=2]="STALE_"})(He||(He={}));var Zi;(function(e){e[e.NONE=0]="NONE",e[e.LOG=1]="LOG",e[e.BREAK=2]="BREAK"})(Zi||(Zi={}));var Ja=function(t){this.cause=void 0,this.cause=t};function el(e){return e instanceof Ja}function Qd(e){switch(e.dependenciesState_){case He.UP_TO_DATE_:return!1;case He.NOT_TRACKING_:case He.STALE_:return!0;case He.POSSIBLY_STALE_:{for(var t=tl(!0),n=$o(),r=e.observing_,o=r.length,u=0;u<o;u++){var c=r[u];if(eu(c)){if(he.disableErrorBoundaries)c.get();else try{c.get()}catch{return oi(n),Uo(t),!0}if(e.dependenciesState_===He.STALE_)return oi(n),Uo(t),!0}}return jv(e),oi(n),Uo(t),!1}}}function Ive(e){}function $v(e,t,n){var r=tl(!0);jv(e),e.newObserving_=new Array(e.observing_.length+100),e.unboundDepsCount_=0,e.runId_=++he.runId;var o=he.trackingDerivation;he.trackingDerivation=e,he.inBatch++;var u;if(he.disableErrorBoundaries===!0)u=t.call(n);else try{u=t.call(n)}catch(c){u=new Ja(c)}return he.inBatch--,he.trackingDerivation=o,B2(e),Uo(r),u}function B2(e){for(var t=e.observing_,n=e.observing_=e.newObserving_,r=He.UP_TO_DATE_,o=0,u=e.unboundDepsCount_,c=0;c<u;c++){var p=n[c];p.diffValue_===0&&(p.diffValue_=1,o!==c&&(n[o]=p),o++),p.dependenciesState_>r&&(r=p.dependenciesState_)}for(n.length=o,e.newObserving_=null,u=t.length;u--;){var g=t[u];g.diffValue_===0&&Vv(g,e),g.diffValue_=0}for(;o--;){var b=n[o];b.diffValue_===1&&(b.diffValue_=0,U2(b,e))}r!==He.UP_TO_DATE_&&(e.dependenciesState_=r,e.onBecomeStale_())}function Xd(e){var t=e.observing_;e.observing_=[];for(var n=t.length;n--;)Vv(t[n],e);e.dependenciesState_=He.NOT_TRACKING_}function Uv(e){var t=$o();try{return e()}finally{oi(t)}}function $o(){var e=he.trackingDerivation;return he.trackingDerivation=null,e}function oi(e){he.trackingDerivation=e}function tl(e){var t=he.allowStateReads;return he.allowStateReads=e,t}function Uo(e){he.allowStateReads=e}function jv(e){if(e.dependenciesState_!==He.UP_TO_DATE_){e.dependenciesState_=He.UP_TO_DATE_;for(var t=e.observing_,n=t.length;n--;)t[n].lowestObserverState_=He.UP_TO_DATE_}}var nl=function(){this.version=6,this.UNCHANGED={},this.trackingDerivation=null,this.trackingContext=null,this.runId=0,this.mobxGuid=0,this.inBatch=0,this.pendingUnobservations=[],this.pendingReactions=[],this.isRunningReactions=!1,this.allowStateChanges=!1,this.allowStateReads=!0,this.enforceActions=!0,this.spyListeners=[],this.globalReactionErrorHandlers=[],this.computedRequiresReaction=!1,this.reactionRequiresObservable=!1,this.observableRequiresReaction=!1,this.disableErrorBoundaries=!1,this.suppressReactionErrors=!1,this.useProxies=!0,this.verifyProxies=!1,this.safeDescriptors=!0,this.stateVersion=Number.MIN_SAFE_INTEGER},rl=!0,Fv=!1,he=function(){var e=Fd();return e.__mobxInstanceCount>0&&!e.__mobxGlobals&&(rl=!1),e.__mobxGlobals&&e.__mobxGlobals.version!==new nl().version&&(rl=!1),rl?e.__mobxGlobals?(e.__mobxInstanceCount+=1,e.__mobxGlobals.UNCHANGED||(e.__mobxGlobals.UNCHANGED={}),e.__mobxGlobals):(e.__mobxInstanceCount=1,e.__mobxGlobals=new nl):(setTimeout(function(){Fv||Ue(35)},1),new nl)}();function z2(){if((he.pendingReactions.length||he.inBatch||he.isRunningReactions)&&Ue(36),Fv=!0,rl){var e=Fd();--e.__mobxInstanceCount===0&&(e.__mobxGlobals=void 0),he=new nl}}function $2(){return he}function U2(e,t){e.observers_.add(t),e.lowestObserverState_>t.dependenciesState_&&(e.lowestObserverState_=t.dependenciesState_)}function Vv(e,t){e.observers_.delete(t),e.observers_.size===0&&Hv(e)}function Hv(e){e.isPendingUnobservation_===!1&&(e.isPendingUnobservation_=!0,he.pendingUnobservations.push(e))}function _n(){he.inBatch++}function yn(){if(--he.inBatch===0){Kv();for(var e=he.pendingUnobservations,t=0;t<e.length;t++){var n=e[t];n.isPendingUnobservation_=!1,n.observers_.size===0&&(n.isBeingObserved_&&(n.isBeingObserved_=!1,n.onBUO()),n instanceof Js&&n.suspend_())}he.pendingUnobservations=[]}}function Gv(e){var t=he.trackingDerivation;return t!==null?(t.runId_!==e.lastAccessedBy_&&(e.lastAccessedBy_=t.runId_,t.newObserving_[t.unboundDepsCount_++]=e,!e.isBeingObserved_&&he.trackingContext&&(e.isBeingObserved_=!0,e.onBO())),e.isBeingObserved_):(e.observers_.size===0&&he.inBatch>0&&Hv(e),!1)}function Wv(e){e.lowestObserverState_!==He.STALE_&&(e.lowestObserverState_=He.STALE_,e.observers_.forEach(function(t){t.dependenciesState_===He.UP_TO_DATE_&&t.onBecomeStale_(),t.dependenciesState_=He.STALE_}))}function j2(e){e.lowestObserverState_!==He.STALE_&&(e.lowestObserverState_=He.STALE_,e.observers_.forEach(function(t){t.dependenciesState_===He.POSSIBLY_STALE_?t.dependenciesState_=He.STALE_:t.dependenciesState_===He.UP_TO_DATE_&&(e.lowestObserverState_=He.UP_TO_DATE_)}))}function F2(e){e.lowestObserverState_===He.UP_TO_DATE_&&(e.lowestObserverState_=He.POSSIBLY_STALE_,e.observers_.forEach(function(t){t.dependenciesState_===He.UP_TO_DATE_&&(t.dependenciesState_=He.POSSIBLY_STALE_,t.onBecomeStale_())}))}var tu=function(){function e(n,r,o,u){n===void 0&&(n="Reaction"),this.name_=void 0,this.onInvalidate_=void 0,this.errorHandler_=void 0,this.requiresObservable_=void 0,this.observing_=[],this.newObserving_=[],this.dependenciesState_=He.NOT_TRACKING_,this.diffValue_=0,this.runId_=0,this.unboundDepsCount_=0,this.isDisposed_=!1,this.isScheduled_=!1,this.isTrackPending_=!1,this.isRunning_=!1,this.isTracing_=Zi.NONE,this.name_=n,this.onInvalidate_=r,this.errorHandler_=o,this.requiresObservable_=u}var t=e.prototype;return t.onBecomeStale_=function(){this.schedule_()},t.schedule_=function(){this.isScheduled_||(this.isScheduled_=!0,he.pendingReactions.push(this),Kv())},t.isScheduled=function(){return this.isScheduled_},t.runReaction_=function(){if(!this.isDisposed_){_n(),this.isScheduled_=!1;var r=he.trackingContext;if(he.trackingContext=this,Qd(this)){this.isTrackPending_=!0;try{this.onInvalidate_()}catch(o){this.reportExceptionInDerivation_(o)}}he.trackingContext=r,yn()}},t.track=function(r){if(!this.isDisposed_){_n(),this.isRunning_=!0;var o=he.trackingContext;he.trackingContext=this;var u=$v(this,r,void 0);he.trackingContext=o,this.isRunning_=!1,this.isTrackPending_=!1,this.isDisposed_&&Xd(this),el(u)&&this.reportExceptionInDerivation_(u.cause),yn()}},t.reportExceptionInDerivation_=function(r){var o=this;if(this.errorHandler_){this.errorHandler_(r,this);return}if(he.disableErrorBoundaries)throw r;var u="[mobx] uncaught error in '"+this+"'";he.suppressReactionErrors||console.error(u,r),he.globalReactionErrorHandlers.forEach(function(c){return c(r,o)})},t.dispose=function(){this.isDisposed_||(this.isDisposed_=!0,this.isRunning_||(_n(),Xd(this),yn()))},t.getDisposer_=function(){var r=this.dispose.bind(this);return r[Le]=this,r},t.toString=function(){return"Reaction["+this.name_+"]"},t.trace=function(r){r===void 0&&(r=!1),mk(this,r)},e}(),V2=100,Zd=function(t){return t()};function Kv(){he.inBatch>0||he.isRunningReactions||Zd(H2)}function H2(){he.isRunningReactions=!0;for(var e=he.pendingReactions,t=0;e.length>0;){++t===V2&&(console.error("[mobx] cycle in reaction: "+e[0]),e.splice(0));for(var n=e.splice(0),r=0,o=n.length;r<o;r++)n[r].runReaction_()}he.isRunningReactions=!1}var il=ii("Reaction",tu);function G2(e){var t=Zd;Zd=function(r){return e(function(){return t(r)})}}function nu(){return!1}function Mve(e){}function Bve(e){}function zve(e){}function W2(e){return console.warn("[mobx.spy] Is a no-op in production builds"),function(){}}var Yv="action",K2="action.bound",Qv="autoAction",Y2="autoAction.bound",Q2="<unnamed action>",Xv=Qs(Yv),X2=Qs(K2,{bound:!0}),Zv=Qs(Qv,{autoAction:!0}),Z2=Qs(Y2,{autoAction:!0,bound:!0});function Jv(e){var t=function(r,o){if(Zn(r))return zo(r.name||Q2,r,e);if(Zn(o))return zo(r,o,e);if(Qi(o))return Ws(r,o,e?Zv:Xv);if(Qi(r))return _r(Qs(e?Qv:Yv,{name:r,autoAction:e}))};return t}var Jn=Jv(!1);Object.assign(Jn,Xv);var ru=Jv(!0);Object.assign(ru,Zv),Jn.bound=_r(X2),ru.bound=_r(Z2);function Jd(e){return Zn(e)&&e.isMobxAction===!0}function J2(e,t){var n,r;t===void 0&&(t=hv);var o=(n=(r=t)==null?void 0:r.name)!=null?n:"Autorun",u=!t.scheduler&&!t.delay,c;if(u)c=new tu(o,function(){this.track(b)},t.onError,t.requiresObservable);else{var p=tk(t),g=!1;c=new tu(o,function(){g||(g=!0,p(function(){g=!1,c.isDisposed_||c.track(b)}))},t.onError,t.requiresObservable)}function b(){e(c)}return c.schedule_(),c.getDisposer_()}var ek=function(t){return t()};function tk(e){return e.scheduler?e.scheduler:e.delay?function(t){return setTimeout(t,e.delay)}:ek}var nk="onBO",rk="onBUO";function ik(e,t,n){return t_(nk,e,t,n)}function e_(e,t,n){return t_(rk,e,t,n)}function t_(e,t,n,r){var o=typeof r=="function"?li(t,n):li(t),u=Zn(r)?r:n,c=e+"L";return o[c]?o[c].add(u):o[c]=new Set([u]),function(){var p=o[c];p&&(p.delete(u),p.size===0&&delete o[c])}}var ok="never",ol="always",sk="observed";function uk(e){e.isolateGlobalState===!0&&z2();var t=e.useProxies,n=e.enforceActions;if(t!==void 0&&(he.useProxies=t===ol?!0:t===ok?!1:typeof Proxy<"u"),t==="ifavailable"&&(he.verifyProxies=!0),n!==void 0){var r=n===ol?ol:n===sk;he.enforceActions=r,he.allowStateChanges=!(r===!0||r===ol)}["computedRequiresReaction","reactionRequiresObservable","observableRequiresReaction","disableErrorBoundaries","safeDescriptors"].forEach(function(o){o in e&&(he[o]=!!e[o])}),he.allowStateReads=!he.observableRequiresReaction,e.reactionScheduler&&G2(e.reactionScheduler)}function ak(e,t,n,r){var o=HE(t),u=cl(e,r)[Le];_n();try{Ha(o).forEach(function(c){u.extend_(c,o[c],n&&c in n?n[c]:!0)})}finally{yn()}return e}function lk(e,t){return n_(li(e,t))}function n_(e){var t={name:e.name_};return e.observing_&&e.observing_.length>0&&(t.dependencies=ck(e.observing_).map(n_)),t}function ck(e){return Array.from(new Set(e))}var dk=0;function r_(){this.message="FLOW_CANCELLED"}r_.prototype=Object.create(Error.prototype);var i_=Av("flow"),fk=Av("flow.bound",{bound:!0}),iu=Object.assign(function(t,n){if(Qi(n))return Ws(t,n,i_);var r=t,o=r.name||"<unnamed flow>",u=function(){var p=this,g=arguments,b=++dk,O=Jn(o+" - runid: "+b+" - init",r).apply(p,g),P,q=void 0,Z=new Promise(function(M,H){var z=0;P=H;function L(l){q=void 0;var h;try{h=Jn(o+" - runid: "+b+" - yield "+z++,O.next).call(O,l)}catch(d){return H(d)}x(h)}function k(l){q=void 0;var h;try{h=Jn(o+" - runid: "+b+" - yield "+z++,O.throw).call(O,l)}catch(d){return H(d)}x(h)}function x(l){if(Zn(l==null?void 0:l.then)){l.then(x,H);return}return l.done?M(l.value):(q=Promise.resolve(l.value),q.then(L,k))}L(void 0)});return Z.cancel=Jn(o+" - runid: "+b+" - cancel",function(){try{q&&o_(q);var M=O.return(void 0),H=Promise.resolve(M.value);H.then(Ro,Ro),o_(H),P(new r_)}catch(z){P(z)}}),Z};return u.isMobXFlow=!0,u},i_);iu.bound=_r(fk);function o_(e){Zn(e.cancel)&&e.cancel()}function sl(e){return(e==null?void 0:e.isMobXFlow)===!0}function pk(e,t){return e?t!==void 0?Vo(e)?e[Le].values_.has(t):!1:Vo(e)||!!e[Le]||Kd(e)||il(e)||eu(e):!1}function ef(e){return pk(e)}function hk(e){if(Vo(e))return e[Le].ownKeys_();Ue(38)}function ul(e,t,n){return e.set(t,n),n}function jo(e,t){if(e==null||typeof e!="object"||e instanceof Date||!ef(e))return e;if(M2(e)||eu(e))return jo(e.get(),t);if(t.has(e))return t.get(e);if(uu(e)){var n=ul(t,e,new Array(e.length));return e.forEach(function(c,p){n[p]=jo(c,t)}),n}if(Ji(e)){var r=ul(t,e,new Set);return e.forEach(function(c){r.add(jo(c,t))}),r}if(ai(e)){var o=ul(t,e,new Map);return e.forEach(function(c,p){o.set(p,jo(c,t))}),o}else{var u=ul(t,e,{});return hk(e).forEach(function(c){Hs.propertyIsEnumerable.call(e,c)&&(u[c]=jo(e[c],t))}),u}}function gk(e,t){return jo(e,new Map)}function mk(){Ue("trace() is not available in production builds");for(var e=!1,t=arguments.length,n=new Array(t),r=0;r<t;r++)n[r]=arguments[r];typeof n[n.length-1]=="boolean"&&(e=n.pop());var o=vk(n);if(!o)return Ue("'trace(break?)' can only be used inside a tracked computed value or a Reaction. Consider passing in the computed value or reaction explicitly");o.isTracing_===Zi.NONE&&console.log("[mobx.trace] '"+o.name_+"' tracing enabled"),o.isTracing_=e?Zi.BREAK:Zi.LOG}function vk(e){switch(e.length){case 0:return he.trackingDerivation;case 1:return li(e[0]);case 2:return li(e[0],e[1])}}function Dr(e,t){t===void 0&&(t=void 0),_n();try{return e.apply(t)}finally{yn()}}function Fo(e){return e[Le]}var _k={has:function(t,n){return Fo(t).has_(n)},get:function(t,n){return Fo(t).get_(n)},set:function(t,n,r){var o;return Qi(n)?(o=Fo(t).set_(n,r,!0))!=null?o:!0:!1},deleteProperty:function(t,n){var r;return Qi(n)?(r=Fo(t).delete_(n,!0))!=null?r:!0:!1},defineProperty:function(t,n,r){var o;return(o=Fo(t).defineProperty_(n,r))!=null?o:!0},ownKeys:function(t){return Fo(t).ownKeys_()},preventExtensions:function(t){Ue(13)}};function yk(e,t){var n,r;return gv(),e=cl(e,t),(r=(n=e[Le]).proxy_)!=null?r:n.proxy_=new Proxy(e,_k)}function Rn(e){return e.interceptors_!==void 0&&e.interceptors_.length>0}function ou(e,t){var n=e.interceptors_||(e.interceptors_=[]);return n.push(t),mv(function(){var r=n.indexOf(t);r!==-1&&n.splice(r,1)})}function qn(e,t){var n=$o();try{for(var r=[].concat(e.interceptors_||[]),o=0,u=r.length;o<u&&(t=r[o](t),t&&!t.type&&Ue(14),!!t);o++);return t}finally{oi(n)}}function er(e){return e.changeListeners_!==void 0&&e.changeListeners_.length>0}function su(e,t){var n=e.changeListeners_||(e.changeListeners_=[]);return n.push(t),mv(function(){var r=n.indexOf(t);r!==-1&&n.splice(r,1)})}function tr(e,t){var n=$o(),r=e.changeListeners_;if(r){r=r.slice();for(var o=0,u=r.length;o<u;o++)r[o](t);oi(n)}}function wk(e,t,n){var r=cl(e,n)[Le];_n();try{var o;(o=t)!=null||(t=YE(e)),Ha(t).forEach(function(u){return r.make_(u,t[u])})}finally{yn()}return e}var s_="splice",yr="update",bk=1e4,Sk={get:function(t,n){var r=t[Le];return n===Le?r:n==="length"?r.getArrayLength_():typeof n=="string"&&!isNaN(n)?r.get_(parseInt(n)):Pr(al,n)?al[n]:t[n]},set:function(t,n,r){var o=t[Le];return n==="length"&&o.setArrayLength_(r),typeof n=="symbol"||isNaN(n)?t[n]=r:o.set_(parseInt(n),r),!0},preventExtensions:function(){Ue(15)}},tf=function(){function e(n,r,o,u){n===void 0&&(n="ObservableArray"),this.owned_=void 0,this.legacyMode_=void 0,this.atom_=void 0,this.values_=[],this.interceptors_=void 0,this.changeListeners_=void 0,this.enhancer_=void 0,this.dehancer=void 0,this.proxy_=void 0,this.lastKnownLength_=0,this.owned_=o,this.legacyMode_=u,this.atom_=new Ks(n),this.enhancer_=function(c,p){return r(c,p,"ObservableArray[..]")}}var t=e.prototype;return t.dehanceValue_=function(r){return this.dehancer!==void 0?this.dehancer(r):r},t.dehanceValues_=function(r){return this.dehancer!==void 0&&r.length>0?r.map(this.dehancer):r},t.intercept_=function(r){return ou(this,r)},t.observe_=function(r,o){return o===void 0&&(o=!1),o&&r({observableKind:"array",object:this.proxy_,debugObjectName:this.atom_.name_,type:"splice",index:0,added:this.values_.slice(),addedCount:this.values_.length,removed:[],removedCount:0}),su(this,r)},t.getArrayLength_=function(){return this.atom_.reportObserved(),this.values_.length},t.setArrayLength_=function(r){(typeof r!="number"||isNaN(r)||r<0)&&Ue("Out of range: "+r);var o=this.values_.length;if(r!==o)if(r>o){for(var u=new Array(r-o),c=0;c<r-o;c++)u[c]=void 0;this.spliceWithArray_(o,0,u)}else this.spliceWithArray_(r,o-r)},t.updateArrayLength_=function(r,o){r!==this.lastKnownLength_&&Ue(16),this.lastKnownLength_+=o,this.legacyMode_&&o>0&&b_(r+o+1)},t.spliceWithArray_=function(r,o,u){var c=this;this.atom_;var p=this.values_.length;if(r===void 0?r=0:r>p?r=p:r<0&&(r=Math.max(0,p+r)),arguments.length===1?o=p-r:o==null?o=0:o=Math.max(0,Math.min(o,p-r)),u===void 0&&(u=Vd),Rn(this)){var g=qn(this,{object:this.proxy_,type:s_,index:r,removedCount:o,added:u});if(!g)return Vd;o=g.removedCount,u=g.added}if(u=u.length===0?u:u.map(function(P){return c.enhancer_(P,void 0)}),this.legacyMode_){var b=u.length-o;this.updateArrayLength_(p,b)}var O=this.spliceItemsIntoValues_(r,o,u);return(o!==0||u.length!==0)&&this.notifyArraySplice_(r,u,O),this.dehanceValues_(O)},t.spliceItemsIntoValues_=function(r,o,u){if(u.length<bk){var c;return(c=this.values_).splice.apply(c,[r,o].concat(u))}else{var p=this.values_.slice(r,r+o),g=this.values_.slice(r+o);this.values_.length+=u.length-o;for(var b=0;b<u.length;b++)this.values_[r+b]=u[b];for(var O=0;O<g.length;O++)this.values_[r+u.length+O]=g[O];return p}},t.notifyArrayChildUpdate_=function(r,o,u){var c=!this.owned_&&nu(),p=er(this),g=p||c?{observableKind:"array",object:this.proxy_,type:yr,debugObjectName:this.atom_.name_,index:r,newValue:o,oldValue:u}:null;this.atom_.reportChanged(),p&&tr(this,g)},t.notifyArraySplice_=function(r,o,u){var c=!this.owned_&&nu(),p=er(this),g=p||c?{observableKind:"array",object:this.proxy_,debugObjectName:this.atom_.name_,type:s_,index:r,removed:u,added:o,removedCount:u.length,addedCount:o.length}:null;this.atom_.reportChanged(),p&&tr(this,g)},t.get_=function(r){if(this.legacyMode_&&r>=this.values_.length){console.warn("[mobx] Out of bounds read: "+r);return}return this.atom_.reportObserved(),this.dehanceValue_(this.values_[r])},t.set_=function(r,o){var u=this.values_;if(this.legacyMode_&&r>u.length&&Ue(17,r,u.length),r<u.length){this.atom_;var c=u[r];if(Rn(this)){var p=qn(this,{type:yr,object:this.proxy_,index:r,newValue:o});if(!p)return;o=p.newValue}o=this.enhancer_(o,c);var g=o!==c;g&&(u[r]=o,this.notifyArrayChildUpdate_(r,o,c))}else{for(var b=new Array(r+1-u.length),O=0;O<b.length-1;O++)b[O]=void 0;b[b.length-1]=o,this.spliceWithArray_(u.length,0,b)}},e}();function Ek(e,t,n,r){n===void 0&&(n="ObservableArray"),r===void 0&&(r=!1),gv();var o=new tf(n,t,r,!1);_v(o.values_,Le,o);var u=new Proxy(o.values_,Sk);if(o.proxy_=u,e&&e.length){var c=Xs(!0);o.spliceWithArray_(0,0,e),Zs(c)}return u}var al={clear:function(){return this.splice(0)},replace:function(t){var n=this[Le];return n.spliceWithArray_(0,n.values_.length,t)},toJSON:function(){return this.slice()},splice:function(t,n){for(var r=arguments.length,o=new Array(r>2?r-2:0),u=2;u<r;u++)o[u-2]=arguments[u];var c=this[Le];switch(arguments.length){case 0:return[];case 1:return c.spliceWithArray_(t);case 2:return c.spliceWithArray_(t,n)}return c.spliceWithArray_(t,n,o)},spliceWithArray:function(t,n,r){return this[Le].spliceWithArray_(t,n,r)},push:function(){for(var t=this[Le],n=arguments.length,r=new Array(n),o=0;o<n;o++)r[o]=arguments[o];return t.spliceWithArray_(t.values_.length,0,r),t.values_.length},pop:function(){return this.splice(Math.max(this[Le].values_.length-1,0),1)[0]},shift:function(){return this.splice(0,1)[0]},unshift:function(){for(var t=this[Le],n=arguments.length,r=new Array(n),o=0;o<n;o++)r[o]=arguments[o];return t.spliceWithArray_(0,0,r),t.values_.length},reverse:function(){return he.trackingDerivation&&Ue(37,"reverse"),this.replace(this.slice().reverse()),this},sort:function(){he.trackingDerivation&&Ue(37,"sort");var t=this.slice();return t.sort.apply(t,arguments),this.replace(t),this},remove:function(t){var n=this[Le],r=n.dehanceValues_(n.values_).indexOf(t);return r>-1?(this.splice(r,1),!0):!1}};Et("concat",Nr),Et("flat",Nr),Et("includes",Nr),Et("indexOf",Nr),Et("join",Nr),Et("lastIndexOf",Nr),Et("slice",Nr),Et("toString",Nr),Et("toLocaleString",Nr),Et("every",si),Et("filter",si),Et("find",si),Et("findIndex",si),Et("flatMap",si),Et("forEach",si),Et("map",si),Et("some",si),Et("reduce",u_),Et("reduceRight",u_);function Et(e,t){typeof Array.prototype[e]=="function"&&(al[e]=t(e))}function Nr(e){return function(){var t=this[Le];t.atom_.reportObserved();var n=t.dehanceValues_(t.values_);return n[e].apply(n,arguments)}}function si(e){return function(t,n){var r=this,o=this[Le];o.atom_.reportObserved();var u=o.dehanceValues_(o.values_);return u[e](function(c,p){return t.call(n,c,p,r)})}}function u_(e){return function(){var t=this,n=this[Le];n.atom_.reportObserved();var r=n.dehanceValues_(n.values_),o=arguments[0];return arguments[0]=function(u,c,p){return o(u,c,p,t)},r[e].apply(r,arguments)}}var kk=ii("ObservableArrayAdministration",tf);function uu(e){return Fa(e)&&kk(e[Le])}var a_,l_,Ak={},ui="add",ll="delete";a_=Symbol.iterator,l_=Symbol.toStringTag;var c_=function(){function e(n,r,o){var u=this;r===void 0&&(r=Bo),o===void 0&&(o="ObservableMap"),this.enhancer_=void 0,this.name_=void 0,this[Le]=Ak,this.data_=void 0,this.hasMap_=void 0,this.keysAtom_=void 0,this.interceptors_=void 0,this.changeListeners_=void 0,this.dehancer=void 0,this.enhancer_=r,this.name_=o,Zn(Map)||Ue(18),this.keysAtom_=Ys("ObservableMap.keys()"),this.data_=new Map,this.hasMap_=new Map,Mv(!0,function(){u.merge(n)})}var t=e.prototype;return t.has_=function(r){return this.data_.has(r)},t.has=function(r){var o=this;if(!he.trackingDerivation)return this.has_(r);var u=this.hasMap_.get(r);if(!u){var c=u=new Xi(this.has_(r),Wa,"ObservableMap.key?",!1);this.hasMap_.set(r,c),e_(c,function(){return o.hasMap_.delete(r)})}return u.get()},t.set=function(r,o){var u=this.has_(r);if(Rn(this)){var c=qn(this,{type:u?yr:ui,object:this,newValue:o,name:r});if(!c)return this;o=c.newValue}return u?this.updateValue_(r,o):this.addValue_(r,o),this},t.delete=function(r){var o=this;if(this.keysAtom_,Rn(this)){var u=qn(this,{type:ll,object:this,name:r});if(!u)return!1}if(this.has_(r)){var c=nu(),p=er(this),g=p||c?{observableKind:"map",debugObjectName:this.name_,type:ll,object:this,oldValue:this.data_.get(r).value_,name:r}:null;return Dr(function(){var b;o.keysAtom_.reportChanged(),(b=o.hasMap_.get(r))==null||b.setNewValue_(!1);var O=o.data_.get(r);O.setNewValue_(void 0),o.data_.delete(r)}),p&&tr(this,g),!0}return!1},t.updateValue_=function(r,o){var u=this.data_.get(r);if(o=u.prepareNewValue_(o),o!==he.UNCHANGED){var c=nu(),p=er(this),g=p||c?{observableKind:"map",debugObjectName:this.name_,type:yr,object:this,oldValue:u.value_,name:r,newValue:o}:null;u.setNewValue_(o),p&&tr(this,g)}},t.addValue_=function(r,o){var u=this;this.keysAtom_,Dr(function(){var b,O=new Xi(o,u.enhancer_,"ObservableMap.key",!1);u.data_.set(r,O),o=O.value_,(b=u.hasMap_.get(r))==null||b.setNewValue_(!0),u.keysAtom_.reportChanged()});var c=nu(),p=er(this),g=p||c?{observableKind:"map",debugObjectName:this.name_,type:ui,object:this,name:r,newValue:o}:null;p&&tr(this,g)},t.get=function(r){return this.has(r)?this.dehanceValue_(this.data_.get(r).get()):this.dehanceValue_(void 0)},t.dehanceValue_=function(r){return this.dehancer!==void 0?this.dehancer(r):r},t.keys=function(){return this.keysAtom_.reportObserved(),this.data_.keys()},t.values=function(){var r=this,o=this.keys();return au({next:function(){var c=o.next(),p=c.done,g=c.value;return{done:p,value:p?void 0:r.get(g)}}})},t.entries=function(){var r=this,o=this.keys();return au({next:function(){var c=o.next(),p=c.done,g=c.value;return{done:p,value:p?void 0:[g,r.get(g)]}}})},t[a_]=function(){return this.entries()},t.forEach=function(r,o){for(var u=Mo(this),c;!(c=u()).done;){var p=c.value,g=p[0],b=p[1];r.call(o,b,g,this)}},t.merge=function(r){var o=this;return ai(r)&&(r=new Map(r)),Dr(function(){ri(r)?VE(r).forEach(function(u){return o.set(u,r[u])}):Array.isArray(r)?r.forEach(function(u){var c=u[0],p=u[1];return o.set(c,p)}):qo(r)?(r.constructor!==Map&&Ue(19,r),r.forEach(function(u,c){return o.set(c,u)})):r!=null&&Ue(20,r)}),this},t.clear=function(){var r=this;Dr(function(){Uv(function(){for(var o=Mo(r.keys()),u;!(u=o()).done;){var c=u.value;r.delete(c)}})})},t.replace=function(r){var o=this;return Dr(function(){for(var u=Ck(r),c=new Map,p=!1,g=Mo(o.data_.keys()),b;!(b=g()).done;){var O=b.value;if(!u.has(O)){var P=o.delete(O);if(P)p=!0;else{var q=o.data_.get(O);c.set(O,q)}}}for(var Z=Mo(u.entries()),M;!(M=Z()).done;){var H=M.value,z=H[0],L=H[1],k=o.data_.has(z);if(o.set(z,L),o.data_.has(z)){var x=o.data_.get(z);c.set(z,x),k||(p=!0)}}if(!p)if(o.data_.size!==c.size)o.keysAtom_.reportChanged();else for(var l=o.data_.keys(),h=c.keys(),d=l.next(),_=h.next();!d.done;){if(d.value!==_.value){o.keysAtom_.reportChanged();break}d=l.next(),_=h.next()}o.data_=c}),this},t.toString=function(){return"[object ObservableMap]"},t.toJSON=function(){return Array.from(this)},t.observe_=function(r,o){return su(this,r)},t.intercept_=function(r){return ou(this,r)},Hd(e,[{key:"size",get:function(){return this.keysAtom_.reportObserved(),this.data_.size}},{key:l_,get:function(){return"Map"}}]),e}(),ai=ii("ObservableMap",c_);function Ck(e){if(qo(e)||ai(e))return e;if(Array.isArray(e))return new Map(e);if(ri(e)){var t=new Map;for(var n in e)t.set(n,e[n]);return t}else return Ue(21,e)}var d_,f_,Tk={};d_=Symbol.iterator,f_=Symbol.toStringTag;var p_=function(){function e(n,r,o){r===void 0&&(r=Bo),o===void 0&&(o="ObservableSet"),this.name_=void 0,this[Le]=Tk,this.data_=new Set,this.atom_=void 0,this.changeListeners_=void 0,this.interceptors_=void 0,this.dehancer=void 0,this.enhancer_=void 0,this.name_=o,Zn(Set)||Ue(22),this.atom_=Ys(this.name_),this.enhancer_=function(u,c){return r(u,c,o)},n&&this.replace(n)}var t=e.prototype;return t.dehanceValue_=function(r){return this.dehancer!==void 0?this.dehancer(r):r},t.clear=function(){var r=this;Dr(function(){Uv(function(){for(var o=Mo(r.data_.values()),u;!(u=o()).done;){var c=u.value;r.delete(c)}})})},t.forEach=function(r,o){for(var u=Mo(this),c;!(c=u()).done;){var p=c.value;r.call(o,p,p,this)}},t.add=function(r){var o=this;if(this.atom_,Rn(this)){var u=qn(this,{type:ui,object:this,newValue:r});if(!u)return this}if(!this.has(r)){Dr(function(){o.data_.add(o.enhancer_(r,void 0)),o.atom_.reportChanged()});var c=!1,p=er(this),g=p||c?{observableKind:"set",debugObjectName:this.name_,type:ui,object:this,newValue:r}:null;p&&tr(this,g)}return this},t.delete=function(r){var o=this;if(Rn(this)){var u=qn(this,{type:ll,object:this,oldValue:r});if(!u)return!1}if(this.has(r)){var c=!1,p=er(this),g=p||c?{observableKind:"set",debugObjectName:this.name_,type:ll,object:this,oldValue:r}:null;return Dr(function(){o.atom_.reportChanged(),o.data_.delete(r)}),p&&tr(this,g),!0}return!1},t.has=function(r){return this.atom_.reportObserved(),this.data_.has(this.dehanceValue_(r))},t.entries=function(){var r=0,o=Array.from(this.keys()),u=Array.from(this.values());return au({next:function(){var p=r;return r+=1,p<u.length?{value:[o[p],u[p]],done:!1}:{done:!0}}})},t.keys=function(){return this.values()},t.values=function(){this.atom_.reportObserved();var r=this,o=0,u=Array.from(this.data_.values());return au({next:function(){return o<u.length?{value:r.dehanceValue_(u[o++]),done:!1}:{done:!0}}})},t.replace=function(r){var o=this;return Ji(r)&&(r=new Set(r)),Dr(function(){Array.isArray(r)?(o.clear(),r.forEach(function(u){return o.add(u)})):Gs(r)?(o.clear(),r.forEach(function(u){return o.add(u)})):r!=null&&Ue("Cannot initialize set from "+r)}),this},t.observe_=function(r,o){return su(this,r)},t.intercept_=function(r){return ou(this,r)},t.toJSON=function(){return Array.from(this)},t.toString=function(){return"[object ObservableSet]"},t[d_]=function(){return this.values()},Hd(e,[{key:"size",get:function(){return this.atom_.reportObserved(),this.data_.size}},{key:f_,get:function(){return"Set"}}]),e}(),Ji=ii("ObservableSet",p_),h_=Object.create(null),g_="remove",m_=function(){function e(n,r,o,u){r===void 0&&(r=new Map),u===void 0&&(u=v2),this.target_=void 0,this.values_=void 0,this.name_=void 0,this.defaultAnnotation_=void 0,this.keysAtom_=void 0,this.changeListeners_=void 0,this.interceptors_=void 0,this.proxy_=void 0,this.isPlainObject_=void 0,this.appliedAnnotations_=void 0,this.pendingKeys_=void 0,this.target_=n,this.values_=r,this.name_=o,this.defaultAnnotation_=u,this.keysAtom_=new Ks("ObservableObject.keys"),this.isPlainObject_=ri(this.target_)}var t=e.prototype;return t.getObservablePropValue_=function(r){return this.values_.get(r).get()},t.setObservablePropValue_=function(r,o){var u=this.values_.get(r);if(u instanceof Js)return u.set(o),!0;if(Rn(this)){var c=qn(this,{type:yr,object:this.proxy_||this.target_,name:r,newValue:o});if(!c)return null;o=c.newValue}if(o=u.prepareNewValue_(o),o!==he.UNCHANGED){var p=er(this),g=!1,b=p||g?{type:yr,observableKind:"object",debugObjectName:this.name_,object:this.proxy_||this.target_,oldValue:u.value_,name:r,newValue:o}:null;u.setNewValue_(o),p&&tr(this,b)}return!0},t.get_=function(r){return he.trackingDerivation&&!Pr(this.target_,r)&&this.has_(r),this.target_[r]},t.set_=function(r,o,u){return u===void 0&&(u=!1),Pr(this.target_,r)?this.values_.has(r)?this.setObservablePropValue_(r,o):u?Reflect.set(this.target_,r,o):(this.target_[r]=o,!0):this.extend_(r,{value:o,enumerable:!0,writable:!0,configurable:!0},this.defaultAnnotation_,u)},t.has_=function(r){if(!he.trackingDerivation)return r in this.target_;this.pendingKeys_||(this.pendingKeys_=new Map);var o=this.pendingKeys_.get(r);return o||(o=new Xi(r in this.target_,Wa,"ObservableObject.key?",!1),this.pendingKeys_.set(r,o)),o.get()},t.make_=function(r,o){if(o===!0&&(o=this.defaultAnnotation_),o!==!1){if(!(r in this.target_)){var u;if((u=this.target_[vr])!=null&&u[r])return;Ue(1,o.annotationType_,this.name_+"."+r.toString())}for(var c=this.target_;c&&c!==Hs;){var p=ja(c,r);if(p){var g=o.make_(this,r,p,c);if(g===0)return;if(g===1)break}c=Object.getPrototypeOf(c)}__(this,o,r)}},t.extend_=function(r,o,u,c){if(c===void 0&&(c=!1),u===!0&&(u=this.defaultAnnotation_),u===!1)return this.defineProperty_(r,o,c);var p=u.extend_(this,r,o,c);return p&&__(this,u,r),p},t.defineProperty_=function(r,o,u){u===void 0&&(u=!1);try{_n();var c=this.delete_(r);if(!c)return c;if(Rn(this)){var p=qn(this,{object:this.proxy_||this.target_,name:r,type:ui,newValue:o.value});if(!p)return null;var g=p.newValue;o.value!==g&&(o=Io({},o,{value:g}))}if(u){if(!Reflect.defineProperty(this.target_,r,o))return!1}else mr(this.target_,r,o);this.notifyPropertyAddition_(r,o.value)}finally{yn()}return!0},t.defineObservableProperty_=function(r,o,u,c){c===void 0&&(c=!1);try{_n();var p=this.delete_(r);if(!p)return p;if(Rn(this)){var g=qn(this,{object:this.proxy_||this.target_,name:r,type:ui,newValue:o});if(!g)return null;o=g.newValue}var b=v_(r),O={configurable:he.safeDescriptors?this.isPlainObject_:!0,enumerable:!0,get:b.get,set:b.set};if(c){if(!Reflect.defineProperty(this.target_,r,O))return!1}else mr(this.target_,r,O);var P=new Xi(o,u,"ObservableObject.key",!1);this.values_.set(r,P),this.notifyPropertyAddition_(r,P.value_)}finally{yn()}return!0},t.defineComputedProperty_=function(r,o,u){u===void 0&&(u=!1);try{_n();var c=this.delete_(r);if(!c)return c;if(Rn(this)){var p=qn(this,{object:this.proxy_||this.target_,name:r,type:ui,newValue:void 0});if(!p)return null}o.name||(o.name="ObservableObject.key"),o.context=this.proxy_||this.target_;var g=v_(r),b={configurable:he.safeDescriptors?this.isPlainObject_:!0,enumerable:!1,get:g.get,set:g.set};if(u){if(!Reflect.defineProperty(this.target_,r,b))return!1}else mr(this.target_,r,b);this.values_.set(r,new Js(o)),this.notifyPropertyAddition_(r,void 0)}finally{yn()}return!0},t.delete_=function(r,o){if(o===void 0&&(o=!1),!Pr(this.target_,r))return!0;if(Rn(this)){var u=qn(this,{object:this.proxy_||this.target_,name:r,type:g_});if(!u)return null}try{var c,p;_n();var g=er(this),b=!1,O=this.values_.get(r),P=void 0;if(!O&&(g||b)){var q;P=(q=ja(this.target_,r))==null?void 0:q.value}if(o){if(!Reflect.deleteProperty(this.target_,r))return!1}else delete this.target_[r];if(O&&(this.values_.delete(r),O instanceof Xi&&(P=O.value_),Wv(O)),this.keysAtom_.reportChanged(),(c=this.pendingKeys_)==null||(p=c.get(r))==null||p.set(r in this.target_),g||b){var Z={type:g_,observableKind:"object",object:this.proxy_||this.target_,debugObjectName:this.name_,oldValue:P,name:r};g&&tr(this,Z)}}finally{yn()}return!0},t.observe_=function(r,o){return su(this,r)},t.intercept_=function(r){return ou(this,r)},t.notifyPropertyAddition_=function(r,o){var u,c,p=er(this),g=!1;if(p||g){var b=p||g?{type:ui,observableKind:"object",debugObjectName:this.name_,object:this.proxy_||this.target_,name:r,newValue:o}:null;p&&tr(this,b)}(u=this.pendingKeys_)==null||(c=u.get(r))==null||c.set(!0),this.keysAtom_.reportChanged()},t.ownKeys_=function(){return this.keysAtom_.reportObserved(),Ha(this.target_)},t.keys_=function(){return this.keysAtom_.reportObserved(),Object.keys(this.target_)},e}();function cl(e,t){var n;if(Pr(e,Le))return e;var r=(n=t==null?void 0:t.name)!=null?n:"ObservableObject",o=new m_(e,new Map,String(r),T2(t));return Va(e,Le,o),e}var xk=ii("ObservableObjectAdministration",m_);function v_(e){return h_[e]||(h_[e]={get:function(){return this[Le].getObservablePropValue_(e)},set:function(n){return this[Le].setObservablePropValue_(e,n)}})}function Vo(e){return Fa(e)?xk(e[Le]):!1}function __(e,t,n){var r;(r=e.target_[vr])==null||delete r[n]}var Lk=w_(0),nf=0,y_=function(){};function Ok(e,t){Object.setPrototypeOf?Object.setPrototypeOf(e.prototype,t):e.prototype.__proto__!==void 0?e.prototype.__proto__=t:e.prototype=t}Ok(y_,Array.prototype);var rf=function(e,t,n){Sv(r,e);function r(u,c,p,g){var b;p===void 0&&(p="ObservableArray"),g===void 0&&(g=!1),b=e.call(this)||this;var O=new tf(p,c,g,!0);if(O.proxy_=Wd(b),_v(Wd(b),Le,O),u&&u.length){var P=Xs(!0);b.spliceWithArray(0,0,u),Zs(P)}return Object.defineProperty(Wd(b),"0",Lk),b}var o=r.prototype;return o.concat=function(){this[Le].atom_.reportObserved();for(var c=arguments.length,p=new Array(c),g=0;g<c;g++)p[g]=arguments[g];return Array.prototype.concat.apply(this.slice(),p.map(function(b){return uu(b)?b.slice():b}))},o[n]=function(){var u=this,c=0;return au({next:function(){return c<u.length?{value:u[c++],done:!1}:{done:!0,value:void 0}}})},Hd(r,[{key:"length",get:function(){return this[Le].getArrayLength_()},set:function(c){this[Le].setArrayLength_(c)}},{key:t,get:function(){return"Array"}}]),r}(y_,Symbol.toStringTag,Symbol.iterator);Object.entries(al).forEach(function(e){var t=e[0],n=e[1];t!=="concat"&&Va(rf.prototype,t,n)});function w_(e){return{enumerable:!1,configurable:!0,get:function(){return this[Le].get_(e)},set:function(n){this[Le].set_(e,n)}}}function Pk(e){mr(rf.prototype,""+e,w_(e))}function b_(e){if(e>nf){for(var t=nf;t<e+100;t++)Pk(t);nf=e}}b_(1e3);function Dk(e,t,n){return new rf(e,t,n)}function li(e,t){if(typeof e=="object"&&e!==null){if(uu(e))return t!==void 0&&Ue(23),e[Le].atom_;if(Ji(e))return e.atom_;if(ai(e)){if(t===void 0)return e.keysAtom_;var n=e.data_.get(t)||e.hasMap_.get(t);return n||Ue(25,t,of(e)),n}if(Vo(e)){if(!t)return Ue(26);var r=e[Le].values_.get(t);return r||Ue(27,t,of(e)),r}if(Kd(e)||eu(e)||il(e))return e}else if(Zn(e)&&il(e[Le]))return e[Le];Ue(28)}function S_(e,t){if(e||Ue(29),t!==void 0)return S_(li(e,t));if(Kd(e)||eu(e)||il(e)||ai(e)||Ji(e))return e;if(e[Le])return e[Le];Ue(24,e)}function of(e,t){var n;if(t!==void 0)n=li(e,t);else{if(Jd(e))return e.name;Vo(e)||ai(e)||Ji(e)?n=S_(e):n=li(e)}return n.name_}var E_=Hs.toString;function sf(e,t,n){return n===void 0&&(n=-1),uf(e,t,n)}function uf(e,t,n,r,o){if(e===t)return e!==0||1/e===1/t;if(e==null||t==null)return!1;if(e!==e)return t!==t;var u=typeof e;if(u!=="function"&&u!=="object"&&typeof t!="object")return!1;var c=E_.call(e);if(c!==E_.call(t))return!1;switch(c){case"[object RegExp]":case"[object String]":return""+e==""+t;case"[object Number]":return+e!=+e?+t!=+t:+e==0?1/+e===1/t:+e==+t;case"[object Date]":case"[object Boolean]":return+e==+t;case"[object Symbol]":return typeof Symbol<"u"&&Symbol.valueOf.call(e)===Symbol.valueOf.call(t);case"[object Map]":case"[object Set]":n>=0&&n++;break}e=k_(e),t=k_(t);var p=c==="[object Array]";if(!p){if(typeof e!="object"||typeof t!="object")return!1;var g=e.constructor,b=t.constructor;if(g!==b&&!(Zn(g)&&g instanceof g&&Zn(b)&&b instanceof b)&&"constructor"in e&&"constructor"in t)return!1}if(n===0)return!1;n<0&&(n=-1),r=r||[],o=o||[];for(var O=r.length;O--;)if(r[O]===e)return o[O]===t;if(r.push(e),o.push(t),p){if(O=e.length,O!==t.length)return!1;for(;O--;)if(!uf(e[O],t[O],n-1,r,o))return!1}else{var P=Object.keys(e),q;if(O=P.length,Object.keys(t).length!==O)return!1;for(;O--;)if(q=P[O],!(Pr(t,q)&&uf(e[q],t[q],n-1,r,o)))return!1}return r.pop(),o.pop(),!0}function k_(e){return uu(e)?e.slice():qo(e)||ai(e)||Gs(e)||Ji(e)?Array.from(e.entries()):e}function au(e){return e[Symbol.iterator]=Nk,e}function Nk(){return this}if(["Symbol","Map","Set"].forEach(function(e){var t=Fd();typeof t[e]>"u"&&Ue("MobX requires global '"+e+"' to be available or polyfilled")}),typeof __MOBX_DEVTOOLS_GLOBAL_HOOK__=="object"&&__MOBX_DEVTOOLS_GLOBAL_HOOK__.injectMobx({spy:W2,extras:{getDebugName:of},$mobx:Le}),!Ee.useState)throw new Error("mobx-react-lite requires React with Hooks support");if(!wk)throw new Error("mobx-react-lite@3 requires mobx at least version 6 to be available");var A_={exports:{}},wn={},C_={exports:{}},T_={};/**
- * @license React
- * scheduler.production.min.js
- *
- * Copyright (c) Facebook, Inc. and its affiliates.
- *
- * This source code is licensed under the MIT license found in the
- * LICENSE file in the root directory of this source tree.
- */(function(e){function t(G,ee){var ie=G.length;G.push(ee);e:for(;0<ie;){var le=ie-1>>>1,Oe=G[le];if(0<o(Oe,ee))G[le]=ee,G[ie]=Oe,ie=le;else break e}}function n(G){return G.length===0?null:G[0]}function r(G){if(G.length===0)return null;var ee=G[0],ie=G.pop();if(ie!==ee){G[0]=ie;e:for(var le=0,Oe=G.length,Xe=Oe>>>1;le<Xe;){var tt=2*(le+1)-1,je=G[tt],Me=tt+1,Ze=G[Me];if(0>o(je,ie))Me<Oe&&0>o(Ze,je)?(G[le]=Ze,G[Me]=ie,le=Me):(G[le]=je,G[tt]=ie,le=tt);else if(Me<Oe&&0>o(Ze,ie))G[le]=Ze,G[Me]=ie,le=Me;else break e}}return ee}function o(G,ee){var ie=G.sortIndex-ee.sortIndex;return ie!==0?ie:G.id-ee.id}if(typeof performance=="object"&&typeof performance.now=="function"){var u=performance;e.unstable_now=function(){return u.now()}}else{var c=Date,p=c.now();e.unstable_now=function(){return c.now()-p}}var g=[],b=[],O=1,P=null,q=3,Z=!1,M=!1,H=!1,z=typeof setTimeout=="function"?setTimeout:null,L=typeof clearTimeout=="function"?clearTimeout:null,k=typeof setImmediate<"u"?setImmediate:null;typeof navigator<"u"&&navigator.scheduling!==void 0&&navigator.scheduling.isInputPending!==void 0&&navigator.scheduling.isInputPending.bind(navigator.scheduling);function x(G){for(var ee=n(b);ee!==null;){if(ee.callback===null)r(b);else if(ee.startTime<=G)r(b),ee.sortIndex=ee.expirationTime,t(g,ee);else break;ee=n(b)}}function l(G){if(H=!1,x(G),!M)if(n(g)!==null)M=!0,T(h);else{var ee=n(b);ee!==null&&Q(l,ee.startTime-G)}}function h(G,ee){M=!1,H&&(H=!1,L(v),v=-1),Z=!0;var ie=q;try{for(x(ee),P=n(g);P!==null&&(!(P.expirationTime>ee)||G&&!E());){var le=P.callback;if(typeof le=="function"){P.callback=null,q=P.priorityLevel;var Oe=le(P.expirationTime<=ee);ee=e.unstable_now(),typeof Oe=="function"?P.callback=Oe:P===n(g)&&r(g),x(ee)}else r(g);P=n(g)}if(P!==null)var Xe=!0;else{var tt=n(b);tt!==null&&Q(l,tt.startTime-ee),Xe=!1}return Xe}finally{P=null,q=ie,Z=!1}}var d=!1,_=null,v=-1,y=5,m=-1;function E(){return!(e.unstable_now()-m<y)}function D(){if(_!==null){var G=e.unstable_now();m=G;var ee=!0;try{ee=_(!0,G)}finally{ee?j():(d=!1,_=null)}}else d=!1}var j;if(typeof k=="function")j=function(){k(D)};else if(typeof MessageChannel<"u"){var V=new MessageChannel,W=V.port2;V.port1.onmessage=D,j=function(){W.postMessage(null)}}else j=function(){z(D,0)};function T(G){_=G,d||(d=!0,j())}function Q(G,ee){v=z(function(){G(e.unstable_now())},ee)}e.unstable_IdlePriority=5,e.unstable_ImmediatePriority=1,e.unstable_LowPriority=4,e.unstable_NormalPriority=3,e.unstable_Profiling=null,e.unstable_UserBlockingPriority=2,e.unstable_cancelCallback=function(G){G.callback=null},e.unstable_continueExecution=function(){M||Z||(M=!0,T(h))},e.unstable_forceFrameRate=function(G){0>G||125<G?console.error("forceFrameRate takes a positive int between 0 and 125, forcing frame rates higher than 125 fps is not supported"):y=0<G?Math.floor(1e3/G):5},e.unstable_getCurrentPriorityLevel=function(){return q},e.unstable_getFirstCallbackNode=function(){return n(g)},e.unstable_next=function(G){switch(q){case 1:case 2:case 3:var ee=3;break;default:ee=q}var ie=q;q=ee;try{return G()}finally{q=ie}},e.unstable_pauseExecution=function(){},e.unstable_requestPaint=function(){},e.unstable_runWithPriority=function(G,ee){switch(G){case 1:case 2:case 3:case 4:case 5:break;default:G=3}var ie=q;q=G;try{return ee()}finally{q=ie}},e.unstable_scheduleCallback=function(G,ee,ie){var le=e.unstable_now();switch(typeof ie=="object"&&ie!==null?(ie=ie.delay,ie=typeof ie=="number"&&0<ie?le+ie:le):ie=le,G){case 1:var Oe=-1;break;case 2:Oe=250;break;case 5:Oe=1073741823;break;case 4:Oe=1e4;break;default:Oe=5e3}return Oe=ie+Oe,G={id:O++,callback:ee,priorityLevel:G,startTime:ie,expirationTime:Oe,sortIndex:-1},ie>le?(G.sortIndex=ie,t(b,G),n(g)===null&&G===n(b)&&(H?(L(v),v=-1):H=!0,Q(l,ie-le))):(G.sortIndex=Oe,t(g,G),M||Z||(M=!0,T(h))),G},e.unstable_shouldYield=E,e.unstable_wrapCallback=function(G){var ee=q;return function(){var ie=q;q=ee;try{return G.apply(this,arguments)}finally{q=ie}}}})(T_),C_.exports=T_;var Rk=C_.exports;/**
- * @license React
- * react-dom.production.min.js
- *
- * Copyright (c) Facebook, Inc. and its affiliates.
- *
- * This source code is licensed under the MIT license found in the
- * LICENSE file in the root directory of this source tree.
- */var x_=Ee,bn=Rk;function de(e){for(var t="https://reactjs.org/docs/error-decoder.html?invariant="+e,n=1;n<arguments.length;n++)t+="&args[]="+encodeURIComponent(arguments[n]);return"Minified React error #"+e+"; visit "+t+" for the full message or use the non-minified dev environment for full errors and additional helpful warnings."}var L_=new Set,lu={};function eo(e,t){Ho(e,t),Ho(e+"Capture",t)}function Ho(e,t){for(lu[e]=t,e=0;e<t.length;e++)L_.add(t[e])}var Rr=!(typeof window>"u"||typeof window.document>"u"||typeof window.document.createElement>"u"),af=Object.prototype.hasOwnProperty,qk=/^[:A-Z_a-z\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u02FF\u0370-\u037D\u037F-\u1FFF\u200C-\u200D\u2070-\u218F\u2C00-\u2FEF\u3001-\uD7FF\uF900-\uFDCF\uFDF0-\uFFFD][:A-Z_a-z\u00C0-\u00D6\u00D8-\u00F6\u00F8-\u02FF\u0370-\u037D\u037F-\u1FFF\u200C-\u200D\u2070-\u218F\u2C00-\u2FEF\u3001-\uD7FF\uF900-\uFDCF\uFDF0-\uFFFD\-.0-9\u00B7\u0300-\u036F\u203F-\u2040]*$/,O_={},P_={};function Ik(e){return af.call(P_,e)?!0:af.call(O_,e)?!1:qk.test(e)?P_[e]=!0:(O_[e]=!0,!1)}function Mk(e,t,n,r){if(n!==null&&n.type===0)return!1;switch(typeof t){case"function":case"symbol":return!0;case"boolean":return r?!1:n!==null?!n.acceptsBooleans:(e=e.toLowerCase().slice(0,5),e!=="data-"&&e!=="aria-");default:return!1}}function Bk(e,t,n,r){if(t===null||typeof t>"u"||Mk(e,t,n,r))return!0;if(r)return!1;if(n!==null)switch(n.type){case 3:return!t;case 4:return t===!1;case 5:return isNaN(t);case 6:return isNaN(t)||1>t}return!1}function Kt(e,t,n,r,o,u,c){this.acceptsBooleans=t===2||t===3||t===4,this.attributeName=r,this.attributeNamespace=o,this.mustUseProperty=n,this.propertyName=e,this.type=t,this.sanitizeURL=u,this.removeEmptyString=c}var Rt={};"children dangerouslySetInnerHTML defaultValue defaultChecked innerHTML suppressContentEditableWarning suppressHydrationWarning style".split(" ").forEach(function(e){Rt[e]=new Kt(e,0,!1,e,null,!1,!1)}),[["acceptCharset","accept-charset"],["className","class"],["htmlFor","for"],["httpEquiv","http-equiv"]].forEach(function(e){var t=e[0];Rt[t]=new Kt(t,1,!1,e[1],null,!1,!1)}),["contentEditable","draggable","spellCheck","value"].forEach(function(e){Rt[e]=new Kt(e,2,!1,e.toLowerCase(),null,!1,!1)}),["autoReverse","externalResourcesRequired","focusable","preserveAlpha"].forEach(function(e){Rt[e]=new Kt(e,2,!1,e,null,!1,!1)}),"allowFullScreen async autoFocus autoPlay controls default defer disabled disablePictureInPicture disableRemotePlayback formNoValidate hidden loop noModule noValidate open playsInline readOnly required reversed scoped seamless itemScope".split(" ").forEach(function(e){Rt[e]=new Kt(e,3,!1,e.toLowerCase(),null,!1,!1)}),["checked","multiple","muted","selected"].forEach(function(e){Rt[e]=new Kt(e,3,!0,e,null,!1,!1)}),["capture","download"].forEach(function(e){Rt[e]=new Kt(e,4,!1,e,null,!1,!1)}),["cols","rows","size","span"].forEach(function(e){Rt[e]=new Kt(e,6,!1,e,null,!1,!1)}),["rowSpan","start"].forEach(function(e){Rt[e]=new Kt(e,5,!1,e.toLowerCase(),null,!1,!1)});var lf=/[\-:]([a-z])/g;function cf(e){return e[1].toUpperCase()}"accent-height alignment-baseline arabic-form baseline-shift cap-height clip-path clip-rule color-interpolation color-interpolation-filters color-profile color-rendering dominant-baseline enable-background fill-opacity fill-rule flood-color flood-opacity font-family font-size font-size-adjust font-stretch font-style font-variant font-weight glyph-name glyph-orientation-horizontal glyph-orientation-vertical horiz-adv-x horiz-origin-x image-rendering letter-spacing lighting-color marker-end marker-mid marker-start overline-position overline-thickness paint-order panose-1 pointer-events rendering-intent shape-rendering stop-color stop-opacity strikethrough-position strikethrough-thickness stroke-dasharray stroke-dashoffset stroke-linecap stroke-linejoin stroke-miterlimit stroke-opacity stroke-width text-anchor text-decoration text-rendering underline-position underline-thickness unicode-bidi unicode-range units-per-em v-alphabetic v-hanging v-ideographic v-mathematical vector-effect vert-adv-y vert-origin-x vert-origin-y word-spacing writing-mode xmlns:xlink x-height".split(" ").forEach(function(e){var t=e.replace(lf,cf);Rt[t]=new Kt(t,1,!1,e,null,!1,!1)}),"xlink:actuate xlink:arcrole xlink:role xlink:show xlink:title xlink:type".split(" ").forEach(function(e){var t=e.replace(lf,cf);Rt[t]=new Kt(t,1,!1,e,"http://www.w3.org/1999/xlink",!1,!1)}),["xml:base","xml:lang","xml:space"].forEach(function(e){var t=e.replace(lf,cf);Rt[t]=new Kt(t,1,!1,e,"http://www.w3.org/XML/1998/namespace",!1,!1)}),["tabIndex","crossOrigin"].forEach(function(e){Rt[e]=new Kt(e,1,!1,e.toLowerCase(),null,!1,!1)}),Rt.xlinkHref=new Kt("xlinkHref",1,!1,"xlink:href","http://www.w3.org/1999/xlink",!0,!1),["src","href","action","formAction"].forEach(function(e){Rt[e]=new Kt(e,1,!1,e.toLowerCase(),null,!0,!0)});function df(e,t,n,r){var o=Rt.hasOwnProperty(t)?Rt[t]:null;(o!==null?o.type!==0:r||!(2<t.length)||t[0]!=="o"&&t[0]!=="O"||t[1]!=="n"&&t[1]!=="N")&&(Bk(t,n,o,r)&&(n=null),r||o===null?Ik(t)&&(n===null?e.removeAttribute(t):e.setAttribute(t,""+n)):o.mustUseProperty?e[o.propertyName]=n===null?o.type===3?!1:"":n:(t=o.attributeName,r=o.attributeNamespace,n===null?e.removeAttribute(t):(o=o.type,n=o===3||o===4&&n===!0?"":""+n,r?e.setAttributeNS(r,t,n):e.setAttribute(t,n))))}var qr=x_.__SECRET_INTERNALS_DO_NOT_USE_OR_YOU_WILL_BE_FIRED,dl=Symbol.for("react.element"),Go=Symbol.for("react.portal"),Wo=Symbol.for("react.fragment"),ff=Symbol.for("react.strict_mode"),pf=Symbol.for("react.profiler"),D_=Symbol.for("react.provider"),N_=Symbol.for("react.context"),hf=Symbol.for("react.forward_ref"),gf=Symbol.for("react.suspense"),mf=Symbol.for("react.suspense_list"),vf=Symbol.for("react.memo"),ci=Symbol.for("react.lazy"),R_=Symbol.for("react.offscreen"),q_=Symbol.iterator;function cu(e){return e===null||typeof e!="object"?null:(e=q_&&e[q_]||e["@@iterator"],typeof e=="function"?e:null)}var lt=Object.assign,_f;function du(e){if(_f===void 0)try{throw Error()}catch(n){var t=n.stack.trim().match(/\n( *(at )?)/);_f=t&&t[1]||""}return`
-`+_f+e}var yf=!1;function wf(e,t){if(!e||yf)return"";yf=!0;var n=Error.prepareStackTrace;Error.prepareStackTrace=void 0;try{if(t)if(t=function(){throw Error()},Object.defineProperty(t.prototype,"props",{set:function(){throw Error()}}),typeof Reflect=="object"&&Reflect.construct){try{Reflect.construct(t,[])}catch(b){var r=b}Reflect.construct(e,[],t)}else{try{t.call()}catch(b){r=b}e.call(t.prototype)}else{try{throw Error()}catch(b){r=b}e()}}catch(b){if(b&&r&&typeof b.stack=="string"){for(var o=b.stack.split(`
-`),u=r.stack.split(`
-`),c=o.length-1,p=u.length-1;1<=c&&0<=p&&o[c]!==u[p];)p--;for(;1<=c&&0<=p;c--,p--)if(o[c]!==u[p]){if(c!==1||p!==1)do if(c--,p--,0>p||o[c]!==u[p]){var g=`
-`+o[c].replace(" at new "," at ");return e.displayName&&g.includes("<anonymous>")&&(g=g.replace("<anonymous>",e.displayName)),g}while(1<=c&&0<=p);break}}}finally{yf=!1,Error.prepareStackTrace=n}return(e=e?e.displayName||e.name:"")?du(e):""}function zk(e){switch(e.tag){case 5:return du(e.type);case 16:return du("Lazy");case 13:return du("Suspense");case 19:return du("SuspenseList");case 0:case 2:case 15:return e=wf(e.type,!1),e;case 11:return e=wf(e.type.render,!1),e;case 1:return e=wf(e.type,!0),e;default:return""}}function bf(e){if(e==null)return null;if(typeof e=="function")return e.displayName||e.name||null;if(typeof e=="string")return e;switch(e){case Wo:return"Fragment";case Go:return"Portal";case pf:return"Profiler";case ff:return"StrictMode";case gf:return"Suspense";case mf:return"SuspenseList"}if(typeof e=="object")switch(e.$$typeof){case N_:return(e.displayName||"Context")+".Consumer";case D_:return(e._context.displayName||"Context")+".Provider";case hf:var t=e.render;return e=e.displayName,e||(e=t.displayName||t.name||"",e=e!==""?"ForwardRef("+e+")":"ForwardRef"),e;case vf:return t=e.displayName||null,t!==null?t:bf(e.type)||"Memo";case ci:t=e._payload,e=e._init;try{return bf(e(t))}catch{}}return null}function $k(e){var t=e.type;switch(e.tag){case 24:return"Cache";case 9:return(t.displayName||"Context")+".Consumer";case 10:return(t._context.displayName||"Context")+".Provider";case 18:return"DehydratedFragment";case 11:return e=t.render,e=e.displayName||e.name||"",t.displayName||(e!==""?"ForwardRef("+e+")":"ForwardRef");case 7:return"Fragment";case 5:return t;case 4:return"Portal";case 3:return"Root";case 6:return"Text";case 16:return bf(t);case 8:return t===ff?"StrictMode":"Mode";case 22:return"Offscreen";case 12:return"Profiler";case 21:return"Scope";case 13:return"Suspense";case 19:return"SuspenseList";case 25:return"TracingMarker";case 1:case 0:case 17:case 2:case 14:case 15:if(typeof t=="function")return t.displayName||t.name||null;if(typeof t=="string")return t}return null}function di(e){switch(typeof e){case"boolean":case"number":case"string":case"undefined":return e;case"object":return e;default:return""}}function I_(e){var t=e.type;return(e=e.nodeName)&&e.toLowerCase()==="input"&&(t==="checkbox"||t==="radio")}function Uk(e){var t=I_(e)?"checked":"value",n=Object.getOwnPropertyDescriptor(e.constructor.prototype,t),r=""+e[t];if(!e.hasOwnProperty(t)&&typeof n<"u"&&typeof n.get=="function"&&typeof n.set=="function"){var o=n.get,u=n.set;return Object.defineProperty(e,t,{configurable:!0,get:function(){return o.call(this)},set:function(c){r=""+c,u.call(this,c)}}),Object.defineProperty(e,t,{enumerable:n.enumerable}),{getValue:function(){return r},setValue:function(c){r=""+c},stopTracking:function(){e._valueTracker=null,delete e[t]}}}}function fl(e){e._valueTracker||(e._valueTracker=Uk(e))}function M_(e){if(!e)return!1;var t=e._valueTracker;if(!t)return!0;var n=t.getValue(),r="";return e&&(r=I_(e)?e.checked?"true":"false":e.value),e=r,e!==n?(t.setValue(e),!0):!1}function pl(e){if(e=e||(typeof document<"u"?document:void 0),typeof e>"u")return null;try{return e.activeElement||e.body}catch{return e.body}}function Sf(e,t){var n=t.checked;return lt({},t,{defaultChecked:void 0,defaultValue:void 0,value:void 0,checked:n??e._wrapperState.initialChecked})}function B_(e,t){var n=t.defaultValue==null?"":t.defaultValue,r=t.checked!=null?t.checked:t.defaultChecked;n=di(t.value!=null?t.value:n),e._wrapperState={initialChecked:r,initialValue:n,controlled:t.type==="checkbox"||t.type==="radio"?t.checked!=null:t.value!=null}}function z_(e,t){t=t.checked,t!=null&&df(e,"checked",t,!1)}function Ef(e,t){z_(e,t);var n=di(t.value),r=t.type;if(n!=null)r==="number"?(n===0&&e.value===""||e.value!=n)&&(e.value=""+n):e.value!==""+n&&(e.value=""+n);else if(r==="submit"||r==="reset"){e.removeAttribute("value");return}t.hasOwnProperty("value")?kf(e,t.type,n):t.hasOwnProperty("defaultValue")&&kf(e,t.type,di(t.defaultValue)),t.checked==null&&t.defaultChecked!=null&&(e.defaultChecked=!!t.defaultChecked)}function $_(e,t,n){if(t.hasOwnProperty("value")||t.hasOwnProperty("defaultValue")){var r=t.type;if(!(r!=="submit"&&r!=="reset"||t.value!==void 0&&t.value!==null))return;t=""+e._wrapperState.initialValue,n||t===e.value||(e.value=t),e.defaultValue=t}n=e.name,n!==""&&(e.name=""),e.defaultChecked=!!e._wrapperState.initialChecked,n!==""&&(e.name=n)}function kf(e,t,n){(t!=="number"||pl(e.ownerDocument)!==e)&&(n==null?e.defaultValue=""+e._wrapperState.initialValue:e.defaultValue!==""+n&&(e.defaultValue=""+n))}var fu=Array.isArray;function Ko(e,t,n,r){if(e=e.options,t){t={};for(var o=0;o<n.length;o++)t["$"+n[o]]=!0;for(n=0;n<e.length;n++)o=t.hasOwnProperty("$"+e[n].value),e[n].selected!==o&&(e[n].selected=o),o&&r&&(e[n].defaultSelected=!0)}else{for(n=""+di(n),t=null,o=0;o<e.length;o++){if(e[o].value===n){e[o].selected=!0,r&&(e[o].defaultSelected=!0);return}t!==null||e[o].disabled||(t=e[o])}t!==null&&(t.selected=!0)}}function Af(e,t){if(t.dangerouslySetInnerHTML!=null)throw Error(de(91));return lt({},t,{value:void 0,defaultValue:void 0,children:""+e._wrapperState.initialValue})}function U_(e,t){var n=t.value;if(n==null){if(n=t.children,t=t.defaultValue,n!=null){if(t!=null)throw Error(de(92));if(fu(n)){if(1<n.length)throw Error(de(93));n=n[0]}t=n}t==null&&(t=""),n=t}e._wrapperState={initialValue:di(n)}}function j_(e,t){var n=di(t.value),r=di(t.defaultValue);n!=null&&(n=""+n,n!==e.value&&(e.value=n),t.defaultValue==null&&e.defaultValue!==n&&(e.defaultValue=n)),r!=null&&(e.defaultValue=""+r)}function F_(e){var t=e.textContent;t===e._wrapperState.initialValue&&t!==""&&t!==null&&(e.value=t)}function V_(e){switch(e){case"svg":return"http://www.w3.org/2000/svg";case"math":return"http://www.w3.org/1998/Math/MathML";default:return"http://www.w3.org/1999/xhtml"}}function Cf(e,t){return e==null||e==="http://www.w3.org/1999/xhtml"?V_(t):e==="http://www.w3.org/2000/svg"&&t==="foreignObject"?"http://www.w3.org/1999/xhtml":e}var hl,H_=function(e){return typeof MSApp<"u"&&MSApp.execUnsafeLocalFunction?function(t,n,r,o){MSApp.execUnsafeLocalFunction(function(){return e(t,n,r,o)})}:e}(function(e,t){if(e.namespaceURI!=="http://www.w3.org/2000/svg"||"innerHTML"in e)e.innerHTML=t;else{for(hl=hl||document.createElement("div"),hl.innerHTML="<svg>"+t.valueOf().toString()+"</svg>",t=hl.firstChild;e.firstChild;)e.removeChild(e.firstChild);for(;t.firstChild;)e.appendChild(t.firstChild)}});function pu(e,t){if(t){var n=e.firstChild;if(n&&n===e.lastChild&&n.nodeType===3){n.nodeValue=t;return}}e.textContent=t}var hu={animationIterationCount:!0,aspectRatio:!0,borderImageOutset:!0,borderImageSlice:!0,borderImageWidth:!0,boxFlex:!0,boxFlexGroup:!0,boxOrdinalGroup:!0,columnCount:!0,columns:!0,flex:!0,flexGrow:!0,flexPositive:!0,flexShrink:!0,flexNegative:!0,flexOrder:!0,gridArea:!0,gridRow:!0,gridRowEnd:!0,gridRowSpan:!0,gridRowStart:!0,gridColumn:!0,gridColumnEnd:!0,gridColumnSpan:!0,gridColumnStart:!0,fontWeight:!0,lineClamp:!0,lineHeight:!0,opacity:!0,order:!0,orphans:!0,tabSize:!0,widows:!0,zIndex:!0,zoom:!0,fillOpacity:!0,floodOpacity:!0,stopOpacity:!0,strokeDasharray:!0,strokeDashoffset:!0,strokeMiterlimit:!0,strokeOpacity:!0,strokeWidth:!0},jk=["Webkit","ms","Moz","O"];Object.keys(hu).forEach(function(e){jk.forEach(function(t){t=t+e.charAt(0).toUpperCase()+e.substring(1),hu[t]=hu[e]})});function G_(e,t,n){return t==null||typeof t=="boolean"||t===""?"":n||typeof t!="number"||t===0||hu.hasOwnProperty(e)&&hu[e]?(""+t).trim():t+"px"}function W_(e,t){e=e.style;for(var n in t)if(t.hasOwnProperty(n)){var r=n.indexOf("--")===0,o=G_(n,t[n],r);n==="float"&&(n="cssFloat"),r?e.setProperty(n,o):e[n]=o}}var Fk=lt({menuitem:!0},{area:!0,base:!0,br:!0,col:!0,embed:!0,hr:!0,img:!0,input:!0,keygen:!0,link:!0,meta:!0,param:!0,source:!0,track:!0,wbr:!0});function Tf(e,t){if(t){if(Fk[e]&&(t.children!=null||t.dangerouslySetInnerHTML!=null))throw Error(de(137,e));if(t.dangerouslySetInnerHTML!=null){if(t.children!=null)throw Error(de(60));if(typeof t.dangerouslySetInnerHTML!="object"||!("__html"in t.dangerouslySetInnerHTML))throw Error(de(61))}if(t.style!=null&&typeof t.style!="object")throw Error(de(62))}}function xf(e,t){if(e.indexOf("-")===-1)return typeof t.is=="string";switch(e){case"annotation-xml":case"color-profile":case"font-face":case"font-face-src":case"font-face-uri":case"font-face-format":case"font-face-name":case"missing-glyph":return!1;default:return!0}}var Lf=null;function Of(e){return e=e.target||e.srcElement||window,e.correspondingUseElement&&(e=e.correspondingUseElement),e.nodeType===3?e.parentNode:e}var Pf=null,Yo=null,Qo=null;function K_(e){if(e=Iu(e)){if(typeof Pf!="function")throw Error(de(280));var t=e.stateNode;t&&(t=Ml(t),Pf(e.stateNode,e.type,t))}}function Y_(e){Yo?Qo?Qo.push(e):Qo=[e]:Yo=e}function Q_(){if(Yo){var e=Yo,t=Qo;if(Qo=Yo=null,K_(e),t)for(e=0;e<t.length;e++)K_(t[e])}}function X_(e,t){return e(t)}function Z_(){}var Df=!1;function J_(e,t,n){if(Df)return e(t,n);Df=!0;try{return X_(e,t,n)}finally{Df=!1,(Yo!==null||Qo!==null)&&(Z_(),Q_())}}function gu(e,t){var n=e.stateNode;if(n===null)return null;var r=Ml(n);if(r===null)return null;n=r[t];e:switch(t){case"onClick":case"onClickCapture":case"onDoubleClick":case"onDoubleClickCapture":case"onMouseDown":case"onMouseDownCapture":case"onMouseMove":case"onMouseMoveCapture":case"onMouseUp":case"onMouseUpCapture":case"onMouseEnter":(r=!r.disabled)||(e=e.type,r=!(e==="button"||e==="input"||e==="select"||e==="textarea")),e=!r;break e;default:e=!1}if(e)return null;if(n&&typeof n!="function")throw Error(de(231,t,typeof n));return n}var Nf=!1;if(Rr)try{var mu={};Object.defineProperty(mu,"passive",{get:function(){Nf=!0}}),window.addEventListener("test",mu,mu),window.removeEventListener("test",mu,mu)}catch{Nf=!1}function Vk(e,t,n,r,o,u,c,p,g){var b=Array.prototype.slice.call(arguments,3);try{t.apply(n,b)}catch(O){this.onError(O)}}var vu=!1,gl=null,ml=!1,Rf=null,Hk={onError:function(e){vu=!0,gl=e}};function Gk(e,t,n,r,o,u,c,p,g){vu=!1,gl=null,Vk.apply(Hk,arguments)}function Wk(e,t,n,r,o,u,c,p,g){if(Gk.apply(this,arguments),vu){if(vu){var b=gl;vu=!1,gl=null}else throw Error(de(198));ml||(ml=!0,Rf=b)}}function to(e){var t=e,n=e;if(e.alternate)for(;t.return;)t=t.return;else{e=t;do t=e,t.flags&4098&&(n=t.return),e=t.return;while(e)}return t.tag===3?n:null}function ey(e){if(e.tag===13){var t=e.memoizedState;if(t===null&&(e=e.alternate,e!==null&&(t=e.memoizedState)),t!==null)return t.dehydrated}return null}function ty(e){if(to(e)!==e)throw Error(de(188))}function Kk(e){var t=e.alternate;if(!t){if(t=to(e),t===null)throw Error(de(188));return t!==e?null:e}for(var n=e,r=t;;){var o=n.return;if(o===null)break;var u=o.alternate;if(u===null){if(r=o.return,r!==null){n=r;continue}break}if(o.child===u.child){for(u=o.child;u;){if(u===n)return ty(o),e;if(u===r)return ty(o),t;u=u.sibling}throw Error(de(188))}if(n.return!==r.return)n=o,r=u;else{for(var c=!1,p=o.child;p;){if(p===n){c=!0,n=o,r=u;break}if(p===r){c=!0,r=o,n=u;break}p=p.sibling}if(!c){for(p=u.child;p;){if(p===n){c=!0,n=u,r=o;break}if(p===r){c=!0,r=u,n=o;break}p=p.sibling}if(!c)throw Error(de(189))}}if(n.alternate!==r)throw Error(de(190))}if(n.tag!==3)throw Error(de(188));return n.stateNode.current===n?e:t}function ny(e){return e=Kk(e),e!==null?ry(e):null}function ry(e){if(e.tag===5||e.tag===6)return e;for(e=e.child;e!==null;){var t=ry(e);if(t!==null)return t;e=e.sibling}return null}var iy=bn.unstable_scheduleCallback,oy=bn.unstable_cancelCallback,Yk=bn.unstable_shouldYield,Qk=bn.unstable_requestPaint,mt=bn.unstable_now,Xk=bn.unstable_getCurrentPriorityLevel,qf=bn.unstable_ImmediatePriority,sy=bn.unstable_UserBlockingPriority,vl=bn.unstable_NormalPriority,Zk=bn.unstable_LowPriority,uy=bn.unstable_IdlePriority,_l=null,wr=null;function Jk(e){if(wr&&typeof wr.onCommitFiberRoot=="function")try{wr.onCommitFiberRoot(_l,e,void 0,(e.current.flags&128)===128)}catch{}}var nr=Math.clz32?Math.clz32:nA,eA=Math.log,tA=Math.LN2;function nA(e){return e>>>=0,e===0?32:31-(eA(e)/tA|0)|0}var yl=64,wl=4194304;function _u(e){switch(e&-e){case 1:return 1;case 2:return 2;case 4:return 4;case 8:return 8;case 16:return 16;case 32:return 32;case 64:case 128:case 256:case 512:case 1024:case 2048:case 4096:case 8192:case 16384:case 32768:case 65536:case 131072:case 262144:case 524288:case 1048576:case 2097152:return e&4194240;case 4194304:case 8388608:case 16777216:case 33554432:case 67108864:return e&130023424;case 134217728:return 134217728;case 268435456:return 268435456;case 536870912:return 536870912;case 1073741824:return 1073741824;default:return e}}function bl(e,t){var n=e.pendingLanes;if(n===0)return 0;var r=0,o=e.suspendedLanes,u=e.pingedLanes,c=n&268435455;if(c!==0){var p=c&~o;p!==0?r=_u(p):(u&=c,u!==0&&(r=_u(u)))}else c=n&~o,c!==0?r=_u(c):u!==0&&(r=_u(u));if(r===0)return 0;if(t!==0&&t!==r&&!(t&o)&&(o=r&-r,u=t&-t,o>=u||o===16&&(u&4194240)!==0))return t;if(r&4&&(r|=n&16),t=e.entangledLanes,t!==0)for(e=e.entanglements,t&=r;0<t;)n=31-nr(t),o=1<<n,r|=e[n],t&=~o;return r}function rA(e,t){switch(e){case 1:case 2:case 4:return t+250;case 8:case 16:case 32:case 64:case 128:case 256:case 512:case 1024:case 2048:case 4096:case 8192:case 16384:case 32768:case 65536:case 131072:case 262144:case 524288:case 1048576:case 2097152:return t+5e3;case 4194304:case 8388608:case 16777216:case 33554432:case 67108864:return-1;case 134217728:case 268435456:case 536870912:case 1073741824:return-1;default:return-1}}function iA(e,t){for(var n=e.suspendedLanes,r=e.pingedLanes,o=e.expirationTimes,u=e.pendingLanes;0<u;){var c=31-nr(u),p=1<<c,g=o[c];g===-1?(!(p&n)||p&r)&&(o[c]=rA(p,t)):g<=t&&(e.expiredLanes|=p),u&=~p}}function If(e){return e=e.pendingLanes&-1073741825,e!==0?e:e&1073741824?1073741824:0}function ay(){var e=yl;return yl<<=1,!(yl&4194240)&&(yl=64),e}function Mf(e){for(var t=[],n=0;31>n;n++)t.push(e);return t}function yu(e,t,n){e.pendingLanes|=t,t!==536870912&&(e.suspendedLanes=0,e.pingedLanes=0),e=e.eventTimes,t=31-nr(t),e[t]=n}function oA(e,t){var n=e.pendingLanes&~t;e.pendingLanes=t,e.suspendedLanes=0,e.pingedLanes=0,e.expiredLanes&=t,e.mutableReadLanes&=t,e.entangledLanes&=t,t=e.entanglements;var r=e.eventTimes;for(e=e.expirationTimes;0<n;){var o=31-nr(n),u=1<<o;t[o]=0,r[o]=-1,e[o]=-1,n&=~u}}function Bf(e,t){var n=e.entangledLanes|=t;for(e=e.entanglements;n;){var r=31-nr(n),o=1<<r;o&t|e[r]&t&&(e[r]|=t),n&=~o}}var Ke=0;function ly(e){return e&=-e,1<e?4<e?e&268435455?16:536870912:4:1}var cy,zf,dy,fy,py,$f=!1,Sl=[],fi=null,pi=null,hi=null,wu=new Map,bu=new Map,gi=[],sA="mousedown mouseup touchcancel touchend touchstart auxclick dblclick pointercancel pointerdown pointerup dragend dragstart drop compositionend compositionstart keydown keypress keyup input textInput copy cut paste click change contextmenu reset submit".split(" ");function hy(e,t){switch(e){case"focusin":case"focusout":fi=null;break;case"dragenter":case"dragleave":pi=null;break;case"mouseover":case"mouseout":hi=null;break;case"pointerover":case"pointerout":wu.delete(t.pointerId);break;case"gotpointercapture":case"lostpointercapture":bu.delete(t.pointerId)}}function Su(e,t,n,r,o,u){return e===null||e.nativeEvent!==u?(e={blockedOn:t,domEventName:n,eventSystemFlags:r,nativeEvent:u,targetContainers:[o]},t!==null&&(t=Iu(t),t!==null&&zf(t)),e):(e.eventSystemFlags|=r,t=e.targetContainers,o!==null&&t.indexOf(o)===-1&&t.push(o),e)}function uA(e,t,n,r,o){switch(t){case"focusin":return fi=Su(fi,e,t,n,r,o),!0;case"dragenter":return pi=Su(pi,e,t,n,r,o),!0;case"mouseover":return hi=Su(hi,e,t,n,r,o),!0;case"pointerover":var u=o.pointerId;return wu.set(u,Su(wu.get(u)||null,e,t,n,r,o)),!0;case"gotpointercapture":return u=o.pointerId,bu.set(u,Su(bu.get(u)||null,e,t,n,r,o)),!0}return!1}function gy(e){var t=no(e.target);if(t!==null){var n=to(t);if(n!==null){if(t=n.tag,t===13){if(t=ey(n),t!==null){e.blockedOn=t,py(e.priority,function(){dy(n)});return}}else if(t===3&&n.stateNode.current.memoizedState.isDehydrated){e.blockedOn=n.tag===3?n.stateNode.containerInfo:null;return}}}e.blockedOn=null}function El(e){if(e.blockedOn!==null)return!1;for(var t=e.targetContainers;0<t.length;){var n=jf(e.domEventName,e.eventSystemFlags,t[0],e.nativeEvent);if(n===null){n=e.nativeEvent;var r=new n.constructor(n.type,n);Lf=r,n.target.dispatchEvent(r),Lf=null}else return t=Iu(n),t!==null&&zf(t),e.blockedOn=n,!1;t.shift()}return!0}function my(e,t,n){El(e)&&n.delete(t)}function aA(){$f=!1,fi!==null&&El(fi)&&(fi=null),pi!==null&&El(pi)&&(pi=null),hi!==null&&El(hi)&&(hi=null),wu.forEach(my),bu.forEach(my)}function Eu(e,t){e.blockedOn===t&&(e.blockedOn=null,$f||($f=!0,bn.unstable_scheduleCallback(bn.unstable_NormalPriority,aA)))}function ku(e){function t(o){return Eu(o,e)}if(0<Sl.length){Eu(Sl[0],e);for(var n=1;n<Sl.length;n++){var r=Sl[n];r.blockedOn===e&&(r.blockedOn=null)}}for(fi!==null&&Eu(fi,e),pi!==null&&Eu(pi,e),hi!==null&&Eu(hi,e),wu.forEach(t),bu.forEach(t),n=0;n<gi.length;n++)r=gi[n],r.blockedOn===e&&(r.blockedOn=null);for(;0<gi.length&&(n=gi[0],n.blockedOn===null);)gy(n),n.blockedOn===null&&gi.shift()}var Xo=qr.ReactCurrentBatchConfig,kl=!0;function lA(e,t,n,r){var o=Ke,u=Xo.transition;Xo.transition=null;try{Ke=1,Uf(e,t,n,r)}finally{Ke=o,Xo.transition=u}}function cA(e,t,n,r){var o=Ke,u=Xo.transition;Xo.transition=null;try{Ke=4,Uf(e,t,n,r)}finally{Ke=o,Xo.transition=u}}function Uf(e,t,n,r){if(kl){var o=jf(e,t,n,r);if(o===null)op(e,t,r,Al,n),hy(e,r);else if(uA(o,e,t,n,r))r.stopPropagation();else if(hy(e,r),t&4&&-1<sA.indexOf(e)){for(;o!==null;){var u=Iu(o);if(u!==null&&cy(u),u=jf(e,t,n,r),u===null&&op(e,t,r,Al,n),u===o)break;o=u}o!==null&&r.stopPropagation()}else op(e,t,r,null,n)}}var Al=null;function jf(e,t,n,r){if(Al=null,e=Of(r),e=no(e),e!==null)if(t=to(e),t===null)e=null;else if(n=t.tag,n===13){if(e=ey(t),e!==null)return e;e=null}else if(n===3){if(t.stateNode.current.memoizedState.isDehydrated)return t.tag===3?t.stateNode.containerInfo:null;e=null}else t!==e&&(e=null);return Al=e,null}function vy(e){switch(e){case"cancel":case"click":case"close":case"contextmenu":case"copy":case"cut":case"auxclick":case"dblclick":case"dragend":case"dragstart":case"drop":case"focusin":case"focusout":case"input":case"invalid":case"keydown":case"keypress":case"keyup":case"mousedown":case"mouseup":case"paste":case"pause":case"play":case"pointercancel":case"pointerdown":case"pointerup":case"ratechange":case"reset":case"resize":case"seeked":case"submit":case"touchcancel":case"touchend":case"touchstart":case"volumechange":case"change":case"selectionchange":case"textInput":case"compositionstart":case"compositionend":case"compositionupdate":case"beforeblur":case"afterblur":case"beforeinput":case"blur":case"fullscreenchange":case"focus":case"hashchange":case"popstate":case"select":case"selectstart":return 1;case"drag":case"dragenter":case"dragexit":case"dragleave":case"dragover":case"mousemove":case"mouseout":case"mouseover":case"pointermove":case"pointerout":case"pointerover":case"scroll":case"toggle":case"touchmove":case"wheel":case"mouseenter":case"mouseleave":case"pointerenter":case"pointerleave":return 4;case"message":switch(Xk()){case qf:return 1;case sy:return 4;case vl:case Zk:return 16;case uy:return 536870912;default:return 16}default:return 16}}var mi=null,Ff=null,Cl=null;function _y(){if(Cl)return Cl;var e,t=Ff,n=t.length,r,o="value"in mi?mi.value:mi.textContent,u=o.length;for(e=0;e<n&&t[e]===o[e];e++);var c=n-e;for(r=1;r<=c&&t[n-r]===o[u-r];r++);return Cl=o.slice(e,1<r?1-r:void 0)}function Tl(e){var t=e.keyCode;return"charCode"in e?(e=e.charCode,e===0&&t===13&&(e=13)):e=t,e===10&&(e=13),32<=e||e===13?e:0}function xl(){return!0}function yy(){return!1}function Sn(e){function t(n,r,o,u,c){this._reactName=n,this._targetInst=o,this.type=r,this.nativeEvent=u,this.target=c,this.currentTarget=null;for(var p in e)e.hasOwnProperty(p)&&(n=e[p],this[p]=n?n(u):u[p]);return this.isDefaultPrevented=(u.defaultPrevented!=null?u.defaultPrevented:u.returnValue===!1)?xl:yy,this.isPropagationStopped=yy,this}return lt(t.prototype,{preventDefault:function(){this.defaultPrevented=!0;var n=this.nativeEvent;n&&(n.preventDefault?n.preventDefault():typeof n.returnValue!="unknown"&&(n.returnValue=!1),this.isDefaultPrevented=xl)},stopPropagation:function(){var n=this.nativeEvent;n&&(n.stopPropagation?n.stopPropagation():typeof n.cancelBubble!="unknown"&&(n.cancelBubble=!0),this.isPropagationStopped=xl)},persist:function(){},isPersistent:xl}),t}var Zo={eventPhase:0,bubbles:0,cancelable:0,timeStamp:function(e){return e.timeStamp||Date.now()},defaultPrevented:0,isTrusted:0},Vf=Sn(Zo),Au=lt({},Zo,{view:0,detail:0}),dA=Sn(Au),Hf,Gf,Cu,Ll=lt({},Au,{screenX:0,screenY:0,clientX:0,clientY:0,pageX:0,pageY:0,ctrlKey:0,shiftKey:0,altKey:0,metaKey:0,getModifierState:Kf,button:0,buttons:0,relatedTarget:function(e){return e.relatedTarget===void 0?e.fromElement===e.srcElement?e.toElement:e.fromElement:e.relatedTarget},movementX:function(e){return"movementX"in e?e.movementX:(e!==Cu&&(Cu&&e.type==="mousemove"?(Hf=e.screenX-Cu.screenX,Gf=e.screenY-Cu.screenY):Gf=Hf=0,Cu=e),Hf)},movementY:function(e){return"movementY"in e?e.movementY:Gf}}),wy=Sn(Ll),fA=lt({},Ll,{dataTransfer:0}),pA=Sn(fA),hA=lt({},Au,{relatedTarget:0}),Wf=Sn(hA),gA=lt({},Zo,{animationName:0,elapsedTime:0,pseudoElement:0}),mA=Sn(gA),vA=lt({},Zo,{clipboardData:function(e){return"clipboardData"in e?e.clipboardData:window.clipboardData}}),_A=Sn(vA),yA=lt({},Zo,{data:0}),by=Sn(yA),wA={Esc:"Escape",Spacebar:" ",Left:"ArrowLeft",Up:"ArrowUp",Right:"ArrowRight",Down:"ArrowDown",Del:"Delete",Win:"OS",Menu:"ContextMenu",Apps:"ContextMenu",Scroll:"ScrollLock",MozPrintableKey:"Unidentified"},bA={8:"Backspace",9:"Tab",12:"Clear",13:"Enter",16:"Shift",17:"Control",18:"Alt",19:"Pause",20:"CapsLock",27:"Escape",32:" ",33:"PageUp",34:"PageDown",35:"End",36:"Home",37:"ArrowLeft",38:"ArrowUp",39:"ArrowRight",40:"ArrowDown",45:"Insert",46:"Delete",112:"F1",113:"F2",114:"F3",115:"F4",116:"F5",117:"F6",118:"F7",119:"F8",120:"F9",121:"F10",122:"F11",123:"F12",144:"NumLock",145:"ScrollLock",224:"Meta"},SA={Alt:"altKey",Control:"ctrlKey",Meta:"metaKey",Shift:"shiftKey"};function EA(e){var t=this.nativeEvent;return t.getModifierState?t.getModifierState(e):(e=SA[e])?!!t[e]:!1}function Kf(){return EA}var kA=lt({},Au,{key:function(e){if(e.key){var t=wA[e.key]||e.key;if(t!=="Unidentified")return t}return e.type==="keypress"?(e=Tl(e),e===13?"Enter":String.fromCharCode(e)):e.type==="keydown"||e.type==="keyup"?bA[e.keyCode]||"Unidentified":""},code:0,location:0,ctrlKey:0,shiftKey:0,altKey:0,metaKey:0,repeat:0,locale:0,getModifierState:Kf,charCode:function(e){return e.type==="keypress"?Tl(e):0},keyCode:function(e){return e.type==="keydown"||e.type==="keyup"?e.keyCode:0},which:function(e){return e.type==="keypress"?Tl(e):e.type==="keydown"||e.type==="keyup"?e.keyCode:0}}),AA=Sn(kA),CA=lt({},Ll,{pointerId:0,width:0,height:0,pressure:0,tangentialPressure:0,tiltX:0,tiltY:0,twist:0,pointerType:0,isPrimary:0}),Sy=Sn(CA),TA=lt({},Au,{touches:0,targetTouches:0,changedTouches:0,altKey:0,metaKey:0,ctrlKey:0,shiftKey:0,getModifierState:Kf}),xA=Sn(TA),LA=lt({},Zo,{propertyName:0,elapsedTime:0,pseudoElement:0}),OA=Sn(LA),PA=lt({},Ll,{deltaX:function(e){return"deltaX"in e?e.deltaX:"wheelDeltaX"in e?-e.wheelDeltaX:0},deltaY:function(e){return"deltaY"in e?e.deltaY:"wheelDeltaY"in e?-e.wheelDeltaY:"wheelDelta"in e?-e.wheelDelta:0},deltaZ:0,deltaMode:0}),DA=Sn(PA),NA=[9,13,27,32],Yf=Rr&&"CompositionEvent"in window,Tu=null;Rr&&"documentMode"in document&&(Tu=document.documentMode);var RA=Rr&&"TextEvent"in window&&!Tu,Ey=Rr&&(!Yf||Tu&&8<Tu&&11>=Tu),ky=String.fromCharCode(32),Ay=!1;function Cy(e,t){switch(e){case"keyup":return NA.indexOf(t.keyCode)!==-1;case"keydown":return t.keyCode!==229;case"keypress":case"mousedown":case"focusout":return!0;default:return!1}}function Ty(e){return e=e.detail,typeof e=="object"&&"data"in e?e.data:null}var Jo=!1;function qA(e,t){switch(e){case"compositionend":return Ty(t);case"keypress":return t.which!==32?null:(Ay=!0,ky);case"textInput":return e=t.data,e===ky&&Ay?null:e;default:return null}}function IA(e,t){if(Jo)return e==="compositionend"||!Yf&&Cy(e,t)?(e=_y(),Cl=Ff=mi=null,Jo=!1,e):null;switch(e){case"paste":return null;case"keypress":if(!(t.ctrlKey||t.altKey||t.metaKey)||t.ctrlKey&&t.altKey){if(t.char&&1<t.char.length)return t.char;if(t.which)return String.fromCharCode(t.which)}return null;case"compositionend":return Ey&&t.locale!=="ko"?null:t.data;default:return null}}var MA={color:!0,date:!0,datetime:!0,"datetime-local":!0,email:!0,month:!0,number:!0,password:!0,range:!0,search:!0,tel:!0,text:!0,time:!0,url:!0,week:!0};function xy(e){var t=e&&e.nodeName&&e.nodeName.toLowerCase();return t==="input"?!!MA[e.type]:t==="textarea"}function Ly(e,t,n,r){Y_(r),t=Rl(t,"onChange"),0<t.length&&(n=new Vf("onChange","change",null,n,r),e.push({event:n,listeners:t}))}var xu=null,Lu=null;function BA(e){Wy(e,0)}function Ol(e){var t=is(e);if(M_(t))return e}function zA(e,t){if(e==="change")return t}var Oy=!1;if(Rr){var Qf;if(Rr){var Xf="oninput"in document;if(!Xf){var Py=document.createElement("div");Py.setAttribute("oninput","return;"),Xf=typeof Py.oninput=="function"}Qf=Xf}else Qf=!1;Oy=Qf&&(!document.documentMode||9<document.documentMode)}function Dy(){xu&&(xu.detachEvent("onpropertychange",Ny),Lu=xu=null)}function Ny(e){if(e.propertyName==="value"&&Ol(Lu)){var t=[];Ly(t,Lu,e,Of(e)),J_(BA,t)}}function $A(e,t,n){e==="focusin"?(Dy(),xu=t,Lu=n,xu.attachEvent("onpropertychange",Ny)):e==="focusout"&&Dy()}function UA(e){if(e==="selectionchange"||e==="keyup"||e==="keydown")return Ol(Lu)}function jA(e,t){if(e==="click")return Ol(t)}function FA(e,t){if(e==="input"||e==="change")return Ol(t)}function VA(e,t){return e===t&&(e!==0||1/e===1/t)||e!==e&&t!==t}var rr=typeof Object.is=="function"?Object.is:VA;function Ou(e,t){if(rr(e,t))return!0;if(typeof e!="object"||e===null||typeof t!="object"||t===null)return!1;var n=Object.keys(e),r=Object.keys(t);if(n.length!==r.length)return!1;for(r=0;r<n.length;r++){var o=n[r];if(!af.call(t,o)||!rr(e[o],t[o]))return!1}return!0}function Ry(e){for(;e&&e.firstChild;)e=e.firstChild;return e}function qy(e,t){var n=Ry(e);e=0;for(var r;n;){if(n.nodeType===3){if(r=e+n.textContent.length,e<=t&&r>=t)return{node:n,offset:t-e};e=r}e:{for(;n;){if(n.nextSibling){n=n.nextSibling;break e}n=n.parentNode}n=void 0}n=Ry(n)}}function Iy(e,t){return e&&t?e===t?!0:e&&e.nodeType===3?!1:t&&t.nodeType===3?Iy(e,t.parentNode):"contains"in e?e.contains(t):e.compareDocumentPosition?!!(e.compareDocumentPosition(t)&16):!1:!1}function My(){for(var e=window,t=pl();t instanceof e.HTMLIFrameElement;){try{var n=typeof t.contentWindow.location.href=="string"}catch{n=!1}if(n)e=t.contentWindow;else break;t=pl(e.document)}return t}function Zf(e){var t=e&&e.nodeName&&e.nodeName.toLowerCase();return t&&(t==="input"&&(e.type==="text"||e.type==="search"||e.type==="tel"||e.type==="url"||e.type==="password")||t==="textarea"||e.contentEditable==="true")}function HA(e){var t=My(),n=e.focusedElem,r=e.selectionRange;if(t!==n&&n&&n.ownerDocument&&Iy(n.ownerDocument.documentElement,n)){if(r!==null&&Zf(n)){if(t=r.start,e=r.end,e===void 0&&(e=t),"selectionStart"in n)n.selectionStart=t,n.selectionEnd=Math.min(e,n.value.length);else if(e=(t=n.ownerDocument||document)&&t.defaultView||window,e.getSelection){e=e.getSelection();var o=n.textContent.length,u=Math.min(r.start,o);r=r.end===void 0?u:Math.min(r.end,o),!e.extend&&u>r&&(o=r,r=u,u=o),o=qy(n,u);var c=qy(n,r);o&&c&&(e.rangeCount!==1||e.anchorNode!==o.node||e.anchorOffset!==o.offset||e.focusNode!==c.node||e.focusOffset!==c.offset)&&(t=t.createRange(),t.setStart(o.node,o.offset),e.removeAllRanges(),u>r?(e.addRange(t),e.extend(c.node,c.offset)):(t.setEnd(c.node,c.offset),e.addRange(t)))}}for(t=[],e=n;e=e.parentNode;)e.nodeType===1&&t.push({element:e,left:e.scrollLeft,top:e.scrollTop});for(typeof n.focus=="function"&&n.focus(),n=0;n<t.length;n++)e=t[n],e.element.scrollLeft=e.left,e.element.scrollTop=e.top}}var GA=Rr&&"documentMode"in document&&11>=document.documentMode,es=null,Jf=null,Pu=null,ep=!1;function By(e,t,n){var r=n.window===n?n.document:n.nodeType===9?n:n.ownerDocument;ep||es==null||es!==pl(r)||(r=es,"selectionStart"in r&&Zf(r)?r={start:r.selectionStart,end:r.selectionEnd}:(r=(r.ownerDocument&&r.ownerDocument.defaultView||window).getSelection(),r={anchorNode:r.anchorNode,anchorOffset:r.anchorOffset,focusNode:r.focusNode,focusOffset:r.focusOffset}),Pu&&Ou(Pu,r)||(Pu=r,r=Rl(Jf,"onSelect"),0<r.length&&(t=new Vf("onSelect","select",null,t,n),e.push({event:t,listeners:r}),t.target=es)))}function Pl(e,t){var n={};return n[e.toLowerCase()]=t.toLowerCase(),n["Webkit"+e]="webkit"+t,n["Moz"+e]="moz"+t,n}var ts={animationend:Pl("Animation","AnimationEnd"),animationiteration:Pl("Animation","AnimationIteration"),animationstart:Pl("Animation","AnimationStart"),transitionend:Pl("Transition","TransitionEnd")},tp={},zy={};Rr&&(zy=document.createElement("div").style,"AnimationEvent"in window||(delete ts.animationend.animation,delete ts.animationiteration.animation,delete ts.animationstart.animation),"TransitionEvent"in window||delete ts.transitionend.transition);function Dl(e){if(tp[e])return tp[e];if(!ts[e])return e;var t=ts[e],n;for(n in t)if(t.hasOwnProperty(n)&&n in zy)return tp[e]=t[n];return e}var $y=Dl("animationend"),Uy=Dl("animationiteration"),jy=Dl("animationstart"),Fy=Dl("transitionend"),Vy=new Map,Hy="abort auxClick cancel canPlay canPlayThrough click close contextMenu copy cut drag dragEnd dragEnter dragExit dragLeave dragOver dragStart drop durationChange emptied encrypted ended error gotPointerCapture input invalid keyDown keyPress keyUp load loadedData loadedMetadata loadStart lostPointerCapture mouseDown mouseMove mouseOut mouseOver mouseUp paste pause play playing pointerCancel pointerDown pointerMove pointerOut pointerOver pointerUp progress rateChange reset resize seeked seeking stalled submit suspend timeUpdate touchCancel touchEnd touchStart volumeChange scroll toggle touchMove waiting wheel".split(" ");function vi(e,t){Vy.set(e,t),eo(t,[e])}for(var np=0;np<Hy.length;np++){var rp=Hy[np],WA=rp.toLowerCase(),KA=rp[0].toUpperCase()+rp.slice(1);vi(WA,"on"+KA)}vi($y,"onAnimationEnd"),vi(Uy,"onAnimationIteration"),vi(jy,"onAnimationStart"),vi("dblclick","onDoubleClick"),vi("focusin","onFocus"),vi("focusout","onBlur"),vi(Fy,"onTransitionEnd"),Ho("onMouseEnter",["mouseout","mouseover"]),Ho("onMouseLeave",["mouseout","mouseover"]),Ho("onPointerEnter",["pointerout","pointerover"]),Ho("onPointerLeave",["pointerout","pointerover"]),eo("onChange","change click focusin focusout input keydown keyup selectionchange".split(" ")),eo("onSelect","focusout contextmenu dragend focusin keydown keyup mousedown mouseup selectionchange".split(" ")),eo("onBeforeInput",["compositionend","keypress","textInput","paste"]),eo("onCompositionEnd","compositionend focusout keydown keypress keyup mousedown".split(" ")),eo("onCompositionStart","compositionstart focusout keydown keypress keyup mousedown".split(" ")),eo("onCompositionUpdate","compositionupdate focusout keydown keypress keyup mousedown".split(" "));var Du="abort canplay canplaythrough durationchange emptied encrypted ended error loadeddata loadedmetadata loadstart pause play playing progress ratechange resize seeked seeking stalled suspend timeupdate volumechange waiting".split(" "),YA=new Set("cancel close invalid load scroll toggle".split(" ").concat(Du));function Gy(e,t,n){var r=e.type||"unknown-event";e.currentTarget=n,Wk(r,t,void 0,e),e.currentTarget=null}function Wy(e,t){t=(t&4)!==0;for(var n=0;n<e.length;n++){var r=e[n],o=r.event;r=r.listeners;e:{var u=void 0;if(t)for(var c=r.length-1;0<=c;c--){var p=r[c],g=p.instance,b=p.currentTarget;if(p=p.listener,g!==u&&o.isPropagationStopped())break e;Gy(o,p,b),u=g}else for(c=0;c<r.length;c++){if(p=r[c],g=p.instance,b=p.currentTarget,p=p.listener,g!==u&&o.isPropagationStopped())break e;Gy(o,p,b),u=g}}}if(ml)throw e=Rf,ml=!1,Rf=null,e}function rt(e,t){var n=t[dp];n===void 0&&(n=t[dp]=new Set);var r=e+"__bubble";n.has(r)||(Ky(t,e,2,!1),n.add(r))}function ip(e,t,n){var r=0;t&&(r|=4),Ky(n,e,r,t)}var Nl="_reactListening"+Math.random().toString(36).slice(2);function Nu(e){if(!e[Nl]){e[Nl]=!0,L_.forEach(function(n){n!=="selectionchange"&&(YA.has(n)||ip(n,!1,e),ip(n,!0,e))});var t=e.nodeType===9?e:e.ownerDocument;t===null||t[Nl]||(t[Nl]=!0,ip("selectionchange",!1,t))}}function Ky(e,t,n,r){switch(vy(t)){case 1:var o=lA;break;case 4:o=cA;break;default:o=Uf}n=o.bind(null,t,n,e),o=void 0,!Nf||t!=="touchstart"&&t!=="touchmove"&&t!=="wheel"||(o=!0),r?o!==void 0?e.addEventListener(t,n,{capture:!0,passive:o}):e.addEventListener(t,n,!0):o!==void 0?e.addEventListener(t,n,{passive:o}):e.addEventListener(t,n,!1)}function op(e,t,n,r,o){var u=r;if(!(t&1)&&!(t&2)&&r!==null)e:for(;;){if(r===null)return;var c=r.tag;if(c===3||c===4){var p=r.stateNode.containerInfo;if(p===o||p.nodeType===8&&p.parentNode===o)break;if(c===4)for(c=r.return;c!==null;){var g=c.tag;if((g===3||g===4)&&(g=c.stateNode.containerInfo,g===o||g.nodeType===8&&g.parentNode===o))return;c=c.return}for(;p!==null;){if(c=no(p),c===null)return;if(g=c.tag,g===5||g===6){r=u=c;continue e}p=p.parentNode}}r=r.return}J_(function(){var b=u,O=Of(n),P=[];e:{var q=Vy.get(e);if(q!==void 0){var Z=Vf,M=e;switch(e){case"keypress":if(Tl(n)===0)break e;case"keydown":case"keyup":Z=AA;break;case"focusin":M="focus",Z=Wf;break;case"focusout":M="blur",Z=Wf;break;case"beforeblur":case"afterblur":Z=Wf;break;case"click":if(n.button===2)break e;case"auxclick":case"dblclick":case"mousedown":case"mousemove":case"mouseup":case"mouseout":case"mouseover":case"contextmenu":Z=wy;break;case"drag":case"dragend":case"dragenter":case"dragexit":case"dragleave":case"dragover":case"dragstart":case"drop":Z=pA;break;case"touchcancel":case"touchend":case"touchmove":case"touchstart":Z=xA;break;case $y:case Uy:case jy:Z=mA;break;case Fy:Z=OA;break;case"scroll":Z=dA;break;case"wheel":Z=DA;break;case"copy":case"cut":case"paste":Z=_A;break;case"gotpointercapture":case"lostpointercapture":case"pointercancel":case"pointerdown":case"pointermove":case"pointerout":case"pointerover":case"pointerup":Z=Sy}var H=(t&4)!==0,z=!H&&e==="scroll",L=H?q!==null?q+"Capture":null:q;H=[];for(var k=b,x;k!==null;){x=k;var l=x.stateNode;if(x.tag===5&&l!==null&&(x=l,L!==null&&(l=gu(k,L),l!=null&&H.push(Ru(k,l,x)))),z)break;k=k.return}0<H.length&&(q=new Z(q,M,null,n,O),P.push({event:q,listeners:H}))}}if(!(t&7)){e:{if(q=e==="mouseover"||e==="pointerover",Z=e==="mouseout"||e==="pointerout",q&&n!==Lf&&(M=n.relatedTarget||n.fromElement)&&(no(M)||M[Ir]))break e;if((Z||q)&&(q=O.window===O?O:(q=O.ownerDocument)?q.defaultView||q.parentWindow:window,Z?(M=n.relatedTarget||n.toElement,Z=b,M=M?no(M):null,M!==null&&(z=to(M),M!==z||M.tag!==5&&M.tag!==6)&&(M=null)):(Z=null,M=b),Z!==M)){if(H=wy,l="onMouseLeave",L="onMouseEnter",k="mouse",(e==="pointerout"||e==="pointerover")&&(H=Sy,l="onPointerLeave",L="onPointerEnter",k="pointer"),z=Z==null?q:is(Z),x=M==null?q:is(M),q=new H(l,k+"leave",Z,n,O),q.target=z,q.relatedTarget=x,l=null,no(O)===b&&(H=new H(L,k+"enter",M,n,O),H.target=x,H.relatedTarget=z,l=H),z=l,Z&&M)t:{for(H=Z,L=M,k=0,x=H;x;x=ns(x))k++;for(x=0,l=L;l;l=ns(l))x++;for(;0<k-x;)H=ns(H),k--;for(;0<x-k;)L=ns(L),x--;for(;k--;){if(H===L||L!==null&&H===L.alternate)break t;H=ns(H),L=ns(L)}H=null}else H=null;Z!==null&&Yy(P,q,Z,H,!1),M!==null&&z!==null&&Yy(P,z,M,H,!0)}}e:{if(q=b?is(b):window,Z=q.nodeName&&q.nodeName.toLowerCase(),Z==="select"||Z==="input"&&q.type==="file")var h=zA;else if(xy(q))if(Oy)h=FA;else{h=UA;var d=$A}else(Z=q.nodeName)&&Z.toLowerCase()==="input"&&(q.type==="checkbox"||q.type==="radio")&&(h=jA);if(h&&(h=h(e,b))){Ly(P,h,n,O);break e}d&&d(e,q,b),e==="focusout"&&(d=q._wrapperState)&&d.controlled&&q.type==="number"&&kf(q,"number",q.value)}switch(d=b?is(b):window,e){case"focusin":(xy(d)||d.contentEditable==="true")&&(es=d,Jf=b,Pu=null);break;case"focusout":Pu=Jf=es=null;break;case"mousedown":ep=!0;break;case"contextmenu":case"mouseup":case"dragend":ep=!1,By(P,n,O);break;case"selectionchange":if(GA)break;case"keydown":case"keyup":By(P,n,O)}var _;if(Yf)e:{switch(e){case"compositionstart":var v="onCompositionStart";break e;case"compositionend":v="onCompositionEnd";break e;case"compositionupdate":v="onCompositionUpdate";break e}v=void 0}else Jo?Cy(e,n)&&(v="onCompositionEnd"):e==="keydown"&&n.keyCode===229&&(v="onCompositionStart");v&&(Ey&&n.locale!=="ko"&&(Jo||v!=="onCompositionStart"?v==="onCompositionEnd"&&Jo&&(_=_y()):(mi=O,Ff="value"in mi?mi.value:mi.textContent,Jo=!0)),d=Rl(b,v),0<d.length&&(v=new by(v,e,null,n,O),P.push({event:v,listeners:d}),_?v.data=_:(_=Ty(n),_!==null&&(v.data=_)))),(_=RA?qA(e,n):IA(e,n))&&(b=Rl(b,"onBeforeInput"),0<b.length&&(O=new by("onBeforeInput","beforeinput",null,n,O),P.push({event:O,listeners:b}),O.data=_))}Wy(P,t)})}function Ru(e,t,n){return{instance:e,listener:t,currentTarget:n}}function Rl(e,t){for(var n=t+"Capture",r=[];e!==null;){var o=e,u=o.stateNode;o.tag===5&&u!==null&&(o=u,u=gu(e,n),u!=null&&r.unshift(Ru(e,u,o)),u=gu(e,t),u!=null&&r.push(Ru(e,u,o))),e=e.return}return r}function ns(e){if(e===null)return null;do e=e.return;while(e&&e.tag!==5);return e||null}function Yy(e,t,n,r,o){for(var u=t._reactName,c=[];n!==null&&n!==r;){var p=n,g=p.alternate,b=p.stateNode;if(g!==null&&g===r)break;p.tag===5&&b!==null&&(p=b,o?(g=gu(n,u),g!=null&&c.unshift(Ru(n,g,p))):o||(g=gu(n,u),g!=null&&c.push(Ru(n,g,p)))),n=n.return}c.length!==0&&e.push({event:t,listeners:c})}var QA=/\r\n?/g,XA=/\u0000|\uFFFD/g;function Qy(e){return(typeof e=="string"?e:""+e).replace(QA,`
-`).replace(XA,"")}function ql(e,t,n){if(t=Qy(t),Qy(e)!==t&&n)throw Error(de(425))}function Il(){}var sp=null,up=null;function ap(e,t){return e==="textarea"||e==="noscript"||typeof t.children=="string"||typeof t.children=="number"||typeof t.dangerouslySetInnerHTML=="object"&&t.dangerouslySetInnerHTML!==null&&t.dangerouslySetInnerHTML.__html!=null}var lp=typeof setTimeout=="function"?setTimeout:void 0,ZA=typeof clearTimeout=="function"?clearTimeout:void 0,Xy=typeof Promise=="function"?Promise:void 0,JA=typeof queueMicrotask=="function"?queueMicrotask:typeof Xy<"u"?function(e){return Xy.resolve(null).then(e).catch(eC)}:lp;function eC(e){setTimeout(function(){throw e})}function cp(e,t){var n=t,r=0;do{var o=n.nextSibling;if(e.removeChild(n),o&&o.nodeType===8)if(n=o.data,n==="/$"){if(r===0){e.removeChild(o),ku(t);return}r--}else n!=="$"&&n!=="$?"&&n!=="$!"||r++;n=o}while(n);ku(t)}function _i(e){for(;e!=null;e=e.nextSibling){var t=e.nodeType;if(t===1||t===3)break;if(t===8){if(t=e.data,t==="$"||t==="$!"||t==="$?")break;if(t==="/$")return null}}return e}function Zy(e){e=e.previousSibling;for(var t=0;e;){if(e.nodeType===8){var n=e.data;if(n==="$"||n==="$!"||n==="$?"){if(t===0)return e;t--}else n==="/$"&&t++}e=e.previousSibling}return null}var rs=Math.random().toString(36).slice(2),br="__reactFiber$"+rs,qu="__reactProps$"+rs,Ir="__reactContainer$"+rs,dp="__reactEvents$"+rs,tC="__reactListeners$"+rs,nC="__reactHandles$"+rs;function no(e){var t=e[br];if(t)return t;for(var n=e.parentNode;n;){if(t=n[Ir]||n[br]){if(n=t.alternate,t.child!==null||n!==null&&n.child!==null)for(e=Zy(e);e!==null;){if(n=e[br])return n;e=Zy(e)}return t}e=n,n=e.parentNode}return null}function Iu(e){return e=e[br]||e[Ir],!e||e.tag!==5&&e.tag!==6&&e.tag!==13&&e.tag!==3?null:e}function is(e){if(e.tag===5||e.tag===6)return e.stateNode;throw Error(de(33))}function Ml(e){return e[qu]||null}var fp=[],os=-1;function yi(e){return{current:e}}function it(e){0>os||(e.current=fp[os],fp[os]=null,os--)}function et(e,t){os++,fp[os]=e.current,e.current=t}var wi={},zt=yi(wi),rn=yi(!1),ro=wi;function ss(e,t){var n=e.type.contextTypes;if(!n)return wi;var r=e.stateNode;if(r&&r.__reactInternalMemoizedUnmaskedChildContext===t)return r.__reactInternalMemoizedMaskedChildContext;var o={},u;for(u in n)o[u]=t[u];return r&&(e=e.stateNode,e.__reactInternalMemoizedUnmaskedChildContext=t,e.__reactInternalMemoizedMaskedChildContext=o),o}function on(e){return e=e.childContextTypes,e!=null}function Bl(){it(rn),it(zt)}function Jy(e,t,n){if(zt.current!==wi)throw Error(de(168));et(zt,t),et(rn,n)}function e0(e,t,n){var r=e.stateNode;if(t=t.childContextTypes,typeof r.getChildContext!="function")return n;r=r.getChildContext();for(var o in r)if(!(o in t))throw Error(de(108,$k(e)||"Unknown",o));return lt({},n,r)}function zl(e){return e=(e=e.stateNode)&&e.__reactInternalMemoizedMergedChildContext||wi,ro=zt.current,et(zt,e),et(rn,rn.current),!0}function t0(e,t,n){var r=e.stateNode;if(!r)throw Error(de(169));n?(e=e0(e,t,ro),r.__reactInternalMemoizedMergedChildContext=e,it(rn),it(zt),et(zt,e)):it(rn),et(rn,n)}var Mr=null,$l=!1,pp=!1;function n0(e){Mr===null?Mr=[e]:Mr.push(e)}function rC(e){$l=!0,n0(e)}function bi(){if(!pp&&Mr!==null){pp=!0;var e=0,t=Ke;try{var n=Mr;for(Ke=1;e<n.length;e++){var r=n[e];do r=r(!0);while(r!==null)}Mr=null,$l=!1}catch(o){throw Mr!==null&&(Mr=Mr.slice(e+1)),iy(qf,bi),o}finally{Ke=t,pp=!1}}return null}var us=[],as=0,Ul=null,jl=0,In=[],Mn=0,io=null,Br=1,zr="";function oo(e,t){us[as++]=jl,us[as++]=Ul,Ul=e,jl=t}function r0(e,t,n){In[Mn++]=Br,In[Mn++]=zr,In[Mn++]=io,io=e;var r=Br;e=zr;var o=32-nr(r)-1;r&=~(1<<o),n+=1;var u=32-nr(t)+o;if(30<u){var c=o-o%5;u=(r&(1<<c)-1).toString(32),r>>=c,o-=c,Br=1<<32-nr(t)+o|n<<o|r,zr=u+e}else Br=1<<u|n<<o|r,zr=e}function hp(e){e.return!==null&&(oo(e,1),r0(e,1,0))}function gp(e){for(;e===Ul;)Ul=us[--as],us[as]=null,jl=us[--as],us[as]=null;for(;e===io;)io=In[--Mn],In[Mn]=null,zr=In[--Mn],In[Mn]=null,Br=In[--Mn],In[Mn]=null}var En=null,kn=null,ut=!1,ir=null;function i0(e,t){var n=Un(5,null,null,0);n.elementType="DELETED",n.stateNode=t,n.return=e,t=e.deletions,t===null?(e.deletions=[n],e.flags|=16):t.push(n)}function o0(e,t){switch(e.tag){case 5:var n=e.type;return t=t.nodeType!==1||n.toLowerCase()!==t.nodeName.toLowerCase()?null:t,t!==null?(e.stateNode=t,En=e,kn=_i(t.firstChild),!0):!1;case 6:return t=e.pendingProps===""||t.nodeType!==3?null:t,t!==null?(e.stateNode=t,En=e,kn=null,!0):!1;case 13:return t=t.nodeType!==8?null:t,t!==null?(n=io!==null?{id:Br,overflow:zr}:null,e.memoizedState={dehydrated:t,treeContext:n,retryLane:1073741824},n=Un(18,null,null,0),n.stateNode=t,n.return=e,e.child=n,En=e,kn=null,!0):!1;default:return!1}}function mp(e){return(e.mode&1)!==0&&(e.flags&128)===0}function vp(e){if(ut){var t=kn;if(t){var n=t;if(!o0(e,t)){if(mp(e))throw Error(de(418));t=_i(n.nextSibling);var r=En;t&&o0(e,t)?i0(r,n):(e.flags=e.flags&-4097|2,ut=!1,En=e)}}else{if(mp(e))throw Error(de(418));e.flags=e.flags&-4097|2,ut=!1,En=e}}}function s0(e){for(e=e.return;e!==null&&e.tag!==5&&e.tag!==3&&e.tag!==13;)e=e.return;En=e}function Fl(e){if(e!==En)return!1;if(!ut)return s0(e),ut=!0,!1;var t;if((t=e.tag!==3)&&!(t=e.tag!==5)&&(t=e.type,t=t!=="head"&&t!=="body"&&!ap(e.type,e.memoizedProps)),t&&(t=kn)){if(mp(e))throw u0(),Error(de(418));for(;t;)i0(e,t),t=_i(t.nextSibling)}if(s0(e),e.tag===13){if(e=e.memoizedState,e=e!==null?e.dehydrated:null,!e)throw Error(de(317));e:{for(e=e.nextSibling,t=0;e;){if(e.nodeType===8){var n=e.data;if(n==="/$"){if(t===0){kn=_i(e.nextSibling);break e}t--}else n!=="$"&&n!=="$!"&&n!=="$?"||t++}e=e.nextSibling}kn=null}}else kn=En?_i(e.stateNode.nextSibling):null;return!0}function u0(){for(var e=kn;e;)e=_i(e.nextSibling)}function ls(){kn=En=null,ut=!1}function _p(e){ir===null?ir=[e]:ir.push(e)}var iC=qr.ReactCurrentBatchConfig;function or(e,t){if(e&&e.defaultProps){t=lt({},t),e=e.defaultProps;for(var n in e)t[n]===void 0&&(t[n]=e[n]);return t}return t}var Vl=yi(null),Hl=null,cs=null,yp=null;function wp(){yp=cs=Hl=null}function bp(e){var t=Vl.current;it(Vl),e._currentValue=t}function Sp(e,t,n){for(;e!==null;){var r=e.alternate;if((e.childLanes&t)!==t?(e.childLanes|=t,r!==null&&(r.childLanes|=t)):r!==null&&(r.childLanes&t)!==t&&(r.childLanes|=t),e===n)break;e=e.return}}function ds(e,t){Hl=e,yp=cs=null,e=e.dependencies,e!==null&&e.firstContext!==null&&(e.lanes&t&&(sn=!0),e.firstContext=null)}function Bn(e){var t=e._currentValue;if(yp!==e)if(e={context:e,memoizedValue:t,next:null},cs===null){if(Hl===null)throw Error(de(308));cs=e,Hl.dependencies={lanes:0,firstContext:e}}else cs=cs.next=e;return t}var so=null;function Ep(e){so===null?so=[e]:so.push(e)}function a0(e,t,n,r){var o=t.interleaved;return o===null?(n.next=n,Ep(t)):(n.next=o.next,o.next=n),t.interleaved=n,$r(e,r)}function $r(e,t){e.lanes|=t;var n=e.alternate;for(n!==null&&(n.lanes|=t),n=e,e=e.return;e!==null;)e.childLanes|=t,n=e.alternate,n!==null&&(n.childLanes|=t),n=e,e=e.return;return n.tag===3?n.stateNode:null}var Si=!1;function kp(e){e.updateQueue={baseState:e.memoizedState,firstBaseUpdate:null,lastBaseUpdate:null,shared:{pending:null,interleaved:null,lanes:0},effects:null}}function l0(e,t){e=e.updateQueue,t.updateQueue===e&&(t.updateQueue={baseState:e.baseState,firstBaseUpdate:e.firstBaseUpdate,lastBaseUpdate:e.lastBaseUpdate,shared:e.shared,effects:e.effects})}function Ur(e,t){return{eventTime:e,lane:t,tag:0,payload:null,callback:null,next:null}}function Ei(e,t,n){var r=e.updateQueue;if(r===null)return null;if(r=r.shared,Fe&2){var o=r.pending;return o===null?t.next=t:(t.next=o.next,o.next=t),r.pending=t,$r(e,n)}return o=r.interleaved,o===null?(t.next=t,Ep(r)):(t.next=o.next,o.next=t),r.interleaved=t,$r(e,n)}function Gl(e,t,n){if(t=t.updateQueue,t!==null&&(t=t.shared,(n&4194240)!==0)){var r=t.lanes;r&=e.pendingLanes,n|=r,t.lanes=n,Bf(e,n)}}function c0(e,t){var n=e.updateQueue,r=e.alternate;if(r!==null&&(r=r.updateQueue,n===r)){var o=null,u=null;if(n=n.firstBaseUpdate,n!==null){do{var c={eventTime:n.eventTime,lane:n.lane,tag:n.tag,payload:n.payload,callback:n.callback,next:null};u===null?o=u=c:u=u.next=c,n=n.next}while(n!==null);u===null?o=u=t:u=u.next=t}else o=u=t;n={baseState:r.baseState,firstBaseUpdate:o,lastBaseUpdate:u,shared:r.shared,effects:r.effects},e.updateQueue=n;return}e=n.lastBaseUpdate,e===null?n.firstBaseUpdate=t:e.next=t,n.lastBaseUpdate=t}function Wl(e,t,n,r){var o=e.updateQueue;Si=!1;var u=o.firstBaseUpdate,c=o.lastBaseUpdate,p=o.shared.pending;if(p!==null){o.shared.pending=null;var g=p,b=g.next;g.next=null,c===null?u=b:c.next=b,c=g;var O=e.alternate;O!==null&&(O=O.updateQueue,p=O.lastBaseUpdate,p!==c&&(p===null?O.firstBaseUpdate=b:p.next=b,O.lastBaseUpdate=g))}if(u!==null){var P=o.baseState;c=0,O=b=g=null,p=u;do{var q=p.lane,Z=p.eventTime;if((r&q)===q){O!==null&&(O=O.next={eventTime:Z,lane:0,tag:p.tag,payload:p.payload,callback:p.callback,next:null});e:{var M=e,H=p;switch(q=t,Z=n,H.tag){case 1:if(M=H.payload,typeof M=="function"){P=M.call(Z,P,q);break e}P=M;break e;case 3:M.flags=M.flags&-65537|128;case 0:if(M=H.payload,q=typeof M=="function"?M.call(Z,P,q):M,q==null)break e;P=lt({},P,q);break e;case 2:Si=!0}}p.callback!==null&&p.lane!==0&&(e.flags|=64,q=o.effects,q===null?o.effects=[p]:q.push(p))}else Z={eventTime:Z,lane:q,tag:p.tag,payload:p.payload,callback:p.callback,next:null},O===null?(b=O=Z,g=P):O=O.next=Z,c|=q;if(p=p.next,p===null){if(p=o.shared.pending,p===null)break;q=p,p=q.next,q.next=null,o.lastBaseUpdate=q,o.shared.pending=null}}while(1);if(O===null&&(g=P),o.baseState=g,o.firstBaseUpdate=b,o.lastBaseUpdate=O,t=o.shared.interleaved,t!==null){o=t;do c|=o.lane,o=o.next;while(o!==t)}else u===null&&(o.shared.lanes=0);lo|=c,e.lanes=c,e.memoizedState=P}}function d0(e,t,n){if(e=t.effects,t.effects=null,e!==null)for(t=0;t<e.length;t++){var r=e[t],o=r.callback;if(o!==null){if(r.callback=null,r=n,typeof o!="function")throw Error(de(191,o));o.call(r)}}}var f0=new x_.Component().refs;function Ap(e,t,n,r){t=e.memoizedState,n=n(r,t),n=n==null?t:lt({},t,n),e.memoizedState=n,e.lanes===0&&(e.updateQueue.baseState=n)}var Kl={isMounted:function(e){return(e=e._reactInternals)?to(e)===e:!1},enqueueSetState:function(e,t,n){e=e._reactInternals;var r=Qt(),o=Ti(e),u=Ur(r,o);u.payload=t,n!=null&&(u.callback=n),t=Ei(e,u,o),t!==null&&(ar(t,e,o,r),Gl(t,e,o))},enqueueReplaceState:function(e,t,n){e=e._reactInternals;var r=Qt(),o=Ti(e),u=Ur(r,o);u.tag=1,u.payload=t,n!=null&&(u.callback=n),t=Ei(e,u,o),t!==null&&(ar(t,e,o,r),Gl(t,e,o))},enqueueForceUpdate:function(e,t){e=e._reactInternals;var n=Qt(),r=Ti(e),o=Ur(n,r);o.tag=2,t!=null&&(o.callback=t),t=Ei(e,o,r),t!==null&&(ar(t,e,r,n),Gl(t,e,r))}};function p0(e,t,n,r,o,u,c){return e=e.stateNode,typeof e.shouldComponentUpdate=="function"?e.shouldComponentUpdate(r,u,c):t.prototype&&t.prototype.isPureReactComponent?!Ou(n,r)||!Ou(o,u):!0}function h0(e,t,n){var r=!1,o=wi,u=t.contextType;return typeof u=="object"&&u!==null?u=Bn(u):(o=on(t)?ro:zt.current,r=t.contextTypes,u=(r=r!=null)?ss(e,o):wi),t=new t(n,u),e.memoizedState=t.state!==null&&t.state!==void 0?t.state:null,t.updater=Kl,e.stateNode=t,t._reactInternals=e,r&&(e=e.stateNode,e.__reactInternalMemoizedUnmaskedChildContext=o,e.__reactInternalMemoizedMaskedChildContext=u),t}function g0(e,t,n,r){e=t.state,typeof t.componentWillReceiveProps=="function"&&t.componentWillReceiveProps(n,r),typeof t.UNSAFE_componentWillReceiveProps=="function"&&t.UNSAFE_componentWillReceiveProps(n,r),t.state!==e&&Kl.enqueueReplaceState(t,t.state,null)}function Cp(e,t,n,r){var o=e.stateNode;o.props=n,o.state=e.memoizedState,o.refs=f0,kp(e);var u=t.contextType;typeof u=="object"&&u!==null?o.context=Bn(u):(u=on(t)?ro:zt.current,o.context=ss(e,u)),o.state=e.memoizedState,u=t.getDerivedStateFromProps,typeof u=="function"&&(Ap(e,t,u,n),o.state=e.memoizedState),typeof t.getDerivedStateFromProps=="function"||typeof o.getSnapshotBeforeUpdate=="function"||typeof o.UNSAFE_componentWillMount!="function"&&typeof o.componentWillMount!="function"||(t=o.state,typeof o.componentWillMount=="function"&&o.componentWillMount(),typeof o.UNSAFE_componentWillMount=="function"&&o.UNSAFE_componentWillMount(),t!==o.state&&Kl.enqueueReplaceState(o,o.state,null),Wl(e,n,o,r),o.state=e.memoizedState),typeof o.componentDidMount=="function"&&(e.flags|=4194308)}function Mu(e,t,n){if(e=n.ref,e!==null&&typeof e!="function"&&typeof e!="object"){if(n._owner){if(n=n._owner,n){if(n.tag!==1)throw Error(de(309));var r=n.stateNode}if(!r)throw Error(de(147,e));var o=r,u=""+e;return t!==null&&t.ref!==null&&typeof t.ref=="function"&&t.ref._stringRef===u?t.ref:(t=function(c){var p=o.refs;p===f0&&(p=o.refs={}),c===null?delete p[u]:p[u]=c},t._stringRef=u,t)}if(typeof e!="string")throw Error(de(284));if(!n._owner)throw Error(de(290,e))}return e}function Yl(e,t){throw e=Object.prototype.toString.call(t),Error(de(31,e==="[object Object]"?"object with keys {"+Object.keys(t).join(", ")+"}":e))}function m0(e){var t=e._init;return t(e._payload)}function v0(e){function t(L,k){if(e){var x=L.deletions;x===null?(L.deletions=[k],L.flags|=16):x.push(k)}}function n(L,k){if(!e)return null;for(;k!==null;)t(L,k),k=k.sibling;return null}function r(L,k){for(L=new Map;k!==null;)k.key!==null?L.set(k.key,k):L.set(k.index,k),k=k.sibling;return L}function o(L,k){return L=Li(L,k),L.index=0,L.sibling=null,L}function u(L,k,x){return L.index=x,e?(x=L.alternate,x!==null?(x=x.index,x<k?(L.flags|=2,k):x):(L.flags|=2,k)):(L.flags|=1048576,k)}function c(L){return e&&L.alternate===null&&(L.flags|=2),L}function p(L,k,x,l){return k===null||k.tag!==6?(k=lh(x,L.mode,l),k.return=L,k):(k=o(k,x),k.return=L,k)}function g(L,k,x,l){var h=x.type;return h===Wo?O(L,k,x.props.children,l,x.key):k!==null&&(k.elementType===h||typeof h=="object"&&h!==null&&h.$$typeof===ci&&m0(h)===k.type)?(l=o(k,x.props),l.ref=Mu(L,k,x),l.return=L,l):(l=hc(x.type,x.key,x.props,null,L.mode,l),l.ref=Mu(L,k,x),l.return=L,l)}function b(L,k,x,l){return k===null||k.tag!==4||k.stateNode.containerInfo!==x.containerInfo||k.stateNode.implementation!==x.implementation?(k=ch(x,L.mode,l),k.return=L,k):(k=o(k,x.children||[]),k.return=L,k)}function O(L,k,x,l,h){return k===null||k.tag!==7?(k=ho(x,L.mode,l,h),k.return=L,k):(k=o(k,x),k.return=L,k)}function P(L,k,x){if(typeof k=="string"&&k!==""||typeof k=="number")return k=lh(""+k,L.mode,x),k.return=L,k;if(typeof k=="object"&&k!==null){switch(k.$$typeof){case dl:return x=hc(k.type,k.key,k.props,null,L.mode,x),x.ref=Mu(L,null,k),x.return=L,x;case Go:return k=ch(k,L.mode,x),k.return=L,k;case ci:var l=k._init;return P(L,l(k._payload),x)}if(fu(k)||cu(k))return k=ho(k,L.mode,x,null),k.return=L,k;Yl(L,k)}return null}function q(L,k,x,l){var h=k!==null?k.key:null;if(typeof x=="string"&&x!==""||typeof x=="number")return h!==null?null:p(L,k,""+x,l);if(typeof x=="object"&&x!==null){switch(x.$$typeof){case dl:return x.key===h?g(L,k,x,l):null;case Go:return x.key===h?b(L,k,x,l):null;case ci:return h=x._init,q(L,k,h(x._payload),l)}if(fu(x)||cu(x))return h!==null?null:O(L,k,x,l,null);Yl(L,x)}return null}function Z(L,k,x,l,h){if(typeof l=="string"&&l!==""||typeof l=="number")return L=L.get(x)||null,p(k,L,""+l,h);if(typeof l=="object"&&l!==null){switch(l.$$typeof){case dl:return L=L.get(l.key===null?x:l.key)||null,g(k,L,l,h);case Go:return L=L.get(l.key===null?x:l.key)||null,b(k,L,l,h);case ci:var d=l._init;return Z(L,k,x,d(l._payload),h)}if(fu(l)||cu(l))return L=L.get(x)||null,O(k,L,l,h,null);Yl(k,l)}return null}function M(L,k,x,l){for(var h=null,d=null,_=k,v=k=0,y=null;_!==null&&v<x.length;v++){_.index>v?(y=_,_=null):y=_.sibling;var m=q(L,_,x[v],l);if(m===null){_===null&&(_=y);break}e&&_&&m.alternate===null&&t(L,_),k=u(m,k,v),d===null?h=m:d.sibling=m,d=m,_=y}if(v===x.length)return n(L,_),ut&&oo(L,v),h;if(_===null){for(;v<x.length;v++)_=P(L,x[v],l),_!==null&&(k=u(_,k,v),d===null?h=_:d.sibling=_,d=_);return ut&&oo(L,v),h}for(_=r(L,_);v<x.length;v++)y=Z(_,L,v,x[v],l),y!==null&&(e&&y.alternate!==null&&_.delete(y.key===null?v:y.key),k=u(y,k,v),d===null?h=y:d.sibling=y,d=y);return e&&_.forEach(function(E){return t(L,E)}),ut&&oo(L,v),h}function H(L,k,x,l){var h=cu(x);if(typeof h!="function")throw Error(de(150));if(x=h.call(x),x==null)throw Error(de(151));for(var d=h=null,_=k,v=k=0,y=null,m=x.next();_!==null&&!m.done;v++,m=x.next()){_.index>v?(y=_,_=null):y=_.sibling;var E=q(L,_,m.value,l);if(E===null){_===null&&(_=y);break}e&&_&&E.alternate===null&&t(L,_),k=u(E,k,v),d===null?h=E:d.sibling=E,d=E,_=y}if(m.done)return n(L,_),ut&&oo(L,v),h;if(_===null){for(;!m.done;v++,m=x.next())m=P(L,m.value,l),m!==null&&(k=u(m,k,v),d===null?h=m:d.sibling=m,d=m);return ut&&oo(L,v),h}for(_=r(L,_);!m.done;v++,m=x.next())m=Z(_,L,v,m.value,l),m!==null&&(e&&m.alternate!==null&&_.delete(m.key===null?v:m.key),k=u(m,k,v),d===null?h=m:d.sibling=m,d=m);return e&&_.forEach(function(D){return t(L,D)}),ut&&oo(L,v),h}function z(L,k,x,l){if(typeof x=="object"&&x!==null&&x.type===Wo&&x.key===null&&(x=x.props.children),typeof x=="object"&&x!==null){switch(x.$$typeof){case dl:e:{for(var h=x.key,d=k;d!==null;){if(d.key===h){if(h=x.type,h===Wo){if(d.tag===7){n(L,d.sibling),k=o(d,x.props.children),k.return=L,L=k;break e}}else if(d.elementType===h||typeof h=="object"&&h!==null&&h.$$typeof===ci&&m0(h)===d.type){n(L,d.sibling),k=o(d,x.props),k.ref=Mu(L,d,x),k.return=L,L=k;break e}n(L,d);break}else t(L,d);d=d.sibling}x.type===Wo?(k=ho(x.props.children,L.mode,l,x.key),k.return=L,L=k):(l=hc(x.type,x.key,x.props,null,L.mode,l),l.ref=Mu(L,k,x),l.return=L,L=l)}return c(L);case Go:e:{for(d=x.key;k!==null;){if(k.key===d)if(k.tag===4&&k.stateNode.containerInfo===x.containerInfo&&k.stateNode.implementation===x.implementation){n(L,k.sibling),k=o(k,x.children||[]),k.return=L,L=k;break e}else{n(L,k);break}else t(L,k);k=k.sibling}k=ch(x,L.mode,l),k.return=L,L=k}return c(L);case ci:return d=x._init,z(L,k,d(x._payload),l)}if(fu(x))return M(L,k,x,l);if(cu(x))return H(L,k,x,l);Yl(L,x)}return typeof x=="string"&&x!==""||typeof x=="number"?(x=""+x,k!==null&&k.tag===6?(n(L,k.sibling),k=o(k,x),k.return=L,L=k):(n(L,k),k=lh(x,L.mode,l),k.return=L,L=k),c(L)):n(L,k)}return z}var fs=v0(!0),_0=v0(!1),Bu={},Sr=yi(Bu),zu=yi(Bu),$u=yi(Bu);function uo(e){if(e===Bu)throw Error(de(174));return e}function Tp(e,t){switch(et($u,t),et(zu,e),et(Sr,Bu),e=t.nodeType,e){case 9:case 11:t=(t=t.documentElement)?t.namespaceURI:Cf(null,"");break;default:e=e===8?t.parentNode:t,t=e.namespaceURI||null,e=e.tagName,t=Cf(t,e)}it(Sr),et(Sr,t)}function ps(){it(Sr),it(zu),it($u)}function y0(e){uo($u.current);var t=uo(Sr.current),n=Cf(t,e.type);t!==n&&(et(zu,e),et(Sr,n))}function xp(e){zu.current===e&&(it(Sr),it(zu))}var ct=yi(0);function Ql(e){for(var t=e;t!==null;){if(t.tag===13){var n=t.memoizedState;if(n!==null&&(n=n.dehydrated,n===null||n.data==="$?"||n.data==="$!"))return t}else if(t.tag===19&&t.memoizedProps.revealOrder!==void 0){if(t.flags&128)return t}else if(t.child!==null){t.child.return=t,t=t.child;continue}if(t===e)break;for(;t.sibling===null;){if(t.return===null||t.return===e)return null;t=t.return}t.sibling.return=t.return,t=t.sibling}return null}var Lp=[];function Op(){for(var e=0;e<Lp.length;e++)Lp[e]._workInProgressVersionPrimary=null;Lp.length=0}var Xl=qr.ReactCurrentDispatcher,Pp=qr.ReactCurrentBatchConfig,ao=0,dt=null,kt=null,Ot=null,Zl=!1,Uu=!1,ju=0,oC=0;function $t(){throw Error(de(321))}function Dp(e,t){if(t===null)return!1;for(var n=0;n<t.length&&n<e.length;n++)if(!rr(e[n],t[n]))return!1;return!0}function Np(e,t,n,r,o,u){if(ao=u,dt=t,t.memoizedState=null,t.updateQueue=null,t.lanes=0,Xl.current=e===null||e.memoizedState===null?lC:cC,e=n(r,o),Uu){u=0;do{if(Uu=!1,ju=0,25<=u)throw Error(de(301));u+=1,Ot=kt=null,t.updateQueue=null,Xl.current=dC,e=n(r,o)}while(Uu)}if(Xl.current=tc,t=kt!==null&&kt.next!==null,ao=0,Ot=kt=dt=null,Zl=!1,t)throw Error(de(300));return e}function Rp(){var e=ju!==0;return ju=0,e}function Er(){var e={memoizedState:null,baseState:null,baseQueue:null,queue:null,next:null};return Ot===null?dt.memoizedState=Ot=e:Ot=Ot.next=e,Ot}function zn(){if(kt===null){var e=dt.alternate;e=e!==null?e.memoizedState:null}else e=kt.next;var t=Ot===null?dt.memoizedState:Ot.next;if(t!==null)Ot=t,kt=e;else{if(e===null)throw Error(de(310));kt=e,e={memoizedState:kt.memoizedState,baseState:kt.baseState,baseQueue:kt.baseQueue,queue:kt.queue,next:null},Ot===null?dt.memoizedState=Ot=e:Ot=Ot.next=e}return Ot}function Fu(e,t){return typeof t=="function"?t(e):t}function qp(e){var t=zn(),n=t.queue;if(n===null)throw Error(de(311));n.lastRenderedReducer=e;var r=kt,o=r.baseQueue,u=n.pending;if(u!==null){if(o!==null){var c=o.next;o.next=u.next,u.next=c}r.baseQueue=o=u,n.pending=null}if(o!==null){u=o.next,r=r.baseState;var p=c=null,g=null,b=u;do{var O=b.lane;if((ao&O)===O)g!==null&&(g=g.next={lane:0,action:b.action,hasEagerState:b.hasEagerState,eagerState:b.eagerState,next:null}),r=b.hasEagerState?b.eagerState:e(r,b.action);else{var P={lane:O,action:b.action,hasEagerState:b.hasEagerState,eagerState:b.eagerState,next:null};g===null?(p=g=P,c=r):g=g.next=P,dt.lanes|=O,lo|=O}b=b.next}while(b!==null&&b!==u);g===null?c=r:g.next=p,rr(r,t.memoizedState)||(sn=!0),t.memoizedState=r,t.baseState=c,t.baseQueue=g,n.lastRenderedState=r}if(e=n.interleaved,e!==null){o=e;do u=o.lane,dt.lanes|=u,lo|=u,o=o.next;while(o!==e)}else o===null&&(n.lanes=0);return[t.memoizedState,n.dispatch]}function Ip(e){var t=zn(),n=t.queue;if(n===null)throw Error(de(311));n.lastRenderedReducer=e;var r=n.dispatch,o=n.pending,u=t.memoizedState;if(o!==null){n.pending=null;var c=o=o.next;do u=e(u,c.action),c=c.next;while(c!==o);rr(u,t.memoizedState)||(sn=!0),t.memoizedState=u,t.baseQueue===null&&(t.baseState=u),n.lastRenderedState=u}return[u,r]}function w0(){}function b0(e,t){var n=dt,r=zn(),o=t(),u=!rr(r.memoizedState,o);if(u&&(r.memoizedState=o,sn=!0),r=r.queue,Mp(k0.bind(null,n,r,e),[e]),r.getSnapshot!==t||u||Ot!==null&&Ot.memoizedState.tag&1){if(n.flags|=2048,Vu(9,E0.bind(null,n,r,o,t),void 0,null),Pt===null)throw Error(de(349));ao&30||S0(n,t,o)}return o}function S0(e,t,n){e.flags|=16384,e={getSnapshot:t,value:n},t=dt.updateQueue,t===null?(t={lastEffect:null,stores:null},dt.updateQueue=t,t.stores=[e]):(n=t.stores,n===null?t.stores=[e]:n.push(e))}function E0(e,t,n,r){t.value=n,t.getSnapshot=r,A0(t)&&C0(e)}function k0(e,t,n){return n(function(){A0(t)&&C0(e)})}function A0(e){var t=e.getSnapshot;e=e.value;try{var n=t();return!rr(e,n)}catch{return!0}}function C0(e){var t=$r(e,1);t!==null&&ar(t,e,1,-1)}function T0(e){var t=Er();return typeof e=="function"&&(e=e()),t.memoizedState=t.baseState=e,e={pending:null,interleaved:null,lanes:0,dispatch:null,lastRenderedReducer:Fu,lastRenderedState:e},t.queue=e,e=e.dispatch=aC.bind(null,dt,e),[t.memoizedState,e]}function Vu(e,t,n,r){return e={tag:e,create:t,destroy:n,deps:r,next:null},t=dt.updateQueue,t===null?(t={lastEffect:null,stores:null},dt.updateQueue=t,t.lastEffect=e.next=e):(n=t.lastEffect,n===null?t.lastEffect=e.next=e:(r=n.next,n.next=e,e.next=r,t.lastEffect=e)),e}function x0(){return zn().memoizedState}function Jl(e,t,n,r){var o=Er();dt.flags|=e,o.memoizedState=Vu(1|t,n,void 0,r===void 0?null:r)}function ec(e,t,n,r){var o=zn();r=r===void 0?null:r;var u=void 0;if(kt!==null){var c=kt.memoizedState;if(u=c.destroy,r!==null&&Dp(r,c.deps)){o.memoizedState=Vu(t,n,u,r);return}}dt.flags|=e,o.memoizedState=Vu(1|t,n,u,r)}function L0(e,t){return Jl(8390656,8,e,t)}function Mp(e,t){return ec(2048,8,e,t)}function O0(e,t){return ec(4,2,e,t)}function P0(e,t){return ec(4,4,e,t)}function D0(e,t){if(typeof t=="function")return e=e(),t(e),function(){t(null)};if(t!=null)return e=e(),t.current=e,function(){t.current=null}}function N0(e,t,n){return n=n!=null?n.concat([e]):null,ec(4,4,D0.bind(null,t,e),n)}function Bp(){}function R0(e,t){var n=zn();t=t===void 0?null:t;var r=n.memoizedState;return r!==null&&t!==null&&Dp(t,r[1])?r[0]:(n.memoizedState=[e,t],e)}function q0(e,t){var n=zn();t=t===void 0?null:t;var r=n.memoizedState;return r!==null&&t!==null&&Dp(t,r[1])?r[0]:(e=e(),n.memoizedState=[e,t],e)}function I0(e,t,n){return ao&21?(rr(n,t)||(n=ay(),dt.lanes|=n,lo|=n,e.baseState=!0),t):(e.baseState&&(e.baseState=!1,sn=!0),e.memoizedState=n)}function sC(e,t){var n=Ke;Ke=n!==0&&4>n?n:4,e(!0);var r=Pp.transition;Pp.transition={};try{e(!1),t()}finally{Ke=n,Pp.transition=r}}function M0(){return zn().memoizedState}function uC(e,t,n){var r=Ti(e);if(n={lane:r,action:n,hasEagerState:!1,eagerState:null,next:null},B0(e))z0(t,n);else if(n=a0(e,t,n,r),n!==null){var o=Qt();ar(n,e,r,o),$0(n,t,r)}}function aC(e,t,n){var r=Ti(e),o={lane:r,action:n,hasEagerState:!1,eagerState:null,next:null};if(B0(e))z0(t,o);else{var u=e.alternate;if(e.lanes===0&&(u===null||u.lanes===0)&&(u=t.lastRenderedReducer,u!==null))try{var c=t.lastRenderedState,p=u(c,n);if(o.hasEagerState=!0,o.eagerState=p,rr(p,c)){var g=t.interleaved;g===null?(o.next=o,Ep(t)):(o.next=g.next,g.next=o),t.interleaved=o;return}}catch{}finally{}n=a0(e,t,o,r),n!==null&&(o=Qt(),ar(n,e,r,o),$0(n,t,r))}}function B0(e){var t=e.alternate;return e===dt||t!==null&&t===dt}function z0(e,t){Uu=Zl=!0;var n=e.pending;n===null?t.next=t:(t.next=n.next,n.next=t),e.pending=t}function $0(e,t,n){if(n&4194240){var r=t.lanes;r&=e.pendingLanes,n|=r,t.lanes=n,Bf(e,n)}}var tc={readContext:Bn,useCallback:$t,useContext:$t,useEffect:$t,useImperativeHandle:$t,useInsertionEffect:$t,useLayoutEffect:$t,useMemo:$t,useReducer:$t,useRef:$t,useState:$t,useDebugValue:$t,useDeferredValue:$t,useTransition:$t,useMutableSource:$t,useSyncExternalStore:$t,useId:$t,unstable_isNewReconciler:!1},lC={readContext:Bn,useCallback:function(e,t){return Er().memoizedState=[e,t===void 0?null:t],e},useContext:Bn,useEffect:L0,useImperativeHandle:function(e,t,n){return n=n!=null?n.concat([e]):null,Jl(4194308,4,D0.bind(null,t,e),n)},useLayoutEffect:function(e,t){return Jl(4194308,4,e,t)},useInsertionEffect:function(e,t){return Jl(4,2,e,t)},useMemo:function(e,t){var n=Er();return t=t===void 0?null:t,e=e(),n.memoizedState=[e,t],e},useReducer:function(e,t,n){var r=Er();return t=n!==void 0?n(t):t,r.memoizedState=r.baseState=t,e={pending:null,interleaved:null,lanes:0,dispatch:null,lastRenderedReducer:e,lastRenderedState:t},r.queue=e,e=e.dispatch=uC.bind(null,dt,e),[r.memoizedState,e]},useRef:function(e){var t=Er();return e={current:e},t.memoizedState=e},useState:T0,useDebugValue:Bp,useDeferredValue:function(e){return Er().memoizedState=e},useTransition:function(){var e=T0(!1),t=e[0];return e=sC.bind(null,e[1]),Er().memoizedState=e,[t,e]},useMutableSource:function(){},useSyncExternalStore:function(e,t,n){var r=dt,o=Er();if(ut){if(n===void 0)throw Error(de(407));n=n()}else{if(n=t(),Pt===null)throw Error(de(349));ao&30||S0(r,t,n)}o.memoizedState=n;var u={value:n,getSnapshot:t};return o.queue=u,L0(k0.bind(null,r,u,e),[e]),r.flags|=2048,Vu(9,E0.bind(null,r,u,n,t),void 0,null),n},useId:function(){var e=Er(),t=Pt.identifierPrefix;if(ut){var n=zr,r=Br;n=(r&~(1<<32-nr(r)-1)).toString(32)+n,t=":"+t+"R"+n,n=ju++,0<n&&(t+="H"+n.toString(32)),t+=":"}else n=oC++,t=":"+t+"r"+n.toString(32)+":";return e.memoizedState=t},unstable_isNewReconciler:!1},cC={readContext:Bn,useCallback:R0,useContext:Bn,useEffect:Mp,useImperativeHandle:N0,useInsertionEffect:O0,useLayoutEffect:P0,useMemo:q0,useReducer:qp,useRef:x0,useState:function(){return qp(Fu)},useDebugValue:Bp,useDeferredValue:function(e){var t=zn();return I0(t,kt.memoizedState,e)},useTransition:function(){var e=qp(Fu)[0],t=zn().memoizedState;return[e,t]},useMutableSource:w0,useSyncExternalStore:b0,useId:M0,unstable_isNewReconciler:!1},dC={readContext:Bn,useCallback:R0,useContext:Bn,useEffect:Mp,useImperativeHandle:N0,useInsertionEffect:O0,useLayoutEffect:P0,useMemo:q0,useReducer:Ip,useRef:x0,useState:function(){return Ip(Fu)},useDebugValue:Bp,useDeferredValue:function(e){var t=zn();return kt===null?t.memoizedState=e:I0(t,kt.memoizedState,e)},useTransition:function(){var e=Ip(Fu)[0],t=zn().memoizedState;return[e,t]},useMutableSource:w0,useSyncExternalStore:b0,useId:M0,unstable_isNewReconciler:!1};function hs(e,t){try{var n="",r=t;do n+=zk(r),r=r.return;while(r);var o=n}catch(u){o=`
-Error generating stack: `+u.message+`
-`+u.stack}return{value:e,source:t,stack:o,digest:null}}function zp(e,t,n){return{value:e,source:null,stack:n??null,digest:t??null}}function $p(e,t){try{console.error(t.value)}catch(n){setTimeout(function(){throw n})}}var fC=typeof WeakMap=="function"?WeakMap:Map;function U0(e,t,n){n=Ur(-1,n),n.tag=3,n.payload={element:null};var r=t.value;return n.callback=function(){ac||(ac=!0,th=r),$p(e,t)},n}function j0(e,t,n){n=Ur(-1,n),n.tag=3;var r=e.type.getDerivedStateFromError;if(typeof r=="function"){var o=t.value;n.payload=function(){return r(o)},n.callback=function(){$p(e,t)}}var u=e.stateNode;return u!==null&&typeof u.componentDidCatch=="function"&&(n.callback=function(){$p(e,t),typeof r!="function"&&(Ai===null?Ai=new Set([this]):Ai.add(this));var c=t.stack;this.componentDidCatch(t.value,{componentStack:c!==null?c:""})}),n}function F0(e,t,n){var r=e.pingCache;if(r===null){r=e.pingCache=new fC;var o=new Set;r.set(t,o)}else o=r.get(t),o===void 0&&(o=new Set,r.set(t,o));o.has(n)||(o.add(n),e=CC.bind(null,e,t,n),t.then(e,e))}function V0(e){do{var t;if((t=e.tag===13)&&(t=e.memoizedState,t=t!==null?t.dehydrated!==null:!0),t)return e;e=e.return}while(e!==null);return null}function H0(e,t,n,r,o){return e.mode&1?(e.flags|=65536,e.lanes=o,e):(e===t?e.flags|=65536:(e.flags|=128,n.flags|=131072,n.flags&=-52805,n.tag===1&&(n.alternate===null?n.tag=17:(t=Ur(-1,1),t.tag=2,Ei(n,t,1))),n.lanes|=1),e)}var pC=qr.ReactCurrentOwner,sn=!1;function Yt(e,t,n,r){t.child=e===null?_0(t,null,n,r):fs(t,e.child,n,r)}function G0(e,t,n,r,o){n=n.render;var u=t.ref;return ds(t,o),r=Np(e,t,n,r,u,o),n=Rp(),e!==null&&!sn?(t.updateQueue=e.updateQueue,t.flags&=-2053,e.lanes&=~o,jr(e,t,o)):(ut&&n&&hp(t),t.flags|=1,Yt(e,t,r,o),t.child)}function W0(e,t,n,r,o){if(e===null){var u=n.type;return typeof u=="function"&&!ah(u)&&u.defaultProps===void 0&&n.compare===null&&n.defaultProps===void 0?(t.tag=15,t.type=u,K0(e,t,u,r,o)):(e=hc(n.type,null,r,t,t.mode,o),e.ref=t.ref,e.return=t,t.child=e)}if(u=e.child,!(e.lanes&o)){var c=u.memoizedProps;if(n=n.compare,n=n!==null?n:Ou,n(c,r)&&e.ref===t.ref)return jr(e,t,o)}return t.flags|=1,e=Li(u,r),e.ref=t.ref,e.return=t,t.child=e}function K0(e,t,n,r,o){if(e!==null){var u=e.memoizedProps;if(Ou(u,r)&&e.ref===t.ref)if(sn=!1,t.pendingProps=r=u,(e.lanes&o)!==0)e.flags&131072&&(sn=!0);else return t.lanes=e.lanes,jr(e,t,o)}return Up(e,t,n,r,o)}function Y0(e,t,n){var r=t.pendingProps,o=r.children,u=e!==null?e.memoizedState:null;if(r.mode==="hidden")if(!(t.mode&1))t.memoizedState={baseLanes:0,cachePool:null,transitions:null},et(ms,An),An|=n;else{if(!(n&1073741824))return e=u!==null?u.baseLanes|n:n,t.lanes=t.childLanes=1073741824,t.memoizedState={baseLanes:e,cachePool:null,transitions:null},t.updateQueue=null,et(ms,An),An|=e,null;t.memoizedState={baseLanes:0,cachePool:null,transitions:null},r=u!==null?u.baseLanes:n,et(ms,An),An|=r}else u!==null?(r=u.baseLanes|n,t.memoizedState=null):r=n,et(ms,An),An|=r;return Yt(e,t,o,n),t.child}function Q0(e,t){var n=t.ref;(e===null&&n!==null||e!==null&&e.ref!==n)&&(t.flags|=512,t.flags|=2097152)}function Up(e,t,n,r,o){var u=on(n)?ro:zt.current;return u=ss(t,u),ds(t,o),n=Np(e,t,n,r,u,o),r=Rp(),e!==null&&!sn?(t.updateQueue=e.updateQueue,t.flags&=-2053,e.lanes&=~o,jr(e,t,o)):(ut&&r&&hp(t),t.flags|=1,Yt(e,t,n,o),t.child)}function X0(e,t,n,r,o){if(on(n)){var u=!0;zl(t)}else u=!1;if(ds(t,o),t.stateNode===null)rc(e,t),h0(t,n,r),Cp(t,n,r,o),r=!0;else if(e===null){var c=t.stateNode,p=t.memoizedProps;c.props=p;var g=c.context,b=n.contextType;typeof b=="object"&&b!==null?b=Bn(b):(b=on(n)?ro:zt.current,b=ss(t,b));var O=n.getDerivedStateFromProps,P=typeof O=="function"||typeof c.getSnapshotBeforeUpdate=="function";P||typeof c.UNSAFE_componentWillReceiveProps!="function"&&typeof c.componentWillReceiveProps!="function"||(p!==r||g!==b)&&g0(t,c,r,b),Si=!1;var q=t.memoizedState;c.state=q,Wl(t,r,c,o),g=t.memoizedState,p!==r||q!==g||rn.current||Si?(typeof O=="function"&&(Ap(t,n,O,r),g=t.memoizedState),(p=Si||p0(t,n,p,r,q,g,b))?(P||typeof c.UNSAFE_componentWillMount!="function"&&typeof c.componentWillMount!="function"||(typeof c.componentWillMount=="function"&&c.componentWillMount(),typeof c.UNSAFE_componentWillMount=="function"&&c.UNSAFE_componentWillMount()),typeof c.componentDidMount=="function"&&(t.flags|=4194308)):(typeof c.componentDidMount=="function"&&(t.flags|=4194308),t.memoizedProps=r,t.memoizedState=g),c.props=r,c.state=g,c.context=b,r=p):(typeof c.componentDidMount=="function"&&(t.flags|=4194308),r=!1)}else{c=t.stateNode,l0(e,t),p=t.memoizedProps,b=t.type===t.elementType?p:or(t.type,p),c.props=b,P=t.pendingProps,q=c.context,g=n.contextType,typeof g=="object"&&g!==null?g=Bn(g):(g=on(n)?ro:zt.current,g=ss(t,g));var Z=n.getDerivedStateFromProps;(O=typeof Z=="function"||typeof c.getSnapshotBeforeUpdate=="function")||typeof c.UNSAFE_componentWillReceiveProps!="function"&&typeof c.componentWillReceiveProps!="function"||(p!==P||q!==g)&&g0(t,c,r,g),Si=!1,q=t.memoizedState,c.state=q,Wl(t,r,c,o);var M=t.memoizedState;p!==P||q!==M||rn.current||Si?(typeof Z=="function"&&(Ap(t,n,Z,r),M=t.memoizedState),(b=Si||p0(t,n,b,r,q,M,g)||!1)?(O||typeof c.UNSAFE_componentWillUpdate!="function"&&typeof c.componentWillUpdate!="function"||(typeof c.componentWillUpdate=="function"&&c.componentWillUpdate(r,M,g),typeof c.UNSAFE_componentWillUpdate=="function"&&c.UNSAFE_componentWillUpdate(r,M,g)),typeof c.componentDidUpdate=="function"&&(t.flags|=4),typeof c.getSnapshotBeforeUpdate=="function"&&(t.flags|=1024)):(typeof c.componentDidUpdate!="function"||p===e.memoizedProps&&q===e.memoizedState||(t.flags|=4),typeof c.getSnapshotBeforeUpdate!="function"||p===e.memoizedProps&&q===e.memoizedState||(t.flags|=1024),t.memoizedProps=r,t.memoizedState=M),c.props=r,c.state=M,c.context=g,r=b):(typeof c.componentDidUpdate!="function"||p===e.memoizedProps&&q===e.memoizedState||(t.flags|=4),typeof c.getSnapshotBeforeUpdate!="function"||p===e.memoizedProps&&q===e.memoizedState||(t.flags|=1024),r=!1)}return jp(e,t,n,r,u,o)}function jp(e,t,n,r,o,u){Q0(e,t);var c=(t.flags&128)!==0;if(!r&&!c)return o&&t0(t,n,!1),jr(e,t,u);r=t.stateNode,pC.current=t;var p=c&&typeof n.getDerivedStateFromError!="function"?null:r.render();return t.flags|=1,e!==null&&c?(t.child=fs(t,e.child,null,u),t.child=fs(t,null,p,u)):Yt(e,t,p,u),t.memoizedState=r.state,o&&t0(t,n,!0),t.child}function Z0(e){var t=e.stateNode;t.pendingContext?Jy(e,t.pendingContext,t.pendingContext!==t.context):t.context&&Jy(e,t.context,!1),Tp(e,t.containerInfo)}function J0(e,t,n,r,o){return ls(),_p(o),t.flags|=256,Yt(e,t,n,r),t.child}var Fp={dehydrated:null,treeContext:null,retryLane:0};function Vp(e){return{baseLanes:e,cachePool:null,transitions:null}}function ew(e,t,n){var r=t.pendingProps,o=ct.current,u=!1,c=(t.flags&128)!==0,p;if((p=c)||(p=e!==null&&e.memoizedState===null?!1:(o&2)!==0),p?(u=!0,t.flags&=-129):(e===null||e.memoizedState!==null)&&(o|=1),et(ct,o&1),e===null)return vp(t),e=t.memoizedState,e!==null&&(e=e.dehydrated,e!==null)?(t.mode&1?e.data==="$!"?t.lanes=8:t.lanes=1073741824:t.lanes=1,null):(c=r.children,e=r.fallback,u?(r=t.mode,u=t.child,c={mode:"hidden",children:c},!(r&1)&&u!==null?(u.childLanes=0,u.pendingProps=c):u=gc(c,r,0,null),e=ho(e,r,n,null),u.return=t,e.return=t,u.sibling=e,t.child=u,t.child.memoizedState=Vp(n),t.memoizedState=Fp,e):Hp(t,c));if(o=e.memoizedState,o!==null&&(p=o.dehydrated,p!==null))return hC(e,t,c,r,p,o,n);if(u){u=r.fallback,c=t.mode,o=e.child,p=o.sibling;var g={mode:"hidden",children:r.children};return!(c&1)&&t.child!==o?(r=t.child,r.childLanes=0,r.pendingProps=g,t.deletions=null):(r=Li(o,g),r.subtreeFlags=o.subtreeFlags&14680064),p!==null?u=Li(p,u):(u=ho(u,c,n,null),u.flags|=2),u.return=t,r.return=t,r.sibling=u,t.child=r,r=u,u=t.child,c=e.child.memoizedState,c=c===null?Vp(n):{baseLanes:c.baseLanes|n,cachePool:null,transitions:c.transitions},u.memoizedState=c,u.childLanes=e.childLanes&~n,t.memoizedState=Fp,r}return u=e.child,e=u.sibling,r=Li(u,{mode:"visible",children:r.children}),!(t.mode&1)&&(r.lanes=n),r.return=t,r.sibling=null,e!==null&&(n=t.deletions,n===null?(t.deletions=[e],t.flags|=16):n.push(e)),t.child=r,t.memoizedState=null,r}function Hp(e,t){return t=gc({mode:"visible",children:t},e.mode,0,null),t.return=e,e.child=t}function nc(e,t,n,r){return r!==null&&_p(r),fs(t,e.child,null,n),e=Hp(t,t.pendingProps.children),e.flags|=2,t.memoizedState=null,e}function hC(e,t,n,r,o,u,c){if(n)return t.flags&256?(t.flags&=-257,r=zp(Error(de(422))),nc(e,t,c,r)):t.memoizedState!==null?(t.child=e.child,t.flags|=128,null):(u=r.fallback,o=t.mode,r=gc({mode:"visible",children:r.children},o,0,null),u=ho(u,o,c,null),u.flags|=2,r.return=t,u.return=t,r.sibling=u,t.child=r,t.mode&1&&fs(t,e.child,null,c),t.child.memoizedState=Vp(c),t.memoizedState=Fp,u);if(!(t.mode&1))return nc(e,t,c,null);if(o.data==="$!"){if(r=o.nextSibling&&o.nextSibling.dataset,r)var p=r.dgst;return r=p,u=Error(de(419)),r=zp(u,r,void 0),nc(e,t,c,r)}if(p=(c&e.childLanes)!==0,sn||p){if(r=Pt,r!==null){switch(c&-c){case 4:o=2;break;case 16:o=8;break;case 64:case 128:case 256:case 512:case 1024:case 2048:case 4096:case 8192:case 16384:case 32768:case 65536:case 131072:case 262144:case 524288:case 1048576:case 2097152:case 4194304:case 8388608:case 16777216:case 33554432:case 67108864:o=32;break;case 536870912:o=268435456;break;default:o=0}o=o&(r.suspendedLanes|c)?0:o,o!==0&&o!==u.retryLane&&(u.retryLane=o,$r(e,o),ar(r,e,o,-1))}return uh(),r=zp(Error(de(421))),nc(e,t,c,r)}return o.data==="$?"?(t.flags|=128,t.child=e.child,t=TC.bind(null,e),o._reactRetry=t,null):(e=u.treeContext,kn=_i(o.nextSibling),En=t,ut=!0,ir=null,e!==null&&(In[Mn++]=Br,In[Mn++]=zr,In[Mn++]=io,Br=e.id,zr=e.overflow,io=t),t=Hp(t,r.children),t.flags|=4096,t)}function tw(e,t,n){e.lanes|=t;var r=e.alternate;r!==null&&(r.lanes|=t),Sp(e.return,t,n)}function Gp(e,t,n,r,o){var u=e.memoizedState;u===null?e.memoizedState={isBackwards:t,rendering:null,renderingStartTime:0,last:r,tail:n,tailMode:o}:(u.isBackwards=t,u.rendering=null,u.renderingStartTime=0,u.last=r,u.tail=n,u.tailMode=o)}function nw(e,t,n){var r=t.pendingProps,o=r.revealOrder,u=r.tail;if(Yt(e,t,r.children,n),r=ct.current,r&2)r=r&1|2,t.flags|=128;else{if(e!==null&&e.flags&128)e:for(e=t.child;e!==null;){if(e.tag===13)e.memoizedState!==null&&tw(e,n,t);else if(e.tag===19)tw(e,n,t);else if(e.child!==null){e.child.return=e,e=e.child;continue}if(e===t)break e;for(;e.sibling===null;){if(e.return===null||e.return===t)break e;e=e.return}e.sibling.return=e.return,e=e.sibling}r&=1}if(et(ct,r),!(t.mode&1))t.memoizedState=null;else switch(o){case"forwards":for(n=t.child,o=null;n!==null;)e=n.alternate,e!==null&&Ql(e)===null&&(o=n),n=n.sibling;n=o,n===null?(o=t.child,t.child=null):(o=n.sibling,n.sibling=null),Gp(t,!1,o,n,u);break;case"backwards":for(n=null,o=t.child,t.child=null;o!==null;){if(e=o.alternate,e!==null&&Ql(e)===null){t.child=o;break}e=o.sibling,o.sibling=n,n=o,o=e}Gp(t,!0,n,null,u);break;case"together":Gp(t,!1,null,null,void 0);break;default:t.memoizedState=null}return t.child}function rc(e,t){!(t.mode&1)&&e!==null&&(e.alternate=null,t.alternate=null,t.flags|=2)}function jr(e,t,n){if(e!==null&&(t.dependencies=e.dependencies),lo|=t.lanes,!(n&t.childLanes))return null;if(e!==null&&t.child!==e.child)throw Error(de(153));if(t.child!==null){for(e=t.child,n=Li(e,e.pendingProps),t.child=n,n.return=t;e.sibling!==null;)e=e.sibling,n=n.sibling=Li(e,e.pendingProps),n.return=t;n.sibling=null}return t.child}function gC(e,t,n){switch(t.tag){case 3:Z0(t),ls();break;case 5:y0(t);break;case 1:on(t.type)&&zl(t);break;case 4:Tp(t,t.stateNode.containerInfo);break;case 10:var r=t.type._context,o=t.memoizedProps.value;et(Vl,r._currentValue),r._currentValue=o;break;case 13:if(r=t.memoizedState,r!==null)return r.dehydrated!==null?(et(ct,ct.current&1),t.flags|=128,null):n&t.child.childLanes?ew(e,t,n):(et(ct,ct.current&1),e=jr(e,t,n),e!==null?e.sibling:null);et(ct,ct.current&1);break;case 19:if(r=(n&t.childLanes)!==0,e.flags&128){if(r)return nw(e,t,n);t.flags|=128}if(o=t.memoizedState,o!==null&&(o.rendering=null,o.tail=null,o.lastEffect=null),et(ct,ct.current),r)break;return null;case 22:case 23:return t.lanes=0,Y0(e,t,n)}return jr(e,t,n)}var rw,Wp,iw,ow;rw=function(e,t){for(var n=t.child;n!==null;){if(n.tag===5||n.tag===6)e.appendChild(n.stateNode);else if(n.tag!==4&&n.child!==null){n.child.return=n,n=n.child;continue}if(n===t)break;for(;n.sibling===null;){if(n.return===null||n.return===t)return;n=n.return}n.sibling.return=n.return,n=n.sibling}},Wp=function(){},iw=function(e,t,n,r){var o=e.memoizedProps;if(o!==r){e=t.stateNode,uo(Sr.current);var u=null;switch(n){case"input":o=Sf(e,o),r=Sf(e,r),u=[];break;case"select":o=lt({},o,{value:void 0}),r=lt({},r,{value:void 0}),u=[];break;case"textarea":o=Af(e,o),r=Af(e,r),u=[];break;default:typeof o.onClick!="function"&&typeof r.onClick=="function"&&(e.onclick=Il)}Tf(n,r);var c;n=null;for(b in o)if(!r.hasOwnProperty(b)&&o.hasOwnProperty(b)&&o[b]!=null)if(b==="style"){var p=o[b];for(c in p)p.hasOwnProperty(c)&&(n||(n={}),n[c]="")}else b!=="dangerouslySetInnerHTML"&&b!=="children"&&b!=="suppressContentEditableWarning"&&b!=="suppressHydrationWarning"&&b!=="autoFocus"&&(lu.hasOwnProperty(b)?u||(u=[]):(u=u||[]).push(b,null));for(b in r){var g=r[b];if(p=o!=null?o[b]:void 0,r.hasOwnProperty(b)&&g!==p&&(g!=null||p!=null))if(b==="style")if(p){for(c in p)!p.hasOwnProperty(c)||g&&g.hasOwnProperty(c)||(n||(n={}),n[c]="");for(c in g)g.hasOwnProperty(c)&&p[c]!==g[c]&&(n||(n={}),n[c]=g[c])}else n||(u||(u=[]),u.push(b,n)),n=g;else b==="dangerouslySetInnerHTML"?(g=g?g.__html:void 0,p=p?p.__html:void 0,g!=null&&p!==g&&(u=u||[]).push(b,g)):b==="children"?typeof g!="string"&&typeof g!="number"||(u=u||[]).push(b,""+g):b!=="suppressContentEditableWarning"&&b!=="suppressHydrationWarning"&&(lu.hasOwnProperty(b)?(g!=null&&b==="onScroll"&&rt("scroll",e),u||p===g||(u=[])):(u=u||[]).push(b,g))}n&&(u=u||[]).push("style",n);var b=u;(t.updateQueue=b)&&(t.flags|=4)}},ow=function(e,t,n,r){n!==r&&(t.flags|=4)};function Hu(e,t){if(!ut)switch(e.tailMode){case"hidden":t=e.tail;for(var n=null;t!==null;)t.alternate!==null&&(n=t),t=t.sibling;n===null?e.tail=null:n.sibling=null;break;case"collapsed":n=e.tail;for(var r=null;n!==null;)n.alternate!==null&&(r=n),n=n.sibling;r===null?t||e.tail===null?e.tail=null:e.tail.sibling=null:r.sibling=null}}function Ut(e){var t=e.alternate!==null&&e.alternate.child===e.child,n=0,r=0;if(t)for(var o=e.child;o!==null;)n|=o.lanes|o.childLanes,r|=o.subtreeFlags&14680064,r|=o.flags&14680064,o.return=e,o=o.sibling;else for(o=e.child;o!==null;)n|=o.lanes|o.childLanes,r|=o.subtreeFlags,r|=o.flags,o.return=e,o=o.sibling;return e.subtreeFlags|=r,e.childLanes=n,t}function mC(e,t,n){var r=t.pendingProps;switch(gp(t),t.tag){case 2:case 16:case 15:case 0:case 11:case 7:case 8:case 12:case 9:case 14:return Ut(t),null;case 1:return on(t.type)&&Bl(),Ut(t),null;case 3:return r=t.stateNode,ps(),it(rn),it(zt),Op(),r.pendingContext&&(r.context=r.pendingContext,r.pendingContext=null),(e===null||e.child===null)&&(Fl(t)?t.flags|=4:e===null||e.memoizedState.isDehydrated&&!(t.flags&256)||(t.flags|=1024,ir!==null&&(ih(ir),ir=null))),Wp(e,t),Ut(t),null;case 5:xp(t);var o=uo($u.current);if(n=t.type,e!==null&&t.stateNode!=null)iw(e,t,n,r,o),e.ref!==t.ref&&(t.flags|=512,t.flags|=2097152);else{if(!r){if(t.stateNode===null)throw Error(de(166));return Ut(t),null}if(e=uo(Sr.current),Fl(t)){r=t.stateNode,n=t.type;var u=t.memoizedProps;switch(r[br]=t,r[qu]=u,e=(t.mode&1)!==0,n){case"dialog":rt("cancel",r),rt("close",r);break;case"iframe":case"object":case"embed":rt("load",r);break;case"video":case"audio":for(o=0;o<Du.length;o++)rt(Du[o],r);break;case"source":rt("error",r);break;case"img":case"image":case"link":rt("error",r),rt("load",r);break;case"details":rt("toggle",r);break;case"input":B_(r,u),rt("invalid",r);break;case"select":r._wrapperState={wasMultiple:!!u.multiple},rt("invalid",r);break;case"textarea":U_(r,u),rt("invalid",r)}Tf(n,u),o=null;for(var c in u)if(u.hasOwnProperty(c)){var p=u[c];c==="children"?typeof p=="string"?r.textContent!==p&&(u.suppressHydrationWarning!==!0&&ql(r.textContent,p,e),o=["children",p]):typeof p=="number"&&r.textContent!==""+p&&(u.suppressHydrationWarning!==!0&&ql(r.textContent,p,e),o=["children",""+p]):lu.hasOwnProperty(c)&&p!=null&&c==="onScroll"&&rt("scroll",r)}switch(n){case"input":fl(r),$_(r,u,!0);break;case"textarea":fl(r),F_(r);break;case"select":case"option":break;default:typeof u.onClick=="function"&&(r.onclick=Il)}r=o,t.updateQueue=r,r!==null&&(t.flags|=4)}else{c=o.nodeType===9?o:o.ownerDocument,e==="http://www.w3.org/1999/xhtml"&&(e=V_(n)),e==="http://www.w3.org/1999/xhtml"?n==="script"?(e=c.createElement("div"),e.innerHTML="<script><\/script>",e=e.removeChild(e.firstChild)):typeof r.is=="string"?e=c.createElement(n,{is:r.is}):(e=c.createElement(n),n==="select"&&(c=e,r.multiple?c.multiple=!0:r.size&&(c.size=r.size))):e=c.createElementNS(e,n),e[br]=t,e[qu]=r,rw(e,t,!1,!1),t.stateNode=e;e:{switch(c=xf(n,r),n){case"dialog":rt("cancel",e),rt("close",e),o=r;break;case"iframe":case"object":case"embed":rt("load",e),o=r;break;case"video":case"audio":for(o=0;o<Du.length;o++)rt(Du[o],e);o=r;break;case"source":rt("error",e),o=r;break;case"img":case"image":case"link":rt("error",e),rt("load",e),o=r;break;case"details":rt("toggle",e),o=r;break;case"input":B_(e,r),o=Sf(e,r),rt("invalid",e);break;case"option":o=r;break;case"select":e._wrapperState={wasMultiple:!!r.multiple},o=lt({},r,{value:void 0}),rt("invalid",e);break;case"textarea":U_(e,r),o=Af(e,r),rt("invalid",e);break;default:o=r}Tf(n,o),p=o;for(u in p)if(p.hasOwnProperty(u)){var g=p[u];u==="style"?W_(e,g):u==="dangerouslySetInnerHTML"?(g=g?g.__html:void 0,g!=null&&H_(e,g)):u==="children"?typeof g=="string"?(n!=="textarea"||g!=="")&&pu(e,g):typeof g=="number"&&pu(e,""+g):u!=="suppressContentEditableWarning"&&u!=="suppressHydrationWarning"&&u!=="autoFocus"&&(lu.hasOwnProperty(u)?g!=null&&u==="onScroll"&&rt("scroll",e):g!=null&&df(e,u,g,c))}switch(n){case"input":fl(e),$_(e,r,!1);break;case"textarea":fl(e),F_(e);break;case"option":r.value!=null&&e.setAttribute("value",""+di(r.value));break;case"select":e.multiple=!!r.multiple,u=r.value,u!=null?Ko(e,!!r.multiple,u,!1):r.defaultValue!=null&&Ko(e,!!r.multiple,r.defaultValue,!0);break;default:typeof o.onClick=="function"&&(e.onclick=Il)}switch(n){case"button":case"input":case"select":case"textarea":r=!!r.autoFocus;break e;case"img":r=!0;break e;default:r=!1}}r&&(t.flags|=4)}t.ref!==null&&(t.flags|=512,t.flags|=2097152)}return Ut(t),null;case 6:if(e&&t.stateNode!=null)ow(e,t,e.memoizedProps,r);else{if(typeof r!="string"&&t.stateNode===null)throw Error(de(166));if(n=uo($u.current),uo(Sr.current),Fl(t)){if(r=t.stateNode,n=t.memoizedProps,r[br]=t,(u=r.nodeValue!==n)&&(e=En,e!==null))switch(e.tag){case 3:ql(r.nodeValue,n,(e.mode&1)!==0);break;case 5:e.memoizedProps.suppressHydrationWarning!==!0&&ql(r.nodeValue,n,(e.mode&1)!==0)}u&&(t.flags|=4)}else r=(n.nodeType===9?n:n.ownerDocument).createTextNode(r),r[br]=t,t.stateNode=r}return Ut(t),null;case 13:if(it(ct),r=t.memoizedState,e===null||e.memoizedState!==null&&e.memoizedState.dehydrated!==null){if(ut&&kn!==null&&t.mode&1&&!(t.flags&128))u0(),ls(),t.flags|=98560,u=!1;else if(u=Fl(t),r!==null&&r.dehydrated!==null){if(e===null){if(!u)throw Error(de(318));if(u=t.memoizedState,u=u!==null?u.dehydrated:null,!u)throw Error(de(317));u[br]=t}else ls(),!(t.flags&128)&&(t.memoizedState=null),t.flags|=4;Ut(t),u=!1}else ir!==null&&(ih(ir),ir=null),u=!0;if(!u)return t.flags&65536?t:null}return t.flags&128?(t.lanes=n,t):(r=r!==null,r!==(e!==null&&e.memoizedState!==null)&&r&&(t.child.flags|=8192,t.mode&1&&(e===null||ct.current&1?At===0&&(At=3):uh())),t.updateQueue!==null&&(t.flags|=4),Ut(t),null);case 4:return ps(),Wp(e,t),e===null&&Nu(t.stateNode.containerInfo),Ut(t),null;case 10:return bp(t.type._context),Ut(t),null;case 17:return on(t.type)&&Bl(),Ut(t),null;case 19:if(it(ct),u=t.memoizedState,u===null)return Ut(t),null;if(r=(t.flags&128)!==0,c=u.rendering,c===null)if(r)Hu(u,!1);else{if(At!==0||e!==null&&e.flags&128)for(e=t.child;e!==null;){if(c=Ql(e),c!==null){for(t.flags|=128,Hu(u,!1),r=c.updateQueue,r!==null&&(t.updateQueue=r,t.flags|=4),t.subtreeFlags=0,r=n,n=t.child;n!==null;)u=n,e=r,u.flags&=14680066,c=u.alternate,c===null?(u.childLanes=0,u.lanes=e,u.child=null,u.subtreeFlags=0,u.memoizedProps=null,u.memoizedState=null,u.updateQueue=null,u.dependencies=null,u.stateNode=null):(u.childLanes=c.childLanes,u.lanes=c.lanes,u.child=c.child,u.subtreeFlags=0,u.deletions=null,u.memoizedProps=c.memoizedProps,u.memoizedState=c.memoizedState,u.updateQueue=c.updateQueue,u.type=c.type,e=c.dependencies,u.dependencies=e===null?null:{lanes:e.lanes,firstContext:e.firstContext}),n=n.sibling;return et(ct,ct.current&1|2),t.child}e=e.sibling}u.tail!==null&&mt()>vs&&(t.flags|=128,r=!0,Hu(u,!1),t.lanes=4194304)}else{if(!r)if(e=Ql(c),e!==null){if(t.flags|=128,r=!0,n=e.updateQueue,n!==null&&(t.updateQueue=n,t.flags|=4),Hu(u,!0),u.tail===null&&u.tailMode==="hidden"&&!c.alternate&&!ut)return Ut(t),null}else 2*mt()-u.renderingStartTime>vs&&n!==1073741824&&(t.flags|=128,r=!0,Hu(u,!1),t.lanes=4194304);u.isBackwards?(c.sibling=t.child,t.child=c):(n=u.last,n!==null?n.sibling=c:t.child=c,u.last=c)}return u.tail!==null?(t=u.tail,u.rendering=t,u.tail=t.sibling,u.renderingStartTime=mt(),t.sibling=null,n=ct.current,et(ct,r?n&1|2:n&1),t):(Ut(t),null);case 22:case 23:return sh(),r=t.memoizedState!==null,e!==null&&e.memoizedState!==null!==r&&(t.flags|=8192),r&&t.mode&1?An&1073741824&&(Ut(t),t.subtreeFlags&6&&(t.flags|=8192)):Ut(t),null;case 24:return null;case 25:return null}throw Error(de(156,t.tag))}function vC(e,t){switch(gp(t),t.tag){case 1:return on(t.type)&&Bl(),e=t.flags,e&65536?(t.flags=e&-65537|128,t):null;case 3:return ps(),it(rn),it(zt),Op(),e=t.flags,e&65536&&!(e&128)?(t.flags=e&-65537|128,t):null;case 5:return xp(t),null;case 13:if(it(ct),e=t.memoizedState,e!==null&&e.dehydrated!==null){if(t.alternate===null)throw Error(de(340));ls()}return e=t.flags,e&65536?(t.flags=e&-65537|128,t):null;case 19:return it(ct),null;case 4:return ps(),null;case 10:return bp(t.type._context),null;case 22:case 23:return sh(),null;case 24:return null;default:return null}}var ic=!1,jt=!1,_C=typeof WeakSet=="function"?WeakSet:Set,ye=null;function gs(e,t){var n=e.ref;if(n!==null)if(typeof n=="function")try{n(null)}catch(r){ht(e,t,r)}else n.current=null}function Kp(e,t,n){try{n()}catch(r){ht(e,t,r)}}var sw=!1;function yC(e,t){if(sp=kl,e=My(),Zf(e)){if("selectionStart"in e)var n={start:e.selectionStart,end:e.selectionEnd};else e:{n=(n=e.ownerDocument)&&n.defaultView||window;var r=n.getSelection&&n.getSelection();if(r&&r.rangeCount!==0){n=r.anchorNode;var o=r.anchorOffset,u=r.focusNode;r=r.focusOffset;try{n.nodeType,u.nodeType}catch{n=null;break e}var c=0,p=-1,g=-1,b=0,O=0,P=e,q=null;t:for(;;){for(var Z;P!==n||o!==0&&P.nodeType!==3||(p=c+o),P!==u||r!==0&&P.nodeType!==3||(g=c+r),P.nodeType===3&&(c+=P.nodeValue.length),(Z=P.firstChild)!==null;)q=P,P=Z;for(;;){if(P===e)break t;if(q===n&&++b===o&&(p=c),q===u&&++O===r&&(g=c),(Z=P.nextSibling)!==null)break;P=q,q=P.parentNode}P=Z}n=p===-1||g===-1?null:{start:p,end:g}}else n=null}n=n||{start:0,end:0}}else n=null;for(up={focusedElem:e,selectionRange:n},kl=!1,ye=t;ye!==null;)if(t=ye,e=t.child,(t.subtreeFlags&1028)!==0&&e!==null)e.return=t,ye=e;else for(;ye!==null;){t=ye;try{var M=t.alternate;if(t.flags&1024)switch(t.tag){case 0:case 11:case 15:break;case 1:if(M!==null){var H=M.memoizedProps,z=M.memoizedState,L=t.stateNode,k=L.getSnapshotBeforeUpdate(t.elementType===t.type?H:or(t.type,H),z);L.__reactInternalSnapshotBeforeUpdate=k}break;case 3:var x=t.stateNode.containerInfo;x.nodeType===1?x.textContent="":x.nodeType===9&&x.documentElement&&x.removeChild(x.documentElement);break;case 5:case 6:case 4:case 17:break;default:throw Error(de(163))}}catch(l){ht(t,t.return,l)}if(e=t.sibling,e!==null){e.return=t.return,ye=e;break}ye=t.return}return M=sw,sw=!1,M}function Gu(e,t,n){var r=t.updateQueue;if(r=r!==null?r.lastEffect:null,r!==null){var o=r=r.next;do{if((o.tag&e)===e){var u=o.destroy;o.destroy=void 0,u!==void 0&&Kp(t,n,u)}o=o.next}while(o!==r)}}function oc(e,t){if(t=t.updateQueue,t=t!==null?t.lastEffect:null,t!==null){var n=t=t.next;do{if((n.tag&e)===e){var r=n.create;n.destroy=r()}n=n.next}while(n!==t)}}function Yp(e){var t=e.ref;if(t!==null){var n=e.stateNode;switch(e.tag){case 5:e=n;break;default:e=n}typeof t=="function"?t(e):t.current=e}}function uw(e){var t=e.alternate;t!==null&&(e.alternate=null,uw(t)),e.child=null,e.deletions=null,e.sibling=null,e.tag===5&&(t=e.stateNode,t!==null&&(delete t[br],delete t[qu],delete t[dp],delete t[tC],delete t[nC])),e.stateNode=null,e.return=null,e.dependencies=null,e.memoizedProps=null,e.memoizedState=null,e.pendingProps=null,e.stateNode=null,e.updateQueue=null}function aw(e){return e.tag===5||e.tag===3||e.tag===4}function lw(e){e:for(;;){for(;e.sibling===null;){if(e.return===null||aw(e.return))return null;e=e.return}for(e.sibling.return=e.return,e=e.sibling;e.tag!==5&&e.tag!==6&&e.tag!==18;){if(e.flags&2||e.child===null||e.tag===4)continue e;e.child.return=e,e=e.child}if(!(e.flags&2))return e.stateNode}}function Qp(e,t,n){var r=e.tag;if(r===5||r===6)e=e.stateNode,t?n.nodeType===8?n.parentNode.insertBefore(e,t):n.insertBefore(e,t):(n.nodeType===8?(t=n.parentNode,t.insertBefore(e,n)):(t=n,t.appendChild(e)),n=n._reactRootContainer,n!=null||t.onclick!==null||(t.onclick=Il));else if(r!==4&&(e=e.child,e!==null))for(Qp(e,t,n),e=e.sibling;e!==null;)Qp(e,t,n),e=e.sibling}function Xp(e,t,n){var r=e.tag;if(r===5||r===6)e=e.stateNode,t?n.insertBefore(e,t):n.appendChild(e);else if(r!==4&&(e=e.child,e!==null))for(Xp(e,t,n),e=e.sibling;e!==null;)Xp(e,t,n),e=e.sibling}var qt=null,sr=!1;function ki(e,t,n){for(n=n.child;n!==null;)cw(e,t,n),n=n.sibling}function cw(e,t,n){if(wr&&typeof wr.onCommitFiberUnmount=="function")try{wr.onCommitFiberUnmount(_l,n)}catch{}switch(n.tag){case 5:jt||gs(n,t);case 6:var r=qt,o=sr;qt=null,ki(e,t,n),qt=r,sr=o,qt!==null&&(sr?(e=qt,n=n.stateNode,e.nodeType===8?e.parentNode.removeChild(n):e.removeChild(n)):qt.removeChild(n.stateNode));break;case 18:qt!==null&&(sr?(e=qt,n=n.stateNode,e.nodeType===8?cp(e.parentNode,n):e.nodeType===1&&cp(e,n),ku(e)):cp(qt,n.stateNode));break;case 4:r=qt,o=sr,qt=n.stateNode.containerInfo,sr=!0,ki(e,t,n),qt=r,sr=o;break;case 0:case 11:case 14:case 15:if(!jt&&(r=n.updateQueue,r!==null&&(r=r.lastEffect,r!==null))){o=r=r.next;do{var u=o,c=u.destroy;u=u.tag,c!==void 0&&(u&2||u&4)&&Kp(n,t,c),o=o.next}while(o!==r)}ki(e,t,n);break;case 1:if(!jt&&(gs(n,t),r=n.stateNode,typeof r.componentWillUnmount=="function"))try{r.props=n.memoizedProps,r.state=n.memoizedState,r.componentWillUnmount()}catch(p){ht(n,t,p)}ki(e,t,n);break;case 21:ki(e,t,n);break;case 22:n.mode&1?(jt=(r=jt)||n.memoizedState!==null,ki(e,t,n),jt=r):ki(e,t,n);break;default:ki(e,t,n)}}function dw(e){var t=e.updateQueue;if(t!==null){e.updateQueue=null;var n=e.stateNode;n===null&&(n=e.stateNode=new _C),t.forEach(function(r){var o=xC.bind(null,e,r);n.has(r)||(n.add(r),r.then(o,o))})}}function ur(e,t){var n=t.deletions;if(n!==null)for(var r=0;r<n.length;r++){var o=n[r];try{var u=e,c=t,p=c;e:for(;p!==null;){switch(p.tag){case 5:qt=p.stateNode,sr=!1;break e;case 3:qt=p.stateNode.containerInfo,sr=!0;break e;case 4:qt=p.stateNode.containerInfo,sr=!0;break e}p=p.return}if(qt===null)throw Error(de(160));cw(u,c,o),qt=null,sr=!1;var g=o.alternate;g!==null&&(g.return=null),o.return=null}catch(b){ht(o,t,b)}}if(t.subtreeFlags&12854)for(t=t.child;t!==null;)fw(t,e),t=t.sibling}function fw(e,t){var n=e.alternate,r=e.flags;switch(e.tag){case 0:case 11:case 14:case 15:if(ur(t,e),kr(e),r&4){try{Gu(3,e,e.return),oc(3,e)}catch(H){ht(e,e.return,H)}try{Gu(5,e,e.return)}catch(H){ht(e,e.return,H)}}break;case 1:ur(t,e),kr(e),r&512&&n!==null&&gs(n,n.return);break;case 5:if(ur(t,e),kr(e),r&512&&n!==null&&gs(n,n.return),e.flags&32){var o=e.stateNode;try{pu(o,"")}catch(H){ht(e,e.return,H)}}if(r&4&&(o=e.stateNode,o!=null)){var u=e.memoizedProps,c=n!==null?n.memoizedProps:u,p=e.type,g=e.updateQueue;if(e.updateQueue=null,g!==null)try{p==="input"&&u.type==="radio"&&u.name!=null&&z_(o,u),xf(p,c);var b=xf(p,u);for(c=0;c<g.length;c+=2){var O=g[c],P=g[c+1];O==="style"?W_(o,P):O==="dangerouslySetInnerHTML"?H_(o,P):O==="children"?pu(o,P):df(o,O,P,b)}switch(p){case"input":Ef(o,u);break;case"textarea":j_(o,u);break;case"select":var q=o._wrapperState.wasMultiple;o._wrapperState.wasMultiple=!!u.multiple;var Z=u.value;Z!=null?Ko(o,!!u.multiple,Z,!1):q!==!!u.multiple&&(u.defaultValue!=null?Ko(o,!!u.multiple,u.defaultValue,!0):Ko(o,!!u.multiple,u.multiple?[]:"",!1))}o[qu]=u}catch(H){ht(e,e.return,H)}}break;case 6:if(ur(t,e),kr(e),r&4){if(e.stateNode===null)throw Error(de(162));o=e.stateNode,u=e.memoizedProps;try{o.nodeValue=u}catch(H){ht(e,e.return,H)}}break;case 3:if(ur(t,e),kr(e),r&4&&n!==null&&n.memoizedState.isDehydrated)try{ku(t.containerInfo)}catch(H){ht(e,e.return,H)}break;case 4:ur(t,e),kr(e);break;case 13:ur(t,e),kr(e),o=e.child,o.flags&8192&&(u=o.memoizedState!==null,o.stateNode.isHidden=u,!u||o.alternate!==null&&o.alternate.memoizedState!==null||(eh=mt())),r&4&&dw(e);break;case 22:if(O=n!==null&&n.memoizedState!==null,e.mode&1?(jt=(b=jt)||O,ur(t,e),jt=b):ur(t,e),kr(e),r&8192){if(b=e.memoizedState!==null,(e.stateNode.isHidden=b)&&!O&&e.mode&1)for(ye=e,O=e.child;O!==null;){for(P=ye=O;ye!==null;){switch(q=ye,Z=q.child,q.tag){case 0:case 11:case 14:case 15:Gu(4,q,q.return);break;case 1:gs(q,q.return);var M=q.stateNode;if(typeof M.componentWillUnmount=="function"){r=q,n=q.return;try{t=r,M.props=t.memoizedProps,M.state=t.memoizedState,M.componentWillUnmount()}catch(H){ht(r,n,H)}}break;case 5:gs(q,q.return);break;case 22:if(q.memoizedState!==null){gw(P);continue}}Z!==null?(Z.return=q,ye=Z):gw(P)}O=O.sibling}e:for(O=null,P=e;;){if(P.tag===5){if(O===null){O=P;try{o=P.stateNode,b?(u=o.style,typeof u.setProperty=="function"?u.setProperty("display","none","important"):u.display="none"):(p=P.stateNode,g=P.memoizedProps.style,c=g!=null&&g.hasOwnProperty("display")?g.display:null,p.style.display=G_("display",c))}catch(H){ht(e,e.return,H)}}}else if(P.tag===6){if(O===null)try{P.stateNode.nodeValue=b?"":P.memoizedProps}catch(H){ht(e,e.return,H)}}else if((P.tag!==22&&P.tag!==23||P.memoizedState===null||P===e)&&P.child!==null){P.child.return=P,P=P.child;continue}if(P===e)break e;for(;P.sibling===null;){if(P.return===null||P.return===e)break e;O===P&&(O=null),P=P.return}O===P&&(O=null),P.sibling.return=P.return,P=P.sibling}}break;case 19:ur(t,e),kr(e),r&4&&dw(e);break;case 21:break;default:ur(t,e),kr(e)}}function kr(e){var t=e.flags;if(t&2){try{e:{for(var n=e.return;n!==null;){if(aw(n)){var r=n;break e}n=n.return}throw Error(de(160))}switch(r.tag){case 5:var o=r.stateNode;r.flags&32&&(pu(o,""),r.flags&=-33);var u=lw(e);Xp(e,u,o);break;case 3:case 4:var c=r.stateNode.containerInfo,p=lw(e);Qp(e,p,c);break;default:throw Error(de(161))}}catch(g){ht(e,e.return,g)}e.flags&=-3}t&4096&&(e.flags&=-4097)}function wC(e,t,n){ye=e,pw(e)}function pw(e,t,n){for(var r=(e.mode&1)!==0;ye!==null;){var o=ye,u=o.child;if(o.tag===22&&r){var c=o.memoizedState!==null||ic;if(!c){var p=o.alternate,g=p!==null&&p.memoizedState!==null||jt;p=ic;var b=jt;if(ic=c,(jt=g)&&!b)for(ye=o;ye!==null;)c=ye,g=c.child,c.tag===22&&c.memoizedState!==null?mw(o):g!==null?(g.return=c,ye=g):mw(o);for(;u!==null;)ye=u,pw(u),u=u.sibling;ye=o,ic=p,jt=b}hw(e)}else o.subtreeFlags&8772&&u!==null?(u.return=o,ye=u):hw(e)}}function hw(e){for(;ye!==null;){var t=ye;if(t.flags&8772){var n=t.alternate;try{if(t.flags&8772)switch(t.tag){case 0:case 11:case 15:jt||oc(5,t);break;case 1:var r=t.stateNode;if(t.flags&4&&!jt)if(n===null)r.componentDidMount();else{var o=t.elementType===t.type?n.memoizedProps:or(t.type,n.memoizedProps);r.componentDidUpdate(o,n.memoizedState,r.__reactInternalSnapshotBeforeUpdate)}var u=t.updateQueue;u!==null&&d0(t,u,r);break;case 3:var c=t.updateQueue;if(c!==null){if(n=null,t.child!==null)switch(t.child.tag){case 5:n=t.child.stateNode;break;case 1:n=t.child.stateNode}d0(t,c,n)}break;case 5:var p=t.stateNode;if(n===null&&t.flags&4){n=p;var g=t.memoizedProps;switch(t.type){case"button":case"input":case"select":case"textarea":g.autoFocus&&n.focus();break;case"img":g.src&&(n.src=g.src)}}break;case 6:break;case 4:break;case 12:break;case 13:if(t.memoizedState===null){var b=t.alternate;if(b!==null){var O=b.memoizedState;if(O!==null){var P=O.dehydrated;P!==null&&ku(P)}}}break;case 19:case 17:case 21:case 22:case 23:case 25:break;default:throw Error(de(163))}jt||t.flags&512&&Yp(t)}catch(q){ht(t,t.return,q)}}if(t===e){ye=null;break}if(n=t.sibling,n!==null){n.return=t.return,ye=n;break}ye=t.return}}function gw(e){for(;ye!==null;){var t=ye;if(t===e){ye=null;break}var n=t.sibling;if(n!==null){n.return=t.return,ye=n;break}ye=t.return}}function mw(e){for(;ye!==null;){var t=ye;try{switch(t.tag){case 0:case 11:case 15:var n=t.return;try{oc(4,t)}catch(g){ht(t,n,g)}break;case 1:var r=t.stateNode;if(typeof r.componentDidMount=="function"){var o=t.return;try{r.componentDidMount()}catch(g){ht(t,o,g)}}var u=t.return;try{Yp(t)}catch(g){ht(t,u,g)}break;case 5:var c=t.return;try{Yp(t)}catch(g){ht(t,c,g)}}}catch(g){ht(t,t.return,g)}if(t===e){ye=null;break}var p=t.sibling;if(p!==null){p.return=t.return,ye=p;break}ye=t.return}}var bC=Math.ceil,sc=qr.ReactCurrentDispatcher,Zp=qr.ReactCurrentOwner,$n=qr.ReactCurrentBatchConfig,Fe=0,Pt=null,wt=null,It=0,An=0,ms=yi(0),At=0,Wu=null,lo=0,uc=0,Jp=0,Ku=null,un=null,eh=0,vs=1/0,Fr=null,ac=!1,th=null,Ai=null,lc=!1,Ci=null,cc=0,Yu=0,nh=null,dc=-1,fc=0;function Qt(){return Fe&6?mt():dc!==-1?dc:dc=mt()}function Ti(e){return e.mode&1?Fe&2&&It!==0?It&-It:iC.transition!==null?(fc===0&&(fc=ay()),fc):(e=Ke,e!==0||(e=window.event,e=e===void 0?16:vy(e.type)),e):1}function ar(e,t,n,r){if(50<Yu)throw Yu=0,nh=null,Error(de(185));yu(e,n,r),(!(Fe&2)||e!==Pt)&&(e===Pt&&(!(Fe&2)&&(uc|=n),At===4&&xi(e,It)),an(e,r),n===1&&Fe===0&&!(t.mode&1)&&(vs=mt()+500,$l&&bi()))}function an(e,t){var n=e.callbackNode;iA(e,t);var r=bl(e,e===Pt?It:0);if(r===0)n!==null&&oy(n),e.callbackNode=null,e.callbackPriority=0;else if(t=r&-r,e.callbackPriority!==t){if(n!=null&&oy(n),t===1)e.tag===0?rC(_w.bind(null,e)):n0(_w.bind(null,e)),JA(function(){!(Fe&6)&&bi()}),n=null;else{switch(ly(r)){case 1:n=qf;break;case 4:n=sy;break;case 16:n=vl;break;case 536870912:n=uy;break;default:n=vl}n=Cw(n,vw.bind(null,e))}e.callbackPriority=t,e.callbackNode=n}}function vw(e,t){if(dc=-1,fc=0,Fe&6)throw Error(de(327));var n=e.callbackNode;if(_s()&&e.callbackNode!==n)return null;var r=bl(e,e===Pt?It:0);if(r===0)return null;if(r&30||r&e.expiredLanes||t)t=pc(e,r);else{t=r;var o=Fe;Fe|=2;var u=ww();(Pt!==e||It!==t)&&(Fr=null,vs=mt()+500,fo(e,t));do try{kC();break}catch(p){yw(e,p)}while(1);wp(),sc.current=u,Fe=o,wt!==null?t=0:(Pt=null,It=0,t=At)}if(t!==0){if(t===2&&(o=If(e),o!==0&&(r=o,t=rh(e,o))),t===1)throw n=Wu,fo(e,0),xi(e,r),an(e,mt()),n;if(t===6)xi(e,r);else{if(o=e.current.alternate,!(r&30)&&!SC(o)&&(t=pc(e,r),t===2&&(u=If(e),u!==0&&(r=u,t=rh(e,u))),t===1))throw n=Wu,fo(e,0),xi(e,r),an(e,mt()),n;switch(e.finishedWork=o,e.finishedLanes=r,t){case 0:case 1:throw Error(de(345));case 2:po(e,un,Fr);break;case 3:if(xi(e,r),(r&130023424)===r&&(t=eh+500-mt(),10<t)){if(bl(e,0)!==0)break;if(o=e.suspendedLanes,(o&r)!==r){Qt(),e.pingedLanes|=e.suspendedLanes&o;break}e.timeoutHandle=lp(po.bind(null,e,un,Fr),t);break}po(e,un,Fr);break;case 4:if(xi(e,r),(r&4194240)===r)break;for(t=e.eventTimes,o=-1;0<r;){var c=31-nr(r);u=1<<c,c=t[c],c>o&&(o=c),r&=~u}if(r=o,r=mt()-r,r=(120>r?120:480>r?480:1080>r?1080:1920>r?1920:3e3>r?3e3:4320>r?4320:1960*bC(r/1960))-r,10<r){e.timeoutHandle=lp(po.bind(null,e,un,Fr),r);break}po(e,un,Fr);break;case 5:po(e,un,Fr);break;default:throw Error(de(329))}}}return an(e,mt()),e.callbackNode===n?vw.bind(null,e):null}function rh(e,t){var n=Ku;return e.current.memoizedState.isDehydrated&&(fo(e,t).flags|=256),e=pc(e,t),e!==2&&(t=un,un=n,t!==null&&ih(t)),e}function ih(e){un===null?un=e:un.push.apply(un,e)}function SC(e){for(var t=e;;){if(t.flags&16384){var n=t.updateQueue;if(n!==null&&(n=n.stores,n!==null))for(var r=0;r<n.length;r++){var o=n[r],u=o.getSnapshot;o=o.value;try{if(!rr(u(),o))return!1}catch{return!1}}}if(n=t.child,t.subtreeFlags&16384&&n!==null)n.return=t,t=n;else{if(t===e)break;for(;t.sibling===null;){if(t.return===null||t.return===e)return!0;t=t.return}t.sibling.return=t.return,t=t.sibling}}return!0}function xi(e,t){for(t&=~Jp,t&=~uc,e.suspendedLanes|=t,e.pingedLanes&=~t,e=e.expirationTimes;0<t;){var n=31-nr(t),r=1<<n;e[n]=-1,t&=~r}}function _w(e){if(Fe&6)throw Error(de(327));_s();var t=bl(e,0);if(!(t&1))return an(e,mt()),null;var n=pc(e,t);if(e.tag!==0&&n===2){var r=If(e);r!==0&&(t=r,n=rh(e,r))}if(n===1)throw n=Wu,fo(e,0),xi(e,t),an(e,mt()),n;if(n===6)throw Error(de(345));return e.finishedWork=e.current.alternate,e.finishedLanes=t,po(e,un,Fr),an(e,mt()),null}function oh(e,t){var n=Fe;Fe|=1;try{return e(t)}finally{Fe=n,Fe===0&&(vs=mt()+500,$l&&bi())}}function co(e){Ci!==null&&Ci.tag===0&&!(Fe&6)&&_s();var t=Fe;Fe|=1;var n=$n.transition,r=Ke;try{if($n.transition=null,Ke=1,e)return e()}finally{Ke=r,$n.transition=n,Fe=t,!(Fe&6)&&bi()}}function sh(){An=ms.current,it(ms)}function fo(e,t){e.finishedWork=null,e.finishedLanes=0;var n=e.timeoutHandle;if(n!==-1&&(e.timeoutHandle=-1,ZA(n)),wt!==null)for(n=wt.return;n!==null;){var r=n;switch(gp(r),r.tag){case 1:r=r.type.childContextTypes,r!=null&&Bl();break;case 3:ps(),it(rn),it(zt),Op();break;case 5:xp(r);break;case 4:ps();break;case 13:it(ct);break;case 19:it(ct);break;case 10:bp(r.type._context);break;case 22:case 23:sh()}n=n.return}if(Pt=e,wt=e=Li(e.current,null),It=An=t,At=0,Wu=null,Jp=uc=lo=0,un=Ku=null,so!==null){for(t=0;t<so.length;t++)if(n=so[t],r=n.interleaved,r!==null){n.interleaved=null;var o=r.next,u=n.pending;if(u!==null){var c=u.next;u.next=o,r.next=c}n.pending=r}so=null}return e}function yw(e,t){do{var n=wt;try{if(wp(),Xl.current=tc,Zl){for(var r=dt.memoizedState;r!==null;){var o=r.queue;o!==null&&(o.pending=null),r=r.next}Zl=!1}if(ao=0,Ot=kt=dt=null,Uu=!1,ju=0,Zp.current=null,n===null||n.return===null){At=1,Wu=t,wt=null;break}e:{var u=e,c=n.return,p=n,g=t;if(t=It,p.flags|=32768,g!==null&&typeof g=="object"&&typeof g.then=="function"){var b=g,O=p,P=O.tag;if(!(O.mode&1)&&(P===0||P===11||P===15)){var q=O.alternate;q?(O.updateQueue=q.updateQueue,O.memoizedState=q.memoizedState,O.lanes=q.lanes):(O.updateQueue=null,O.memoizedState=null)}var Z=V0(c);if(Z!==null){Z.flags&=-257,H0(Z,c,p,u,t),Z.mode&1&&F0(u,b,t),t=Z,g=b;var M=t.updateQueue;if(M===null){var H=new Set;H.add(g),t.updateQueue=H}else M.add(g);break e}else{if(!(t&1)){F0(u,b,t),uh();break e}g=Error(de(426))}}else if(ut&&p.mode&1){var z=V0(c);if(z!==null){!(z.flags&65536)&&(z.flags|=256),H0(z,c,p,u,t),_p(hs(g,p));break e}}u=g=hs(g,p),At!==4&&(At=2),Ku===null?Ku=[u]:Ku.push(u),u=c;do{switch(u.tag){case 3:u.flags|=65536,t&=-t,u.lanes|=t;var L=U0(u,g,t);c0(u,L);break e;case 1:p=g;var k=u.type,x=u.stateNode;if(!(u.flags&128)&&(typeof k.getDerivedStateFromError=="function"||x!==null&&typeof x.componentDidCatch=="function"&&(Ai===null||!Ai.has(x)))){u.flags|=65536,t&=-t,u.lanes|=t;var l=j0(u,p,t);c0(u,l);break e}}u=u.return}while(u!==null)}Sw(n)}catch(h){t=h,wt===n&&n!==null&&(wt=n=n.return);continue}break}while(1)}function ww(){var e=sc.current;return sc.current=tc,e===null?tc:e}function uh(){(At===0||At===3||At===2)&&(At=4),Pt===null||!(lo&268435455)&&!(uc&268435455)||xi(Pt,It)}function pc(e,t){var n=Fe;Fe|=2;var r=ww();(Pt!==e||It!==t)&&(Fr=null,fo(e,t));do try{EC();break}catch(o){yw(e,o)}while(1);if(wp(),Fe=n,sc.current=r,wt!==null)throw Error(de(261));return Pt=null,It=0,At}function EC(){for(;wt!==null;)bw(wt)}function kC(){for(;wt!==null&&!Yk();)bw(wt)}function bw(e){var t=Aw(e.alternate,e,An);e.memoizedProps=e.pendingProps,t===null?Sw(e):wt=t,Zp.current=null}function Sw(e){var t=e;do{var n=t.alternate;if(e=t.return,t.flags&32768){if(n=vC(n,t),n!==null){n.flags&=32767,wt=n;return}if(e!==null)e.flags|=32768,e.subtreeFlags=0,e.deletions=null;else{At=6,wt=null;return}}else if(n=mC(n,t,An),n!==null){wt=n;return}if(t=t.sibling,t!==null){wt=t;return}wt=t=e}while(t!==null);At===0&&(At=5)}function po(e,t,n){var r=Ke,o=$n.transition;try{$n.transition=null,Ke=1,AC(e,t,n,r)}finally{$n.transition=o,Ke=r}return null}function AC(e,t,n,r){do _s();while(Ci!==null);if(Fe&6)throw Error(de(327));n=e.finishedWork;var o=e.finishedLanes;if(n===null)return null;if(e.finishedWork=null,e.finishedLanes=0,n===e.current)throw Error(de(177));e.callbackNode=null,e.callbackPriority=0;var u=n.lanes|n.childLanes;if(oA(e,u),e===Pt&&(wt=Pt=null,It=0),!(n.subtreeFlags&2064)&&!(n.flags&2064)||lc||(lc=!0,Cw(vl,function(){return _s(),null})),u=(n.flags&15990)!==0,n.subtreeFlags&15990||u){u=$n.transition,$n.transition=null;var c=Ke;Ke=1;var p=Fe;Fe|=4,Zp.current=null,yC(e,n),fw(n,e),HA(up),kl=!!sp,up=sp=null,e.current=n,wC(n),Qk(),Fe=p,Ke=c,$n.transition=u}else e.current=n;if(lc&&(lc=!1,Ci=e,cc=o),u=e.pendingLanes,u===0&&(Ai=null),Jk(n.stateNode),an(e,mt()),t!==null)for(r=e.onRecoverableError,n=0;n<t.length;n++)o=t[n],r(o.value,{componentStack:o.stack,digest:o.digest});if(ac)throw ac=!1,e=th,th=null,e;return cc&1&&e.tag!==0&&_s(),u=e.pendingLanes,u&1?e===nh?Yu++:(Yu=0,nh=e):Yu=0,bi(),null}function _s(){if(Ci!==null){var e=ly(cc),t=$n.transition,n=Ke;try{if($n.transition=null,Ke=16>e?16:e,Ci===null)var r=!1;else{if(e=Ci,Ci=null,cc=0,Fe&6)throw Error(de(331));var o=Fe;for(Fe|=4,ye=e.current;ye!==null;){var u=ye,c=u.child;if(ye.flags&16){var p=u.deletions;if(p!==null){for(var g=0;g<p.length;g++){var b=p[g];for(ye=b;ye!==null;){var O=ye;switch(O.tag){case 0:case 11:case 15:Gu(8,O,u)}var P=O.child;if(P!==null)P.return=O,ye=P;else for(;ye!==null;){O=ye;var q=O.sibling,Z=O.return;if(uw(O),O===b){ye=null;break}if(q!==null){q.return=Z,ye=q;break}ye=Z}}}var M=u.alternate;if(M!==null){var H=M.child;if(H!==null){M.child=null;do{var z=H.sibling;H.sibling=null,H=z}while(H!==null)}}ye=u}}if(u.subtreeFlags&2064&&c!==null)c.return=u,ye=c;else e:for(;ye!==null;){if(u=ye,u.flags&2048)switch(u.tag){case 0:case 11:case 15:Gu(9,u,u.return)}var L=u.sibling;if(L!==null){L.return=u.return,ye=L;break e}ye=u.return}}var k=e.current;for(ye=k;ye!==null;){c=ye;var x=c.child;if(c.subtreeFlags&2064&&x!==null)x.return=c,ye=x;else e:for(c=k;ye!==null;){if(p=ye,p.flags&2048)try{switch(p.tag){case 0:case 11:case 15:oc(9,p)}}catch(h){ht(p,p.return,h)}if(p===c){ye=null;break e}var l=p.sibling;if(l!==null){l.return=p.return,ye=l;break e}ye=p.return}}if(Fe=o,bi(),wr&&typeof wr.onPostCommitFiberRoot=="function")try{wr.onPostCommitFiberRoot(_l,e)}catch{}r=!0}return r}finally{Ke=n,$n.transition=t}}return!1}function Ew(e,t,n){t=hs(n,t),t=U0(e,t,1),e=Ei(e,t,1),t=Qt(),e!==null&&(yu(e,1,t),an(e,t))}function ht(e,t,n){if(e.tag===3)Ew(e,e,n);else for(;t!==null;){if(t.tag===3){Ew(t,e,n);break}else if(t.tag===1){var r=t.stateNode;if(typeof t.type.getDerivedStateFromError=="function"||typeof r.componentDidCatch=="function"&&(Ai===null||!Ai.has(r))){e=hs(n,e),e=j0(t,e,1),t=Ei(t,e,1),e=Qt(),t!==null&&(yu(t,1,e),an(t,e));break}}t=t.return}}function CC(e,t,n){var r=e.pingCache;r!==null&&r.delete(t),t=Qt(),e.pingedLanes|=e.suspendedLanes&n,Pt===e&&(It&n)===n&&(At===4||At===3&&(It&130023424)===It&&500>mt()-eh?fo(e,0):Jp|=n),an(e,t)}function kw(e,t){t===0&&(e.mode&1?(t=wl,wl<<=1,!(wl&130023424)&&(wl=4194304)):t=1);var n=Qt();e=$r(e,t),e!==null&&(yu(e,t,n),an(e,n))}function TC(e){var t=e.memoizedState,n=0;t!==null&&(n=t.retryLane),kw(e,n)}function xC(e,t){var n=0;switch(e.tag){case 13:var r=e.stateNode,o=e.memoizedState;o!==null&&(n=o.retryLane);break;case 19:r=e.stateNode;break;default:throw Error(de(314))}r!==null&&r.delete(t),kw(e,n)}var Aw;Aw=function(e,t,n){if(e!==null)if(e.memoizedProps!==t.pendingProps||rn.current)sn=!0;else{if(!(e.lanes&n)&&!(t.flags&128))return sn=!1,gC(e,t,n);sn=!!(e.flags&131072)}else sn=!1,ut&&t.flags&1048576&&r0(t,jl,t.index);switch(t.lanes=0,t.tag){case 2:var r=t.type;rc(e,t),e=t.pendingProps;var o=ss(t,zt.current);ds(t,n),o=Np(null,t,r,e,o,n);var u=Rp();return t.flags|=1,typeof o=="object"&&o!==null&&typeof o.render=="function"&&o.$$typeof===void 0?(t.tag=1,t.memoizedState=null,t.updateQueue=null,on(r)?(u=!0,zl(t)):u=!1,t.memoizedState=o.state!==null&&o.state!==void 0?o.state:null,kp(t),o.updater=Kl,t.stateNode=o,o._reactInternals=t,Cp(t,r,e,n),t=jp(null,t,r,!0,u,n)):(t.tag=0,ut&&u&&hp(t),Yt(null,t,o,n),t=t.child),t;case 16:r=t.elementType;e:{switch(rc(e,t),e=t.pendingProps,o=r._init,r=o(r._payload),t.type=r,o=t.tag=OC(r),e=or(r,e),o){case 0:t=Up(null,t,r,e,n);break e;case 1:t=X0(null,t,r,e,n);break e;case 11:t=G0(null,t,r,e,n);break e;case 14:t=W0(null,t,r,or(r.type,e),n);break e}throw Error(de(306,r,""))}return t;case 0:return r=t.type,o=t.pendingProps,o=t.elementType===r?o:or(r,o),Up(e,t,r,o,n);case 1:return r=t.type,o=t.pendingProps,o=t.elementType===r?o:or(r,o),X0(e,t,r,o,n);case 3:e:{if(Z0(t),e===null)throw Error(de(387));r=t.pendingProps,u=t.memoizedState,o=u.element,l0(e,t),Wl(t,r,null,n);var c=t.memoizedState;if(r=c.element,u.isDehydrated)if(u={element:r,isDehydrated:!1,cache:c.cache,pendingSuspenseBoundaries:c.pendingSuspenseBoundaries,transitions:c.transitions},t.updateQueue.baseState=u,t.memoizedState=u,t.flags&256){o=hs(Error(de(423)),t),t=J0(e,t,r,n,o);break e}else if(r!==o){o=hs(Error(de(424)),t),t=J0(e,t,r,n,o);break e}else for(kn=_i(t.stateNode.containerInfo.firstChild),En=t,ut=!0,ir=null,n=_0(t,null,r,n),t.child=n;n;)n.flags=n.flags&-3|4096,n=n.sibling;else{if(ls(),r===o){t=jr(e,t,n);break e}Yt(e,t,r,n)}t=t.child}return t;case 5:return y0(t),e===null&&vp(t),r=t.type,o=t.pendingProps,u=e!==null?e.memoizedProps:null,c=o.children,ap(r,o)?c=null:u!==null&&ap(r,u)&&(t.flags|=32),Q0(e,t),Yt(e,t,c,n),t.child;case 6:return e===null&&vp(t),null;case 13:return ew(e,t,n);case 4:return Tp(t,t.stateNode.containerInfo),r=t.pendingProps,e===null?t.child=fs(t,null,r,n):Yt(e,t,r,n),t.child;case 11:return r=t.type,o=t.pendingProps,o=t.elementType===r?o:or(r,o),G0(e,t,r,o,n);case 7:return Yt(e,t,t.pendingProps,n),t.child;case 8:return Yt(e,t,t.pendingProps.children,n),t.child;case 12:return Yt(e,t,t.pendingProps.children,n),t.child;case 10:e:{if(r=t.type._context,o=t.pendingProps,u=t.memoizedProps,c=o.value,et(Vl,r._currentValue),r._currentValue=c,u!==null)if(rr(u.value,c)){if(u.children===o.children&&!rn.current){t=jr(e,t,n);break e}}else for(u=t.child,u!==null&&(u.return=t);u!==null;){var p=u.dependencies;if(p!==null){c=u.child;for(var g=p.firstContext;g!==null;){if(g.context===r){if(u.tag===1){g=Ur(-1,n&-n),g.tag=2;var b=u.updateQueue;if(b!==null){b=b.shared;var O=b.pending;O===null?g.next=g:(g.next=O.next,O.next=g),b.pending=g}}u.lanes|=n,g=u.alternate,g!==null&&(g.lanes|=n),Sp(u.return,n,t),p.lanes|=n;break}g=g.next}}else if(u.tag===10)c=u.type===t.type?null:u.child;else if(u.tag===18){if(c=u.return,c===null)throw Error(de(341));c.lanes|=n,p=c.alternate,p!==null&&(p.lanes|=n),Sp(c,n,t),c=u.sibling}else c=u.child;if(c!==null)c.return=u;else for(c=u;c!==null;){if(c===t){c=null;break}if(u=c.sibling,u!==null){u.return=c.return,c=u;break}c=c.return}u=c}Yt(e,t,o.children,n),t=t.child}return t;case 9:return o=t.type,r=t.pendingProps.children,ds(t,n),o=Bn(o),r=r(o),t.flags|=1,Yt(e,t,r,n),t.child;case 14:return r=t.type,o=or(r,t.pendingProps),o=or(r.type,o),W0(e,t,r,o,n);case 15:return K0(e,t,t.type,t.pendingProps,n);case 17:return r=t.type,o=t.pendingProps,o=t.elementType===r?o:or(r,o),rc(e,t),t.tag=1,on(r)?(e=!0,zl(t)):e=!1,ds(t,n),h0(t,r,o),Cp(t,r,o,n),jp(null,t,r,!0,e,n);case 19:return nw(e,t,n);case 22:return Y0(e,t,n)}throw Error(de(156,t.tag))};function Cw(e,t){return iy(e,t)}function LC(e,t,n,r){this.tag=e,this.key=n,this.sibling=this.child=this.return=this.stateNode=this.type=this.elementType=null,this.index=0,this.ref=null,this.pendingProps=t,this.dependencies=this.memoizedState=this.updateQueue=this.memoizedProps=null,this.mode=r,this.subtreeFlags=this.flags=0,this.deletions=null,this.childLanes=this.lanes=0,this.alternate=null}function Un(e,t,n,r){return new LC(e,t,n,r)}function ah(e){return e=e.prototype,!(!e||!e.isReactComponent)}function OC(e){if(typeof e=="function")return ah(e)?1:0;if(e!=null){if(e=e.$$typeof,e===hf)return 11;if(e===vf)return 14}return 2}function Li(e,t){var n=e.alternate;return n===null?(n=Un(e.tag,t,e.key,e.mode),n.elementType=e.elementType,n.type=e.type,n.stateNode=e.stateNode,n.alternate=e,e.alternate=n):(n.pendingProps=t,n.type=e.type,n.flags=0,n.subtreeFlags=0,n.deletions=null),n.flags=e.flags&14680064,n.childLanes=e.childLanes,n.lanes=e.lanes,n.child=e.child,n.memoizedProps=e.memoizedProps,n.memoizedState=e.memoizedState,n.updateQueue=e.updateQueue,t=e.dependencies,n.dependencies=t===null?null:{lanes:t.lanes,firstContext:t.firstContext},n.sibling=e.sibling,n.index=e.index,n.ref=e.ref,n}function hc(e,t,n,r,o,u){var c=2;if(r=e,typeof e=="function")ah(e)&&(c=1);else if(typeof e=="string")c=5;else e:switch(e){case Wo:return ho(n.children,o,u,t);case ff:c=8,o|=8;break;case pf:return e=Un(12,n,t,o|2),e.elementType=pf,e.lanes=u,e;case gf:return e=Un(13,n,t,o),e.elementType=gf,e.lanes=u,e;case mf:return e=Un(19,n,t,o),e.elementType=mf,e.lanes=u,e;case R_:return gc(n,o,u,t);default:if(typeof e=="object"&&e!==null)switch(e.$$typeof){case D_:c=10;break e;case N_:c=9;break e;case hf:c=11;break e;case vf:c=14;break e;case ci:c=16,r=null;break e}throw Error(de(130,e==null?e:typeof e,""))}return t=Un(c,n,t,o),t.elementType=e,t.type=r,t.lanes=u,t}function ho(e,t,n,r){return e=Un(7,e,r,t),e.lanes=n,e}function gc(e,t,n,r){return e=Un(22,e,r,t),e.elementType=R_,e.lanes=n,e.stateNode={isHidden:!1},e}function lh(e,t,n){return e=Un(6,e,null,t),e.lanes=n,e}function ch(e,t,n){return t=Un(4,e.children!==null?e.children:[],e.key,t),t.lanes=n,t.stateNode={containerInfo:e.containerInfo,pendingChildren:null,implementation:e.implementation},t}function PC(e,t,n,r,o){this.tag=t,this.containerInfo=e,this.finishedWork=this.pingCache=this.current=this.pendingChildren=null,this.timeoutHandle=-1,this.callbackNode=this.pendingContext=this.context=null,this.callbackPriority=0,this.eventTimes=Mf(0),this.expirationTimes=Mf(-1),this.entangledLanes=this.finishedLanes=this.mutableReadLanes=this.expiredLanes=this.pingedLanes=this.suspendedLanes=this.pendingLanes=0,this.entanglements=Mf(0),this.identifierPrefix=r,this.onRecoverableError=o,this.mutableSourceEagerHydrationData=null}function dh(e,t,n,r,o,u,c,p,g){return e=new PC(e,t,n,p,g),t===1?(t=1,u===!0&&(t|=8)):t=0,u=Un(3,null,null,t),e.current=u,u.stateNode=e,u.memoizedState={element:r,isDehydrated:n,cache:null,transitions:null,pendingSuspenseBoundaries:null},kp(u),e}function DC(e,t,n){var r=3<arguments.length&&arguments[3]!==void 0?arguments[3]:null;return{$$typeof:Go,key:r==null?null:""+r,children:e,containerInfo:t,implementation:n}}function Tw(e){if(!e)return wi;e=e._reactInternals;e:{if(to(e)!==e||e.tag!==1)throw Error(de(170));var t=e;do{switch(t.tag){case 3:t=t.stateNode.context;break e;case 1:if(on(t.type)){t=t.stateNode.__reactInternalMemoizedMergedChildContext;break e}}t=t.return}while(t!==null);throw Error(de(171))}if(e.tag===1){var n=e.type;if(on(n))return e0(e,n,t)}return t}function xw(e,t,n,r,o,u,c,p,g){return e=dh(n,r,!0,e,o,u,c,p,g),e.context=Tw(null),n=e.current,r=Qt(),o=Ti(n),u=Ur(r,o),u.callback=t??null,Ei(n,u,o),e.current.lanes=o,yu(e,o,r),an(e,r),e}function mc(e,t,n,r){var o=t.current,u=Qt(),c=Ti(o);return n=Tw(n),t.context===null?t.context=n:t.pendingContext=n,t=Ur(u,c),t.payload={element:e},r=r===void 0?null:r,r!==null&&(t.callback=r),e=Ei(o,t,c),e!==null&&(ar(e,o,c,u),Gl(e,o,c)),c}function vc(e){if(e=e.current,!e.child)return null;switch(e.child.tag){case 5:return e.child.stateNode;default:return e.child.stateNode}}function Lw(e,t){if(e=e.memoizedState,e!==null&&e.dehydrated!==null){var n=e.retryLane;e.retryLane=n!==0&&n<t?n:t}}function fh(e,t){Lw(e,t),(e=e.alternate)&&Lw(e,t)}function NC(){return null}var Ow=typeof reportError=="function"?reportError:function(e){console.error(e)};function ph(e){this._internalRoot=e}_c.prototype.render=ph.prototype.render=function(e){var t=this._internalRoot;if(t===null)throw Error(de(409));mc(e,t,null,null)},_c.prototype.unmount=ph.prototype.unmount=function(){var e=this._internalRoot;if(e!==null){this._internalRoot=null;var t=e.containerInfo;co(function(){mc(null,e,null,null)}),t[Ir]=null}};function _c(e){this._internalRoot=e}_c.prototype.unstable_scheduleHydration=function(e){if(e){var t=fy();e={blockedOn:null,target:e,priority:t};for(var n=0;n<gi.length&&t!==0&&t<gi[n].priority;n++);gi.splice(n,0,e),n===0&&gy(e)}};function hh(e){return!(!e||e.nodeType!==1&&e.nodeType!==9&&e.nodeType!==11)}function yc(e){return!(!e||e.nodeType!==1&&e.nodeType!==9&&e.nodeType!==11&&(e.nodeType!==8||e.nodeValue!==" react-mount-point-unstable "))}function Pw(){}function RC(e,t,n,r,o){if(o){if(typeof r=="function"){var u=r;r=function(){var b=vc(c);u.call(b)}}var c=xw(t,r,e,0,null,!1,!1,"",Pw);return e._reactRootContainer=c,e[Ir]=c.current,Nu(e.nodeType===8?e.parentNode:e),co(),c}for(;o=e.lastChild;)e.removeChild(o);if(typeof r=="function"){var p=r;r=function(){var b=vc(g);p.call(b)}}var g=dh(e,0,!1,null,null,!1,!1,"",Pw);return e._reactRootContainer=g,e[Ir]=g.current,Nu(e.nodeType===8?e.parentNode:e),co(function(){mc(t,g,n,r)}),g}function wc(e,t,n,r,o){var u=n._reactRootContainer;if(u){var c=u;if(typeof o=="function"){var p=o;o=function(){var g=vc(c);p.call(g)}}mc(t,c,e,o)}else c=RC(n,t,e,o,r);return vc(c)}cy=function(e){switch(e.tag){case 3:var t=e.stateNode;if(t.current.memoizedState.isDehydrated){var n=_u(t.pendingLanes);n!==0&&(Bf(t,n|1),an(t,mt()),!(Fe&6)&&(vs=mt()+500,bi()))}break;case 13:co(function(){var r=$r(e,1);if(r!==null){var o=Qt();ar(r,e,1,o)}}),fh(e,1)}},zf=function(e){if(e.tag===13){var t=$r(e,134217728);if(t!==null){var n=Qt();ar(t,e,134217728,n)}fh(e,134217728)}},dy=function(e){if(e.tag===13){var t=Ti(e),n=$r(e,t);if(n!==null){var r=Qt();ar(n,e,t,r)}fh(e,t)}},fy=function(){return Ke},py=function(e,t){var n=Ke;try{return Ke=e,t()}finally{Ke=n}},Pf=function(e,t,n){switch(t){case"input":if(Ef(e,n),t=n.name,n.type==="radio"&&t!=null){for(n=e;n.parentNode;)n=n.parentNode;for(n=n.querySelectorAll("input[name="+JSON.stringify(""+t)+'][type="radio"]'),t=0;t<n.length;t++){var r=n[t];if(r!==e&&r.form===e.form){var o=Ml(r);if(!o)throw Error(de(90));M_(r),Ef(r,o)}}}break;case"textarea":j_(e,n);break;case"select":t=n.value,t!=null&&Ko(e,!!n.multiple,t,!1)}},X_=oh,Z_=co;var qC={usingClientEntryPoint:!1,Events:[Iu,is,Ml,Y_,Q_,oh]},Qu={findFiberByHostInstance:no,bundleType:0,version:"18.2.0",rendererPackageName:"react-dom"},IC={bundleType:Qu.bundleType,version:Qu.version,rendererPackageName:Qu.rendererPackageName,rendererConfig:Qu.rendererConfig,overrideHookState:null,overrideHookStateDeletePath:null,overrideHookStateRenamePath:null,overrideProps:null,overridePropsDeletePath:null,overridePropsRenamePath:null,setErrorHandler:null,setSuspenseHandler:null,scheduleUpdate:null,currentDispatcherRef:qr.ReactCurrentDispatcher,findHostInstanceByFiber:function(e){return e=ny(e),e===null?null:e.stateNode},findFiberByHostInstance:Qu.findFiberByHostInstance||NC,findHostInstancesForRefresh:null,scheduleRefresh:null,scheduleRoot:null,setRefreshHandler:null,getCurrentFiber:null,reconcilerVersion:"18.2.0-next-9e3b772b8-20220608"};if(typeof __REACT_DEVTOOLS_GLOBAL_HOOK__<"u"){var bc=__REACT_DEVTOOLS_GLOBAL_HOOK__;if(!bc.isDisabled&&bc.supportsFiber)try{_l=bc.inject(IC),wr=bc}catch{}}wn.__SECRET_INTERNALS_DO_NOT_USE_OR_YOU_WILL_BE_FIRED=qC,wn.createPortal=function(e,t){var n=2<arguments.length&&arguments[2]!==void 0?arguments[2]:null;if(!hh(t))throw Error(de(200));return DC(e,t,null,n)},wn.createRoot=function(e,t){if(!hh(e))throw Error(de(299));var n=!1,r="",o=Ow;return t!=null&&(t.unstable_strictMode===!0&&(n=!0),t.identifierPrefix!==void 0&&(r=t.identifierPrefix),t.onRecoverableError!==void 0&&(o=t.onRecoverableError)),t=dh(e,1,!1,null,null,n,!1,r,o),e[Ir]=t.current,Nu(e.nodeType===8?e.parentNode:e),new ph(t)},wn.findDOMNode=function(e){if(e==null)return null;if(e.nodeType===1)return e;var t=e._reactInternals;if(t===void 0)throw typeof e.render=="function"?Error(de(188)):(e=Object.keys(e).join(","),Error(de(268,e)));return e=ny(t),e=e===null?null:e.stateNode,e},wn.flushSync=function(e){return co(e)},wn.hydrate=function(e,t,n){if(!yc(t))throw Error(de(200));return wc(null,e,t,!0,n)},wn.hydrateRoot=function(e,t,n){if(!hh(e))throw Error(de(405));var r=n!=null&&n.hydratedSources||null,o=!1,u="",c=Ow;if(n!=null&&(n.unstable_strictMode===!0&&(o=!0),n.identifierPrefix!==void 0&&(u=n.identifierPrefix),n.onRecoverableError!==void 0&&(c=n.onRecoverableError)),t=xw(t,null,e,1,n??null,o,!1,u,c),e[Ir]=t.current,Nu(e),r)for(e=0;e<r.length;e++)n=r[e],o=n._getVersion,o=o(n._source),t.mutableSourceEagerHydrationData==null?t.mutableSourceEagerHydrationData=[n,o]:t.mutableSourceEagerHydrationData.push(n,o);return new _c(t)},wn.render=function(e,t,n){if(!yc(t))throw Error(de(200));return wc(null,e,t,!1,n)},wn.unmountComponentAtNode=function(e){if(!yc(e))throw Error(de(40));return e._reactRootContainer?(co(function(){wc(null,null,e,!1,function(){e._reactRootContainer=null,e[Ir]=null})}),!0):!1},wn.unstable_batchedUpdates=oh,wn.unstable_renderSubtreeIntoContainer=function(e,t,n,r){if(!yc(n))throw Error(de(200));if(e==null||e._reactInternals===void 0)throw Error(de(38));return wc(e,t,n,!1,r)},wn.version="18.2.0-next-9e3b772b8-20220608";function Dw(){if(!(typeof __REACT_DEVTOOLS_GLOBAL_HOOK__>"u"||typeof __REACT_DEVTOOLS_GLOBAL_HOOK__.checkDCE!="function"))try{__REACT_DEVTOOLS_GLOBAL_HOOK__.checkDCE(Dw)}catch(e){console.error(e)}}Dw(),A_.exports=wn;var Nw=A_.exports;function MC(e){e()}function BC(e){e||(e=MC),uk({reactionScheduler:e})}function zC(e){return lk(e)}var $C=1e4,UC=1e4,jC=function(){function e(t){var n=this;Object.defineProperty(this,"finalize",{enumerable:!0,configurable:!0,writable:!0,value:t}),Object.defineProperty(this,"registrations",{enumerable:!0,configurable:!0,writable:!0,value:new Map}),Object.defineProperty(this,"sweepTimeout",{enumerable:!0,configurable:!0,writable:!0,value:void 0}),Object.defineProperty(this,"sweep",{enumerable:!0,configurable:!0,writable:!0,value:function(r){r===void 0&&(r=$C),clearTimeout(n.sweepTimeout),n.sweepTimeout=void 0;var o=Date.now();n.registrations.forEach(function(u,c){o-u.registeredAt>=r&&(n.finalize(u.value),n.registrations.delete(c))}),n.registrations.size>0&&n.scheduleSweep()}}),Object.defineProperty(this,"finalizeAllImmediately",{enumerable:!0,configurable:!0,writable:!0,value:function(){n.sweep(0)}})}return Object.defineProperty(e.prototype,"register",{enumerable:!1,configurable:!0,writable:!0,value:function(t,n,r){this.registrations.set(r,{value:n,registeredAt:Date.now()}),this.scheduleSweep()}}),Object.defineProperty(e.prototype,"unregister",{enumerable:!1,configurable:!0,writable:!0,value:function(t){this.registrations.delete(t)}}),Object.defineProperty(e.prototype,"scheduleSweep",{enumerable:!1,configurable:!0,writable:!0,value:function(){this.sweepTimeout===void 0&&(this.sweepTimeout=setTimeout(this.sweep,UC))}}),e}(),FC=typeof FinalizationRegistry<"u"?FinalizationRegistry:jC,Xu=new FC(function(e){var t;(t=e.reaction)===null||t===void 0||t.dispose(),e.reaction=null}),Rw={exports:{}},qw={};/**
- * @license React
- * use-sync-external-store-shim.production.min.js
- *
- * Copyright (c) Facebook, Inc. and its affiliates.
- *
- * This source code is licensed under the MIT license found in the
- * LICENSE file in the root directory of this source tree.
- */var ys=Ee;function VC(e,t){return e===t&&(e!==0||1/e===1/t)||e!==e&&t!==t}var HC=typeof Object.is=="function"?Object.is:VC,GC=ys.useState,WC=ys.useEffect,KC=ys.useLayoutEffect,YC=ys.useDebugValue;function QC(e,t){var n=t(),r=GC({inst:{value:n,getSnapshot:t}}),o=r[0].inst,u=r[1];return KC(function(){o.value=n,o.getSnapshot=t,gh(o)&&u({inst:o})},[e,n,t]),WC(function(){return gh(o)&&u({inst:o}),e(function(){gh(o)&&u({inst:o})})},[e]),YC(n),n}function gh(e){var t=e.getSnapshot;e=e.value;try{var n=t();return!HC(e,n)}catch{return!0}}function XC(e,t){return t()}var ZC=typeof window>"u"||typeof window.document>"u"||typeof window.document.createElement>"u"?XC:QC;qw.useSyncExternalStore=ys.useSyncExternalStore!==void 0?ys.useSyncExternalStore:ZC,Rw.exports=qw;var JC=Rw.exports,Iw=$2(),Mw=typeof Iw.globalVersion<"u";function Bw(e){e.reaction=new tu("observer".concat(e.name),function(){var t;Mw||(e.stateVersion=Symbol()),(t=e.forceUpdate)===null||t===void 0||t.call(e)})}function eT(e,t){t===void 0&&(t="observed");var n=K.useRef(null);if(!n.current){var r={reaction:null,forceUpdate:null,stateVersion:Symbol(),name:t,subscribe:function(p){return Xu.unregister(r),r.forceUpdate=p,r.reaction||(Bw(r),r.forceUpdate()),function(){var g;r.forceUpdate=null,(g=r.reaction)===null||g===void 0||g.dispose(),r.reaction=null}},getSnapshot:function(){return Mw?Iw.stateVersion:r.stateVersion}};n.current=r}var o=n.current;o.reaction||(Bw(o),Xu.register(n,o,o)),K.useDebugValue(o.reaction,zC),JC.useSyncExternalStore(o.subscribe,o.getSnapshot);var u,c;if(o.reaction.track(function(){try{u=e()}catch(p){c=p}}),c)throw c;return u}var zw=typeof Symbol=="function"&&Symbol.for,$w=zw?Symbol.for("react.forward_ref"):typeof Ee.forwardRef=="function"&&Ee.forwardRef(function(e){return null}).$$typeof,Uw=zw?Symbol.for("react.memo"):typeof Ee.memo=="function"&&Ee.memo(function(e){return null}).$$typeof;function tT(e,t){var n;if(Uw&&e.$$typeof===Uw)throw new Error("[mobx-react-lite] You are trying to use `observer` on a function component wrapped in either another `observer` or `React.memo`. The observer already applies 'React.memo' for you.");var r=(n=t==null?void 0:t.forwardRef)!==null&&n!==void 0?n:!1,o=e,u=e.displayName||e.name;if($w&&e.$$typeof===$w&&(r=!0,o=e.render,typeof o!="function"))throw new Error("[mobx-react-lite] `render` property of ForwardRef was not a function");var c=function(p,g){return eT(function(){return o(p,g)},u)};return c.displayName=e.displayName,Object.defineProperty(c,"name",{value:e.name,writable:!0,configurable:!0}),e.contextTypes&&(c.contextTypes=e.contextTypes),r&&(c=Ee.forwardRef(c)),c=Ee.memo(c),rT(e,c),c}var nT={$$typeof:!0,render:!0,compare:!0,type:!0,displayName:!0};function rT(e,t){Object.keys(e).forEach(function(n){nT[n]||Object.defineProperty(t,n,Object.getOwnPropertyDescriptor(e,n))})}function iT(e,t){return Ee.useState(function(){return St(e(),t,{autoBind:!0})})[0]}globalThis&&globalThis.__read;var mh;BC(Nw.unstable_batchedUpdates),mh=Xu.finalizeAllImmediately;function jw(e,t){if(Fw(e,t))return!0;if(typeof e!="object"||e===null||typeof t!="object"||t===null)return!1;var n=Object.keys(e),r=Object.keys(t);if(n.length!==r.length)return!1;for(var o=0;o<n.length;o++)if(!Object.hasOwnProperty.call(t,n[o])||!Fw(e[n[o]],t[n[o]]))return!1;return!0}function Fw(e,t){return e===t?e!==0||1/e===1/t:e!==e&&t!==t}var Vw=Symbol("patchMixins"),Hw=Symbol("patchedDefinition");function oT(e,t){var n=e[Vw]=e[Vw]||{},r=n[t]=n[t]||{};return r.locks=r.locks||0,r.methods=r.methods||[],r}function Gw(e,t){for(var n=this,r=arguments.length,o=new Array(r>2?r-2:0),u=2;u<r;u++)o[u-2]=arguments[u];t.locks++;try{var c;return e!=null&&(c=e.apply(this,o)),c}finally{t.locks--,t.locks===0&&t.methods.forEach(function(p){p.apply(n,o)})}}function Ww(e,t){var n=function(){for(var o=arguments.length,u=new Array(o),c=0;c<o;c++)u[c]=arguments[c];Gw.call.apply(Gw,[this,e,t].concat(u))};return n}function Kw(e,t,n){var r=oT(e,t);r.methods.indexOf(n)<0&&r.methods.push(n);var o=Object.getOwnPropertyDescriptor(e,t);if(!(o&&o[Hw])){var u=e[t],c=Yw(e,t,o?o.enumerable:void 0,r,u);Object.defineProperty(e,t,c)}}function Yw(e,t,n,r,o){var u,c=Ww(o,r);return u={},u[Hw]=!0,u.get=function(){return c},u.set=function(g){if(this===e)c=Ww(g,r);else{var b=Yw(this,t,n,r,g);Object.defineProperty(this,t,b)}},u.configurable=!0,u.enumerable=n,u}var Qw=Symbol("ObserverAdministration"),Xw=Symbol("isMobXReactObserver");function Zu(e){var t;return(t=e[Qw])!=null?t:e[Qw]={reaction:null,mounted:!1,forceUpdate:null,name:vh(e.constructor),state:void 0,props:void 0,context:void 0,propsAtom:Ys("props"),stateAtom:Ys("state"),contextAtom:Ys("context"),isUpdating:!1}}function sT(e){var t=e.prototype;if(e[Xw]){var n=vh(e);console.warn("The provided component class ("+n+`)
-                has already been declared as an observer component.`)}else e[Xw]=!0;if(t.componentWillReact)throw new Error("The componentWillReact life-cycle event is no longer supported");if(e.__proto__!==Ee.PureComponent){if(!t.shouldComponentUpdate)t.shouldComponentUpdate=Zw;else if(t.shouldComponentUpdate!==Zw)throw new Error("It is not allowed to use shouldComponentUpdate in observer based components.")}Object.defineProperties(t,{props:lT,state:cT,context:dT});var r=t.render;if(typeof r!="function"){var o=vh(e);throw new Error("[mobx-react] class component ("+o+") is missing `render` method.\n`observer` requires `render` being a function defined on prototype.\n`render = () => {}` or `render = function() {}` is not supported.")}return t.render=function(){return Object.defineProperty(this,"render",{configurable:!1,writable:!1,value:uT.call(this,r)}),this.render()},Kw(t,"componentDidMount",function(){var u=this,c=Zu(this);c.mounted=!0,Xu.unregister(c),c.forceUpdate=function(){return u.forceUpdate()},c.reaction||c.forceUpdate()}),Kw(t,"componentWillUnmount",function(){var u,c=Zu(this);(u=c.reaction)==null||u.dispose(),c.reaction=null,c.forceUpdate=null,c.mounted=!1}),e}function vh(e){return e.displayName||e.name||"<component>"}function uT(e){var t=e.bind(this),n=Zu(this);function r(){n.reaction||(n.reaction=aT(n),n.mounted||Xu.register(this,n,this));var o=void 0,u=void 0;if(n.reaction.track(function(){try{u=Mv(!1,t)}catch(c){o=c}}),o)throw o;return u}return r}function aT(e){return new tu(e.name+".render()",function(){if(!e.isUpdating&&e.mounted)try{e.isUpdating=!0,e.forceUpdate==null||e.forceUpdate()}catch{var t;(t=e.reaction)==null||t.dispose(),e.reaction=null}finally{e.isUpdating=!1}})}function Zw(e,t){return this.state!==t?!0:!jw(this.props,e)}function _h(e){var t=e+"Atom";return{configurable:!0,enumerable:!0,get:function(){var r=Zu(this),o=tl(!0);return r[t].reportObserved(),Uo(o),r[e]},set:function(r){var o=Zu(this);!o.isUpdating&&!jw(o[e],r)?(o[e]=r,o.isUpdating=!0,o[t].reportChanged(),o.isUpdating=!1):o[e]=r}}}var lT=_h("props"),cT=_h("state"),dT=_h("context");function Jw(e){return e.isMobxInjector===!0&&console.warn("Mobx observer: You are trying to use `observer` on a component that already has `inject`. Please apply `observer` before applying `inject`"),Object.prototype.isPrototypeOf.call(Ee.Component,e)||Object.prototype.isPrototypeOf.call(Ee.PureComponent,e)?sT(e):tT(e)}if(K.version.split(".")[0],!Ee.Component)throw new Error("mobx-react requires React to be available");if(!St)throw new Error("mobx-react requires mobx to be available");function Sc(e){throw new Error('Could not dynamically require "'+e+'". Please configure the dynamicRequireTargets or/and ignoreDynamicRequires option of @rollup/plugin-commonjs appropriately for this require call to work.')}var e1={exports:{}};(function(e,t){(function(n){e.exports=n()})(function(){return function n(r,o,u){function c(b,O){if(!o[b]){if(!r[b]){var P=typeof Sc=="function"&&Sc;if(!O&&P)return P(b,!0);if(p)return p(b,!0);throw new Error("Cannot find module '"+b+"'")}O=o[b]={exports:{}},r[b][0].call(O.exports,function(q){var Z=r[b][1][q];return c(Z||q)},O,O.exports,n,r,o,u)}return o[b].exports}for(var p=typeof Sc=="function"&&Sc,g=0;g<u.length;g++)c(u[g]);return c}({1:[function(n,r,o){(function(u,c,p,g,b,O,P,q,Z){var M=n("crypto");function H(d,_){_=k(d,_);var v;return(v=_.algorithm!=="passthrough"?M.createHash(_.algorithm):new h).write===void 0&&(v.write=v.update,v.end=v.update),l(_,v).dispatch(d),v.update||v.end(""),v.digest?v.digest(_.encoding==="buffer"?void 0:_.encoding):(d=v.read(),_.encoding!=="buffer"?d.toString(_.encoding):d)}(o=r.exports=H).sha1=function(d){return H(d)},o.keys=function(d){return H(d,{excludeValues:!0,algorithm:"sha1",encoding:"hex"})},o.MD5=function(d){return H(d,{algorithm:"md5",encoding:"hex"})},o.keysMD5=function(d){return H(d,{algorithm:"md5",encoding:"hex",excludeValues:!0})};var z=M.getHashes?M.getHashes().slice():["sha1","md5"],L=(z.push("passthrough"),["buffer","hex","binary","base64"]);function k(d,_){var v={};if(v.algorithm=(_=_||{}).algorithm||"sha1",v.encoding=_.encoding||"hex",v.excludeValues=!!_.excludeValues,v.algorithm=v.algorithm.toLowerCase(),v.encoding=v.encoding.toLowerCase(),v.ignoreUnknown=_.ignoreUnknown===!0,v.respectType=_.respectType!==!1,v.respectFunctionNames=_.respectFunctionNames!==!1,v.respectFunctionProperties=_.respectFunctionProperties!==!1,v.unorderedArrays=_.unorderedArrays===!0,v.unorderedSets=_.unorderedSets!==!1,v.unorderedObjects=_.unorderedObjects!==!1,v.replacer=_.replacer||void 0,v.excludeKeys=_.excludeKeys||void 0,d===void 0)throw new Error("Object argument required.");for(var y=0;y<z.length;++y)z[y].toLowerCase()===v.algorithm.toLowerCase()&&(v.algorithm=z[y]);if(z.indexOf(v.algorithm)===-1)throw new Error('Algorithm "'+v.algorithm+'"  not supported. supported values: '+z.join(", "));if(L.indexOf(v.encoding)===-1&&v.algorithm!=="passthrough")throw new Error('Encoding "'+v.encoding+'"  not supported. supported values: '+L.join(", "));return v}function x(d){if(typeof d=="function")return/^function\s+\w*\s*\(\s*\)\s*{\s+\[native code\]\s+}$/i.exec(Function.prototype.toString.call(d))!=null}function l(d,_,v){v=v||[];function y(m){return _.update?_.update(m,"utf8"):_.write(m,"utf8")}return{dispatch:function(m){return this["_"+((m=d.replacer?d.replacer(m):m)===null?"null":typeof m)](m)},_object:function(m){var E,D=Object.prototype.toString.call(m),j=/\[object (.*)\]/i.exec(D);if(j=(j=j?j[1]:"unknown:["+D+"]").toLowerCase(),0<=(D=v.indexOf(m)))return this.dispatch("[CIRCULAR:"+D+"]");if(v.push(m),p!==void 0&&p.isBuffer&&p.isBuffer(m))return y("buffer:"),y(m);if(j==="object"||j==="function"||j==="asyncfunction")return D=Object.keys(m),d.unorderedObjects&&(D=D.sort()),d.respectType===!1||x(m)||D.splice(0,0,"prototype","__proto__","constructor"),d.excludeKeys&&(D=D.filter(function(V){return!d.excludeKeys(V)})),y("object:"+D.length+":"),E=this,D.forEach(function(V){E.dispatch(V),y(":"),d.excludeValues||E.dispatch(m[V]),y(",")});if(!this["_"+j]){if(d.ignoreUnknown)return y("["+j+"]");throw new Error('Unknown object type "'+j+'"')}this["_"+j](m)},_array:function(m,V){V=V!==void 0?V:d.unorderedArrays!==!1;var D=this;if(y("array:"+m.length+":"),!V||m.length<=1)return m.forEach(function(W){return D.dispatch(W)});var j=[],V=m.map(function(W){var T=new h,Q=v.slice();return l(d,T,Q).dispatch(W),j=j.concat(Q.slice(v.length)),T.read().toString()});return v=v.concat(j),V.sort(),this._array(V,!1)},_date:function(m){return y("date:"+m.toJSON())},_symbol:function(m){return y("symbol:"+m.toString())},_error:function(m){return y("error:"+m.toString())},_boolean:function(m){return y("bool:"+m.toString())},_string:function(m){y("string:"+m.length+":"),y(m.toString())},_function:function(m){y("fn:"),x(m)?this.dispatch("[native]"):this.dispatch(m.toString()),d.respectFunctionNames!==!1&&this.dispatch("function-name:"+String(m.name)),d.respectFunctionProperties&&this._object(m)},_number:function(m){return y("number:"+m.toString())},_xml:function(m){return y("xml:"+m.toString())},_null:function(){return y("Null")},_undefined:function(){return y("Undefined")},_regexp:function(m){return y("regex:"+m.toString())},_uint8array:function(m){return y("uint8array:"),this.dispatch(Array.prototype.slice.call(m))},_uint8clampedarray:function(m){return y("uint8clampedarray:"),this.dispatch(Array.prototype.slice.call(m))},_int8array:function(m){return y("int8array:"),this.dispatch(Array.prototype.slice.call(m))},_uint16array:function(m){return y("uint16array:"),this.dispatch(Array.prototype.slice.call(m))},_int16array:function(m){return y("int16array:"),this.dispatch(Array.prototype.slice.call(m))},_uint32array:function(m){return y("uint32array:"),this.dispatch(Array.prototype.slice.call(m))},_int32array:function(m){return y("int32array:"),this.dispatch(Array.prototype.slice.call(m))},_float32array:function(m){return y("float32array:"),this.dispatch(Array.prototype.slice.call(m))},_float64array:function(m){return y("float64array:"),this.dispatch(Array.prototype.slice.call(m))},_arraybuffer:function(m){return y("arraybuffer:"),this.dispatch(new Uint8Array(m))},_url:function(m){return y("url:"+m.toString())},_map:function(m){return y("map:"),m=Array.from(m),this._array(m,d.unorderedSets!==!1)},_set:function(m){return y("set:"),m=Array.from(m),this._array(m,d.unorderedSets!==!1)},_file:function(m){return y("file:"),this.dispatch([m.name,m.size,m.type,m.lastModfied])},_blob:function(){if(d.ignoreUnknown)return y("[blob]");throw Error(`Hashing Blob objects is currently not supported
-(see https://github.com/puleos/object-hash/issues/26)
-Use "options.replacer" or "options.ignoreUnknown"
-`)},_domwindow:function(){return y("domwindow")},_bigint:function(m){return y("bigint:"+m.toString())},_process:function(){return y("process")},_timer:function(){return y("timer")},_pipe:function(){return y("pipe")},_tcp:function(){return y("tcp")},_udp:function(){return y("udp")},_tty:function(){return y("tty")},_statwatcher:function(){return y("statwatcher")},_securecontext:function(){return y("securecontext")},_connection:function(){return y("connection")},_zlib:function(){return y("zlib")},_context:function(){return y("context")},_nodescript:function(){return y("nodescript")},_httpparser:function(){return y("httpparser")},_dataview:function(){return y("dataview")},_signal:function(){return y("signal")},_fsevent:function(){return y("fsevent")},_tlswrap:function(){return y("tlswrap")}}}function h(){return{buf:"",write:function(d){this.buf+=d},end:function(d){this.buf+=d},read:function(){return this.buf}}}o.writeToStream=function(d,_,v){return v===void 0&&(v=_,_={}),l(_=k(d,_),v).dispatch(d)}}).call(this,n("lYpoI2"),typeof self<"u"?self:typeof window<"u"?window:{},n("buffer").Buffer,arguments[3],arguments[4],arguments[5],arguments[6],"/fake_9a5aa49d.js","/")},{buffer:3,crypto:5,lYpoI2:11}],2:[function(n,r,o){(function(u,c,p,g,b,O,P,q,Z){(function(M){var H=typeof Uint8Array<"u"?Uint8Array:Array,z="+".charCodeAt(0),L="/".charCodeAt(0),k="0".charCodeAt(0),x="a".charCodeAt(0),l="A".charCodeAt(0),h="-".charCodeAt(0),d="_".charCodeAt(0);function _(v){return v=v.charCodeAt(0),v===z||v===h?62:v===L||v===d?63:v<k?-1:v<k+10?v-k+26+26:v<l+26?v-l:v<x+26?v-x+26:void 0}M.toByteArray=function(v){var y,m;if(0<v.length%4)throw new Error("Invalid string. Length must be a multiple of 4");var E=v.length,E=v.charAt(E-2)==="="?2:v.charAt(E-1)==="="?1:0,D=new H(3*v.length/4-E),j=0<E?v.length-4:v.length,V=0;function W(T){D[V++]=T}for(y=0;y<j;y+=4,0)W((16711680&(m=_(v.charAt(y))<<18|_(v.charAt(y+1))<<12|_(v.charAt(y+2))<<6|_(v.charAt(y+3))))>>16),W((65280&m)>>8),W(255&m);return E==2?W(255&(m=_(v.charAt(y))<<2|_(v.charAt(y+1))>>4)):E==1&&(W((m=_(v.charAt(y))<<10|_(v.charAt(y+1))<<4|_(v.charAt(y+2))>>2)>>8&255),W(255&m)),D},M.fromByteArray=function(v){var y,m,E,D,j=v.length%3,V="";function W(T){return"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/".charAt(T)}for(y=0,E=v.length-j;y<E;y+=3)m=(v[y]<<16)+(v[y+1]<<8)+v[y+2],V+=W((D=m)>>18&63)+W(D>>12&63)+W(D>>6&63)+W(63&D);switch(j){case 1:V=(V+=W((m=v[v.length-1])>>2))+W(m<<4&63)+"==";break;case 2:V=(V=(V+=W((m=(v[v.length-2]<<8)+v[v.length-1])>>10))+W(m>>4&63))+W(m<<2&63)+"="}return V}})(o===void 0?this.base64js={}:o)}).call(this,n("lYpoI2"),typeof self<"u"?self:typeof window<"u"?window:{},n("buffer").Buffer,arguments[3],arguments[4],arguments[5],arguments[6],"/node_modules/gulp-browserify/node_modules/base64-js/lib/b64.js","/node_modules/gulp-browserify/node_modules/base64-js/lib")},{buffer:3,lYpoI2:11}],3:[function(n,r,o){(function(u,c,z,g,b,O,P,q,Z){var M=n("base64-js"),H=n("ieee754");function z(A,R,$){if(!(this instanceof z))return new z(A,R,$);var se,ae,fe,I,Y=typeof A;if(R==="base64"&&Y=="string")for(A=(I=A).trim?I.trim():I.replace(/^\s+|\s+$/g,"");A.length%4!=0;)A+="=";if(Y=="number")se=G(A);else if(Y=="string")se=z.byteLength(A,R);else{if(Y!="object")throw new Error("First argument needs to be a number, array or string.");se=G(A.length)}if(z._useTypedArrays?ae=z._augment(new Uint8Array(se)):((ae=this).length=se,ae._isBuffer=!0),z._useTypedArrays&&typeof A.byteLength=="number")ae._set(A);else if(ee(I=A)||z.isBuffer(I)||I&&typeof I=="object"&&typeof I.length=="number")for(fe=0;fe<se;fe++)z.isBuffer(A)?ae[fe]=A.readUInt8(fe):ae[fe]=A[fe];else if(Y=="string")ae.write(A,0,R);else if(Y=="number"&&!z._useTypedArrays&&!$)for(fe=0;fe<se;fe++)ae[fe]=0;return ae}function L(A,R,$,se){return z._charsWritten=Xe(function(ae){for(var fe=[],I=0;I<ae.length;I++)fe.push(255&ae.charCodeAt(I));return fe}(R),A,$,se)}function k(A,R,$,se){return z._charsWritten=Xe(function(ae){for(var fe,I,Y=[],J=0;J<ae.length;J++)I=ae.charCodeAt(J),fe=I>>8,I=I%256,Y.push(I),Y.push(fe);return Y}(R),A,$,se)}function x(A,R,$){var se="";$=Math.min(A.length,$);for(var ae=R;ae<$;ae++)se+=String.fromCharCode(A[ae]);return se}function l(A,R,$,fe){fe||(ce(typeof $=="boolean","missing or invalid endian"),ce(R!=null,"missing offset"),ce(R+1<A.length,"Trying to read beyond buffer length"));var ae,fe=A.length;if(!(fe<=R))return $?(ae=A[R],R+1<fe&&(ae|=A[R+1]<<8)):(ae=A[R]<<8,R+1<fe&&(ae|=A[R+1])),ae}function h(A,R,$,fe){fe||(ce(typeof $=="boolean","missing or invalid endian"),ce(R!=null,"missing offset"),ce(R+3<A.length,"Trying to read beyond buffer length"));var ae,fe=A.length;if(!(fe<=R))return $?(R+2<fe&&(ae=A[R+2]<<16),R+1<fe&&(ae|=A[R+1]<<8),ae|=A[R],R+3<fe&&(ae+=A[R+3]<<24>>>0)):(R+1<fe&&(ae=A[R+1]<<16),R+2<fe&&(ae|=A[R+2]<<8),R+3<fe&&(ae|=A[R+3]),ae+=A[R]<<24>>>0),ae}function d(A,R,$,se){if(se||(ce(typeof $=="boolean","missing or invalid endian"),ce(R!=null,"missing offset"),ce(R+1<A.length,"Trying to read beyond buffer length")),!(A.length<=R))return se=l(A,R,$,!0),32768&se?-1*(65535-se+1):se}function _(A,R,$,se){if(se||(ce(typeof $=="boolean","missing or invalid endian"),ce(R!=null,"missing offset"),ce(R+3<A.length,"Trying to read beyond buffer length")),!(A.length<=R))return se=h(A,R,$,!0),2147483648&se?-1*(4294967295-se+1):se}function v(A,R,$,se){return se||(ce(typeof $=="boolean","missing or invalid endian"),ce(R+3<A.length,"Trying to read beyond buffer length")),H.read(A,R,$,23,4)}function y(A,R,$,se){return se||(ce(typeof $=="boolean","missing or invalid endian"),ce(R+7<A.length,"Trying to read beyond buffer length")),H.read(A,R,$,52,8)}function m(A,R,$,se,ae){if(ae||(ce(R!=null,"missing value"),ce(typeof se=="boolean","missing or invalid endian"),ce($!=null,"missing offset"),ce($+1<A.length,"trying to write beyond buffer length"),je(R,65535)),ae=A.length,!(ae<=$))for(var fe=0,I=Math.min(ae-$,2);fe<I;fe++)A[$+fe]=(R&255<<8*(se?fe:1-fe))>>>8*(se?fe:1-fe)}function E(A,R,$,se,ae){if(ae||(ce(R!=null,"missing value"),ce(typeof se=="boolean","missing or invalid endian"),ce($!=null,"missing offset"),ce($+3<A.length,"trying to write beyond buffer length"),je(R,4294967295)),ae=A.length,!(ae<=$))for(var fe=0,I=Math.min(ae-$,4);fe<I;fe++)A[$+fe]=R>>>8*(se?fe:3-fe)&255}function D(A,R,$,se,ae){ae||(ce(R!=null,"missing value"),ce(typeof se=="boolean","missing or invalid endian"),ce($!=null,"missing offset"),ce($+1<A.length,"Trying to write beyond buffer length"),Me(R,32767,-32768)),A.length<=$||m(A,0<=R?R:65535+R+1,$,se,ae)}function j(A,R,$,se,ae){ae||(ce(R!=null,"missing value"),ce(typeof se=="boolean","missing or invalid endian"),ce($!=null,"missing offset"),ce($+3<A.length,"Trying to write beyond buffer length"),Me(R,2147483647,-2147483648)),A.length<=$||E(A,0<=R?R:4294967295+R+1,$,se,ae)}function V(A,R,$,se,ae){ae||(ce(R!=null,"missing value"),ce(typeof se=="boolean","missing or invalid endian"),ce($!=null,"missing offset"),ce($+3<A.length,"Trying to write beyond buffer length"),Ze(R,34028234663852886e22,-34028234663852886e22)),A.length<=$||H.write(A,R,$,se,23,4)}function W(A,R,$,se,ae){ae||(ce(R!=null,"missing value"),ce(typeof se=="boolean","missing or invalid endian"),ce($!=null,"missing offset"),ce($+7<A.length,"Trying to write beyond buffer length"),Ze(R,17976931348623157e292,-17976931348623157e292)),A.length<=$||H.write(A,R,$,se,52,8)}o.Buffer=z,o.SlowBuffer=z,o.INSPECT_MAX_BYTES=50,z.poolSize=8192,z._useTypedArrays=function(){try{var A=new ArrayBuffer(0),R=new Uint8Array(A);return R.foo=function(){return 42},R.foo()===42&&typeof R.subarray=="function"}catch{return!1}}(),z.isEncoding=function(A){switch(String(A).toLowerCase()){case"hex":case"utf8":case"utf-8":case"ascii":case"binary":case"base64":case"raw":case"ucs2":case"ucs-2":case"utf16le":case"utf-16le":return!0;default:return!1}},z.isBuffer=function(A){return!(A==null||!A._isBuffer)},z.byteLength=function(A,R){var $;switch(A+="",R||"utf8"){case"hex":$=A.length/2;break;case"utf8":case"utf-8":$=le(A).length;break;case"ascii":case"binary":case"raw":$=A.length;break;case"base64":$=Oe(A).length;break;case"ucs2":case"ucs-2":case"utf16le":case"utf-16le":$=2*A.length;break;default:throw new Error("Unknown encoding")}return $},z.concat=function(A,R){if(ce(ee(A),`Usage: Buffer.concat(list, [totalLength])
-list should be an Array.`),A.length===0)return new z(0);if(A.length===1)return A[0];if(typeof R!="number")for(ae=R=0;ae<A.length;ae++)R+=A[ae].length;for(var $=new z(R),se=0,ae=0;ae<A.length;ae++){var fe=A[ae];fe.copy($,se),se+=fe.length}return $},z.prototype.write=function(A,R,$,se){isFinite(R)?isFinite($)||(se=$,$=void 0):(J=se,se=R,R=$,$=J),R=Number(R)||0;var ae,fe,I,Y,J=this.length-R;switch((!$||J<($=Number($)))&&($=J),se=String(se||"utf8").toLowerCase()){case"hex":ae=function(ve,pe,oe,ge){oe=Number(oe)||0;var Te=ve.length-oe;(!ge||Te<(ge=Number(ge)))&&(ge=Te),ce((Te=pe.length)%2==0,"Invalid hex string"),Te/2<ge&&(ge=Te/2);for(var Ve=0;Ve<ge;Ve++){var lr=parseInt(pe.substr(2*Ve,2),16);ce(!isNaN(lr),"Invalid hex string"),ve[oe+Ve]=lr}return z._charsWritten=2*Ve,Ve}(this,A,R,$);break;case"utf8":case"utf-8":fe=this,I=R,Y=$,ae=z._charsWritten=Xe(le(A),fe,I,Y);break;case"ascii":case"binary":ae=L(this,A,R,$);break;case"base64":fe=this,I=R,Y=$,ae=z._charsWritten=Xe(Oe(A),fe,I,Y);break;case"ucs2":case"ucs-2":case"utf16le":case"utf-16le":ae=k(this,A,R,$);break;default:throw new Error("Unknown encoding")}return ae},z.prototype.toString=function(A,R,$){var se,ae,fe,I,Y=this;if(A=String(A||"utf8").toLowerCase(),R=Number(R)||0,($=$!==void 0?Number($):Y.length)===R)return"";switch(A){case"hex":se=function(J,ve,pe){var oe=J.length;(!ve||ve<0)&&(ve=0),(!pe||pe<0||oe<pe)&&(pe=oe);for(var ge="",Te=ve;Te<pe;Te++)ge+=ie(J[Te]);return ge}(Y,R,$);break;case"utf8":case"utf-8":se=function(J,ve,pe){var oe="",ge="";pe=Math.min(J.length,pe);for(var Te=ve;Te<pe;Te++)J[Te]<=127?(oe+=tt(ge)+String.fromCharCode(J[Te]),ge=""):ge+="%"+J[Te].toString(16);return oe+tt(ge)}(Y,R,$);break;case"ascii":case"binary":se=x(Y,R,$);break;case"base64":ae=Y,I=$,se=(fe=R)===0&&I===ae.length?M.fromByteArray(ae):M.fromByteArray(ae.slice(fe,I));break;case"ucs2":case"ucs-2":case"utf16le":case"utf-16le":se=function(J,ve,pe){for(var oe=J.slice(ve,pe),ge="",Te=0;Te<oe.length;Te+=2)ge+=String.fromCharCode(oe[Te]+256*oe[Te+1]);return ge}(Y,R,$);break;default:throw new Error("Unknown encoding")}return se},z.prototype.toJSON=function(){return{type:"Buffer",data:Array.prototype.slice.call(this._arr||this,0)}},z.prototype.copy=function(A,R,$,se){if(R=R||0,(se=se||se===0?se:this.length)!==($=$||0)&&A.length!==0&&this.length!==0){ce($<=se,"sourceEnd < sourceStart"),ce(0<=R&&R<A.length,"targetStart out of bounds"),ce(0<=$&&$<this.length,"sourceStart out of bounds"),ce(0<=se&&se<=this.length,"sourceEnd out of bounds"),se>this.length&&(se=this.length);var ae=(se=A.length-R<se-$?A.length-R+$:se)-$;if(ae<100||!z._useTypedArrays)for(var fe=0;fe<ae;fe++)A[fe+R]=this[fe+$];else A._set(this.subarray($,$+ae),R)}},z.prototype.slice=function(A,R){var $=this.length;if(A=Q(A,$,0),R=Q(R,$,$),z._useTypedArrays)return z._augment(this.subarray(A,R));for(var se=R-A,ae=new z(se,void 0,!0),fe=0;fe<se;fe++)ae[fe]=this[fe+A];return ae},z.prototype.get=function(A){return console.log(".get() is deprecated. Access using array indexes instead."),this.readUInt8(A)},z.prototype.set=function(A,R){return console.log(".set() is deprecated. Access using array indexes instead."),this.writeUInt8(A,R)},z.prototype.readUInt8=function(A,R){if(R||(ce(A!=null,"missing offset"),ce(A<this.length,"Trying to read beyond buffer length")),!(A>=this.length))return this[A]},z.prototype.readUInt16LE=function(A,R){return l(this,A,!0,R)},z.prototype.readUInt16BE=function(A,R){return l(this,A,!1,R)},z.prototype.readUInt32LE=function(A,R){return h(this,A,!0,R)},z.prototype.readUInt32BE=function(A,R){return h(this,A,!1,R)},z.prototype.readInt8=function(A,R){if(R||(ce(A!=null,"missing offset"),ce(A<this.length,"Trying to read beyond buffer length")),!(A>=this.length))return 128&this[A]?-1*(255-this[A]+1):this[A]},z.prototype.readInt16LE=function(A,R){return d(this,A,!0,R)},z.prototype.readInt16BE=function(A,R){return d(this,A,!1,R)},z.prototype.readInt32LE=function(A,R){return _(this,A,!0,R)},z.prototype.readInt32BE=function(A,R){return _(this,A,!1,R)},z.prototype.readFloatLE=function(A,R){return v(this,A,!0,R)},z.prototype.readFloatBE=function(A,R){return v(this,A,!1,R)},z.prototype.readDoubleLE=function(A,R){return y(this,A,!0,R)},z.prototype.readDoubleBE=function(A,R){return y(this,A,!1,R)},z.prototype.writeUInt8=function(A,R,$){$||(ce(A!=null,"missing value"),ce(R!=null,"missing offset"),ce(R<this.length,"trying to write beyond buffer length"),je(A,255)),R>=this.length||(this[R]=A)},z.prototype.writeUInt16LE=function(A,R,$){m(this,A,R,!0,$)},z.prototype.writeUInt16BE=function(A,R,$){m(this,A,R,!1,$)},z.prototype.writeUInt32LE=function(A,R,$){E(this,A,R,!0,$)},z.prototype.writeUInt32BE=function(A,R,$){E(this,A,R,!1,$)},z.prototype.writeInt8=function(A,R,$){$||(ce(A!=null,"missing value"),ce(R!=null,"missing offset"),ce(R<this.length,"Trying to write beyond buffer length"),Me(A,127,-128)),R>=this.length||(0<=A?this.writeUInt8(A,R,$):this.writeUInt8(255+A+1,R,$))},z.prototype.writeInt16LE=function(A,R,$){D(this,A,R,!0,$)},z.prototype.writeInt16BE=function(A,R,$){D(this,A,R,!1,$)},z.prototype.writeInt32LE=function(A,R,$){j(this,A,R,!0,$)},z.prototype.writeInt32BE=function(A,R,$){j(this,A,R,!1,$)},z.prototype.writeFloatLE=function(A,R,$){V(this,A,R,!0,$)},z.prototype.writeFloatBE=function(A,R,$){V(this,A,R,!1,$)},z.prototype.writeDoubleLE=function(A,R,$){W(this,A,R,!0,$)},z.prototype.writeDoubleBE=function(A,R,$){W(this,A,R,!1,$)},z.prototype.fill=function(A,R,$){if(R=R||0,$=$||this.length,ce(typeof(A=typeof(A=A||0)=="string"?A.charCodeAt(0):A)=="number"&&!isNaN(A),"value is not a number"),ce(R<=$,"end < start"),$!==R&&this.length!==0){ce(0<=R&&R<this.length,"start out of bounds"),ce(0<=$&&$<=this.length,"end out of bounds");for(var se=R;se<$;se++)this[se]=A}},z.prototype.inspect=function(){for(var A=[],R=this.length,$=0;$<R;$++)if(A[$]=ie(this[$]),$===o.INSPECT_MAX_BYTES){A[$+1]="...";break}return"<Buffer "+A.join(" ")+">"},z.prototype.toArrayBuffer=function(){if(typeof Uint8Array>"u")throw new Error("Buffer.toArrayBuffer not supported in this browser");if(z._useTypedArrays)return new z(this).buffer;for(var A=new Uint8Array(this.length),R=0,$=A.length;R<$;R+=1)A[R]=this[R];return A.buffer};var T=z.prototype;function Q(A,R,$){return typeof A!="number"?$:R<=(A=~~A)?R:0<=A||0<=(A+=R)?A:0}function G(A){return(A=~~Math.ceil(+A))<0?0:A}function ee(A){return(Array.isArray||function(R){return Object.prototype.toString.call(R)==="[object Array]"})(A)}function ie(A){return A<16?"0"+A.toString(16):A.toString(16)}function le(A){for(var R=[],$=0;$<A.length;$++){var se=A.charCodeAt($);if(se<=127)R.push(A.charCodeAt($));else for(var ae=$,fe=(55296<=se&&se<=57343&&$++,encodeURIComponent(A.slice(ae,$+1)).substr(1).split("%")),I=0;I<fe.length;I++)R.push(parseInt(fe[I],16))}return R}function Oe(A){return M.toByteArray(A)}function Xe(A,R,$,se){for(var ae=0;ae<se&&!(ae+$>=R.length||ae>=A.length);ae++)R[ae+$]=A[ae];return ae}function tt(A){try{return decodeURIComponent(A)}catch{return String.fromCharCode(65533)}}function je(A,R){ce(typeof A=="number","cannot write a non-number as a number"),ce(0<=A,"specified a negative value for writing an unsigned value"),ce(A<=R,"value is larger than maximum value for type"),ce(Math.floor(A)===A,"value has a fractional component")}function Me(A,R,$){ce(typeof A=="number","cannot write a non-number as a number"),ce(A<=R,"value larger than maximum allowed value"),ce($<=A,"value smaller than minimum allowed value"),ce(Math.floor(A)===A,"value has a fractional component")}function Ze(A,R,$){ce(typeof A=="number","cannot write a non-number as a number"),ce(A<=R,"value larger than maximum allowed value"),ce($<=A,"value smaller than minimum allowed value")}function ce(A,R){if(!A)throw new Error(R||"Failed assertion")}z._augment=function(A){return A._isBuffer=!0,A._get=A.get,A._set=A.set,A.get=T.get,A.set=T.set,A.write=T.write,A.toString=T.toString,A.toLocaleString=T.toString,A.toJSON=T.toJSON,A.copy=T.copy,A.slice=T.slice,A.readUInt8=T.readUInt8,A.readUInt16LE=T.readUInt16LE,A.readUInt16BE=T.readUInt16BE,A.readUInt32LE=T.readUInt32LE,A.readUInt32BE=T.readUInt32BE,A.readInt8=T.readInt8,A.readInt16LE=T.readInt16LE,A.readInt16BE=T.readInt16BE,A.readInt32LE=T.readInt32LE,A.readInt32BE=T.readInt32BE,A.readFloatLE=T.readFloatLE,A.readFloatBE=T.readFloatBE,A.readDoubleLE=T.readDoubleLE,A.readDoubleBE=T.readDoubleBE,A.writeUInt8=T.writeUInt8,A.writeUInt16LE=T.writeUInt16LE,A.writeUInt16BE=T.writeUInt16BE,A.writeUInt32LE=T.writeUInt32LE,A.writeUInt32BE=T.writeUInt32BE,A.writeInt8=T.writeInt8,A.writeInt16LE=T.writeInt16LE,A.writeInt16BE=T.writeInt16BE,A.writeInt32LE=T.writeInt32LE,A.writeInt32BE=T.writeInt32BE,A.writeFloatLE=T.writeFloatLE,A.writeFloatBE=T.writeFloatBE,A.writeDoubleLE=T.writeDoubleLE,A.writeDoubleBE=T.writeDoubleBE,A.fill=T.fill,A.inspect=T.inspect,A.toArrayBuffer=T.toArrayBuffer,A}}).call(this,n("lYpoI2"),typeof self<"u"?self:typeof window<"u"?window:{},n("buffer").Buffer,arguments[3],arguments[4],arguments[5],arguments[6],"/node_modules/gulp-browserify/node_modules/buffer/index.js","/node_modules/gulp-browserify/node_modules/buffer")},{"base64-js":2,buffer:3,ieee754:10,lYpoI2:11}],4:[function(n,r,o){(function(u,c,M,g,b,O,P,q,Z){var M=n("buffer").Buffer,H=4,z=new M(H);z.fill(0),r.exports={hash:function(L,k,x,l){for(var h=k(function(m,E){m.length%H!=0&&(D=m.length+(H-m.length%H),m=M.concat([m,z],D));for(var D,j=[],V=E?m.readInt32BE:m.readInt32LE,W=0;W<m.length;W+=H)j.push(V.call(m,W));return j}(L=M.isBuffer(L)?L:new M(L),l),8*L.length),k=l,d=new M(x),_=k?d.writeInt32BE:d.writeInt32LE,v=0;v<h.length;v++)_.call(d,h[v],4*v,!0);return d}}}).call(this,n("lYpoI2"),typeof self<"u"?self:typeof window<"u"?window:{},n("buffer").Buffer,arguments[3],arguments[4],arguments[5],arguments[6],"/node_modules/gulp-browserify/node_modules/crypto-browserify/helpers.js","/node_modules/gulp-browserify/node_modules/crypto-browserify")},{buffer:3,lYpoI2:11}],5:[function(n,r,o){(function(u,c,M,g,b,O,P,q,Z){var M=n("buffer").Buffer,H=n("./sha"),z=n("./sha256"),L=n("./rng"),k={sha1:H,sha256:z,md5:n("./md5")},x=64,l=new M(x);function h(m,E){var D=k[m=m||"sha1"],j=[];return D||d("algorithm:",m,"is not yet supported"),{update:function(V){return M.isBuffer(V)||(V=new M(V)),j.push(V),V.length,this},digest:function(V){var W=M.concat(j),W=E?function(T,Q,G){M.isBuffer(Q)||(Q=new M(Q)),M.isBuffer(G)||(G=new M(G)),Q.length>x?Q=T(Q):Q.length<x&&(Q=M.concat([Q,l],x));for(var ee=new M(x),ie=new M(x),le=0;le<x;le++)ee[le]=54^Q[le],ie[le]=92^Q[le];return G=T(M.concat([ee,G])),T(M.concat([ie,G]))}(D,E,W):D(W);return j=null,V?W.toString(V):W}}}function d(){var m=[].slice.call(arguments).join(" ");throw new Error([m,"we accept pull requests","http://github.com/dominictarr/crypto-browserify"].join(`
-`))}l.fill(0),o.createHash=function(m){return h(m)},o.createHmac=h,o.randomBytes=function(m,E){if(!E||!E.call)return new M(L(m));try{E.call(this,void 0,new M(L(m)))}catch(D){E(D)}};var _,v=["createCredentials","createCipher","createCipheriv","createDecipher","createDecipheriv","createSign","createVerify","createDiffieHellman","pbkdf2"],y=function(m){o[m]=function(){d("sorry,",m,"is not implemented yet")}};for(_ in v)y(v[_])}).call(this,n("lYpoI2"),typeof self<"u"?self:typeof window<"u"?window:{},n("buffer").Buffer,arguments[3],arguments[4],arguments[5],arguments[6],"/node_modules/gulp-browserify/node_modules/crypto-browserify/index.js","/node_modules/gulp-browserify/node_modules/crypto-browserify")},{"./md5":6,"./rng":7,"./sha":8,"./sha256":9,buffer:3,lYpoI2:11}],6:[function(n,r,o){(function(u,c,p,g,b,O,P,q,Z){var M=n("./helpers");function H(d,_){d[_>>5]|=128<<_%32,d[14+(_+64>>>9<<4)]=_;for(var v=1732584193,y=-271733879,m=-1732584194,E=271733878,D=0;D<d.length;D+=16){var j=v,V=y,W=m,T=E,v=L(v,y,m,E,d[D+0],7,-680876936),E=L(E,v,y,m,d[D+1],12,-389564586),m=L(m,E,v,y,d[D+2],17,606105819),y=L(y,m,E,v,d[D+3],22,-1044525330);v=L(v,y,m,E,d[D+4],7,-176418897),E=L(E,v,y,m,d[D+5],12,1200080426),m=L(m,E,v,y,d[D+6],17,-1473231341),y=L(y,m,E,v,d[D+7],22,-45705983),v=L(v,y,m,E,d[D+8],7,1770035416),E=L(E,v,y,m,d[D+9],12,-1958414417),m=L(m,E,v,y,d[D+10],17,-42063),y=L(y,m,E,v,d[D+11],22,-1990404162),v=L(v,y,m,E,d[D+12],7,1804603682),E=L(E,v,y,m,d[D+13],12,-40341101),m=L(m,E,v,y,d[D+14],17,-1502002290),v=k(v,y=L(y,m,E,v,d[D+15],22,1236535329),m,E,d[D+1],5,-165796510),E=k(E,v,y,m,d[D+6],9,-1069501632),m=k(m,E,v,y,d[D+11],14,643717713),y=k(y,m,E,v,d[D+0],20,-373897302),v=k(v,y,m,E,d[D+5],5,-701558691),E=k(E,v,y,m,d[D+10],9,38016083),m=k(m,E,v,y,d[D+15],14,-660478335),y=k(y,m,E,v,d[D+4],20,-405537848),v=k(v,y,m,E,d[D+9],5,568446438),E=k(E,v,y,m,d[D+14],9,-1019803690),m=k(m,E,v,y,d[D+3],14,-187363961),y=k(y,m,E,v,d[D+8],20,1163531501),v=k(v,y,m,E,d[D+13],5,-1444681467),E=k(E,v,y,m,d[D+2],9,-51403784),m=k(m,E,v,y,d[D+7],14,1735328473),v=x(v,y=k(y,m,E,v,d[D+12],20,-1926607734),m,E,d[D+5],4,-378558),E=x(E,v,y,m,d[D+8],11,-2022574463),m=x(m,E,v,y,d[D+11],16,1839030562),y=x(y,m,E,v,d[D+14],23,-35309556),v=x(v,y,m,E,d[D+1],4,-1530992060),E=x(E,v,y,m,d[D+4],11,1272893353),m=x(m,E,v,y,d[D+7],16,-155497632),y=x(y,m,E,v,d[D+10],23,-1094730640),v=x(v,y,m,E,d[D+13],4,681279174),E=x(E,v,y,m,d[D+0],11,-358537222),m=x(m,E,v,y,d[D+3],16,-722521979),y=x(y,m,E,v,d[D+6],23,76029189),v=x(v,y,m,E,d[D+9],4,-640364487),E=x(E,v,y,m,d[D+12],11,-421815835),m=x(m,E,v,y,d[D+15],16,530742520),v=l(v,y=x(y,m,E,v,d[D+2],23,-995338651),m,E,d[D+0],6,-198630844),E=l(E,v,y,m,d[D+7],10,1126891415),m=l(m,E,v,y,d[D+14],15,-1416354905),y=l(y,m,E,v,d[D+5],21,-57434055),v=l(v,y,m,E,d[D+12],6,1700485571),E=l(E,v,y,m,d[D+3],10,-1894986606),m=l(m,E,v,y,d[D+10],15,-1051523),y=l(y,m,E,v,d[D+1],21,-2054922799),v=l(v,y,m,E,d[D+8],6,1873313359),E=l(E,v,y,m,d[D+15],10,-30611744),m=l(m,E,v,y,d[D+6],15,-1560198380),y=l(y,m,E,v,d[D+13],21,1309151649),v=l(v,y,m,E,d[D+4],6,-145523070),E=l(E,v,y,m,d[D+11],10,-1120210379),m=l(m,E,v,y,d[D+2],15,718787259),y=l(y,m,E,v,d[D+9],21,-343485551),v=h(v,j),y=h(y,V),m=h(m,W),E=h(E,T)}return Array(v,y,m,E)}function z(d,_,v,y,m,E){return h((_=h(h(_,d),h(y,E)))<<m|_>>>32-m,v)}function L(d,_,v,y,m,E,D){return z(_&v|~_&y,d,_,m,E,D)}function k(d,_,v,y,m,E,D){return z(_&y|v&~y,d,_,m,E,D)}function x(d,_,v,y,m,E,D){return z(_^v^y,d,_,m,E,D)}function l(d,_,v,y,m,E,D){return z(v^(_|~y),d,_,m,E,D)}function h(d,_){var v=(65535&d)+(65535&_);return(d>>16)+(_>>16)+(v>>16)<<16|65535&v}r.exports=function(d){return M.hash(d,H,16)}}).call(this,n("lYpoI2"),typeof self<"u"?self:typeof window<"u"?window:{},n("buffer").Buffer,arguments[3],arguments[4],arguments[5],arguments[6],"/node_modules/gulp-browserify/node_modules/crypto-browserify/md5.js","/node_modules/gulp-browserify/node_modules/crypto-browserify")},{"./helpers":4,buffer:3,lYpoI2:11}],7:[function(n,r,o){(function(u,c,p,g,b,O,P,q,Z){r.exports=function(M){for(var H,z=new Array(M),L=0;L<M;L++)!(3&L)&&(H=4294967296*Math.random()),z[L]=H>>>((3&L)<<3)&255;return z}}).call(this,n("lYpoI2"),typeof self<"u"?self:typeof window<"u"?window:{},n("buffer").Buffer,arguments[3],arguments[4],arguments[5],arguments[6],"/node_modules/gulp-browserify/node_modules/crypto-browserify/rng.js","/node_modules/gulp-browserify/node_modules/crypto-browserify")},{buffer:3,lYpoI2:11}],8:[function(n,r,o){(function(u,c,p,g,b,O,P,q,Z){var M=n("./helpers");function H(k,x){k[x>>5]|=128<<24-x%32,k[15+(x+64>>9<<4)]=x;for(var l,h,d,_=Array(80),v=1732584193,y=-271733879,m=-1732584194,E=271733878,D=-1009589776,j=0;j<k.length;j+=16){for(var V=v,W=y,T=m,Q=E,G=D,ee=0;ee<80;ee++){_[ee]=ee<16?k[j+ee]:L(_[ee-3]^_[ee-8]^_[ee-14]^_[ee-16],1);var ie=z(z(L(v,5),(ie=y,h=m,d=E,(l=ee)<20?ie&h|~ie&d:!(l<40)&&l<60?ie&h|ie&d|h&d:ie^h^d)),z(z(D,_[ee]),(l=ee)<20?1518500249:l<40?1859775393:l<60?-1894007588:-899497514)),D=E,E=m,m=L(y,30),y=v,v=ie}v=z(v,V),y=z(y,W),m=z(m,T),E=z(E,Q),D=z(D,G)}return Array(v,y,m,E,D)}function z(k,x){var l=(65535&k)+(65535&x);return(k>>16)+(x>>16)+(l>>16)<<16|65535&l}function L(k,x){return k<<x|k>>>32-x}r.exports=function(k){return M.hash(k,H,20,!0)}}).call(this,n("lYpoI2"),typeof self<"u"?self:typeof window<"u"?window:{},n("buffer").Buffer,arguments[3],arguments[4],arguments[5],arguments[6],"/node_modules/gulp-browserify/node_modules/crypto-browserify/sha.js","/node_modules/gulp-browserify/node_modules/crypto-browserify")},{"./helpers":4,buffer:3,lYpoI2:11}],9:[function(n,r,o){(function(u,c,p,g,b,O,P,q,Z){function M(x,l){var h=(65535&x)+(65535&l);return(x>>16)+(l>>16)+(h>>16)<<16|65535&h}function H(x,l){var h,d=new Array(1116352408,1899447441,3049323471,3921009573,961987163,1508970993,2453635748,2870763221,3624381080,310598401,607225278,1426881987,1925078388,2162078206,2614888103,3248222580,3835390401,4022224774,264347078,604807628,770255983,1249150122,1555081692,1996064986,2554220882,2821834349,2952996808,3210313671,3336571891,3584528711,113926993,338241895,666307205,773529912,1294757372,1396182291,1695183700,1986661051,2177026350,2456956037,2730485921,2820302411,3259730800,3345764771,3516065817,3600352804,4094571909,275423344,430227734,506948616,659060556,883997877,958139571,1322822218,1537002063,1747873779,1955562222,2024104815,2227730452,2361852424,2428436474,2756734187,3204031479,3329325298),_=new Array(1779033703,3144134277,1013904242,2773480762,1359893119,2600822924,528734635,1541459225),v=new Array(64);x[l>>5]|=128<<24-l%32,x[15+(l+64>>9<<4)]=l;for(var y,m,E=0;E<x.length;E+=16){for(var D=_[0],j=_[1],V=_[2],W=_[3],T=_[4],Q=_[5],G=_[6],ee=_[7],ie=0;ie<64;ie++)v[ie]=ie<16?x[ie+E]:M(M(M((m=v[ie-2],L(m,17)^L(m,19)^k(m,10)),v[ie-7]),(m=v[ie-15],L(m,7)^L(m,18)^k(m,3))),v[ie-16]),h=M(M(M(M(ee,L(m=T,6)^L(m,11)^L(m,25)),T&Q^~T&G),d[ie]),v[ie]),y=M(L(y=D,2)^L(y,13)^L(y,22),D&j^D&V^j&V),ee=G,G=Q,Q=T,T=M(W,h),W=V,V=j,j=D,D=M(h,y);_[0]=M(D,_[0]),_[1]=M(j,_[1]),_[2]=M(V,_[2]),_[3]=M(W,_[3]),_[4]=M(T,_[4]),_[5]=M(Q,_[5]),_[6]=M(G,_[6]),_[7]=M(ee,_[7])}return _}var z=n("./helpers"),L=function(x,l){return x>>>l|x<<32-l},k=function(x,l){return x>>>l};r.exports=function(x){return z.hash(x,H,32,!0)}}).call(this,n("lYpoI2"),typeof self<"u"?self:typeof window<"u"?window:{},n("buffer").Buffer,arguments[3],arguments[4],arguments[5],arguments[6],"/node_modules/gulp-browserify/node_modules/crypto-browserify/sha256.js","/node_modules/gulp-browserify/node_modules/crypto-browserify")},{"./helpers":4,buffer:3,lYpoI2:11}],10:[function(n,r,o){(function(u,c,p,g,b,O,P,q,Z){o.read=function(M,H,z,L,E){var x,l,h=8*E-L-1,d=(1<<h)-1,_=d>>1,v=-7,y=z?E-1:0,m=z?-1:1,E=M[H+y];for(y+=m,x=E&(1<<-v)-1,E>>=-v,v+=h;0<v;x=256*x+M[H+y],y+=m,v-=8);for(l=x&(1<<-v)-1,x>>=-v,v+=L;0<v;l=256*l+M[H+y],y+=m,v-=8);if(x===0)x=1-_;else{if(x===d)return l?NaN:1/0*(E?-1:1);l+=Math.pow(2,L),x-=_}return(E?-1:1)*l*Math.pow(2,x-L)},o.write=function(M,H,z,L,k,D){var l,h,d=8*D-k-1,_=(1<<d)-1,v=_>>1,y=k===23?Math.pow(2,-24)-Math.pow(2,-77):0,m=L?0:D-1,E=L?1:-1,D=H<0||H===0&&1/H<0?1:0;for(H=Math.abs(H),isNaN(H)||H===1/0?(h=isNaN(H)?1:0,l=_):(l=Math.floor(Math.log(H)/Math.LN2),H*(L=Math.pow(2,-l))<1&&(l--,L*=2),2<=(H+=1<=l+v?y/L:y*Math.pow(2,1-v))*L&&(l++,L/=2),_<=l+v?(h=0,l=_):1<=l+v?(h=(H*L-1)*Math.pow(2,k),l+=v):(h=H*Math.pow(2,v-1)*Math.pow(2,k),l=0));8<=k;M[z+m]=255&h,m+=E,h/=256,k-=8);for(l=l<<k|h,d+=k;0<d;M[z+m]=255&l,m+=E,l/=256,d-=8);M[z+m-E]|=128*D}}).call(this,n("lYpoI2"),typeof self<"u"?self:typeof window<"u"?window:{},n("buffer").Buffer,arguments[3],arguments[4],arguments[5],arguments[6],"/node_modules/gulp-browserify/node_modules/ieee754/index.js","/node_modules/gulp-browserify/node_modules/ieee754")},{buffer:3,lYpoI2:11}],11:[function(n,r,o){(function(u,c,p,g,b,O,P,q,Z){var M,H,z;function L(){}(u=r.exports={}).nextTick=(H=typeof window<"u"&&window.setImmediate,z=typeof window<"u"&&window.postMessage&&window.addEventListener,H?function(k){return window.setImmediate(k)}:z?(M=[],window.addEventListener("message",function(k){var x=k.source;x!==window&&x!==null||k.data!=="process-tick"||(k.stopPropagation(),0<M.length&&M.shift()())},!0),function(k){M.push(k),window.postMessage("process-tick","*")}):function(k){setTimeout(k,0)}),u.title="browser",u.browser=!0,u.env={},u.argv=[],u.on=L,u.addListener=L,u.once=L,u.off=L,u.removeListener=L,u.removeAllListeners=L,u.emit=L,u.binding=function(k){throw new Error("process.binding is not supported")},u.cwd=function(){return"/"},u.chdir=function(k){throw new Error("process.chdir is not supported")}}).call(this,n("lYpoI2"),typeof self<"u"?self:typeof window<"u"?window:{},n("buffer").Buffer,arguments[3],arguments[4],arguments[5],arguments[6],"/node_modules/gulp-browserify/node_modules/process/browser.js","/node_modules/gulp-browserify/node_modules/process")},{buffer:3,lYpoI2:11}]},{},[1])(1)})})(e1);var fT=e1.exports;const pT=Dn(fT);class hT{constructor(t,n){dd(this,"quizName");dd(this,"quizHash");dd(this,"storageKey",()=>`mdbook-quiz:${this.quizName}`);this.quizName=t,this.quizHash=n}save(t,n,r,o){let u={answers:t,confirmedDone:n,attempt:r,quizHash:this.quizHash,wrongAnswers:o};localStorage.setItem(this.storageKey(),JSON.stringify(u))}load(){let t=localStorage.getItem(this.storageKey());if(t){let n=JSON.parse(t);if(n.quizHash===this.quizHash)return n}}}let gT=({wrapperRef:e})=>{let[t,n]=Ee.useState(!1);return Ee.useEffect(()=>{t&&e.current.scrollTo({top:e.current.offsetHeight,behavior:"smooth"})},[t]),K.createElement("div",{className:"exit-explanation"},K.createElement("div",{className:"trigger",onClick:()=>n(!t)},"Why is this quiz fullscreen?"),K.createElement("div",{style:{display:t?"block":"none"}},"We want to know how much you are learning that can be recalled without assistance. Please complete the quiz without re-reading the text, e.g. by opening it in another tab."))},mT=({quiz:e,answerStorage:t,cacheAnswers:n,autoStart:r})=>{let o=t.load(),u=o&&o.attempt>0&&!o.confirmedDone&&!o.wrongAnswers;return n&&o&&!u?{started:!0,index:e.questions.length,answers:o.answers,confirmedDone:o.confirmedDone||!1,attempt:o.attempt||0,wrongAnswers:o.wrongAnswers||(o.attempt>0?ji.range(e.questions.length):void 0)}:{started:r||!1,index:0,attempt:0,confirmedDone:!1,answers:[]}},vT=Jw(({state:e,ended:t})=>{let{quiz:n,initialText:r}=Ee.useContext(go);return K.createElement("header",null,K.createElement("h3",null,r||"Quiz"),K.createElement("div",{className:"counter"},e.started?!t&&K.createElement(K.Fragment,null,"Question"," ",(e.attempt===0?e.index:e.wrongAnswers.indexOf(e.index))+1," ","/"," ",e.attempt===0?n.questions.length:e.wrongAnswers.length):K.createElement(K.Fragment,null,n.questions.length," question",n.questions.length>1&&"s")))}),_T=({state:e,nCorrect:t,onRetry:n,onGiveUp:r})=>{let{quiz:o,name:u}=Ee.useContext(go),c=!e.confirmedDone&&K.createElement("p",{style:{marginBottom:"1em"}},"You can either"," ",K.createElement("button",{type:"button",onClick:n},"retry the quiz")," ","or"," ",K.createElement("button",{type:"button",onClick:r},"see the correct answers"),"."),p=n1(o);return K.createElement(K.Fragment,null,K.createElement("h3",null,"Answer Review"),K.createElement("p",null,"You answered"," ",K.createElement("strong",null,t,"/",o.questions.length)," ","questions correctly."),c,o.questions.map((g,b)=>{let{answer:O,correct:P}=e.answers[b];return K.createElement("div",{className:"answer-wrapper",key:b},K.createElement(xT,{index:b+1,title:p[b],multipart:o.multipart,quizName:u,question:g,userAnswer:O,correct:P,showCorrect:e.confirmedDone}))}),c)},t1=e=>{Ee.useLayoutEffect(()=>{if(e){let t=r=>r.stopPropagation(),n=r=>(r.preventDefault(),r.stopPropagation(),!1);return document.documentElement.addEventListener("keydown",t,!1),document.documentElement.addEventListener("touchmove",n,!1),()=>{document.documentElement.removeEventListener("keydown",t,!1),document.documentElement.removeEventListener("touchmove",n)}}},[e])},go=K.createContext(null),yT="a".charCodeAt(0),n1=e=>{let t=[],n,r;return e.questions.forEach(o=>{o.multipart?(o.multipart===r?n.push(o):(n=[o],t.push(n)),r=o.multipart):(n=[o],t.push(n))}),t.flatMap((o,u)=>o.map((c,p)=>{let g=(u+1).toString();return c.multipart&&(g+=String.fromCharCode(yT+p)),g}))},wT=Jw(({onFinish:e,...t})=>{let[n]=Ee.useState(()=>pT.MD5(t.quiz)),r=new hT(t.name,n),o=Ee.useMemo(()=>t.quiz.questions.map(l=>{var d;let h=yh(l.type);return(d=h.questionState)==null?void 0:d.call(h,l.prompt,l.answer)}),[t.quiz]),u=iT(()=>mT({quiz:t.quiz,answerStorage:r,cacheAnswers:t.cacheAnswers,autoStart:t.autoStart})),c=()=>{t.cacheAnswers&&r.save(u.answers,u.confirmedDone,u.attempt,u.wrongAnswers)},p=u.index===t.quiz.questions.length,g=u.started&&!p;t1(g);let[b,O]=Ee.useState(),P=g&&(t.fullscreen??!1);Ee.useLayoutEffect(()=>{document.body.style.overflowY=P?"hidden":"auto",P?O(window.scrollY+100):t.fullscreen&&b!==void 0&&window.scrollTo(0,b)},[P]);let q=Jn(l=>{var h;if(l=ji.cloneDeep(l),u.attempt===0)u.answers.push(l),u.index+=1;else{u.answers[u.index]=l;let d=u.wrongAnswers.findIndex(_=>_===u.index);d===u.wrongAnswers.length-1?u.index=t.quiz.questions.length:u.index=u.wrongAnswers[d+1]}if((h=window.telemetry)==null||h.log("answers",{quizName:t.name,quizHash:n,answers:u.answers,attempt:u.attempt}),u.index===t.quiz.questions.length){let d=u.answers.map((_,v)=>({a:_,i:v})).filter(({a:_})=>!_.correct);d.length===0||!t.allowRetry?u.confirmedDone=!0:u.wrongAnswers=d.map(({i:_})=>_),c(),e==null||e(gk(u.answers))}}),Z=u.answers.filter(l=>l.correct).length;u.confirmedDone;let M=n1(t.quiz),H=K.createElement("section",null,u.started?p?K.createElement(_T,{state:u,nCorrect:Z,onRetry:Jn(()=>{u.index=u.wrongAnswers[0],u.attempt+=1}),onGiveUp:Jn(()=>{u.confirmedDone=!0,c()})}):K.createElement(TT,{key:u.index,multipart:t.quiz.multipart,index:u.index,title:M[u.index],attempt:u.attempt,question:t.quiz.questions[u.index],questionState:o[u.index],onSubmit:q}):K.createElement("button",{type:"button",className:"start",onClick:Jn(()=>{u.started=!0})},"Start")),z=Ui("mdbook-quiz-wrapper",{expanded:P}),L=Jn(()=>{u.started=!1,u.index=0,u.answers=[]}),k=K.createElement("div",{className:"exit",onClick:L},"✕"),x=Ee.useRef();return K.createElement(go.Provider,{value:t},K.createElement("div",{ref:x,className:z},K.createElement("div",{className:"mdbook-quiz"},P&&K.createElement(K.Fragment,null,k,K.createElement(gT,{wrapperRef:x})),K.createElement(vT,{state:u,ended:p}),H)))});var r1,i1=Nw;r1=i1.createRoot,i1.hydrateRoot;let o1=({snippet:e,language:t,lineNumbers:n,syntaxHighlighter:r})=>{e=e.trim(),e=e.replace(/[<>]/g,c=>({"<":"&lt;",">":"&gt;"})[c]||c),e=e.replace(/`\[/g,"<mark>").replace(/\]`/g,"</mark>"),n?e=e.split(`
-`).map(p=>`<span class="line-number"></span><code>${p}</code>`).join(`
-`):e=`<code>${e}</code>`;let o=document.createElement("code");o.className=`language-${t||"rust"}`,o.innerHTML=e,r&&r(o);for(let c of o.children)c.tagName==="SPAN"&&c.className!=="line-number"&&c.remove();let u=document.createElement("pre");return u.innerHTML=o.innerHTML,n&&u.classList.add("line-numbers"),u},bT=(e,t)=>{},ST=e=>{let t=Ee.useContext(go),n=Ee.useRef(null);return Ee.useEffect(()=>{n.current.appendChild(o1({...e,syntaxHighlighter:t.syntaxHighlighter}))},[]),K.createElement("div",{ref:n})},ET=e=>({type:"output",filter(t){let r=new DOMParser().parseFromString(t,"text/html");return r.querySelectorAll("pre > code").forEach(u=>{if(u.classList.contains("ide"))return;let c;u.classList.forEach(b=>{let O="language-";b.startsWith(O)&&(c=b.slice(O.length))});let p=o1({...e||{},snippet:u.textContent,language:c});u.parentNode.replaceWith(p)}),r.body.innerHTML}}),Oi=({markdown:e,snippetOptions:t})=>{let n=Ee.useRef(null);Ee.useEffect(()=>{var o;bT(n.current),(o=window.initAquascopeBlocks)==null||o.call(window,n.current)},[e]);let r=Ee.useContext(go);return K.createElement("div",{ref:n},K.createElement($E,{markdown:e,extensions:[ET({...t,syntaxHighlighter:r.syntaxHighlighter})]}))},kT=({markdown:e})=>{let[t,n]=Ee.useState(!1);return K.createElement("span",{className:"info-wrapper"},t&&K.createElement("div",{className:"info-popout"},K.createElement(Oi,{markdown:e})),K.createElement("span",{className:"info",onClick:()=>n(!t)}))},s1={PromptView:({prompt:e})=>K.createElement(Oi,{markdown:e.prompt,snippetOptions:{lineNumbers:!0}}),questionState(e,t){let n,r=Array.isArray(t.answer)?t.answer:[t.answer];return e.answerIndex!==void 0?(n=[...e.distractors],n.splice(e.answerIndex,0,...r)):(n=[...r,...e.distractors],e.sortAnswers?n=ji.sortBy(n):n=ji.shuffle(n)),{choices:n}},ResponseView:({answer:e,state:t,formValidators:{required:n,register:r}})=>K.createElement(K.Fragment,null,t.choices.map((o,u)=>{let c=Ee.useId(),p=Array.isArray(e.answer);return K.createElement("div",{className:"choice",key:u},K.createElement("input",{type:p?"checkbox":"radio",...p?r("answer",{validate:g=>g.length>0}):n("answer"),value:o,id:c}),K.createElement("label",{htmlFor:c},K.createElement(Oi,{markdown:o})))})),getAnswerFromDOM(e){return Array.isArray(e.answer)&&e.answer.sort(),{answer:e.answer}},compareAnswers(e,t){let n=r=>ji.sortBy(Array.isArray(r)?r:[r]);return ji.isEqual(n(e.answer),n(t.answer))},AnswerView:({answer:e,baseline:t})=>K.createElement("div",{className:Ui("md-flex",s1.compareAnswers(t,e)?"correct":"incorrect")},Array.isArray(e.answer)?K.createElement("ul",null,e.answer.map((n,r)=>K.createElement("li",{key:r},K.createElement(Oi,{markdown:n})))):K.createElement(Oi,{markdown:e.answer}))},u1={PromptView:({prompt:e})=>K.createElement(Oi,{markdown:e.prompt,snippetOptions:{lineNumbers:!0}}),ResponseView:({prompt:e,submit:t,formValidators:{required:n}})=>{let r=n("answer");return K.createElement(K.Fragment,null,!e.response||e.response==="short"?K.createElement("input",{...r,type:"text",placeholder:"Write your answer here...",onKeyDown:o=>{o.key==="Enter"&&t()}}):K.createElement("textarea",{...r,placeholder:"Write your answer here..."}))},AnswerView:({answer:e,baseline:t})=>K.createElement("code",{className:u1.compareAnswers(t,e)?"correct":"incorrect"},e.answer),compareAnswers(e,t){let n=o=>o.toLowerCase().trim();return[e.answer].concat(e.alternatives||[]).map(n).includes(n(t.answer))}},AT={ShortAnswer:u1,Tracing:{PromptView:({prompt:e})=>K.createElement(K.Fragment,null,K.createElement("p",null,"Determine whether the program will pass the compiler. If it passes, write the expected output of the program if it were executed."),K.createElement(ST,{snippet:e.program,lineNumbers:!0})),ResponseView:({formValidators:{required:e,formState:{errors:t}}})=>{let[n,r]=Ee.useState(void 0),[o,u]=[Ee.useId(),Ee.useId()];return K.createElement(K.Fragment,null,K.createElement("div",{className:"response-block"},"This program:"," ",K.createElement("span",{className:Ui("option",{error:t.doesCompile})},K.createElement("input",{type:"radio",...e("doesCompile"),id:o,value:"true",onClick:()=>r(!0)})," ",K.createElement("label",{htmlFor:o},"DOES compile")),K.createElement("span",{className:"option-separator"},"OR"),K.createElement("span",{className:Ui("option",{error:t.doesCompile})},K.createElement("input",{type:"radio",...e("doesCompile"),id:u,value:"false",onClick:()=>r(!1)})," ",K.createElement("label",{htmlFor:u},"does NOT compile"))),n!==void 0&&n&&K.createElement("div",null,K.createElement("p",null,"The output of this program will be:"),K.createElement("textarea",{...e("stdout"),placeholder:"Write the program's stdout here..."})))},getAnswerFromDOM(e){let t=e.doesCompile==="true";if(t){let n=e.stdout;return{doesCompile:t,stdout:n}}else return{doesCompile:t}},AnswerView:({answer:e,baseline:t})=>{let n=r=>e[r]===t[r]?"correct":"incorrect";return K.createElement("div",null,K.createElement("p",{className:n("doesCompile")},"This program"," ",K.createElement("strong",null,e.doesCompile?"does":"does not")," compile."),e.doesCompile?K.createElement(K.Fragment,null,K.createElement("p",{className:t.stdout&&e.stdout.trim()===t.stdout.trim()?"correct":"incorrect"},"The output of this program will be:"),K.createElement("pre",null,e.stdout)):null)},compareAnswers(e,t){let n=r=>r.trim();return e.doesCompile===t.doesCompile&&(e.doesCompile?n(t.stdout)===n(e.stdout):!0)}},MultipleChoice:s1},yh=e=>AT[e],a1=e=>{let t=[];for(let n=0;n<e.length;++n)n>0&&e[n].match(/[A-Z]/)&&t.push("-"),t.push(e[n].toLowerCase());return t.join("")},l1=({quizName:e,question:t})=>{let[n,r]=Ee.useState(!1);t1(n);let o=u=>{let p=new FormData(u.target).get("feedback").toString();window.telemetry.log("bug",{quizName:e,question:t,feedback:p}),u.preventDefault(),r(!1)};return K.createElement("div",{className:"bug-report"},K.createElement("button",{type:"button",title:"Report a bug in this question",onClick:()=>r(!n)},"🐞"),n&&K.createElement("div",{className:"reporter"},K.createElement("button",{type:"button",className:"close",onClick:()=>r(!1)},"✕"),K.createElement("h3",null,"Report a bug"),K.createElement("p",null,"If you found an issue in this question (e.g. a typo or an incorrect answer), please describe the issue and report it:"),K.createElement("form",{onSubmit:o},K.createElement("textarea",{name:"feedback","aria-label":"Bug feedback"}),K.createElement("input",{type:"submit","aria-label":"Submit bug feedback"}))))},c1=()=>new Date().getTime();const CT=`
-Normally, we only observe *whether* readers get a question correct or incorrect. 
-This explanation helps us understand *why* a reader answers a particular way, so 
-we can better improve the surrounding text.
-`.trim();let d1=({title:e,multipart:t,question:n})=>K.createElement("div",{className:"multipart-context"},K.createElement("p",null,K.createElement("strong",null,"Question ",e.substring(0,1)," has multiple parts.")," The box below contains the shared context for each part."),K.createElement("div",{className:"multipart-context-content"},K.createElement(Oi,{markdown:t[n.multipart]}))),TT=({multipart:e,question:t,index:n,title:r,attempt:o,questionState:u,onSubmit:c})=>{let{name:p,showBugReporter:g}=Ee.useContext(go),b=Ee.useMemo(c1,[p,t,n]),O=Ee.useRef(null),[P,q]=Ee.useState(!1),Z=yh(t.type);if(!Z)return K.createElement("div",null,"QUIZ FORMAT ERROR: unknown question type ",K.createElement("code",null,t.type));let M=O8(),H=(l,h)=>{let d=M.register(l,{...h,required:!0}),_=Ui({error:M.formState.errors[l]});return{...d,className:_}},z=a1(t.type),L=M.handleSubmit(l=>{let h=Z.getAnswerFromDOM?Z.getAnswerFromDOM(l,O.current):l,_=(Z.compareAnswers||ji.isEqual)(t.answer,h);c({answer:h,correct:_,start:b,end:c1(),explanation:l.explanation})}),k=t.promptExplanation&&o===0,x=Ee.useId();return K.createElement("div",{className:Ui("question",z)},K.createElement("div",{className:"prompt"},K.createElement("h4",null,"Question ",r),t.multipart&&K.createElement(d1,{question:t,multipart:e,title:r}),K.createElement(Z.PromptView,{prompt:t.prompt}),window.telemetry&&g&&K.createElement(l1,{quizName:p,question:n})),K.createElement("form",{className:"response",ref:O,onSubmit:L},K.createElement("h4",null,"Response"),K.createElement("fieldset",{disabled:P},K.createElement(Z.ResponseView,{key:`${p}-question${n}`,prompt:t.prompt,answer:t.answer,submit:L,state:u,formValidators:{...M,required:H}})),P&&K.createElement(K.Fragment,null,K.createElement("p",null,K.createElement("br",null),K.createElement("label",{htmlFor:x},"In 1-2 sentences, please explain why you picked this answer.   ",K.createElement(kT,{markdown:CT}))),K.createElement("textarea",{id:x,title:"Explanation",...H("explanation")})),k&&!P?K.createElement("button",{type:"button",onClick:()=>q(!0)},"Submit"):K.createElement("input",{type:"submit"})))},xT=({multipart:e,question:t,index:n,title:r,userAnswer:o,correct:u,showCorrect:c})=>{let{name:p,showBugReporter:g}=Ee.useContext(go),b=yh(t.type),O=a1(t.type),P=null;if(t.multipart){let q=`${p}-${t.multipart}`;r.substring(1,2)==="a"?P=K.createElement("div",null,K.createElement("a",{id:q}),K.createElement(d1,{question:t,multipart:e,title:r})):P=K.createElement("div",{className:"multipart-context"},K.createElement("a",{href:`#${q}`},"Return to question context ",K.createElement("span",{className:"rotate-arrow"},"↳")))}return K.createElement("div",{className:Ui("answer",O)},K.createElement("div",{className:"prompt"},K.createElement("h4",null,"Question ",r),P,K.createElement(b.PromptView,{prompt:t.prompt}),window.telemetry&&g&&K.createElement(l1,{quizName:p,question:n})),K.createElement("div",{className:"answer-row"},K.createElement("div",null,K.createElement("div",{className:"answer-header"},"You answered:"),K.createElement("div",null,K.createElement(b.AnswerView,{answer:o,baseline:t.answer,prompt:t.prompt}))),c&&!u&&K.createElement("div",null,K.createElement("div",{className:"answer-header"},"The correct answer is:"),K.createElement("div",null,K.createElement(b.AnswerView,{answer:t.answer,baseline:t.answer,prompt:t.prompt})))),c&&t.context&&K.createElement("div",{className:"context"},K.createElement(Oi,{markdown:`**Context**:
-${t.context}`})))};function wh(e,t){return wh=Object.setPrototypeOf?Object.setPrototypeOf.bind():function(r,o){return r.__proto__=o,r},wh(e,t)}function LT(e,t){e.prototype=Object.create(t.prototype),e.prototype.constructor=e,wh(e,t)}var OT=function(t,n){return t===void 0&&(t=[]),n===void 0&&(n=[]),t.length!==n.length||t.some(function(r,o){return!Object.is(r,n[o])})},f1={error:null},PT=function(e){LT(t,e);function t(){for(var r,o=arguments.length,u=new Array(o),c=0;c<o;c++)u[c]=arguments[c];return r=e.call.apply(e,[this].concat(u))||this,r.state=f1,r.resetErrorBoundary=function(){for(var p,g=arguments.length,b=new Array(g),O=0;O<g;O++)b[O]=arguments[O];r.props.onReset==null||(p=r.props).onReset.apply(p,b),r.reset()},r}t.getDerivedStateFromError=function(o){return{error:o}};var n=t.prototype;return n.reset=function(){this.setState(f1)},n.componentDidCatch=function(o,u){var c,p;(c=(p=this.props).onError)==null||c.call(p,o,u)},n.componentDidUpdate=function(o,u){var c=this.state.error,p=this.props.resetKeys;if(c!==null&&u.error!==null&&OT(o.resetKeys,p)){var g,b;(g=(b=this.props).onResetKeysChange)==null||g.call(b,o.resetKeys,p),this.reset()}},n.render=function(){var o=this.state.error,u=this.props,c=u.fallbackRender,p=u.FallbackComponent,g=u.fallback;if(o!==null){var b={error:o,resetErrorBoundary:this.resetErrorBoundary};if(Ee.isValidElement(g))return g;if(typeof c=="function")return c(b);if(p)return Ee.createElement(p,b);throw new Error("react-error-boundary requires either a fallback, fallbackRender, or FallbackComponent prop")}return this.props.children},t}(Ee.Component);const Uve="";let DT=({error:e})=>{var t;return document.body.style.overflowY="auto",(t=window.telemetry)==null||t.log("runtime_error",{error:e.stack||e.message}),wa.jsxs("div",{className:"mdbook-quiz",children:[wa.jsx("strong",{children:"The quiz component encountered a runtime error!"})," Sorry for the inconvenience. The error has been reported to the developers, and we will try to fix it soon."]})},NT=()=>{document.querySelectorAll(".quiz-placeholder").forEach(e=>{let t=e,n=JSON.parse(t.dataset.quizName),r=JSON.parse(t.dataset.quizQuestions),o=r1(e),u=P=>P!==void 0?JSON.parse(P):void 0,c=u(t.dataset.quizFullscreen)===!0,p=u(t.dataset.quizCacheAnswers)===!0,g=u(t.dataset.quizShowBugReporter)===!0,b=u(t.dataset.quizInitialText),O=hljs.highlightBlock;o.render(wa.jsx(PT,{FallbackComponent:DT,children:wa.jsx(wT,{name:n,quiz:r,fullscreen:c,cacheAnswers:p,showBugReporter:g,initialText:b,syntaxHighlighter:O,allowRetry:!0})}))})};window.addEventListener("DOMContentLoaded",NT,!1)})();
-//# sourceMappingURL=quiz-embed.iife.js.map
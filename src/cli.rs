@@ -0,0 +1,758 @@
+//! Command-line subcommands for checking exercise collections.
+//!
+//! These back `mdbook-exercises validate|lint|stats|test|mutate|runner`. Each command accepts
+//! markdown files, directories, or a `book.toml` (in which case the book's
+//! `src` directory is scanned and `{{#exercise ...}}` includes are followed).
+//! `runner` only reads the book.toml's language settings and then serves
+//! test runs over HTTP until interrupted.
+//!
+//! Exit codes: `0` on success, `1` when problems were found, `2` on usage
+//! or I/O errors.
+
+use crate::collect::{collect_markdown_files, load_exercise_files, ExerciseFile};
+use crate::language::LanguageRegistry;
+use crate::lint::{LintLevel, LintRegistry};
+use crate::mutate::{mutate_exercises, MutationReport};
+use crate::parser::ParseOptions;
+use crate::preprocessor::ExercisesPreprocessor;
+use crate::runner::{
+    test_exercises, ExerciseTestReport, ExerciseTestStatus, ResourceLimits, RunOptions, RunStatus, StarterProblem,
+    TestStatus,
+};
+use crate::server::{serve, ServerOptions};
+use crate::stats::{compute_stats, ExerciseStats};
+use crate::types::{ParsedExercise, Requirements, StarterCode};
+use crate::validate::{validate_files, Diagnostic, Severity};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Exit code for a successful run.
+pub const EXIT_OK: i32 = 0;
+/// Exit code when problems were found.
+pub const EXIT_PROBLEMS: i32 = 1;
+/// Exit code for usage or I/O errors.
+pub const EXIT_USAGE: i32 = 2;
+
+/// Output format for command results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Human,
+    Json,
+}
+
+/// Options shared by all subcommands.
+#[derive(Debug, Clone, Default)]
+struct CommandOptions {
+    format: OutputFormat,
+    strict: bool,
+    list_rules: bool,
+    online: bool,
+    timeout: Option<Duration>,
+    max_mutants: Option<usize>,
+    listen: Option<String>,
+    concurrency: Option<usize>,
+    cpu_time: Option<Duration>,
+    memory_mb: Option<u64>,
+    max_output: Option<usize>,
+    allow_origin: Option<String>,
+    private_dir: Option<PathBuf>,
+    paths: Vec<PathBuf>,
+}
+
+const USAGE: &str = "\
+Usage: mdbook-exercises <command> [options] [<path>...]
+
+Commands:
+  validate   Check that exercises parse and reference valid IDs
+  lint       Check exercises for common authoring mistakes
+  stats      Summarize exercises by difficulty, domain and completeness
+  test       Run each exercise's tests against its solution (Rust, Python, JavaScript)
+  mutate     Check that tests catch small mutations of each solution
+  runner     Serve test runs over HTTP (the execution protocol)
+
+Paths may be markdown files, directories, or a book.toml.
+Defaults to ./book.toml when no path is given.
+
+Options:
+  --format <human|json>   Output format (default: human)
+  --json                  Shorthand for --format json
+  --strict                Treat warnings as failures
+  --list-rules            (lint) List lint rules and their levels
+  --timeout <seconds>     (test, mutate) Time limit per test run (default: 120)
+  --online                (test, mutate, runner) Let cargo download dependencies
+  --max-mutants <n>       (mutate) Mutants tried per exercise (default: 50)
+  --listen <addr:port>    (runner) Address to listen on (default: 127.0.0.1:8080)
+  --concurrency <n>       (runner) Test runs executed at once (default: CPU count)
+  --cpu-time <seconds>    (runner) CPU time limit per process (default: 60)
+  --memory <MB>           (runner) Memory limit per process (default: 2048)
+  --max-output <bytes>    (runner) Output returned per stream (default: 65536)
+  --allow-origin <origin> (runner) CORS allowed origin (default: *)
+  --private-dir <path>    Private solutions and tests to merge (default: book.toml's private_dir)
+  -h, --help              Show this help";
+
+/// Returns true if `name` is a subcommand handled by [`run`].
+pub fn is_command(name: &str) -> bool {
+    matches!(name, "validate" | "lint" | "stats" | "test" | "mutate" | "runner")
+}
+
+/// Run a subcommand and return the process exit code.
+pub fn run(command: &str, args: &[String]) -> i32 {
+    let options = match parse_options(args) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return EXIT_OK;
+        }
+        Err(msg) => {
+            eprintln!("Error: {}\n\n{}", msg, USAGE);
+            return EXIT_USAGE;
+        }
+    };
+
+    if command == "lint" && options.list_rules {
+        return match lint_registry(&options.paths) {
+            Ok(registry) => {
+                for (rule, level) in registry.rules() {
+                    println!("{:<26}{:<7}{}", rule.id(), level.to_string(), rule.description());
+                }
+                EXIT_OK
+            }
+            Err(msg) => {
+                eprintln!("Error: {}", msg);
+                EXIT_USAGE
+            }
+        };
+    }
+
+    if command == "runner" {
+        return match book_run_options(&options.paths) {
+            Ok(book) => run_server(&options, book),
+            Err(msg) => {
+                eprintln!("Error: {}", msg);
+                EXIT_USAGE
+            }
+        };
+    }
+
+    let loaded = book_run_options(&options.paths).and_then(|book| {
+        let parse_options = book_parse_options(&options, book.languages.clone())?;
+        load(&options.paths, &parse_options).map(|files| (files, book))
+    });
+    let (files, book) = match loaded {
+        Ok(loaded) => loaded,
+        Err(msg) => {
+            eprintln!("Error: {}", msg);
+            return EXIT_USAGE;
+        }
+    };
+
+    match command {
+        "validate" => run_validate(&files, &options),
+        "lint" => run_lint(&files, &options, &book.languages),
+        "stats" => run_stats(&files, &options),
+        "test" => run_test(&files, &options, book),
+        "mutate" => run_mutate(&files, &options, book),
+        other => {
+            eprintln!("Error: unknown command '{}'\n\n{}", other, USAGE);
+            EXIT_USAGE
+        }
+    }
+}
+
+fn parse_options(args: &[String]) -> Result<Option<CommandOptions>, String> {
+    let mut options = CommandOptions::default();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--json" => options.format = OutputFormat::Json,
+            "--strict" => options.strict = true,
+            "--list-rules" => options.list_rules = true,
+            "--online" => options.online = true,
+            "--format" => {
+                let value = iter.next().ok_or("--format requires a value")?;
+                options.format = parse_format(value)?;
+            }
+            "--timeout" => {
+                let value = iter.next().ok_or("--timeout requires a value")?;
+                let secs: u64 = value
+                    .parse()
+                    .map_err(|_| format!("invalid timeout '{}' (expected seconds)", value))?;
+                options.timeout = Some(Duration::from_secs(secs));
+            }
+            "--max-mutants" => {
+                let value = iter.next().ok_or("--max-mutants requires a value")?;
+                let max: usize = value
+                    .parse()
+                    .map_err(|_| format!("invalid mutant count '{}'", value))?;
+                options.max_mutants = Some(max);
+            }
+            "--listen" => {
+                options.listen = Some(iter.next().ok_or("--listen requires an address")?.clone());
+            }
+            "--concurrency" => {
+                let value = iter.next().ok_or("--concurrency requires a value")?;
+                let n: usize = value
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or_else(|| format!("invalid concurrency '{}'", value))?;
+                options.concurrency = Some(n);
+            }
+            "--cpu-time" => {
+                let value = iter.next().ok_or("--cpu-time requires a value")?;
+                let secs: u64 = value
+                    .parse()
+                    .map_err(|_| format!("invalid CPU time '{}' (expected seconds)", value))?;
+                options.cpu_time = Some(Duration::from_secs(secs));
+            }
+            "--memory" => {
+                let value = iter.next().ok_or("--memory requires a value")?;
+                let mb: u64 = value
+                    .parse()
+                    .map_err(|_| format!("invalid memory limit '{}' (expected megabytes)", value))?;
+                options.memory_mb = Some(mb);
+            }
+            "--max-output" => {
+                let value = iter.next().ok_or("--max-output requires a value")?;
+                let bytes: usize = value
+                    .parse()
+                    .map_err(|_| format!("invalid output limit '{}' (expected bytes)", value))?;
+                options.max_output = Some(bytes);
+            }
+            "--allow-origin" => {
+                options.allow_origin = Some(iter.next().ok_or("--allow-origin requires a value")?.clone());
+            }
+            "--private-dir" => {
+                options.private_dir = Some(PathBuf::from(iter.next().ok_or("--private-dir requires a path")?));
+            }
+            _ if arg.starts_with("--format=") => {
+                options.format = parse_format(&arg["--format=".len()..])?;
+            }
+            _ if arg.starts_with('-') => return Err(format!("unknown option '{}'", arg)),
+            _ => options.paths.push(PathBuf::from(arg)),
+        }
+    }
+    if options.paths.is_empty() {
+        options.paths.push(PathBuf::from("book.toml"));
+    }
+    Ok(Some(options))
+}
+
+fn parse_format(value: &str) -> Result<OutputFormat, String> {
+    match value {
+        "human" => Ok(OutputFormat::Human),
+        "json" => Ok(OutputFormat::Json),
+        _ => Err(format!("invalid format '{}' (expected human or json)", value)),
+    }
+}
+
+/// Resolve command paths (including book.toml files) into parsed exercise files.
+fn load(paths: &[PathBuf], parse_options: &ParseOptions) -> Result<Vec<ExerciseFile>, String> {
+    let mut markdown = Vec::new();
+    for path in paths {
+        let found = if is_book_toml(path) {
+            let src = book_src_dir(path)?;
+            collect_markdown_files(std::slice::from_ref(&src), Some(&src))
+        } else {
+            collect_markdown_files(std::slice::from_ref(path), None)
+        };
+        markdown.extend(found.map_err(|e| e.to_string())?);
+    }
+    markdown.sort();
+    markdown.dedup();
+    load_exercise_files(&markdown, parse_options).map_err(|e| e.to_string())
+}
+
+fn is_book_toml(path: &Path) -> bool {
+    path.file_name().map(|n| n == "book.toml").unwrap_or(false)
+}
+
+fn load_book_config(book_toml: &Path) -> Result<mdbook::Config, String> {
+    mdbook::Config::from_disk(book_toml).map_err(|e| format!("{}: {}", book_toml.display(), e))
+}
+
+/// Locate the book source directory declared by a book.toml.
+fn book_src_dir(book_toml: &Path) -> Result<PathBuf, String> {
+    let config = load_book_config(book_toml)?;
+    let root = book_toml.parent().unwrap_or_else(|| Path::new("."));
+    Ok(root.join(&config.book.src))
+}
+
+/// Run options with the language registry and toolchain defaults from the
+/// first book.toml among the paths.
+fn book_run_options(paths: &[PathBuf]) -> Result<RunOptions, String> {
+    let mut run_options = RunOptions::default();
+    if let Some(path) = paths.iter().find(|p| is_book_toml(p) && p.is_file()) {
+        let config = load_book_config(path)?;
+        let in_book = |e: mdbook::errors::Error| format!("{}: {:#}", path.display(), e);
+        run_options.languages = ExercisesPreprocessor::load_languages(&config).map_err(in_book)?;
+        run_options.toolchain = ExercisesPreprocessor::load_toolchain(&config).map_err(in_book)?;
+    }
+    Ok(run_options)
+}
+
+/// Parse options with the given languages and the private directory from
+/// `--private-dir` or the first book.toml among the paths.
+fn book_parse_options(options: &CommandOptions, languages: LanguageRegistry) -> Result<ParseOptions, String> {
+    let private_dir = match (&options.private_dir, options.paths.iter().find(|p| is_book_toml(p) && p.is_file())) {
+        (Some(dir), _) => Some(dir.clone()),
+        (None, Some(path)) => {
+            let root = path.parent().unwrap_or_else(|| Path::new("."));
+            ExercisesPreprocessor::load_private_dir(&load_book_config(path)?, root)
+        }
+        (None, None) => None,
+    };
+    Ok(ParseOptions {
+        languages,
+        private_dir,
+        base_dir: None,
+    })
+}
+
+/// Build the lint registry, applying `[preprocessor.exercises.lints]` from any book.toml.
+fn lint_registry(paths: &[PathBuf]) -> Result<LintRegistry, String> {
+    let mut registry = LintRegistry::with_default_rules();
+    for path in paths.iter().filter(|p| is_book_toml(p) && p.is_file()) {
+        let config = load_book_config(path)?;
+        let Some(lints) = config.get("preprocessor.exercises.lints").and_then(|v| v.as_table()) else {
+            continue;
+        };
+        for (id, value) in lints {
+            let level: LintLevel = value
+                .as_str()
+                .ok_or_else(|| format!("{}: lint level for '{}' must be a string", path.display(), id))?
+                .parse()
+                .map_err(|e| format!("{}: {}", path.display(), e))?;
+            registry
+                .set_level(id, level)
+                .map_err(|e| format!("{}: {}", path.display(), e))?;
+        }
+    }
+    Ok(registry)
+}
+
+fn print_json<T: Serialize>(value: &T) {
+    match serde_json::to_string_pretty(value) {
+        Ok(json) => println!("{}", json),
+        Err(e) => eprintln!("Error: failed to serialize output: {}", e),
+    }
+}
+
+fn print_diagnostics(diagnostics: &[Diagnostic]) {
+    for d in diagnostics {
+        println!("{}", d);
+        if let Some(suggestion) = &d.suggestion {
+            println!("  help: {}", suggestion);
+        }
+    }
+}
+
+fn exit_code(diagnostics: &[Diagnostic], strict: bool) -> i32 {
+    let failing = diagnostics
+        .iter()
+        .any(|d| d.severity == Severity::Error || strict);
+    if failing {
+        EXIT_PROBLEMS
+    } else {
+        EXIT_OK
+    }
+}
+
+fn run_validate(files: &[ExerciseFile], options: &CommandOptions) -> i32 {
+    let report = validate_files(files);
+    match options.format {
+        OutputFormat::Json => print_json(&report),
+        OutputFormat::Human => {
+            print_diagnostics(&report.diagnostics);
+            println!(
+                "Checked {} files ({} exercises): {} errors, {} warnings",
+                report.files_checked,
+                report.exercises,
+                report.error_count(),
+                report.warning_count()
+            );
+        }
+    }
+    exit_code(&report.diagnostics, options.strict)
+}
+
+/// JSON shape of `lint` output.
+#[derive(Serialize)]
+struct LintOutput<'a> {
+    files_checked: usize,
+    diagnostics: &'a [Diagnostic],
+}
+
+fn run_lint(files: &[ExerciseFile], options: &CommandOptions, languages: &LanguageRegistry) -> i32 {
+    let mut registry = match lint_registry(&options.paths) {
+        Ok(registry) => registry,
+        Err(msg) => {
+            eprintln!("Error: {}", msg);
+            return EXIT_USAGE;
+        }
+    };
+    registry.set_languages(languages.clone());
+    let diagnostics = registry.run(files);
+    match options.format {
+        OutputFormat::Json => print_json(&LintOutput {
+            files_checked: files.len(),
+            diagnostics: &diagnostics,
+        }),
+        OutputFormat::Human => {
+            print_diagnostics(&diagnostics);
+            println!("Linted {} files: {} problems", files.len(), diagnostics.len());
+        }
+    }
+    exit_code(&diagnostics, options.strict)
+}
+
+fn run_stats(files: &[ExerciseFile], options: &CommandOptions) -> i32 {
+    let stats = compute_stats(files);
+    match options.format {
+        OutputFormat::Json => print_json(&stats),
+        OutputFormat::Human => print_stats(&stats),
+    }
+    EXIT_OK
+}
+
+fn print_stats(stats: &ExerciseStats) {
+    println!(
+        "Exercises: {} ({} code, {} usecase, {} quiz)",
+        stats.total, stats.code_exercises, stats.usecase_exercises, stats.quiz_exercises
+    );
+    if !stats.by_difficulty.is_empty() {
+        println!("By difficulty:");
+        for (difficulty, count) in &stats.by_difficulty {
+            println!("  {:<14}{}", difficulty, count);
+        }
+    }
+    if !stats.by_domain.is_empty() {
+        println!("By domain:");
+        for (domain, count) in &stats.by_domain {
+            println!("  {:<14}{}", domain, count);
+        }
+    }
+    println!(
+        "Total estimated time: {}h {}m",
+        stats.total_time_minutes / 60,
+        stats.total_time_minutes % 60
+    );
+    print_id_list("Missing time estimate", &stats.missing_time);
+    print_id_list("Missing hints", &stats.missing_hints);
+    print_id_list("Missing tests", &stats.missing_tests);
+    print_id_list("Missing solutions", &stats.missing_solutions);
+}
+
+/// JSON shape of `test` output.
+#[derive(Serialize)]
+struct TestOutput<'a> {
+    passed: usize,
+    failed: usize,
+    skipped: usize,
+    exercises: &'a [ExerciseTestReport],
+}
+
+/// Apply command-line options to the book's run options.
+fn run_options(options: &CommandOptions, mut run_options: RunOptions) -> RunOptions {
+    if options.online {
+        run_options.offline = false;
+    }
+    if let Some(timeout) = options.timeout {
+        run_options.timeout = timeout;
+    }
+    run_options
+}
+
+fn run_test(files: &[ExerciseFile], options: &CommandOptions, book: RunOptions) -> i32 {
+    let reports = test_exercises(files, &run_options(options, book));
+    let count = |status| reports.iter().filter(|r| r.status == status).count();
+    let (passed, failed, skipped) = (
+        count(ExerciseTestStatus::Passed),
+        count(ExerciseTestStatus::Failed),
+        count(ExerciseTestStatus::Skipped),
+    );
+
+    match options.format {
+        OutputFormat::Json => print_json(&TestOutput {
+            passed,
+            failed,
+            skipped,
+            exercises: &reports,
+        }),
+        OutputFormat::Human => {
+            for report in &reports {
+                print_test_report(report);
+            }
+            println!(
+                "Tested {} exercises: {} passed, {} failed, {} skipped",
+                reports.len(),
+                passed,
+                failed,
+                skipped
+            );
+        }
+    }
+
+    if failed > 0 {
+        EXIT_PROBLEMS
+    } else {
+        EXIT_OK
+    }
+}
+
+fn print_test_report(report: &ExerciseTestReport) {
+    let label = match report.status {
+        ExerciseTestStatus::Passed => "PASS",
+        ExerciseTestStatus::Failed => "FAIL",
+        ExerciseTestStatus::Skipped => "SKIP",
+    };
+    let mut line = format!("{} {} ({})", label, report.id, report.file.display());
+    if let Some(result) = &report.solution {
+        line.push_str(&format!(" - {} tests in {:.1}s", result.tests.len(), result.duration_ms as f64 / 1000.0));
+    }
+    if let Some(reason) = &report.reason {
+        line.push_str(&format!(": {}", reason));
+    }
+    println!("{}", line);
+
+    if report.status != ExerciseTestStatus::Failed {
+        return;
+    }
+    let results = report.solution.iter().chain(&report.alternatives);
+    for (i, result) in results.enumerate().filter(|(_, r)| r.status != RunStatus::Passed) {
+        if !report.alternatives.is_empty() {
+            println!("  solution {}:", i + 1);
+        }
+        for test in result.tests.iter().filter(|t| t.status == TestStatus::Failed) {
+            println!("  ✗ {}", test.name);
+            for msg_line in test.message.iter().flat_map(|m| m.lines()) {
+                println!("      {}", msg_line);
+            }
+        }
+        if result.tests.is_empty() {
+            print_compiler_output(&result.stderr);
+        }
+    }
+    // A starter that unexpectedly fails to compile is worth showing in full
+    if let Some(starter) = &report.starter {
+        if report.starter_problem == Some(StarterProblem::DoesNotCompile) {
+            println!("  starter:");
+            print_compiler_output(&starter.stderr);
+        }
+    }
+}
+
+fn print_compiler_output(stderr: &str) {
+    for err_line in stderr.lines().filter(|l| !l.trim_start().starts_with("Compiling")) {
+        println!("    {}", err_line);
+    }
+}
+
+/// JSON shape of `mutate` output.
+#[derive(Serialize)]
+struct MutateOutput<'a> {
+    killed: usize,
+    survived: usize,
+    unviable: usize,
+    exercises: &'a [MutationReport],
+}
+
+fn run_mutate(files: &[ExerciseFile], options: &CommandOptions, book: RunOptions) -> i32 {
+    let max_mutants = options.max_mutants.unwrap_or(50);
+    let reports = mutate_exercises(files, &run_options(options, book), max_mutants);
+    let killed = reports.iter().map(|r| r.killed).sum();
+    let survived: usize = reports.iter().map(|r| r.survived.len()).sum();
+    let unviable = reports.iter().map(|r| r.unviable).sum();
+
+    match options.format {
+        OutputFormat::Json => print_json(&MutateOutput {
+            killed,
+            survived,
+            unviable,
+            exercises: &reports,
+        }),
+        OutputFormat::Human => {
+            for report in &reports {
+                if let Some(reason) = &report.skipped {
+                    println!("SKIP {} ({}): {}", report.id, report.file.display(), reason);
+                    continue;
+                }
+                println!(
+                    "{} {} ({}) - score {:.0}%: {} killed, {} survived, {} unviable",
+                    if report.survived.is_empty() { "PASS" } else { "WEAK" },
+                    report.id,
+                    report.file.display(),
+                    report.score() * 100.0,
+                    report.killed,
+                    report.survived.len(),
+                    report.unviable
+                );
+                for mutant in &report.survived {
+                    println!("  survived: line {}: {}", mutant.line, mutant.description);
+                }
+            }
+            println!(
+                "Mutated {} exercises: {} killed, {} survived, {} unviable",
+                reports.iter().filter(|r| r.skipped.is_none()).count(),
+                killed,
+                survived,
+                unviable
+            );
+        }
+    }
+
+    if survived > 0 && options.strict {
+        EXIT_PROBLEMS
+    } else {
+        EXIT_OK
+    }
+}
+
+fn run_server(options: &CommandOptions, book: RunOptions) -> i32 {
+    let mut server_options = ServerOptions {
+        run: run_options(options, book),
+        ..Default::default()
+    };
+    server_options.run.timeout = options.timeout.unwrap_or(Duration::from_secs(60));
+    server_options.run.check_starter = false;
+    server_options.run.limits = ResourceLimits {
+        cpu_time: Some(options.cpu_time.unwrap_or(Duration::from_secs(60))),
+        memory_mb: Some(options.memory_mb.unwrap_or(2048)),
+    };
+    if let Some(n) = options.concurrency {
+        server_options.max_concurrency = n;
+    }
+    if let Some(bytes) = options.max_output {
+        server_options.max_output_bytes = bytes;
+    }
+    if let Some(origin) = &options.allow_origin {
+        server_options.allow_origin = origin.clone();
+    }
+    let paths: Vec<PathBuf> = options.paths.iter().filter(|p| p.exists()).cloned().collect();
+    let loaded = book_parse_options(options, server_options.run.languages.clone())
+        .and_then(|parse_options| load(&paths, &parse_options));
+    match loaded {
+        Ok(files) => {
+            server_options.hidden_tests = hidden_tests(&files);
+            server_options.requirements = requirements(&files);
+            server_options.starters = starters(&files);
+        }
+        Err(msg) => {
+            eprintln!("Error: {}", msg);
+            return EXIT_USAGE;
+        }
+    }
+
+    let addr = options.listen.as_deref().unwrap_or("127.0.0.1:8080");
+    let listener = match TcpListener::bind(addr) {
+        Ok(listener) => listener,
+        Err(e) => {
+            eprintln!("Error: cannot listen on {}: {}", addr, e);
+            return EXIT_USAGE;
+        }
+    };
+    eprintln!(
+        "Serving test runs on http://{}/execute ({} at a time, {}s timeout)",
+        listener.local_addr().map(|a| a.to_string()).unwrap_or_else(|_| addr.to_string()),
+        server_options.max_concurrency,
+        server_options.run.timeout.as_secs()
+    );
+    if !server_options.hidden_tests.is_empty() {
+        eprintln!("Running hidden tests for {} exercise(s)", server_options.hidden_tests.len());
+    }
+    if !server_options.requirements.is_empty() {
+        eprintln!("Checking requirements for {} exercise(s)", server_options.requirements.len());
+    }
+    match serve(listener, server_options) {
+        Ok(()) => EXIT_OK,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            EXIT_USAGE
+        }
+    }
+}
+
+/// Hidden test code of each code exercise, by exercise ID.
+fn hidden_tests(files: &[ExerciseFile]) -> HashMap<String, String> {
+    files
+        .iter()
+        .filter_map(|f| match f.exercise() {
+            Some(ParsedExercise::Code(exercise)) => exercise
+                .hidden_tests
+                .as_ref()
+                .map(|tests| (exercise.metadata.id.clone(), tests.code.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+fn requirements(files: &[ExerciseFile]) -> HashMap<String, Requirements> {
+    files
+        .iter()
+        .filter_map(|f| match f.exercise() {
+            Some(ParsedExercise::Code(exercise)) => exercise
+                .requirements
+                .clone()
+                .map(|requirements| (exercise.metadata.id.clone(), requirements)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn starters(files: &[ExerciseFile]) -> HashMap<String, StarterCode> {
+    files
+        .iter()
+        .filter_map(|f| match f.exercise() {
+            Some(ParsedExercise::Code(exercise)) => exercise
+                .starter
+                .clone()
+                .map(|starter| (exercise.metadata.id.clone(), starter)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn print_id_list(label: &str, ids: &[String]) {
+    if !ids.is_empty() {
+        println!("{} ({}): {}", label, ids.len(), ids.join(", "));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let options = parse_options(&args(&["--format", "json", "--strict", "a.md", "dir"]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(options.format, OutputFormat::Json);
+        assert!(options.strict);
+        assert_eq!(options.paths, vec![PathBuf::from("a.md"), PathBuf::from("dir")]);
+
+        let defaults = parse_options(&[]).unwrap().unwrap();
+        assert_eq!(defaults.format, OutputFormat::Human);
+        assert_eq!(defaults.paths, vec![PathBuf::from("book.toml")]);
+
+        assert!(parse_options(&args(&["--format=xml"])).is_err());
+        assert!(parse_options(&args(&["--help"])).unwrap().is_none());
+
+        let runner = parse_options(&args(&["--listen", "0.0.0.0:9000", "--concurrency", "4", "--memory", "512"]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(runner.listen.as_deref(), Some("0.0.0.0:9000"));
+        assert_eq!(runner.concurrency, Some(4));
+        assert_eq!(runner.memory_mb, Some(512));
+        assert!(parse_options(&args(&["--concurrency", "0"])).is_err());
+    }
+}
@@ -0,0 +1,132 @@
+//! Discovery and loading of exercise files.
+//!
+//! Command-line tooling (validate, lint, stats, ...) works on sets of
+//! markdown files rather than on a single exercise. This module walks
+//! directories, follows `{{#exercise ...}}` includes and parses every file
+//! that contains an exercise directive.
+
+use crate::parser::{parse_exercise_with_options, ParseError, ParseOptions};
+use crate::types::ParsedExercise;
+use regex::Regex;
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A markdown file that was loaded from disk and parsed.
+#[derive(Debug)]
+pub struct ExerciseFile {
+    /// Path of the markdown file
+    pub path: PathBuf,
+
+    /// Raw markdown source
+    pub source: String,
+
+    /// Parse result (`Err` for files with broken exercise directives)
+    pub parsed: Result<ParsedExercise, ParseError>,
+}
+
+impl ExerciseFile {
+    /// The parsed exercise, if parsing succeeded.
+    pub fn exercise(&self) -> Option<&ParsedExercise> {
+        self.parsed.as_ref().ok()
+    }
+}
+
+/// Collect markdown files from a list of files and directories.
+///
+/// Directories are walked recursively. `{{#exercise ...}}` includes found in
+/// collected files are followed (relative to `include_root`, or to the file's
+/// own directory when no root is given). The result is sorted and deduplicated.
+pub fn collect_markdown_files(paths: &[PathBuf], include_root: Option<&Path>) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for path in paths {
+        if path.is_dir() {
+            files.extend(walk_markdown(path));
+        } else if path.is_file() {
+            files.push(path.clone());
+        } else {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{}: no such file or directory", path.display()),
+            ));
+        }
+    }
+
+    let include_re = Regex::new(r"\{\{#exercise\s+([^}]+)\}\}").expect("valid include regex");
+    let mut seen: HashSet<PathBuf> = HashSet::new();
+    let mut queue = files;
+    let mut out = Vec::new();
+    while let Some(file) = queue.pop() {
+        let key = fs::canonicalize(&file).unwrap_or_else(|_| file.clone());
+        if !seen.insert(key) {
+            continue;
+        }
+        if let Ok(text) = fs::read_to_string(&file) {
+            for cap in include_re.captures_iter(&text) {
+                let include = cap[1].trim();
+                let base = match include_root {
+                    Some(root) => root.to_path_buf(),
+                    None => file.parent().map(Path::to_path_buf).unwrap_or_default(),
+                };
+                let included = base.join(include);
+                if included.is_file() {
+                    queue.push(included);
+                }
+            }
+        }
+        out.push(file);
+    }
+
+    out.sort();
+    Ok(out)
+}
+
+/// Load and parse every collected file that contains an exercise.
+///
+/// Files without any `::: exercise`, `::: usecase` or `::: quiz` directive
+/// (chapter pages, READMEs) are skipped rather than reported as errors.
+pub fn load_exercise_files(files: &[PathBuf], options: &ParseOptions) -> io::Result<Vec<ExerciseFile>> {
+    let mut out = Vec::new();
+    for path in files {
+        let source = fs::read_to_string(path)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+        // `source=` paths are relative to the markdown file
+        let options = ParseOptions {
+            base_dir: path.parent().map(Path::to_path_buf),
+            ..options.clone()
+        };
+        let parsed = parse_exercise_with_options(&source, &options);
+        if matches!(parsed, Err(ParseError::UnknownExerciseType)) {
+            continue;
+        }
+        out.push(ExerciseFile {
+            path: path.clone(),
+            source,
+            parsed,
+        });
+    }
+    Ok(out)
+}
+
+fn is_markdown(path: &Path) -> bool {
+    path.extension().map(|e| e == "md").unwrap_or(false)
+}
+
+fn walk_markdown(root: &Path) -> Vec<PathBuf> {
+    let mut out = Vec::new();
+    let mut stack = vec![root.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        if let Ok(entries) = fs::read_dir(&dir) {
+            for entry in entries.flatten() {
+                let p = entry.path();
+                if p.is_dir() {
+                    stack.push(p);
+                } else if is_markdown(&p) {
+                    out.push(p);
+                }
+            }
+        }
+    }
+    out
+}
@@ -0,0 +1,433 @@
+//! Per-language settings for code exercises.
+//!
+//! A [`LanguageRegistry`] maps a code block language (`rust`, `py`, ...) to a
+//! [`LanguageSpec`] describing how that language is highlighted, commented,
+//! combined with test code, and run. The parser, renderer, browser script and
+//! local runners all look languages up here instead of assuming Rust.
+//!
+//! The built-in registry covers Rust, Python and JavaScript. Books can adjust
+//! or add languages in `book.toml`:
+//!
+//! ```toml
+//! [preprocessor.exercises]
+//! default_language = "python"
+//!
+//! [preprocessor.exercises.languages.go]
+//! highlight = "go"
+//! comment = "//"
+//! default_filename = "main_test.go"
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// Language assumed when a code block doesn't name one.
+pub const DEFAULT_LANGUAGE: &str = "rust";
+
+/// How user code (starter or solution) is combined with test code.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CombineStrategy {
+    /// Append the test code after the user code
+    #[default]
+    Append,
+
+    /// Append, dropping the test code's `fn main` when both define one
+    Rust,
+}
+
+impl std::fmt::Display for CombineStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CombineStrategy::Append => write!(f, "append"),
+            CombineStrategy::Rust => write!(f, "rust"),
+        }
+    }
+}
+
+impl std::str::FromStr for CombineStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "append" => Ok(CombineStrategy::Append),
+            "rust" => Ok(CombineStrategy::Rust),
+            _ => Err(format!("Invalid combine strategy: {}", s)),
+        }
+    }
+}
+
+/// Tool used to run a language's tests locally.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TestRunnerKind {
+    /// `cargo test`
+    Cargo,
+
+    /// pytest, falling back to unittest
+    Pytest,
+
+    /// `node --test`
+    Node,
+}
+
+impl TestRunnerKind {
+    /// Command shown to students for running the tests themselves.
+    pub fn command(&self) -> &'static str {
+        match self {
+            TestRunnerKind::Cargo => "cargo test",
+            TestRunnerKind::Pytest => "python3 -m pytest",
+            TestRunnerKind::Node => "node --test",
+        }
+    }
+}
+
+impl std::fmt::Display for TestRunnerKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TestRunnerKind::Cargo => write!(f, "cargo"),
+            TestRunnerKind::Pytest => write!(f, "pytest"),
+            TestRunnerKind::Node => write!(f, "node"),
+        }
+    }
+}
+
+impl std::str::FromStr for TestRunnerKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "cargo" => Ok(TestRunnerKind::Cargo),
+            "pytest" | "unittest" | "python" => Ok(TestRunnerKind::Pytest),
+            "node" => Ok(TestRunnerKind::Node),
+            _ => Err(format!("Invalid test runner: {}", s)),
+        }
+    }
+}
+
+/// Settings for one language.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LanguageSpec {
+    /// Canonical name (e.g., "python")
+    pub name: String,
+
+    /// Other names accepted in code fences and attributes (e.g., "py")
+    #[serde(default)]
+    pub aliases: Vec<String>,
+
+    /// File the combined code is written to when running tests
+    pub default_filename: Option<String>,
+
+    /// Highlight class suffix (`language-<highlight>`)
+    pub highlight: String,
+
+    /// Line comment prefix (e.g., "//" or "#")
+    pub comment: String,
+
+    /// Local test runner, if any
+    pub test_runner: Option<TestRunnerKind>,
+
+    /// Whether tests can run on the Rust Playground in the browser
+    pub playground: bool,
+
+    /// How user code is combined with test code
+    pub combine: CombineStrategy,
+
+    /// Prefix marking lines hidden from the student, e.g. `#` for rustdoc-style
+    /// `# ` lines. Code fences can set one with `hidelines=<prefix>`.
+    pub hidelines: Option<String>,
+
+    /// Statement that stands in for code removed from a starter derived
+    /// from the solution (e.g. `todo!()`); defaults to a `TODO` comment
+    pub placeholder: Option<String>,
+}
+
+impl LanguageSpec {
+    /// Create a spec with neutral defaults (no runner, no playground).
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            aliases: Vec::new(),
+            default_filename: None,
+            highlight: name.to_string(),
+            comment: "//".to_string(),
+            test_runner: None,
+            playground: false,
+            combine: CombineStrategy::Append,
+            hidelines: None,
+            placeholder: None,
+        }
+    }
+
+    /// Returns true if `name` is this language's name or one of its aliases.
+    pub fn matches(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name) || self.aliases.iter().any(|a| a.eq_ignore_ascii_case(name))
+    }
+
+    /// Combine user code with test code according to the combine strategy.
+    pub fn combine(&self, user_code: &str, test_code: &str) -> String {
+        if test_code.is_empty() {
+            return user_code.to_string();
+        }
+        match self.combine {
+            CombineStrategy::Append => format!("{}\n\n{}", user_code, test_code),
+            CombineStrategy::Rust => {
+                let main_re = regex::Regex::new(r"fn\s+main\s*\(").expect("valid regex");
+                if main_re.is_match(user_code) && main_re.is_match(test_code) {
+                    let strip_re = regex::Regex::new(r"fn\s+main\s*\([^)]*\)\s*\{[^}]*\}").expect("valid regex");
+                    format!("{}\n\n{}", user_code, strip_re.replace_all(test_code, ""))
+                } else {
+                    format!("{}\n\n{}", user_code, test_code)
+                }
+            }
+        }
+    }
+
+    /// Apply overrides from configuration.
+    pub fn apply(&mut self, overrides: &LanguageOverrides) {
+        if let Some(aliases) = &overrides.aliases {
+            self.aliases = aliases.clone();
+        }
+        if let Some(filename) = &overrides.default_filename {
+            self.default_filename = Some(filename.clone());
+        }
+        if let Some(highlight) = &overrides.highlight {
+            self.highlight = highlight.clone();
+        }
+        if let Some(comment) = &overrides.comment {
+            self.comment = comment.clone();
+        }
+        if let Some(runner) = overrides.test_runner {
+            self.test_runner = Some(runner);
+        }
+        if let Some(playground) = overrides.playground {
+            self.playground = playground;
+        }
+        if let Some(combine) = overrides.combine {
+            self.combine = combine;
+        }
+        if let Some(hidelines) = &overrides.hidelines {
+            self.hidelines = Some(hidelines.clone()).filter(|p| !p.is_empty());
+        }
+        if let Some(placeholder) = &overrides.placeholder {
+            self.placeholder = Some(placeholder.clone()).filter(|p| !p.is_empty());
+        }
+    }
+
+    /// The placeholder statement, or a `TODO` comment.
+    pub fn placeholder(&self) -> String {
+        self.placeholder.clone().unwrap_or_else(|| format!("{} TODO", self.comment))
+    }
+}
+
+/// Per-language settings from `[preprocessor.exercises.languages.<name>]`.
+///
+/// Unset fields keep the built-in value (or the [`LanguageSpec::new`]
+/// default for a new language).
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LanguageOverrides {
+    pub aliases: Option<Vec<String>>,
+    pub default_filename: Option<String>,
+    pub highlight: Option<String>,
+    pub comment: Option<String>,
+    pub test_runner: Option<TestRunnerKind>,
+    pub playground: Option<bool>,
+    pub combine: Option<CombineStrategy>,
+    pub hidelines: Option<String>,
+    pub placeholder: Option<String>,
+}
+
+/// The set of known languages.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanguageRegistry {
+    default_language: String,
+    specs: Vec<LanguageSpec>,
+}
+
+impl LanguageRegistry {
+    /// An empty registry whose default language is [`DEFAULT_LANGUAGE`].
+    pub fn new() -> Self {
+        Self {
+            default_language: DEFAULT_LANGUAGE.to_string(),
+            specs: Vec::new(),
+        }
+    }
+
+    /// A registry with the built-in Rust, Python and JavaScript specs.
+    pub fn with_defaults() -> Self {
+        let mut registry = Self::new();
+        registry.register(LanguageSpec {
+            aliases: vec!["rs".to_string()],
+            default_filename: Some("src/lib.rs".to_string()),
+            test_runner: Some(TestRunnerKind::Cargo),
+            playground: true,
+            combine: CombineStrategy::Rust,
+            hidelines: Some("#".to_string()),
+            placeholder: Some("todo!()".to_string()),
+            ..LanguageSpec::new("rust")
+        });
+        registry.register(LanguageSpec {
+            aliases: vec!["py".to_string(), "python3".to_string()],
+            default_filename: Some("test_exercise.py".to_string()),
+            comment: "#".to_string(),
+            test_runner: Some(TestRunnerKind::Pytest),
+            placeholder: Some("raise NotImplementedError".to_string()),
+            ..LanguageSpec::new("python")
+        });
+        registry.register(LanguageSpec {
+            aliases: vec!["js".to_string(), "node".to_string()],
+            default_filename: Some("exercise.test.js".to_string()),
+            test_runner: Some(TestRunnerKind::Node),
+            placeholder: Some("throw new Error(\"not implemented\");".to_string()),
+            ..LanguageSpec::new("javascript")
+        });
+        registry
+    }
+
+    /// Add a language, replacing any existing spec with the same name.
+    pub fn register(&mut self, spec: LanguageSpec) {
+        self.specs.retain(|s| s.name != spec.name);
+        self.specs.push(spec);
+    }
+
+    /// Look up a language by name or alias (case-insensitive).
+    pub fn get(&self, name: &str) -> Option<&LanguageSpec> {
+        self.specs.iter().find(|s| s.matches(name))
+    }
+
+    /// Language assumed when a code block doesn't name one.
+    pub fn default_language(&self) -> &str {
+        &self.default_language
+    }
+
+    /// Change the default language.
+    pub fn set_default_language(&mut self, name: &str) {
+        self.default_language = name.to_string();
+    }
+
+    /// All registered languages.
+    pub fn specs(&self) -> &[LanguageSpec] {
+        &self.specs
+    }
+
+    /// Apply configured overrides, creating languages that don't exist yet.
+    pub fn apply(&mut self, name: &str, overrides: &LanguageOverrides) {
+        match self.specs.iter_mut().find(|s| s.matches(name)) {
+            Some(spec) => spec.apply(overrides),
+            None => {
+                let mut spec = LanguageSpec::new(name);
+                spec.apply(overrides);
+                self.specs.push(spec);
+            }
+        }
+    }
+
+    /// Highlight class suffix for a language (the name itself if unknown).
+    pub fn highlight<'a>(&'a self, name: &'a str) -> &'a str {
+        self.get(name).map(|s| s.highlight.as_str()).unwrap_or(name)
+    }
+
+    /// Combine user and test code for a language (plain append if unknown).
+    pub fn combine(&self, language: &str, user_code: &str, test_code: &str) -> String {
+        match self.get(language) {
+            Some(spec) => spec.combine(user_code, test_code),
+            None => LanguageSpec::new(language).combine(user_code, test_code),
+        }
+    }
+}
+
+/// Remove hidden lines' markers from `code`.
+///
+/// Returns the code that runs and the (0-based) numbers of its hidden lines.
+/// The `#` prefix follows rustdoc: `# code` and a lone `#` are hidden, `##`
+/// escapes a literal `#`, and attributes such as `#[test]` are untouched.
+/// Any other prefix hides every line starting with it, as in mdBook.
+pub fn strip_hidden_lines(code: &str, prefix: &str) -> (String, Vec<usize>) {
+    let mut lines = Vec::new();
+    let mut hidden = Vec::new();
+    for (i, line) in code.lines().enumerate() {
+        let trimmed = line.trim_start();
+        let indent = &line[..line.len() - trimmed.len()];
+        if prefix == "#" {
+            if trimmed == "#" {
+                hidden.push(i);
+                lines.push(String::new());
+            } else if let Some(rest) = trimmed.strip_prefix("# ") {
+                hidden.push(i);
+                lines.push(format!("{}{}", indent, rest));
+            } else if let Some(rest) = trimmed.strip_prefix("##") {
+                lines.push(format!("{}#{}", indent, rest));
+            } else {
+                lines.push(line.to_string());
+            }
+        } else if let Some(rest) = trimmed.strip_prefix(prefix).filter(|_| !prefix.is_empty()) {
+            hidden.push(i);
+            lines.push(format!("{}{}", indent, rest));
+        } else {
+            lines.push(line.to_string());
+        }
+    }
+    (lines.join("\n"), hidden)
+}
+
+impl Default for LanguageRegistry {
+    fn default() -> Self {
+        Self::with_defaults()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_and_overrides() {
+        let mut registry = LanguageRegistry::default();
+        assert_eq!(registry.get("PY").unwrap().name, "python");
+        assert_eq!(registry.highlight("js"), "javascript");
+        assert_eq!(registry.highlight("haskell"), "haskell");
+        assert!(registry.get("rust").unwrap().playground);
+
+        registry.apply("python", &LanguageOverrides {
+            highlight: Some("py".to_string()),
+            ..Default::default()
+        });
+        registry.apply("go", &LanguageOverrides {
+            default_filename: Some("main_test.go".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(registry.highlight("python"), "py");
+        assert_eq!(registry.get("python").unwrap().comment, "#");
+        let go = registry.get("go").unwrap();
+        assert_eq!(go.default_filename.as_deref(), Some("main_test.go"));
+        assert!(go.test_runner.is_none());
+    }
+
+    #[test]
+    fn test_combine_strategies() {
+        let registry = LanguageRegistry::default();
+        let rust = registry.combine("rust", "fn main() {}\nfn add() {}", "fn main() { run(); }\n#[test]\nfn t() {}");
+        assert_eq!(rust.matches("fn main").count(), 1);
+        assert!(rust.contains("#[test]"));
+        let rust = registry.combine("rs", "fn add() {}", "#[test]\nfn t() {}");
+        assert_eq!(rust, "fn add() {}\n\n#[test]\nfn t() {}");
+
+        let python = registry.combine("python", "def f(): pass", "assert f() is None");
+        assert_eq!(python, "def f(): pass\n\nassert f() is None");
+    }
+    #[test]
+    fn test_strip_hidden_lines() {
+        let code = "# use std::fmt;\n#[derive(Debug)]\nstruct S;\n    # fn helper() {}\n## not hidden\n#";
+        let (stripped, hidden) = strip_hidden_lines(code, "#");
+        assert_eq!(stripped, "use std::fmt;\n#[derive(Debug)]\nstruct S;\n    fn helper() {}\n# not hidden\n");
+        assert_eq!(hidden, vec![0, 3, 5]);
+
+        let (stripped, hidden) = strip_hidden_lines("~import os\nprint(1)", "~");
+        assert_eq!(stripped, "import os\nprint(1)");
+        assert_eq!(hidden, vec![0]);
+
+        let registry = LanguageRegistry::default();
+        assert_eq!(registry.get("rust").unwrap().hidelines.as_deref(), Some("#"));
+        assert_eq!(registry.get("python").unwrap().hidelines, None);
+    }
+}
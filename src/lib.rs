@@ -32,7 +32,7 @@
 //!     ParsedExercise::Code(exercise) => {
 //!         assert_eq!(exercise.metadata.id, "hello-world");
 //!     }
-//!     ParsedExercise::UseCase(_) => panic!("Expected code exercise"),
+//!     _ => panic!("Expected code exercise"),
 //! }
 //! ````
 //!
@@ -43,8 +43,17 @@
 //! - `render` - HTML rendering
 //! - (no features) - Parser only, minimal dependencies
 
+pub mod collect;
+pub mod language;
+pub mod lint;
+pub mod mutate;
 pub mod parser;
+pub mod protocol;
+pub mod runner;
+pub mod server;
+pub mod stats;
 pub mod types;
+pub mod validate;
 
 #[cfg(feature = "render")]
 pub mod render;
@@ -52,8 +61,11 @@ pub mod render;
 #[cfg(feature = "preprocessor")]
 pub mod preprocessor;
 
+#[cfg(feature = "preprocessor")]
+pub mod cli;
+
 // Re-export main types for convenience
-pub use parser::{parse_exercise, ParseError};
+pub use parser::{parse_exercise, parse_exercise_with_options, ParseError, ParseOptions};
 pub use types::*;
 
 #[cfg(feature = "render")]
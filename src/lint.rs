@@ -0,0 +1,756 @@
+//! Configurable quality checks for exercise files.
+//!
+//! Unlike [`validate`](crate::validate), linting looks for exercises that
+//! parse fine but are likely mistakes: empty code blocks, solutions that
+//! equal the starter, rubric weights that don't add up, and so on.
+//!
+//! Every rule implements [`Lint`] and has a stable ID. A [`LintRegistry`]
+//! holds the rules together with their level (`allow`, `warn` or `deny`),
+//! which books can override in `book.toml`:
+//!
+//! ```toml
+//! [preprocessor.exercises.lints]
+//! starter-missing-todo = "allow"
+//! solution-matches-starter = "deny"
+//! ```
+
+use crate::collect::ExerciseFile;
+use crate::language::LanguageRegistry;
+use crate::protocol::playground_missing_crates;
+use crate::types::*;
+use crate::validate::{exercise_id_and_prereqs, Diagnostic, Severity};
+use std::collections::HashSet;
+
+/// How a lint rule is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintLevel {
+    /// Rule is disabled
+    Allow,
+    /// Findings are reported as warnings
+    Warn,
+    /// Findings are reported as errors
+    Deny,
+}
+
+impl std::fmt::Display for LintLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LintLevel::Allow => write!(f, "allow"),
+            LintLevel::Warn => write!(f, "warn"),
+            LintLevel::Deny => write!(f, "deny"),
+        }
+    }
+}
+
+impl std::str::FromStr for LintLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "allow" => Ok(LintLevel::Allow),
+            "warn" => Ok(LintLevel::Warn),
+            "deny" => Ok(LintLevel::Deny),
+            _ => Err(format!("Invalid lint level: {}", s)),
+        }
+    }
+}
+
+/// A problem reported by a single lint rule.
+#[derive(Debug, Clone, Default)]
+pub struct LintFinding {
+    /// 1-based line number, when known
+    pub line: Option<usize>,
+
+    /// Human-readable description
+    pub message: String,
+
+    /// Suggested fix, when one is available
+    pub suggestion: Option<String>,
+}
+
+impl LintFinding {
+    fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            ..Default::default()
+        }
+    }
+
+    fn suggest(mut self, suggestion: impl Into<String>) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+}
+
+/// A lint rule.
+pub trait Lint {
+    /// Stable rule ID used in configuration and output (e.g., "empty-code-block").
+    fn id(&self) -> &'static str;
+
+    /// One-line description of what the rule checks.
+    fn description(&self) -> &'static str;
+
+    /// Level used when the book doesn't configure one.
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Warn
+    }
+
+    /// Check a single exercise file, with the book's languages.
+    fn check(&self, file: &ExerciseFile, languages: &LanguageRegistry) -> Vec<LintFinding>;
+}
+
+/// A set of lint rules with their configured levels.
+pub struct LintRegistry {
+    rules: Vec<(Box<dyn Lint>, LintLevel)>,
+    languages: LanguageRegistry,
+}
+
+impl LintRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            languages: LanguageRegistry::default(),
+        }
+    }
+
+    /// Create a registry with all built-in rules at their default levels.
+    pub fn with_default_rules() -> Self {
+        let mut registry = Self::new();
+        registry.register(Box::new(EmptyCodeBlock));
+        registry.register(Box::new(SolutionMatchesStarter));
+        registry.register(Box::new(StarterMissingTodo));
+        registry.register(Box::new(HintLevels));
+        registry.register(Box::new(UnavailablePlaygroundCrate));
+        registry.register(Box::new(UnreferencedObjective));
+        registry.register(Box::new(CriteriaWeights));
+        registry.register(Box::new(PassThresholdRange));
+        registry.register(Box::new(WordLimits));
+        registry
+    }
+
+    /// Add a rule at its default level.
+    pub fn register(&mut self, rule: Box<dyn Lint>) {
+        let level = rule.default_level();
+        self.rules.push((rule, level));
+    }
+
+    /// Override the level of a rule. Returns an error for unknown rule IDs.
+    pub fn set_level(&mut self, id: &str, level: LintLevel) -> Result<(), String> {
+        match self.rules.iter_mut().find(|(rule, _)| rule.id() == id) {
+            Some((_, current)) => {
+                *current = level;
+                Ok(())
+            }
+            None => Err(format!("Unknown lint rule: {}", id)),
+        }
+    }
+
+    /// Use a book's languages (with its aliases and settings) instead of
+    /// the built-in ones.
+    pub fn set_languages(&mut self, languages: LanguageRegistry) {
+        self.languages = languages;
+    }
+
+    /// The registered rules and their levels.
+    pub fn rules(&self) -> impl Iterator<Item = (&dyn Lint, LintLevel)> {
+        self.rules.iter().map(|(rule, level)| (rule.as_ref(), *level))
+    }
+
+    /// Run every enabled rule over the given files.
+    pub fn run(&self, files: &[ExerciseFile]) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        for file in files {
+            let exercise_id = file
+                .exercise()
+                .map(|p| exercise_id_and_prereqs(p).0.to_string());
+            for (rule, level) in &self.rules {
+                let severity = match level {
+                    LintLevel::Allow => continue,
+                    LintLevel::Warn => Severity::Warning,
+                    LintLevel::Deny => Severity::Error,
+                };
+                for finding in rule.check(file, &self.languages) {
+                    diagnostics.push(Diagnostic {
+                        severity,
+                        code: rule.id().to_string(),
+                        file: file.path.clone(),
+                        line: finding.line,
+                        exercise_id: exercise_id.clone(),
+                        message: finding.message,
+                        suggestion: finding.suggestion,
+                    });
+                }
+            }
+        }
+        diagnostics
+    }
+}
+
+impl Default for LintRegistry {
+    fn default() -> Self {
+        Self::with_default_rules()
+    }
+}
+
+/// Lint a set of loaded exercise files with the default rules.
+pub fn lint_files(files: &[ExerciseFile]) -> Vec<Diagnostic> {
+    LintRegistry::with_default_rules().run(files)
+}
+
+// --- Rules ---
+
+/// Starter/solution/tests/parsons blocks without code are silently dropped by the parser.
+pub struct EmptyCodeBlock;
+
+impl Lint for EmptyCodeBlock {
+    fn id(&self) -> &'static str {
+        "empty-code-block"
+    }
+
+    fn description(&self) -> &'static str {
+        "starter, solution, tests or parsons block without a non-empty fenced code block"
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Deny
+    }
+
+    fn check(&self, file: &ExerciseFile, _languages: &LanguageRegistry) -> Vec<LintFinding> {
+        empty_code_blocks(&file.source)
+            .into_iter()
+            .map(|(line, directive)| LintFinding {
+                line: Some(line),
+                ..LintFinding::new(format!("`::: {}` block has no code and will be ignored", directive))
+                    .suggest(format!("add a fenced code block inside `::: {}` or remove the block", directive))
+            })
+            .collect()
+    }
+}
+
+/// A solution that equals the starter means the exercise is already solved.
+pub struct SolutionMatchesStarter;
+
+impl Lint for SolutionMatchesStarter {
+    fn id(&self) -> &'static str {
+        "solution-matches-starter"
+    }
+
+    fn description(&self) -> &'static str {
+        "solution code is identical to the starter code"
+    }
+
+    fn check(&self, file: &ExerciseFile, _languages: &LanguageRegistry) -> Vec<LintFinding> {
+        let Some(ParsedExercise::Code(ex)) = file.exercise() else { return vec![] };
+        let (Some(starter), Some(solution)) = (&ex.starter, &ex.solution) else { return vec![] };
+        if normalize_code(&starter.code) == normalize_code(&solution.code) {
+            vec![LintFinding::new("solution is identical to the starter code")
+                .suggest("replace the parts students should write in the starter with `todo!()`")]
+        } else {
+            vec![]
+        }
+    }
+}
+
+/// Rust starters should leave `todo!()` where students write code.
+pub struct StarterMissingTodo;
+
+impl Lint for StarterMissingTodo {
+    fn id(&self) -> &'static str {
+        "starter-missing-todo"
+    }
+
+    fn description(&self) -> &'static str {
+        "Rust starter code without a `todo!()` placeholder"
+    }
+
+    fn check(&self, file: &ExerciseFile, languages: &LanguageRegistry) -> Vec<LintFinding> {
+        let Some(ParsedExercise::Code(ex)) = file.exercise() else { return vec![] };
+        let Some(starter) = &ex.starter else { return vec![] };
+        // Aliases such as `rs` count as Rust too
+        let is_rust = languages.get(&starter.language).map(|spec| spec.name == "rust").unwrap_or(false);
+        if !is_rust {
+            return vec![];
+        }
+        if starter.code.contains("todo!(") || starter.code.contains("unimplemented!(") {
+            return vec![];
+        }
+        vec![LintFinding::new("starter code has no `todo!()` placeholder")
+            .suggest("mark the code students should write with `todo!()` so the starter compiles")]
+    }
+}
+
+/// Hints should be numbered 1, 2, 3, ... without gaps or duplicates.
+pub struct HintLevels;
+
+impl Lint for HintLevels {
+    fn id(&self) -> &'static str {
+        "hint-levels"
+    }
+
+    fn description(&self) -> &'static str {
+        "hint levels are not progressive (1, 2, 3, ...)"
+    }
+
+    fn check(&self, file: &ExerciseFile, _languages: &LanguageRegistry) -> Vec<LintFinding> {
+        let hints = match file.exercise() {
+            Some(ParsedExercise::Code(ex)) => &ex.hints,
+            Some(ParsedExercise::UseCase(ex)) => &ex.hints,
+            Some(ParsedExercise::Quiz(ex)) => &ex.hints,
+            None => return vec![],
+        };
+        let levels: Vec<u8> = hints.iter().map(|h| h.level).collect();
+        let expected: Vec<u8> = (1..=levels.len() as u8).collect();
+        if levels == expected {
+            return vec![];
+        }
+        let found: Vec<String> = levels.iter().map(u8::to_string).collect();
+        vec![LintFinding::new(format!("hint levels are [{}]", found.join(", ")))
+            .suggest(format!("number hints consecutively starting at 1 (1..={})", levels.len()))]
+    }
+}
+
+/// Playground tests can only use the crates the Rust Playground provides.
+pub struct UnavailablePlaygroundCrate;
+
+impl Lint for UnavailablePlaygroundCrate {
+    fn id(&self) -> &'static str {
+        "unavailable-playground-crate"
+    }
+
+    fn description(&self) -> &'static str {
+        "dependency of a playground exercise that the Rust Playground doesn't provide"
+    }
+
+    fn check(&self, file: &ExerciseFile, languages: &LanguageRegistry) -> Vec<LintFinding> {
+        let Some(ParsedExercise::Code(ex)) = file.exercise() else { return vec![] };
+        let playground_language = ex
+            .tests
+            .as_ref()
+            .filter(|t| t.mode == TestMode::Playground)
+            .map(|t| &t.language)
+            .or(ex.io_tests.as_ref().filter(|t| t.mode == TestMode::Playground).map(|t| &t.language));
+        let Some(language) = playground_language else { return vec![] };
+        if !languages.get(language).map(|spec| spec.playground).unwrap_or(false) {
+            return vec![];
+        }
+        playground_missing_crates(&ex.metadata.dependencies)
+            .into_iter()
+            .map(|dep| {
+                LintFinding::new(format!("crate `{}` is not available on the Rust Playground", dep.name))
+                    .suggest("use `mode=local` for these tests, or configure an `execution_backend` that has the crate")
+            })
+            .collect()
+    }
+}
+
+/// Objectives that nothing in the exercise relates to.
+pub struct UnreferencedObjective;
+
+impl Lint for UnreferencedObjective {
+    fn id(&self) -> &'static str {
+        "unreferenced-objective"
+    }
+
+    fn description(&self) -> &'static str {
+        "learning objective whose key terms appear nowhere else in the exercise"
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Allow
+    }
+
+    fn check(&self, file: &ExerciseFile, _languages: &LanguageRegistry) -> Vec<LintFinding> {
+        let (objectives, body) = match file.exercise() {
+            Some(ParsedExercise::Code(ex)) => {
+                let mut body = vec![ex.description.clone()];
+                body.extend(ex.hints.iter().map(|h| h.content.clone()));
+                body.extend(ex.starter.iter().map(|s| s.code.clone()));
+                body.extend(ex.solution.iter().flat_map(|s| [s.code.clone(), s.explanation.clone().unwrap_or_default()]));
+                body.extend(ex.tests.iter().map(|t| t.code.clone()));
+                body.extend(ex.parsons.iter().map(|p| p.code()));
+                body.extend(ex.discussion.iter().flatten().cloned());
+                body.extend(ex.reflection.iter().flatten().cloned());
+                (&ex.objectives, body)
+            }
+            Some(ParsedExercise::UseCase(ex)) => {
+                let mut body = vec![ex.description.clone(), ex.scenario.content.clone(), ex.prompt.prompt.clone()];
+                body.extend(ex.prompt.aspects.iter().cloned());
+                body.extend(ex.hints.iter().map(|h| h.content.clone()));
+                body.extend(ex.evaluation.key_points.iter().cloned());
+                body.extend(ex.context.iter().cloned());
+                (&ex.objectives, body)
+            }
+            Some(ParsedExercise::Quiz(ex)) => {
+                let mut body = vec![ex.description.clone()];
+                for question in &ex.questions {
+                    body.push(question.prompt.clone());
+                    body.extend(question.explanation.iter().cloned());
+                    for option in &question.options {
+                        body.push(option.text.clone());
+                        body.extend(option.explanation.iter().cloned());
+                    }
+                }
+                body.extend(ex.hints.iter().map(|h| h.content.clone()));
+                (&ex.objectives, body)
+            }
+            None => return vec![],
+        };
+        let Some(objectives) = objectives else { return vec![] };
+
+        let body_words: HashSet<String> = body.iter().flat_map(|t| key_terms(t)).collect();
+        objectives
+            .thinking
+            .iter()
+            .chain(&objectives.doing)
+            .filter(|objective| {
+                let terms = key_terms(objective);
+                !terms.is_empty() && terms.iter().all(|t| !body_words.contains(t))
+            })
+            .map(|objective| {
+                LintFinding::new(format!("objective '{}' is not referenced by the exercise", objective))
+                    .suggest("mention the objective's concepts in the description, hints or reflection, or drop it")
+            })
+            .collect()
+    }
+}
+
+/// UseCase rubric weights should add up to 100.
+pub struct CriteriaWeights;
+
+impl Lint for CriteriaWeights {
+    fn id(&self) -> &'static str {
+        "criteria-weights"
+    }
+
+    fn description(&self) -> &'static str {
+        "UseCase evaluation criteria weights do not sum to 100"
+    }
+
+    fn check(&self, file: &ExerciseFile, _languages: &LanguageRegistry) -> Vec<LintFinding> {
+        let Some(ParsedExercise::UseCase(ex)) = file.exercise() else { return vec![] };
+        let criteria = &ex.evaluation.criteria;
+        if criteria.is_empty() {
+            return vec![];
+        }
+        let total: u32 = criteria.iter().map(|c| c.weight).sum();
+        if total == 100 {
+            return vec![];
+        }
+        vec![LintFinding::new(format!("criteria weights sum to {} instead of 100", total))
+            .suggest("adjust the `weight` of each criterion so they add up to 100")]
+    }
+}
+
+/// `pass_threshold` is a fraction, not a percentage.
+pub struct PassThresholdRange;
+
+impl Lint for PassThresholdRange {
+    fn id(&self) -> &'static str {
+        "pass-threshold-range"
+    }
+
+    fn description(&self) -> &'static str {
+        "UseCase pass_threshold outside 0.0..=1.0"
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Deny
+    }
+
+    fn check(&self, file: &ExerciseFile, _languages: &LanguageRegistry) -> Vec<LintFinding> {
+        let Some(ParsedExercise::UseCase(ex)) = file.exercise() else { return vec![] };
+        let Some(threshold) = ex.evaluation.pass_threshold else { return vec![] };
+        if (0.0..=1.0).contains(&threshold) {
+            return vec![];
+        }
+        let mut finding = LintFinding::new(format!("pass_threshold {} is outside 0.0..=1.0", threshold));
+        if (1.0..=100.0).contains(&threshold) {
+            finding = finding.suggest(format!("use a fraction, e.g. `pass_threshold: {}`", threshold / 100.0));
+        }
+        vec![finding]
+    }
+}
+
+/// `min_words` must not exceed `max_words`.
+pub struct WordLimits;
+
+impl Lint for WordLimits {
+    fn id(&self) -> &'static str {
+        "word-limits"
+    }
+
+    fn description(&self) -> &'static str {
+        "UseCase min_words is greater than max_words"
+    }
+
+    fn default_level(&self) -> LintLevel {
+        LintLevel::Deny
+    }
+
+    fn check(&self, file: &ExerciseFile, _languages: &LanguageRegistry) -> Vec<LintFinding> {
+        let Some(ParsedExercise::UseCase(ex)) = file.exercise() else { return vec![] };
+        match (ex.evaluation.min_words, ex.evaluation.max_words) {
+            (Some(min), Some(max)) if min > max => {
+                vec![LintFinding::new(format!("min_words ({}) is greater than max_words ({})", min, max))
+                    .suggest("swap the values or raise `max_words`")]
+            }
+            _ => vec![],
+        }
+    }
+}
+
+// --- Helpers ---
+
+/// Directives whose content must contain a non-empty fenced code block.
+const CODE_DIRECTIVES: &[&str] = &["starter", "solution", "tests", "hidden-tests", "parsons"];
+
+/// Find starter/solution/tests/parsons blocks without a non-empty fenced code block.
+///
+/// Returns the 1-based line of each offending directive and its name.
+fn empty_code_blocks(source: &str) -> Vec<(usize, &'static str)> {
+    let mut found = Vec::new();
+    let mut lines = source.lines().enumerate();
+    while let Some((i, line)) = lines.next() {
+        let t = line.trim_start();
+        let Some(directive) = CODE_DIRECTIVES
+            .iter()
+            .find(|d| t.strip_prefix(":::").map(|r| r.trim_start().starts_with(**d)).unwrap_or(false))
+        else {
+            continue;
+        };
+
+        // Scan until the closing ':::' and check the fenced code inside.
+        // Blocks with `source=` get their code from a file.
+        let sourced = t.contains(" source=");
+        let mut saw_fence = false;
+        let mut in_fence = false;
+        let mut code_empty = true;
+        for (_, l) in lines.by_ref() {
+            let trimmed = l.trim();
+            if !in_fence && trimmed == ":::" {
+                break;
+            }
+            if trimmed.starts_with("```") {
+                saw_fence = true;
+                in_fence = !in_fence;
+            } else if in_fence && !trimmed.is_empty() {
+                code_empty = false;
+            }
+        }
+        if !sourced && (!saw_fence || code_empty) {
+            found.push((i + 1, *directive));
+        }
+    }
+    found
+}
+
+/// Collapse whitespace so formatting differences don't matter when comparing code.
+fn normalize_code(code: &str) -> String {
+    code.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Lowercased significant words (5+ letters) of a piece of text.
+fn key_terms(text: &str) -> HashSet<String> {
+    const STOPWORDS: &[&str] = &["about", "their", "there", "these", "those", "which", "while", "would", "should", "could", "using", "understand", "learn", "write"];
+    text.split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|w| w.len() >= 5)
+        .map(str::to_lowercase)
+        .filter(|w| !STOPWORDS.contains(&w.as_str()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::LanguageOverrides;
+    use crate::parser::parse_exercise;
+    use std::path::PathBuf;
+
+    fn file(source: &str) -> ExerciseFile {
+        ExerciseFile {
+            path: PathBuf::from("ex.md"),
+            source: source.to_string(),
+            parsed: parse_exercise(source),
+        }
+    }
+
+    #[test]
+    fn test_empty_code_blocks() {
+        let source = r#"::: exercise
+id: x
+:::
+
+::: starter
+```rust
+fn main() {}
+```
+:::
+
+::: tests
+```rust
+```
+:::
+
+::: solution
+No code here.
+:::
+
+::: hidden-tests source="../code/tests.rs"
+:::
+"#;
+        let found = empty_code_blocks(source);
+        assert_eq!(found, vec![(11, "tests"), (16, "solution")]);
+    }
+
+    #[test]
+    fn test_code_exercise_rules() {
+        let source = r#"::: exercise
+id: x
+dependencies:
+  - rand = "0.8"
+  - my_private_crate = "1"
+:::
+
+::: starter
+```rust
+fn add(a: i32, b: i32) -> i32 { a + b }
+```
+:::
+
+::: hint level=1
+One
+:::
+
+::: hint level=3
+Three
+:::
+
+::: solution
+```rust
+fn add(a: i32,  b: i32) -> i32 {
+    a + b
+}
+```
+:::
+
+::: tests mode=playground
+```rust
+#[test]
+fn t() { assert_eq!(add(1, 2), 3); }
+```
+:::
+"#;
+        let diagnostics = lint_files(&[file(source)]);
+        let codes: Vec<&str> = diagnostics.iter().map(|d| d.code.as_str()).collect();
+        assert!(codes.contains(&"solution-matches-starter"));
+        assert!(codes.contains(&"starter-missing-todo"));
+        assert!(codes.contains(&"hint-levels"));
+        let crates: Vec<&str> = diagnostics
+            .iter()
+            .filter(|d| d.code == "unavailable-playground-crate")
+            .map(|d| d.message.as_str())
+            .collect();
+        assert_eq!(crates, vec!["crate `my_private_crate` is not available on the Rust Playground"]);
+        assert!(diagnostics.iter().all(|d| d.severity == Severity::Warning));
+    }
+
+    #[test]
+    fn test_starter_missing_todo_uses_book_languages() {
+        let source = r#"::: exercise
+id: x
+:::
+
+::: starter
+```ferris
+fn add(a: i32, b: i32) -> i32 { a + b }
+```
+:::
+"#;
+        let files = [file(source)];
+        let flagged = |registry: &LintRegistry| registry.run(&files).iter().any(|d| d.code == "starter-missing-todo");
+        let mut registry = LintRegistry::with_default_rules();
+        assert!(!flagged(&registry));
+
+        let mut languages = LanguageRegistry::default();
+        let aliases = LanguageOverrides {
+            aliases: Some(vec!["rs".to_string(), "ferris".to_string()]),
+            ..LanguageOverrides::default()
+        };
+        languages.apply("rust", &aliases);
+        registry.set_languages(languages);
+        assert!(flagged(&registry));
+    }
+
+    #[test]
+    fn test_unavailable_playground_crate_uses_book_languages() {
+        let source = r#"::: exercise
+id: x
+dependencies:
+  - my_private_crate = "1"
+:::
+
+::: tests mode=playground
+```ferris
+#[test]
+fn t() {}
+```
+:::
+"#;
+        let files = [file(source)];
+        let flagged = |registry: &LintRegistry| registry.run(&files).iter().any(|d| d.code == "unavailable-playground-crate");
+        let mut registry = LintRegistry::with_default_rules();
+        assert!(!flagged(&registry));
+
+        let mut languages = LanguageRegistry::default();
+        let aliases = LanguageOverrides {
+            aliases: Some(vec!["rs".to_string(), "ferris".to_string()]),
+            ..LanguageOverrides::default()
+        };
+        languages.apply("rust", &aliases);
+        registry.set_languages(languages.clone());
+        assert!(flagged(&registry));
+
+        // A book can take a language off the playground
+        let local = LanguageOverrides {
+            playground: Some(false),
+            ..LanguageOverrides::default()
+        };
+        languages.apply("rust", &local);
+        registry.set_languages(languages);
+        assert!(!flagged(&registry));
+    }
+
+    #[test]
+    fn test_usecase_rules_and_levels() {
+        let source = r#"::: usecase
+id: uc
+:::
+
+::: evaluation
+min_words: 300
+max_words: 100
+pass_threshold: 70
+criteria:
+  - name: A
+    weight: 50
+  - name: B
+    weight: 30
+:::
+"#;
+        let files = [file(source)];
+        let mut registry = LintRegistry::with_default_rules();
+        let diagnostics = registry.run(&files);
+        let find = |diags: &[Diagnostic], code: &str| diags.iter().find(|d| d.code == code).map(|d| d.severity);
+        assert_eq!(find(&diagnostics, "criteria-weights"), Some(Severity::Warning));
+        assert_eq!(find(&diagnostics, "pass-threshold-range"), Some(Severity::Error));
+        assert_eq!(find(&diagnostics, "word-limits"), Some(Severity::Error));
+
+        registry.set_level("criteria-weights", LintLevel::Deny).unwrap();
+        registry.set_level("word-limits", LintLevel::Allow).unwrap();
+        assert!(registry.set_level("no-such-rule", LintLevel::Deny).is_err());
+        let diagnostics = registry.run(&files);
+        assert_eq!(find(&diagnostics, "criteria-weights"), Some(Severity::Error));
+        assert_eq!(find(&diagnostics, "word-limits"), None);
+    }
+}
@@ -1,6 +1,9 @@
 //! mdbook-exercises preprocessor binary.
 //!
-//! This binary is invoked by mdBook during the build process.
+//! This binary is invoked by mdBook during the build process. It also
+//! provides subcommands (`validate`, `lint`, `stats`, `test`, `mutate`) for
+//! checking exercises outside of a book build (e.g., in CI), and `runner`
+//! for serving test runs over HTTP.
 
 use std::env;
 use std::io;
@@ -9,6 +12,8 @@ use std::process;
 #[cfg(feature = "preprocessor")]
 use mdbook::preprocess::{CmdPreprocessor, Preprocessor};
 #[cfg(feature = "preprocessor")]
+use mdbook_exercises::cli;
+#[cfg(feature = "preprocessor")]
 use mdbook_exercises::preprocessor::FullExercisesPreprocessor;
 
 fn main() {
@@ -22,6 +27,10 @@ fn main() {
                 let renderer = args.get(2).map(|s| s.as_str()).unwrap_or("");
                 handle_supports(renderer);
             }
+            #[cfg(feature = "preprocessor")]
+            command if cli::is_command(command) => {
+                process::exit(cli::run(command, &args[2..]));
+            }
             _ => {
                 // Unknown command, try to process as preprocessor
                 #[cfg(feature = "preprocessor")]
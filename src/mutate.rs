@@ -0,0 +1,392 @@
+//! Mutation testing of exercise test suites.
+//!
+//! Weak tests let students pass with wrong code. This module measures test
+//! strength by applying small source mutations to an exercise's solution
+//! (flipped operators, replaced return values, deleted statements) and
+//! checking that the exercise's tests catch each mutant.
+//!
+//! Mutations are purely textual. A mutant that no longer compiles is
+//! reported as *unviable* rather than caught, so an imprecise mutation never
+//! counts in the tests' favour.
+
+use crate::collect::ExerciseFile;
+use crate::language::TestRunnerKind;
+use crate::runner::{run_suite, runner_for, target_filename, RunOptions, RunStatus, TestSuite};
+use crate::types::*;
+use crate::validate::exercise_id_and_prereqs;
+use serde::Serialize;
+use std::path::PathBuf;
+
+/// A mutated copy of some source code.
+#[derive(Debug, Clone, Serialize)]
+pub struct Mutant {
+    /// 1-based line of the mutation in the original code
+    pub line: usize,
+
+    /// What was changed (e.g., "replace `+` with `-`")
+    pub description: String,
+
+    /// The full mutated code
+    #[serde(skip)]
+    pub code: String,
+}
+
+/// Mutation testing report for one exercise.
+#[derive(Debug, Clone, Serialize)]
+pub struct MutationReport {
+    /// Exercise ID
+    pub id: String,
+
+    /// File the exercise came from
+    pub file: PathBuf,
+
+    /// Why the exercise was skipped, if it was
+    pub skipped: Option<String>,
+
+    /// Number of mutants caught by the tests
+    pub killed: usize,
+
+    /// Number of mutants that didn't compile
+    pub unviable: usize,
+
+    /// Mutants the tests did not catch
+    pub survived: Vec<Mutant>,
+}
+
+impl MutationReport {
+    /// Fraction of viable mutants that were killed (1.0 when there were none).
+    pub fn score(&self) -> f64 {
+        let viable = self.killed + self.survived.len();
+        if viable == 0 {
+            1.0
+        } else {
+            self.killed as f64 / viable as f64
+        }
+    }
+}
+
+/// Run mutation testing on every code exercise that has a solution and tests.
+pub fn mutate_exercises(files: &[ExerciseFile], options: &RunOptions, max_mutants: usize) -> Vec<MutationReport> {
+    files
+        .iter()
+        .filter(|f| matches!(f.exercise(), Some(ParsedExercise::Code(_))))
+        .map(|f| mutate_exercise(f, options, max_mutants))
+        .collect()
+}
+
+/// Run mutation testing on a single exercise.
+pub fn mutate_exercise(file: &ExerciseFile, options: &RunOptions, max_mutants: usize) -> MutationReport {
+    let mut report = MutationReport {
+        id: file.exercise().map(|p| exercise_id_and_prereqs(p).0.to_string()).unwrap_or_default(),
+        file: file.path.clone(),
+        skipped: None,
+        killed: 0,
+        unviable: 0,
+        survived: Vec::new(),
+    };
+    let Some(ParsedExercise::Code(exercise)) = file.exercise() else {
+        report.skipped = Some("not a code exercise".to_string());
+        return report;
+    };
+    let tests = exercise.tests.as_ref().or(exercise.hidden_tests.as_ref());
+    let (Some(solution), Some(tests)) = (&exercise.solution, tests) else {
+        report.skipped = Some("needs both a solution and tests".to_string());
+        return report;
+    };
+    let spec = options.languages.get(&tests.language);
+    let Some(spec) = spec.filter(|s| s.test_runner == Some(TestRunnerKind::Cargo) && s.matches(&solution.language)) else {
+        report.skipped = Some("mutation testing supports Rust only".to_string());
+        return report;
+    };
+
+    let runner = runner_for(TestRunnerKind::Cargo);
+    let filename = target_filename(spec, runner.as_ref(), exercise.starter.as_ref());
+    let options = &RunOptions {
+        toolchain: tests.toolchain.or(options.toolchain),
+        dependencies: exercise.metadata.dependencies.clone(),
+        files: exercise.solution_files(solution),
+        ..options.clone()
+    };
+    let suite = TestSuite {
+        visible: exercise.tests.as_ref().map(|t| t.code.as_str()).unwrap_or_default(),
+        hidden: exercise.hidden_tests.as_ref().map(|t| t.code.as_str()),
+        io: exercise.io_tests.as_ref().map(|t| t.cases.as_slice()).unwrap_or_default(),
+        redact_hidden: false,
+    };
+    for mutant in generate_mutants(&solution.code).into_iter().take(max_mutants) {
+        match run_suite(runner.as_ref(), spec, &mutant.code, suite, &filename, options).map(|r| r.status) {
+            Ok(RunStatus::Passed) => report.survived.push(mutant),
+            Ok(RunStatus::CompileError) | Err(_) => report.unviable += 1,
+            Ok(RunStatus::Failed) | Ok(RunStatus::TimedOut) => report.killed += 1,
+        }
+    }
+    report
+}
+
+/// Binary operators and their replacements. Only mutated when surrounded by
+/// whitespace, which keeps generics, references and paths out of the way.
+/// Two-character operators come first so `<=` isn't matched as `<`.
+const OPERATOR_MUTATIONS: &[(&str, &str)] = &[
+    ("==", "!="),
+    ("!=", "=="),
+    ("<=", "<"),
+    (">=", ">"),
+    ("&&", "||"),
+    ("||", "&&"),
+    ("+=", "-="),
+    ("-=", "+="),
+    ("<", "<="),
+    (">", ">="),
+    ("+", "-"),
+    ("-", "+"),
+    ("*", "/"),
+    ("/", "*"),
+    ("%", "*"),
+];
+
+/// Generate mutants of Rust source code.
+pub fn generate_mutants(code: &str) -> Vec<Mutant> {
+    let mask = code_mask(code);
+    let mut mutants = Vec::new();
+
+    // Flip operators
+    let bytes = code.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let at_token_start = i > 0 && bytes[i - 1].is_ascii_whitespace();
+        let mut advanced = false;
+        if mask[i] && at_token_start {
+            for (from, to) in OPERATOR_MUTATIONS {
+                let end = i + from.len();
+                if code[i..].starts_with(from) && bytes.get(end).map(|b| b.is_ascii_whitespace()).unwrap_or(false) {
+                    mutants.push(Mutant {
+                        line: line_of(code, i),
+                        description: format!("replace `{}` with `{}`", from, to),
+                        code: format!("{}{}{}", &code[..i], to, &code[end..]),
+                    });
+                    i = end;
+                    advanced = true;
+                    break;
+                }
+            }
+        }
+        if !advanced {
+            i += 1;
+        }
+    }
+
+    // Flip boolean literals
+    for (word, replacement) in [("true", "false"), ("false", "true")] {
+        for (pos, _) in code.match_indices(word) {
+            if mask[pos] && is_word_at(code, pos, word.len()) {
+                mutants.push(Mutant {
+                    line: line_of(code, pos),
+                    description: format!("replace `{}` with `{}`", word, replacement),
+                    code: format!("{}{}{}", &code[..pos], replacement, &code[pos + word.len()..]),
+                });
+            }
+        }
+    }
+
+    // Replace function bodies that return a value with `Default::default()`
+    let fn_re = regex::Regex::new(r"fn\s+(\w+)[^{;]*->\s*[^{;]+\{").expect("valid regex");
+    for m in fn_re.find_iter(code) {
+        let name = fn_re.captures(m.as_str()).map(|c| c[1].to_string()).unwrap_or_default();
+        if !mask[m.start()] || name == "main" {
+            continue;
+        }
+        let open = m.end() - 1;
+        if let Some(close) = matching_brace(code, &mask, open) {
+            mutants.push(Mutant {
+                line: line_of(code, m.start()),
+                description: format!("replace body of `{}` with `Default::default()`", name),
+                code: format!("{}{{ Default::default() }}{}", &code[..open], &code[close + 1..]),
+            });
+        }
+    }
+
+    // Delete statements inside blocks
+    let mut offset = 0;
+    for (idx, line) in code.split_inclusive('\n').enumerate() {
+        let trimmed = line.trim();
+        let indented = line.starts_with(' ') || line.starts_with('\t');
+        let is_declaration = ["let ", "use ", "pub ", "mod ", "const ", "static ", "type ", "//"]
+            .iter()
+            .any(|p| trimmed.starts_with(p));
+        if indented && trimmed.ends_with(';') && !is_declaration && mask[offset + line.find(trimmed).unwrap_or(0)] {
+            mutants.push(Mutant {
+                line: idx + 1,
+                description: format!("delete statement `{}`", trimmed),
+                code: format!("{}{}", &code[..offset], &code[offset + line.len()..]),
+            });
+        }
+        offset += line.len();
+    }
+
+    mutants.sort_by_key(|m| m.line);
+    mutants
+}
+
+/// For each byte, whether it is code (not inside a comment, string or char literal).
+fn code_mask(code: &str) -> Vec<bool> {
+    let bytes = code.as_bytes();
+    let mut mask = vec![true; bytes.len()];
+    let mut i = 0;
+    while i < bytes.len() {
+        let start = i;
+        let end = if bytes[i..].starts_with(b"//") {
+            bytes[i..].iter().position(|&b| b == b'\n').map(|p| i + p).unwrap_or(bytes.len())
+        } else if bytes[i..].starts_with(b"/*") {
+            code[i + 2..].find("*/").map(|p| i + 2 + p + 2).unwrap_or(bytes.len())
+        } else if let Some(len) = raw_string_len(&bytes[i..]).filter(|_| i == 0 || !is_ident_byte(bytes[i - 1])) {
+            i + len
+        } else if bytes[i] == b'"' {
+            let mut j = i + 1;
+            while j < bytes.len() && bytes[j] != b'"' {
+                j += if bytes[j] == b'\\' { 2 } else { 1 };
+            }
+            (j + 1).min(bytes.len())
+        } else if bytes[i] == b'\'' && (bytes.get(i + 2) == Some(&b'\'') || bytes.get(i + 1) == Some(&b'\\')) {
+            // Char literal ('a' or '\n'); lifetimes like 'a are left alone
+            code[i + 1..].find('\'').map(|p| i + 1 + p + 1).unwrap_or(bytes.len())
+        } else {
+            i += 1;
+            continue;
+        };
+        for m in &mut mask[start..end] {
+            *m = false;
+        }
+        i = end;
+    }
+    mask
+}
+
+/// Length of the raw string literal (`r"…"`, `r#"…"#`, `br"…"`) that `bytes`
+/// starts with, if any. Raw strings have no escapes: they end at the first
+/// `"` followed by as many `#` as they started with.
+fn raw_string_len(bytes: &[u8]) -> Option<usize> {
+    let prefix = if bytes.starts_with(b"br") {
+        2
+    } else if bytes.starts_with(b"r") {
+        1
+    } else {
+        return None;
+    };
+    let hashes = bytes[prefix..].iter().take_while(|&&b| b == b'#').count();
+    let open = prefix + hashes;
+    if bytes.get(open) != Some(&b'"') {
+        // A raw identifier such as `r#type`
+        return None;
+    }
+    let close: Vec<u8> = std::iter::once(b'"').chain(std::iter::repeat_n(b'#', hashes)).collect();
+    let end = bytes[open + 1..]
+        .windows(close.len())
+        .position(|w| w == close.as_slice())
+        .map(|p| open + 1 + p + close.len());
+    Some(end.unwrap_or(bytes.len()))
+}
+
+fn is_ident_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+fn line_of(code: &str, pos: usize) -> usize {
+    code[..pos].matches('\n').count() + 1
+}
+
+fn is_word_at(code: &str, pos: usize, len: usize) -> bool {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    let before = code[..pos].chars().next_back().map(is_ident).unwrap_or(false);
+    let after = code[pos + len..].chars().next().map(is_ident).unwrap_or(false);
+    !before && !after
+}
+
+fn matching_brace(code: &str, mask: &[bool], open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, b) in code.bytes().enumerate().skip(open) {
+        if !mask[i] {
+            continue;
+        }
+        match b {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_mutants() {
+        let code = r#"pub fn add(a: i32, b: i32) -> i32 {
+    // a + b in a comment is ignored
+    let msg = "a + b";
+    a + b
+}
+
+pub fn is_big(x: Vec<i32>) -> bool {
+    x.len() > 10 && true
+}
+
+pub fn log(v: &mut Vec<i32>) {
+    v.push(1);
+}
+"#;
+        let mutants = generate_mutants(code);
+        let descriptions: Vec<&str> = mutants.iter().map(|m| m.description.as_str()).collect();
+
+        assert!(descriptions.contains(&"replace `+` with `-`"));
+        assert!(descriptions.contains(&"replace `>` with `>=`"));
+        assert!(descriptions.contains(&"replace `&&` with `||`"));
+        assert!(descriptions.contains(&"replace `true` with `false`"));
+        assert!(descriptions.contains(&"replace body of `add` with `Default::default()`"));
+        assert!(descriptions.contains(&"delete statement `v.push(1);`"));
+
+        // Only the real `a + b` on line 4 is mutated, not the comment or string
+        let plus: Vec<usize> = mutants.iter().filter(|m| m.description == "replace `+` with `-`").map(|m| m.line).collect();
+        assert_eq!(plus, vec![4]);
+        // Generics (`Vec<i32>`) are not treated as comparisons
+        assert_eq!(mutants.iter().filter(|m| m.description.contains("`<`")).count(), 0);
+    }
+
+    #[test]
+    fn test_raw_strings_are_not_mutated() {
+        let code = r####"pub fn add(a: i32, b: i32) -> i32 {
+    let quoted = r#"say "a + b" twice"#;
+    let path = r"C:\dir\";
+    let bytes = br"a > b";
+    let r#type = a;
+    r#type + b
+}
+"####;
+        let mutants = generate_mutants(code);
+        let lines = |description: &str| -> Vec<usize> {
+            mutants.iter().filter(|m| m.description == description).map(|m| m.line).collect()
+        };
+        // `r"C:\dir\"` ends at its first quote: the backslash escapes nothing
+        assert_eq!(lines("replace `+` with `-`"), vec![6]);
+        assert!(lines("replace `>` with `>=`").is_empty());
+    }
+
+    #[test]
+    fn test_mutation_score() {
+        let report = MutationReport {
+            id: "x".to_string(),
+            file: PathBuf::from("x.md"),
+            skipped: None,
+            killed: 3,
+            unviable: 2,
+            survived: vec![Mutant { line: 1, description: "d".to_string(), code: String::new() }],
+        };
+        assert_eq!(report.score(), 0.75);
+    }
+}
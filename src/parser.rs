@@ -3,10 +3,14 @@
 //! This module handles parsing markdown files that contain exercise directives
 //! like `::: exercise`, `::: hint`, `::: solution`, etc.
 
+use crate::language::{strip_hidden_lines, LanguageRegistry, LanguageSpec};
+use crate::runner::{runner_for, target_filename};
 use crate::types::*;
 use pulldown_cmark::{Event, Parser, Tag, TagEnd};
 use std::collections::HashMap;
+use std::fs;
 use std::ops::Range;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
 /// Errors that can occur during parsing.
@@ -34,8 +38,26 @@ pub enum ParseError {
     #[error("Invalid hint level: {0}")]
     InvalidHintLevel(String),
 
-    #[error("Unknown exercise type. Must contain either '::: exercise' or '::: usecase'")]
+    #[error("Unknown exercise type. Must contain '::: exercise', '::: usecase' or '::: quiz'")]
     UnknownExerciseType,
+
+    #[error("Cannot read private file '{path}': {message}")]
+    PrivateFile { path: String, message: String },
+
+    #[error("Misplaced '{marker}' marker at line {line} of the solution code")]
+    InvalidMarker { marker: String, line: usize },
+
+    #[error("Cannot include source '{path}': {message}")]
+    Include { path: String, message: String },
+
+    #[error("Invalid quiz question {number}: {message}")]
+    InvalidQuestion { number: usize, message: String },
+
+    #[error("Invalid parsons block: {0}")]
+    InvalidParsons(String),
+
+    #[error("Hidden line {line} of the starter code is between visible lines; only lines before and after the editable code can be hidden")]
+    InteriorHiddenLine { line: usize },
 }
 
 /// Result type for parsing operations.
@@ -54,8 +76,28 @@ struct Directive {
     line: usize,
 }
 
+/// Options that affect parsing.
+#[derive(Debug, Clone, Default)]
+pub struct ParseOptions {
+    /// Known languages; code blocks without a language get the registry's default
+    pub languages: LanguageRegistry,
+
+    /// Directory of private companion files (solutions, tests) merged into
+    /// code exercises by ID; ignored when the directory doesn't exist
+    pub private_dir: Option<PathBuf>,
+
+    /// Directory `source=` paths are relative to, normally the markdown
+    /// file's own (the current directory when unset)
+    pub base_dir: Option<PathBuf>,
+}
+
 /// Parse a markdown file containing exercise directives.
 pub fn parse_exercise(markdown: &str) -> ParseResult<ParsedExercise> {
+    parse_exercise_with_options(markdown, &ParseOptions::default())
+}
+
+/// Parse a markdown file containing exercise directives with custom options.
+pub fn parse_exercise_with_options(markdown: &str, options: &ParseOptions) -> ParseResult<ParsedExercise> {
     // Detect exercise type based on the presence of specific directives
     // This is a simple heuristic: scan for ::: exercise vs ::: usecase
     // We ignore code blocks for this check to avoid false positives in examples
@@ -66,16 +108,143 @@ pub fn parse_exercise(markdown: &str) -> ParseResult<ParsedExercise> {
     if contains_directive(markdown, "usecase", &excluded) {
         return parse_usecase_exercise(markdown, excluded).map(ParsedExercise::UseCase);
     }
+
+    if contains_directive(markdown, "quiz", &excluded) {
+        return parse_quiz_exercise(markdown, excluded).map(ParsedExercise::Quiz);
+    }
     
     // Check for exercise directive
     if contains_directive(markdown, "exercise", &excluded) {
-        return parse_code_exercise(markdown, excluded).map(ParsedExercise::Code);
+        let exercise = parse_code_exercise(markdown, excluded, options)?;
+        let Some(dir) = options.private_dir.as_deref().filter(|d| d.is_dir()) else {
+            return Ok(ParsedExercise::Code(exercise));
+        };
+        return match private_markdown(dir, &exercise.metadata.id)? {
+            Some(private) => {
+                let merged = format!("{}\n\n{}", markdown, private);
+                let excluded = find_excluded_ranges(&merged);
+                parse_code_exercise(&merged, excluded, options).map(ParsedExercise::Code)
+            }
+            None => Ok(ParsedExercise::Code(exercise)),
+        };
     }
 
     // Default to error if neither is found
     Err(ParseError::UnknownExerciseType)
 }
 
+/// Blocks that can take their code from a file with `source=`.
+const SOURCE_BLOCKS: &[&str] = &["starter", "solution", "tests", "hidden-tests", "parsons"];
+
+/// Blocks that can come from a private code file named `<id>.<block>.<ext>`.
+const PRIVATE_CODE_BLOCKS: &[&str] = &["solution", "tests", "hidden-tests"];
+
+/// Collect an exercise's companion files from the private directory as markdown.
+///
+/// `<id>.<block>.md` files hold directive blocks and are used as they are;
+/// `<id>.<block>.<ext>` code files become a `::: <block>` with the extension
+/// as the fence language (e.g. `hello-world.tests.rs`). Files are merged in
+/// name order. Returns `None` when the exercise has no companion files.
+fn private_markdown(dir: &Path, id: &str) -> ParseResult<Option<String>> {
+    let read_error = |path: &Path, e: std::io::Error| ParseError::PrivateFile {
+        path: path.display().to_string(),
+        message: e.to_string(),
+    };
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+        .map_err(|e| read_error(dir, e))?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .collect();
+    paths.sort();
+
+    let mut markdown = Vec::new();
+    for path in paths {
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+        let Some((block, ext)) = name.strip_prefix(id).and_then(|rest| rest.strip_prefix('.')).and_then(|rest| rest.split_once('.')) else {
+            continue;
+        };
+        if block.contains('.') || ext.contains('.') || !(ext == "md" || PRIVATE_CODE_BLOCKS.contains(&block)) {
+            continue;
+        }
+        let content = fs::read_to_string(&path).map_err(|e| read_error(&path, e))?;
+        if ext == "md" {
+            markdown.push(content);
+        } else {
+            markdown.push(format!("::: {}\n{}\n:::", block, fenced(ext, content.trim_end())));
+        }
+    }
+    Ok((!markdown.is_empty()).then(|| markdown.join("\n\n")))
+}
+
+/// Wrap code in a fence longer than any backtick run in it.
+fn fenced(info: &str, code: &str) -> String {
+    let longest = code.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest.max(2) + 1);
+    format!("{}{}\n{}\n{}", fence, info, code, fence)
+}
+
+/// Read the code named by a `source=` attribute, with mdBook's `{{#include}}`
+/// semantics: `file.rs` for the whole file, `file.rs#name` (or `file.rs:name`)
+/// for the lines between `ANCHOR: name` and `ANCHOR_END: name`, and
+/// `file.rs:2`, `file.rs:2:10`, `file.rs:2:` or `file.rs::10` for 1-based
+/// line ranges. Lines with anchor markers are left out.
+fn read_source(source: &str, base_dir: Option<&Path>) -> ParseResult<String> {
+    let error = |message: String| ParseError::Include {
+        path: source.to_string(),
+        message,
+    };
+    let name_start = source.rfind('/').map_or(0, |i| i + 1);
+    let (path, selector) = match source.rsplit_once('#') {
+        Some((path, anchor)) => (path, Some(anchor)),
+        None => match source[name_start..].find(':') {
+            Some(colon) => (&source[..name_start + colon], Some(&source[name_start + colon + 1..])),
+            None => (source, None),
+        },
+    };
+    let full_path = base_dir.unwrap_or_else(|| Path::new("")).join(path);
+    let text = fs::read_to_string(&full_path).map_err(|e| error(e.to_string()))?;
+    let lines: Vec<&str> = text.lines().collect();
+
+    let anchor_re = regex::Regex::new(r"ANCHOR(_END)?:\s*([\w_-]+)").expect("valid anchor regex");
+    let is_anchor = |line: &str, end: bool, name: &str| {
+        anchor_re
+            .captures(line)
+            .is_some_and(|c| c.get(1).is_some() == end && &c[2] == name)
+    };
+    let selected = match selector {
+        None => &lines[..],
+        Some(range) if !range.is_empty() && range.chars().all(|c| c.is_ascii_digit() || c == ':') => {
+            let invalid = || error(format!("invalid line range '{}'", range));
+            let bound = |n: &str, default: usize| if n.is_empty() { Ok(default) } else { n.parse::<usize>().map_err(|_| invalid()) };
+            let (first, last) = match range.split_once(':') {
+                Some((first, last)) => (bound(first, 1)?, bound(last, lines.len())?),
+                None => (bound(range, 1)?, bound(range, 1)?),
+            };
+            if first == 0 || last < first {
+                return Err(invalid());
+            }
+            &lines[(first - 1).min(lines.len())..last.min(lines.len())]
+        }
+        Some(anchor) => {
+            let start = lines
+                .iter()
+                .position(|l| is_anchor(l, false, anchor))
+                .ok_or_else(|| error(format!("anchor '{}' not found", anchor)))?;
+            let end = lines[start + 1..]
+                .iter()
+                .position(|l| is_anchor(l, true, anchor))
+                .map_or(lines.len(), |p| start + 1 + p);
+            &lines[start + 1..end]
+        }
+    };
+    let code: Vec<&str> = selected.iter().copied().filter(|l| !anchor_re.is_match(l)).collect();
+    if code.iter().all(|l| l.trim().is_empty()) {
+        return Err(error("no code selected".to_string()));
+    }
+    Ok(code.join("\n"))
+}
+
 /// Check if the markdown contains a specific directive, ignoring excluded ranges.
 fn contains_directive(markdown: &str, directive: &str, excluded: &[Range<usize>]) -> bool {
     let pattern = format!("::: {}", directive);
@@ -86,22 +255,30 @@ fn contains_directive(markdown: &str, directive: &str, excluded: &[Range<usize>]
         let range = offset..(offset + line.len());
         offset += line_len;
 
-        if line.trim().starts_with(&pattern) {
-            if !is_range_excluded(&range, excluded) {
-                return true;
-            }
+        if line.trim().starts_with(&pattern) && !is_range_excluded(&range, excluded) {
+            return true;
         }
     }
     false
 }
 
 /// Parse a code exercise (original format).
-fn parse_code_exercise(markdown: &str, excluded_ranges: Vec<Range<usize>>) -> ParseResult<Exercise> {
+fn parse_code_exercise(
+    markdown: &str,
+    excluded_ranges: Vec<Range<usize>>,
+    options: &ParseOptions,
+) -> ParseResult<Exercise> {
     let mut exercise = Exercise::default();
     let mut current_directive: Option<Directive> = None;
     let mut block_content = String::new();
     let mut description_buffer = String::new();
     let mut in_description = true;
+    // Whether the block making the main starter file sets `expect`, which
+    // then takes precedence over the metadata
+    let mut starter_expect_given = false;
+    let gives_expect = |exercise: &Exercise, directive: &Directive| {
+        directive.name == "starter" && exercise.starter.is_none() && directive.attributes.contains_key("expect")
+    };
 
     let mut current_offset = 0;
     for (line_num, line_raw) in markdown.split_inclusive('\n').enumerate() {
@@ -116,7 +293,8 @@ fn parse_code_exercise(markdown: &str, excluded_ranges: Vec<Range<usize>>) -> Pa
         if !is_excluded {
             if let Some(directive) = parse_directive_start(line, line_number) {
                 if let Some(prev_directive) = current_directive.take() {
-                    process_code_block(&mut exercise, &prev_directive, &block_content)?;
+                    starter_expect_given |= gives_expect(&exercise, &prev_directive);
+                    process_code_block(&mut exercise, &prev_directive, &block_content, options)?;
                 } else if in_description && directive.name != "exercise" {
                     exercise.description = description_buffer.trim().to_string();
                     in_description = false;
@@ -129,7 +307,8 @@ fn parse_code_exercise(markdown: &str, excluded_ranges: Vec<Range<usize>>) -> Pa
 
             if line.trim() == ":::" {
                 if let Some(directive) = current_directive.take() {
-                    process_code_block(&mut exercise, &directive, &block_content)?;
+                    starter_expect_given |= gives_expect(&exercise, &directive);
+                    process_code_block(&mut exercise, &directive, &block_content, options)?;
                     block_content.clear();
                 }
                 continue;
@@ -161,12 +340,68 @@ fn parse_code_exercise(markdown: &str, excluded_ranges: Vec<Range<usize>>) -> Pa
         exercise.description = description_buffer.trim().to_string();
     }
 
+    // Tests attributes take precedence over the exercise metadata
+    for tests in exercise.tests.iter_mut().chain(exercise.hidden_tests.iter_mut()) {
+        tests.toolchain = tests.toolchain.or(exercise.metadata.toolchain);
+    }
+    if let Some(io_tests) = &mut exercise.io_tests {
+        io_tests.toolchain = io_tests.toolchain.or(exercise.metadata.toolchain);
+    }
+
+    // Likewise starter attributes over the metadata's expected outcome.
+    // Without an `expect` of their own, error codes imply `compile_error`
+    if let Some(starter) = &mut exercise.starter {
+        if !starter_expect_given {
+            let has_codes = !starter.error_codes.is_empty() || !exercise.metadata.error_codes.is_empty();
+            starter.expect = if has_codes {
+                StarterExpectation::CompileError
+            } else {
+                exercise.metadata.expect.unwrap_or_default()
+            };
+        }
+        if starter.expect == StarterExpectation::CompileError && starter.error_codes.is_empty() {
+            starter.error_codes = exercise.metadata.error_codes.clone();
+        }
+    }
+
     Ok(exercise)
 }
 
 /// Parse a UseCase exercise.
 fn parse_usecase_exercise(markdown: &str, excluded_ranges: Vec<Range<usize>>) -> ParseResult<UseCaseExercise> {
     let mut exercise = UseCaseExercise::default();
+    let (title, description) = parse_blocks(markdown, &excluded_ranges, "usecase", |directive, content| {
+        process_usecase_block(&mut exercise, directive, content)
+    })?;
+    exercise.title = title;
+    exercise.description = description;
+    Ok(exercise)
+}
+
+/// Parse a quiz exercise.
+fn parse_quiz_exercise(markdown: &str, excluded_ranges: Vec<Range<usize>>) -> ParseResult<QuizExercise> {
+    let mut exercise = QuizExercise::default();
+    let (title, description) = parse_blocks(markdown, &excluded_ranges, "quiz", |directive, content| {
+        process_quiz_block(&mut exercise, directive, content)
+    })?;
+    exercise.title = title;
+    exercise.description = description;
+    Ok(exercise)
+}
+
+/// Walk the directive blocks of an exercise whose metadata block is `meta`,
+/// passing each block's directive and content to `process`.
+///
+/// Returns the title (the first heading) and the description: the markdown
+/// before the first directive other than `meta`.
+fn parse_blocks(
+    markdown: &str,
+    excluded_ranges: &[Range<usize>],
+    meta: &str,
+    mut process: impl FnMut(&Directive, &str) -> ParseResult<()>,
+) -> ParseResult<(Option<String>, String)> {
+    let mut title = None;
+    let mut description = String::new();
     let mut current_directive: Option<Directive> = None;
     let mut block_content = String::new();
     let mut description_buffer = String::new();
@@ -179,15 +414,15 @@ fn parse_usecase_exercise(markdown: &str, excluded_ranges: Vec<Range<usize>>) ->
         let line_range = current_offset..(current_offset + line_len);
 
         let line = line_raw.trim_end_matches(['\n', '\r']);
-        let is_excluded = is_range_excluded(&line_range, &excluded_ranges);
+        let is_excluded = is_range_excluded(&line_range, excluded_ranges);
         current_offset += line_len;
 
         if !is_excluded {
             if let Some(directive) = parse_directive_start(line, line_number) {
                 if let Some(prev_directive) = current_directive.take() {
-                    process_usecase_block(&mut exercise, &prev_directive, &block_content)?;
-                } else if in_description && directive.name != "usecase" {
-                    exercise.description = description_buffer.trim().to_string();
+                    process(&prev_directive, &block_content)?;
+                } else if in_description && directive.name != meta {
+                    description = description_buffer.trim().to_string();
                     in_description = false;
                 }
 
@@ -198,7 +433,7 @@ fn parse_usecase_exercise(markdown: &str, excluded_ranges: Vec<Range<usize>>) ->
 
             if line.trim() == ":::" {
                 if let Some(directive) = current_directive.take() {
-                    process_usecase_block(&mut exercise, &directive, &block_content)?;
+                    process(&directive, &block_content)?;
                     block_content.clear();
                 }
                 continue;
@@ -208,10 +443,10 @@ fn parse_usecase_exercise(markdown: &str, excluded_ranges: Vec<Range<usize>>) ->
         if current_directive.is_some() {
             block_content.push_str(line_raw);
         } else if in_description {
-            if exercise.title.is_none() && line.starts_with('#') && !is_excluded {
-                let title = line.trim_start_matches('#').trim();
-                if !title.is_empty() {
-                    exercise.title = Some(title.to_string());
+            if title.is_none() && line.starts_with('#') && !is_excluded {
+                let heading = line.trim_start_matches('#').trim();
+                if !heading.is_empty() {
+                    title = Some(heading.to_string());
                     continue;
                 }
             }
@@ -227,22 +462,46 @@ fn parse_usecase_exercise(markdown: &str, excluded_ranges: Vec<Range<usize>>) ->
     }
 
     if in_description && !description_buffer.is_empty() {
-        exercise.description = description_buffer.trim().to_string();
+        description = description_buffer.trim().to_string();
     }
 
-    Ok(exercise)
+    Ok((title, description))
 }
 
 /// Process a directive block for code exercises.
-fn process_code_block(exercise: &mut Exercise, directive: &Directive, content: &str) -> ParseResult<()> {
+fn process_code_block(
+    exercise: &mut Exercise,
+    directive: &Directive,
+    content: &str,
+    options: &ParseOptions,
+) -> ParseResult<()> {
+    let languages = &options.languages;
+
+    // `source=` code goes first, before any fences or explanation in the block
+    let sourced;
+    let content = match directive.attributes.get("source") {
+        Some(source) if SOURCE_BLOCKS.contains(&directive.name.as_str()) => {
+            let code = read_source(source, options.base_dir.as_deref())?;
+            let path = source.split(['#', ':']).next().unwrap_or_default();
+            let extension = Path::new(path).extension().and_then(|e| e.to_str()).unwrap_or_default();
+            sourced = format!("{}\n{}", fenced(extension, &code), content);
+            &sourced
+        }
+        _ => content,
+    };
+
     match directive.name.as_str() {
         "exercise" => parse_exercise_block(exercise, content)?,
         "objectives" => parse_objectives_block(&mut exercise.objectives, content)?,
         "discussion" => parse_discussion_block(exercise, content)?,
-        "starter" => parse_starter_block(exercise, &directive.attributes, content)?,
+        "starter" => parse_starter_block(exercise, &directive.attributes, content, languages)?,
         "hint" => parse_hint_block(&mut exercise.hints, &directive.attributes, content)?,
-        "solution" => parse_solution_block(exercise, &directive.attributes, content)?,
-        "tests" => parse_tests_block(exercise, &directive.attributes, content)?,
+        "solution" => parse_solution_block(exercise, &directive.attributes, content, languages)?,
+        "tests" => parse_tests_block(exercise, &directive.attributes, content, languages, false)?,
+        "hidden-tests" => parse_tests_block(exercise, &directive.attributes, content, languages, true)?,
+        "io-tests" => parse_io_tests_block(exercise, &directive.attributes, content, languages)?,
+        "requirements" => parse_requirements_block(exercise, content)?,
+        "parsons" => parse_parsons_block(exercise, &directive.attributes, content, languages)?,
         "reflection" => parse_reflection_block(exercise, content)?,
         _ => {
             // Unknown directive - ignore
@@ -269,6 +528,20 @@ fn process_usecase_block(exercise: &mut UseCaseExercise, directive: &Directive,
     Ok(())
 }
 
+/// Process a directive block for quiz exercises.
+fn process_quiz_block(exercise: &mut QuizExercise, directive: &Directive, content: &str) -> ParseResult<()> {
+    match directive.name.as_str() {
+        "quiz" => parse_quiz_meta_block(exercise, content)?,
+        "question" => parse_question_block(exercise, &directive.attributes, content)?,
+        "objectives" => parse_objectives_block(&mut exercise.objectives, content)?,
+        "hint" => parse_hint_block(&mut exercise.hints, &directive.attributes, content)?,
+        _ => {
+            // Unknown directive - ignore
+        }
+    }
+    Ok(())
+}
+
 // --- Common Parsers ---
 
 fn parse_objectives_block(objectives_opt: &mut Option<Objectives>, content: &str) -> ParseResult<()> {
@@ -373,9 +646,101 @@ fn parse_exercise_block(exercise: &mut Exercise, content: &str) -> ParseResult<(
         }
     }
 
+    if let Some(deps) = yaml.get("dependencies") {
+        exercise.metadata.dependencies = parse_dependencies(deps)?;
+    }
+
+    for key in [
+        "edition",
+        "channel",
+        "build_mode",
+        "crate_type",
+        "backtrace",
+        "lint",
+        "lint_level",
+        "format",
+    ] {
+        let value = match yaml.get(key) {
+            Some(serde_yaml::Value::String(s)) => s.clone(),
+            Some(serde_yaml::Value::Number(n)) => n.to_string(),
+            Some(serde_yaml::Value::Bool(b)) => b.to_string(),
+            _ => continue,
+        };
+        exercise
+            .metadata
+            .toolchain
+            .set(key, &value)
+            .map_err(|_| ParseError::InvalidAttribute {
+                attribute: key.to_string(),
+                value,
+            })?;
+    }
+
+    if let Some(expect) = yaml.get("expect").and_then(|v| v.as_str()) {
+        exercise.metadata.expect = Some(expect.parse().map_err(|_| ParseError::InvalidAttribute {
+            attribute: "expect".to_string(),
+            value: expect.to_string(),
+        })?);
+    }
+
+    match yaml.get("error_codes") {
+        Some(serde_yaml::Value::String(codes)) => {
+            exercise.metadata.error_codes = parse_error_codes(codes)?;
+        }
+        Some(serde_yaml::Value::Sequence(items)) => {
+            let codes: Vec<&str> = items.iter().filter_map(|v| v.as_str()).collect();
+            exercise.metadata.error_codes = parse_error_codes(&codes.join(","))?;
+        }
+        _ => {}
+    }
+
     Ok(())
 }
 
+/// Parse a comma-separated list of rustc error codes such as `E0382, E0499`.
+fn parse_error_codes(value: &str) -> ParseResult<Vec<String>> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|code| !code.is_empty())
+        .map(|code| {
+            let digits = code.strip_prefix('E').unwrap_or("");
+            if digits.len() == 4 && digits.bytes().all(|b| b.is_ascii_digit()) {
+                Ok(code.to_string())
+            } else {
+                Err(ParseError::InvalidAttribute {
+                    attribute: "error_codes".to_string(),
+                    value: code.to_string(),
+                })
+            }
+        })
+        .collect()
+}
+
+/// Parse `dependencies:` as a list of `name = spec` strings or a map of name to version.
+fn parse_dependencies(value: &serde_yaml::Value) -> ParseResult<Vec<Dependency>> {
+    let invalid = |value: String| ParseError::InvalidAttribute {
+        attribute: "dependencies".to_string(),
+        value,
+    };
+    let entries: Vec<String> = match value {
+        serde_yaml::Value::Sequence(items) => items
+            .iter()
+            .map(|item| item.as_str().map(String::from).ok_or_else(|| invalid(format!("{:?}", item))))
+            .collect::<ParseResult<_>>()?,
+        serde_yaml::Value::Mapping(map) => map
+            .iter()
+            .map(|(name, version)| match (name.as_str(), version) {
+                (Some(name), serde_yaml::Value::String(v)) => Ok(format!("{} = \"{}\"", name, v)),
+                (Some(name), serde_yaml::Value::Number(v)) => Ok(format!("{} = \"{}\"", name, v)),
+                _ => Err(invalid(format!("{:?}", name))),
+            })
+            .collect::<ParseResult<_>>()?,
+        other => return Err(invalid(format!("{:?}", other))),
+    };
+    entries.iter().map(|e| e.parse().map_err(|_| invalid(e.clone()))).collect()
+}
+
 fn parse_discussion_block(exercise: &mut Exercise, content: &str) -> ParseResult<()> {
     let items = parse_markdown_list(content);
     if !items.is_empty() {
@@ -388,59 +753,130 @@ fn parse_starter_block(
     exercise: &mut Exercise,
     attrs: &HashMap<String, String>,
     content: &str,
+    languages: &LanguageRegistry,
 ) -> ParseResult<()> {
-    let (language_raw, code) = extract_code_block(content);
-
-    if code.trim().is_empty() {
-        return Ok(());
-    }
-
-    let mut filename = attrs.get("file").cloned();
-    let mut language = attrs.get("language").cloned();
+    let expect = match attrs.get("expect") {
+        Some(value) => value.parse().map_err(|_| ParseError::InvalidAttribute {
+            attribute: "expect".to_string(),
+            value: value.clone(),
+        })?,
+        None => StarterExpectation::default(),
+    };
+    let error_codes = match attrs.get("error_codes") {
+        Some(value) => parse_error_codes(value)?,
+        None => Vec::new(),
+    };
 
-    let mut info_opt = language_raw;
-    if info_opt.is_none() {
-        for line in content.lines() {
-            let t = line.trim();
-            if t.starts_with("```") {
-                let info = t.trim_start_matches('`').trim().to_string();
-                if !info.is_empty() { info_opt = Some(info); }
-                break;
-            }
+    // Each fence is a file, except one tagged `diagnostic` holding the
+    // expected compiler output; directive attributes apply to the first file
+    let no_attrs = HashMap::new();
+    let (diagnostics, files): (Vec<_>, Vec<_>) = extract_code_blocks(content)
+        .into_iter()
+        .partition(|(info, _)| parse_fence_info(info.as_deref().unwrap_or("")).1.contains_key("diagnostic"));
+    for (i, (info, code)) in files.into_iter().enumerate() {
+        if code.trim().is_empty() {
+            continue;
         }
+        let attrs = if i == 0 { attrs } else { &no_attrs };
+        let (lang_clean, fence_attrs) = parse_fence_info(info.as_deref().unwrap_or(""));
+        let filename = attrs
+            .get("file")
+            .or_else(|| fence_attrs.get("filename"))
+            .or_else(|| fence_attrs.get("file"))
+            .cloned();
+        let language = attrs
+            .get("language")
+            .cloned()
+            .or_else(|| Some(lang_clean).filter(|l| !l.is_empty()))
+            .unwrap_or_else(|| languages.default_language().to_string());
+
+        let comment = languages.get(&language).map(|s| s.comment.as_str()).unwrap_or("//");
+        let locked = attrs.get("locked").or_else(|| fence_attrs.get("locked"));
+        let (code, locked_lines) = parse_locked_lines(&code, locked.map(String::as_str), comment)?;
+        let (code, hidden_lines) = strip_hidden(code, &language, &fence_attrs, languages);
+        add_starter_file(
+            exercise,
+            StarterCode {
+                filename,
+                language,
+                code,
+                expect,
+                error_codes: if i == 0 { error_codes.clone() } else { Vec::new() },
+                hidden_lines,
+                locked_lines,
+                diagnostic: None,
+            },
+            languages,
+        )?;
     }
-
-    if let Some(info) = info_opt {
-        let (lang_clean, fence_attrs) = parse_fence_info(&info);
-        if language.is_none() && !lang_clean.is_empty() {
-            language = Some(lang_clean);
-        }
-        if filename.is_none() {
-            if let Some(f) = fence_attrs.get("filename") {
-                filename = Some(f.clone());
-            } else if let Some(f) = fence_attrs.get("file") {
-                filename = Some(f.clone());
-            }
-        }
+    if let (Some(starter), Some((_, diagnostic))) = (&mut exercise.starter, diagnostics.into_iter().next()) {
+        starter.diagnostic = Some(diagnostic.trim_end().to_string());
     }
 
-    exercise.starter = Some(StarterCode {
-        filename,
-        language: language.unwrap_or_else(|| "rust".to_string()),
-        code,
-    });
+    Ok(())
+}
 
+/// Add a starter file: the first becomes `starter`, later ones (which need
+/// distinct filenames) go to `starter_files`.
+fn add_starter_file(exercise: &mut Exercise, starter: StarterCode, languages: &LanguageRegistry) -> ParseResult<()> {
+    check_hidden_lines(&starter)?;
+    let Some(main) = &exercise.starter else {
+        exercise.starter = Some(starter);
+        return Ok(());
+    };
+    let Some(filename) = &starter.filename else {
+        return Err(ParseError::MissingField {
+            block: "starter".to_string(),
+            field: "file".to_string(),
+        });
+    };
+    let taken = main_file_path(main, languages).as_ref() == Some(filename)
+        || std::iter::once(main)
+            .chain(&exercise.starter_files)
+            .any(|s| s.filename.as_ref() == Some(filename));
+    if taken {
+        return Err(ParseError::DuplicateBlock {
+            block_type: format!("starter file={}", filename),
+        });
+    }
+    exercise.starter_files.push(starter);
     Ok(())
 }
 
-fn parse_solution_block(exercise: &mut Exercise, attrs: &HashMap<String, String>, content: &str) -> ParseResult<()> {
-    let (language, code) = extract_code_block(content);
+/// Where the main starter file is written when the tests run locally, which
+/// without a `file=` is the language's default file (e.g., `src/lib.rs`).
+fn main_file_path(main: &StarterCode, languages: &LanguageRegistry) -> Option<String> {
+    let spec = languages.get(&main.language)?;
+    let runner = runner_for(spec.test_runner?);
+    Some(target_filename(spec, runner.as_ref(), Some(main)))
+}
+
+fn parse_solution_block(
+    exercise: &mut Exercise,
+    attrs: &HashMap<String, String>,
+    content: &str,
+    languages: &LanguageRegistry,
+) -> ParseResult<()> {
+    // The first fence is the file combined with the tests, further ones with
+    // a filename are other files of the project
+    let mut blocks = extract_code_blocks(content).into_iter();
+    let (language_raw, code) = blocks.next().unwrap_or((None, String::new()));
     let explanation = extract_explanation(content);
 
+    let (language, fence_attrs) = parse_fence_info(language_raw.as_deref().unwrap_or(""));
+    let language = if language.is_empty() { languages.default_language().to_string() } else { language };
+    let spec = languages.get(&language).cloned().unwrap_or_else(|| LanguageSpec::new(&language));
+    let (code, derived) = match derive_starter(&code, &spec.comment, &spec.placeholder())? {
+        Some((solution, starter)) => (solution, Some(starter)),
+        None => (code, None),
+    };
+    let (code, hidden_lines) = strip_hidden(code, &language, &fence_attrs, languages);
     let mut sol = Solution {
+        title: attrs.get("title").or_else(|| attrs.get("approach")).cloned(),
         code,
-        language: language.unwrap_or_else(|| "rust".to_string()),
+        language,
         explanation,
+        hidden_lines,
         ..Default::default()
     };
 
@@ -448,6 +884,27 @@ fn parse_solution_block(exercise: &mut Exercise, attrs: &HashMap<String, String>
         return Ok(());
     }
 
+    for (info, code) in blocks.filter(|(_, code)| !code.trim().is_empty()) {
+        let (lang_clean, file_attrs) = parse_fence_info(info.as_deref().unwrap_or(""));
+        // Fences without one are examples in the explanation
+        let Some(path) = file_attrs.get("filename").or_else(|| file_attrs.get("file")).cloned() else {
+            continue;
+        };
+        if sol.files.iter().any(|f| f.path == path) {
+            return Err(ParseError::DuplicateBlock {
+                block_type: format!("solution file={}", path),
+            });
+        }
+        let language = if lang_clean.is_empty() { sol.language.clone() } else { lang_clean };
+        let (code, hidden_lines) = strip_hidden(code, &language, &file_attrs, languages);
+        sol.files.push(SolutionFile {
+            path,
+            language,
+            code,
+            hidden_lines,
+        });
+    }
+
     if let Some(reveal) = attrs.get("reveal").map(|s| s.to_lowercase()) {
         sol.reveal = match reveal.as_str() {
             "always" => SolutionReveal::Always,
@@ -456,14 +913,76 @@ fn parse_solution_block(exercise: &mut Exercise, attrs: &HashMap<String, String>
         };
     }
 
+    // Later solution blocks are alternative approaches
+    if exercise.solution.is_some() {
+        exercise.alternative_solutions.push(sol);
+        return Ok(());
+    }
+    if let Some(starter) = derived {
+        let (code, hidden_lines) = strip_hidden(starter, &sol.language, &fence_attrs, languages);
+        let filename = attrs
+            .get("file")
+            .or_else(|| fence_attrs.get("filename"))
+            .or_else(|| fence_attrs.get("file"))
+            .cloned();
+        set_derived_starter(
+            exercise,
+            StarterCode {
+                filename,
+                language: sol.language.clone(),
+                code,
+                hidden_lines,
+                ..Default::default()
+            },
+            languages,
+        )?;
+    }
     exercise.solution = Some(sol);
     Ok(())
 }
 
+/// Make a starter derived from the solution the main starter file. An
+/// explicit `::: starter` block can only add further, named files.
+fn set_derived_starter(exercise: &mut Exercise, starter: StarterCode, languages: &LanguageRegistry) -> ParseResult<()> {
+    check_hidden_lines(&starter)?;
+    if let Some(explicit) = exercise.starter.take() {
+        let clash = || ParseError::DuplicateBlock {
+            block_type: "starter (also derived from the solution)".to_string(),
+        };
+        if explicit.filename.is_none()
+            || explicit.filename == starter.filename
+            || explicit.filename == main_file_path(&starter, languages)
+        {
+            return Err(clash());
+        }
+        if exercise.starter_files.iter().any(|f| f.filename == starter.filename) {
+            return Err(clash());
+        }
+        exercise.starter_files.insert(0, explicit);
+    }
+    exercise.starter = Some(starter);
+    Ok(())
+}
+
+/// Check that a starter's hidden lines come before or after the editable
+/// code: the editor adds them back around the student's code, but can't put
+/// lines in between back where they were once the student has edited it.
+fn check_hidden_lines(starter: &StarterCode) -> ParseResult<()> {
+    let (prefix, visible, _) = starter.split_hidden();
+    let leading = prefix.matches('\n').count();
+    let shown = visible.split('\n').count();
+    match starter.hidden_lines.iter().find(|&&line| line >= leading && line < leading + shown) {
+        Some(line) => Err(ParseError::InteriorHiddenLine { line: line + 1 }),
+        None => Ok(()),
+    }
+}
+
 fn parse_tests_block(
     exercise: &mut Exercise,
     attrs: &HashMap<String, String>,
     content: &str,
+    languages: &LanguageRegistry,
+    hidden: bool,
 ) -> ParseResult<()> {
     let (language_raw, code) = extract_code_block(content);
 
@@ -471,23 +990,231 @@ fn parse_tests_block(
         return Ok(());
     }
 
+    let hidden = match attrs.get("visibility").map(String::as_str) {
+        None => hidden,
+        Some("hidden") => true,
+        Some("visible") => false,
+        Some(value) => {
+            return Err(ParseError::InvalidAttribute {
+                attribute: "visibility".to_string(),
+                value: value.to_string(),
+            })
+        }
+    };
+
     let mode = attrs
         .get("mode")
         .map(|m| m.parse().unwrap_or(TestMode::Playground))
         .unwrap_or(TestMode::Playground);
 
     let mut language = attrs.get("language").cloned();
+    let mut fence_attrs = HashMap::new();
     if let Some(info) = language_raw {
-        let (lang_clean, _fa) = parse_fence_info(&info);
+        let (lang_clean, fa) = parse_fence_info(&info);
         if language.is_none() && !lang_clean.is_empty() {
             language = Some(lang_clean);
         }
+        fence_attrs = fa;
     }
 
-    exercise.tests = Some(TestBlock { language: language.unwrap_or_else(|| "rust".to_string()), code, mode });
+    let mut toolchain = ToolchainSettings::default();
+    for (key, value) in attrs {
+        toolchain.set(key, value).map_err(|_| ParseError::InvalidAttribute {
+            attribute: key.clone(),
+            value: value.clone(),
+        })?;
+    }
+
+    let language = language.unwrap_or_else(|| languages.default_language().to_string());
+    let (code, hidden_lines) = strip_hidden(code, &language, &fence_attrs, languages);
+    let block = TestBlock {
+        language,
+        code,
+        mode,
+        toolchain,
+        hidden_lines,
+    };
+    if hidden {
+        exercise.hidden_tests = Some(block);
+    } else {
+        exercise.tests = Some(block);
+    }
     Ok(())
 }
 
+/// Parse `::: io-tests`: a YAML list of `{name, stdin, args, expected_stdout, match}` cases.
+fn parse_io_tests_block(
+    exercise: &mut Exercise,
+    attrs: &HashMap<String, String>,
+    content: &str,
+    languages: &LanguageRegistry,
+) -> ParseResult<()> {
+    let cases: Vec<IoTestCase> = serde_yaml::from_str(content).map_err(|e| ParseError::YamlError {
+        block: "io-tests".to_string(),
+        source: e,
+    })?;
+    for case in cases.iter().filter(|c| c.matching == OutputMatch::Regex) {
+        if regex::Regex::new(&case.expected_stdout).is_err() {
+            return Err(ParseError::InvalidAttribute {
+                attribute: "expected_stdout".to_string(),
+                value: case.expected_stdout.clone(),
+            });
+        }
+    }
+    if cases.is_empty() {
+        return Ok(());
+    }
+
+    let mode = attrs
+        .get("mode")
+        .map(|m| m.parse().unwrap_or(TestMode::Playground))
+        .unwrap_or(TestMode::Playground);
+
+    let mut toolchain = ToolchainSettings::default();
+    for (key, value) in attrs {
+        toolchain.set(key, value).map_err(|_| ParseError::InvalidAttribute {
+            attribute: key.clone(),
+            value: value.clone(),
+        })?;
+    }
+
+    // The program is the starter, so it shares its language by default
+    let language = attrs
+        .get("language")
+        .cloned()
+        .or_else(|| exercise.starter.as_ref().map(|s| s.language.clone()))
+        .unwrap_or_else(|| languages.default_language().to_string());
+    exercise.io_tests = Some(IoTestBlock {
+        language,
+        cases,
+        mode,
+        toolchain,
+    });
+    Ok(())
+}
+
+/// Parse `::: requirements`: `must_use`, `must_not_use` and `max_lines` in YAML.
+fn parse_requirements_block(exercise: &mut Exercise, content: &str) -> ParseResult<()> {
+    if content.trim().is_empty() {
+        return Ok(());
+    }
+    let requirements: Requirements = serde_yaml::from_str(content).map_err(|e| ParseError::YamlError {
+        block: "requirements".to_string(),
+        source: e,
+    })?;
+    if requirements != Requirements::default() {
+        exercise.requirements = Some(requirements);
+    }
+    Ok(())
+}
+
+/// Parse `::: parsons`: the correct code in the first fenced block and,
+/// optionally, lines that don't belong in blocks tagged `distractors`.
+fn parse_parsons_block(
+    exercise: &mut Exercise,
+    attrs: &HashMap<String, String>,
+    content: &str,
+    languages: &LanguageRegistry,
+) -> ParseResult<()> {
+    let blocks = extract_code_blocks(content);
+    let Some((info, code)) = blocks.first().filter(|(_, code)| !code.trim().is_empty()) else {
+        return Ok(());
+    };
+    if exercise.parsons.is_some() {
+        return Err(ParseError::DuplicateBlock {
+            block_type: "parsons".to_string(),
+        });
+    }
+    let (language, _) = parse_fence_info(info.as_deref().unwrap_or(""));
+    let language = if language.is_empty() { languages.default_language().to_string() } else { language };
+    let indent = match attrs.get("indent").map(|v| v.to_lowercase()) {
+        None => true,
+        Some(v) if v == "true" => true,
+        Some(v) if v == "false" => false,
+        Some(v) => {
+            return Err(ParseError::InvalidAttribute {
+                attribute: "indent".to_string(),
+                value: v,
+            })
+        }
+    };
+
+    let mut distractor_code = Vec::new();
+    for (info, code) in &blocks[1..] {
+        let (_, fence_attrs) = parse_fence_info(info.as_deref().unwrap_or(""));
+        if !fence_attrs.contains_key("distractors") {
+            return Err(ParseError::InvalidParsons(
+                "only the first code block is the code; tag further blocks with `distractors`".to_string(),
+            ));
+        }
+        distractor_code.push(code.as_str());
+    }
+    let indent_unit = parsons_indent_unit(std::iter::once(code.as_str()).chain(distractor_code.iter().copied()));
+    let lines = parsons_lines(code, &indent_unit)?;
+    let distractors = distractor_code
+        .iter()
+        .map(|code| parsons_lines(code, &indent_unit))
+        .collect::<ParseResult<Vec<_>>>()?
+        .concat();
+
+    if lines.len() < 2 {
+        return Err(ParseError::InvalidParsons("the code needs at least two lines to put in order".to_string()));
+    }
+    if let Some(line) = distractors.iter().find(|d| lines.iter().any(|l| l.code == d.code)) {
+        return Err(ParseError::InvalidParsons(format!(
+            "distractor `{}` is also a line of the code",
+            line.code
+        )));
+    }
+
+    exercise.parsons = Some(ParsonsProblem {
+        language,
+        lines,
+        distractors,
+        indent,
+        indent_unit,
+    });
+    Ok(())
+}
+
+/// The smallest indentation used in any of `codes`: a tab if lines are
+/// indented with tabs, otherwise a number of spaces (four if none are indented).
+fn parsons_indent_unit<'a>(codes: impl Iterator<Item = &'a str>) -> String {
+    let mut smallest: Option<usize> = None;
+    for line in codes.flat_map(str::lines).filter(|l| !l.trim().is_empty()) {
+        if line.starts_with('\t') {
+            return "\t".to_string();
+        }
+        let spaces = line.len() - line.trim_start_matches(' ').len();
+        if spaces > 0 {
+            smallest = Some(smallest.map_or(spaces, |s| s.min(spaces)));
+        }
+    }
+    " ".repeat(smallest.unwrap_or(4))
+}
+
+/// The non-blank lines of `code`, with their indentation as a level.
+fn parsons_lines(code: &str, indent_unit: &str) -> ParseResult<Vec<ParsonsLine>> {
+    code.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let code = line.trim_start();
+            let leading = &line[..line.len() - code.len()];
+            let indent = leading.len() / indent_unit.len();
+            if leading != indent_unit.repeat(indent) {
+                return Err(ParseError::InvalidParsons(format!(
+                    "`{}` isn't indented by a multiple of {:?}",
+                    code, indent_unit
+                )));
+            }
+            Ok(ParsonsLine {
+                code: code.trim_end().to_string(),
+                indent,
+            })
+        })
+        .collect()
+}
+
 fn parse_reflection_block(exercise: &mut Exercise, content: &str) -> ParseResult<()> {
     let items = parse_markdown_list(content);
     if !items.is_empty() {
@@ -731,6 +1458,124 @@ fn parse_context_block(exercise: &mut UseCaseExercise, content: &str) -> ParseRe
     Ok(())
 }
 
+// --- Quiz Exercise Specific Parsers ---
+
+fn parse_quiz_meta_block(exercise: &mut QuizExercise, content: &str) -> ParseResult<()> {
+    let yaml: serde_yaml::Value =
+        serde_yaml::from_str(content).map_err(|e| ParseError::YamlError {
+            block: "quiz".to_string(),
+            source: e,
+        })?;
+
+    if let Some(id) = yaml.get("id").and_then(|v| v.as_str()) {
+        exercise.metadata.id = id.to_string();
+    } else {
+        return Err(ParseError::MissingField {
+            block: "quiz".to_string(),
+            field: "id".to_string(),
+        });
+    }
+
+    if let Some(difficulty) = yaml.get("difficulty").and_then(|v| v.as_str()) {
+        exercise.metadata.difficulty = difficulty.parse().unwrap_or_default();
+    }
+
+    if let Some(time_value) = yaml.get("time") {
+        if let Some(time_str) = time_value.as_str() {
+            exercise.metadata.time_minutes = parse_time_string(time_str);
+        } else if let Some(time_int) = time_value.as_u64() {
+            exercise.metadata.time_minutes = Some(time_int as u32);
+        }
+    }
+
+    if let Some(prereqs) = yaml.get("prerequisites").and_then(|v| v.as_sequence()) {
+        exercise.metadata.prerequisites = prereqs
+            .iter()
+            .filter_map(|v| v.as_str())
+            .map(String::from)
+            .collect();
+    }
+
+    if let Some(value) = yaml.get("pass_mark") {
+        let pass_mark = value
+            .as_f64()
+            .filter(|mark| (0.0..=1.0).contains(mark))
+            .ok_or_else(|| ParseError::InvalidAttribute {
+                attribute: "pass_mark".to_string(),
+                value: serde_yaml::to_string(value).unwrap_or_default().trim().to_string(),
+            })?;
+        exercise.metadata.pass_mark = Some(pass_mark as f32);
+    }
+
+    if let Some(shuffle) = yaml.get("shuffle").and_then(|v| v.as_bool()) {
+        exercise.metadata.shuffle = shuffle;
+    }
+
+    Ok(())
+}
+
+/// Parse a `::: question` block: YAML with a `prompt` and either `options`
+/// or `answers`. The type comes from `type` (in the YAML or as an
+/// attribute), else short-answer with `answers`, multiple-choice with
+/// several correct options and single-choice otherwise.
+fn parse_question_block(
+    exercise: &mut QuizExercise,
+    attrs: &HashMap<String, String>,
+    content: &str,
+) -> ParseResult<()> {
+    let number = exercise.questions.len() + 1;
+    let invalid = |message: String| ParseError::InvalidQuestion { number, message };
+    let mut yaml: serde_yaml::Value = serde_yaml::from_str(content).map_err(|e| ParseError::YamlError {
+        block: "question".to_string(),
+        source: e,
+    })?;
+
+    let explicit = match yaml.as_mapping_mut().and_then(|m| m.remove("type")) {
+        Some(serde_yaml::Value::String(kind)) => Some(kind),
+        Some(other) => return Err(invalid(format!("invalid type {:?}", other))),
+        None => attrs.get("type").cloned(),
+    };
+    let mut question: QuizQuestion = serde_yaml::from_value(yaml).map_err(|e| ParseError::YamlError {
+        block: "question".to_string(),
+        source: e,
+    })?;
+
+    let correct = question.options.iter().filter(|o| o.correct).count();
+    question.kind = match explicit {
+        Some(kind) => kind.parse().map_err(|_| ParseError::InvalidAttribute {
+            attribute: "type".to_string(),
+            value: kind,
+        })?,
+        None if !question.answers.is_empty() => QuestionType::Short,
+        None if correct > 1 => QuestionType::Multiple,
+        None => QuestionType::Single,
+    };
+
+    match question.kind {
+        QuestionType::Single | QuestionType::Multiple if question.options.is_empty() => {
+            return Err(invalid("a choice question needs options".to_string()));
+        }
+        QuestionType::Single if correct != 1 => {
+            return Err(invalid(format!("a single-choice question needs exactly one correct option, not {}", correct)));
+        }
+        QuestionType::Multiple if correct == 0 => {
+            return Err(invalid("a multiple-choice question needs a correct option".to_string()));
+        }
+        QuestionType::Short if question.answers.is_empty() => {
+            return Err(invalid("a short-answer question needs answers".to_string()));
+        }
+        QuestionType::Short if question.matching == AnswerMatch::Regex => {
+            if let Some(answer) = question.answers.iter().find(|a| regex::Regex::new(a).is_err()) {
+                return Err(invalid(format!("invalid answer regex '{}'", answer)));
+            }
+        }
+        _ => {}
+    }
+
+    exercise.questions.push(question);
+    Ok(())
+}
+
 
 // --- Helper Functions ---
 
@@ -741,10 +1586,8 @@ fn find_excluded_ranges(markdown: &str) -> Vec<Range<usize>> {
 
     for (event, range) in parser {
         match event {
-            Event::Start(Tag::CodeBlock(_)) | Event::Start(Tag::HtmlBlock) => {
-                if block_start.is_none() {
-                    block_start = Some(range.start);
-                }
+            Event::Start(Tag::CodeBlock(_)) | Event::Start(Tag::HtmlBlock) if block_start.is_none() => {
+                block_start = Some(range.start);
             }
             Event::End(TagEnd::CodeBlock) | Event::End(TagEnd::HtmlBlock) => {
                 if let Some(start) = block_start {
@@ -752,10 +1595,8 @@ fn find_excluded_ranges(markdown: &str) -> Vec<Range<usize>> {
                     block_start = None;
                 }
             }
-            Event::Code(_) | Event::Html(_) => {
-                if block_start.is_none() {
-                    ranges.push(range);
-                }
+            Event::Code(_) | Event::Html(_) if block_start.is_none() => {
+                ranges.push(range);
             }
             _ => {}
         }
@@ -857,29 +1698,222 @@ fn parse_fence_info(info: &str) -> (String, HashMap<String, String>) {
     (lang, attrs)
 }
 
+/// Find the locked lines of starter code.
+///
+/// Lines are locked by a `locked=1-5,8` attribute (1-based line numbers of
+/// the code as written; `;` also separates ranges, for use in fence info) or
+/// by `@locked-begin`/`@locked-end` comment lines, which are removed. Returns
+/// the code without markers and the locked lines' 0-based numbers in it.
+fn parse_locked_lines(code: &str, ranges: Option<&str>, comment: &str) -> ParseResult<(String, Vec<usize>)> {
+    let invalid = || ParseError::InvalidAttribute {
+        attribute: "locked".to_string(),
+        value: ranges.unwrap_or_default().to_string(),
+    };
+    let mut from_attribute = Vec::new();
+    for range in ranges.unwrap_or_default().split([',', ';', ' ']).filter(|r| !r.is_empty()) {
+        let (first, last) = range.split_once('-').unwrap_or((range, range));
+        let first: usize = first.trim().parse().map_err(|_| invalid())?;
+        let last: usize = last.trim().parse().map_err(|_| invalid())?;
+        if first == 0 || last < first {
+            return Err(invalid());
+        }
+        from_attribute.extend(first - 1..last);
+    }
+
+    let begin = format!("{} @locked-begin", comment);
+    let end = format!("{} @locked-end", comment);
+    let mut lines = Vec::new();
+    let mut locked = Vec::new();
+    let mut in_region = false;
+    for (i, line) in code.lines().enumerate() {
+        match line.trim() {
+            marker if marker == begin => in_region = true,
+            marker if marker == end => in_region = false,
+            _ => {
+                if in_region || from_attribute.contains(&i) {
+                    locked.push(lines.len());
+                }
+                lines.push(line);
+            }
+        }
+    }
+    Ok((lines.join("\n"), locked))
+}
+
+/// Derive starter code from a solution's markers: lines between
+/// `{comment} region:student` and `{comment} endregion` are the student's
+/// work, and `{comment} @todo` marks a function whose body is. Each becomes
+/// `placeholder` in the starter.
+///
+/// Returns the solution without the markers and the starter, or `None` when
+/// the code has no markers.
+fn derive_starter(code: &str, comment: &str, placeholder: &str) -> ParseResult<Option<(String, String)>> {
+    let begin = format!("{} region:student", comment);
+    let end = format!("{} endregion", comment);
+    let todo = format!("{} @todo", comment);
+    let lines: Vec<&str> = code.lines().collect();
+    let is_marker = |line: &str| [begin.as_str(), end.as_str(), todo.as_str()].contains(&line.trim());
+    if !lines.iter().any(|l| is_marker(l)) {
+        return Ok(None);
+    }
+    let misplaced = |marker: &str, i: usize| ParseError::InvalidMarker {
+        marker: marker.to_string(),
+        line: i + 1,
+    };
+
+    let mut solution = Vec::new();
+    let mut starter = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        let indent = &line[..line.len() - line.trim_start().len()];
+        match line.trim() {
+            marker if marker == begin => {
+                let close = lines[i + 1..]
+                    .iter()
+                    .position(|l| l.trim() == end)
+                    .map(|p| i + 1 + p)
+                    .ok_or_else(|| misplaced(&begin, i))?;
+                if let Some(nested) = lines[i + 1..close].iter().position(|l| is_marker(l)) {
+                    return Err(misplaced(lines[i + 1 + nested].trim(), i + 1 + nested));
+                }
+                solution.extend(lines[i + 1..close].iter().map(|l| l.to_string()));
+                starter.push(format!("{}{}", indent, placeholder));
+                i = close + 1;
+            }
+            marker if marker == end => return Err(misplaced(&end, i)),
+            marker if marker == todo => {
+                let (consumed, stub) = stub_function(&lines[i + 1..], placeholder).ok_or_else(|| misplaced(&todo, i))?;
+                solution.extend(lines[i + 1..i + 1 + consumed].iter().map(|l| l.to_string()));
+                starter.push(stub);
+                i += 1 + consumed;
+            }
+            _ => {
+                solution.push(line.to_string());
+                starter.push(line.to_string());
+                i += 1;
+            }
+        }
+    }
+    Ok(Some((solution.join("\n"), starter.join("\n"))))
+}
+
+/// Replace the body of the function starting at `lines[0]` with `placeholder`.
+///
+/// Bodies are found by matching braces, or by indentation after a line
+/// ending in `:` (Python). Returns the number of lines the function spans
+/// and its stub.
+fn stub_function(lines: &[&str], placeholder: &str) -> Option<(usize, String)> {
+    let indent_of = |line: &str| line.len() - line.trim_start().len();
+    let indent = &lines.first()?[..indent_of(lines[0])];
+    let text = lines.join("\n");
+    let colon_line = lines.iter().position(|l| l.trim_end().ends_with(':'));
+    let open = text.find('{').filter(|&open| colon_line.is_none_or(|c| text[..open].matches('\n').count() <= c));
+
+    if let Some(open) = open {
+        let close = matching_close_brace(&text, open)?;
+        let rest = text[close + 1..].split('\n').next().unwrap_or_default();
+        let stub = format!("{}\n{}    {}\n{}}}{}", text[..=open].trim_end(), indent, placeholder, indent, rest);
+        return Some((text[..close].matches('\n').count() + 1, stub));
+    }
+
+    let header = colon_line?;
+    let body_indent = indent_of(lines[header]);
+    let end = lines[header + 1..]
+        .iter()
+        .take_while(|l| l.trim().is_empty() || indent_of(l) > body_indent)
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(k, _)| header + 2 + k)
+        .last()?;
+    let stub = format!("{}\n{}    {}", lines[..=header].join("\n"), &lines[header][..body_indent], placeholder);
+    Some((end, stub))
+}
+
+/// Index of the `}` matching the `{` at `open`, skipping strings and line comments.
+fn matching_close_brace(text: &str, open: usize) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut depth = 0usize;
+    let mut i = open;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'"' {
+                    i += if bytes[i] == b'\\' { 2 } else { 1 };
+                }
+            }
+            // Char literals ('"', '{'); lifetimes like 'a are left alone
+            b'\'' if bytes.get(i + 2) == Some(&b'\'') || bytes.get(i + 1) == Some(&b'\\') => {
+                let start = if bytes[i + 1] == b'\\' { i + 3 } else { i + 2 };
+                i = text.get(start..).and_then(|t| t.find('\'')).map(|p| start + p).unwrap_or(bytes.len());
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                i = text[i..].find('\n').map(|p| i + p).unwrap_or(bytes.len());
+                continue;
+            }
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Strip hidden lines using the fence's `hidelines=` prefix, or else the language's.
+fn strip_hidden(
+    code: String,
+    language: &str,
+    fence_attrs: &HashMap<String, String>,
+    languages: &LanguageRegistry,
+) -> (String, Vec<usize>) {
+    let prefix = fence_attrs
+        .get("hidelines")
+        .cloned()
+        .or_else(|| languages.get(language).and_then(|spec| spec.hidelines.clone()));
+    match prefix {
+        Some(prefix) if !prefix.is_empty() => strip_hidden_lines(&code, &prefix),
+        _ => (code, Vec::new()),
+    }
+}
+
 fn extract_code_block(content: &str) -> (Option<String>, String) {
-    let lines: Vec<&str> = content.lines().collect();
-    let mut in_code_block = false;
-    let mut language = None;
-    let mut code_lines = Vec::new();
+    extract_code_blocks(content)
+        .into_iter()
+        .next()
+        .unwrap_or((None, String::new()))
+}
+
+/// Every fenced code block in `content`, as (info string, code) pairs.
+fn extract_code_blocks(content: &str) -> Vec<(Option<String>, String)> {
+    let mut blocks = Vec::new();
+    let mut current: Option<(Option<String>, Vec<&str>)> = None;
 
-    for line in lines {
+    for line in content.lines() {
         if line.trim().starts_with("```") {
-            if in_code_block {
-                break;
-            } else {
-                in_code_block = true;
-                let lang = line.trim().trim_start_matches('`').trim();
-                if !lang.is_empty() {
-                    language = Some(lang.to_string());
+            match current.take() {
+                Some((language, code_lines)) => blocks.push((language, code_lines.join("\n"))),
+                None => {
+                    let lang = line.trim().trim_start_matches('`').trim();
+                    current = Some(((!lang.is_empty()).then(|| lang.to_string()), Vec::new()));
                 }
             }
-        } else if in_code_block {
+        } else if let Some((_, code_lines)) = current.as_mut() {
             code_lines.push(line);
         }
     }
+    // An unclosed fence runs to the end of the block
+    if let Some((language, code_lines)) = current {
+        blocks.push((language, code_lines.join("\n")));
+    }
 
-    (language, code_lines.join("\n"))
+    blocks
 }
 
 fn extract_explanation(content: &str) -> Option<String> {
@@ -1003,4 +2037,48 @@ Analyze the security.
             _ => panic!("Expected UseCase exercise"),
         }
     }
+
+    #[test]
+    fn test_derive_starter() {
+        let rust = "use std::fmt;\n\n// @todo\npub fn add(a: i32, b: i32) -> i32 {\n    let c = '}';\n    a + b // }\n}\n\npub fn double(x: i32) -> i32 {\n    // region:student\n    x * 2\n    // endregion\n}";
+        let (solution, starter) = derive_starter(rust, "//", "todo!()").unwrap().unwrap();
+        assert!(!solution.contains("@todo") && !solution.contains("region"));
+        assert!(solution.contains("a + b") && solution.contains("x * 2"));
+        assert_eq!(
+            starter,
+            "use std::fmt;\n\npub fn add(a: i32, b: i32) -> i32 {\n    todo!()\n}\n\npub fn double(x: i32) -> i32 {\n    todo!()\n}"
+        );
+
+        let python = "# @todo\ndef greet(name):\n    return f\"Hi {name}\"\n\n\ndef other():\n    pass";
+        let (_, starter) = derive_starter(python, "#", "raise NotImplementedError").unwrap().unwrap();
+        assert_eq!(starter, "def greet(name):\n    raise NotImplementedError\n\n\ndef other():\n    pass");
+
+        assert!(derive_starter("fn f() {}", "//", "todo!()").unwrap().is_none());
+        assert!(matches!(
+            derive_starter("// region:student\nx", "//", "todo!()"),
+            Err(ParseError::InvalidMarker { line: 1, .. })
+        ));
+        assert!(derive_starter("// @todo\nlet x = 1;", "//", "todo!()").is_err());
+    }
+
+    #[test]
+    fn test_read_source() {
+        let dir = tempfile::tempdir().unwrap();
+        let code = "use std::fmt;\n// ANCHOR: add\npub fn add(a: i32, b: i32) -> i32 {\n    // ANCHOR: body\n    a + b\n    // ANCHOR_END: body\n}\n// ANCHOR_END: add\n";
+        fs::write(dir.path().join("lib.rs"), code).unwrap();
+        let read = |source: &str| read_source(source, Some(dir.path()));
+
+        assert_eq!(read("lib.rs#add").unwrap(), "pub fn add(a: i32, b: i32) -> i32 {\n    a + b\n}");
+        assert_eq!(read("lib.rs:body").unwrap(), "    a + b");
+        assert_eq!(read("lib.rs:1").unwrap(), "use std::fmt;");
+        assert_eq!(read("lib.rs:3:5").unwrap(), "pub fn add(a: i32, b: i32) -> i32 {\n    a + b");
+        assert_eq!(read("lib.rs::3").unwrap(), "use std::fmt;\npub fn add(a: i32, b: i32) -> i32 {");
+        assert_eq!(read("lib.rs:7:").unwrap(), "}");
+        assert!(read("lib.rs").unwrap().starts_with("use std::fmt;\npub fn add"));
+
+        assert!(matches!(read("lib.rs#missing"), Err(ParseError::Include { .. })));
+        assert!(read("lib.rs:5:2").is_err());
+        assert!(read("lib.rs:100").is_err());
+        assert!(read("missing.rs").is_err());
+    }
 }
@@ -3,13 +3,17 @@
 //! This module provides the mdBook preprocessor that transforms exercise
 //! directives in markdown files into interactive HTML.
 
-use crate::parser::parse_exercise;
+use crate::language::{LanguageOverrides, LanguageRegistry};
+use crate::parser::{parse_exercise_with_options, ParseOptions};
 use crate::render::{render_exercise_with_config, RenderConfig};
-use mdbook::book::{Book, BookItem};
+use crate::protocol::{playground_missing_crates, ExecutionBackend};
+use crate::types::{Edition, ParsedExercise, TestMode, ToolchainSettings};
+use mdbook::book::{Book, BookItem, Chapter};
 use mdbook::errors::Error;
 use mdbook::preprocess::{Preprocessor, PreprocessorContext};
 use regex::Regex;
-use std::path::Path;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
 /// The mdBook preprocessor for exercises.
 pub struct ExercisesPreprocessor;
@@ -42,6 +46,12 @@ impl ExercisesPreprocessor {
                     config.playground_url = url.to_string();
                 }
             }
+            if let Some(backend) = exercises_config.get("execution_backend").and_then(|v| v.as_str()) {
+                match backend.parse() {
+                    Ok(backend) => config.execution_backend = backend,
+                    Err(e) => eprintln!("[WARN] (mdbook-exercises): {}; using the playground", e),
+                }
+            }
             if let Some(progress) = exercises_config.get("progress_tracking") {
                 config.enable_progress = progress.as_bool().unwrap_or(true);
             }
@@ -50,19 +60,96 @@ impl ExercisesPreprocessor {
             }
         }
 
+        match Self::load_languages(&ctx.config) {
+            Ok(languages) => config.languages = languages,
+            Err(e) => eprintln!("[WARN] (mdbook-exercises): Ignoring language settings: {:#}", e),
+        }
+        match Self::load_toolchain(&ctx.config) {
+            Ok(toolchain) => config.toolchain = toolchain,
+            Err(e) => eprintln!("[WARN] (mdbook-exercises): Ignoring toolchain settings: {:#}", e),
+        }
+        config.private_dir = Self::load_private_dir(&ctx.config, &ctx.root);
+        if let Some(dir) = config.private_dir.as_ref().filter(|d| !d.is_dir()) {
+            eprintln!(
+                "[INFO] (mdbook-exercises): private_dir '{}' not found; building without private solutions and tests",
+                dir.display()
+            );
+        }
+
         config
     }
 
+    /// Build the language registry from `default_language` and
+    /// `[preprocessor.exercises.languages.*]` in book.toml.
+    pub fn load_languages(config: &mdbook::Config) -> Result<LanguageRegistry, Error> {
+        let mut languages = LanguageRegistry::default();
+        if let Some(default) = config
+            .get("preprocessor.exercises.default_language")
+            .and_then(|v| v.as_str())
+        {
+            languages.set_default_language(default);
+        }
+        let overrides: Option<BTreeMap<String, LanguageOverrides>> =
+            config.get_deserialized_opt("preprocessor.exercises.languages")?;
+        for (name, language) in overrides.unwrap_or_default() {
+            languages.apply(&name, &language);
+        }
+        Ok(languages)
+    }
+
+    /// Load book-level toolchain defaults from `[preprocessor.exercises.toolchain]`.
+    ///
+    /// The edition falls back to mdBook's own `[rust] edition`.
+    pub fn load_toolchain(config: &mdbook::Config) -> Result<ToolchainSettings, Error> {
+        let mut toolchain: ToolchainSettings = config
+            .get_deserialized_opt("preprocessor.exercises.toolchain")?
+            .unwrap_or_default();
+        if toolchain.edition.is_none() {
+            toolchain.edition = config.rust.edition.map(|edition| match edition {
+                mdbook::config::RustEdition::E2015 => Edition::E2015,
+                mdbook::config::RustEdition::E2018 => Edition::E2018,
+                mdbook::config::RustEdition::E2021 => Edition::E2021,
+                mdbook::config::RustEdition::E2024 => Edition::E2024,
+            });
+        }
+        Ok(toolchain)
+    }
+
+    /// The `private_dir` setting, relative to the book root.
+    pub fn load_private_dir(config: &mdbook::Config, root: &Path) -> Option<PathBuf> {
+        config
+            .get("preprocessor.exercises.private_dir")
+            .and_then(|v| v.as_str())
+            .map(|dir| root.join(dir))
+    }
+
+    /// Parse options matching the render configuration, for a markdown file in `base_dir`.
+    fn parse_options(config: &RenderConfig, base_dir: Option<&Path>) -> ParseOptions {
+        ParseOptions {
+            languages: config.languages.clone(),
+            private_dir: config.private_dir.clone(),
+            base_dir: base_dir.map(Path::to_path_buf),
+        }
+    }
+
+    /// The directory of a chapter's source file, which `source=` paths are relative to.
+    fn chapter_dir(src_dir: &Path, chapter: &Chapter) -> Option<PathBuf> {
+        let path = chapter.source_path.as_ref().or(chapter.path.as_ref())?;
+        Some(src_dir.join(path).parent()?.to_path_buf())
+    }
+
     /// Process a single chapter's content.
-    fn process_chapter(content: &str, config: &RenderConfig) -> Result<String, Error> {
+    fn process_chapter(content: &str, config: &RenderConfig, base_dir: Option<&Path>) -> Result<String, Error> {
         // First, check if the content has any exercise directives
-        if !content.contains("::: exercise") && !content.contains("::: usecase") {
+        if !["::: exercise", "::: usecase", "::: quiz"].iter().any(|d| content.contains(d)) {
             return Ok(content.to_string());
         }
 
         // Parse the exercise from the content
-        match parse_exercise(content) {
+        match parse_exercise_with_options(content, &Self::parse_options(config, base_dir)) {
             Ok(exercise) => {
+                Self::warn_unavailable_crates(&exercise, config);
+                Self::warn_unrunnable_hidden_tests(&exercise, config);
                 // If we successfully parsed an exercise, render it
                 match render_exercise_with_config(&exercise, config) {
                     Ok(html) => {
@@ -93,6 +180,49 @@ impl ExercisesPreprocessor {
             }
         }
     }
+
+    /// Warn when playground tests depend on crates the Playground doesn't have.
+    fn warn_unavailable_crates(parsed: &ParsedExercise, config: &RenderConfig) {
+        let ParsedExercise::Code(exercise) = parsed else { return };
+        let runs_on_playground = config.enable_playground
+            && config.execution_backend == ExecutionBackend::Playground
+            && exercise.tests.as_ref().is_some_and(|t| {
+                t.mode == TestMode::Playground && config.languages.get(&t.language).is_some_and(|s| s.playground)
+            });
+        if !runs_on_playground {
+            return;
+        }
+        for dep in playground_missing_crates(&exercise.metadata.dependencies) {
+            eprintln!(
+                "[WARN] (mdbook-exercises): Exercise '{}' depends on `{}`, which the Rust Playground doesn't provide; its tests will fail to compile there",
+                exercise.metadata.id, dep.name
+            );
+        }
+    }
+
+    /// Warn when hidden tests, or I/O cases that need input, can't run
+    /// because there is no execution server.
+    fn warn_unrunnable_hidden_tests(parsed: &ParsedExercise, config: &RenderConfig) {
+        let ParsedExercise::Code(exercise) = parsed else { return };
+        if exercise.hidden_tests.is_some() && config.execution_backend == ExecutionBackend::Playground {
+            eprintln!(
+                "[WARN] (mdbook-exercises): Exercise '{}' has hidden tests, which only run with an `execution_backend` server; readers can't run them on the Rust Playground",
+                exercise.metadata.id
+            );
+        }
+        let needs_input = exercise
+            .io_tests
+            .iter()
+            .flat_map(|io| &io.cases)
+            .filter(|case| !case.stdin.is_empty() || !case.args.is_empty())
+            .count();
+        if needs_input > 0 && config.execution_backend == ExecutionBackend::Playground {
+            eprintln!(
+                "[WARN] (mdbook-exercises): Exercise '{}' has {} input/output case(s) with stdin or arguments, which only run with an `execution_backend` server; the Rust Playground skips them",
+                exercise.metadata.id, needs_input
+            );
+        }
+    }
 }
 
 impl Default for ExercisesPreprocessor {
@@ -134,10 +264,12 @@ impl Preprocessor for ExercisesPreprocessor {
         }
 
         // Process each chapter
+        let src_dir = ctx.root.join(&ctx.config.book.src);
         book.for_each_mut(|item| {
             if let BookItem::Chapter(chapter) = item {
+                let base_dir = Self::chapter_dir(&src_dir, chapter);
                 if let Some(ref mut content) = Some(&mut chapter.content) {
-                    match Self::process_chapter(content, &config) {
+                    match Self::process_chapter(content, &config, base_dir.as_deref()) {
                         Ok(new_content) => {
                             chapter.content = new_content;
                         }
@@ -199,7 +331,10 @@ impl ExerciseIncludeProcessor {
             let full_path = self.book_root.join(exercise_path);
 
             match std::fs::read_to_string(&full_path) {
-                Ok(exercise_content) => match parse_exercise(&exercise_content) {
+                Ok(exercise_content) => match parse_exercise_with_options(
+                    &exercise_content,
+                    &ExercisesPreprocessor::parse_options(&self.config, full_path.parent()),
+                ) {
                     Ok(exercise) => match render_exercise_with_config(&exercise, &self.config) {
                         Ok(html) => {
                             let wrapped = format!(
@@ -312,8 +447,9 @@ impl Preprocessor for FullExercisesPreprocessor {
                 };
 
                 // Then, process inline exercises
+                let base_dir = ExercisesPreprocessor::chapter_dir(&book_root, chapter);
                 let final_content =
-                    match ExercisesPreprocessor::process_chapter(&after_includes, &config) {
+                    match ExercisesPreprocessor::process_chapter(&after_includes, &config, base_dir.as_deref()) {
                         Ok(c) => c,
                         Err(e) => {
                             eprintln!(
@@ -337,38 +473,68 @@ impl Preprocessor for FullExercisesPreprocessor {
 }
 
 impl ExercisesPreprocessor {
-    /// Replace the contiguous exercise directive region with rendered HTML, preserving surrounding content.
+    /// Replace the exercise's region with rendered HTML, preserving surrounding content.
+    ///
+    /// The region runs from the exercise's first directive (`exercise`, `usecase`
+    /// or `quiz`) to the end of its last directive block, so none of its blocks
+    /// (solutions, hidden tests, quiz answers) are passed through as markdown.
+    /// It stops before another exercise starts; directives inside code fences
+    /// don't count.
     fn replace_exercise_region(content: &str, rendered_html: &str) -> String {
-        // Find start of the exercise region (exercise or usecase)
-        let re_start = Regex::new(r"(?m)^\s*:::\s+(exercise|usecase)\b").unwrap();
-        let Some(m) = re_start.find(content) else { return content.to_string(); };
-
-        // Count directive starts/ends to find the end of the region
+        let re_start = Regex::new(r"^\s*:::\s+(exercise|usecase|quiz)\b").unwrap();
         let re_open = Regex::new(r"^\s*:::\s+[a-zA-Z]").unwrap();
         let re_close = Regex::new(r"^\s*:::\s*$").unwrap();
+
+        let mut start_idx = None;
+        let mut end_idx = None;
         let mut open: i32 = 0;
-        let mut in_region = false;
-        let mut end_idx = content.len();
+        let mut fence: Option<String> = None;
         let mut offset = 0usize;
         for line in content.split_inclusive('\n') {
             let ls = offset;
             let le = offset + line.len();
             offset = le;
-            if ls < m.start() { continue; }
-            let t = line.trim_end_matches(['\n','\r']);
+            let t = line.trim_end_matches(['\n', '\r']);
+
+            // Skip code fences, both in and out of blocks
+            let trimmed = t.trim_start();
+            let ticks = trimmed.len() - trimmed.trim_start_matches('`').len();
+            if let Some(marker) = &fence {
+                if ticks >= marker.len() && trimmed.trim_start_matches('`').trim().is_empty() {
+                    fence = None;
+                }
+                continue;
+            }
+            if ticks >= 3 {
+                fence = Some("`".repeat(ticks));
+                continue;
+            }
+
             if re_open.is_match(t) {
-                if !in_region { in_region = true; }
-                open += 1;
-            } else if in_region && re_close.is_match(t) {
+                if open == 0 && re_start.is_match(t) {
+                    // A second exercise starts: the first one's region is over
+                    if start_idx.is_some() {
+                        break;
+                    }
+                    start_idx = Some(ls);
+                }
+                if start_idx.is_some() {
+                    open += 1;
+                }
+            } else if start_idx.is_some() && open > 0 && re_close.is_match(t) {
                 open -= 1;
-                if open <= 0 { end_idx = le; break; }
+                if open == 0 {
+                    end_idx = Some(le);
+                }
             }
         }
 
+        let Some(start) = start_idx else { return content.to_string() };
+        let end = end_idx.unwrap_or(content.len());
         let mut out = String::new();
-        out.push_str(&content[..m.start()]);
+        out.push_str(&content[..start]);
         out.push_str(&format!("<div class=\"exercise-container\">\n{}\n</div>\n", rendered_html));
-        out.push_str(&content[end_idx..]);
+        out.push_str(&content[end..]);
         out
     }
 
@@ -434,7 +600,7 @@ mod tests {
         let content = "# Just a normal chapter\n\nSome content here.";
         let config = RenderConfig::default();
 
-        let result = ExercisesPreprocessor::process_chapter(content, &config).unwrap();
+        let result = ExercisesPreprocessor::process_chapter(content, &config, None).unwrap();
 
         // Should return unchanged
         assert_eq!(result, content);
@@ -459,13 +625,105 @@ fn main() {}
 "#;
         let config = RenderConfig::default();
 
-        let result = ExercisesPreprocessor::process_chapter(content, &config).unwrap();
+        let result = ExercisesPreprocessor::process_chapter(content, &config, None).unwrap();
 
         // Should contain rendered HTML
         assert!(result.contains("exercise-container"));
         assert!(result.contains("test-ex"));
     }
     
+    #[test]
+    fn test_process_chapter_hides_hidden_tests() {
+        let content = r#"# My Exercise
+
+::: exercise
+id: test-hidden
+difficulty: beginner
+:::
+
+::: starter
+```rust
+pub fn double(x: i32) -> i32 { todo!() }
+```
+:::
+
+::: tests
+```rust
+#[test]
+fn test_visible() { assert_eq!(double(2), 4); }
+```
+:::
+
+::: hidden-tests
+```rust
+#[test]
+fn test_secret_edge_case() { assert_eq!(double(-3), -6); }
+```
+:::
+
+::: tests visibility=hidden
+```rust
+#[test]
+fn test_other_secret() { assert_eq!(double(0), 0); }
+```
+:::
+
+Text after the exercise.
+"#;
+        let config = RenderConfig::default();
+
+        let result = ExercisesPreprocessor::process_chapter(content, &config, None).unwrap();
+
+        assert!(result.contains("test_visible"));
+        assert!(!result.contains("test_secret_edge_case"));
+        assert!(!result.contains("test_other_secret"));
+        assert!(!result.contains("::: "));
+        assert!(result.starts_with("# My Exercise\n"));
+        assert!(result.contains("Text after the exercise."));
+    }
+
+    #[test]
+    fn test_replace_exercise_region_stops_at_next_exercise() {
+        let content = "Intro\n\n::: exercise\nid: a\n:::\n\n::: starter\n```rust\n:::\n```\n:::\n\nBetween\n\n::: exercise\nid: b\n:::\n";
+        let result = ExercisesPreprocessor::replace_exercise_region(content, "<p>A</p>");
+        assert_eq!(
+            result,
+            "Intro\n\n<div class=\"exercise-container\">\n<p>A</p>\n</div>\n\nBetween\n\n::: exercise\nid: b\n:::\n"
+        );
+    }
+
+    #[test]
+    fn test_process_chapter_with_parsons() {
+        let content = r#"# Parsons
+
+::: exercise
+id: test-parsons
+difficulty: beginner
+:::
+
+::: parsons
+```rust
+fn answer() -> i32 {
+    let secret_value = 42;
+    secret_value
+}
+```
+
+```rust,distractors
+    let secret_value = 24;
+```
+:::
+"#;
+        let config = RenderConfig::default();
+
+        let result = ExercisesPreprocessor::process_chapter(content, &config, None).unwrap();
+
+        assert!(result.contains("exercise-parsons"));
+        assert!(!result.contains("::: parsons"));
+        assert!(!result.contains("fn answer() -> i32 {\n    let secret_value = 42;"));
+        assert!(!result.contains("```"));
+    }
+
     #[test]
     fn test_process_chapter_with_usecase() {
         let content = r#"# My UseCase
@@ -486,11 +744,50 @@ Prompt...
 "#;
         let config = RenderConfig::default();
 
-        let result = ExercisesPreprocessor::process_chapter(content, &config).unwrap();
+        let result = ExercisesPreprocessor::process_chapter(content, &config, None).unwrap();
 
         // Should contain rendered HTML
         assert!(result.contains("exercise-container"));
         assert!(result.contains("test-uc"));
         assert!(result.contains("usecase-exercise"));
     }
+
+    #[test]
+    fn test_process_chapter_with_quiz() {
+        let content = r#"# Chapter
+
+Some reading first.
+
+::: quiz
+id: test-quiz
+:::
+
+::: question
+prompt: Which keyword makes a binding mutable?
+answers: [mut]
+:::
+
+::: question
+prompt: Which types are `Copy`?
+options:
+  - text: "`i32`"
+    correct: true
+  - text: "`String`"
+:::
+
+More reading after.
+"#;
+        let config = RenderConfig::default();
+
+        let result = ExercisesPreprocessor::process_chapter(content, &config, None).unwrap();
+
+        assert!(result.contains("Some reading first."));
+        assert!(result.contains(r#"<article class="quiz-exercise" data-exercise-id="test-quiz""#));
+        assert!(result.contains("More reading after."));
+        // The questions are only in the rendered quiz, not repeated with their answers
+        assert!(!result.contains("::: question"));
+        assert!(!result.contains("answers:"));
+        assert!(!result.contains("correct: true"));
+        assert!(!result.contains("btn-complete"));
+    }
 }
@@ -0,0 +1,419 @@
+//! JSON protocol for remote test execution.
+//!
+//! The browser sends an [`ExecutionRequest`] (language, source files, test
+//! code, input/output cases, limits) to the book's execution backend and gets
+//! back an [`ExecutionResponse`] with per-test results. The Rust Playground is
+//! supported through an adapter in `exercises.js`; any service that speaks
+//! this protocol can be configured with `execution_backend` instead.
+//!
+//! See `docs/EXECUTION_PROTOCOL.md` for the wire format.
+
+use crate::runner::{run_suite, runner_for, RunOptions, RunResult, RunStatus, TestCaseResult, TestStatus, TestSuite};
+use crate::types::{locked_regions_preserved, Dependency, IoTestCase, Requirements, StarterCode, ToolchainSettings};
+pub use crate::types::SourceFile;
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::time::Duration;
+
+/// Version of the protocol described here.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// A request to run an exercise's tests against some code.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionRequest {
+    /// Protocol version (currently 1)
+    #[serde(default = "default_version")]
+    pub version: u32,
+
+    /// Exercise the code belongs to, if known
+    #[serde(default)]
+    pub exercise_id: Option<String>,
+
+    /// Code block language (name or alias, e.g., "rust" or "py")
+    pub language: String,
+
+    /// Student source files; the first is the one combined with the tests
+    pub files: Vec<SourceFile>,
+
+    /// Test code, combined with the first file using the language's strategy
+    #[serde(default)]
+    pub tests: String,
+
+    /// Input/output cases; the first file is also run as a program once per case
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub io_tests: Vec<IoTestCase>,
+
+    /// Resource limits requested by the client
+    #[serde(default)]
+    pub limits: ExecutionLimits,
+
+    /// Rust toolchain settings (edition, channel, build mode, crate type, backtrace)
+    #[serde(default)]
+    pub toolchain: ToolchainSettings,
+
+    /// Crates the code depends on
+    #[serde(default)]
+    pub dependencies: Vec<Dependency>,
+
+    /// Locked regions of the starter, which the first file must still contain
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locked: Vec<String>,
+}
+
+fn default_version() -> u32 {
+    PROTOCOL_VERSION
+}
+
+/// Resource limits for one execution. Servers may lower them further.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExecutionLimits {
+    /// Wall-clock limit in milliseconds
+    pub timeout_ms: u64,
+
+    /// Maximum bytes of stdout and of stderr returned
+    pub max_output_bytes: usize,
+}
+
+impl Default for ExecutionLimits {
+    fn default() -> Self {
+        Self {
+            timeout_ms: 30_000,
+            max_output_bytes: 64 * 1024,
+        }
+    }
+}
+
+/// The result of an execution: overall status, per-test results and output.
+///
+/// This is the same shape the local runner reports, so
+/// `mdbook-exercises test --format json` and remote backends agree.
+pub type ExecutionResponse = RunResult;
+
+/// Where the browser sends test runs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ExecutionBackend {
+    /// The Rust Playground (at `playground_url`), through the built-in adapter
+    #[default]
+    Playground,
+
+    /// A service implementing this protocol at the given URL
+    Remote(String),
+}
+
+impl ExecutionBackend {
+    /// Adapter name used by `exercises.js` (`data-backend`).
+    pub fn adapter(&self) -> &'static str {
+        match self {
+            ExecutionBackend::Playground => "playground",
+            ExecutionBackend::Remote(_) => "protocol",
+        }
+    }
+}
+
+impl std::fmt::Display for ExecutionBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExecutionBackend::Playground => write!(f, "playground"),
+            ExecutionBackend::Remote(url) => write!(f, "{}", url),
+        }
+    }
+}
+
+impl std::str::FromStr for ExecutionBackend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("playground") {
+            Ok(ExecutionBackend::Playground)
+        } else if s.starts_with("http://") || s.starts_with("https://") || s.starts_with('/') {
+            Ok(ExecutionBackend::Remote(s.to_string()))
+        } else {
+            Err(format!("Invalid execution backend: {} (expected \"playground\" or a URL)", s))
+        }
+    }
+}
+
+/// Crates available on the Rust Playground (a snapshot of its top crates;
+/// names normalized to underscores).
+///
+/// Used to warn at build time about dependencies the Playground can't
+/// provide. The browser checks the live list (`/meta/crates`) before running.
+pub const PLAYGROUND_CRATES: &[&str] = &[
+    "addr2line", "adler", "ahash", "aho_corasick", "anyhow", "approx", "arrayvec", "async_trait", "autocfg",
+    "base64", "bitflags", "block_buffer", "byteorder", "bytes", "cc", "cfg_if", "chrono", "clap", "crossbeam",
+    "crossbeam_channel", "crossbeam_utils", "digest", "either", "env_logger", "futures", "getrandom", "hashbrown",
+    "hex", "http", "indexmap", "itertools", "itoa", "lazy_static", "libc", "log", "memchr", "mio", "nom", "num",
+    "num_bigint", "num_traits", "once_cell", "parking_lot", "percent_encoding", "pin_project_lite", "proc_macro2",
+    "quote", "rand", "rand_chacha", "rand_core", "rayon", "regex", "ryu", "scopeguard", "semver", "serde",
+    "serde_derive", "serde_json", "sha2", "smallvec", "socket2", "strsim", "syn", "tempfile", "thiserror", "time",
+    "tokio", "tracing", "unicode_segmentation", "url", "uuid",
+];
+
+/// Dependencies the Rust Playground doesn't provide.
+pub fn playground_missing_crates(dependencies: &[Dependency]) -> Vec<&Dependency> {
+    dependencies
+        .iter()
+        .filter(|d| !PLAYGROUND_CRATES.contains(&d.name.replace('-', "_").as_str()))
+        .collect()
+}
+
+/// Serve an execution request locally with the language's test runner.
+///
+/// The request's timeout is capped by `options.timeout`, and output is
+/// truncated to the request's `max_output_bytes`.
+pub fn execute(request: &ExecutionRequest, options: &RunOptions) -> io::Result<ExecutionResponse> {
+    execute_with_hidden_tests(request, None, options)
+}
+
+/// Like [`execute`], also running `hidden_tests` the client never sees.
+///
+/// Hidden test results are marked `hidden` and their messages and output
+/// are redacted from the response.
+pub fn execute_with_hidden_tests(
+    request: &ExecutionRequest,
+    hidden_tests: Option<&str>,
+    options: &RunOptions,
+) -> io::Result<ExecutionResponse> {
+    let unsupported = |msg: String| io::Error::new(io::ErrorKind::InvalidInput, msg);
+    if request.version != PROTOCOL_VERSION {
+        return Err(unsupported(format!("unsupported protocol version {}", request.version)));
+    }
+    let spec = options
+        .languages
+        .get(&request.language)
+        .ok_or_else(|| unsupported(format!("unknown language '{}'", request.language)))?;
+    let kind = spec
+        .test_runner
+        .ok_or_else(|| unsupported(format!("no runner for language '{}'", request.language)))?;
+    let main = request
+        .files
+        .first()
+        .ok_or_else(|| unsupported("request has no files".to_string()))?;
+
+    let runner = runner_for(kind);
+    let filename = if main.path.is_empty() {
+        spec.default_filename
+            .clone()
+            .unwrap_or_else(|| runner.default_filename().to_string())
+    } else {
+        main.path.clone()
+    };
+    if filename.starts_with('/') || filename.split(['/', '\\']).any(|part| part == "..") {
+        return Err(unsupported(format!("invalid file path '{}'", filename)));
+    }
+
+    // Each dependency must stay a single `Cargo.toml` line
+    for dependency in &request.dependencies {
+        let line = dependency.cargo_toml_line();
+        if line.contains('\n') || line.parse::<Dependency>().map(|d| d != *dependency).unwrap_or(true) {
+            return Err(unsupported(format!("invalid dependency '{}'", line)));
+        }
+    }
+
+    if !locked_regions_preserved(&main.content, &request.locked) {
+        return Ok(RunResult {
+            status: RunStatus::CompileError,
+            tests: Vec::new(),
+            stdout: String::new(),
+            stderr: "error: a locked region of the starter code was changed\n".to_string(),
+            duration_ms: 0,
+            lints: Vec::new(),
+            format_diff: None,
+        });
+    }
+
+    let mut run_options = options.clone();
+    run_options.timeout = run_options.timeout.min(Duration::from_millis(request.limits.timeout_ms));
+    run_options.toolchain = request.toolchain.or(options.toolchain);
+    run_options.dependencies = request.dependencies.clone();
+    run_options.files = request.files[1..].to_vec();
+    let suite = TestSuite {
+        visible: &request.tests,
+        hidden: hidden_tests,
+        io: &request.io_tests,
+        redact_hidden: true,
+    };
+    let mut result = run_suite(runner.as_ref(), spec, &main.content, suite, &filename, &run_options)?;
+    truncate_output(&mut result.stdout, request.limits.max_output_bytes);
+    truncate_output(&mut result.stderr, request.limits.max_output_bytes);
+    Ok(result)
+}
+
+/// The response for a request whose first file breaks an exercise's
+/// requirements: a failed test per broken rule, without running anything.
+/// `None` when the code meets them. With the exercise's `starter`, its hidden
+/// lines (which the browser adds back around the editor's code) aren't checked.
+pub fn requirements_response(
+    request: &ExecutionRequest,
+    requirements: &Requirements,
+    starter: Option<&StarterCode>,
+    options: &RunOptions,
+) -> Option<ExecutionResponse> {
+    let code = request.files.first().map(|f| f.content.as_str()).unwrap_or_default();
+    let code = starter.map_or(code, |starter| starter.visible_code(code));
+    let comment = options.languages.get(&request.language).map_or("//", |s| s.comment.as_str());
+    let violations = requirements.violations(code, comment);
+    if violations.is_empty() {
+        return None;
+    }
+    Some(RunResult {
+        status: RunStatus::Failed,
+        tests: violations
+            .into_iter()
+            .map(|violation| TestCaseResult {
+                name: violation,
+                status: TestStatus::Failed,
+                message: None,
+                assertion: None,
+                hidden: false,
+            })
+            .collect(),
+        stdout: String::new(),
+        stderr: String::new(),
+        duration_ms: 0,
+        lints: Vec::new(),
+        format_diff: None,
+    })
+}
+
+fn truncate_output(output: &mut String, max_bytes: usize) {
+    if output.len() > max_bytes {
+        let mut end = max_bytes;
+        while !output.is_char_boundary(end) {
+            end -= 1;
+        }
+        output.truncate(end);
+        output.push_str("\n[output truncated]");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_defaults() {
+        let request: ExecutionRequest = serde_json::from_str(
+            r#"{"language": "py", "files": [{"content": "def f(): pass"}], "tests": "assert f() is None"}"#,
+        )
+        .unwrap();
+        assert_eq!(request.version, PROTOCOL_VERSION);
+        assert_eq!(request.files[0].path, "");
+        assert_eq!(request.limits, ExecutionLimits::default());
+    }
+
+    #[test]
+    fn test_execution_backend_from_str() {
+        assert_eq!("playground".parse::<ExecutionBackend>(), Ok(ExecutionBackend::Playground));
+        assert_eq!(
+            "http://localhost:8080/execute".parse::<ExecutionBackend>(),
+            Ok(ExecutionBackend::Remote("http://localhost:8080/execute".to_string()))
+        );
+        assert!("somewhere".parse::<ExecutionBackend>().is_err());
+    }
+
+    #[test]
+    fn test_execute_rejects_bad_requests() {
+        let options = RunOptions::default();
+        let mut request = ExecutionRequest {
+            version: PROTOCOL_VERSION,
+            exercise_id: None,
+            language: "cobol".to_string(),
+            files: vec![SourceFile {
+                path: "../escape.rs".to_string(),
+                content: String::new(),
+            }],
+            tests: String::new(),
+            io_tests: Vec::new(),
+            limits: ExecutionLimits::default(),
+            toolchain: ToolchainSettings::default(),
+            dependencies: Vec::new(),
+            locked: Vec::new(),
+        };
+        assert!(execute(&request, &options).is_err());
+        request.language = "rust".to_string();
+        assert!(execute(&request, &options).is_err());
+    }
+
+    #[test]
+    fn test_execute_checks_locked_regions() {
+        let request: ExecutionRequest = serde_json::from_str(
+            r#"{"language": "rust", "files": [{"content": "pub fn add(a: u8) -> u8 { a }"}],
+                "locked": ["pub fn add(a: i32, b: i32) -> i32 {"]}"#,
+        )
+        .unwrap();
+        let result = execute(&request, &RunOptions::default()).unwrap();
+        assert_eq!(result.status, RunStatus::CompileError);
+        assert!(result.stderr.contains("locked region"));
+    }
+
+    #[test]
+    fn test_requirements_response() {
+        let request: ExecutionRequest = serde_json::from_str(
+            r#"{"language": "rust", "files": [{"content": "// no unwrap() here\nfn first(v: &[i32]) -> i32 {\n    *v.first().unwrap()\n}\n"}]}"#,
+        )
+        .unwrap();
+        let requirements = Requirements {
+            must_use: vec!["match".to_string()],
+            must_not_use: vec!["unwrap()".to_string(), "first".to_string()],
+            max_lines: Some(2),
+        };
+        let result = requirements_response(&request, &requirements, None, &RunOptions::default()).unwrap();
+        assert_eq!(result.status, RunStatus::Failed);
+        let names: Vec<&str> = result.tests.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "must use `match`",
+                "must not use `unwrap()`",
+                "must not use `first`",
+                "has 3 lines of code, more than 2"
+            ]
+        );
+
+        let relaxed = Requirements {
+            must_not_use: vec!["fir".to_string()],
+            ..Requirements::default()
+        };
+        assert!(requirements_response(&request, &relaxed, None, &RunOptions::default()).is_none());
+    }
+
+    #[test]
+    fn test_requirements_response_skips_hidden_lines() {
+        let request: ExecutionRequest = serde_json::from_str(
+            r#"{"language": "rust", "files": [{"content": "use std::collections::HashMap;\nfn count() -> usize {\n    0\n}\nfn main() { println!(\"{}\", count()); }"}]}"#,
+        )
+        .unwrap();
+        let requirements = Requirements {
+            must_not_use: vec!["HashMap".to_string(), "main".to_string()],
+            max_lines: Some(3),
+            ..Requirements::default()
+        };
+        let starter = StarterCode {
+            code: "use std::collections::HashMap;\nfn count() -> usize {\n    todo!()\n}\nfn main() { println!(\"{}\", count()); }".to_string(),
+            hidden_lines: vec![0, 4],
+            ..StarterCode::default()
+        };
+        assert!(requirements_response(&request, &requirements, Some(&starter), &RunOptions::default()).is_none());
+        assert!(requirements_response(&request, &requirements, None, &RunOptions::default()).is_some());
+    }
+
+    #[test]
+    fn test_playground_missing_crates() {
+        let deps: Vec<Dependency> = ["serde = \"1\"", "serde-json", "my_private_crate = \"0.1\""]
+            .iter()
+            .map(|d| d.parse().unwrap())
+            .collect();
+        let missing: Vec<&str> = playground_missing_crates(&deps).iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(missing, vec!["my_private_crate"]);
+    }
+
+    #[test]
+    fn test_truncate_output() {
+        let mut output = "héllo".to_string();
+        truncate_output(&mut output, 2);
+        assert_eq!(output, "h\n[output truncated]");
+    }
+}
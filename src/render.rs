@@ -3,8 +3,11 @@
 //! This module transforms parsed exercises into HTML suitable for
 //! display in mdBook.
 
+use crate::language::LanguageRegistry;
+use crate::protocol::ExecutionBackend;
 use crate::types::*;
 use pulldown_cmark::{html, Parser};
+use std::path::PathBuf;
 
 /// Errors that can occur during rendering.
 #[derive(Debug, thiserror::Error)]
@@ -31,6 +34,9 @@ pub struct RenderConfig {
     /// Custom playground URL
     pub playground_url: String,
 
+    /// Where the browser sends test runs (the playground or a protocol service)
+    pub execution_backend: ExecutionBackend,
+
     /// Enable progress tracking via localStorage
     pub enable_progress: bool,
 
@@ -39,6 +45,15 @@ pub struct RenderConfig {
 
     /// If true, copy CSS/JS assets into the book's theme directory
     pub manage_assets: bool,
+
+    /// Language settings (highlight classes, playground support, combine strategy)
+    pub languages: LanguageRegistry,
+
+    /// Book-level toolchain defaults; exercise and tests settings take precedence
+    pub toolchain: ToolchainSettings,
+
+    /// Directory of private solutions and tests merged into exercises, if present
+    pub private_dir: Option<PathBuf>,
 }
 
 impl Default for RenderConfig {
@@ -48,9 +63,13 @@ impl Default for RenderConfig {
             reveal_solution: false,
             enable_playground: true,
             playground_url: "https://play.rust-lang.org".to_string(),
+            execution_backend: ExecutionBackend::default(),
             enable_progress: true,
             enabled: true,
             manage_assets: false,
+            languages: LanguageRegistry::default(),
+            toolchain: ToolchainSettings::default(),
+            private_dir: None,
         }
     }
 }
@@ -68,6 +87,7 @@ pub fn render_exercise_with_config(
     match parsed {
         ParsedExercise::Code(exercise) => render_code_exercise(exercise, config),
         ParsedExercise::UseCase(exercise) => render_usecase_exercise(exercise, config),
+        ParsedExercise::Quiz(exercise) => render_quiz_exercise(exercise, config),
     }
 }
 
@@ -102,7 +122,17 @@ fn render_code_exercise(
     }
 
     if let Some(starter) = &exercise.starter {
-        html.push_str(&render_starter(starter, &exercise.metadata.id));
+        html.push_str(&render_starter(starter, &exercise.starter_files, &exercise.metadata.id, config));
+    }
+
+    if let Some(parsons) = &exercise.parsons {
+        html.push_str(&render_parsons(parsons, &exercise.metadata.id, config));
+    }
+
+    if let Some(requirements) = &exercise.requirements {
+        let language = exercise.starter.as_ref().map(|s| s.language.as_str()).unwrap_or_default();
+        let comment = config.languages.get(language).map_or("//", |s| s.comment.as_str());
+        html.push_str(&render_requirements(requirements, &exercise.metadata.id, comment));
     }
 
     if !exercise.hints.is_empty() {
@@ -110,11 +140,44 @@ fn render_code_exercise(
     }
 
     if let Some(solution) = &exercise.solution {
-        html.push_str(&render_solution(solution, config.reveal_solution, &exercise.metadata.id));
+        let starter_file = exercise.starter.as_ref().and_then(|s| s.filename.as_deref());
+        let main_name = main_file_name(starter_file, &solution.language, config);
+        html.push_str(&render_solution(solution, &exercise.alternative_solutions, &exercise.metadata.id, &main_name, config));
     }
 
-    if let Some(tests) = &exercise.tests {
-        html.push_str(&render_tests(tests, &exercise.metadata.id, config));
+    // A compile-fail exercise can be run even without tests, to check it builds
+    let compile_fail = exercise
+        .starter
+        .as_ref()
+        .filter(|s| s.expect == StarterExpectation::CompileError)
+        .map(|s| TestBlock {
+            language: s.language.clone(),
+            toolchain: exercise.metadata.toolchain,
+            ..Default::default()
+        });
+    // Likewise an exercise with only input/output cases
+    let io_tests = exercise.io_tests.as_ref().map(|io| TestBlock {
+        language: io.language.clone(),
+        mode: io.mode,
+        toolchain: io.toolchain,
+        ..Default::default()
+    });
+    let visible_tests = exercise
+        .tests
+        .as_ref()
+        .or(io_tests.as_ref())
+        .or(compile_fail.as_ref().filter(|_| exercise.hidden_tests.is_none()));
+    if visible_tests.is_some() || exercise.hidden_tests.is_some() {
+        let io_cases = exercise.io_tests.as_ref().map(|io| io.cases.as_slice()).unwrap_or_default();
+        html.push_str(&render_tests(
+            visible_tests,
+            exercise.hidden_tests.as_ref(),
+            io_cases,
+            &exercise.metadata.id,
+            exercise.starter.as_ref(),
+            &exercise.metadata.dependencies,
+            config,
+        ));
     }
 
     if let Some(reflection) = &exercise.reflection {
@@ -186,6 +249,63 @@ fn render_usecase_exercise(
     Ok(html)
 }
 
+// --- Quiz Exercise Renderer ---
+
+fn render_quiz_exercise(
+    exercise: &QuizExercise,
+    config: &RenderConfig,
+) -> Result<String, RenderError> {
+    let mut html = String::new();
+    let id = &exercise.metadata.id;
+
+    html.push_str(&format!(
+        r#"<article class="quiz-exercise" data-exercise-id="{}" data-difficulty="{}" data-pass-mark="{}" data-shuffle="{}">"#,
+        escape_html(id),
+        exercise.metadata.difficulty,
+        exercise.pass_mark(),
+        exercise.metadata.shuffle
+    ));
+    html.push('\n');
+
+    html.push_str(&render_quiz_header(exercise));
+
+    if !exercise.description.is_empty() {
+        html.push_str(&render_description(&exercise.description, id));
+    }
+
+    if let Some(objectives) = &exercise.objectives {
+        html.push_str(&render_objectives(objectives, id));
+    }
+
+    html.push_str(&format!(r#"<section class="quiz-questions" id="{}-questions">"#, id));
+    html.push('\n');
+    for (i, question) in exercise.questions.iter().enumerate() {
+        html.push_str(&render_question(question, i + 1, id, exercise.metadata.shuffle));
+    }
+    html.push_str("</section>\n");
+
+    if !exercise.hints.is_empty() {
+        html.push_str(&render_hints(&exercise.hints, config.reveal_hints, id));
+    }
+
+    html.push_str(r#"<div class="quiz-controls">"#);
+    html.push('\n');
+    html.push_str(&format!(
+        r#"  <button class="btn btn-check-quiz" data-exercise-id="{}">✓ Check Answers</button>"#,
+        escape_html(id)
+    ));
+    html.push('\n');
+    html.push_str(r#"  <button class="btn btn-retry-quiz" hidden>↺ Try Again</button>"#);
+    html.push('\n');
+    html.push_str("</div>\n");
+    html.push_str(&format!(r#"<div class="quiz-result" id="{}-result" aria-live="polite" hidden></div>"#, id));
+    html.push('\n');
+
+    // No "Mark Complete" button: a quiz is complete once it's passed
+    html.push_str("</article>\n");
+    Ok(html)
+}
+
 // --- Shared Components ---
 
 fn render_description(description: &str, exercise_id: &str) -> String {
@@ -352,6 +472,17 @@ fn render_code_header(exercise: &Exercise) -> String {
         html.push_str(&format!(r#"    <span class="badge prerequisites">📚 Requires: {}</span>"#, prereqs.join(", ")));
         html.push('\n');
     }
+
+    if !exercise.metadata.dependencies.is_empty() {
+        let names: Vec<&str> = exercise.metadata.dependencies.iter().map(|d| d.name.as_str()).collect();
+        let details: Vec<String> = exercise.metadata.dependencies.iter().map(|d| d.to_string()).collect();
+        html.push_str(&format!(
+            r#"    <span class="badge dependencies" title="Crates: {}">📦 {}</span>"#,
+            escape_html_attr(&details.join(", ")),
+            escape_html(&names.join(", "))
+        ));
+        html.push('\n');
+    }
     html.push_str("  </div>\n");
     html.push_str("</header>\n");
     html
@@ -400,15 +531,63 @@ fn render_discussion(discussion: &[String]) -> String {
     html
 }
 
-fn render_starter(starter: &StarterCode, exercise_id: &str) -> String {
+/// The rules the student's code must follow. `exercises.js` checks them
+/// (from `data-requirements`) before running the tests.
+fn render_requirements(requirements: &Requirements, exercise_id: &str, comment: &str) -> String {
+    let mut html = String::new();
+    html.push_str(&format!(
+        r#"<section class="exercise-requirements" id="{}-requirements" data-requirements="{}" data-comment="{}">"#,
+        exercise_id,
+        escape_html_attr(&serde_json::to_string(requirements).unwrap_or_else(|_| "{}".to_string())),
+        escape_html_attr(comment)
+    ));
+    html.push('\n');
+    html.push_str("  <h3>📏 Requirements</h3>\n");
+    html.push_str("  <ul>\n");
+    for construct in &requirements.must_use {
+        html.push_str(&format!("    <li class=\"requirement must-use\">Use <code>{}</code></li>\n", escape_html(construct)));
+    }
+    for construct in &requirements.must_not_use {
+        html.push_str(&format!("    <li class=\"requirement must-not-use\">Don't use <code>{}</code></li>\n", escape_html(construct)));
+    }
+    if let Some(max) = requirements.max_lines {
+        html.push_str(&format!("    <li class=\"requirement max-lines\">At most {} lines of code</li>\n", max));
+    }
+    html.push_str("  </ul>\n");
+    html.push_str("</section>\n");
+    html
+}
+
+fn render_starter(starter: &StarterCode, files: &[StarterCode], exercise_id: &str, config: &RenderConfig) -> String {
     let mut html = String::new();
     html.push_str(&format!(r#"<section class="exercise-starter" id="{}-starter">"#, exercise_id));
     html.push('\n');
     html.push_str(r#"  <div class="code-header">"#);
     html.push('\n');
-    if let Some(filename) = &starter.filename {
-        html.push_str(&format!(r#"    <span class="filename">{}</span>"#, escape_html(filename)));
+    if files.is_empty() {
+        if let Some(filename) = &starter.filename {
+            html.push_str(&format!(r#"    <span class="filename">{}</span>"#, escape_html(filename)));
+            html.push('\n');
+        }
+    } else {
+        // One tab per file; the first is the file combined with the tests
+        let main_name = main_file_name(starter.filename.as_deref(), &starter.language, config);
+        html.push_str(r#"    <div class="starter-tabs" role="tablist">"#);
         html.push('\n');
+        let names = std::iter::once(main_name.as_str()).chain(files.iter().filter_map(|f| f.filename.as_deref()));
+        for (i, name) in names.enumerate() {
+            let id = starter_editor_id(exercise_id, i);
+            html.push_str(&format!(
+                r#"      <button class="starter-tab{}" role="tab" aria-selected="{}" aria-controls="{}-panel" data-target="{}">{}</button>"#,
+                if i == 0 { " active" } else { "" },
+                i == 0,
+                id,
+                id,
+                escape_html(name)
+            ));
+            html.push('\n');
+        }
+        html.push_str("    </div>\n");
     }
     html.push_str(r#"    <div class="code-actions">"#);
     html.push('\n');
@@ -418,25 +597,225 @@ fn render_starter(starter: &StarterCode, exercise_id: &str) -> String {
     html.push('\n');
     html.push_str("    </div>\n");
     html.push_str("  </div>\n");
+    if starter.expect == StarterExpectation::CompileError {
+        html.push_str(&render_expected_outcome(&starter.error_codes, starter.diagnostic.as_deref()));
+    }
+    if files.is_empty() {
+        html.push_str(&render_starter_editor(starter, &starter_editor_id(exercise_id, 0), None, config));
+    } else {
+        for (i, file) in std::iter::once(starter).chain(files).enumerate() {
+            let id = starter_editor_id(exercise_id, i);
+            html.push_str(&format!(
+                r#"  <div class="starter-file" id="{}-panel" role="tabpanel"{}>"#,
+                id,
+                if i == 0 { "" } else { " hidden" }
+            ));
+            html.push('\n');
+            let path = if i == 0 { None } else { file.filename.as_deref() };
+            html.push_str(&render_starter_editor(file, &id, path, config));
+            html.push_str("  </div>\n");
+        }
+    }
+    html.push_str("</section>\n");
+    html
+}
+
+/// A Parsons problem: the shuffled lines and an area to put them in order.
+/// The correct lines are in `data-solution` for `exercises.js` to check against.
+fn render_parsons(parsons: &ParsonsProblem, exercise_id: &str, config: &RenderConfig) -> String {
+    let mut html = String::new();
     html.push_str(&format!(
-        r#"  <textarea class="code-editor" id="code-{}" data-language="{}" data-original="{}" spellcheck="false"></textarea>"#,
+        r#"<section class="exercise-parsons" id="{}-parsons" data-language="{}" data-indent="{}" data-indent-unit="{}" data-solution="{}">"#,
         exercise_id,
-        escape_html(&starter.language),
-        escape_html_attr(&starter.code)
+        escape_html_attr(&parsons.language),
+        parsons.indent,
+        escape_html_attr(&parsons.indent_unit),
+        escape_html_attr(&serde_json::to_string(&parsons.lines).unwrap_or_else(|_| "[]".to_string()))
+    ));
+    html.push('\n');
+    html.push_str("  <h3>🧩 Put the Lines in Order</h3>\n");
+    html.push_str(&format!(
+        r#"  <p class="parsons-instructions">Drag the lines into your solution in the right order{}. With the keyboard, Enter moves a line across, Alt+↑/↓ moves it up or down{}.</p>"#,
+        if parsons.indent { " and indent them" } else { "" },
+        if parsons.indent { " and ←/→ change its indentation" } else { "" }
     ));
     html.push('\n');
+    if !parsons.distractors.is_empty() {
+        html.push_str(r#"  <p class="parsons-distractors-note">Some lines don't belong in the solution.</p>"#);
+        html.push('\n');
+    }
+    html.push_str(r#"  <div class="parsons-areas">"#);
+    html.push('\n');
+    html.push_str(r#"    <div class="parsons-area"><h4>Lines</h4><ul class="parsons-source" aria-label="Available lines">"#);
+    html.push('\n');
+    let language = escape_html(config.languages.highlight(&parsons.language));
+    for line in scrambled_lines(parsons, exercise_id) {
+        // Without indentation to set, lines show their own
+        let indent = if parsons.indent { 0 } else { line.indent };
+        html.push_str(&format!(
+            r#"      <li class="parsons-line" draggable="true" tabindex="0" data-code="{}" data-indent="{}" style="--indent: {}"><code class="language-{}">{}</code></li>"#,
+            escape_html_attr(&line.code),
+            indent,
+            indent,
+            language,
+            escape_html(&line.code)
+        ));
+        html.push('\n');
+    }
+    html.push_str("    </ul></div>\n");
+    html.push_str(r#"    <div class="parsons-area"><h4>Your solution</h4><ul class="parsons-answer" aria-label="Your solution"></ul></div>"#);
+    html.push('\n');
+    html.push_str("  </div>\n");
+    html.push_str(r#"  <div class="parsons-controls">"#);
+    html.push('\n');
+    html.push_str(r#"    <button class="btn btn-check-parsons">✓ Check Order</button>"#);
+    html.push('\n');
+    html.push_str(r#"    <button class="btn btn-reset-parsons">↺ Reset</button>"#);
+    html.push('\n');
+    html.push_str("  </div>\n");
+    html.push_str(r#"  <p class="parsons-feedback" aria-live="polite" hidden></p>"#);
+    html.push('\n');
     html.push_str("</section>\n");
     html
 }
 
-fn render_solution(solution: &Solution, reveal: bool, exercise_id: &str) -> String {
+/// The lines and distractors of a Parsons problem in an order that depends
+/// only on `seed`, so rebuilds don't change it, and is never the correct one
+/// (unless every line is the same).
+fn scrambled_lines<'a>(parsons: &'a ParsonsProblem, seed: &str) -> Vec<&'a ParsonsLine> {
+    // FNV-1a of the seed and the line's position
+    let key = |i: usize| {
+        seed.bytes()
+            .chain(i.to_le_bytes())
+            .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3))
+    };
+    let count = parsons.lines.len();
+    let mut order: Vec<usize> = (0..count + parsons.distractors.len()).collect();
+    order.sort_by_key(|&i| key(i));
+    // Identical lines are interchangeable, so compare the code, and swap the
+    // first line with one that differs from it
+    let in_order = order
+        .iter()
+        .filter(|&&i| i < count)
+        .map(|&i| &parsons.lines[i].code)
+        .eq(parsons.lines.iter().map(|line| &line.code));
+    if in_order {
+        if let Some(other) = (1..count).find(|&i| parsons.lines[i].code != parsons.lines[0].code) {
+            let first = order.iter().position(|&i| i == 0).unwrap_or_default();
+            let second = order.iter().position(|&i| i == other).unwrap_or_default();
+            order.swap(first, second);
+        }
+    }
+    order
+        .into_iter()
+        .map(|i| parsons.lines.get(i).unwrap_or_else(|| &parsons.distractors[i - count]))
+        .collect()
+}
+
+/// Note that the starter is meant to fail to compile, linking each expected
+/// rustc error code to its explanation.
+fn render_expected_outcome(error_codes: &[String], diagnostic: Option<&str>) -> String {
+    let mut html = String::from(r#"  <div class="expected-outcome"><span class="expected-label">Expected:</span> this code does not compile"#);
+    if !error_codes.is_empty() {
+        let codes: Vec<String> = error_codes
+            .iter()
+            .map(|code| {
+                format!(
+                    r#"<a href="https://doc.rust-lang.org/error_codes/{}.html" target="_blank" rel="noopener"><code>error[{}]</code></a>"#,
+                    escape_html_attr(code),
+                    escape_html(code)
+                )
+            })
+            .collect();
+        html.push_str(&format!(" — {}", codes.join(", ")));
+    }
+    html.push_str(". Fix it so it builds.");
+    if let Some(diagnostic) = diagnostic {
+        html.push_str(&format!(r#"<pre class="expected-diagnostic"><code>{}</code></pre>"#, escape_html(diagnostic)));
+    }
+    html.push_str("</div>\n");
+    html
+}
+
+/// ID of the editor for a starter file (`code-<id>` for the main file).
+fn starter_editor_id(exercise_id: &str, index: usize) -> String {
+    if index == 0 {
+        format!("code-{}", exercise_id)
+    } else {
+        format!("code-{}-{}", exercise_id, index)
+    }
+}
+
+/// The editor for one starter file. Further files carry their `data-path`.
+fn render_starter_editor(starter: &StarterCode, id: &str, path: Option<&str>, config: &RenderConfig) -> String {
+    let mut html = String::new();
+    // Leading and trailing hidden lines are added back around the student's
+    // code when it runs (the parser rejects hidden lines in between)
+    let (prefix, visible, suffix) = starter.split_hidden();
+    let mut extra_attrs = String::new();
+    if let Some(path) = path {
+        extra_attrs.push_str(&format!(r#" data-path="{}""#, escape_html_attr(path)));
+    }
+    if !prefix.is_empty() {
+        extra_attrs.push_str(&format!(r#" data-hidden-prefix="{}""#, escape_html_attr(&prefix)));
+    }
+    if !suffix.is_empty() {
+        extra_attrs.push_str(&format!(r#" data-hidden-suffix="{}""#, escape_html_attr(&suffix)));
+    }
+    // Locked lines, numbered within the editor's text
+    let leading = prefix.matches('\n').count();
+    let shown = visible.split('\n').count();
+    let locked: Vec<usize> = starter
+        .locked_lines
+        .iter()
+        .filter(|&&line| line >= leading && line < leading + shown)
+        .map(|line| line - leading)
+        .collect();
+    if !locked.is_empty() {
+        let lines: Vec<String> = locked.iter().map(|line| line.to_string()).collect();
+        extra_attrs.push_str(&format!(r#" data-locked-lines="{}""#, lines.join(",")));
+    }
+    html.push_str(&format!(
+        r#"  <textarea class="code-editor" id="{}" data-language="{}" data-original="{}"{} spellcheck="false"></textarea>"#,
+        id,
+        escape_html(config.languages.highlight(&starter.language)),
+        escape_html_attr(&visible),
+        extra_attrs
+    ));
+    html.push('\n');
+    if !locked.is_empty() {
+        html.push_str(&format!(
+            r#"  <p class="locked-note">🔒 {} locked and can't be edited.</p>"#,
+            describe_lines(&locked)
+        ));
+        html.push('\n');
+    }
+    html
+}
+
+/// Name of the file combined with the tests, in file tabs: its `file=`, else
+/// the language's default file.
+fn main_file_name(filename: Option<&str>, language: &str, config: &RenderConfig) -> String {
+    filename
+        .map(String::from)
+        .or_else(|| config.languages.get(language).and_then(|s| s.default_filename.clone()))
+        .unwrap_or_else(|| "main".to_string())
+}
+
+fn render_solution(
+    solution: &Solution,
+    alternatives: &[Solution],
+    exercise_id: &str,
+    main_name: &str,
+    config: &RenderConfig,
+) -> String {
     let mut html = String::new();
     html.push_str(&format!(r#"<section class="exercise-solution" id="{}-solution">"#, exercise_id));
     html.push('\n');
     let should_open = match solution.reveal {
         SolutionReveal::Always => true,
         SolutionReveal::Never => false,
-        SolutionReveal::OnDemand => reveal,
+        SolutionReveal::OnDemand => config.reveal_solution,
     };
     let open_attr = if should_open { " open" } else { "" };
     html.push_str(&format!(r#"  <details class="solution"{}>"#, open_attr));
@@ -445,8 +824,91 @@ fn render_solution(solution: &Solution, reveal: bool, exercise_id: &str) -> Stri
     html.push('\n');
     html.push_str(r#"    <div class="solution-content">"#);
     html.push('\n');
-    html.push_str(&format!(r#"      <pre><code class="language-{}">{}</code></pre>"#, escape_html(&solution.language), escape_html(&solution.code)));
-    html.push('\n');
+    if alternatives.is_empty() {
+        html.push_str(&render_solution_body(solution, &format!("{}-solution-1", exercise_id), main_name, config));
+    } else {
+        // One tab per approach
+        let solutions: Vec<&Solution> = std::iter::once(solution).chain(alternatives).collect();
+        html.push_str(r#"      <div class="solution-tabs" role="tablist">"#);
+        html.push('\n');
+        for (i, sol) in solutions.iter().enumerate() {
+            let title = sol.title.clone().unwrap_or_else(|| format!("Solution {}", i + 1));
+            html.push_str(&format!(
+                r#"        <button class="solution-tab{}" role="tab" aria-selected="{}" aria-controls="{}-solution-{}-panel" data-target="{}-solution-{}">{}</button>"#,
+                if i == 0 { " active" } else { "" },
+                i == 0,
+                exercise_id,
+                i + 1,
+                exercise_id,
+                i + 1,
+                escape_html(&title)
+            ));
+            html.push('\n');
+        }
+        html.push_str("      </div>\n");
+        for (i, sol) in solutions.iter().enumerate() {
+            html.push_str(&format!(
+                r#"      <div class="solution-panel" id="{}-solution-{}-panel" role="tabpanel"{}>"#,
+                exercise_id,
+                i + 1,
+                if i == 0 { "" } else { " hidden" }
+            ));
+            html.push('\n');
+            html.push_str(&render_solution_body(sol, &format!("{}-solution-{}", exercise_id, i + 1), main_name, config));
+            html.push_str("      </div>\n");
+        }
+    }
+    html.push_str("    </div>\n  </details>\n</section>\n");
+    html
+}
+
+/// A solution's code and explanation. Further files get a tab each, with
+/// element IDs starting with `id`.
+fn render_solution_body(solution: &Solution, id: &str, main_name: &str, config: &RenderConfig) -> String {
+    let mut html = String::new();
+    let code_block = |language: &str, code: &str, hidden_lines: &[usize]| {
+        format!(
+            r#"<pre><code class="language-{}">{}</code></pre>"#,
+            escape_html(config.languages.highlight(language)),
+            code_html(code, hidden_lines)
+        )
+    };
+    if solution.files.is_empty() {
+        html.push_str("      ");
+        html.push_str(&code_block(&solution.language, &solution.code, &solution.hidden_lines));
+        html.push('\n');
+    } else {
+        let main = std::iter::once((main_name, solution.language.as_str(), solution.code.as_str(), solution.hidden_lines.as_slice()));
+        let files: Vec<_> = main
+            .chain(solution.files.iter().map(|f| (f.path.as_str(), f.language.as_str(), f.code.as_str(), f.hidden_lines.as_slice())))
+            .collect();
+        html.push_str(r#"      <div class="solution-file-tabs" role="tablist">"#);
+        html.push('\n');
+        for (i, (name, ..)) in files.iter().enumerate() {
+            html.push_str(&format!(
+                r#"        <button class="solution-file-tab{}" role="tab" aria-selected="{}" aria-controls="{}-file-{}-panel" data-target="{}-file-{}">{}</button>"#,
+                if i == 0 { " active" } else { "" },
+                i == 0,
+                id,
+                i + 1,
+                id,
+                i + 1,
+                escape_html(name)
+            ));
+            html.push('\n');
+        }
+        html.push_str("      </div>\n");
+        for (i, (_, language, code, hidden_lines)) in files.iter().enumerate() {
+            html.push_str(&format!(
+                r#"      <div class="solution-file" id="{}-file-{}-panel" role="tabpanel"{}>{}</div>"#,
+                id,
+                i + 1,
+                if i == 0 { "" } else { " hidden" },
+                code_block(language, code, hidden_lines)
+            ));
+            html.push('\n');
+        }
+    }
     if let Some(explanation) = &solution.explanation {
         html.push_str(r#"      <div class="solution-explanation"><h4>Explanation</h4>"#);
         let parser = Parser::new(explanation);
@@ -455,35 +917,147 @@ fn render_solution(solution: &Solution, reveal: bool, exercise_id: &str) -> Stri
         html.push_str(&explanation_html);
         html.push_str("</div>\n");
     }
-    html.push_str("    </div>\n  </details>\n</section>\n");
     html
 }
 
-fn render_tests(tests: &TestBlock, exercise_id: &str, config: &RenderConfig) -> String {
+/// Render the tests section. Hidden tests are never rendered: only a remote
+/// backend, which has its own copy, can run them.
+fn render_tests(
+    visible: Option<&TestBlock>,
+    hidden: Option<&TestBlock>,
+    io_cases: &[IoTestCase],
+    exercise_id: &str,
+    starter: Option<&StarterCode>,
+    dependencies: &[Dependency],
+    config: &RenderConfig,
+) -> String {
+    let Some(tests) = visible.or(hidden) else {
+        return String::new();
+    };
+    let filename = starter.and_then(|s| s.filename.as_deref());
+    let error_codes = starter.map(|s| s.error_codes.join(",")).unwrap_or_default();
+    let has_test_code = visible.is_some_and(|t| !t.code.trim().is_empty());
     let mut html = String::new();
     html.push_str(&format!(r#"<section class="exercise-tests" id="{}-tests" data-mode="{}">"#, exercise_id, tests.mode));
     html.push('\n');
     html.push_str("  <h3>🧪 Tests</h3>\n");
     html.push_str(r#"  <div class="test-actions">"#);
     html.push('\n');
-    if tests.mode == TestMode::Playground && config.enable_playground {
+    let spec = config.languages.get(&tests.language);
+    // A protocol backend decides for itself which languages it can run
+    let can_run = match &config.execution_backend {
+        ExecutionBackend::Playground => visible.is_some() && spec.map(|s| s.playground).unwrap_or(false),
+        ExecutionBackend::Remote(_) => true,
+    };
+    if tests.mode == TestMode::Playground && config.enable_playground && can_run {
+        let combine = spec.map(|s| s.combine).unwrap_or_default();
+        let backend_url = match &config.execution_backend {
+            ExecutionBackend::Playground => &config.playground_url,
+            ExecutionBackend::Remote(url) => url,
+        };
+        let toolchain = tests.toolchain.or(config.toolchain).resolve();
+        let dependencies = serde_json::to_string(dependencies).unwrap_or_else(|_| "[]".to_string());
         html.push_str(&format!(
-            r#"    <button class="btn btn-run-tests" data-exercise-id="{}" data-playground-url="{}">▶ Run Tests</button>"#,
-            exercise_id, escape_html(&config.playground_url)
+            r#"    <button class="btn btn-run-tests" data-exercise-id="{}" data-backend="{}" data-backend-url="{}" data-language="{}" data-filename="{}" data-combine="{}" data-edition="{}" data-channel="{}" data-build-mode="{}" data-crate-type="{}" data-backtrace="{}" data-lint="{}" data-lint-level="{}" data-format="{}" data-dependencies="{}" data-error-codes="{}" data-io-tests="{}">▶ Run Tests</button>"#,
+            exercise_id,
+            config.execution_backend.adapter(),
+            escape_html_attr(backend_url),
+            escape_html_attr(spec.map(|s| s.name.as_str()).unwrap_or(&tests.language)),
+            escape_html_attr(filename.unwrap_or("")),
+            combine,
+            toolchain.edition,
+            toolchain.channel,
+            toolchain.build_mode,
+            toolchain.crate_type,
+            toolchain.backtrace,
+            toolchain.lint.map(|l| l.to_string()).unwrap_or_default(),
+            toolchain.lint_level,
+            toolchain.format.map(|f| f.to_string()).unwrap_or_default(),
+            escape_html_attr(&dependencies),
+            escape_html_attr(&error_codes),
+            escape_html_attr(&serde_json::to_string(io_cases).unwrap_or_else(|_| "[]".to_string()))
         ));
         html.push('\n');
-    } else {
-        html.push_str(r#"    <div class="local-test-info"><p>Run these tests locally with:</p><pre><code>cargo test</code></pre></div>"#);
+    } else if has_test_code {
+        let command = spec.and_then(|s| s.test_runner).map(|r| r.command()).unwrap_or("your test runner");
+        html.push_str(&format!(
+            r#"    <div class="local-test-info"><p>Run these tests locally with:</p><pre><code>{}</code></pre></div>"#,
+            escape_html(command)
+        ));
         html.push('\n');
     }
     html.push_str("  </div>\n");
-    html.push_str(&format!(r#"  <div class="test-results" id="results-{}" hidden></div>"#, exercise_id));
-    html.push('\n');
-    html.push_str(r#"  <details class="tests-code"><summary>View Test Code</summary>"#);
+    if hidden.is_some() {
+        let note = match &config.execution_backend {
+            ExecutionBackend::Remote(_) => "🔒 Hidden tests also run when you click Run Tests; only their results are shown.",
+            ExecutionBackend::Playground => "🔒 This exercise has hidden tests, which run only on the course's test server.",
+        };
+        html.push_str(&format!(r#"  <p class="hidden-tests-note">{}</p>"#, note));
+        html.push('\n');
+    }
+    let resolved = tests.toolchain.or(config.toolchain).resolve();
+    let checks: Vec<String> = [
+        resolved.lint.map(|l| l.to_string()),
+        resolved.format.map(|f| f.to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|tool| format!("<code>{}</code>", tool))
+    .collect();
+    if !checks.is_empty() {
+        let effect = match resolved.lint_level {
+            CheckLevel::Warn => "its findings are shown with the test results",
+            CheckLevel::Deny => "it has to be clean for the tests to pass",
+        };
+        html.push_str(&format!(
+            r#"  <p class="code-checks-note">🧹 Your code is also checked with {}; {}.</p>"#,
+            checks.join(" and "),
+            effect
+        ));
+        html.push('\n');
+    }
+    if !io_cases.is_empty() {
+        html.push_str(&render_io_cases(io_cases));
+    }
+    html.push_str(&format!(r#"  <div class="test-results" id="results-{}" aria-live="polite" hidden></div>"#, exercise_id));
     html.push('\n');
-    html.push_str(&format!(r#"    <pre><code class="language-{}">{}</code></pre>"#, escape_html(&tests.language), escape_html(&tests.code)));
+    if let Some(tests) = visible.filter(|_| has_test_code) {
+        html.push_str(r#"  <details class="tests-code"><summary>View Test Code</summary>"#);
+        html.push('\n');
+        html.push_str(&format!(r#"    <pre><code class="language-{}">{}</code></pre>"#, escape_html(config.languages.highlight(&tests.language)), code_html(&tests.code, &tests.hidden_lines)));
+        html.push('\n');
+        html.push_str("  </details>\n");
+    }
+    html.push_str("</section>\n");
+    html
+}
+
+/// Table of input/output cases: arguments (when any case has them), input
+/// and expected output.
+fn render_io_cases(cases: &[IoTestCase]) -> String {
+    let with_args = cases.iter().any(|c| !c.args.is_empty());
+    let mut html = String::new();
+    html.push_str(r#"  <table class="io-tests">"#);
     html.push('\n');
-    html.push_str("  </details>\n</section>\n");
+    html.push_str("    <thead><tr><th>Case</th>");
+    if with_args {
+        html.push_str("<th>Arguments</th>");
+    }
+    html.push_str("<th>Input</th><th>Expected output</th></tr></thead>\n");
+    html.push_str("    <tbody>\n");
+    for case in cases {
+        html.push_str(&format!("      <tr><td>{}</td>", escape_html(&case.name)));
+        if with_args {
+            html.push_str(&format!("<td><code>{}</code></td>", escape_html(&case.args.join(" "))));
+        }
+        html.push_str(&format!("<td><pre>{}</pre></td>", escape_html(&case.stdin)));
+        let matching = match case.matching {
+            OutputMatch::Trim => String::new(),
+            other => format!(r#"<span class="io-match">{}</span>"#, other),
+        };
+        html.push_str(&format!("<td><pre>{}</pre>{}</td></tr>\n", escape_html(&case.expected_stdout), matching));
+    }
+    html.push_str("    </tbody>\n  </table>\n");
     html
 }
 
@@ -676,8 +1250,131 @@ fn render_context(context: &str, exercise_id: &str) -> String {
     html
 }
 
+// --- Quiz Exercise Specific Components ---
+
+fn render_quiz_header(exercise: &QuizExercise) -> String {
+    let mut html = String::new();
+    html.push_str(r#"<header class="exercise-header">"#);
+    html.push('\n');
+
+    if let Some(title) = &exercise.title {
+        html.push_str(&format!(r#"  <h2 class="exercise-title">{}</h2>"#, escape_html(title)));
+        html.push('\n');
+    }
+
+    html.push_str(r#"  <div class="exercise-meta">"#);
+    html.push('\n');
+    html.push_str(&format!(
+        r#"    <span class="badge difficulty {}">{}</span>"#,
+        exercise.metadata.difficulty, exercise.metadata.difficulty
+    ));
+    html.push('\n');
+
+    if let Some(minutes) = exercise.metadata.time_minutes {
+        html.push_str(&format!(r#"    <span class="badge time">{} min</span>"#, minutes));
+        html.push('\n');
+    }
+
+    let count = exercise.questions.len();
+    html.push_str(&format!(
+        r#"    <span class="badge questions">❓ {} question{}</span>"#,
+        count,
+        if count == 1 { "" } else { "s" }
+    ));
+    html.push('\n');
+    if exercise.metadata.pass_mark.is_some() {
+        html.push_str(&format!(
+            r#"    <span class="badge pass-mark">Pass: {}%</span>"#,
+            (exercise.pass_mark() * 100.0).round()
+        ));
+        html.push('\n');
+    }
+    html.push_str("  </div>\n</header>\n");
+    html
+}
+
+fn render_question(question: &QuizQuestion, number: usize, exercise_id: &str, shuffle: bool) -> String {
+    let mut html = String::new();
+    let name = format!("{}-q{}", exercise_id, number);
+    html.push_str(&format!(
+        r#"<fieldset class="quiz-question" id="{}" data-type="{}" data-shuffle="{}""#,
+        name,
+        question.kind,
+        question.shuffle.unwrap_or(shuffle)
+    ));
+    if question.kind == QuestionType::Short {
+        html.push_str(&format!(
+            r#" data-answers="{}" data-match="{}" data-case-sensitive="{}""#,
+            escape_html_attr(&serde_json::to_string(&question.answers).unwrap_or_else(|_| "[]".to_string())),
+            question.matching,
+            question.case_sensitive
+        ));
+    }
+    html.push_str(">\n");
+    html.push_str(&format!(r#"  <legend class="question-number">Question {}</legend>"#, number));
+    html.push('\n');
+    html.push_str(&format!(r#"  <div class="question-prompt">{}</div>"#, markdown_html(&question.prompt)));
+    html.push('\n');
+
+    match question.kind {
+        QuestionType::Short => {
+            html.push_str(r#"  <input type="text" class="quiz-answer" aria-label="Your answer" autocomplete="off" spellcheck="false">"#);
+            html.push('\n');
+        }
+        QuestionType::Single | QuestionType::Multiple => {
+            let input = if question.kind == QuestionType::Single { "radio" } else { "checkbox" };
+            if question.kind == QuestionType::Multiple {
+                html.push_str(r#"  <p class="question-instructions">Select all that apply.</p>"#);
+                html.push('\n');
+            }
+            html.push_str(r#"  <ul class="quiz-options">"#);
+            html.push('\n');
+            for (i, option) in question.options.iter().enumerate() {
+                html.push_str(&format!(r#"    <li class="quiz-option" data-correct="{}">"#, option.correct));
+                html.push_str(&format!(
+                    r#"<label><input type="{}" name="{}" value="{}"> <span class="option-text">{}</span></label>"#,
+                    input,
+                    name,
+                    i,
+                    inline_markdown_html(&option.text)
+                ));
+                if let Some(explanation) = &option.explanation {
+                    html.push_str(&format!(r#"<div class="option-explanation" hidden>{}</div>"#, markdown_html(explanation)));
+                }
+                html.push_str("</li>\n");
+            }
+            html.push_str("  </ul>\n");
+        }
+    }
+
+    html.push_str(r#"  <p class="question-feedback" aria-live="polite" hidden></p>"#);
+    html.push('\n');
+    if let Some(explanation) = &question.explanation {
+        html.push_str(&format!(r#"  <div class="question-explanation" hidden>{}</div>"#, markdown_html(explanation)));
+        html.push('\n');
+    }
+    html.push_str("</fieldset>\n");
+    html
+}
+
 // --- Utils ---
 
+fn markdown_html(markdown: &str) -> String {
+    let mut out = String::new();
+    html::push_html(&mut out, Parser::new(markdown));
+    out
+}
+
+/// Markdown rendered without its wrapping paragraph, for use inside a line.
+fn inline_markdown_html(markdown: &str) -> String {
+    let out = markdown_html(markdown);
+    let trimmed = out.trim_end();
+    match trimmed.strip_prefix("<p>").and_then(|s| s.strip_suffix("</p>")) {
+        Some(inner) if !inner.contains("<p>") => inner.to_string(),
+        _ => out,
+    }
+}
+
 fn escape_html(s: &str) -> String {
     s.replace('&', "&amp;")
         .replace('<', "&lt;")
@@ -686,6 +1383,50 @@ fn escape_html(s: &str) -> String {
         .replace('\'', "&#x27;")
 }
 
+/// Escape code for a `<code>` element, wrapping hidden lines in
+/// `<span class="boring">` as mdBook does. The element's text stays the full code.
+fn code_html(code: &str, hidden: &[usize]) -> String {
+    if hidden.is_empty() {
+        return escape_html(code);
+    }
+    let lines: Vec<&str> = code.split('\n').collect();
+    let mut html = String::new();
+    for (i, line) in lines.iter().enumerate() {
+        let newline = if i + 1 < lines.len() { "\n" } else { "" };
+        if hidden.contains(&i) {
+            html.push_str(&format!(r#"<span class="boring">{}{}</span>"#, escape_html(line), newline));
+        } else {
+            html.push_str(&escape_html(line));
+            html.push_str(newline);
+        }
+    }
+    html
+}
+
+/// Describe 0-based line numbers for students, e.g. "Lines 1–3 and 7 are".
+fn describe_lines(lines: &[usize]) -> String {
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for &line in lines {
+        match ranges.last_mut() {
+            Some((_, last)) if *last + 1 == line => *last = line,
+            _ => ranges.push((line, line)),
+        }
+    }
+    let parts: Vec<String> = ranges
+        .iter()
+        .map(|&(first, last)| if first == last { format!("{}", first + 1) } else { format!("{}–{}", first + 1, last + 1) })
+        .collect();
+    let list = match parts.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} and {}", rest.join(", "), last),
+        _ => parts.join(""),
+    };
+    if lines.len() == 1 {
+        format!("Line {} is", list)
+    } else {
+        format!("Lines {} are", list)
+    }
+}
+
 fn escape_html_attr(s: &str) -> String {
     s.replace('&', "&amp;")
         .replace('<', "&lt;")
@@ -708,6 +1449,7 @@ mod tests {
                 difficulty: Difficulty::Beginner,
                 time_minutes: Some(15),
                 prerequisites: vec![],
+                ..Default::default()
             },
             title: Some("Test Exercise".to_string()),
             description: "A simple test exercise.".to_string(),
@@ -752,4 +1494,31 @@ mod tests {
         assert!(html.contains("HIPAA Analysis"));
         assert!(html.contains("Hospital"));
     }
+
+    #[test]
+    fn test_scrambled_lines_never_in_order() {
+        let line = |code: &str| ParsonsLine { code: code.to_string(), indent: 0 };
+        let parsons = ParsonsProblem {
+            lines: vec![line("a"), line("b")],
+            distractors: vec![line("x")],
+            ..Default::default()
+        };
+        for seed in ["one", "two", "three", "four", "five", "six"] {
+            let order: Vec<&str> = scrambled_lines(&parsons, seed).iter().map(|l| l.code.as_str()).collect();
+            assert_eq!(order.len(), 3);
+            let correct: Vec<&str> = order.into_iter().filter(|code| *code != "x").collect();
+            assert_eq!(correct, vec!["b", "a"]);
+        }
+
+        // Swapping two identical lines wouldn't change the code
+        let parsons = ParsonsProblem {
+            lines: vec![line("}"), line("}"), line("a"), line("}")],
+            ..Default::default()
+        };
+        // "seed14" sorts the lines as 0, 3, 2, 1, which is already the correct code
+        for seed in ["seed14", "one", "two", "three"] {
+            let order: Vec<&str> = scrambled_lines(&parsons, seed).iter().map(|l| l.code.as_str()).collect();
+            assert_ne!(order, vec!["}", "}", "a", "}"]);
+        }
+    }
 }
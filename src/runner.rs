@@ -0,0 +1,1804 @@
+//! Local execution of exercise tests.
+//!
+//! This backs `mdbook-exercises test`: for each exercise the solution and the
+//! tests are combined into a throwaway project and run with a timeout by the
+//! [`LanguageRunner`] that the tests' [`LanguageSpec`] names (`cargo test`
+//! for Rust, pytest or unittest for Python, `node --test` for JavaScript). Results are
+//! reported per test in the same shape for every language, so that published
+//! solutions can be checked against the current toolchains in CI. Exercises
+//! with `::: io-tests` are also run as programs, once per input/output case.
+//!
+//! Solutions must also meet the exercise's `::: requirements`.
+//!
+//! The tests are also run against the unmodified starter, which must compile
+//! but fail the tests (or fail to compile, for `expect=compile_error`
+//! fix-the-bug exercises). That catches exercises that are accidentally
+//! pre-solved or broken before students see them.
+
+use crate::collect::ExerciseFile;
+use crate::language::{LanguageRegistry, LanguageSpec, TestRunnerKind};
+use crate::types::*;
+use crate::validate::exercise_id_and_prereqs;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Options for running exercise tests locally.
+#[derive(Debug, Clone)]
+pub struct RunOptions {
+    /// Maximum wall time for a single test run
+    pub timeout: Duration,
+
+    /// Shared cargo target directory (speeds up repeated builds)
+    pub target_dir: Option<PathBuf>,
+
+    /// Pass `--offline` to cargo
+    pub offline: bool,
+
+    /// Also run the tests against the starter code
+    pub check_starter: bool,
+
+    /// Language settings (combine strategy, runner, file names)
+    pub languages: LanguageRegistry,
+
+    /// Per-process CPU time and memory limits (Unix only)
+    pub limits: ResourceLimits,
+
+    /// Default Rust toolchain settings; an exercise's own settings take precedence
+    pub toolchain: ToolchainSettings,
+
+    /// Crates added to the generated `Cargo.toml`
+    pub dependencies: Vec<Dependency>,
+
+    /// Other project files written next to the tested file (e.g., further
+    /// modules, or a `Cargo.toml` replacing the generated one)
+    pub files: Vec<SourceFile>,
+}
+
+/// Resource limits applied to every process a test run starts.
+///
+/// On Unix these are set with `ulimit` before the runner starts, so they
+/// apply to the compiler and test binary as well as the runner itself.
+/// They are ignored on other platforms.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ResourceLimits {
+    /// CPU time per process
+    pub cpu_time: Option<Duration>,
+
+    /// Virtual memory per process, in megabytes
+    pub memory_mb: Option<u64>,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(120),
+            target_dir: Some(std::env::temp_dir().join("mdbook-exercises-target")),
+            offline: true,
+            check_starter: true,
+            languages: LanguageRegistry::default(),
+            limits: ResourceLimits::default(),
+            toolchain: ToolchainSettings::default(),
+            dependencies: Vec::new(),
+            files: Vec::new(),
+        }
+    }
+}
+
+/// Outcome of a single test case.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TestStatus {
+    Passed,
+    Failed,
+    Ignored,
+}
+
+/// Result of a single test case.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestCaseResult {
+    /// Test name (e.g., "tests::test_add")
+    pub name: String,
+
+    /// Outcome
+    pub status: TestStatus,
+
+    /// Failure output (panic message, assertion values)
+    pub message: Option<String>,
+
+    /// `left`/`right` values of a failed `assert_eq!` or `assert_ne!`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assertion: Option<AssertionValues>,
+
+    /// Whether the test comes from the exercise's hidden tests
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub hidden: bool,
+}
+
+/// A lint warning about the code, from `lint: clippy` checks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LintWarning {
+    /// Lint name (e.g., "clippy::needless_return")
+    pub lint: Option<String>,
+
+    /// Short description
+    pub message: String,
+
+    /// Line of the code the warning points at (1-based)
+    pub line: Option<usize>,
+
+    /// The full diagnostic, as the compiler prints it
+    pub rendered: String,
+}
+
+/// The two sides of a failed equality assertion, as printed by libtest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AssertionValues {
+    pub left: String,
+    pub right: String,
+}
+
+/// Overall outcome of a test run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunStatus {
+    /// Code compiled and every test passed
+    Passed,
+    /// Code compiled but at least one test failed
+    Failed,
+    /// Code did not compile
+    CompileError,
+    /// The run exceeded the timeout and was killed
+    TimedOut,
+}
+
+impl std::fmt::Display for RunStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunStatus::Passed => write!(f, "passed"),
+            RunStatus::Failed => write!(f, "failed"),
+            RunStatus::CompileError => write!(f, "compile error"),
+            RunStatus::TimedOut => write!(f, "timed out"),
+        }
+    }
+}
+
+/// Result of running a test suite against some code.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunResult {
+    /// Overall outcome
+    pub status: RunStatus,
+
+    /// Per-test results
+    pub tests: Vec<TestCaseResult>,
+
+    /// Captured standard output
+    pub stdout: String,
+
+    /// Captured standard error (compiler diagnostics)
+    pub stderr: String,
+
+    /// Wall time of the run in milliseconds
+    pub duration_ms: u64,
+
+    /// Lint warnings about the code (`lint: clippy`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub lints: Vec<LintWarning>,
+
+    /// Formatter diff of the code, when it isn't formatted (`format: rustfmt`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format_diff: Option<String>,
+}
+
+/// Outcome of testing one exercise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExerciseTestStatus {
+    Passed,
+    Failed,
+    Skipped,
+}
+
+/// How the starter differs from what the exercise expects of it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StarterProblem {
+    /// The starter already passes the tests
+    AlreadyPasses,
+    /// The starter doesn't compile, but should only fail the tests
+    DoesNotCompile,
+    /// The starter fails to compile, but without these expected error codes
+    MissingErrorCodes(Vec<String>),
+    /// The starter compiles, but was expected not to
+    Compiles,
+}
+
+impl std::fmt::Display for StarterProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StarterProblem::AlreadyPasses => write!(f, "starter already passes the tests"),
+            StarterProblem::DoesNotCompile => write!(f, "starter does not compile"),
+            StarterProblem::MissingErrorCodes(codes) => {
+                write!(f, "starter fails to compile, but not with error {}", codes.join(", "))
+            }
+            StarterProblem::Compiles => write!(f, "starter was expected not to compile"),
+        }
+    }
+}
+
+/// Report for one exercise.
+#[derive(Debug, Clone, Serialize)]
+pub struct ExerciseTestReport {
+    /// Exercise ID
+    pub id: String,
+
+    /// File the exercise came from
+    pub file: PathBuf,
+
+    /// Overall outcome
+    pub status: ExerciseTestStatus,
+
+    /// Why the exercise was skipped or failed
+    pub reason: Option<String>,
+
+    /// Result of running the tests against the solution
+    pub solution: Option<RunResult>,
+
+    /// Results for the alternative solutions, in order
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub alternatives: Vec<RunResult>,
+
+    /// Result of running the tests against the starter
+    pub starter: Option<RunResult>,
+
+    /// How the starter run differs from what the exercise expects
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starter_problem: Option<StarterProblem>,
+}
+
+/// Run the tests of every code exercise against its solution.
+pub fn test_exercises(files: &[ExerciseFile], options: &RunOptions) -> Vec<ExerciseTestReport> {
+    files
+        .iter()
+        .filter(|f| matches!(f.exercise(), Some(ParsedExercise::Code(_))))
+        .map(|f| test_exercise(f, options))
+        .collect()
+}
+
+/// Run the tests of a single code exercise against its solution.
+pub fn test_exercise(file: &ExerciseFile, options: &RunOptions) -> ExerciseTestReport {
+    let mut report = ExerciseTestReport {
+        id: file.exercise().map(|p| exercise_id_and_prereqs(p).0.to_string()).unwrap_or_default(),
+        file: file.path.clone(),
+        status: ExerciseTestStatus::Skipped,
+        reason: None,
+        solution: None,
+        alternatives: Vec::new(),
+        starter: None,
+        starter_problem: None,
+    };
+    let Some(ParsedExercise::Code(exercise)) = file.exercise() else {
+        report.reason = Some("not a code exercise".to_string());
+        return report;
+    };
+    // Exercises may have only hidden tests, and compile-fail exercises none:
+    // their solution just has to build
+    let tests = exercise.tests.as_ref().or(exercise.hidden_tests.as_ref());
+    let io_tests = exercise.io_tests.as_ref();
+    let compile_fail = exercise
+        .starter
+        .as_ref()
+        .filter(|s| s.expect == StarterExpectation::CompileError);
+    let Some(language) = tests
+        .map(|t| &t.language)
+        .or(io_tests.map(|t| &t.language))
+        .or(compile_fail.map(|s| &s.language))
+    else {
+        report.reason = Some("no tests".to_string());
+        return report;
+    };
+    let suite = TestSuite {
+        visible: exercise.tests.as_ref().map(|t| t.code.as_str()).unwrap_or_default(),
+        hidden: exercise.hidden_tests.as_ref().map(|t| t.code.as_str()),
+        io: io_tests.map(|t| t.cases.as_slice()).unwrap_or_default(),
+        redact_hidden: false,
+    };
+    // A Parsons problem's code is its solution
+    let parsons = exercise.parsons.as_ref().map(ParsonsProblem::solution);
+    let Some(solution) = exercise.solution.as_ref().or(parsons.as_ref()) else {
+        report.reason = Some("no solution".to_string());
+        return report;
+    };
+    let Some((spec, kind)) = options
+        .languages
+        .get(language)
+        .and_then(|spec| Some((spec, spec.test_runner?)))
+    else {
+        report.reason = Some(format!("no local runner for language '{}'", language));
+        return report;
+    };
+    let runner = runner_for(kind);
+    let filename = target_filename(spec, runner.as_ref(), exercise.starter.as_ref());
+    let options = &RunOptions {
+        toolchain: tests
+            .map(|t| t.toolchain)
+            .or(io_tests.map(|t| t.toolchain))
+            .unwrap_or(exercise.metadata.toolchain)
+            .or(options.toolchain),
+        dependencies: exercise.metadata.dependencies.clone(),
+        files: exercise.starter_files.iter().map(StarterCode::source_file).collect(),
+        ..options.clone()
+    };
+    let mut problems = Vec::new();
+    let same_language = |s: &&StarterCode| spec.matches(&s.language);
+    // Solutions run with their own versions of the starter's further files
+    let with_files = |solution: &Solution| RunOptions {
+        files: exercise.solution_files(solution),
+        ..options.clone()
+    };
+
+    for (i, solution) in std::iter::once(solution).chain(&exercise.alternative_solutions).enumerate() {
+        let name = solution_name(solution, i);
+        if let Some(starter) = exercise.starter.as_ref().filter(same_language) {
+            if !starter.preserves_locked(&solution.code) {
+                problems.push(format!("{} changes a locked region of the starter", name));
+            }
+        }
+        if let Some(requirements) = &exercise.requirements {
+            let shown = without_lines(&solution.code, &solution.hidden_lines);
+            for violation in requirements.violations(&shown, &spec.comment) {
+                problems.push(format!("{} breaks a requirement: {}", name, violation));
+            }
+        }
+
+        match run_suite(runner.as_ref(), spec, &solution.code, suite, &filename, &with_files(solution)) {
+            Ok(result) => {
+                if result.status != RunStatus::Passed {
+                    problems.push(format!("{} {}", name, result.status));
+                }
+                if !result.lints.is_empty() {
+                    problems.push(format!("{} has {} lint warning(s)", name, result.lints.len()));
+                }
+                if result.format_diff.is_some() {
+                    problems.push(format!("{} isn't formatted", name));
+                }
+                if i == 0 {
+                    report.solution = Some(result);
+                } else {
+                    report.alternatives.push(result);
+                }
+            }
+            Err(e) => problems.push(format!("failed to run {} tests: {}", kind, e)),
+        }
+    }
+
+    if let Some(starter) = exercise.starter.as_ref().filter(|s| options.check_starter && same_language(s)) {
+        match run_suite(runner.as_ref(), spec, &starter.code, suite, &filename, options) {
+            Ok(result) => {
+                report.starter_problem = starter_problem(starter, &result);
+                problems.extend(report.starter_problem.as_ref().map(StarterProblem::to_string));
+                report.starter = Some(result);
+            }
+            Err(e) => problems.push(format!("failed to run {} tests: {}", kind, e)),
+        }
+    }
+
+    if problems.is_empty() {
+        report.status = ExerciseTestStatus::Passed;
+    } else {
+        report.status = ExerciseTestStatus::Failed;
+        report.reason = Some(problems.join("; "));
+    }
+    report
+}
+
+/// The tests to run against some code.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TestSuite<'a> {
+    /// Test code shown to students
+    pub visible: &'a str,
+
+    /// Hidden test code, run separately
+    pub hidden: Option<&'a str>,
+
+    /// Input/output cases, run against the code as a program
+    pub io: &'a [IoTestCase],
+
+    /// Return nothing that would reveal the hidden tests' code or expected
+    /// values: drop their failure messages and the output of their run
+    pub redact_hidden: bool,
+}
+
+/// Run `code` against the visible tests and, in a separate run, the hidden
+/// tests, and merge the results. Results of hidden tests are marked `hidden`.
+///
+/// When hidden tests are redacted, compiler errors only cover the student's code.
+/// Input/output cases run last, with the code as a program. Code that builds
+/// is then linted and format-checked if the toolchain settings ask for it;
+/// with `lint_level: deny`, findings fail the run.
+pub fn run_suite(
+    runner: &dyn LanguageRunner,
+    spec: &LanguageSpec,
+    code: &str,
+    suite: TestSuite,
+    filename: &str,
+    options: &RunOptions,
+) -> io::Result<RunResult> {
+    let mut result = run_tests(runner, spec, code, suite, filename, options)?;
+    let toolchain = options.toolchain.resolve();
+    if !toolchain.has_checks() || matches!(result.status, RunStatus::CompileError | RunStatus::TimedOut) {
+        return Ok(result);
+    }
+    runner.check(code, filename, options, &mut result)?;
+    let has_findings = !result.lints.is_empty() || result.format_diff.is_some();
+    if toolchain.lint_level == CheckLevel::Deny && has_findings && result.status == RunStatus::Passed {
+        result.status = RunStatus::Failed;
+    }
+    Ok(result)
+}
+
+fn run_tests(
+    runner: &dyn LanguageRunner,
+    spec: &LanguageSpec,
+    code: &str,
+    suite: TestSuite,
+    filename: &str,
+    options: &RunOptions,
+) -> io::Result<RunResult> {
+    if !suite.io.is_empty() {
+        let io = runner.run_io(code, filename, suite.io, options)?;
+        if suite.visible.trim().is_empty() && suite.hidden.is_none_or(|t| t.trim().is_empty()) {
+            return Ok(io);
+        }
+        let tests = run_tests(runner, spec, code, TestSuite { io: &[], ..suite }, filename, options)?;
+        return Ok(merge_results(tests, io, "io tests"));
+    }
+    let Some(hidden_tests) = suite.hidden.filter(|t| !t.trim().is_empty()) else {
+        return runner.run(&spec.combine(code, suite.visible), filename, options);
+    };
+    let redact = suite.redact_hidden;
+    let visible = if suite.visible.trim().is_empty() {
+        None
+    } else {
+        let result = runner.run(&spec.combine(code, suite.visible), filename, options)?;
+        // Nothing more to learn if the student's code doesn't build
+        if matches!(result.status, RunStatus::CompileError | RunStatus::TimedOut) {
+            return Ok(result);
+        }
+        Some(result)
+    };
+
+    let mut hidden = runner.run(&spec.combine(code, hidden_tests), filename, options)?;
+    for test in &mut hidden.tests {
+        test.hidden = true;
+        if redact {
+            test.message = None;
+            test.assertion = None;
+        }
+    }
+    if redact {
+        hidden.stdout.clear();
+        hidden.stderr.clear();
+        if hidden.status == RunStatus::CompileError {
+            // Report the student's own errors, or a generic message
+            let alone = runner.run(code, filename, options)?;
+            hidden.stderr = if alone.status == RunStatus::CompileError {
+                alone.stderr
+            } else {
+                "error: the hidden tests don't compile against this code\n".to_string()
+            };
+        }
+    }
+
+    Ok(match visible {
+        Some(visible) => merge_results(visible, hidden, "hidden tests"),
+        None => hidden,
+    })
+}
+
+/// Combine the results of two runs: the worse status, all tests, and the
+/// other run's output after a `--- <label> ---` line.
+fn merge_results(mut result: RunResult, other: RunResult, label: &str) -> RunResult {
+    result.status = [result.status, other.status]
+        .into_iter()
+        .max_by_key(|status| match status {
+            RunStatus::Passed => 0,
+            RunStatus::Failed => 1,
+            RunStatus::TimedOut => 2,
+            RunStatus::CompileError => 3,
+        })
+        .unwrap_or(result.status);
+    result.tests.extend(other.tests);
+    for (output, other_output) in [(&mut result.stdout, other.stdout), (&mut result.stderr, other.stderr)] {
+        if !other_output.is_empty() {
+            output.push_str(&format!("\n--- {} ---\n", label));
+            output.push_str(&other_output);
+        }
+    }
+    result.duration_ms += other.duration_ms;
+    result.lints.extend(other.lints);
+    result.format_diff = result.format_diff.or(other.format_diff);
+    result
+}
+
+/// `code` without the lines at the given (0-based) indices.
+fn without_lines(code: &str, lines: &[usize]) -> String {
+    code.lines()
+        .enumerate()
+        .filter(|(i, _)| !lines.contains(i))
+        .map(|(_, line)| line)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// How reports refer to a solution: by its title, else by position.
+pub fn solution_name(solution: &Solution, index: usize) -> String {
+    match (&solution.title, index) {
+        (Some(title), _) => format!("solution '{}'", title),
+        (None, 0) => "solution".to_string(),
+        (None, _) => format!("solution {}", index + 1),
+    }
+}
+
+/// Check a starter run against what the exercise expects of it.
+fn starter_problem(starter: &StarterCode, result: &RunResult) -> Option<StarterProblem> {
+    match (starter.expect, result.status) {
+        (StarterExpectation::TestsFail, RunStatus::Passed) => Some(StarterProblem::AlreadyPasses),
+        (StarterExpectation::TestsFail, RunStatus::CompileError) => Some(StarterProblem::DoesNotCompile),
+        (StarterExpectation::CompileError, RunStatus::CompileError) => {
+            let missing = missing_error_codes(&starter.error_codes, &result.stderr);
+            (!missing.is_empty()).then(|| StarterProblem::MissingErrorCodes(missing.into_iter().map(String::from).collect()))
+        }
+        (StarterExpectation::CompileError, _) => Some(StarterProblem::Compiles),
+        (StarterExpectation::TestsFail, _) => None,
+    }
+}
+
+/// Expected rustc error codes that don't appear as `error[EXXXX]` in the diagnostics.
+pub fn missing_error_codes<'a>(codes: &'a [String], stderr: &str) -> Vec<&'a str> {
+    codes
+        .iter()
+        .map(String::as_str)
+        .filter(|code| !stderr.contains(&format!("error[{}]", code)))
+        .collect()
+}
+
+// --- Language runners ---
+
+/// Runs an exercise's tests locally for one language.
+///
+/// Every runner reports results as a [`RunResult`], so callers don't need to
+/// know which tool produced them.
+pub trait LanguageRunner {
+    /// File the code is written to when the language spec doesn't name one.
+    fn default_filename(&self) -> &'static str;
+
+    /// Write the combined code to `filename` in a temporary project and run its tests.
+    fn run(&self, code: &str, filename: &str, options: &RunOptions) -> io::Result<RunResult>;
+
+    /// Write the code to `filename` in a temporary project and run it as a
+    /// program once per input/output case, with one test result per case.
+    fn run_io(&self, _code: &str, _filename: &str, _cases: &[IoTestCase], _options: &RunOptions) -> io::Result<RunResult> {
+        Err(io::Error::new(io::ErrorKind::Unsupported, "input/output tests are not supported"))
+    }
+
+    /// Lint and format-check the code (without tests) as `options.toolchain`
+    /// asks, adding the findings to `result`. Runners without such tools
+    /// leave it unchanged.
+    fn check(&self, _code: &str, _filename: &str, _options: &RunOptions, _result: &mut RunResult) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The runner for a kind of test tool.
+pub fn runner_for(kind: TestRunnerKind) -> Box<dyn LanguageRunner> {
+    match kind {
+        TestRunnerKind::Cargo => Box::new(RustRunner),
+        TestRunnerKind::Pytest => Box::new(PythonRunner),
+        TestRunnerKind::Node => Box::new(JavaScriptRunner),
+    }
+}
+
+/// Where an exercise's combined code is written.
+///
+/// Rust starters named `main.rs` are tested as a binary crate; otherwise the
+/// language's default filename is used.
+pub(crate) fn target_filename(spec: &LanguageSpec, runner: &dyn LanguageRunner, starter: Option<&StarterCode>) -> String {
+    let starter_file = starter.and_then(|s| s.filename.as_deref());
+    match starter_file {
+        Some(f) if spec.test_runner == Some(TestRunnerKind::Cargo) && (f == "main.rs" || f.ends_with("/main.rs")) => {
+            "src/main.rs".to_string()
+        }
+        _ => spec
+            .default_filename
+            .clone()
+            .unwrap_or_else(|| runner.default_filename().to_string()),
+    }
+}
+
+/// Runs Rust tests with `cargo test` in a generated cargo project.
+pub struct RustRunner;
+
+impl LanguageRunner for RustRunner {
+    fn default_filename(&self) -> &'static str {
+        "src/lib.rs"
+    }
+
+    fn run(&self, code: &str, filename: &str, options: &RunOptions) -> io::Result<RunResult> {
+        run_rust_tests(code, filename, options)
+    }
+
+    fn run_io(&self, code: &str, _filename: &str, cases: &[IoTestCase], options: &RunOptions) -> io::Result<RunResult> {
+        run_rust_program(code, cases, options)
+    }
+
+    fn check(&self, code: &str, filename: &str, options: &RunOptions, result: &mut RunResult) -> io::Result<()> {
+        check_rust_code(code, filename, options, result)
+    }
+}
+
+/// Runs Python tests with pytest when it is installed, otherwise with unittest.
+///
+/// Test files without test cases (plain `assert` scripts) are reported as a
+/// single test named after the module.
+pub struct PythonRunner;
+
+impl LanguageRunner for PythonRunner {
+    fn default_filename(&self) -> &'static str {
+        "test_exercise.py"
+    }
+
+    fn run(&self, code: &str, filename: &str, options: &RunOptions) -> io::Result<RunResult> {
+        let project = TempProject::new()?;
+        project.write_all(&options.files)?;
+        project.write(filename, code)?;
+        let module = Path::new(filename)
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let mut cmd = limited_command(PYTHON, options.limits);
+        if pytest_available() {
+            cmd.args(["-m", "pytest", "-v", "-rfE", "--tb=short", "-p", "no:cacheprovider", filename]);
+        } else {
+            cmd.args(["-m", "unittest", "-v", &module]);
+        }
+        cmd.current_dir(project.path()).env("PYTHONDONTWRITEBYTECODE", "1");
+
+        let output = run_with_timeout(&mut cmd, options.timeout)?;
+        let (tests, diagnostics) = if pytest_available() {
+            (parse_pytest_output(&output.stdout), output.stdout.clone())
+        } else {
+            (parse_unittest_output(&output.stderr), output.stderr.clone())
+        };
+        // pytest and unittest (3.12+) exit with 5 when no tests were found
+        let no_tests_ran = output.status.and_then(|s| s.code()) == Some(5);
+        Ok(script_result(output, tests, &module, &diagnostics, no_tests_ran))
+    }
+
+    fn run_io(&self, code: &str, filename: &str, cases: &[IoTestCase], options: &RunOptions) -> io::Result<RunResult> {
+        let project = TempProject::new()?;
+        project.write_all(&options.files)?;
+        project.write(filename, code)?;
+        let command = || {
+            let mut cmd = limited_command(PYTHON, options.limits);
+            cmd.arg(filename)
+                .current_dir(project.path())
+                .env("PYTHONDONTWRITEBYTECODE", "1");
+            cmd
+        };
+        run_io_cases(command, cases, options, is_syntax_error)
+    }
+}
+
+#[cfg(windows)]
+const PYTHON: &str = "python";
+#[cfg(not(windows))]
+const PYTHON: &str = "python3";
+
+fn pytest_available() -> bool {
+    static AVAILABLE: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+    *AVAILABLE.get_or_init(|| {
+        Command::new(PYTHON)
+            .args(["-c", "import pytest"])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .map(|s| s.success())
+            .unwrap_or(false)
+    })
+}
+
+/// Runs JavaScript tests with node's built-in test runner (`node --test`).
+///
+/// Files that don't use `node:test` (plain `assert` scripts) are reported as
+/// a single test named after the file.
+pub struct JavaScriptRunner;
+
+impl LanguageRunner for JavaScriptRunner {
+    fn default_filename(&self) -> &'static str {
+        "exercise.test.js"
+    }
+
+    fn run(&self, code: &str, filename: &str, options: &RunOptions) -> io::Result<RunResult> {
+        let project = TempProject::new()?;
+        project.write_all(&options.files)?;
+        project.write(filename, code)?;
+
+        let mut cmd = limited_command("node", options.limits);
+        cmd.args(["--test", "--test-reporter=tap", filename])
+            .current_dir(project.path());
+
+        let output = run_with_timeout(&mut cmd, options.timeout)?;
+        let tests = parse_node_tap_output(&output.stdout);
+        // Uncaught errors are echoed as TAP comments before the file's entry
+        let diagnostics: String = output
+            .stdout
+            .lines()
+            .take_while(|l| !l.starts_with("# Subtest"))
+            .filter_map(|l| l.strip_prefix("# "))
+            .map(|l| format!("{}\n", l))
+            .collect();
+        Ok(script_result(output, tests, filename, &diagnostics, false))
+    }
+
+    fn run_io(&self, code: &str, filename: &str, cases: &[IoTestCase], options: &RunOptions) -> io::Result<RunResult> {
+        let project = TempProject::new()?;
+        project.write_all(&options.files)?;
+        project.write(filename, code)?;
+        let command = || {
+            let mut cmd = limited_command("node", options.limits);
+            cmd.arg(filename).current_dir(project.path());
+            cmd
+        };
+        run_io_cases(command, cases, options, is_syntax_error)
+    }
+}
+
+/// Run a program once per input/output case and check its output.
+///
+/// `command` creates the command that starts the program; each case's
+/// arguments are appended and its `stdin` is fed to it. When the first run
+/// fails with diagnostics `is_build_error` recognizes, the result is a
+/// compile error and no further cases run.
+fn run_io_cases(
+    command: impl Fn() -> Command,
+    cases: &[IoTestCase],
+    options: &RunOptions,
+    is_build_error: impl Fn(&str) -> bool,
+) -> io::Result<RunResult> {
+    let mut result = RunResult {
+        status: RunStatus::Passed,
+        tests: Vec::new(),
+        stdout: String::new(),
+        stderr: String::new(),
+        duration_ms: 0,
+        lints: Vec::new(),
+        format_diff: None,
+    };
+    for (i, case) in cases.iter().enumerate() {
+        let mut cmd = command();
+        cmd.args(&case.args);
+        let output = run_with_input(&mut cmd, Some(&case.stdin), options.timeout)?;
+        result.duration_ms += output.duration.as_millis() as u64;
+        if i == 0 && output.status.is_some_and(|s| !s.success()) && is_build_error(&output.stderr) {
+            result.status = RunStatus::CompileError;
+            result.stderr = output.stderr;
+            return Ok(result);
+        }
+
+        let (status, message, assertion) = match output.status {
+            None => (TestStatus::Failed, Some("timed out".to_string()), None),
+            Some(s) if !s.success() => {
+                let stderr = output.stderr.trim();
+                let message = if stderr.is_empty() { s.to_string() } else { format!("{}\n{}", s, stderr) };
+                (TestStatus::Failed, Some(message), None)
+            }
+            Some(_) if case.matches(&output.stdout) => (TestStatus::Passed, None, None),
+            Some(_) => {
+                let message = match case.matching {
+                    OutputMatch::Regex => format!("output doesn't match /{}/", case.expected_stdout),
+                    _ => "output differs from the expected output".to_string(),
+                };
+                let assertion = AssertionValues {
+                    left: output.stdout.clone(),
+                    right: case.expected_stdout.clone(),
+                };
+                (TestStatus::Failed, Some(message), Some(assertion))
+            }
+        };
+        result.tests.push(TestCaseResult {
+            name: case.name.clone(),
+            status,
+            message,
+            assertion,
+            hidden: false,
+        });
+        for (all, output) in [(&mut result.stdout, &output.stdout), (&mut result.stderr, &output.stderr)] {
+            if !output.is_empty() {
+                all.push_str(&format!("--- {} ---\n{}\n", case.name, output.trim_end()));
+            }
+        }
+        if output.status.is_none() {
+            // Later cases would most likely hang too
+            result.status = RunStatus::TimedOut;
+            return Ok(result);
+        }
+        if status == TestStatus::Failed {
+            result.status = RunStatus::Failed;
+        }
+    }
+    Ok(result)
+}
+
+/// Build a [`RunResult`] for an interpreted language.
+///
+/// A load failure (nothing ran) that mentions a syntax error is a compile
+/// error; any other load failure, or a successful load without test cases,
+/// becomes a single synthetic test named `script`, with the end of the
+/// `diagnostics` traceback as its message.
+fn script_result(
+    output: ProcessOutput,
+    mut tests: Vec<TestCaseResult>,
+    script: &str,
+    diagnostics: &str,
+    no_tests_ran: bool,
+) -> RunResult {
+    let succeeded = output.status.map(|s| s.success()).unwrap_or(false) || no_tests_ran;
+    let is_file_entry = |t: &TestCaseResult| t.name.ends_with(script) || t.name == script;
+    let loaded = !tests.iter().all(is_file_entry);
+
+    let status = if output.status.is_none() {
+        RunStatus::TimedOut
+    } else if !loaded && !succeeded && is_syntax_error(diagnostics) {
+        RunStatus::CompileError
+    } else if succeeded && tests.iter().all(|t| t.status != TestStatus::Failed) {
+        RunStatus::Passed
+    } else {
+        RunStatus::Failed
+    };
+
+    if !loaded && status != RunStatus::CompileError && status != RunStatus::TimedOut {
+        let message = (status == RunStatus::Failed).then(|| traceback_tail(diagnostics));
+        tests = vec![TestCaseResult {
+            name: script.to_string(),
+            status: if status == RunStatus::Passed { TestStatus::Passed } else { TestStatus::Failed },
+            message,
+            assertion: None,
+            hidden: false,
+        }];
+    }
+
+    RunResult {
+        status,
+        tests,
+        stdout: output.stdout,
+        stderr: output.stderr,
+        duration_ms: output.duration.as_millis() as u64,
+        lints: Vec::new(),
+        format_diff: None,
+    }
+}
+
+fn is_syntax_error(diagnostics: &str) -> bool {
+    diagnostics.contains("SyntaxError") || diagnostics.contains("IndentationError")
+}
+
+/// The end of a traceback: the last error line and the couple of lines before it.
+fn traceback_tail(diagnostics: &str) -> String {
+    let lines: Vec<&str> = diagnostics.lines().filter(|l| !l.trim().is_empty()).collect();
+    let start = lines
+        .iter()
+        .rposition(|l| l.contains("Error"))
+        .map(|i| i.saturating_sub(2))
+        .unwrap_or(0);
+    lines[start..].iter().take(5).copied().collect::<Vec<_>>().join("\n")
+}
+
+/// Parse `unittest -v` output (written to stderr) into per-test results.
+///
+/// Recognizes `test_name (module.Class.test_name) ... ok|FAIL|ERROR|skipped`
+/// lines and attaches the tracebacks of the `FAIL:`/`ERROR:` sections.
+pub fn parse_unittest_output(output: &str) -> Vec<TestCaseResult> {
+    let mut results: Vec<TestCaseResult> = Vec::new();
+    for line in output.lines() {
+        let Some((test, outcome)) = line.rsplit_once(" ... ") else { continue };
+        let status = match outcome.trim() {
+            "ok" => TestStatus::Passed,
+            "FAIL" | "ERROR" => TestStatus::Failed,
+            s if s.starts_with("skipped") || s == "expected failure" => TestStatus::Ignored,
+            _ => continue,
+        };
+        results.push(TestCaseResult {
+            name: unittest_name(test),
+            status,
+            message: None,
+            assertion: None,
+            hidden: false,
+        });
+    }
+
+    // Sections: "=====" / "FAIL: test (qualified)" / "-----" / traceback
+    let mut sections: Vec<(String, Vec<&str>)> = Vec::new();
+    let mut lines = output.lines().peekable();
+    while let Some(line) = lines.next() {
+        let header = line.strip_prefix("FAIL: ").or_else(|| line.strip_prefix("ERROR: "));
+        if let Some(test) = header {
+            let mut body = Vec::new();
+            lines.next_if(|l| l.starts_with("----"));
+            while let Some(l) = lines.next_if(|l| !l.starts_with("=====") && !l.starts_with("-----")) {
+                body.push(l);
+            }
+            sections.push((unittest_name(test), body));
+        }
+    }
+    for (name, body) in sections {
+        if let Some(result) = results.iter_mut().find(|r| r.name == name) {
+            result.message = Some(body.join("\n").trim().to_string());
+        }
+    }
+    results
+}
+
+/// `test_x (test_exercise.TestCase.test_x)` -> `TestCase.test_x`
+fn unittest_name(test: &str) -> String {
+    let Some((method, qualified)) = test.trim().split_once(" (") else {
+        return test.trim().to_string();
+    };
+    let qualified = qualified.trim_end_matches(')');
+    // Drop the module name
+    let qualified = qualified.split_once('.').map(|(_, q)| q).unwrap_or(qualified);
+    if qualified.ends_with(method) {
+        qualified.to_string()
+    } else {
+        format!("{}.{}", qualified, method)
+    }
+}
+
+/// Parse `pytest -v -rfE` output into per-test results.
+///
+/// Recognizes `file.py::Class::test PASSED|FAILED|ERROR|SKIPPED` lines and
+/// attaches the messages from the short test summary.
+pub fn parse_pytest_output(stdout: &str) -> Vec<TestCaseResult> {
+    let mut results: Vec<TestCaseResult> = Vec::new();
+    for line in stdout.lines() {
+        let Some((id, rest)) = line.split_once(' ') else { continue };
+        let Some((_, name)) = id.split_once("::") else { continue };
+        let status = match rest.split_whitespace().next() {
+            Some("PASSED") | Some("XFAIL") => TestStatus::Passed,
+            Some("FAILED") | Some("ERROR") | Some("XPASS") => TestStatus::Failed,
+            Some("SKIPPED") => TestStatus::Ignored,
+            _ => continue,
+        };
+        results.push(TestCaseResult {
+            name: name.to_string(),
+            status,
+            message: None,
+            assertion: None,
+            hidden: false,
+        });
+    }
+
+    // Short summary: "FAILED test_exercise.py::test_x - assert 1 == 2"
+    for line in stdout.lines() {
+        let Some(rest) = line.strip_prefix("FAILED ").or_else(|| line.strip_prefix("ERROR ")) else { continue };
+        let Some((id, message)) = rest.split_once(" - ") else { continue };
+        let Some((_, name)) = id.split_once("::") else { continue };
+        if let Some(result) = results.iter_mut().find(|r| r.name == name) {
+            result.message = Some(message.to_string());
+        }
+    }
+    results
+}
+
+/// Parse node's TAP reporter output into per-test results.
+///
+/// Only leaf tests are reported; `describe` suites, which TAP lists after
+/// their (more indented) subtests, are skipped. The `error:` field of a
+/// failing test's YAML block becomes its message.
+pub fn parse_node_tap_output(stdout: &str) -> Vec<TestCaseResult> {
+    let mut results: Vec<TestCaseResult> = Vec::new();
+    let mut last_indent: Option<usize> = None;
+    let mut lines = stdout.lines().peekable();
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+        let (status, rest) = if let Some(rest) = trimmed.strip_prefix("not ok ") {
+            (TestStatus::Failed, rest)
+        } else if let Some(rest) = trimmed.strip_prefix("ok ") {
+            (TestStatus::Passed, rest)
+        } else {
+            continue;
+        };
+        let Some((_, description)) = rest.split_once(" - ") else { continue };
+        let (name, directive) = match description.split_once(" # ") {
+            Some((name, directive)) => (name, Some(directive)),
+            None => (description, None),
+        };
+        let status = match directive {
+            Some(d) if d.starts_with("SKIP") || d.starts_with("TODO") => TestStatus::Ignored,
+            _ => status,
+        };
+
+        // YAML diagnostics block following the test line
+        let mut message = None;
+        if lines.peek().map(|l| l.trim() == "---").unwrap_or(false) {
+            lines.next();
+            let mut block = Vec::new();
+            while let Some(l) = lines.next_if(|l| l.trim() != "...") {
+                block.push(l);
+            }
+            lines.next();
+            message = tap_error(&block);
+        }
+
+        let is_parent = last_indent.map(|prev| prev > indent).unwrap_or(false);
+        last_indent = Some(indent);
+        if is_parent {
+            continue;
+        }
+        // node names a file without subtests after its absolute path
+        let name = Path::new(name)
+            .file_name()
+            .filter(|_| Path::new(name).is_absolute())
+            .map(|f| f.to_string_lossy().into_owned())
+            .unwrap_or_else(|| name.to_string());
+        results.push(TestCaseResult {
+            name,
+            status,
+            message: if status == TestStatus::Failed { message } else { None },
+            assertion: None,
+            hidden: false,
+        });
+    }
+    results
+}
+
+/// Extract the `error:` value from a TAP YAML block (inline or `|-` multi-line).
+fn tap_error(block: &[&str]) -> Option<String> {
+    let pos = block.iter().position(|l| l.trim_start().starts_with("error:"))?;
+    let line = block[pos].trim_start();
+    let indent = block[pos].len() - line.len();
+    let value = line["error:".len()..].trim();
+    let message = if value.starts_with('|') {
+        block[pos + 1..]
+            .iter()
+            .take_while(|l| l.trim().is_empty() || l.len() - l.trim_start().len() > indent)
+            .map(|l| l.trim())
+            .collect::<Vec<_>>()
+            .join("\n")
+    } else {
+        value.trim_matches('\'').to_string()
+    };
+    let message = message.trim().to_string();
+    (!message.is_empty()).then_some(message)
+}
+
+/// Build a throwaway cargo project with `code` at `filename` and run `cargo test` on it.
+///
+/// The project uses `options.toolchain` (its edition and crate type, and a
+/// `+beta`/`+nightly` rustup toolchain for non-stable channels) and lists
+/// `options.dependencies` in its `Cargo.toml`.
+pub fn run_rust_tests(code: &str, filename: &str, options: &RunOptions) -> io::Result<RunResult> {
+    let (project, _) = rust_project(code, filename, options)?;
+    let mut cmd = cargo_command("test", &project, options);
+    cmd.arg("--all-targets");
+    let output = run_with_timeout(&mut cmd, options.timeout)?;
+    let tests = parse_libtest_output(&output.stdout);
+    let status = match output.status {
+        None => RunStatus::TimedOut,
+        Some(s) if s.success() => RunStatus::Passed,
+        Some(_) if output.stderr.contains("could not compile") => RunStatus::CompileError,
+        Some(_) => RunStatus::Failed,
+    };
+
+    Ok(RunResult {
+        status,
+        tests,
+        stdout: output.stdout,
+        stderr: output.stderr,
+        duration_ms: output.duration.as_millis() as u64,
+        lints: Vec::new(),
+        format_diff: None,
+    })
+}
+
+/// Build `code` as a binary crate and run it once per input/output case with
+/// `cargo run`, using the same toolchain settings as [`run_rust_tests`].
+pub fn run_rust_program(code: &str, cases: &[IoTestCase], options: &RunOptions) -> io::Result<RunResult> {
+    let (project, _) = rust_project(code, "src/main.rs", options)?;
+
+    let command = || {
+        let mut cmd = cargo_command("run", &project, options);
+        cmd.args(["--quiet", "--"]);
+        cmd
+    };
+    run_io_cases(command, cases, options, |stderr| stderr.contains("could not compile"))
+}
+
+/// Lint `code` with clippy and check it with `rustfmt --check`, on its own
+/// in a cargo project like [`run_rust_tests`]'s, as `options.toolchain` asks.
+///
+/// Only warnings about the code's own file are kept. A timed-out check
+/// reports nothing.
+pub fn check_rust_code(code: &str, filename: &str, options: &RunOptions, result: &mut RunResult) -> io::Result<()> {
+    let toolchain = options.toolchain.resolve();
+    let (project, filename) = rust_project(code, filename, options)?;
+
+    if toolchain.lint == Some(Linter::Clippy) {
+        let mut cmd = cargo_command("clippy", &project, options);
+        cmd.args(["--quiet", "--message-format=json"]);
+        let output = run_with_timeout(&mut cmd, options.timeout)?;
+        result.duration_ms += output.duration.as_millis() as u64;
+        result.lints.extend(parse_clippy_output(&output.stdout, filename));
+    }
+
+    if toolchain.format == Some(Formatter::Rustfmt) {
+        let mut cmd = limited_command("rustfmt", options.limits);
+        if toolchain.channel != Channel::Stable {
+            cmd.arg(format!("+{}", toolchain.channel));
+        }
+        cmd.args(["--check", "--color", "never", "--edition"])
+            .arg(toolchain.edition.to_string())
+            .arg(filename)
+            .current_dir(project.path());
+        let output = run_with_timeout(&mut cmd, options.timeout)?;
+        result.duration_ms += output.duration.as_millis() as u64;
+        // Exit code 1 with a diff on stdout; errors (such as unparsable code) only on stderr
+        if output.status.is_some_and(|s| !s.success()) && !output.stdout.trim().is_empty() {
+            let prefix = format!("{}{}", project.path().display(), std::path::MAIN_SEPARATOR);
+            result.format_diff = Some(output.stdout.replace(&prefix, ""));
+        }
+    }
+    Ok(())
+}
+
+/// Parse `cargo clippy --message-format=json` output into the clippy lints
+/// about `filename`.
+///
+/// rustc's own warnings are left out: the code is checked without its tests,
+/// so helpers only the tests call would be reported as `dead_code`. Lints
+/// that are deny-by-default (e.g. `clippy::eq_op`) are reported as errors and
+/// kept too.
+pub fn parse_clippy_output(stdout: &str, filename: &str) -> Vec<LintWarning> {
+    stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .filter(|value| value["reason"] == "compiler-message")
+        .map(|value| value["message"].clone())
+        .filter(|message| message["level"] == "warning" || message["level"] == "error")
+        .filter(|message| message["code"]["code"].as_str().is_some_and(|code| code.starts_with("clippy::")))
+        .filter_map(|message| {
+            let span = message["spans"]
+                .as_array()?
+                .iter()
+                .find(|span| span["is_primary"] == true && span["file_name"] == filename)?;
+            Some(LintWarning {
+                lint: message["code"]["code"].as_str().map(String::from),
+                message: message["message"].as_str().unwrap_or_default().to_string(),
+                line: span["line_start"].as_u64().map(|line| line as usize),
+                rendered: message["rendered"].as_str().unwrap_or_default().trim_end().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// A temporary cargo project with `code` at `filename`, the manifest for
+/// `options` and its other files. Returns the project and the path the code
+/// was written to: `src/main.rs` instead of `src/lib.rs` for binary crates.
+fn rust_project<'a>(code: &str, filename: &'a str, options: &RunOptions) -> io::Result<(TempProject, &'a str)> {
+    let toolchain = options.toolchain.resolve();
+    let filename = match (toolchain.crate_type, filename) {
+        (CrateType::Bin, "src/lib.rs") => "src/main.rs",
+        _ => filename,
+    };
+    let project = TempProject::new()?;
+    project.write("Cargo.toml", &cargo_toml(toolchain.edition, &options.dependencies))?;
+    project.write_all(&options.files)?;
+    // Code blocks lose their final newline, which rustfmt would report
+    if code.ends_with('\n') {
+        project.write(filename, code)?;
+    } else {
+        project.write(filename, &format!("{}\n", code))?;
+    }
+    Ok((project, filename))
+}
+
+/// A `cargo <subcommand>` in `project` with the toolchain, build mode,
+/// offline and target directory settings of `options`.
+fn cargo_command(subcommand: &str, project: &TempProject, options: &RunOptions) -> Command {
+    let toolchain = options.toolchain.resolve();
+    let mut cmd = limited_command("cargo", options.limits);
+    if toolchain.channel != Channel::Stable {
+        cmd.arg(format!("+{}", toolchain.channel));
+    }
+    cmd.arg(subcommand)
+        .current_dir(project.path())
+        .env("RUST_BACKTRACE", if toolchain.backtrace { "1" } else { "0" });
+    if toolchain.build_mode == BuildMode::Release {
+        cmd.arg("--release");
+    }
+    if options.offline {
+        cmd.arg("--offline");
+    }
+    if let Some(target_dir) = &options.target_dir {
+        cmd.env("CARGO_TARGET_DIR", target_dir);
+    }
+    cmd
+}
+
+/// Manifest for the generated project.
+fn cargo_toml(edition: Edition, dependencies: &[Dependency]) -> String {
+    let mut manifest = format!(
+        "[package]\nname = \"exercise\"\nversion = \"0.0.0\"\nedition = \"{}\"\n\n[workspace]\n",
+        edition
+    );
+    if !dependencies.is_empty() {
+        manifest.push_str("\n[dependencies]\n");
+        for dependency in dependencies {
+            manifest.push_str(&dependency.cargo_toml_line());
+            manifest.push('\n');
+        }
+    }
+    manifest
+}
+
+/// Parse libtest's human-readable output into per-test results.
+///
+/// Recognizes `test <name> ... ok|FAILED|ignored` lines and attaches the
+/// captured `---- <name> stdout ----` section to failed tests.
+pub fn parse_libtest_output(stdout: &str) -> Vec<TestCaseResult> {
+    let mut results: Vec<TestCaseResult> = Vec::new();
+    for line in stdout.lines() {
+        let Some(rest) = line.strip_prefix("test ") else { continue };
+        let Some((name, outcome)) = rest.rsplit_once(" ... ") else { continue };
+        let status = match outcome.trim() {
+            "ok" => TestStatus::Passed,
+            "FAILED" => TestStatus::Failed,
+            s if s.starts_with("ignored") => TestStatus::Ignored,
+            _ => continue,
+        };
+        results.push(TestCaseResult {
+            name: name.trim().to_string(),
+            status,
+            message: None,
+            assertion: None,
+            hidden: false,
+        });
+    }
+
+    // Failure sections: "---- name stdout ----" followed by captured output
+    let mut current: Option<(String, Vec<&str>)> = None;
+    let mut sections = Vec::new();
+    for line in stdout.lines() {
+        if let Some(name) = line
+            .strip_prefix("---- ")
+            .and_then(|l| l.strip_suffix(" stdout ----"))
+        {
+            sections.extend(current.take());
+            current = Some((name.to_string(), Vec::new()));
+        } else if line == "failures:" || line.starts_with("test result:") {
+            sections.extend(current.take());
+        } else if let Some((_, lines)) = current.as_mut() {
+            lines.push(line);
+        }
+    }
+    sections.extend(current);
+
+    for (name, lines) in sections {
+        if let Some(result) = results.iter_mut().find(|r| r.name == name) {
+            let message = lines.join("\n").trim().to_string();
+            if !message.is_empty() {
+                result.assertion = parse_assertion(&message);
+                result.message = Some(message);
+            }
+        }
+    }
+    results
+}
+
+/// Extract the `left:`/`right:` lines of a failed `assert_eq!`/`assert_ne!`.
+pub fn parse_assertion(message: &str) -> Option<AssertionValues> {
+    let value = |label: &str| {
+        message
+            .lines()
+            .find_map(|l| l.trim_start().strip_prefix(label))
+            .map(|v| v.trim().to_string())
+    };
+    Some(AssertionValues {
+        left: value("left:")?,
+        right: value("right:")?,
+    })
+}
+
+// --- Process and filesystem helpers ---
+
+/// Captured output of a child process.
+pub(crate) struct ProcessOutput {
+    /// Exit status, or `None` if the process was killed after the timeout
+    pub status: Option<ExitStatus>,
+    pub stdout: String,
+    pub stderr: String,
+    pub duration: Duration,
+}
+
+/// Create a command for `program` that runs under the given resource limits.
+pub(crate) fn limited_command(program: &str, limits: ResourceLimits) -> Command {
+    let mut ulimits = Vec::new();
+    if let Some(cpu) = limits.cpu_time {
+        ulimits.push(format!("ulimit -t {}", cpu.as_secs().max(1)));
+    }
+    if let Some(mb) = limits.memory_mb {
+        ulimits.push(format!("ulimit -v {}", mb * 1024));
+    }
+    if !cfg!(unix) || ulimits.is_empty() {
+        return Command::new(program);
+    }
+    // Arguments added later become "$@" of the exec'd program
+    let mut cmd = Command::new("sh");
+    cmd.arg("-c")
+        .arg(format!("{} && exec \"$0\" \"$@\"", ulimits.join(" && ")))
+        .arg(program);
+    cmd
+}
+
+/// Run a command, capturing its output and killing it after `timeout`.
+pub(crate) fn run_with_timeout(cmd: &mut Command, timeout: Duration) -> io::Result<ProcessOutput> {
+    run_with_input(cmd, None, timeout)
+}
+
+/// Like [`run_with_timeout`], feeding `input` to the command's standard input.
+fn run_with_input(cmd: &mut Command, input: Option<&str>, timeout: Duration) -> io::Result<ProcessOutput> {
+    let stdin = if input.is_some() { Stdio::piped() } else { Stdio::null() };
+    cmd.stdin(stdin).stdout(Stdio::piped()).stderr(Stdio::piped());
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        // Own process group, so the whole tree (cargo, rustc, test binary) can be killed
+        cmd.process_group(0);
+    }
+
+    let start = Instant::now();
+    let mut child = cmd.spawn()?;
+    if let (Some(mut pipe), Some(input)) = (child.stdin.take(), input) {
+        // Written in the background so a program that doesn't read it can't block us
+        let input = input.to_string();
+        thread::spawn(move || {
+            use std::io::Write;
+            let _ = pipe.write_all(input.as_bytes());
+        });
+    }
+    let stdout = read_in_background(child.stdout.take());
+    let stderr = read_in_background(child.stderr.take());
+
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break Some(status);
+        }
+        if start.elapsed() >= timeout {
+            kill_tree(&mut child);
+            let _ = child.wait();
+            break None;
+        }
+        thread::sleep(Duration::from_millis(20));
+    };
+
+    Ok(ProcessOutput {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+        duration: start.elapsed(),
+    })
+}
+
+fn read_in_background<R: Read + Send + 'static>(pipe: Option<R>) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buf);
+        }
+        String::from_utf8_lossy(&buf).into_owned()
+    })
+}
+
+fn kill_tree(child: &mut std::process::Child) {
+    #[cfg(unix)]
+    {
+        let _ = Command::new("kill")
+            .arg("-KILL")
+            .arg(format!("-{}", child.id()))
+            .stderr(Stdio::null())
+            .status();
+    }
+    let _ = child.kill();
+}
+
+/// A temporary project directory, removed on drop.
+pub(crate) struct TempProject {
+    dir: PathBuf,
+}
+
+impl TempProject {
+    pub fn new() -> io::Result<Self> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let dir = std::env::temp_dir().join(format!(
+            "mdbook-exercises-{}-{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::SeqCst)
+        ));
+        if dir.exists() {
+            fs::remove_dir_all(&dir)?;
+        }
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Write a file relative to the project root, creating parent directories.
+    ///
+    /// Absolute paths and paths containing `..` are rejected.
+    pub fn write(&self, relative: &str, contents: &str) -> io::Result<()> {
+        if relative.is_empty() || Path::new(relative).is_absolute() || relative.split(['/', '\\']).any(|part| part == "..") {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("invalid file path '{}'", relative)));
+        }
+        let path = self.dir.join(relative);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, contents)
+    }
+
+    /// Write extra project files.
+    pub fn write_all(&self, files: &[SourceFile]) -> io::Result<()> {
+        files.iter().try_for_each(|file| self.write(&file.path, &file.content))
+    }
+}
+
+impl Drop for TempProject {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_libtest_output() {
+        let stdout = r#"
+running 3 tests
+test tests::test_add ... ok
+test tests::test_sub ... FAILED
+test tests::test_slow ... ignored, takes too long
+
+failures:
+
+---- tests::test_sub stdout ----
+thread 'tests::test_sub' panicked at src/lib.rs:10:9:
+assertion `left == right` failed
+  left: 1
+ right: 2
+
+failures:
+    tests::test_sub
+
+test result: FAILED. 1 passed; 1 failed; 1 ignored; 0 measured; 0 filtered out
+"#;
+        let results = parse_libtest_output(stdout);
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].status, TestStatus::Passed);
+        assert_eq!(results[1].status, TestStatus::Failed);
+        assert!(results[1].message.as_deref().unwrap().contains("right: 2"));
+        assert_eq!(
+            results[1].assertion,
+            Some(AssertionValues {
+                left: "1".to_string(),
+                right: "2".to_string()
+            })
+        );
+        assert_eq!(results[0].assertion, None);
+        assert_eq!(results[2].status, TestStatus::Ignored);
+    }
+
+    #[test]
+    fn test_cargo_toml_dependencies() {
+        let deps: Vec<Dependency> = vec!["serde = { version = \"1\", features = [\"derive\"] }".parse().unwrap()];
+        let manifest = cargo_toml(Edition::E2024, &deps);
+        assert!(manifest.contains("edition = \"2024\""));
+        assert!(manifest.ends_with("[dependencies]\nserde = { version = \"1\", features = [\"derive\"] }\n"));
+        assert!(!cargo_toml(Edition::E2021, &[]).contains("[dependencies]"));
+    }
+
+    #[test]
+    fn test_temp_project_files() {
+        let project = TempProject::new().unwrap();
+        let file = |path: &str| SourceFile { path: path.to_string(), content: "x".to_string() };
+        project.write_all(&[file("src/parser.rs"), file("Cargo.toml")]).unwrap();
+        assert!(project.path().join("src/parser.rs").exists());
+        for path in ["../escape.rs", "/etc/passwd", "src/../../x", ""] {
+            assert!(project.write_all(&[file(path)]).is_err(), "{} accepted", path);
+        }
+    }
+
+    #[test]
+    fn test_target_filename() {
+        let languages = LanguageRegistry::default();
+        let rust = languages.get("rust").unwrap();
+        let runner = runner_for(TestRunnerKind::Cargo);
+        let starter = |filename: &str| StarterCode {
+            filename: Some(filename.to_string()),
+            ..Default::default()
+        };
+        assert_eq!(target_filename(rust, runner.as_ref(), None), "src/lib.rs");
+        assert_eq!(target_filename(rust, runner.as_ref(), Some(&starter("src/main.rs"))), "src/main.rs");
+
+        let python = languages.get("py").unwrap();
+        let runner = runner_for(TestRunnerKind::Pytest);
+        assert_eq!(target_filename(python, runner.as_ref(), Some(&starter("main.rs"))), "test_exercise.py");
+    }
+
+    #[test]
+    fn test_starter_problem_error_codes() {
+        let starter = StarterCode {
+            expect: StarterExpectation::CompileError,
+            error_codes: vec!["E0382".to_string(), "E0499".to_string()],
+            ..Default::default()
+        };
+        let result = |status, stderr: &str| RunResult {
+            status,
+            tests: Vec::new(),
+            stdout: String::new(),
+            stderr: stderr.to_string(),
+            duration_ms: 0,
+            lints: Vec::new(),
+            format_diff: None,
+        };
+        let stderr = "error[E0382]: borrow of moved value: `s`\nerror[E0499]: cannot borrow `v` as mutable more than once\n";
+        assert_eq!(starter_problem(&starter, &result(RunStatus::CompileError, stderr)), None);
+        assert_eq!(
+            starter_problem(&starter, &result(RunStatus::CompileError, "error[E0382]: borrow of moved value\n")),
+            Some(StarterProblem::MissingErrorCodes(vec!["E0499".to_string()]))
+        );
+        assert_eq!(
+            starter_problem(&starter, &result(RunStatus::Passed, "")).map(|p| p.to_string()).as_deref(),
+            Some("starter was expected not to compile")
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_run_io_cases() {
+        let case = |name: &str, stdin: &str, args: &[&str], expected: &str, matching| IoTestCase {
+            name: name.to_string(),
+            stdin: stdin.to_string(),
+            args: args.iter().map(|a| a.to_string()).collect(),
+            expected_stdout: expected.to_string(),
+            matching,
+        };
+        let cases = [
+            case("greets", "Ada\n", &["Hello"], "Hello, Ada!", OutputMatch::Trim),
+            case("exact", "Bob\n", &["Hi"], "Hi, Bob!", OutputMatch::Exact),
+            case("pattern", "Cy\n", &["Hey"], r"^Hey, \w+!$", OutputMatch::Regex),
+        ];
+        let command = || {
+            let mut cmd = Command::new("sh");
+            cmd.args(["-c", r#"read name; echo "$1, $name!""#, "sh"]);
+            cmd
+        };
+        let result = run_io_cases(command, &cases, &RunOptions::default(), |_| false).unwrap();
+        assert_eq!(result.status, RunStatus::Failed);
+        let statuses: Vec<TestStatus> = result.tests.iter().map(|t| t.status).collect();
+        assert_eq!(statuses, vec![TestStatus::Passed, TestStatus::Failed, TestStatus::Passed]);
+        let assertion = result.tests[1].assertion.as_ref().unwrap();
+        assert_eq!((assertion.left.as_str(), assertion.right.as_str()), ("Hi, Bob!\n", "Hi, Bob!"));
+        assert!(result.stdout.contains("--- greets ---\nHello, Ada!"));
+
+        // Only a failed first run can be a build error
+        let result = run_io_cases(command, &cases, &RunOptions::default(), |_| true).unwrap();
+        assert_eq!(result.tests.len(), 3);
+    }
+
+    #[test]
+    fn test_parse_unittest_output() {
+        let stderr = r#"test_err (test_exercise.TestX.test_err) ... ERROR
+test_ok (test_exercise.TestX.test_ok) ... ok
+test_skip (test_exercise.TestX) ... skipped 'no'
+
+======================================================================
+ERROR: test_err (test_exercise.TestX.test_err)
+----------------------------------------------------------------------
+Traceback (most recent call last):
+  File "test_exercise.py", line 9, in test_err
+NotImplementedError
+
+----------------------------------------------------------------------
+Ran 3 tests in 0.001s
+"#;
+        let results = parse_unittest_output(stderr);
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].name, "TestX.test_err");
+        assert_eq!(results[0].status, TestStatus::Failed);
+        assert!(results[0].message.as_deref().unwrap().ends_with("NotImplementedError"));
+        assert_eq!(results[1].status, TestStatus::Passed);
+        assert_eq!(results[2].name, "TestX.test_skip");
+        assert_eq!(results[2].status, TestStatus::Ignored);
+    }
+
+    #[test]
+    fn test_parse_pytest_output() {
+        let stdout = r#"test_exercise.py::TestReverse::test_reverse PASSED                  [ 50%]
+test_exercise.py::test_empty FAILED                                  [100%]
+
+=========================== short test summary info ============================
+FAILED test_exercise.py::test_empty - assert 'x' == ''
+"#;
+        let results = parse_pytest_output(stdout);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].name, "TestReverse::test_reverse");
+        assert_eq!(results[1].status, TestStatus::Failed);
+        assert_eq!(results[1].message.as_deref(), Some("assert 'x' == ''"));
+    }
+
+    #[test]
+    fn test_parse_node_tap_output() {
+        let stdout = r#"TAP version 13
+# Subtest: palindrome
+    # Subtest: racecar
+    ok 1 - racecar
+      ---
+      duration_ms: 0.5
+      ...
+    # Subtest: hello
+    not ok 2 - hello
+      ---
+      duration_ms: 0.4
+      failureType: 'testCodeFailure'
+      error: |-
+        Expected values to be strictly equal:
+
+        true !== false
+      code: 'ERR_ASSERTION'
+      ...
+    1..2
+not ok 1 - palindrome
+  ---
+  duration_ms: 2.1
+  ...
+# Subtest: skipped
+ok 2 - skipped # SKIP
+  ---
+  duration_ms: 0.1
+  ...
+1..2
+"#;
+        let results = parse_node_tap_output(stdout);
+        let names: Vec<&str> = results.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["racecar", "hello", "skipped"]);
+        assert_eq!(results[1].status, TestStatus::Failed);
+        assert!(results[1].message.as_deref().unwrap().contains("true !== false"));
+        assert_eq!(results[2].status, TestStatus::Ignored);
+    }
+
+    /// Fails a test whenever the combined code mentions `bug`.
+    struct FakeRunner;
+
+    impl LanguageRunner for FakeRunner {
+        fn default_filename(&self) -> &'static str {
+            "lib.rs"
+        }
+
+        fn run(&self, code: &str, _filename: &str, _options: &RunOptions) -> io::Result<RunResult> {
+            let failed = code.contains("bug");
+            Ok(RunResult {
+                status: if failed { RunStatus::Failed } else { RunStatus::Passed },
+                tests: vec![TestCaseResult {
+                    name: code.lines().last().unwrap_or_default().to_string(),
+                    status: if failed { TestStatus::Failed } else { TestStatus::Passed },
+                    message: failed.then(|| "secret expected value".to_string()),
+                    assertion: None,
+                    hidden: false,
+                }],
+                stdout: "secret output".to_string(),
+                stderr: String::new(),
+                duration_ms: 1,
+                lints: Vec::new(),
+                format_diff: None,
+            })
+        }
+
+        fn check(&self, code: &str, _filename: &str, _options: &RunOptions, result: &mut RunResult) -> io::Result<()> {
+            if code.contains("return") {
+                result.lints.push(LintWarning {
+                    lint: Some("clippy::needless_return".to_string()),
+                    message: "unneeded `return` statement".to_string(),
+                    line: Some(1),
+                    rendered: String::new(),
+                });
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_run_suite_hidden_tests() {
+        let options = RunOptions::default();
+        let spec = options.languages.get("rust").unwrap();
+        let suite = TestSuite { visible: "visible", hidden: Some("hidden bug"), io: &[], redact_hidden: true };
+        let result = run_suite(&FakeRunner, spec, "code", suite, "lib.rs", &options).unwrap();
+
+        assert_eq!(result.status, RunStatus::Failed);
+        assert_eq!(result.tests.len(), 2);
+        assert!(!result.tests[0].hidden);
+        assert!(result.tests[1].hidden);
+        assert_eq!(result.tests[1].status, TestStatus::Failed);
+        assert_eq!(result.tests[1].message, None);
+        assert_eq!(result.stdout.matches("secret output").count(), 1);
+        assert_eq!(result.duration_ms, 2);
+
+        // Authors see everything
+        let suite = TestSuite { redact_hidden: false, ..suite };
+        let result = run_suite(&FakeRunner, spec, "code", suite, "lib.rs", &options).unwrap();
+        assert_eq!(result.tests[1].message.as_deref(), Some("secret expected value"));
+    }
+
+    #[test]
+    fn test_run_suite_code_checks() {
+        let mut options = RunOptions::default();
+        let spec = options.languages.get("rust").unwrap().clone();
+        let suite = TestSuite { visible: "visible", ..Default::default() };
+
+        // No checks unless the toolchain settings ask for them
+        let result = run_suite(&FakeRunner, &spec, "return x", suite, "lib.rs", &options).unwrap();
+        assert!(result.lints.is_empty());
+
+        options.toolchain.lint = Some(Linter::Clippy);
+        let result = run_suite(&FakeRunner, &spec, "return x", suite, "lib.rs", &options).unwrap();
+        assert_eq!(result.status, RunStatus::Passed);
+        assert_eq!(result.lints[0].lint.as_deref(), Some("clippy::needless_return"));
+
+        options.toolchain.lint_level = Some(CheckLevel::Deny);
+        let result = run_suite(&FakeRunner, &spec, "return x", suite, "lib.rs", &options).unwrap();
+        assert_eq!(result.status, RunStatus::Failed);
+        let result = run_suite(&FakeRunner, &spec, "x", suite, "lib.rs", &options).unwrap();
+        assert_eq!(result.status, RunStatus::Passed);
+    }
+
+    #[test]
+    fn test_parse_clippy_output() {
+        let stdout = r#"{"reason":"compiler-artifact","package_id":"exercise"}
+{"reason":"compiler-message","message":{"rendered":"warning: unneeded `return` statement\n --> src/lib.rs:3:5\n\n","code":{"code":"clippy::needless_return"},"level":"warning","message":"unneeded `return` statement","spans":[{"file_name":"src/lib.rs","is_primary":true,"line_start":3}]}}
+{"reason":"compiler-message","message":{"rendered":"warning: unused import\n","code":{"code":"unused_imports"},"level":"warning","message":"unused import","spans":[{"file_name":"src/other.rs","is_primary":true,"line_start":1}]}}
+{"reason":"compiler-message","message":{"rendered":"warning: function `add` is never used\n","code":{"code":"dead_code"},"level":"warning","message":"function `add` is never used","spans":[{"file_name":"src/lib.rs","is_primary":true,"line_start":7}]}}
+{"reason":"compiler-message","message":{"rendered":"error: equal expressions as operands to `==`\n --> src/lib.rs:9:33\n","code":{"code":"clippy::eq_op"},"level":"error","message":"equal expressions as operands to `==`","spans":[{"file_name":"src/lib.rs","is_primary":true,"line_start":9}]}}
+{"reason":"compiler-message","message":{"rendered":"error[E0308]: mismatched types\n","code":{"code":"E0308"},"level":"error","message":"mismatched types","spans":[{"file_name":"src/lib.rs","is_primary":true,"line_start":2}]}}
+{"reason":"compiler-message","message":{"rendered":"warning: 1 warning emitted\n","code":null,"level":"warning","message":"1 warning emitted","spans":[]}}
+{"reason":"build-finished","success":false}"#;
+        let lints = parse_clippy_output(stdout, "src/lib.rs");
+        assert_eq!(
+            lints,
+            vec![
+                LintWarning {
+                    lint: Some("clippy::needless_return".to_string()),
+                    message: "unneeded `return` statement".to_string(),
+                    line: Some(3),
+                    rendered: "warning: unneeded `return` statement\n --> src/lib.rs:3:5".to_string(),
+                },
+                LintWarning {
+                    lint: Some("clippy::eq_op".to_string()),
+                    message: "equal expressions as operands to `==`".to_string(),
+                    line: Some(9),
+                    rendered: "error: equal expressions as operands to `==`\n --> src/lib.rs:9:33".to_string(),
+                },
+            ]
+        );
+    }
+}
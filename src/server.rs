@@ -0,0 +1,449 @@
+//! A small HTTP server implementing the execution protocol.
+//!
+//! This backs `mdbook-exercises runner --listen <addr>`: books configured with
+//! `execution_backend = "http://<addr>/execute"` send their test runs here
+//! instead of to the Rust Playground, which is useful on networks that can't
+//! reach play.rust-lang.org or for exercises that need other crates.
+//!
+//! Each request is served by [`protocol::execute`] in a temporary directory,
+//! under the wall time, CPU time, memory and output limits in
+//! [`ServerOptions`]. At most `max_concurrency` runs execute at once; further
+//! requests wait in a bounded queue and get `503` when it is full.
+//!
+//! When started with a book, the server also runs each exercise's hidden
+//! tests and checks its requirements, looked up by the request's `exercise_id`.
+//!
+//! Endpoints:
+//!
+//! - `POST /execute` (or `POST /`): run an [`ExecutionRequest`]
+//! - `GET /health`: protocol version and the languages this server can run
+//!
+//! The server runs untrusted code with the permissions of the user that
+//! started it. Resource limits protect the machine from runaway exercises,
+//! not from malicious ones; run it in a container or VM when exposed.
+
+use crate::protocol::{self, ExecutionRequest, PROTOCOL_VERSION};
+use crate::runner::RunOptions;
+use crate::types::{Requirements, StarterCode};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Options for the execution server.
+#[derive(Debug, Clone)]
+pub struct ServerOptions {
+    /// Maximum number of test runs executing at once
+    pub max_concurrency: usize,
+
+    /// Maximum number of requests waiting for a free slot
+    pub max_queue: usize,
+
+    /// Maximum request body size in bytes
+    pub max_body_bytes: usize,
+
+    /// Maximum bytes of stdout and of stderr returned per run
+    pub max_output_bytes: usize,
+
+    /// Value of the `Access-Control-Allow-Origin` header
+    pub allow_origin: String,
+
+    /// How code is run: wall time limit, resource limits, languages
+    pub run: RunOptions,
+
+    /// Hidden test code by exercise ID, run alongside a request's own tests
+    pub hidden_tests: HashMap<String, String>,
+
+    /// Requirements by exercise ID; code that breaks them isn't run
+    pub requirements: HashMap<String, Requirements>,
+
+    /// Starters by exercise ID, whose hidden lines requirements don't apply to
+    pub starters: HashMap<String, StarterCode>,
+}
+
+impl Default for ServerOptions {
+    fn default() -> Self {
+        Self {
+            max_concurrency: thread::available_parallelism().map(|n| n.get()).unwrap_or(2),
+            max_queue: 32,
+            max_body_bytes: 1024 * 1024,
+            max_output_bytes: 64 * 1024,
+            allow_origin: "*".to_string(),
+            run: RunOptions::default(),
+            hidden_tests: HashMap::new(),
+            requirements: HashMap::new(),
+            starters: HashMap::new(),
+        }
+    }
+}
+
+/// Accept connections on `listener` and serve them until the process exits.
+pub fn serve(listener: TcpListener, options: ServerOptions) -> io::Result<()> {
+    let server = Arc::new(Server {
+        slots: Slots::new(options.max_concurrency.max(1), options.max_queue),
+        options,
+    });
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                eprintln!("Warning: failed to accept connection: {}", e);
+                continue;
+            }
+        };
+        let server = Arc::clone(&server);
+        thread::spawn(move || {
+            if let Err(e) = server.handle(stream) {
+                eprintln!("Warning: connection error: {}", e);
+            }
+        });
+    }
+    Ok(())
+}
+
+struct Server {
+    options: ServerOptions,
+    slots: Slots,
+}
+
+impl Server {
+    fn handle(&self, stream: TcpStream) -> io::Result<()> {
+        stream.set_read_timeout(Some(Duration::from_secs(10)))?;
+        let peer = stream.peer_addr().map(|a| a.to_string()).unwrap_or_default();
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let response = match read_request(&mut reader, self.options.max_body_bytes) {
+            Ok(request) => self.route(&request, &peer),
+            Err(response) => response,
+        };
+        write_response(stream, &response, &self.options.allow_origin)
+    }
+
+    fn route(&self, request: &HttpRequest, peer: &str) -> HttpResponse {
+        let path = request.path.split('?').next().unwrap_or("");
+        match (request.method.as_str(), path) {
+            ("OPTIONS", _) => HttpResponse::new(204, ""),
+            ("GET", "/health") => HttpResponse::json(200, &self.health()),
+            ("POST", "/" | "/execute") => self.execute(&request.body, peer),
+            (_, "/" | "/execute" | "/health") => HttpResponse::error(405, "method not allowed"),
+            _ => HttpResponse::error(404, "not found"),
+        }
+    }
+
+    fn health(&self) -> Health {
+        let languages = self
+            .options
+            .run
+            .languages
+            .specs()
+            .iter()
+            .filter(|s| s.test_runner.is_some())
+            .map(|s| s.name.clone())
+            .collect();
+        Health {
+            status: "ok",
+            protocol_version: PROTOCOL_VERSION,
+            languages,
+        }
+    }
+
+    fn execute(&self, body: &[u8], peer: &str) -> HttpResponse {
+        let mut request: ExecutionRequest = match serde_json::from_slice(body) {
+            Ok(request) => request,
+            Err(e) => return HttpResponse::error(400, &format!("invalid request: {}", e)),
+        };
+        request.limits.max_output_bytes = request.limits.max_output_bytes.min(self.options.max_output_bytes);
+
+        let exercise_id = request.exercise_id.as_ref();
+        let requirements = exercise_id.and_then(|id| self.options.requirements.get(id));
+        let starter = exercise_id.and_then(|id| self.options.starters.get(id));
+        if let Some(response) =
+            requirements.and_then(|r| protocol::requirements_response(&request, r, starter, &self.options.run))
+        {
+            eprintln!(
+                "{} {} {} (requirements not met)",
+                peer,
+                request.exercise_id.as_deref().unwrap_or("-"),
+                request.language
+            );
+            return HttpResponse::json(200, &response);
+        }
+
+        let Some(slot) = self.slots.acquire() else {
+            return HttpResponse::error(503, "server busy, try again shortly");
+        };
+        let mut run_options = self.options.run.clone();
+        run_options.target_dir = run_options
+            .target_dir
+            .map(|dir| dir.join(format!("slot-{}", slot.index)));
+        let start = Instant::now();
+        let hidden_tests = request
+            .exercise_id
+            .as_ref()
+            .and_then(|id| self.options.hidden_tests.get(id))
+            .map(String::as_str);
+        let result = protocol::execute_with_hidden_tests(&request, hidden_tests, &run_options);
+        let outcome = match &result {
+            Ok(response) => serde_json::to_string(&response.status)
+                .unwrap_or_default()
+                .trim_matches('"')
+                .to_string(),
+            Err(e) => format!("error: {}", e),
+        };
+        eprintln!(
+            "{} {} {} ({}) in {:.1}s",
+            peer,
+            request.exercise_id.as_deref().unwrap_or("-"),
+            request.language,
+            outcome,
+            start.elapsed().as_secs_f64()
+        );
+        match result {
+            Ok(response) => HttpResponse::json(200, &response),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput => HttpResponse::error(400, &e.to_string()),
+            Err(e) => HttpResponse::error(500, &format!("failed to run tests: {}", e)),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Health {
+    status: &'static str,
+    protocol_version: u32,
+    languages: Vec<String>,
+}
+
+/// Numbered execution slots with a bounded wait queue.
+///
+/// Each slot gets its own cargo target directory, so concurrent Rust runs
+/// neither block on cargo's lock nor overwrite each other's test binaries.
+struct Slots {
+    state: Mutex<SlotState>,
+    freed: Condvar,
+    max_waiting: usize,
+}
+
+struct SlotState {
+    free: Vec<usize>,
+    waiting: usize,
+}
+
+struct SlotGuard<'a> {
+    slots: &'a Slots,
+    index: usize,
+}
+
+impl Slots {
+    fn new(count: usize, max_waiting: usize) -> Self {
+        Self {
+            state: Mutex::new(SlotState {
+                free: (0..count).rev().collect(),
+                waiting: 0,
+            }),
+            freed: Condvar::new(),
+            max_waiting,
+        }
+    }
+
+    /// Wait for a free slot; `None` if the queue is full.
+    fn acquire(&self) -> Option<SlotGuard<'_>> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if state.free.is_empty() {
+            if state.waiting >= self.max_waiting {
+                return None;
+            }
+            state.waiting += 1;
+            while state.free.is_empty() {
+                state = self.freed.wait(state).unwrap_or_else(|e| e.into_inner());
+            }
+            state.waiting -= 1;
+        }
+        let index = state.free.pop()?;
+        Some(SlotGuard { slots: self, index })
+    }
+}
+
+impl Drop for SlotGuard<'_> {
+    fn drop(&mut self) {
+        let mut state = self.slots.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.free.push(self.index);
+        self.slots.freed.notify_one();
+    }
+}
+
+// --- Minimal HTTP/1.1 handling ---
+
+/// Maximum size of the request line plus headers.
+const MAX_HEADER_BYTES: usize = 16 * 1024;
+
+#[derive(Debug)]
+struct HttpRequest {
+    method: String,
+    path: String,
+    body: Vec<u8>,
+}
+
+#[derive(Debug)]
+struct HttpResponse {
+    status: u16,
+    body: String,
+}
+
+impl HttpResponse {
+    fn new(status: u16, body: &str) -> Self {
+        Self {
+            status,
+            body: body.to_string(),
+        }
+    }
+
+    fn json<T: Serialize>(status: u16, value: &T) -> Self {
+        match serde_json::to_string(value) {
+            Ok(body) => Self { status, body },
+            Err(e) => Self::error(500, &e.to_string()),
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self::json(status, &serde_json::json!({ "error": message }))
+    }
+}
+
+/// Read one request (request line, headers, `Content-Length` body).
+fn read_request<R: BufRead>(reader: &mut R, max_body_bytes: usize) -> Result<HttpRequest, HttpResponse> {
+    let bad_request = |msg: &str| HttpResponse::error(400, msg);
+    let mut header_bytes = 0;
+    let mut read_line = |reader: &mut R| -> Result<String, HttpResponse> {
+        let mut line = String::new();
+        let n = reader
+            .by_ref()
+            .take((MAX_HEADER_BYTES - header_bytes) as u64)
+            .read_line(&mut line)
+            .map_err(|_| bad_request("malformed request"))?;
+        header_bytes += n;
+        if header_bytes >= MAX_HEADER_BYTES {
+            return Err(HttpResponse::error(431, "request headers too large"));
+        }
+        Ok(line.trim_end_matches(['\r', '\n']).to_string())
+    };
+
+    let request_line = read_line(reader)?;
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Err(bad_request("malformed request line"));
+    };
+
+    let mut content_length = 0;
+    loop {
+        let line = read_line(reader)?;
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().map_err(|_| bad_request("invalid Content-Length"))?;
+            }
+        }
+    }
+    if content_length > max_body_bytes {
+        return Err(HttpResponse::error(413, "request body too large"));
+    }
+
+    let mut body = vec![0; content_length];
+    reader
+        .read_exact(&mut body)
+        .map_err(|_| bad_request("incomplete request body"))?;
+    Ok(HttpRequest {
+        method: method.to_string(),
+        path: path.to_string(),
+        body,
+    })
+}
+
+fn write_response(mut stream: TcpStream, response: &HttpResponse, allow_origin: &str) -> io::Result<()> {
+    let reason = match response.status {
+        200 => "OK",
+        204 => "No Content",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        431 => "Request Header Fields Too Large",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    };
+    let head = format!(
+        "HTTP/1.1 {} {}\r\n\
+         Content-Type: application/json\r\n\
+         Content-Length: {}\r\n\
+         Access-Control-Allow-Origin: {}\r\n\
+         Access-Control-Allow-Methods: GET, POST, OPTIONS\r\n\
+         Access-Control-Allow-Headers: Content-Type\r\n\
+         Connection: close\r\n\r\n",
+        response.status,
+        reason,
+        response.body.len(),
+        allow_origin
+    );
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(response.body.as_bytes())?;
+    stream.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_request() {
+        let raw = "POST /execute HTTP/1.1\r\nHost: x\r\ncontent-length: 7\r\n\r\n{\"a\":1}extra";
+        let request = read_request(&mut raw.as_bytes(), 1024).unwrap();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/execute");
+        assert_eq!(request.body, b"{\"a\":1}");
+
+        let too_big = "POST / HTTP/1.1\r\nContent-Length: 2048\r\n\r\n";
+        assert_eq!(read_request(&mut too_big.as_bytes(), 1024).unwrap_err().status, 413);
+        assert_eq!(read_request(&mut "\r\n".as_bytes(), 1024).unwrap_err().status, 400);
+    }
+
+    #[test]
+    fn test_slots_queue_limit() {
+        let slots = Slots::new(1, 0);
+        let first = slots.acquire();
+        assert_eq!(first.as_ref().map(|s| s.index), Some(0));
+        assert!(slots.acquire().is_none());
+        drop(first);
+        assert!(slots.acquire().is_some());
+    }
+
+    #[test]
+    fn test_serve_health_and_errors() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        thread::spawn(move || serve(listener, ServerOptions::default()));
+
+        let send = |raw: &str| {
+            let mut stream = TcpStream::connect(addr).unwrap();
+            stream.write_all(raw.as_bytes()).unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        };
+
+        let health = send("GET /health HTTP/1.1\r\n\r\n");
+        assert!(health.starts_with("HTTP/1.1 200 OK"));
+        assert!(health.contains("Access-Control-Allow-Origin: *"));
+        assert!(health.contains(r#""languages":["rust","python","javascript"]"#));
+
+        let body = r#"{"language": "cobol", "files": [{"content": ""}]}"#;
+        let unknown = send(&format!("POST /execute HTTP/1.1\r\nContent-Length: {}\r\n\r\n{}", body.len(), body));
+        assert!(unknown.starts_with("HTTP/1.1 400"));
+        assert!(unknown.contains("unknown language"));
+
+        assert!(send("OPTIONS /execute HTTP/1.1\r\n\r\n").starts_with("HTTP/1.1 204"));
+        assert!(send("GET /nope HTTP/1.1\r\n\r\n").starts_with("HTTP/1.1 404"));
+    }
+}
@@ -0,0 +1,141 @@
+//! Aggregate statistics over a set of exercises.
+
+use crate::collect::ExerciseFile;
+use crate::types::ParsedExercise;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Summary counts for a set of exercises.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ExerciseStats {
+    /// Total number of parsed exercises
+    pub total: usize,
+
+    /// Number of code exercises
+    pub code_exercises: usize,
+
+    /// Number of UseCase exercises
+    pub usecase_exercises: usize,
+
+    /// Number of quiz exercises
+    pub quiz_exercises: usize,
+
+    /// Exercise count per difficulty level
+    pub by_difficulty: BTreeMap<String, usize>,
+
+    /// UseCase exercise count per domain
+    pub by_domain: BTreeMap<String, usize>,
+
+    /// Sum of all estimated times, in minutes
+    pub total_time_minutes: u32,
+
+    /// IDs of exercises without a time estimate
+    pub missing_time: Vec<String>,
+
+    /// IDs of exercises without any hints
+    pub missing_hints: Vec<String>,
+
+    /// IDs of code exercises without tests
+    pub missing_tests: Vec<String>,
+
+    /// IDs of exercises without a solution (or sample answer)
+    pub missing_solutions: Vec<String>,
+}
+
+/// Compute statistics for the exercises that parsed successfully.
+pub fn compute_stats(files: &[ExerciseFile]) -> ExerciseStats {
+    let mut stats = ExerciseStats::default();
+
+    for parsed in files.iter().filter_map(ExerciseFile::exercise) {
+        stats.total += 1;
+        let (id, difficulty, time, has_hints, has_solution) = match parsed {
+            ParsedExercise::Code(ex) => {
+                stats.code_exercises += 1;
+                if ex.tests.is_none() && ex.io_tests.is_none() {
+                    stats.missing_tests.push(ex.metadata.id.clone());
+                }
+                (
+                    &ex.metadata.id,
+                    ex.metadata.difficulty,
+                    ex.metadata.time_minutes,
+                    !ex.hints.is_empty(),
+                    ex.solution.is_some() || ex.parsons.is_some(),
+                )
+            }
+            ParsedExercise::UseCase(ex) => {
+                stats.usecase_exercises += 1;
+                *stats.by_domain.entry(ex.metadata.domain.to_string()).or_default() += 1;
+                (
+                    &ex.metadata.id,
+                    ex.metadata.difficulty,
+                    ex.metadata.time_minutes,
+                    !ex.hints.is_empty(),
+                    ex.sample_answer.is_some(),
+                )
+            }
+            ParsedExercise::Quiz(ex) => {
+                stats.quiz_exercises += 1;
+                // Every question carries its own answer
+                (
+                    &ex.metadata.id,
+                    ex.metadata.difficulty,
+                    ex.metadata.time_minutes,
+                    !ex.hints.is_empty(),
+                    true,
+                )
+            }
+        };
+
+        *stats.by_difficulty.entry(difficulty.to_string()).or_default() += 1;
+        match time {
+            Some(minutes) => stats.total_time_minutes += minutes,
+            None => stats.missing_time.push(id.clone()),
+        }
+        if !has_hints {
+            stats.missing_hints.push(id.clone());
+        }
+        if !has_solution {
+            stats.missing_solutions.push(id.clone());
+        }
+    }
+
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_exercise;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_compute_stats() {
+        let sources = [
+            "::: exercise\nid: a\ndifficulty: beginner\ntime: 10\n:::\n\n::: hint level=1\nHi\n:::\n",
+            "::: exercise\nid: b\ndifficulty: advanced\ntime: 1 hour\n:::\n",
+            "::: usecase\nid: c\ndomain: healthcare\n:::\n",
+            "::: quiz\nid: d\ntime: 5\n:::\n\n::: question\nprompt: Two?\nanswers: [2]\n:::\n",
+        ];
+        let files: Vec<ExerciseFile> = sources
+            .iter()
+            .enumerate()
+            .map(|(i, s)| ExerciseFile {
+                path: PathBuf::from(format!("{}.md", i)),
+                source: s.to_string(),
+                parsed: parse_exercise(s),
+            })
+            .collect();
+
+        let stats = compute_stats(&files);
+        assert_eq!(stats.total, 4);
+        assert_eq!(stats.code_exercises, 2);
+        assert_eq!(stats.quiz_exercises, 1);
+        assert_eq!(stats.by_difficulty.get("beginner"), Some(&3));
+        assert_eq!(stats.by_domain.get("healthcare"), Some(&1));
+        assert_eq!(stats.total_time_minutes, 75);
+        assert_eq!(stats.missing_time, vec!["c"]);
+        assert_eq!(stats.missing_hints, vec!["b", "c", "d"]);
+        assert_eq!(stats.missing_tests, vec!["a", "b"]);
+        assert_eq!(stats.missing_solutions.len(), 3);
+    }
+}
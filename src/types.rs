@@ -1,13 +1,17 @@
 //! Core types for representing parsed exercises.
 
+use crate::language::DEFAULT_LANGUAGE;
 use serde::{Deserialize, Serialize};
 
 /// A top-level wrapper for any type of parsed exercise.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
+// Parsed once per chapter, so boxing `Code` isn't worth the API churn
+#[allow(clippy::large_enum_variant)]
 pub enum ParsedExercise {
     Code(Exercise),
     UseCase(UseCaseExercise),
+    Quiz(QuizExercise),
 }
 
 /// A parsed exercise with all its components.
@@ -28,22 +32,68 @@ pub struct Exercise {
     /// Discussion prompts before the exercise
     pub discussion: Option<Vec<String>>,
 
-    /// Starter code for the student to complete
+    /// Starter code for the student to complete (the file combined with the tests)
     pub starter: Option<StarterCode>,
 
+    /// Further starter files (e.g., `src/parser.rs` or `Cargo.toml`), each with a filename
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub starter_files: Vec<StarterCode>,
+
     /// Progressive hints (level 1, 2, 3, etc.)
     pub hints: Vec<Hint>,
 
     /// Complete solution
     pub solution: Option<Solution>,
 
+    /// Further solutions taking other approaches (e.g., iterator-based)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alternative_solutions: Vec<Solution>,
+
     /// Test code
     pub tests: Option<TestBlock>,
 
+    /// Tests run for grading whose code is never shown to students
+    /// (`::: hidden-tests` or `::: tests visibility=hidden`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hidden_tests: Option<TestBlock>,
+
+    /// Input/output cases the solution is run against as a program
+    /// (`::: io-tests`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub io_tests: Option<IoTestBlock>,
+
+    /// Rules the student's code must follow (`::: requirements`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requirements: Option<Requirements>,
+
+    /// Code the student assembles from shuffled lines (`::: parsons`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parsons: Option<ParsonsProblem>,
+
     /// Reflection questions after the exercise
     pub reflection: Option<Vec<String>>,
 }
 
+impl Exercise {
+    /// The solution followed by its alternatives.
+    pub fn solutions(&self) -> impl Iterator<Item = &Solution> {
+        self.solution.iter().chain(&self.alternative_solutions)
+    }
+
+    /// The further project files to run `solution` with: the starter's, with
+    /// the solution's own version of each file it has.
+    pub fn solution_files(&self, solution: &Solution) -> Vec<SourceFile> {
+        let mut files: Vec<SourceFile> = self.starter_files.iter().map(StarterCode::source_file).collect();
+        for file in &solution.files {
+            match files.iter_mut().find(|f| f.path == file.path) {
+                Some(starter_file) => *starter_file = file.source_file(),
+                None => files.push(file.source_file()),
+            }
+        }
+        files
+    }
+}
+
 /// Metadata about an exercise.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ExerciseMetadata {
@@ -58,6 +108,77 @@ pub struct ExerciseMetadata {
 
     /// List of prerequisite exercise IDs
     pub prerequisites: Vec<String>,
+
+    /// Toolchain settings for running this exercise's tests
+    #[serde(default)]
+    pub toolchain: ToolchainSettings,
+
+    /// Crates the exercise code depends on
+    #[serde(default)]
+    pub dependencies: Vec<Dependency>,
+
+    /// Expected outcome of the starter, applied to it when it doesn't set one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expect: Option<StarterExpectation>,
+
+    /// rustc error codes (e.g. `E0382`) the starter is expected to fail with
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub error_codes: Vec<String>,
+}
+
+/// A crate dependency declared in exercise metadata.
+///
+/// Written in `Cargo.toml` syntax, e.g. `serde = "1"` or
+/// `serde = { version = "1", features = ["derive"] }`. A bare name means
+/// any version.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Dependency {
+    /// Crate name (e.g., "serde")
+    pub name: String,
+
+    /// Right-hand side of the `Cargo.toml` entry (e.g., `"1"`)
+    pub spec: String,
+}
+
+impl Dependency {
+    /// The `[dependencies]` line for this crate.
+    pub fn cargo_toml_line(&self) -> String {
+        format!("{} = {}", self.name, self.spec)
+    }
+
+    /// The version requirement, when the spec is a plain version string.
+    pub fn version(&self) -> Option<&str> {
+        let version = self.spec.trim().strip_prefix('"')?.strip_suffix('"')?;
+        (version != "*").then_some(version)
+    }
+}
+
+impl std::fmt::Display for Dependency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.version() {
+            Some(version) => write!(f, "{} {}", self.name, version),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+impl std::str::FromStr for Dependency {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, spec) = match s.split_once('=') {
+            Some((name, spec)) => (name.trim(), spec.trim().to_string()),
+            None => (s.trim(), "\"*\"".to_string()),
+        };
+        let valid_name = !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid_name || spec.is_empty() {
+            return Err(format!("Invalid dependency: {}", s));
+        }
+        Ok(Dependency {
+            name: name.to_string(),
+            spec,
+        })
+    }
 }
 
 /// Difficulty level of an exercise.
@@ -114,14 +235,159 @@ pub struct StarterCode {
 
     /// The code content
     pub code: String,
+
+    /// Expected outcome when the tests run against the unmodified starter
+    #[serde(default)]
+    pub expect: StarterExpectation,
+
+    /// rustc error codes the starter must fail with, when `expect` is `CompileError`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub error_codes: Vec<String>,
+
+    /// Lines of `code` (0-based) hidden from the student but still run
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hidden_lines: Vec<usize>,
+
+    /// Lines of `code` (0-based) the student can't edit
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locked_lines: Vec<usize>,
+
+    /// Compiler output the starter is expected to fail with, shown with the
+    /// expected outcome (a fence tagged `diagnostic`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diagnostic: Option<String>,
 }
 
 impl Default for StarterCode {
     fn default() -> Self {
         Self {
             filename: None,
-            language: "rust".to_string(),
+            language: DEFAULT_LANGUAGE.to_string(),
             code: String::new(),
+            expect: StarterExpectation::default(),
+            error_codes: Vec::new(),
+            hidden_lines: Vec::new(),
+            locked_lines: Vec::new(),
+            diagnostic: None,
+        }
+    }
+}
+
+impl StarterCode {
+    /// The text of each run of consecutive locked lines, in order.
+    pub fn locked_regions(&self) -> Vec<String> {
+        let mut regions: Vec<Vec<&str>> = Vec::new();
+        let mut previous = None;
+        for (i, line) in self.code.lines().enumerate() {
+            if !self.locked_lines.contains(&i) {
+                continue;
+            }
+            match regions.last_mut() {
+                Some(region) if previous.map(|p| p + 1) == Some(i) => region.push(line),
+                _ => regions.push(vec![line]),
+            }
+            previous = Some(i);
+        }
+        regions.into_iter().map(|lines| lines.join("\n")).collect()
+    }
+
+    /// Whether `code` keeps every locked region of this starter unchanged.
+    pub fn preserves_locked(&self, code: &str) -> bool {
+        locked_regions_preserved(code, &self.locked_regions())
+    }
+
+    /// Split the code into leading hidden lines, the visible middle and
+    /// trailing hidden lines, such that `prefix + visible + suffix == code`.
+    pub fn split_hidden(&self) -> (String, String, String) {
+        let lines: Vec<&str> = self.code.split('\n').collect();
+        let hidden = &self.hidden_lines;
+        let leading = (0..lines.len()).take_while(|i| hidden.contains(i)).count();
+        let trailing = (leading..lines.len()).rev().take_while(|i| hidden.contains(i)).count();
+        let middle = lines.len() - trailing;
+        let prefix: String = lines[..leading].iter().map(|l| format!("{}\n", l)).collect();
+        let suffix: String = lines[middle..].iter().map(|l| format!("\n{}", l)).collect();
+        (prefix, lines[leading..middle].join("\n"), suffix)
+    }
+
+    /// The part of `code`, a student's version of this starter with its
+    /// hidden lines added back, that the student wrote.
+    pub fn visible_code<'a>(&self, code: &'a str) -> &'a str {
+        let (prefix, _, suffix) = self.split_hidden();
+        let code = code.strip_prefix(prefix.as_str()).unwrap_or(code);
+        code.strip_suffix(suffix.as_str()).unwrap_or(code)
+    }
+
+    /// This starter as a project file.
+    pub fn source_file(&self) -> SourceFile {
+        SourceFile {
+            path: self.filename.clone().unwrap_or_default(),
+            content: self.code.clone(),
+        }
+    }
+}
+
+/// A source file of an exercise project.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceFile {
+    /// Relative path (e.g., "src/lib.rs"); empty for the language default
+    #[serde(default)]
+    pub path: String,
+
+    /// File contents
+    pub content: String,
+}
+
+/// Whether `code` contains each region, as whole lines and in order.
+///
+/// Trailing whitespace is ignored, so editors that trim it don't count as
+/// changing a locked line.
+pub fn locked_regions_preserved(code: &str, regions: &[String]) -> bool {
+    let lines: Vec<&str> = code.lines().map(str::trim_end).collect();
+    let mut start = 0;
+    for region in regions {
+        let wanted: Vec<&str> = region.lines().map(str::trim_end).collect();
+        if wanted.is_empty() {
+            continue;
+        }
+        match (start..lines.len()).find(|&i| lines[i..].starts_with(&wanted)) {
+            Some(i) => start = i + wanted.len(),
+            None => return false,
+        }
+    }
+    true
+}
+
+/// What running the tests against the unmodified starter should produce.
+///
+/// Used by local verification to catch starters that are accidentally
+/// pre-solved or broken before students see them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StarterExpectation {
+    /// Starter compiles, but the tests fail
+    #[default]
+    TestsFail,
+    /// Starter does not compile (fix-the-bug exercises)
+    CompileError,
+}
+
+impl std::fmt::Display for StarterExpectation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StarterExpectation::TestsFail => write!(f, "tests_fail"),
+            StarterExpectation::CompileError => write!(f, "compile_error"),
+        }
+    }
+}
+
+impl std::str::FromStr for StarterExpectation {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "_").as_str() {
+            "tests_fail" | "fail" => Ok(StarterExpectation::TestsFail),
+            "compile_error" => Ok(StarterExpectation::CompileError),
+            _ => Err(format!("Invalid starter expectation: {}", s)),
         }
     }
 }
@@ -142,6 +408,10 @@ pub struct Hint {
 /// The complete solution for an exercise.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Solution {
+    /// Name of the approach (e.g., "Iterator-based"), from `title` or `approach`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
     /// The complete solution code
     pub code: String,
 
@@ -154,24 +424,64 @@ pub struct Solution {
     /// Reveal policy for this solution (on-demand/always/never)
     #[serde(skip)]
     pub reveal: SolutionReveal,
+
+    /// Lines of `code` (0-based) hidden from the student but still run
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hidden_lines: Vec<usize>,
+
+    /// Further files (e.g., `src/parser.rs`), each with a filename, in place
+    /// of the starter files with the same path
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<SolutionFile>,
 }
 
 impl Default for Solution {
     fn default() -> Self {
         Self {
+            title: None,
             code: String::new(),
-            language: "rust".to_string(),
+            language: DEFAULT_LANGUAGE.to_string(),
             explanation: None,
             reveal: SolutionReveal::OnDemand,
+            hidden_lines: Vec::new(),
+            files: Vec::new(),
+        }
+    }
+}
+
+/// A further file of a solution.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SolutionFile {
+    /// Relative path (e.g., "src/parser.rs")
+    pub path: String,
+
+    /// Programming language for syntax highlighting
+    pub language: String,
+
+    /// The code content
+    pub code: String,
+
+    /// Lines of `code` (0-based) hidden from the student but still run
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hidden_lines: Vec<usize>,
+}
+
+impl SolutionFile {
+    /// This file as a project file.
+    pub fn source_file(&self) -> SourceFile {
+        SourceFile {
+            path: self.path.clone(),
+            content: self.code.clone(),
         }
     }
 }
 
 /// When to reveal a solution in the UI.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum SolutionReveal {
     /// Hidden behind a toggle
+    #[default]
     OnDemand,
     /// Shown expanded
     Always,
@@ -179,10 +489,6 @@ pub enum SolutionReveal {
     Never,
 }
 
-impl Default for SolutionReveal {
-    fn default() -> Self { SolutionReveal::OnDemand }
-}
-
 /// Test code for verifying solutions.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TestBlock {
@@ -194,14 +500,350 @@ pub struct TestBlock {
 
     /// Execution mode
     pub mode: TestMode,
+
+    /// Toolchain settings (from the tests attributes, falling back to the
+    /// exercise metadata)
+    #[serde(default)]
+    pub toolchain: ToolchainSettings,
+
+    /// Lines of `code` (0-based) hidden from the student but still run
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hidden_lines: Vec<usize>,
 }
 
 impl Default for TestBlock {
     fn default() -> Self {
         Self {
-            language: "rust".to_string(),
+            language: DEFAULT_LANGUAGE.to_string(),
             code: String::new(),
             mode: TestMode::Playground,
+            toolchain: ToolchainSettings::default(),
+            hidden_lines: Vec::new(),
+        }
+    }
+}
+
+/// Rust toolchain settings for running tests.
+///
+/// Every field is optional so settings can be layered: tests block
+/// attributes over exercise metadata over book defaults. Use
+/// [`ToolchainSettings::or`] to layer and [`ToolchainSettings::resolve`] to
+/// fill in the built-in defaults.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ToolchainSettings {
+    /// Rust edition
+    pub edition: Option<Edition>,
+
+    /// Release channel
+    pub channel: Option<Channel>,
+
+    /// Debug or release build
+    pub build_mode: Option<BuildMode>,
+
+    /// Library or binary crate
+    pub crate_type: Option<CrateType>,
+
+    /// Show backtraces for panicking tests
+    pub backtrace: Option<bool>,
+
+    /// Lint the student's code
+    pub lint: Option<Linter>,
+
+    /// Whether lint warnings and formatting differences fail the run
+    pub lint_level: Option<CheckLevel>,
+
+    /// Check the student's code is formatted
+    pub format: Option<Formatter>,
+}
+
+/// Toolchain settings with every value filled in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResolvedToolchain {
+    pub edition: Edition,
+    pub channel: Channel,
+    pub build_mode: BuildMode,
+    pub crate_type: CrateType,
+    pub backtrace: bool,
+    pub lint: Option<Linter>,
+    pub lint_level: CheckLevel,
+    pub format: Option<Formatter>,
+}
+
+impl ResolvedToolchain {
+    /// Whether the code is linted or format-checked after the tests run.
+    pub fn has_checks(&self) -> bool {
+        self.lint.is_some() || self.format.is_some()
+    }
+}
+
+impl ToolchainSettings {
+    /// Use `self`'s values where set, otherwise `fallback`'s.
+    pub fn or(self, fallback: ToolchainSettings) -> ToolchainSettings {
+        ToolchainSettings {
+            edition: self.edition.or(fallback.edition),
+            channel: self.channel.or(fallback.channel),
+            build_mode: self.build_mode.or(fallback.build_mode),
+            crate_type: self.crate_type.or(fallback.crate_type),
+            backtrace: self.backtrace.or(fallback.backtrace),
+            lint: self.lint.or(fallback.lint),
+            lint_level: self.lint_level.or(fallback.lint_level),
+            format: self.format.or(fallback.format),
+        }
+    }
+
+    /// Fill unset values with the defaults (2021, stable, debug, lib, no
+    /// backtrace, no lint or format checks).
+    pub fn resolve(self) -> ResolvedToolchain {
+        ResolvedToolchain {
+            edition: self.edition.unwrap_or_default(),
+            channel: self.channel.unwrap_or_default(),
+            build_mode: self.build_mode.unwrap_or_default(),
+            crate_type: self.crate_type.unwrap_or_default(),
+            backtrace: self.backtrace.unwrap_or(false),
+            lint: self.lint,
+            lint_level: self.lint_level.unwrap_or_default(),
+            format: self.format,
+        }
+    }
+
+    /// Set a value from a `key=value` attribute or metadata field.
+    ///
+    /// Returns `Ok(false)` if `key` isn't a toolchain setting.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<bool, String> {
+        match key {
+            "edition" => self.edition = Some(value.parse()?),
+            "channel" => self.channel = Some(value.parse()?),
+            "build_mode" => self.build_mode = Some(value.parse()?),
+            "crate_type" => self.crate_type = Some(value.parse()?),
+            "backtrace" => {
+                self.backtrace = Some(match value.to_lowercase().as_str() {
+                    "true" | "yes" | "1" => true,
+                    "false" | "no" | "0" => false,
+                    _ => return Err(format!("Invalid backtrace setting: {}", value)),
+                })
+            }
+            "lint" => self.lint = Some(value.parse()?),
+            "lint_level" => self.lint_level = Some(value.parse()?),
+            "format" => self.format = Some(value.parse()?),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+}
+
+/// Rust edition.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Edition {
+    #[serde(rename = "2015")]
+    E2015,
+    #[serde(rename = "2018")]
+    E2018,
+    #[default]
+    #[serde(rename = "2021")]
+    E2021,
+    #[serde(rename = "2024")]
+    E2024,
+}
+
+impl std::fmt::Display for Edition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Edition::E2015 => write!(f, "2015"),
+            Edition::E2018 => write!(f, "2018"),
+            Edition::E2021 => write!(f, "2021"),
+            Edition::E2024 => write!(f, "2024"),
+        }
+    }
+}
+
+impl std::str::FromStr for Edition {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "2015" => Ok(Edition::E2015),
+            "2018" => Ok(Edition::E2018),
+            "2021" => Ok(Edition::E2021),
+            "2024" => Ok(Edition::E2024),
+            _ => Err(format!("Invalid edition: {}", s)),
+        }
+    }
+}
+
+/// Rust release channel.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Channel {
+    #[default]
+    Stable,
+    Beta,
+    Nightly,
+}
+
+impl std::fmt::Display for Channel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Channel::Stable => write!(f, "stable"),
+            Channel::Beta => write!(f, "beta"),
+            Channel::Nightly => write!(f, "nightly"),
+        }
+    }
+}
+
+impl std::str::FromStr for Channel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "stable" => Ok(Channel::Stable),
+            "beta" => Ok(Channel::Beta),
+            "nightly" => Ok(Channel::Nightly),
+            _ => Err(format!("Invalid channel: {}", s)),
+        }
+    }
+}
+
+/// Build profile.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BuildMode {
+    #[default]
+    Debug,
+    Release,
+}
+
+impl std::fmt::Display for BuildMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuildMode::Debug => write!(f, "debug"),
+            BuildMode::Release => write!(f, "release"),
+        }
+    }
+}
+
+impl std::str::FromStr for BuildMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "debug" => Ok(BuildMode::Debug),
+            "release" => Ok(BuildMode::Release),
+            _ => Err(format!("Invalid build mode: {}", s)),
+        }
+    }
+}
+
+/// Kind of crate the code is compiled as.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CrateType {
+    #[default]
+    Lib,
+    Bin,
+}
+
+impl std::fmt::Display for CrateType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CrateType::Lib => write!(f, "lib"),
+            CrateType::Bin => write!(f, "bin"),
+        }
+    }
+}
+
+impl std::str::FromStr for CrateType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "lib" | "library" => Ok(CrateType::Lib),
+            "bin" | "binary" => Ok(CrateType::Bin),
+            _ => Err(format!("Invalid crate type: {}", s)),
+        }
+    }
+}
+
+/// Linter run on the student's code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Linter {
+    Clippy,
+}
+
+impl std::fmt::Display for Linter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Linter::Clippy => write!(f, "clippy"),
+        }
+    }
+}
+
+impl std::str::FromStr for Linter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "clippy" => Ok(Linter::Clippy),
+            _ => Err(format!("Invalid linter: {}", s)),
+        }
+    }
+}
+
+/// Formatter the student's code is checked against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Formatter {
+    Rustfmt,
+}
+
+impl std::fmt::Display for Formatter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Formatter::Rustfmt => write!(f, "rustfmt"),
+        }
+    }
+}
+
+impl std::str::FromStr for Formatter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "rustfmt" => Ok(Formatter::Rustfmt),
+            _ => Err(format!("Invalid formatter: {}", s)),
+        }
+    }
+}
+
+/// What lint warnings and formatting differences do to a run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckLevel {
+    /// Reported alongside the test results
+    #[default]
+    Warn,
+    /// Reported, and the run fails
+    Deny,
+}
+
+impl std::fmt::Display for CheckLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CheckLevel::Warn => write!(f, "warn"),
+            CheckLevel::Deny => write!(f, "deny"),
+        }
+    }
+}
+
+impl std::str::FromStr for CheckLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "warn" => Ok(CheckLevel::Warn),
+            "deny" => Ok(CheckLevel::Deny),
+            _ => Err(format!("Invalid lint level: {}", s)),
         }
     }
 }
@@ -239,6 +881,286 @@ impl std::str::FromStr for TestMode {
     }
 }
 
+/// Input/output tests: the code runs as a program once per case, and its
+/// standard output is compared with the expected output.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IoTestBlock {
+    /// Programming language
+    pub language: String,
+
+    /// The cases, in order
+    pub cases: Vec<IoTestCase>,
+
+    /// Execution mode
+    pub mode: TestMode,
+
+    /// Toolchain settings (from the block attributes, falling back to the
+    /// exercise metadata)
+    #[serde(default)]
+    pub toolchain: ToolchainSettings,
+}
+
+impl Default for IoTestBlock {
+    fn default() -> Self {
+        Self {
+            language: DEFAULT_LANGUAGE.to_string(),
+            cases: Vec::new(),
+            mode: TestMode::Playground,
+            toolchain: ToolchainSettings::default(),
+        }
+    }
+}
+
+/// One run of a program: its input and the output expected from it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IoTestCase {
+    /// Case name, shown in the results
+    pub name: String,
+
+    /// Text fed to standard input
+    #[serde(default, deserialize_with = "scalar_string")]
+    pub stdin: String,
+
+    /// Command-line arguments
+    #[serde(default, deserialize_with = "scalar_strings")]
+    pub args: Vec<String>,
+
+    /// Expected standard output (a pattern for `match: regex`)
+    #[serde(deserialize_with = "scalar_string")]
+    pub expected_stdout: String,
+
+    /// How the output is compared
+    #[serde(default, rename = "match")]
+    pub matching: OutputMatch,
+}
+
+impl IoTestCase {
+    /// Whether a program's output satisfies this case.
+    ///
+    /// An invalid `regex` pattern matches nothing.
+    pub fn matches(&self, stdout: &str) -> bool {
+        match self.matching {
+            OutputMatch::Exact => stdout == self.expected_stdout,
+            OutputMatch::Trim => trim_output(stdout) == trim_output(&self.expected_stdout),
+            OutputMatch::Regex => regex::Regex::new(&self.expected_stdout)
+                .map(|re| re.is_match(stdout.trim()))
+                .unwrap_or(false),
+        }
+    }
+}
+
+/// A YAML scalar, so that `expected_stdout: 42` needn't be quoted.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Scalar {
+    String(String),
+    Integer(i64),
+    Float(f64),
+    Bool(bool),
+}
+
+impl From<Scalar> for String {
+    fn from(scalar: Scalar) -> Self {
+        match scalar {
+            Scalar::String(s) => s,
+            Scalar::Integer(n) => n.to_string(),
+            Scalar::Float(n) => n.to_string(),
+            Scalar::Bool(b) => b.to_string(),
+        }
+    }
+}
+
+fn scalar_string<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    Scalar::deserialize(deserializer).map(String::from)
+}
+
+fn scalar_strings<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    Vec::<Scalar>::deserialize(deserializer).map(|v| v.into_iter().map(String::from).collect())
+}
+
+/// Output without leading and trailing blank space or trailing spaces on
+/// each line, with `\r\n` line endings normalized.
+fn trim_output(output: &str) -> String {
+    output
+        .trim()
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// How an I/O test case compares a program's output with the expected output.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputMatch {
+    /// Byte-for-byte equality
+    Exact,
+
+    /// Equality ignoring surrounding whitespace and trailing spaces on each line
+    #[default]
+    Trim,
+
+    /// `expected_stdout` is a regular expression found in the output
+    /// (without surrounding whitespace, so `^` and `$` anchor to its text)
+    Regex,
+}
+
+impl std::fmt::Display for OutputMatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutputMatch::Exact => write!(f, "exact"),
+            OutputMatch::Trim => write!(f, "trim"),
+            OutputMatch::Regex => write!(f, "regex"),
+        }
+    }
+}
+
+impl std::str::FromStr for OutputMatch {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "exact" => Ok(OutputMatch::Exact),
+            "trim" => Ok(OutputMatch::Trim),
+            "regex" => Ok(OutputMatch::Regex),
+            _ => Err(format!("Invalid output match: {}", s)),
+        }
+    }
+}
+
+/// Rules on the student's code that tests can't express, such as using the
+/// technique the exercise teaches.
+///
+/// Constructs are matched as text, as whole words where they start or end
+/// with a word character (`match` doesn't match `matches`). Lines that are
+/// entirely comments are ignored.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Requirements {
+    /// Constructs the code must contain (e.g. `match`, `impl Iterator`)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub must_use: Vec<String>,
+
+    /// Constructs the code must not contain (e.g. `unwrap()`, `unsafe`)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub must_not_use: Vec<String>,
+
+    /// Maximum lines of code, not counting blank and comment lines
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_lines: Option<usize>,
+}
+
+impl Requirements {
+    /// The rules `code` breaks, as messages such as "must use `match`".
+    ///
+    /// `comment` is the language's line comment prefix.
+    pub fn violations(&self, code: &str, comment: &str) -> Vec<String> {
+        let lines: Vec<&str> = code
+            .lines()
+            .filter(|line| {
+                let line = line.trim();
+                !line.is_empty() && !line.starts_with(comment)
+            })
+            .collect();
+        let code = lines.join("\n");
+
+        let mut violations = Vec::new();
+        for construct in &self.must_use {
+            if !contains_construct(&code, construct) {
+                violations.push(format!("must use `{}`", construct));
+            }
+        }
+        for construct in &self.must_not_use {
+            if contains_construct(&code, construct) {
+                violations.push(format!("must not use `{}`", construct));
+            }
+        }
+        if let Some(max) = self.max_lines.filter(|max| lines.len() > *max) {
+            violations.push(format!("has {} lines of code, more than {}", lines.len(), max));
+        }
+        violations
+    }
+}
+
+/// Whether `construct` appears in `code`, bounded as a word at ends that are
+/// word characters.
+fn contains_construct(code: &str, construct: &str) -> bool {
+    let is_word = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+    let pattern = format!(
+        "{}{}{}",
+        if is_word(construct.chars().next()) { r"\b" } else { "" },
+        regex::escape(construct),
+        if is_word(construct.chars().last()) { r"\b" } else { "" }
+    );
+    regex::Regex::new(&pattern).map(|re| re.is_match(code)).unwrap_or(false)
+}
+
+/// A Parsons problem: correct code whose lines students put back in order,
+/// and optionally indent, from a shuffled list that may include distractors.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParsonsProblem {
+    /// Programming language
+    pub language: String,
+
+    /// Lines of the correct code, in order (blank lines are dropped)
+    pub lines: Vec<ParsonsLine>,
+
+    /// Lines that don't belong in the answer
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub distractors: Vec<ParsonsLine>,
+
+    /// Whether students set the indentation too; otherwise lines keep theirs
+    pub indent: bool,
+
+    /// One level of indentation (e.g. four spaces)
+    pub indent_unit: String,
+}
+
+/// A line of a Parsons problem, without its indentation.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParsonsLine {
+    /// The line's code
+    pub code: String,
+
+    /// Indentation level
+    pub indent: usize,
+}
+
+impl ParsonsProblem {
+    /// The correct code.
+    pub fn code(&self) -> String {
+        self.assemble(&self.lines)
+    }
+
+    /// Code made of `lines`, indented with the problem's indentation unit.
+    pub fn assemble(&self, lines: &[ParsonsLine]) -> String {
+        lines
+            .iter()
+            .map(|line| format!("{}{}", self.indent_unit.repeat(line.indent), line.code))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Whether `answer` is the correct code. Identical lines are
+    /// interchangeable, and indentation only counts when students set it.
+    pub fn check(&self, answer: &[ParsonsLine]) -> bool {
+        answer.len() == self.lines.len()
+            && answer
+                .iter()
+                .zip(&self.lines)
+                .all(|(given, expected)| given.code == expected.code && (!self.indent || given.indent == expected.indent))
+    }
+
+    /// The correct code as a solution, to run the tests against.
+    pub fn solution(&self) -> Solution {
+        Solution {
+            code: self.code(),
+            language: self.language.clone(),
+            ..Default::default()
+        }
+    }
+}
+
 /// A UseCase exercise - scenario-based analysis with LLM evaluation.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct UseCaseExercise {
@@ -399,4 +1321,211 @@ pub struct SampleAnswer {
 
 // Note: Evaluation output types (UseCaseEvaluation, CriterionScore, KeyPointCoverage,
 // EvaluationFeedback) are defined in the MCP server crate, not here. The preprocessor
-// only needs to parse and render exercises; evaluation happens server-side.
\ No newline at end of file
+// only needs to parse and render exercises; evaluation happens server-side.
+
+/// A quiz exercise - single-choice, multiple-choice and short-answer
+/// questions, graded in the browser.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QuizExercise {
+    /// Quiz metadata
+    pub metadata: QuizMetadata,
+
+    /// Title from first heading
+    pub title: Option<String>,
+
+    /// Introduction (markdown before the first question)
+    pub description: String,
+
+    /// Learning objectives
+    pub objectives: Option<Objectives>,
+
+    /// The questions, in order (`::: question` blocks)
+    pub questions: Vec<QuizQuestion>,
+
+    /// Progressive hints
+    pub hints: Vec<Hint>,
+}
+
+impl QuizExercise {
+    /// Fraction of questions (0.0-1.0) that must be answered correctly to
+    /// pass: the `pass_mark`, or all of them.
+    pub fn pass_mark(&self) -> f32 {
+        self.metadata.pass_mark.unwrap_or(1.0)
+    }
+}
+
+/// Metadata specific to quiz exercises.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QuizMetadata {
+    /// Unique identifier
+    pub id: String,
+
+    /// Difficulty level
+    pub difficulty: Difficulty,
+
+    /// Estimated time in minutes
+    pub time_minutes: Option<u32>,
+
+    /// Prerequisites
+    pub prerequisites: Vec<String>,
+
+    /// Fraction of questions (0.0-1.0) needed to pass
+    pub pass_mark: Option<f32>,
+
+    /// Show the questions, and their options, in random order
+    pub shuffle: bool,
+}
+
+/// One quiz question.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct QuizQuestion {
+    /// Kind of question
+    #[serde(rename = "type", default)]
+    pub kind: QuestionType,
+
+    /// The question (markdown)
+    pub prompt: String,
+
+    /// Options of a single- or multiple-choice question
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<QuizOption>,
+
+    /// Accepted answers of a short-answer question
+    #[serde(default, deserialize_with = "scalar_strings", skip_serializing_if = "Vec::is_empty")]
+    pub answers: Vec<String>,
+
+    /// How short answers are compared with `answers`
+    #[serde(rename = "match", default)]
+    pub matching: AnswerMatch,
+
+    /// Compare short answers case-sensitively
+    #[serde(default)]
+    pub case_sensitive: bool,
+
+    /// Whether to shuffle the options; defaults to the quiz's `shuffle`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shuffle: Option<bool>,
+
+    /// Shown once the question is answered (markdown)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<String>,
+}
+
+impl QuizQuestion {
+    /// Whether `chosen` (option indices) are exactly the correct options.
+    pub fn check_choices(&self, chosen: &[usize]) -> bool {
+        let mut chosen = chosen.to_vec();
+        chosen.sort_unstable();
+        chosen.dedup();
+        let correct: Vec<usize> = self
+            .options
+            .iter()
+            .enumerate()
+            .filter(|(_, option)| option.correct)
+            .map(|(i, _)| i)
+            .collect();
+        chosen == correct
+    }
+
+    /// Whether a short answer matches one of the accepted `answers`.
+    ///
+    /// Surrounding whitespace is ignored, and so is case unless
+    /// `case_sensitive` is set. Regexes must match the whole answer.
+    pub fn check_answer(&self, answer: &str) -> bool {
+        let answer = answer.trim();
+        self.answers.iter().any(|expected| match self.matching {
+            AnswerMatch::Exact if self.case_sensitive => answer == expected.trim(),
+            AnswerMatch::Exact => answer.to_lowercase() == expected.trim().to_lowercase(),
+            AnswerMatch::Regex => regex::RegexBuilder::new(&format!("^(?:{})$", expected))
+                .case_insensitive(!self.case_sensitive)
+                .build()
+                .is_ok_and(|re| re.is_match(answer)),
+        })
+    }
+}
+
+/// An option of a choice question.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct QuizOption {
+    /// Option text (inline markdown)
+    #[serde(deserialize_with = "scalar_string")]
+    pub text: String,
+
+    /// Whether choosing it is (part of) the right answer
+    #[serde(default)]
+    pub correct: bool,
+
+    /// Why the option is right or wrong, shown after answering (markdown)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<String>,
+}
+
+/// Kind of quiz question.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QuestionType {
+    /// Exactly one correct option
+    #[default]
+    Single,
+    /// Any number of correct options, all of which must be chosen
+    Multiple,
+    /// A typed answer
+    Short,
+}
+
+impl std::fmt::Display for QuestionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuestionType::Single => write!(f, "single"),
+            QuestionType::Multiple => write!(f, "multiple"),
+            QuestionType::Short => write!(f, "short"),
+        }
+    }
+}
+
+impl std::str::FromStr for QuestionType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "single" | "single-choice" => Ok(QuestionType::Single),
+            "multiple" | "multiple-choice" => Ok(QuestionType::Multiple),
+            "short" | "short-answer" => Ok(QuestionType::Short),
+            _ => Err(format!("Invalid question type: {}", s)),
+        }
+    }
+}
+
+/// How a short answer is compared with the accepted answers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AnswerMatch {
+    /// Equal, ignoring surrounding whitespace
+    #[default]
+    Exact,
+    /// The answer matches the regex as a whole
+    Regex,
+}
+
+impl std::fmt::Display for AnswerMatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswerMatch::Exact => write!(f, "exact"),
+            AnswerMatch::Regex => write!(f, "regex"),
+        }
+    }
+}
+
+impl std::str::FromStr for AnswerMatch {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "exact" => Ok(AnswerMatch::Exact),
+            "regex" => Ok(AnswerMatch::Regex),
+            _ => Err(format!("Invalid answer match: {}", s)),
+        }
+    }
+}
@@ -0,0 +1,201 @@
+//! Structural validation across a set of exercise files.
+//!
+//! Validation catches problems that make an exercise unusable: parse
+//! errors, duplicate IDs and references to prerequisites that don't exist.
+//! Style and quality issues are the job of the [`lint`](crate::lint) module.
+
+use crate::collect::ExerciseFile;
+use crate::types::ParsedExercise;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+/// How serious a diagnostic is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// A single problem found in an exercise file.
+#[derive(Debug, Clone, Serialize)]
+pub struct Diagnostic {
+    /// Severity of the problem
+    pub severity: Severity,
+
+    /// Short machine-readable code (e.g., "duplicate-id")
+    pub code: String,
+
+    /// File the problem was found in
+    pub file: PathBuf,
+
+    /// 1-based line number, when known
+    pub line: Option<usize>,
+
+    /// Exercise ID, when the file parsed far enough to have one
+    pub exercise_id: Option<String>,
+
+    /// Human-readable description
+    pub message: String,
+
+    /// Suggested fix, when one is available
+    pub suggestion: Option<String>,
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}[{}]: {}", self.severity, self.code, self.file.display())?;
+        if let Some(line) = self.line {
+            write!(f, ":{}", line)?;
+        }
+        if let Some(id) = &self.exercise_id {
+            write!(f, " ({})", id)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// Result of validating a set of exercise files.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ValidationReport {
+    /// Number of exercise files checked
+    pub files_checked: usize,
+
+    /// Number of exercises that parsed successfully
+    pub exercises: usize,
+
+    /// All problems found
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl ValidationReport {
+    /// Number of error-level diagnostics.
+    pub fn error_count(&self) -> usize {
+        self.diagnostics.iter().filter(|d| d.severity == Severity::Error).count()
+    }
+
+    /// Number of warning-level diagnostics.
+    pub fn warning_count(&self) -> usize {
+        self.diagnostics.iter().filter(|d| d.severity == Severity::Warning).count()
+    }
+}
+
+/// ID and prerequisites of any exercise variant.
+pub(crate) fn exercise_id_and_prereqs(parsed: &ParsedExercise) -> (&str, &[String]) {
+    match parsed {
+        ParsedExercise::Code(ex) => (&ex.metadata.id, &ex.metadata.prerequisites),
+        ParsedExercise::UseCase(ex) => (&ex.metadata.id, &ex.metadata.prerequisites),
+        ParsedExercise::Quiz(ex) => (&ex.metadata.id, &ex.metadata.prerequisites),
+    }
+}
+
+/// Validate a set of loaded exercise files.
+pub fn validate_files(files: &[ExerciseFile]) -> ValidationReport {
+    let mut report = ValidationReport {
+        files_checked: files.len(),
+        ..Default::default()
+    };
+
+    let mut first_seen: HashMap<&str, &PathBuf> = HashMap::new();
+    for file in files {
+        let parsed = match &file.parsed {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                report.diagnostics.push(Diagnostic {
+                    severity: Severity::Error,
+                    code: "parse-error".to_string(),
+                    file: file.path.clone(),
+                    line: None,
+                    exercise_id: None,
+                    message: e.to_string(),
+                    suggestion: None,
+                });
+                continue;
+            }
+        };
+        report.exercises += 1;
+
+        let (id, _) = exercise_id_and_prereqs(parsed);
+        if let Some(previous) = first_seen.get(id) {
+            report.diagnostics.push(Diagnostic {
+                severity: Severity::Error,
+                code: "duplicate-id".to_string(),
+                file: file.path.clone(),
+                line: None,
+                exercise_id: Some(id.to_string()),
+                message: format!("exercise ID '{}' is already used by {}", id, previous.display()),
+                suggestion: Some("give every exercise a unique `id`".to_string()),
+            });
+        } else {
+            first_seen.insert(id, &file.path);
+        }
+    }
+
+    let known: HashSet<&str> = first_seen.keys().copied().collect();
+    for file in files {
+        let Some(parsed) = file.exercise() else { continue };
+        let (id, prereqs) = exercise_id_and_prereqs(parsed);
+        for prereq in prereqs {
+            if !known.contains(prereq.as_str()) {
+                report.diagnostics.push(Diagnostic {
+                    severity: Severity::Warning,
+                    code: "unknown-prerequisite".to_string(),
+                    file: file.path.clone(),
+                    line: None,
+                    exercise_id: Some(id.to_string()),
+                    message: format!("prerequisite '{}' does not match any checked exercise", prereq),
+                    suggestion: None,
+                });
+            }
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_exercise;
+
+    fn file(name: &str, source: &str) -> ExerciseFile {
+        ExerciseFile {
+            path: PathBuf::from(name),
+            source: source.to_string(),
+            parsed: parse_exercise(source),
+        }
+    }
+
+    #[test]
+    fn test_duplicate_ids_and_unknown_prereqs() {
+        let a = file("a.md", "::: exercise\nid: same\nprerequisites:\n  - missing\n:::\n");
+        let b = file("b.md", "::: exercise\nid: same\n:::\n");
+        let report = validate_files(&[a, b]);
+
+        assert_eq!(report.exercises, 2);
+        assert_eq!(report.error_count(), 1);
+        assert_eq!(report.warning_count(), 1);
+        assert!(report.diagnostics.iter().any(|d| d.code == "duplicate-id"));
+        assert!(report.diagnostics.iter().any(|d| d.code == "unknown-prerequisite"));
+    }
+
+    #[test]
+    fn test_parse_errors_are_reported() {
+        let broken = file("broken.md", "::: exercise\ndifficulty: beginner\n:::\n");
+        let report = validate_files(&[broken]);
+
+        assert_eq!(report.exercises, 0);
+        assert_eq!(report.error_count(), 1);
+        assert_eq!(report.diagnostics[0].code, "parse-error");
+    }
+}
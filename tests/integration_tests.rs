@@ -2,7 +2,7 @@
 //!
 //! These tests verify the full flow from markdown to HTML.
 
-use mdbook_exercises::{parse_exercise, Difficulty, Exercise, ParsedExercise, TestMode};
+use mdbook_exercises::{parse_exercise, Difficulty, Exercise, ParseError, ParsedExercise, TestMode};
 
 #[cfg(feature = "render")]
 use mdbook_exercises::render::{render_exercise, render_exercise_with_config, RenderConfig};
@@ -12,6 +12,7 @@ fn unwrap_code(parsed: ParsedExercise) -> Exercise {
     match parsed {
         ParsedExercise::Code(ex) => ex,
         ParsedExercise::UseCase(_) => panic!("Expected Code exercise, got UseCase"),
+        ParsedExercise::Quiz(_) => panic!("Expected Code exercise, got Quiz"),
     }
 }
 
@@ -572,3 +573,1099 @@ This is a discussion-only exercise.
     assert!(exercise.starter.is_none());
     assert!(exercise.discussion.is_some());
 }
+
+/// Test running an example's tests against its solution with cargo.
+#[test]
+fn test_local_runner_on_example() {
+    use mdbook_exercises::collect::load_exercise_files;
+    use mdbook_exercises::runner::{test_exercises, ExerciseTestStatus, RunOptions, RunStatus};
+
+    let files = load_exercise_files(&[
+        "examples/hello-world.md".into(),
+        "examples/multilang-python.md".into(),
+    ], &Default::default())
+    .expect("Failed to load examples");
+    let reports = test_exercises(&files, &RunOptions::default());
+
+    assert_eq!(reports.len(), 2);
+    let hello = reports.iter().find(|r| r.id == "hello-world").unwrap();
+    assert_eq!(hello.status, ExerciseTestStatus::Passed, "{:?}", hello);
+    assert_eq!(hello.solution.as_ref().unwrap().tests.len(), 4);
+    // The starter compiles but fails the tests
+    assert_eq!(hello.starter.as_ref().unwrap().status, RunStatus::Failed);
+}
+
+/// Test the `expect` attribute on starter blocks.
+#[test]
+fn test_starter_expectation() {
+    use mdbook_exercises::StarterExpectation;
+
+    let markdown = r#"
+::: exercise
+id: fix-the-bug
+difficulty: beginner
+:::
+
+::: starter expect=compile_error
+```rust
+fn main() { let x: i32 = "oops"; }
+```
+:::
+"#;
+    let exercise = unwrap_code(parse_exercise(markdown).expect("Failed to parse"));
+    assert_eq!(exercise.starter.unwrap().expect, StarterExpectation::CompileError);
+
+    let invalid = markdown.replace("compile_error", "maybe");
+    assert!(parse_exercise(&invalid).is_err());
+}
+
+/// Test that the language registry drives parser defaults and rendering.
+#[test]
+#[cfg(feature = "render")]
+fn test_language_registry() {
+    use mdbook_exercises::language::LanguageRegistry;
+    use mdbook_exercises::{parse_exercise_with_options, ParseOptions};
+
+    let markdown = r#"
+::: exercise
+id: py-add
+difficulty: beginner
+:::
+
+::: starter
+```
+def add(a, b):
+    raise NotImplementedError
+```
+:::
+
+::: tests
+```py
+assert add(1, 2) == 3
+```
+:::
+"#;
+    let mut languages = LanguageRegistry::default();
+    languages.set_default_language("python");
+    let options = ParseOptions {
+        languages: languages.clone(),
+        ..Default::default()
+    };
+    let parsed = parse_exercise_with_options(markdown, &options).expect("Failed to parse");
+    let exercise = unwrap_code(parsed.clone());
+    assert_eq!(exercise.starter.as_ref().unwrap().language, "python");
+    assert_eq!(exercise.tests.as_ref().unwrap().language, "py");
+
+    let config = RenderConfig {
+        languages,
+        ..Default::default()
+    };
+    let html = render_exercise_with_config(&parsed, &config).expect("Failed to render");
+    // Python has no playground: tests are shown with the local command instead
+    assert!(!html.contains("btn-run-tests"));
+    assert!(html.contains("python3 -m pytest"));
+    assert!(html.contains(r#"class="language-python""#));
+}
+
+/// Test toolchain settings from exercise metadata and tests attributes.
+#[test]
+#[cfg(feature = "render")]
+fn test_toolchain_settings() {
+    use mdbook_exercises::{BuildMode, Channel, Edition, ToolchainSettings};
+
+    let markdown = r#"
+::: exercise
+id: let-chains
+difficulty: advanced
+edition: 2024
+channel: nightly
+:::
+
+::: starter
+```rust
+pub fn first_even(v: &[i32]) -> Option<i32> { todo!() }
+```
+:::
+
+::: tests mode=playground build_mode=release backtrace=true
+```rust
+#[test]
+fn finds_even() { assert_eq!(first_even(&[1, 2]), Some(2)); }
+```
+:::
+"#;
+    let exercise = unwrap_code(parse_exercise(markdown).expect("Failed to parse"));
+    assert_eq!(exercise.metadata.toolchain.edition, Some(Edition::E2024));
+    let tests = exercise.tests.clone().unwrap();
+    assert_eq!(tests.toolchain.edition, Some(Edition::E2024));
+    assert_eq!(tests.toolchain.channel, Some(Channel::Nightly));
+    assert_eq!(tests.toolchain.build_mode, Some(BuildMode::Release));
+    assert_eq!(tests.toolchain.crate_type, None);
+
+    // Book defaults fill in what the exercise leaves unset
+    let config = RenderConfig {
+        toolchain: ToolchainSettings {
+            edition: Some(Edition::E2018),
+            crate_type: Some(mdbook_exercises::CrateType::Bin),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let html = render_exercise_with_config(&ParsedExercise::Code(exercise), &config).unwrap();
+    assert!(html.contains(r#"data-edition="2024""#));
+    assert!(html.contains(r#"data-channel="nightly""#));
+    assert!(html.contains(r#"data-build-mode="release""#));
+    assert!(html.contains(r#"data-crate-type="bin""#));
+    assert!(html.contains(r#"data-backtrace="true""#));
+
+    let invalid = markdown.replace("edition: 2024", "edition: 2019");
+    assert!(parse_exercise(&invalid).is_err());
+}
+
+/// Test declared crate dependencies in exercise metadata.
+#[test]
+#[cfg(feature = "render")]
+fn test_exercise_dependencies() {
+    let markdown = r#"
+::: exercise
+id: roll-dice
+difficulty: beginner
+dependencies:
+  - rand = "0.8"
+  - serde = { version = "1", features = ["derive"] }
+:::
+
+::: starter
+```rust
+pub fn roll() -> u8 { todo!() }
+```
+:::
+
+::: tests mode=playground
+```rust
+#[test]
+fn in_range() { assert!((1..=6).contains(&roll())); }
+```
+:::
+"#;
+    let exercise = unwrap_code(parse_exercise(markdown).expect("Failed to parse"));
+    let deps = &exercise.metadata.dependencies;
+    assert_eq!(deps.len(), 2);
+    assert_eq!(deps[0].name, "rand");
+    assert_eq!(deps[0].version(), Some("0.8"));
+    assert_eq!(
+        deps[1].cargo_toml_line(),
+        r#"serde = { version = "1", features = ["derive"] }"#
+    );
+
+    let html = render_exercise(&ParsedExercise::Code(exercise)).unwrap();
+    assert!(html.contains(r#"class="badge dependencies""#));
+    assert!(html.contains("📦 rand, serde"));
+    assert!(html.contains("data-dependencies="));
+
+    // Map form
+    let map = markdown.replace(
+        "  - rand = \"0.8\"\n  - serde = { version = \"1\", features = [\"derive\"] }",
+        "  rand: \"0.8\"",
+    );
+    let exercise = unwrap_code(parse_exercise(&map).expect("Failed to parse map form"));
+    assert_eq!(exercise.metadata.dependencies[0].version(), Some("0.8"));
+
+    let invalid = markdown.replace("rand = \"0.8\"", "rand! = \"0.8\"");
+    assert!(parse_exercise(&invalid).is_err());
+}
+
+/// Test rustdoc-style hidden lines in starter, solution and tests.
+#[test]
+#[cfg(feature = "render")]
+fn test_hidden_lines() {
+    let markdown = r#"
+::: exercise
+id: word-count
+difficulty: beginner
+:::
+
+::: starter
+```rust
+# use std::collections::HashMap;
+pub fn count(text: &str) -> HashMap<&str, usize> {
+    todo!()
+}
+# fn main() {}
+```
+:::
+
+::: solution
+```rust
+# use std::collections::HashMap;
+pub fn count(text: &str) -> HashMap<&str, usize> {
+    let mut map = HashMap::new();
+    for word in text.split_whitespace() { *map.entry(word).or_default() += 1; }
+    map
+}
+```
+:::
+
+::: tests mode=playground
+```python,hidelines=~
+~import solution
+def test_count():
+    assert solution.count("a a") == {"a": 2}
+```
+:::
+"#;
+    let exercise = unwrap_code(parse_exercise(markdown).expect("Failed to parse"));
+
+    // The code that runs keeps the hidden lines, without their markers
+    let starter = exercise.starter.clone().unwrap();
+    assert!(starter.code.starts_with("use std::collections::HashMap;\n"));
+    assert_eq!(starter.hidden_lines, vec![0, 4]);
+    let solution = exercise.solution.clone().unwrap();
+    assert_eq!(solution.hidden_lines, vec![0]);
+    let tests = exercise.tests.clone().unwrap();
+    assert_eq!(tests.language, "python");
+    assert!(tests.code.starts_with("import solution\n"));
+    assert_eq!(tests.hidden_lines, vec![0]);
+
+    let html = render_exercise(&ParsedExercise::Code(exercise)).unwrap();
+    assert!(html.contains(r#"data-original="pub fn count"#));
+    assert!(html.contains(r#"data-hidden-prefix="use std::collections::HashMap;&#10;""#));
+    assert!(html.contains(r#"data-hidden-suffix="&#10;fn main() {}""#));
+    assert!(html.contains(r#"<span class="boring">import solution
+</span>"#));
+}
+
+/// Test that starter lines hidden between visible ones are rejected.
+#[test]
+fn test_interior_hidden_lines_rejected() {
+    let markdown = r#"
+::: exercise
+id: word-count
+difficulty: beginner
+:::
+
+::: starter
+```rust
+pub fn count(text: &str) -> usize {
+# let text = text.trim();
+    todo!()
+}
+```
+:::
+"#;
+    let err = parse_exercise(markdown).unwrap_err();
+    assert!(matches!(err, ParseError::InteriorHiddenLine { line: 2 }));
+
+    // Hidden lines of a solution can be anywhere
+    let solution = markdown.replace("::: starter", "::: solution").replace("todo!()", "text.len()");
+    assert!(parse_exercise(&solution).is_ok());
+}
+
+/// Test locked regions in starter code.
+#[test]
+#[cfg(feature = "render")]
+fn test_locked_regions() {
+    let markdown = r#"
+::: exercise
+id: area
+difficulty: beginner
+:::
+
+::: starter
+```rust
+# use std::f64::consts::PI;
+// @locked-begin
+pub fn area(radius: f64) -> f64 {
+// @locked-end
+    todo!()
+// @locked-begin
+}
+// @locked-end
+```
+:::
+"#;
+    let exercise = unwrap_code(parse_exercise(markdown).expect("Failed to parse"));
+    let starter = exercise.starter.clone().unwrap();
+    assert_eq!(starter.code, "use std::f64::consts::PI;\npub fn area(radius: f64) -> f64 {\n    todo!()\n}");
+    assert_eq!(starter.locked_lines, vec![1, 3]);
+    assert_eq!(starter.locked_regions(), vec!["pub fn area(radius: f64) -> f64 {", "}"]);
+    assert!(starter.preserves_locked("use std::f64::consts::PI;\npub fn area(radius: f64) -> f64 {\n    PI * radius * radius\n}"));
+    assert!(!starter.preserves_locked("pub fn area(r: f32) -> f32 {\n    0.0\n}"));
+
+    // Line numbers in the editor leave out the hidden prefix
+    let html = render_exercise(&ParsedExercise::Code(exercise)).unwrap();
+    assert!(html.contains(r#"data-locked-lines="0,2""#));
+    assert!(html.contains("Lines 1 and 3 are locked"));
+
+    // The same lock as a fence attribute
+    let fenced = "::: exercise\nid: a\n:::\n\n::: starter\n```rust,locked=1;3\npub fn f() {\n    todo!()\n}\n```\n:::\n";
+    let exercise = unwrap_code(parse_exercise(fenced).expect("Failed to parse"));
+    assert_eq!(exercise.starter.unwrap().locked_lines, vec![0, 2]);
+
+    assert!(parse_exercise(&fenced.replace("locked=1;3", "locked=3-1")).is_err());
+}
+
+/// Test starters with several files.
+#[test]
+#[cfg(feature = "render")]
+fn test_multi_file_starter() {
+    let markdown = r#"
+::: exercise
+id: calculator
+difficulty: intermediate
+:::
+
+::: starter file="src/lib.rs"
+```rust
+pub mod parser;
+pub fn eval(input: &str) -> i64 { parser::parse(input) }
+```
+
+```rust,filename=src/parser.rs
+pub fn parse(input: &str) -> i64 {
+    todo!()
+}
+```
+:::
+
+::: starter
+```toml,filename=Cargo.toml
+[package]
+name = "calculator"
+```
+:::
+"#;
+    let exercise = unwrap_code(parse_exercise(markdown).expect("Failed to parse"));
+    assert_eq!(exercise.starter.as_ref().unwrap().filename.as_deref(), Some("src/lib.rs"));
+    let files: Vec<_> = exercise.starter_files.iter().map(|f| f.source_file().path).collect();
+    assert_eq!(files, vec!["src/parser.rs", "Cargo.toml"]);
+    assert_eq!(exercise.starter_files[1].language, "toml");
+
+    let html = render_exercise(&ParsedExercise::Code(exercise)).unwrap();
+    assert!(html.contains(r#"<div class="starter-tabs" role="tablist">"#));
+    assert!(html.contains(r#"data-target="code-calculator-1">src/parser.rs</button>"#));
+    assert!(html.contains(r#"id="code-calculator-2" data-language="toml""#));
+    assert!(html.contains(r#"data-path="Cargo.toml""#));
+    assert!(html.contains(r#"<div class="starter-file" id="code-calculator-1-panel" role="tabpanel" hidden>"#));
+
+    // Further files need distinct names
+    let unnamed = markdown.replace(",filename=src/parser.rs", "");
+    assert!(parse_exercise(&unnamed).is_err());
+    let duplicate = markdown.replace("filename=Cargo.toml", "filename=src/parser.rs");
+    assert!(parse_exercise(&duplicate).is_err());
+    // Including the main file's default path when it has no `file=`
+    let default_path = markdown
+        .replace(r#"::: starter file="src/lib.rs""#, "::: starter")
+        .replace("filename=Cargo.toml", "filename=src/lib.rs");
+    assert!(parse_exercise(&default_path).is_err());
+}
+
+/// Test solutions with several files, run in place of the starter's files.
+#[test]
+#[cfg(feature = "render")]
+fn test_multi_file_solution() {
+    use mdbook_exercises::collect::ExerciseFile;
+    use mdbook_exercises::runner::{test_exercise, ExerciseTestStatus, RunOptions, RunStatus};
+
+    let markdown = r#"
+::: exercise
+id: calculator
+difficulty: intermediate
+:::
+
+::: starter file="src/lib.rs"
+```rust
+pub mod parser;
+pub fn eval(input: &str) -> i64 { parser::parse(input) }
+```
+
+```rust,filename=src/parser.rs
+pub fn parse(input: &str) -> i64 {
+    todo!()
+}
+```
+:::
+
+::: solution
+```rust
+pub mod parser;
+pub fn eval(input: &str) -> i64 { parser::parse(input) }
+```
+
+```rust,filename=src/parser.rs
+pub fn parse(input: &str) -> i64 {
+    input.trim().parse().unwrap()
+}
+```
+:::
+
+::: tests mode=local
+```rust
+#[test]
+fn evaluates() {
+    assert_eq!(eval(" 42 "), 42);
+}
+```
+:::
+"#;
+    let file = ExerciseFile {
+        path: "calculator.md".into(),
+        source: markdown.to_string(),
+        parsed: parse_exercise(markdown),
+    };
+    let report = test_exercise(&file, &RunOptions::default());
+    assert_eq!(report.status, ExerciseTestStatus::Passed, "{:?}", report);
+    assert_eq!(report.solution.as_ref().unwrap().tests.len(), 1);
+    // The starter runs with its own, unfinished parser
+    assert_eq!(report.starter.as_ref().unwrap().status, RunStatus::Failed);
+
+    let exercise = unwrap_code(parse_exercise(markdown).unwrap());
+    let solution = exercise.solution.clone().unwrap();
+    assert_eq!(solution.files.len(), 1);
+    assert_eq!(solution.files[0].path, "src/parser.rs");
+
+    let html = render_exercise(&ParsedExercise::Code(exercise)).unwrap();
+    assert!(html.contains(r#"<div class="solution-file-tabs" role="tablist">"#));
+    assert!(html.contains(r#"data-target="calculator-solution-1-file-1">src/lib.rs</button>"#));
+    assert!(html.contains(r#"data-target="calculator-solution-1-file-2">src/parser.rs</button>"#));
+    assert!(html.contains(r#"<div class="solution-file" id="calculator-solution-1-file-2-panel" role="tabpanel" hidden>"#));
+
+    // Fences without a filename aren't files
+    let untagged = markdown.replace("```rust,filename=src/parser.rs\npub fn parse(input: &str) -> i64 {\n    input", "```rust\npub fn parse(input: &str) -> i64 {\n    input");
+    let exercise = unwrap_code(parse_exercise(&untagged).unwrap());
+    assert!(exercise.solution.unwrap().files.is_empty());
+}
+
+/// Test alternative solutions.
+#[test]
+#[cfg(feature = "render")]
+fn test_alternative_solutions() {
+    let markdown = r#"
+::: exercise
+id: sum
+difficulty: beginner
+:::
+
+::: solution title="Idiomatic"
+```rust
+pub fn sum(v: &[i32]) -> i32 { v.iter().sum() }
+```
+
+### Explanation
+`Iterator::sum` does the work.
+:::
+
+::: solution approach="Beginner-friendly"
+```rust
+pub fn sum(v: &[i32]) -> i32 {
+    let mut total = 0;
+    for x in v { total += x; }
+    total
+}
+```
+
+### Explanation
+A plain loop with an accumulator.
+:::
+"#;
+    let exercise = unwrap_code(parse_exercise(markdown).expect("Failed to parse"));
+    assert_eq!(exercise.solution.as_ref().unwrap().title.as_deref(), Some("Idiomatic"));
+    assert_eq!(exercise.alternative_solutions.len(), 1);
+    let alternative = &exercise.alternative_solutions[0];
+    assert_eq!(alternative.title.as_deref(), Some("Beginner-friendly"));
+    assert!(alternative.explanation.as_ref().unwrap().contains("accumulator"));
+    assert_eq!(exercise.solutions().count(), 2);
+
+    let html = render_exercise(&ParsedExercise::Code(exercise)).unwrap();
+    assert_eq!(html.matches(r#"<details class="solution""#).count(), 1);
+    assert!(html.contains(r#"data-target="sum-solution-2">Beginner-friendly</button>"#));
+    assert!(html.contains(r#"<div class="solution-panel" id="sum-solution-2-panel" role="tabpanel" hidden>"#));
+    assert_eq!(html.matches("solution-explanation").count(), 2);
+}
+
+/// Test hidden tests are parsed but never rendered.
+#[test]
+#[cfg(feature = "render")]
+fn test_hidden_tests() {
+    let markdown = r#"
+::: exercise
+id: clamp
+difficulty: beginner
+:::
+
+::: starter
+```rust
+pub fn clamp(x: i32) -> i32 { x }
+```
+:::
+
+::: tests
+```rust
+#[test]
+fn test_in_range() { assert_eq!(clamp(5), 5); }
+```
+:::
+
+::: tests visibility=hidden
+```rust
+#[test]
+fn test_secret_edge_case() { assert_eq!(clamp(-1), 0); }
+```
+:::
+"#;
+    let exercise = unwrap_code(parse_exercise(markdown).expect("Failed to parse"));
+    assert!(exercise.tests.as_ref().unwrap().code.contains("test_in_range"));
+    assert!(exercise.hidden_tests.as_ref().unwrap().code.contains("test_secret_edge_case"));
+
+    let html = render_exercise(&ParsedExercise::Code(exercise)).unwrap();
+    assert!(html.contains("test_in_range"));
+    assert!(!html.contains("test_secret_edge_case"));
+    assert!(html.contains(r#"class="hidden-tests-note""#));
+
+    // `::: hidden-tests` is the same as `visibility=hidden`
+    let block = markdown.replace("::: tests visibility=hidden", "::: hidden-tests");
+    let exercise = unwrap_code(parse_exercise(&block).expect("Failed to parse"));
+    assert!(exercise.hidden_tests.is_some());
+
+    let invalid = markdown.replace("visibility=hidden", "visibility=secret");
+    assert!(parse_exercise(&invalid).is_err());
+}
+
+/// Test solutions and tests merged from a private directory.
+#[test]
+fn test_private_dir() {
+    use mdbook_exercises::{parse_exercise_with_options, ParseOptions};
+
+    let markdown = r#"
+::: exercise
+id: hello-world
+difficulty: beginner
+:::
+
+::: starter
+```rust
+pub fn greet() -> &'static str { todo!() }
+```
+:::
+"#;
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("hello-world.solution.md"),
+        "::: solution\n```rust\npub fn greet() -> &'static str { \"Hello\" }\n```\n:::\n",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("hello-world.tests.rs"),
+        "#[test]\nfn test_greet() { assert_eq!(greet(), \"Hello\"); }\n",
+    )
+    .unwrap();
+    std::fs::write(dir.path().join("hello-world.notes.txt"), "not a companion file").unwrap();
+    std::fs::write(dir.path().join("other.tests.rs"), "#[test]\nfn other() {}\n").unwrap();
+
+    // Without the private directory, the public exercise parses as is
+    let public = unwrap_code(parse_exercise(markdown).unwrap());
+    assert!(public.solution.is_none());
+    assert!(public.tests.is_none());
+
+    let options = ParseOptions {
+        private_dir: Some(dir.path().to_path_buf()),
+        ..Default::default()
+    };
+    let exercise = unwrap_code(parse_exercise_with_options(markdown, &options).unwrap());
+    assert!(exercise.solution.as_ref().unwrap().code.contains("\"Hello\""));
+    let tests = exercise.tests.as_ref().unwrap();
+    assert!(tests.code.contains("fn test_greet"));
+    assert!(!tests.code.contains("fn other"));
+    assert_eq!(tests.language, "rs");
+
+    // A missing private directory is ignored
+    let options = ParseOptions {
+        private_dir: Some(dir.path().join("missing")),
+        ..Default::default()
+    };
+    assert!(unwrap_code(parse_exercise_with_options(markdown, &options).unwrap()).solution.is_none());
+}
+
+/// Test starter code derived from solution markers.
+#[test]
+fn test_starter_from_solution() {
+    let markdown = r#"
+::: exercise
+id: stack
+difficulty: beginner
+:::
+
+::: solution file=src/lib.rs
+```rust
+# use std::collections::VecDeque;
+pub struct Stack(Vec<i32>);
+
+impl Stack {
+    // @todo
+    pub fn push(&mut self, x: i32) {
+        self.0.push(x);
+    }
+
+    pub fn pop(&mut self) -> Option<i32> {
+        // region:student
+        self.0.pop()
+        // endregion
+    }
+}
+```
+:::
+"#;
+    let exercise = unwrap_code(parse_exercise(markdown).expect("Failed to parse"));
+    let starter = exercise.starter.as_ref().unwrap();
+    assert_eq!(starter.filename.as_deref(), Some("src/lib.rs"));
+    assert!(starter.code.contains("pub fn push(&mut self, x: i32) {\n        todo!()\n    }"));
+    assert!(starter.code.contains("pub fn pop(&mut self) -> Option<i32> {\n        todo!()\n    }"));
+    assert!(!starter.code.contains("self.0"));
+    assert_eq!(starter.hidden_lines, vec![0]);
+    let solution = exercise.solution.as_ref().unwrap();
+    assert!(solution.code.contains("self.0.pop()"));
+    assert!(!solution.code.contains("region:student"));
+
+    // An explicit starter for the same file clashes with the derived one
+    let explicit = format!("{}\n::: starter\n```rust\npub struct Stack;\n```\n:::\n", markdown);
+    assert!(parse_exercise(&explicit).is_err());
+
+    // ... but can add other files
+    let extra = format!("{}\n::: starter file=src/main.rs\n```rust\nfn main() {{}}\n```\n:::\n", markdown);
+    let exercise = unwrap_code(parse_exercise(&extra).expect("Failed to parse"));
+    assert_eq!(exercise.starter.as_ref().unwrap().filename.as_deref(), Some("src/lib.rs"));
+    assert_eq!(exercise.starter_files[0].filename.as_deref(), Some("src/main.rs"));
+}
+
+/// Test starter, solution and tests code included from source files.
+#[test]
+fn test_source_includes() {
+    use mdbook_exercises::{parse_exercise_with_options, ParseOptions};
+
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("code/src")).unwrap();
+    std::fs::create_dir_all(dir.path().join("book")).unwrap();
+    std::fs::write(
+        dir.path().join("code/src/lib.rs"),
+        "// ANCHOR: solution\npub fn square(x: i32) -> i32 {\n    x * x\n}\n// ANCHOR_END: solution\n\n#[cfg(test)]\nmod tests {\n    use super::*;\n    // ANCHOR: tests\n    #[test]\n    fn test_square() { assert_eq!(square(3), 9); }\n    // ANCHOR_END: tests\n}\n",
+    )
+    .unwrap();
+
+    let markdown = r#"
+::: exercise
+id: square
+difficulty: beginner
+:::
+
+::: starter source="../code/src/lib.rs:2"
+:::
+
+::: solution source="../code/src/lib.rs#solution"
+### Explanation
+Multiply the number by itself.
+:::
+
+::: tests source="../code/src/lib.rs#tests"
+:::
+"#;
+    let options = ParseOptions {
+        base_dir: Some(dir.path().join("book")),
+        ..Default::default()
+    };
+    let exercise = unwrap_code(parse_exercise_with_options(markdown, &options).expect("Failed to parse"));
+    let starter = exercise.starter.as_ref().unwrap();
+    assert_eq!(starter.code, "pub fn square(x: i32) -> i32 {");
+    assert_eq!(starter.language, "rs");
+    let solution = exercise.solution.as_ref().unwrap();
+    assert_eq!(solution.code, "pub fn square(x: i32) -> i32 {\n    x * x\n}");
+    assert!(solution.explanation.as_ref().unwrap().contains("by itself"));
+    assert!(exercise.tests.as_ref().unwrap().code.contains("fn test_square"));
+
+    let missing = markdown.replace("#tests", "#nope");
+    assert!(parse_exercise_with_options(&missing, &options).is_err());
+}
+
+/// Test compile-fail exercises with expected rustc error codes.
+#[test]
+#[cfg(feature = "render")]
+fn test_compile_fail_exercise() {
+    use mdbook_exercises::StarterExpectation;
+
+    let markdown = r#"
+::: exercise
+id: use-after-move
+difficulty: beginner
+expect: compile_error
+error_codes: [E0382]
+:::
+
+::: starter
+```rust
+pub fn twice() -> usize {
+    let s = String::from("hi");
+    let t = s;
+    s.len() + t.len()
+}
+```
+
+```text,diagnostic
+error[E0382]: borrow of moved value: `s`
+ --> src/lib.rs:4:5
+```
+:::
+
+::: solution
+```rust
+pub fn twice() -> usize {
+    let s = String::from("hi");
+    let t = s.clone();
+    s.len() + t.len()
+}
+```
+:::
+"#;
+    let exercise = unwrap_code(parse_exercise(markdown).expect("Failed to parse"));
+    let starter = exercise.starter.as_ref().unwrap();
+    assert_eq!(starter.expect, StarterExpectation::CompileError);
+    assert_eq!(starter.error_codes, vec!["E0382"]);
+    assert_eq!(starter.diagnostic.as_deref(), Some("error[E0382]: borrow of moved value: `s`\n --> src/lib.rs:4:5"));
+    assert!(exercise.starter_files.is_empty());
+
+    // Without tests, the exercise can still be run to check that it compiles
+    let html = render_exercise(&ParsedExercise::Code(exercise)).unwrap();
+    assert!(html.contains(r#"class="expected-outcome""#));
+    assert!(html.contains("https://doc.rust-lang.org/error_codes/E0382.html"));
+    assert!(html.contains(r#"<pre class="expected-diagnostic"><code>error[E0382]: borrow of moved value: `s`"#));
+    assert!(html.contains(r#"data-error-codes="E0382""#));
+    assert!(!html.contains("View Test Code"));
+
+    // Starter attributes work too, and imply `expect=compile_error`
+    let attrs = markdown
+        .replace("expect: compile_error\nerror_codes: [E0382]\n", "")
+        .replace("::: starter", "::: starter error_codes=E0382,E0505");
+    let exercise = unwrap_code(parse_exercise(&attrs).expect("Failed to parse"));
+    let starter = exercise.starter.as_ref().unwrap();
+    assert_eq!(starter.expect, StarterExpectation::CompileError);
+    assert_eq!(starter.error_codes, vec!["E0382", "E0505"]);
+
+    // An explicit `expect` on the starter wins over the metadata's
+    let explicit = markdown.replace("::: starter", "::: starter expect=tests_fail");
+    let exercise = unwrap_code(parse_exercise(&explicit).expect("Failed to parse"));
+    let starter = exercise.starter.as_ref().unwrap();
+    assert_eq!(starter.expect, StarterExpectation::TestsFail);
+    assert!(starter.error_codes.is_empty());
+    let explicit = markdown
+        .replace("expect: compile_error\n", "")
+        .replace("::: starter", "::: starter expect=compile_error");
+    let exercise = unwrap_code(parse_exercise(&explicit).expect("Failed to parse"));
+    assert_eq!(exercise.starter.as_ref().unwrap().error_codes, vec!["E0382"]);
+
+    let invalid = markdown.replace("[E0382]", "[borrowck]");
+    assert!(parse_exercise(&invalid).is_err());
+}
+
+/// Test stdin/stdout cases that run the solution as a program.
+#[test]
+#[cfg(feature = "render")]
+fn test_io_tests() {
+    use mdbook_exercises::OutputMatch;
+
+    let markdown = r#"
+::: exercise
+id: sum-lines
+difficulty: beginner
+:::
+
+::: starter file=src/main.rs
+```rust
+fn main() {
+    todo!()
+}
+```
+:::
+
+::: io-tests
+- name: two numbers
+  stdin: |
+    1
+    2
+  expected_stdout: 3
+- name: greeting
+  args: [--greet, Ada]
+  expected_stdout: "^Hello, \\w+!$"
+  match: regex
+:::
+"#;
+    let exercise = unwrap_code(parse_exercise(markdown).expect("Failed to parse"));
+    let io = exercise.io_tests.as_ref().unwrap();
+    assert_eq!(io.language, "rust");
+    assert_eq!(io.cases.len(), 2);
+    assert_eq!(io.cases[0].stdin, "1\n2\n");
+    assert_eq!(io.cases[0].expected_stdout, "3");
+    assert_eq!(io.cases[0].matching, OutputMatch::Trim);
+    assert!(io.cases[0].matches("3\n"));
+    assert_eq!(io.cases[1].args, vec!["--greet", "Ada"]);
+    assert!(io.cases[1].matches("Hello, Ada!\n"));
+
+    let html = render_exercise(&ParsedExercise::Code(exercise)).unwrap();
+    assert!(html.contains(r#"<table class="io-tests">"#));
+    assert!(html.contains("<th>Arguments</th>"));
+    assert!(html.contains("<code>--greet Ada</code>"));
+    assert!(html.contains(r#"data-io-tests="[{"#));
+    assert!(!html.contains("View Test Code"));
+
+    let invalid = markdown.replace(r"^Hello, \\w+!$", "(unclosed");
+    assert!(parse_exercise(&invalid).is_err());
+    let missing_output = markdown.replace("  expected_stdout: 3\n", "");
+    assert!(parse_exercise(&missing_output).is_err());
+}
+
+/// Test static requirements on the student's code.
+#[test]
+#[cfg(feature = "render")]
+fn test_requirements() {
+    let markdown = r#"
+::: exercise
+id: no-unwrap
+difficulty: beginner
+:::
+
+::: starter
+```rust
+pub fn parse(s: &str) -> Option<i32> {
+    todo!()
+}
+```
+:::
+
+::: requirements
+must_use: [match]
+must_not_use: ["unwrap()", "expect("]
+max_lines: 8
+:::
+"#;
+    let exercise = unwrap_code(parse_exercise(markdown).expect("Failed to parse"));
+    let requirements = exercise.requirements.as_ref().unwrap();
+    assert_eq!(requirements.must_use, vec!["match"]);
+    assert_eq!(requirements.must_not_use, vec!["unwrap()", "expect("]);
+    assert_eq!(requirements.max_lines, Some(8));
+    assert_eq!(
+        requirements.violations("pub fn parse(s: &str) -> Option<i32> {\n    s.parse().ok()\n}", "//"),
+        vec!["must use `match`"]
+    );
+
+    let html = render_exercise(&ParsedExercise::Code(exercise)).unwrap();
+    assert!(html.contains(r#"<section class="exercise-requirements" id="no-unwrap-requirements""#));
+    assert!(html.contains("data-requirements=\"{&quot;must_use&quot;:[&quot;match&quot;]"));
+    assert!(html.contains("Don't use <code>unwrap()</code>"));
+    assert!(html.contains("At most 8 lines of code"));
+
+    let invalid = markdown.replace("must_use:", "must_uze:");
+    assert!(parse_exercise(&invalid).is_err());
+}
+
+/// Test clippy and rustfmt checks from exercise metadata and tests attributes.
+#[test]
+#[cfg(feature = "render")]
+fn test_code_checks() {
+    use mdbook_exercises::{CheckLevel, Formatter, Linter};
+
+    let markdown = r#"
+::: exercise
+id: idiomatic-sum
+difficulty: intermediate
+lint: clippy
+:::
+
+::: starter
+```rust
+pub fn sum(v: &[i32]) -> i32 { todo!() }
+```
+:::
+
+::: tests format=rustfmt lint_level=deny
+```rust
+#[test]
+fn sums() { assert_eq!(sum(&[1, 2]), 3); }
+```
+:::
+"#;
+    let exercise = unwrap_code(parse_exercise(markdown).expect("Failed to parse"));
+    assert_eq!(exercise.metadata.toolchain.lint, Some(Linter::Clippy));
+    let toolchain = exercise.tests.as_ref().unwrap().toolchain.resolve();
+    assert_eq!(toolchain.lint, Some(Linter::Clippy));
+    assert_eq!(toolchain.format, Some(Formatter::Rustfmt));
+    assert_eq!(toolchain.lint_level, CheckLevel::Deny);
+
+    let html = render_exercise(&ParsedExercise::Code(exercise)).unwrap();
+    assert!(html.contains(r#"data-lint="clippy" data-lint-level="deny" data-format="rustfmt""#));
+    assert!(html.contains("checked with <code>clippy</code> and <code>rustfmt</code>"));
+
+    let invalid = markdown.replace("lint: clippy", "lint: eslint");
+    assert!(parse_exercise(&invalid).is_err());
+}
+
+/// Test parsing, checking and rendering a quiz.
+#[test]
+#[cfg(feature = "render")]
+fn test_quiz_exercise() {
+    use mdbook_exercises::{AnswerMatch, QuestionType};
+
+    let markdown = r#"
+::: quiz
+id: ownership-quiz
+difficulty: beginner
+pass_mark: 0.75
+shuffle: true
+:::
+
+# Ownership Check
+
+::: question
+prompt: Which keyword makes a binding mutable?
+options:
+  - text: "`mut`"
+    correct: true
+  - text: "`let`"
+    explanation: "`let` introduces every binding."
+:::
+
+::: question
+prompt: Which types are `Copy`?
+options:
+  - text: "`i32`"
+    correct: true
+  - text: "`bool`"
+    correct: true
+  - text: "`String`"
+:::
+
+::: question
+prompt: What does `vec![1, 2, 3].len()` return?
+answers: ["3", "3usize"]
+match: regex
+explanation: The vector has three elements.
+:::
+
+::: hint level=1
+Think about moves.
+:::
+"#;
+    let quiz = match parse_exercise(markdown).expect("Failed to parse") {
+        ParsedExercise::Quiz(quiz) => quiz,
+        _ => panic!("Expected Quiz exercise"),
+    };
+    assert_eq!(quiz.metadata.id, "ownership-quiz");
+    assert_eq!(quiz.title.as_deref(), Some("Ownership Check"));
+    assert!(quiz.metadata.shuffle);
+    assert_eq!(quiz.pass_mark(), 0.75);
+    assert_eq!(quiz.hints.len(), 1);
+
+    let kinds: Vec<QuestionType> = quiz.questions.iter().map(|q| q.kind).collect();
+    assert_eq!(kinds, vec![QuestionType::Single, QuestionType::Multiple, QuestionType::Short]);
+    assert!(quiz.questions[0].check_choices(&[0]));
+    assert!(!quiz.questions[0].check_choices(&[1]));
+    assert!(quiz.questions[1].check_choices(&[1, 0]));
+    assert!(!quiz.questions[1].check_choices(&[0]));
+    assert_eq!(quiz.questions[2].matching, AnswerMatch::Regex);
+    assert!(quiz.questions[2].check_answer(" 3 "));
+    assert!(!quiz.questions[2].check_answer("33"));
+
+    let html = render_exercise(&ParsedExercise::Quiz(quiz)).unwrap();
+    assert!(html.contains(r#"<article class="quiz-exercise" data-exercise-id="ownership-quiz""#));
+    assert!(html.contains(r#"data-pass-mark="0.75" data-shuffle="true""#));
+    assert!(html.contains(r#"<fieldset class="quiz-question" id="ownership-quiz-q2" data-type="multiple""#));
+    assert!(html.contains(r#"<li class="quiz-option" data-correct="true">"#));
+    assert!(html.contains(r#"data-match="regex""#));
+    assert!(html.contains("Pass: 75%"));
+    assert!(html.contains("btn-check-quiz"));
+
+    let two_correct = markdown.replacen(
+        "prompt: Which types",
+        "type: single\nprompt: Which types",
+        1,
+    );
+    assert!(parse_exercise(&two_correct).is_err());
+    let bad_regex = markdown.replace(r#"["3", "3usize"]"#, r#"["(3"]"#);
+    assert!(parse_exercise(&bad_regex).is_err());
+    let bad_pass_mark = markdown.replace("pass_mark: 0.75", "pass_mark: 1.5");
+    assert!(parse_exercise(&bad_pass_mark).is_err());
+}
+
+/// Test parsing, checking and rendering a Parsons problem.
+#[test]
+#[cfg(feature = "render")]
+fn test_parsons_problem() {
+    use mdbook_exercises::ParsonsLine;
+
+    let markdown = r#"
+::: exercise
+id: parsons-max
+difficulty: beginner
+:::
+
+::: parsons
+```rust
+fn max(a: i32, b: i32) -> i32 {
+  if a > b {
+    a
+  } else {
+    b
+  }
+}
+```
+
+```rust,distractors
+  if a < b {
+```
+:::
+
+::: tests
+```rust
+#[test]
+fn picks_larger() { assert_eq!(max(1, 2), 2); }
+```
+:::
+"#;
+    let exercise = unwrap_code(parse_exercise(markdown).expect("Failed to parse"));
+    let parsons = exercise.parsons.as_ref().unwrap();
+    assert_eq!(parsons.language, "rust");
+    assert_eq!(parsons.indent_unit, "  ");
+    assert!(parsons.indent);
+    assert_eq!(parsons.lines.len(), 7);
+    assert_eq!(parsons.lines[2], ParsonsLine { code: "a".to_string(), indent: 2 });
+    assert_eq!(parsons.distractors, vec![ParsonsLine { code: "if a < b {".to_string(), indent: 1 }]);
+    assert!(parsons.code().starts_with("fn max(a: i32, b: i32) -> i32 {\n  if a > b {\n    a\n"));
+    assert!(parsons.check(&parsons.lines));
+
+    // The two `b`/`a` lines swapped, or a line indented wrongly, is wrong
+    let mut swapped = parsons.lines.clone();
+    swapped.swap(2, 4);
+    assert!(!parsons.check(&swapped));
+    let mut flat = parsons.lines.clone();
+    flat[2].indent = 1;
+    assert!(!parsons.check(&flat));
+    assert!(!parsons.check(&parsons.lines[..6]));
+
+    let html = render_exercise(&ParsedExercise::Code(exercise)).unwrap();
+    assert!(html.contains(r#"<section class="exercise-parsons" id="parsons-max-parsons" data-language="rust" data-indent="true" data-indent-unit="  ""#));
+    assert!(html.contains(r#"data-code="if a &lt; b {""#));
+    assert!(html.contains(r#"<ul class="parsons-answer""#));
+    assert!(html.contains("Some lines don't belong in the solution."));
+    assert!(html.contains("btn-run-tests"));
+
+    // Lines keep their indentation when students don't set it
+    let fixed = unwrap_code(parse_exercise(&markdown.replace("::: parsons", "::: parsons indent=false")).unwrap());
+    assert!(!fixed.parsons.as_ref().unwrap().indent);
+    let html = render_exercise(&ParsedExercise::Code(fixed)).unwrap();
+    assert!(html.contains(r#"data-code="a" data-indent="2""#));
+
+    let duplicate = markdown.replace("  if a < b {", "  } else {");
+    assert!(parse_exercise(&duplicate).is_err());
+    let uneven = markdown.replace("    a\n", "   a\n");
+    assert!(parse_exercise(&uneven).is_err());
+    let invalid = markdown.replace("::: parsons", "::: parsons indent=maybe");
+    assert!(parse_exercise(&invalid).is_err());
+    let untagged = markdown.replace("```rust,distractors", "```rust");
+    assert!(parse_exercise(&untagged).is_err());
+}